@@ -0,0 +1,20 @@
+//! Exposes Ion decoding to JavaScript. See `index.html` for how it is used from a web page.
+
+use ion_rs::{v1_0, Element, IonResult, TextFormat};
+use wasm_bindgen::prelude::*;
+
+/// Decodes a text or binary Ion payload and returns its values as pretty-printed text Ion.
+#[wasm_bindgen]
+pub fn ion_to_text(data: &[u8]) -> Result<String, JsError> {
+    Ok(to_pretty_text(data)?)
+}
+
+/// Returns the number of top-level values in a text or binary Ion payload.
+#[wasm_bindgen]
+pub fn count_values(data: &[u8]) -> Result<usize, JsError> {
+    Ok(Element::read_all(data)?.len())
+}
+
+fn to_pretty_text(data: &[u8]) -> IonResult<String> {
+    Element::read_all(data)?.encode_as(v1_0::Text.with_format(TextFormat::Pretty))
+}
@@ -0,0 +1,436 @@
+//! `ion`, a command line tool for inspecting and converting Ion data.
+//!
+//! This binary is only built when the `cli` feature is enabled:
+//!
+//! ```text
+//! cargo install ion-rs --features cli
+//! ion dump data.10n
+//! ```
+
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::process::ExitCode;
+
+use ion_rs::{
+    transcode, v1_0, AnyEncoding, Element, ElementReader, Encoding, IonError, IonResult, Reader,
+    Symbol, SystemReader, SystemStreamItem, TextFormat, WriteConfig, Writer,
+};
+
+const USAGE: &str = "\
+USAGE:
+    ion <COMMAND> [OPTIONS] [FILE...]
+
+Reads each FILE in turn, or standard input if no FILE is given or FILE is '-'.
+
+COMMANDS:
+    dump        Writes the input's values as text Ion
+    convert     Writes the input's values in another format
+    count       Prints the number of top-level values in each input
+    symbols     Prints the symbols defined by each input's symbol tables
+    validate    Reads every value in each input and reports whether it is well-formed
+    help        Prints this message
+
+OPTIONS:
+    -f, --format <FORMAT>   The output format of `dump` (default: pretty) or `convert` (default:
+                            binary): one of binary, text, compact, lines or pretty
+    -t, --to <FORMAT>       The same as --format
+    -o, --output <FILE>     Writes the output of `dump` or `convert` to FILE instead of standard
+                            output";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    match run(&args, &mut stdout) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error @ CliError::Usage(_)) => {
+            eprintln!("ion: {error}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+        Err(error) => {
+            eprintln!("ion: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[derive(Debug)]
+enum CliError {
+    /// The command line arguments were invalid.
+    Usage(String),
+    /// An input could not be read or an output could not be written.
+    Io(String, io::Error),
+    /// An input was not valid Ion, or its values could not be written.
+    Ion(String, IonError),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{message}"),
+            CliError::Io(path, error) => write!(f, "{path}: {error}"),
+            CliError::Ion(path, error) => write!(f, "{path}: {error}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Binary,
+    Text(TextFormat),
+}
+
+impl OutputFormat {
+    fn parse(name: &str) -> Result<Self, CliError> {
+        let format = match name {
+            "binary" => OutputFormat::Binary,
+            "text" | "lines" => OutputFormat::Text(TextFormat::Lines),
+            "compact" => OutputFormat::Text(TextFormat::Compact),
+            "pretty" => OutputFormat::Text(TextFormat::Pretty),
+            _ => return Err(CliError::Usage(format!("unknown format '{name}'"))),
+        };
+        Ok(format)
+    }
+}
+
+/// A parsed command line.
+struct Command {
+    name: String,
+    format: Option<OutputFormat>,
+    output: Option<String>,
+    inputs: Vec<String>,
+}
+
+impl Command {
+    fn parse(args: &[String]) -> Result<Self, CliError> {
+        let Some((name, args)) = args.split_first() else {
+            return Err(CliError::Usage("no command was specified".to_string()));
+        };
+        let mut command = Command {
+            name: name.clone(),
+            format: None,
+            output: None,
+            inputs: Vec::new(),
+        };
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = |option: &str| {
+                args.next()
+                    .cloned()
+                    .ok_or_else(|| CliError::Usage(format!("{option} requires a value")))
+            };
+            match arg.as_str() {
+                "-f" | "--format" | "-t" | "--to" => {
+                    command.format = Some(OutputFormat::parse(&value(arg)?)?)
+                }
+                "-o" | "--output" => command.output = Some(value(arg)?),
+                "--" => {
+                    command.inputs.extend(args.cloned());
+                    break;
+                }
+                option if option.len() > 1 && option.starts_with('-') => {
+                    return Err(CliError::Usage(format!("unknown option '{option}'")))
+                }
+                input => command.inputs.push(input.to_string()),
+            }
+        }
+        if command.inputs.is_empty() {
+            command.inputs.push("-".to_string());
+        }
+        Ok(command)
+    }
+}
+
+/// Runs the command described by `args`, writing its output to `stdout` unless the command line
+/// names an output file. Returns `Ok(false)` if the command completed but found invalid input.
+fn run(args: &[String], stdout: &mut dyn Write) -> Result<bool, CliError> {
+    let command = Command::parse(args)?;
+    match command.name.as_str() {
+        "dump" | "convert" => {
+            let default_format = if command.name == "dump" {
+                OutputFormat::Text(TextFormat::Pretty)
+            } else {
+                OutputFormat::Binary
+            };
+            let format = command.format.unwrap_or(default_format);
+            match &command.output {
+                Some(path) => {
+                    let file = File::create(path).map_err(|e| CliError::Io(path.clone(), e))?;
+                    let mut output = BufWriter::new(file);
+                    convert(&command.inputs, format, &mut output)?;
+                    output.flush().map_err(|e| CliError::Io(path.clone(), e))?;
+                }
+                None => convert(&command.inputs, format, stdout)?,
+            }
+            Ok(true)
+        }
+        "count" => {
+            for path in &command.inputs {
+                let mut reader = Reader::new(AnyEncoding, open(path)?).map_err(ion_error(path))?;
+                let mut count = 0usize;
+                while reader.next().map_err(ion_error(path))?.is_some() {
+                    count += 1;
+                }
+                if command.inputs.len() == 1 {
+                    writeln!(stdout, "{count}").map_err(stdout_error)?;
+                } else {
+                    writeln!(stdout, "{count} {path}").map_err(stdout_error)?;
+                }
+            }
+            Ok(true)
+        }
+        "symbols" => {
+            for path in &command.inputs {
+                if command.inputs.len() > 1 {
+                    writeln!(stdout, "{path}:").map_err(stdout_error)?;
+                }
+                print_symbols(path, stdout)?;
+            }
+            Ok(true)
+        }
+        "validate" => {
+            let mut all_valid = true;
+            for path in &command.inputs {
+                match validate(open(path)?) {
+                    Ok(count) => writeln!(stdout, "{path}: ok, {count} values"),
+                    Err(error) => {
+                        all_valid = false;
+                        writeln!(stdout, "{path}: {error}")
+                    }
+                }
+                .map_err(stdout_error)?;
+            }
+            Ok(all_valid)
+        }
+        "help" | "-h" | "--help" => {
+            writeln!(stdout, "{USAGE}").map_err(stdout_error)?;
+            Ok(true)
+        }
+        other => Err(CliError::Usage(format!("unknown command '{other}'"))),
+    }
+}
+
+/// Opens the input at `path`, or standard input if `path` is `-`.
+fn open(path: &str) -> Result<Box<dyn Read>, CliError> {
+    if path == "-" {
+        return Ok(Box::new(io::stdin()));
+    }
+    let file = File::open(path).map_err(|e| CliError::Io(path.to_string(), e))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn ion_error(path: &str) -> impl Fn(IonError) -> CliError + '_ {
+    move |error| CliError::Ion(path.to_string(), error)
+}
+
+fn stdout_error(error: io::Error) -> CliError {
+    CliError::Io("<stdout>".to_string(), error)
+}
+
+/// Writes the values of each input to `output` as a single stream in the requested format.
+fn convert(
+    inputs: &[String],
+    format: OutputFormat,
+    output: &mut dyn Write,
+) -> Result<(), CliError> {
+    match format {
+        OutputFormat::Binary => convert_to(inputs, v1_0::Binary, output),
+        OutputFormat::Text(text_format) => {
+            convert_to(inputs, v1_0::Text.with_format(text_format), output)
+        }
+    }
+}
+
+fn convert_to<E: Encoding>(
+    inputs: &[String],
+    config: impl Into<WriteConfig<E>>,
+    output: &mut dyn Write,
+) -> Result<(), CliError> {
+    let mut writer = Writer::new(config, output).map_err(ion_error("<stdout>"))?;
+    for path in inputs {
+        let mut reader = Reader::new(AnyEncoding, open(path)?).map_err(ion_error(path))?;
+        transcode(&mut reader, &mut writer).map_err(ion_error(path))?;
+    }
+    writer.close().map_err(ion_error("<stdout>"))?;
+    Ok(())
+}
+
+/// Prints the symbols that each of the input's symbol tables adds to the active symbol table,
+/// along with their symbol IDs. When a symbol table replaces the previous one, its symbol IDs
+/// start over after the system symbols of the stream's encoding.
+fn print_symbols(path: &str, output: &mut dyn Write) -> Result<(), CliError> {
+    let mut reader = SystemReader::new(AnyEncoding, open(path)?).map_err(ion_error(path))?;
+    // The local symbols that have already been printed
+    let mut printed: Vec<Symbol> = Vec::new();
+    // Whether the reader has visited a system value since the symbol table was last examined
+    let mut may_have_changed = false;
+    loop {
+        let is_end = match reader.next_item().map_err(ion_error(path))? {
+            SystemStreamItem::VersionMarker(_) | SystemStreamItem::SymbolTable(_) => {
+                may_have_changed = true;
+                continue;
+            }
+            SystemStreamItem::EndOfStream(_) => true,
+            _ => false,
+        };
+        if may_have_changed {
+            may_have_changed = false;
+            let system_symbol_count = reader.detected_encoding().system_symbols().len();
+            let symbols = &reader.symbol_table().symbols()[system_symbol_count..];
+            if !symbols.starts_with(&printed) {
+                printed.clear();
+            }
+            for (offset, symbol) in symbols[printed.len()..].iter().enumerate() {
+                let sid = system_symbol_count + printed.len() + offset;
+                let text = match symbol.text() {
+                    Some(text) => Element::string(text).to_string(),
+                    None => "null".to_string(),
+                };
+                writeln!(output, "${sid}: {text}").map_err(stdout_error)?;
+            }
+            printed = symbols.to_vec();
+        }
+        if is_end {
+            return Ok(());
+        }
+    }
+}
+
+/// Reads every value in the input, returning the number of top-level values.
+fn validate(input: Box<dyn Read>) -> IonResult<usize> {
+    let mut reader = Reader::new(AnyEncoding, input)?;
+    let mut count = 0;
+    while reader.read_next_element()?.is_some() {
+        count += 1;
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn run_to_string(args: &[&str]) -> Result<(bool, Vec<u8>), CliError> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let mut output = Vec::new();
+        let success = run(&args, &mut output)?;
+        Ok((success, output))
+    }
+
+    fn write_file(dir: &TempDir, name: &str, data: impl AsRef<[u8]>) -> String {
+        let path = dir.path().join(name);
+        std::fs::write(&path, data).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn dump_and_convert() -> IonResult<()> {
+        let dir = TempDir::new().unwrap();
+        let text = write_file(&dir, "a.ion", "a::{b: [1, c]} null.int");
+        let (_, binary) = run_to_string(&["convert", &text]).unwrap();
+        assert!(binary.starts_with(&[0xE0, 0x01, 0x00, 0xEA]));
+        assert_eq!(
+            Element::read_all(&binary)?,
+            Element::read_all("a::{b: [1, c]} null.int")?
+        );
+
+        let binary = write_file(&dir, "a.10n", binary);
+        let (_, dumped) = run_to_string(&["dump", "--format", "compact", &binary]).unwrap();
+        assert_eq!(dumped, b"a::{b: [1, c, ], } null.int ");
+        let (_, dumped) = run_to_string(&["dump", &binary]).unwrap();
+        assert_eq!(
+            String::from_utf8(dumped).unwrap(),
+            "a::{\n  b: [\n    1,\n    c,\n  ],\n}\nnull.int\n"
+        );
+
+        // Multiple inputs are written as a single stream
+        let output = dir.path().join("out.10n");
+        let output_path = output.to_str().unwrap();
+        run_to_string(&["convert", "-t", "binary", "-o", output_path, &text, &binary]).unwrap();
+        assert_eq!(
+            Element::read_all(std::fs::read(Path::new(output_path))?)?,
+            Element::read_all("a::{b: [1, c]} null.int a::{b: [1, c]} null.int")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn count() {
+        let dir = TempDir::new().unwrap();
+        let a = write_file(&dir, "a.ion", "1 [2, 3] {four: 4}");
+        let b = write_file(&dir, "b.ion", "");
+        assert_eq!(run_to_string(&["count", &a]).unwrap().1, b"3\n");
+        let (_, output) = run_to_string(&["count", &a, &b]).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!("3 {a}\n0 {b}\n")
+        );
+    }
+
+    #[test]
+    fn symbols() -> IonResult<()> {
+        let dir = TempDir::new().unwrap();
+        let text = r#"
+            $ion_symbol_table::{symbols: ["a", "b c"]}
+            $10
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: [null]}
+            $11
+            $ion_symbol_table::{symbols: ["d"]}
+            $10
+        "#;
+        let path = write_file(&dir, "symbols.ion", text);
+        let (_, output) = run_to_string(&["symbols", &path]).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "$10: \"a\"\n$11: \"b c\"\n$12: null\n$10: \"d\"\n"
+        );
+
+        // Binary streams number their symbols after the same system symbols
+        let binary = Element::read_all("a [b, a]")?.encode_as(v1_0::Binary)?;
+        let path = write_file(&dir, "symbols.10n", binary);
+        let (_, output) = run_to_string(&["symbols", &path]).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "$10: \"a\"\n$11: \"b\"\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn validate() {
+        let dir = TempDir::new().unwrap();
+        let valid = write_file(&dir, "valid.ion", "1 2 3");
+        let invalid = write_file(&dir, "invalid.ion", "1 {a: ");
+        let (success, output) = run_to_string(&["validate", &valid]).unwrap();
+        assert!(success);
+        assert_eq!(output, format!("{valid}: ok, 3 values\n").as_bytes());
+        let (success, output) = run_to_string(&["validate", &valid, &invalid]).unwrap();
+        assert!(!success);
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains(&format!("{invalid}: ")));
+    }
+
+    #[test]
+    fn usage_errors() {
+        for args in [
+            &[][..],
+            &["frobnicate"],
+            &["dump", "--format"],
+            &["dump", "--format", "xml"],
+            &["count", "--verbose"],
+        ] {
+            assert!(matches!(run_to_string(args), Err(CliError::Usage(_))));
+        }
+        assert!(matches!(
+            run_to_string(&["count", "/does/not/exist"]),
+            Err(CliError::Io(_, _))
+        ));
+    }
+}
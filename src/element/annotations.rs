@@ -1,8 +1,14 @@
 use crate::element::iterators::{AnnotationsIntoIter, SymbolsIterator};
 use crate::ion_data::IonOrd;
+use crate::symbol_ref::AsSymbolRef;
 use crate::Symbol;
+use smallvec::SmallVec;
 use std::cmp::Ordering;
 
+// Most values have zero or one annotation. Storing a single annotation inline means that reading
+// annotation-light data does not require a heap allocation for each annotated value.
+pub(crate) type SymbolVec = SmallVec<[Symbol; 1]>;
+
 /// An ordered sequence of symbols that convey additional, application-specific information about
 /// their associated Ion value.
 ///
@@ -18,21 +24,26 @@ use std::cmp::Ordering;
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Annotations {
-    symbols: Vec<Symbol>,
+    symbols: SymbolVec,
 }
 
 impl Annotations {
-    // This is limited to crate visibility to allow us to change `Vec<_>` to something
+    // This is limited to crate visibility to allow us to change `SymbolVec` to something
     // else (`thinvec`?) in the future. Users are unlikely to need to construct an `Annotations`
     // themselves, but can use a `From` impl if necessary.
-    pub(crate) fn new(symbols: Vec<Symbol>) -> Self {
+    pub(crate) fn new(symbols: SymbolVec) -> Self {
         Annotations { symbols }
     }
 
+    /// Provides mutable access to the symbols so that they can be overwritten in place.
+    pub(crate) fn symbols_mut(&mut self) -> &mut SymbolVec {
+        &mut self.symbols
+    }
+
     /// Constructs an Annotations object representing an empty symbol sequence
     pub fn empty() -> Self {
         Annotations {
-            symbols: Vec::new(),
+            symbols: SymbolVec::new(),
         }
     }
 
@@ -79,6 +90,95 @@ impl Annotations {
         self.iter().any(|symbol| symbol.text() == Some(query))
     }
 
+    /// Returns `true` if any symbol in this annotations sequence is equal to the provided symbol.
+    /// Otherwise, returns `false`.
+    ///
+    /// Unlike [`Self::contains`], this accepts a [`Symbol`] or a `&SymbolRef` and can therefore
+    /// match annotations whose text is unknown (`$0`).
+    /// ```
+    /// use ion_rs::{Annotations, IntoAnnotations, Symbol};
+    /// let annotations: Annotations = [Symbol::from("foo"), Symbol::unknown_text()].into_annotations();
+    /// assert!(annotations.contains_symbol("foo"));
+    /// assert!(annotations.contains_symbol(Symbol::unknown_text()));
+    /// assert!(!annotations.contains_symbol("bar"));
+    /// ```
+    pub fn contains_symbol<A: AsSymbolRef>(&self, query: A) -> bool {
+        let query = query.as_symbol_ref();
+        self.iter().any(|symbol| symbol.as_symbol_ref() == query)
+    }
+
+    /// Returns `true` if every one of the provided strings is the text of a symbol in this
+    /// annotations sequence. Otherwise, returns `false`.
+    /// ```
+    /// use ion_rs::{Annotations, IntoAnnotations};
+    /// let annotations: Annotations = ["foo", "bar", "baz"].into_annotations();
+    /// assert!(annotations.contains_all(["baz", "foo"]));
+    /// assert!(!annotations.contains_all(["foo", "quux"]));
+    /// ```
+    pub fn contains_all<I, S>(&self, queries: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        queries.into_iter().all(|query| self.contains(query))
+    }
+
+    /// Returns `true` if at least one of the provided strings is the text of a symbol in this
+    /// annotations sequence. Otherwise, returns `false`.
+    /// ```
+    /// use ion_rs::{Annotations, IntoAnnotations};
+    /// let annotations: Annotations = ["foo", "bar", "baz"].into_annotations();
+    /// assert!(annotations.contains_any(["quux", "bar"]));
+    /// assert!(!annotations.contains_any(["quux", "quuz"]));
+    /// ```
+    pub fn contains_any<I, S>(&self, queries: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        queries.into_iter().any(|query| self.contains(query))
+    }
+
+    /// Returns `true` if this sequence and `other` contain the same set of symbols, ignoring
+    /// their order and any duplicates. Otherwise, returns `false`.
+    ///
+    /// Use `==` to test whether two sequences contain the same symbols in the same order.
+    /// ```
+    /// use ion_rs::{Annotations, IntoAnnotations};
+    /// let annotations: Annotations = ["foo", "bar"].into_annotations();
+    /// assert!(annotations.set_eq(&["bar", "foo", "bar"].into_annotations()));
+    /// assert!(!annotations.set_eq(&["foo"].into_annotations()));
+    /// ```
+    pub fn set_eq(&self, other: &Annotations) -> bool {
+        self.is_subset(other) && other.is_subset(self)
+    }
+
+    /// Returns `true` if every symbol in this sequence also appears in `other`. Otherwise,
+    /// returns `false`.
+    /// ```
+    /// use ion_rs::{Annotations, IntoAnnotations};
+    /// let annotations: Annotations = ["foo", "bar"].into_annotations();
+    /// assert!(annotations.is_subset(&["bar", "baz", "foo"].into_annotations()));
+    /// assert!(!annotations.is_subset(&["foo"].into_annotations()));
+    /// ```
+    pub fn is_subset(&self, other: &Annotations) -> bool {
+        self.iter()
+            .all(|symbol| other.symbols.iter().any(|other| other == symbol))
+    }
+
+    /// Returns an [`Iterator`] that yields the text of each annotation in this sequence in order.
+    /// Annotations with unknown text (`$0`) are yielded as `None`.
+    /// ```
+    /// use ion_rs::{Annotations, IntoAnnotations, Symbol};
+    /// let mut annotations: Annotations = ["foo", "bar"].into_annotations();
+    /// annotations.extend([Symbol::unknown_text()]);
+    /// let text: Vec<Option<&str>> = annotations.iter_text().collect();
+    /// assert_eq!(text, vec![Some("foo"), Some("bar"), None]);
+    /// ```
+    pub fn iter_text(&self) -> impl Iterator<Item = Option<&str>> {
+        self.iter().map(|symbol| symbol.text())
+    }
+
     /// Returns the text of the first annotation in this sequence.
     ///
     /// If the sequence is empty, returns `None`.
@@ -103,6 +203,70 @@ impl Annotations {
     pub fn first(&self) -> Option<&str> {
         self.iter().next().and_then(|a| a.text())
     }
+
+    /// Splits a namespaced annotation like `com.example.retention` into its namespace
+    /// (`com.example`) and name (`retention`) at the last `.` in its text.
+    ///
+    /// Returns `None` if the text does not contain a `.`, or if either the namespace or the name
+    /// would be empty.
+    /// ```
+    /// use ion_rs::Annotations;
+    /// assert_eq!(
+    ///     Annotations::split_namespace("com.example.retention"),
+    ///     Some(("com.example", "retention"))
+    /// );
+    /// assert_eq!(Annotations::split_namespace("retention"), None);
+    /// assert_eq!(Annotations::split_namespace("com.example."), None);
+    /// ```
+    pub fn split_namespace(text: &str) -> Option<(&str, &str)> {
+        let (namespace, name) = text.rsplit_once('.')?;
+        if namespace.is_empty() || name.is_empty() {
+            return None;
+        }
+        Some((namespace, name))
+    }
+
+    /// Returns an [`Iterator`] that yields the name of each annotation in the specified
+    /// namespace, in order. Annotations in nested namespaces (for example, `com.example.a.b` when
+    /// the namespace is `com.example`) are not included.
+    /// ```
+    /// use ion_rs::{Annotations, IntoAnnotations};
+    /// let annotations: Annotations =
+    ///     ["com.example.retention", "internal", "org.other.tier", "com.example.owner"]
+    ///         .into_annotations();
+    /// let names: Vec<&str> = annotations.in_namespace("com.example").collect();
+    /// assert_eq!(names, vec!["retention", "owner"]);
+    /// ```
+    pub fn in_namespace<'a>(&'a self, namespace: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.iter().filter_map(move |symbol| {
+            let (symbol_namespace, name) = Self::split_namespace(symbol.text()?)?;
+            (symbol_namespace == namespace).then_some(name)
+        })
+    }
+
+    /// Returns a copy of this sequence in which each annotation in the specified namespace has
+    /// been replaced by its unqualified name. Other annotations are left as they are.
+    /// ```
+    /// use ion_rs::{Annotations, IntoAnnotations};
+    /// let annotations: Annotations =
+    ///     ["com.example.retention", "internal", "org.other.tier"].into_annotations();
+    /// assert_eq!(
+    ///     annotations.strip_namespace("com.example"),
+    ///     ["retention", "internal", "org.other.tier"].into_annotations()
+    /// );
+    /// ```
+    pub fn strip_namespace(&self, namespace: &str) -> Annotations {
+        self.iter()
+            .map(
+                |symbol| match symbol.text().and_then(Self::split_namespace) {
+                    Some((symbol_namespace, name)) if symbol_namespace == namespace => {
+                        Symbol::from(name)
+                    }
+                    _ => symbol.clone(),
+                },
+            )
+            .collect()
+    }
 }
 
 impl AsRef<[Symbol]> for Annotations {
@@ -113,7 +277,21 @@ impl AsRef<[Symbol]> for Annotations {
 
 impl From<Vec<Symbol>> for Annotations {
     fn from(value: Vec<Symbol>) -> Self {
-        Annotations::new(value)
+        Annotations::new(SymbolVec::from_vec(value))
+    }
+}
+
+impl From<Annotations> for Vec<Symbol> {
+    fn from(value: Annotations) -> Self {
+        value.symbols.into_vec()
+    }
+}
+
+// Allows annotations from different kinds of symbol-convertible sources to be combined into a
+// single sequence.
+impl<S: Into<Symbol>> Extend<S> for Annotations {
+    fn extend<T: IntoIterator<Item = S>>(&mut self, iter: T) {
+        self.symbols.extend(iter.into_iter().map(Into::into))
     }
 }
 
@@ -165,7 +343,7 @@ where
     I: IntoIterator<Item = S>,
 {
     fn into_annotations(self) -> Annotations {
-        let symbols: Vec<Symbol> = self.into_iter().map(|a| a.into()).collect();
+        let symbols: SymbolVec = self.into_iter().map(|a| a.into()).collect();
         Annotations::new(symbols)
     }
 }
@@ -188,4 +366,73 @@ mod tests {
         let from: Annotations = symbols.into();
         assert_eq!(expected, from);
     }
+
+    #[test]
+    fn test_single_annotation_is_stored_inline() -> crate::IonResult<()> {
+        let element = crate::Element::read_one("foo::1")?;
+        assert!(!element.annotations().symbols.spilled());
+        let annotations = ["foo"].into_annotations();
+        assert!(!annotations.symbols.spilled());
+        let annotations = ["foo", "bar"].into_annotations();
+        assert!(annotations.symbols.spilled());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mixed_construction() {
+        let mut annotations: Annotations = [Symbol::from("a")].into_annotations();
+        annotations.extend(["b"]);
+        annotations.extend([String::from("c")]);
+        annotations.extend([Symbol::unknown_text()]);
+        assert_eq!(
+            annotations.iter_text().collect::<Vec<_>>(),
+            vec![Some("a"), Some("b"), Some("c"), None]
+        );
+        let symbols: Vec<Symbol> = annotations.into();
+        assert_eq!(symbols.len(), 4);
+    }
+
+    #[test]
+    fn test_set_comparisons() {
+        let annotations = ["a", "b", "a"].into_annotations();
+        assert!(annotations.set_eq(&["b", "a"].into_annotations()));
+        assert!(annotations.is_subset(&["c", "b", "a"].into_annotations()));
+        assert!(!annotations.set_eq(&["c", "b", "a"].into_annotations()));
+        assert!(annotations.contains_all(Vec::<&str>::new()));
+        assert!(!annotations.contains_any(Vec::<&str>::new()));
+
+        let unknown = [Symbol::unknown_text()].into_annotations();
+        assert!(unknown.set_eq(&unknown));
+        assert!(!unknown.contains_any(["a"]));
+    }
+
+    #[test]
+    fn test_namespaces() {
+        let annotations = [
+            "com.example.retention",
+            "com.example.a.b",
+            "com.example",
+            ".hidden",
+        ]
+        .into_annotations();
+        assert_eq!(
+            annotations.in_namespace("com.example").collect::<Vec<_>>(),
+            vec!["retention"]
+        );
+        assert_eq!(
+            annotations
+                .in_namespace("com.example.a")
+                .collect::<Vec<_>>(),
+            vec!["b"]
+        );
+        assert_eq!(annotations.in_namespace("com").count(), 1);
+        assert_eq!(
+            annotations.strip_namespace("com.example"),
+            ["retention", "com.example.a.b", "com.example", ".hidden"].into_annotations()
+        );
+
+        let unknown = [Symbol::unknown_text()].into_annotations();
+        assert_eq!(unknown.in_namespace("com.example").count(), 0);
+        assert_eq!(unknown.strip_namespace("com.example"), unknown);
+    }
 }
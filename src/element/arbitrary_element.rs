@@ -0,0 +1,270 @@
+//! [`Arbitrary`] implementations for [`Element`] and [`Value`], for use in property tests and
+//! fuzzing.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{Bytes, Element, Int, IonType, Sequence, Str, Struct, Symbol, Value};
+
+const ALL_ION_TYPES: [IonType; 13] = [
+    IonType::Null,
+    IonType::Bool,
+    IonType::Int,
+    IonType::Float,
+    IonType::Decimal,
+    IonType::Timestamp,
+    IonType::Symbol,
+    IonType::String,
+    IonType::Clob,
+    IonType::Blob,
+    IonType::List,
+    IonType::SExp,
+    IonType::Struct,
+];
+
+/// Controls the shape of the [`Element`]s and [`Value`]s produced from [`Unstructured`] data.
+///
+/// The [`Arbitrary`] implementations of `Element` and `Value` use the default configuration.
+///
+/// ```
+/// use arbitrary::Unstructured;
+/// use ion_rs::{ArbitraryElementConfig, IonType};
+///
+/// let config = ArbitraryElementConfig::new()
+///     .with_max_depth(1)
+///     .with_ion_types([IonType::Int, IonType::List]);
+/// let data = [7u8; 64];
+/// let element = config.element(&mut Unstructured::new(&data)).unwrap();
+/// assert!(matches!(element.ion_type(), IonType::Int | IonType::List));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArbitraryElementConfig {
+    max_depth: usize,
+    ion_types: Vec<IonType>,
+    annotations: bool,
+}
+
+impl ArbitraryElementConfig {
+    /// Returns a configuration that produces annotated values of every Ion type, nested at most
+    /// three containers deep.
+    pub fn new() -> Self {
+        Self {
+            max_depth: 3,
+            ion_types: ALL_ION_TYPES.to_vec(),
+            annotations: true,
+        }
+    }
+
+    /// Sets the maximum container nesting depth. Containers at the maximum depth are empty, so a
+    /// maximum depth of zero produces only scalars and empty containers.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the Ion types that may be produced, including as typed nulls. `IonType::Null` controls
+    /// whether an untyped `null` may be produced. If no types are allowed, producing a value fails
+    /// with [`arbitrary::Error::EmptyChoose`].
+    pub fn with_ion_types(mut self, ion_types: impl IntoIterator<Item = IonType>) -> Self {
+        self.ion_types = ion_types.into_iter().collect();
+        self
+    }
+
+    /// Sets whether elements may have annotations.
+    pub fn with_annotations(mut self, annotations: bool) -> Self {
+        self.annotations = annotations;
+        self
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    pub fn ion_types(&self) -> &[IonType] {
+        &self.ion_types
+    }
+
+    pub fn annotations(&self) -> bool {
+        self.annotations
+    }
+
+    /// Produces an [`Element`] from the provided data.
+    pub fn element(&self, u: &mut Unstructured<'_>) -> Result<Element> {
+        self.element_at_depth(u, 0)
+    }
+
+    /// Produces a [`Value`] from the provided data.
+    pub fn value(&self, u: &mut Unstructured<'_>) -> Result<Value> {
+        self.value_at_depth(u, 0)
+    }
+
+    fn element_at_depth(&self, u: &mut Unstructured<'_>, depth: usize) -> Result<Element> {
+        let element = Element::from(self.value_at_depth(u, depth)?);
+        if !self.annotations {
+            return Ok(element);
+        }
+        let annotations = (0..u.int_in_range(0..=2u8)?)
+            .map(|_| arbitrary_symbol(u))
+            .collect::<Result<Vec<Symbol>>>()?;
+        Ok(element.with_annotations(annotations))
+    }
+
+    fn value_at_depth(&self, u: &mut Unstructured<'_>, depth: usize) -> Result<Value> {
+        let ion_type = *u.choose(&self.ion_types)?;
+        if ion_type != IonType::Null && u.ratio(1u8, 16u8)? {
+            return Ok(Value::Null(ion_type));
+        }
+        let value = match ion_type {
+            IonType::Null => Value::Null(IonType::Null),
+            IonType::Bool => Value::Bool(u.arbitrary()?),
+            IonType::Int => Value::Int(Int::from(u.arbitrary::<i128>()?)),
+            IonType::Float => Value::Float(u.arbitrary()?),
+            IonType::Decimal => Value::Decimal(u.arbitrary()?),
+            IonType::Timestamp => Value::Timestamp(u.arbitrary()?),
+            IonType::Symbol => Value::Symbol(arbitrary_symbol(u)?),
+            IonType::String => Value::String(Str::from(u.arbitrary::<String>()?)),
+            IonType::Clob => Value::Clob(Bytes::from(u.arbitrary::<Vec<u8>>()?)),
+            IonType::Blob => Value::Blob(Bytes::from(u.arbitrary::<Vec<u8>>()?)),
+            IonType::List => Value::List(self.sequence(u, depth)?),
+            IonType::SExp => Value::SExp(self.sequence(u, depth)?),
+            IonType::Struct => Value::Struct(self.fields(u, depth)?),
+        };
+        Ok(value)
+    }
+
+    fn sequence(&self, u: &mut Unstructured<'_>, depth: usize) -> Result<Sequence> {
+        if depth >= self.max_depth {
+            return Ok(Sequence::new(Vec::<Element>::new()));
+        }
+        (0..u.arbitrary_len::<Element>()?)
+            .map(|_| self.element_at_depth(u, depth + 1))
+            .collect()
+    }
+
+    fn fields(&self, u: &mut Unstructured<'_>, depth: usize) -> Result<Struct> {
+        if depth >= self.max_depth {
+            return Ok(Struct::from_iter(Vec::<(Symbol, Element)>::new()));
+        }
+        (0..u.arbitrary_len::<Element>()?)
+            .map(|_| Ok((arbitrary_symbol(u)?, self.element_at_depth(u, depth + 1)?)))
+            .collect()
+    }
+}
+
+impl Default for ArbitraryElementConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Produces a symbol with arbitrary text, or occasionally one with unknown text (`$0`).
+fn arbitrary_symbol(u: &mut Unstructured<'_>) -> Result<Symbol> {
+    if u.ratio(1u8, 32u8)? {
+        return Ok(Symbol::unknown_text());
+    }
+    Ok(Symbol::owned(u.arbitrary::<String>()?))
+}
+
+impl<'a> Arbitrary<'a> for Element {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        ArbitraryElementConfig::new().element(u)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Value {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        ArbitraryElementConfig::new().value(u)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ion_data::IonEq;
+    use crate::{v1_0, IonResult};
+
+    /// Returns data that exercises a variety of choices.
+    fn data() -> Vec<u8> {
+        (0..=255u8)
+            .cycle()
+            .zip((0..=250u8).cycle())
+            .map(|(a, b)| a.wrapping_mul(31) ^ b)
+            .take(16 * 1024)
+            .collect()
+    }
+
+    fn depth(element: &Element) -> usize {
+        match element.value() {
+            Value::List(sequence) | Value::SExp(sequence) => {
+                1 + sequence.elements().map(depth).max().unwrap_or(0)
+            }
+            Value::Struct(struct_) => {
+                1 + struct_.fields().map(|(_, e)| depth(e)).max().unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
+    fn elements(config: &ArbitraryElementConfig) -> Vec<Element> {
+        let data = data();
+        let mut u = Unstructured::new(&data);
+        let mut elements = Vec::new();
+        while !u.is_empty() {
+            elements.push(config.element(&mut u).unwrap());
+        }
+        elements
+    }
+
+    #[test]
+    fn arbitrary_elements_round_trip() -> IonResult<()> {
+        let data = data();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let element = Element::arbitrary(&mut u).unwrap();
+            let text = element.encode_as(v1_0::Text)?;
+            let binary = element.encode_as(v1_0::Binary)?;
+            assert!(Element::read_one(text)?.ion_eq(&element));
+            assert!(Element::read_one(binary)?.ion_eq(&element));
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(3)]
+    fn max_depth_is_respected(#[case] max_depth: usize) {
+        let config = ArbitraryElementConfig::new().with_max_depth(max_depth);
+        for element in elements(&config) {
+            // Empty containers at the maximum depth count as one more level.
+            assert!(depth(&element) <= max_depth + 1);
+        }
+    }
+
+    #[test]
+    fn ion_types_are_respected() {
+        let config = ArbitraryElementConfig::new()
+            .with_ion_types([IonType::Int, IonType::List])
+            .with_annotations(false);
+        let elements = elements(&config);
+        assert!(elements.iter().any(|e| e.ion_type() == IonType::Int));
+        assert!(elements.iter().any(|e| e.ion_type() == IonType::List));
+        fn check(element: &Element) {
+            assert!(matches!(element.ion_type(), IonType::Int | IonType::List));
+            assert!(element.annotations().is_empty());
+            if let Some(list) = element.as_sequence() {
+                list.elements().for_each(check);
+            }
+        }
+        elements.iter().for_each(check);
+    }
+
+    #[test]
+    fn no_ion_types_is_an_error() {
+        let config = ArbitraryElementConfig::new().with_ion_types([]);
+        let data = data();
+        assert!(config.element(&mut Unstructured::new(&data)).is_err());
+    }
+}
@@ -0,0 +1,455 @@
+//! Provides [`ArenaElement`], an alternative to [`Element`] whose nodes are allocated in a
+//! caller-provided [`Bump`] arena.
+
+use std::fmt::{Display, Formatter};
+use std::vec::Drain;
+
+use bumpalo::collections::Vec as BumpVec;
+use bumpalo::Bump;
+
+use crate::element::materialize::{materialize, BuildStacks, ElementBuilder};
+use crate::lazy::any_encoding::AnyEncoding;
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonSlice;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::{
+    Annotations, Bytes, Decimal, Element, Int, IonResult, IonType, Sequence, Struct, SymbolRef,
+    SymbolToken, Timestamp, Value,
+};
+
+/// An Ion value whose annotations, text, and child values are stored in a [`Bump`] arena. The
+/// arena type comes from the `bumpalo` crate, which applications add as a dependency of their own.
+///
+/// Materializing an [`Element`] requires a separate heap allocation for each container, string,
+/// and annotation sequence, and dropping it requires visiting every node. For workloads that
+/// read a document, inspect it, and then discard it, those costs can dominate. An `ArenaElement`
+/// borrows all of its data from an arena instead: reading allocates by bumping a pointer, and
+/// nothing is freed until the arena itself is reset or dropped, at which point all of the
+/// elements read into it are freed at once.
+///
+/// `ArenaElement` is `Copy`; it is a small handle to data in the arena. Values that need to
+/// outlive the arena can be converted to an `Element` with [`to_element`](Self::to_element).
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # #[cfg(feature = "experimental-element-apis")]
+/// # fn main() -> IonResult<()> {
+/// use bumpalo::Bump;
+/// use ion_rs::ArenaElement;
+///
+/// let mut arena = Bump::new();
+/// for batch in [r#"{name: "widget", tags: [red, blue]}"#, r#"{name: "gadget", tags: []}"#] {
+///     let elements = ArenaElement::read_all(&arena, batch)?;
+///     let tags = elements[0].get("tags").unwrap().as_sequence().unwrap();
+///     assert!(tags.len() <= 2);
+///     // Free everything that was read from this batch before reading the next one.
+///     arena.reset();
+/// }
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "experimental-element-apis"))]
+/// # fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArenaElement<'bump> {
+    annotations: &'bump [SymbolRef<'bump>],
+    value: ArenaValue<'bump>,
+}
+
+/// The value of an [`ArenaElement`]. Variable-length data is stored in the arena.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArenaValue<'bump> {
+    Null(IonType),
+    Bool(bool),
+    Int(Int),
+    Float(f64),
+    // Decimals and timestamps are stored in the arena to keep the size of each node small.
+    Decimal(&'bump Decimal),
+    Timestamp(&'bump Timestamp),
+    Symbol(SymbolRef<'bump>),
+    String(&'bump str),
+    Clob(&'bump [u8]),
+    Blob(&'bump [u8]),
+    List(&'bump [ArenaElement<'bump>]),
+    SExp(&'bump [ArenaElement<'bump>]),
+    Struct(&'bump [(SymbolRef<'bump>, ArenaElement<'bump>)]),
+}
+
+impl<'bump> ArenaValue<'bump> {
+    pub fn ion_type(&self) -> IonType {
+        match self {
+            ArenaValue::Null(ion_type) => *ion_type,
+            ArenaValue::Bool(_) => IonType::Bool,
+            ArenaValue::Int(_) => IonType::Int,
+            ArenaValue::Float(_) => IonType::Float,
+            ArenaValue::Decimal(_) => IonType::Decimal,
+            ArenaValue::Timestamp(_) => IonType::Timestamp,
+            ArenaValue::Symbol(_) => IonType::Symbol,
+            ArenaValue::String(_) => IonType::String,
+            ArenaValue::Clob(_) => IonType::Clob,
+            ArenaValue::Blob(_) => IonType::Blob,
+            ArenaValue::List(_) => IonType::List,
+            ArenaValue::SExp(_) => IonType::SExp,
+            ArenaValue::Struct(_) => IonType::Struct,
+        }
+    }
+}
+
+impl<'bump> ArenaElement<'bump> {
+    /// Reads all of the values in `data` into `arena`.
+    pub fn read_all<A: AsRef<[u8]>>(
+        arena: &'bump Bump,
+        data: A,
+    ) -> IonResult<&'bump [ArenaElement<'bump>]> {
+        let mut reader = Reader::new(AnyEncoding, IonSlice::new(data))?;
+        let mut builder = ArenaBuilder { arena };
+        let mut stacks = BuildStacks::default();
+        let mut elements = BumpVec::new_in(arena);
+        while let Some(value) = reader.next()? {
+            elements.push(materialize(&mut builder, &mut stacks, value)?);
+        }
+        Ok(elements.into_bump_slice())
+    }
+
+    /// Reads a single value from `data` into `arena`. If the input has invalid data or does not
+    /// contain exactly one value, returns `Err(IonError)`.
+    pub fn read_one<A: AsRef<[u8]>>(arena: &'bump Bump, data: A) -> IonResult<ArenaElement<'bump>> {
+        match Self::read_all(arena, data)? {
+            [element] => Ok(*element),
+            elements => crate::result::IonFailure::decoding_error(format!(
+                "expected 1 value, found {}",
+                elements.len()
+            )),
+        }
+    }
+
+    pub fn annotations(&self) -> &'bump [SymbolRef<'bump>] {
+        self.annotations
+    }
+
+    pub fn value(&self) -> &ArenaValue<'bump> {
+        &self.value
+    }
+
+    pub fn ion_type(&self) -> IonType {
+        self.value.ion_type()
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self.value, ArenaValue::Null(_))
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.value {
+            ArenaValue::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<Int> {
+        match self.value {
+            ArenaValue::Int(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        self.as_int().and_then(|i| i.as_i64())
+    }
+
+    pub fn as_float(&self) -> Option<f64> {
+        match self.value {
+            ArenaValue::Float(f) => Some(f),
+            _ => None,
+        }
+    }
+
+    pub fn as_decimal(&self) -> Option<&'bump Decimal> {
+        match self.value {
+            ArenaValue::Decimal(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    pub fn as_timestamp(&self) -> Option<&'bump Timestamp> {
+        match self.value {
+            ArenaValue::Timestamp(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Returns the text of a string or a symbol with known text.
+    pub fn as_text(&self) -> Option<&'bump str> {
+        match self.value {
+            ArenaValue::String(text) => Some(text),
+            ArenaValue::Symbol(symbol) => symbol.text(),
+            _ => None,
+        }
+    }
+
+    pub fn as_string(&self) -> Option<&'bump str> {
+        match self.value {
+            ArenaValue::String(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_symbol(&self) -> Option<SymbolRef<'bump>> {
+        match self.value {
+            ArenaValue::Symbol(symbol) => Some(symbol),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes of a blob or clob.
+    pub fn as_lob(&self) -> Option<&'bump [u8]> {
+        match self.value {
+            ArenaValue::Clob(bytes) | ArenaValue::Blob(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Returns the child values of a list or s-expression.
+    pub fn as_sequence(&self) -> Option<&'bump [ArenaElement<'bump>]> {
+        match self.value {
+            ArenaValue::List(children) | ArenaValue::SExp(children) => Some(children),
+            _ => None,
+        }
+    }
+
+    /// Returns the `(name, value)` pairs of a struct, in the order they were read.
+    pub fn as_struct(&self) -> Option<&'bump [(SymbolRef<'bump>, ArenaElement<'bump>)]> {
+        match self.value {
+            ArenaValue::Struct(fields) => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// If this is a struct, returns the value of its last field with the specified name.
+    /// (This matches the behavior of [`Struct::get`].)
+    pub fn get(&self, field_name: &str) -> Option<&'bump ArenaElement<'bump>> {
+        self.as_struct()?
+            .iter()
+            .rev()
+            .find(|(name, _)| name.text() == Some(field_name))
+            .map(|(_, value)| value)
+    }
+
+    /// Copies this value out of the arena, producing an `Element` with no borrowed data.
+    pub fn to_element(self) -> Element {
+        let value = match self.value {
+            ArenaValue::Null(ion_type) => Value::Null(ion_type),
+            ArenaValue::Bool(b) => Value::Bool(b),
+            ArenaValue::Int(i) => Value::Int(i),
+            ArenaValue::Float(f) => Value::Float(f),
+            ArenaValue::Decimal(d) => Value::Decimal(*d),
+            ArenaValue::Timestamp(t) => Value::Timestamp(*t),
+            ArenaValue::Symbol(s) => Value::Symbol(s.to_owned()),
+            ArenaValue::String(s) => Value::String(s.into()),
+            ArenaValue::Clob(c) => Value::Clob(Bytes::from(c)),
+            ArenaValue::Blob(b) => Value::Blob(Bytes::from(b)),
+            ArenaValue::List(children) => Value::List(to_sequence(children)),
+            ArenaValue::SExp(children) => Value::SExp(to_sequence(children)),
+            ArenaValue::Struct(fields) => Value::Struct(
+                fields
+                    .iter()
+                    .map(|(name, value)| (name.to_owned(), value.to_element()))
+                    .collect::<Struct>(),
+            ),
+        };
+        let annotations: Annotations = self
+            .annotations
+            .iter()
+            .map(|annotation| annotation.to_owned())
+            .collect();
+        Element::new(annotations, value)
+    }
+}
+
+/// Materializes `ArenaElement`s in `arena`.
+struct ArenaBuilder<'bump> {
+    arena: &'bump Bump,
+}
+
+impl<'bump, 'top> ElementBuilder<'top, AnyEncoding> for ArenaBuilder<'bump> {
+    type Element = ArenaElement<'bump>;
+    type Symbol = SymbolRef<'bump>;
+    type Value = ArenaValue<'bump>;
+
+    fn substitute(
+        &mut self,
+        _value: LazyValue<'top, AnyEncoding>,
+        _path: &[usize],
+    ) -> IonResult<Option<ArenaValue<'bump>>> {
+        Ok(None)
+    }
+
+    fn symbol(&mut self, symbol: SymbolToken<'top>) -> SymbolRef<'bump> {
+        symbol_in(self.arena, symbol.resolved())
+    }
+
+    fn symbol_value(&mut self, symbol: SymbolRef<'bump>) -> ArenaValue<'bump> {
+        ArenaValue::Symbol(symbol)
+    }
+
+    fn scalar(&mut self, value: ValueRef<'top, AnyEncoding>) -> IonResult<ArenaValue<'bump>> {
+        let arena = self.arena;
+        let value = match value {
+            ValueRef::Null(ion_type) => ArenaValue::Null(ion_type),
+            ValueRef::Bool(b) => ArenaValue::Bool(b),
+            ValueRef::Int(i) => ArenaValue::Int(i),
+            ValueRef::Float(f) => ArenaValue::Float(f),
+            ValueRef::Decimal(d) => ArenaValue::Decimal(arena.alloc(d)),
+            ValueRef::Timestamp(t) => ArenaValue::Timestamp(arena.alloc(t)),
+            ValueRef::String(s) => ArenaValue::String(arena.alloc_str(s.text())),
+            ValueRef::Clob(c) => ArenaValue::Clob(arena.alloc_slice_copy(c.data())),
+            ValueRef::Blob(b) => ArenaValue::Blob(arena.alloc_slice_copy(b.data())),
+            ValueRef::Symbol(_) => unreachable!("symbols are built by `symbol_value`"),
+            ValueRef::List(_) | ValueRef::SExp(_) | ValueRef::Struct(_) => {
+                unreachable!("containers are built by `sequence` and `structure`")
+            }
+        };
+        Ok(value)
+    }
+
+    fn sequence(
+        &mut self,
+        ion_type: IonType,
+        children: Drain<'_, ArenaElement<'bump>>,
+    ) -> ArenaValue<'bump> {
+        let children = self.arena.alloc_slice_fill_iter(children);
+        match ion_type {
+            IonType::SExp => ArenaValue::SExp(children),
+            _ => ArenaValue::List(children),
+        }
+    }
+
+    fn structure(
+        &mut self,
+        fields: Drain<'_, (SymbolRef<'bump>, ArenaElement<'bump>)>,
+    ) -> ArenaValue<'bump> {
+        ArenaValue::Struct(self.arena.alloc_slice_fill_iter(fields))
+    }
+
+    fn element(
+        &mut self,
+        annotations: Drain<'_, SymbolRef<'bump>>,
+        value: ArenaValue<'bump>,
+    ) -> ArenaElement<'bump> {
+        ArenaElement {
+            annotations: self.arena.alloc_slice_fill_iter(annotations),
+            value,
+        }
+    }
+
+    fn finish(&mut self, _value: LazyValue<'top, AnyEncoding>, _num_children: usize) {}
+}
+
+fn symbol_in<'bump>(arena: &'bump Bump, symbol: SymbolRef<'_>) -> SymbolRef<'bump> {
+    match symbol.text() {
+        Some(text) => SymbolRef::with_text(arena.alloc_str(text)),
+        None => SymbolRef::with_unknown_text(),
+    }
+}
+
+fn to_sequence(children: &[ArenaElement<'_>]) -> Sequence {
+    children
+        .iter()
+        .copied()
+        .map(ArenaElement::to_element)
+        .collect()
+}
+
+impl<'bump> From<ArenaElement<'bump>> for Element {
+    fn from(element: ArenaElement<'bump>) -> Self {
+        element.to_element()
+    }
+}
+
+impl<'bump> Display for ArenaElement<'bump> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.to_element(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v1_0;
+
+    const DATA: &str = r#"
+        null.int
+        true
+        -7
+        2.5e0
+        1.50
+        2024-05-06T07:08:09.123Z
+        foo::bar::baz
+        "hello"
+        {{"clob"}}
+        {{aGVsbG8=}}
+        [1, [2], ()]
+        (a b c)
+        {name: "widget", tags: [red], name: "gadget", $0: 1}
+    "#;
+
+    #[test]
+    fn round_trip_to_element() -> IonResult<()> {
+        let arena = Bump::new();
+        let elements = ArenaElement::read_all(&arena, DATA)?;
+        let expected = Element::read_all(DATA)?;
+        assert_eq!(elements.len(), expected.len());
+        for (arena_element, element) in elements.iter().zip(expected.iter()) {
+            assert_eq!(&arena_element.to_element(), element);
+            assert_eq!(arena_element.ion_type(), element.ion_type());
+            assert_eq!(arena_element.to_string(), element.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_from_binary() -> IonResult<()> {
+        let arena = Bump::new();
+        let binary = Element::read_all(DATA)?.encode_as(v1_0::Binary)?;
+        let from_binary = ArenaElement::read_all(&arena, binary)?;
+        let from_text = ArenaElement::read_all(&arena, DATA)?;
+        assert_eq!(from_binary, from_text);
+        Ok(())
+    }
+
+    #[test]
+    fn accessors() -> IonResult<()> {
+        let arena = Bump::new();
+        let elements = ArenaElement::read_all(&arena, DATA)?;
+        assert!(elements[0].is_null());
+        assert_eq!(elements[1].as_bool(), Some(true));
+        assert_eq!(elements[2].as_i64(), Some(-7));
+        assert_eq!(elements[3].as_float(), Some(2.5));
+        assert_eq!(elements[4].as_decimal(), Some(&Decimal::new(150, -2)));
+        assert_eq!(elements[5].as_timestamp().unwrap().year(), 2024);
+        assert_eq!(elements[6].as_text(), Some("baz"));
+        assert_eq!(elements[6].annotations()[1].text(), Some("bar"));
+        assert_eq!(elements[7].as_string(), Some("hello"));
+        assert_eq!(elements[8].as_lob(), Some(b"clob".as_slice()));
+        assert_eq!(elements[9].as_lob(), Some(b"hello".as_slice()));
+        assert_eq!(elements[10].as_sequence().unwrap().len(), 3);
+        assert_eq!(elements[11].ion_type(), IonType::SExp);
+        let strukt = &elements[12];
+        assert_eq!(strukt.as_struct().unwrap().len(), 4);
+        assert_eq!(strukt.get("name").unwrap().as_string(), Some("gadget"));
+        assert_eq!(strukt.as_struct().unwrap()[3].0.text(), None);
+        assert!(strukt.get("missing").is_none());
+        assert!(elements[1].get("name").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn read_one() -> IonResult<()> {
+        let arena = Bump::new();
+        assert_eq!(ArenaElement::read_one(&arena, "5")?.as_i64(), Some(5));
+        assert!(ArenaElement::read_one(&arena, "5 6").is_err());
+        assert!(ArenaElement::read_one(&arena, "").is_err());
+        Ok(())
+    }
+}
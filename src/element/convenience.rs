@@ -0,0 +1,162 @@
+//! Functions that read or write an Ion stream in a single call, for cases that don't need the
+//! flexibility of a [`WriteConfig`] or a streaming reader.
+
+use crate::lazy::encoding::Encoding;
+use crate::write_config::WriteConfig;
+use crate::{v1_0, Element, IonResult, Sequence, TextFormat};
+
+/// Values that can be written as the top-level values of an Ion stream by [`to_string`],
+/// [`to_pretty_string`] and [`to_binary`].
+///
+/// An [`Element`] is written as the only value in the stream; each element in a [`Sequence`],
+/// slice or `Vec` is written as a separate top-level value.
+pub trait ToIonStream {
+    /// Encodes these values as an Ion stream using the provided configuration.
+    fn encode_stream<E: Encoding>(&self, config: WriteConfig<E>) -> IonResult<E::Output>;
+}
+
+impl ToIonStream for Element {
+    fn encode_stream<E: Encoding>(&self, config: WriteConfig<E>) -> IonResult<E::Output> {
+        config.encode(self)
+    }
+}
+
+impl ToIonStream for Sequence {
+    fn encode_stream<E: Encoding>(&self, config: WriteConfig<E>) -> IonResult<E::Output> {
+        config.encode_all(self.elements())
+    }
+}
+
+impl ToIonStream for [Element] {
+    fn encode_stream<E: Encoding>(&self, config: WriteConfig<E>) -> IonResult<E::Output> {
+        config.encode_all(self.iter())
+    }
+}
+
+impl ToIonStream for Vec<Element> {
+    fn encode_stream<E: Encoding>(&self, config: WriteConfig<E>) -> IonResult<E::Output> {
+        self.as_slice().encode_stream(config)
+    }
+}
+
+impl<T: ToIonStream + ?Sized> ToIonStream for &T {
+    fn encode_stream<E: Encoding>(&self, config: WriteConfig<E>) -> IonResult<E::Output> {
+        (**self).encode_stream(config)
+    }
+}
+
+/// Values that can be read from an Ion stream by [`from_str`] and [`from_slice`].
+///
+/// An [`Element`] can only be read from a stream containing exactly one value; a [`Sequence`] or
+/// `Vec<Element>` holds all of the stream's top-level values.
+pub trait FromIonStream: Sized {
+    /// Reads these values from a text or binary Ion stream.
+    fn decode_stream(data: &[u8]) -> IonResult<Self>;
+}
+
+impl FromIonStream for Element {
+    fn decode_stream(data: &[u8]) -> IonResult<Self> {
+        Element::read_one(data)
+    }
+}
+
+impl FromIonStream for Sequence {
+    fn decode_stream(data: &[u8]) -> IonResult<Self> {
+        Element::read_all(data)
+    }
+}
+
+impl FromIonStream for Vec<Element> {
+    fn decode_stream(data: &[u8]) -> IonResult<Self> {
+        Ok(Element::read_all(data)?.into_iter().collect())
+    }
+}
+
+/// Encodes `values` as a compact text Ion stream.
+pub fn to_string<V: ToIonStream + ?Sized>(values: &V) -> IonResult<String> {
+    values.encode_stream(v1_0::Text.with_format(TextFormat::Compact))
+}
+
+/// Encodes `values` as a text Ion stream with each nested value on its own indented line.
+pub fn to_pretty_string<V: ToIonStream + ?Sized>(values: &V) -> IonResult<String> {
+    values.encode_stream(v1_0::Text.with_format(TextFormat::Pretty))
+}
+
+/// Encodes `values` as a binary Ion 1.0 stream.
+pub fn to_binary<V: ToIonStream + ?Sized>(values: &V) -> IonResult<Vec<u8>> {
+    values.encode_stream(v1_0::Binary.into())
+}
+
+/// Reads an [`Element`] or a [`Sequence`] of elements from a text Ion stream.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::{Element, Sequence};
+///
+/// let element: Element = ion_rs::from_str("{name: \"Alice\", tags: [a, b]}")?;
+/// let bytes = ion_rs::to_binary(&element)?;
+/// assert_eq!(ion_rs::from_slice::<Element>(&bytes)?, element);
+///
+/// let values: Sequence = ion_rs::from_str("1 2 3")?;
+/// assert_eq!(ion_rs::to_string(&values)?, "1 2 3 ");
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_str<T: FromIonStream>(text: &str) -> IonResult<T> {
+    T::decode_stream(text.as_bytes())
+}
+
+/// Reads an [`Element`] or a [`Sequence`] of elements from a text or binary Ion stream.
+pub fn from_slice<T: FromIonStream>(data: &[u8]) -> IonResult<T> {
+    T::decode_stream(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::IonType;
+
+    const ION: &str = "a::{x: [1, 2.5], y: (b c)} 3";
+
+    #[test]
+    fn write_element() -> IonResult<()> {
+        let element = Element::read_one("a::[1, {b: 2}]")?;
+        assert_eq!(to_string(&element)?, "a::[1, {b: 2, }, ] ");
+        assert_eq!(
+            to_pretty_string(&element)?,
+            "a::[\n  1,\n  {\n    b: 2,\n  },\n]\n"
+        );
+        assert_eq!(from_slice::<Element>(&to_binary(&element)?)?, element);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::text(to_string(&Element::read_all(ION)?)?.into_bytes())]
+    #[case::pretty(to_pretty_string(&Element::read_all(ION)?)?.into_bytes())]
+    #[case::binary(to_binary(&Element::read_all(ION)?)?)]
+    #[case::vec(to_binary(&Element::read_all(ION)?.into_iter().collect::<Vec<_>>())?)]
+    #[case::slice(to_binary(Element::read_all(ION)?.into_iter().collect::<Vec<_>>().as_slice())?)]
+    fn values_round_trip(#[case] encoded: Vec<u8>) -> IonResult<()> {
+        let expected = Element::read_all(ION)?;
+        assert_eq!(from_slice::<Sequence>(&encoded)?, expected);
+        let elements: Vec<Element> = from_slice(&encoded)?;
+        assert_eq!(Sequence::from(elements), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn read_from_str() -> IonResult<()> {
+        let element: Element = from_str("null.int")?;
+        assert_eq!(element, Element::null(IonType::Int));
+        let values: Sequence = from_str("1 2 3")?;
+        assert_eq!(values.len(), 3);
+        // Reading a single element requires exactly one value
+        assert!(from_str::<Element>("1 2").is_err());
+        assert!(from_str::<Element>("").is_err());
+        assert!(from_str::<Sequence>("{").is_err());
+        Ok(())
+    }
+}
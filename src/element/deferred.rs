@@ -0,0 +1,291 @@
+use std::ops::Range;
+
+use crate::element::materialize::ElementHooks;
+use crate::lazy::any_encoding::AnyEncoding;
+use crate::lazy::decoder::{HasRange, LazyRawValue};
+use crate::lazy::value::LazyValue;
+use crate::result::IonFailure;
+use crate::{Element, IonEncoding, IonError, IonResult, IonType, Value};
+
+/// The location of a string, clob, or blob whose payload was not read from the input.
+///
+/// When reading with a size threshold (see
+/// [`Reader::read_next_element_deferring`](crate::Reader::read_next_element_deferring)),
+/// values whose encoding is larger than the threshold are not materialized. The `Element` tree
+/// holds a null of the same type in their place, and a [`DeferredValues`] side table maps the
+/// position of each such null to a `DeferredValue`. The `DeferredValue` records the value's Ion
+/// type and where its encoding can be found in the source, allowing the payload to be loaded on
+/// demand by calling [`DeferredValue::load`].
+///
+/// Only strings, clobs, and blobs are deferred; their encodings do not depend on the symbol
+/// table that was active when they were read, so they can be decoded on their own later.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeferredValue {
+    ion_type: IonType,
+    encoding: IonEncoding,
+    range: Range<usize>,
+}
+
+impl DeferredValue {
+    pub(crate) fn new(ion_type: IonType, encoding: IonEncoding, range: Range<usize>) -> Self {
+        Self {
+            ion_type,
+            encoding,
+            range,
+        }
+    }
+
+    /// The Ion type of the value that was deferred.
+    pub fn ion_type(&self) -> IonType {
+        self.ion_type
+    }
+
+    /// The encoding of the source in which the value was found.
+    pub fn encoding(&self) -> IonEncoding {
+        self.encoding
+    }
+
+    /// The offset in the source at which the value's encoding (excluding annotations) begins.
+    pub fn offset(&self) -> usize {
+        self.range.start
+    }
+
+    /// The number of bytes used to encode the value (excluding annotations).
+    pub fn length(&self) -> usize {
+        self.range.len()
+    }
+
+    /// The range of source bytes that encode the value (excluding annotations).
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Reads the deferred value from `source`, which must be the same data that the placeholder
+    /// was read from. The returned `Element` does not have any annotations; those remain on the
+    /// `Element` that holds the placeholder.
+    pub fn load(&self, source: impl AsRef<[u8]>) -> IonResult<Element> {
+        let bytes = source.as_ref().get(self.range()).ok_or_else(|| {
+            IonError::illegal_operation(format!(
+                "source is {} bytes long; deferred value occupies bytes {:?}",
+                source.as_ref().len(),
+                self.range
+            ))
+        })?;
+        // Binary values cannot be read without an IVM announcing their encoding.
+        let ivm: &[u8] = match self.encoding {
+            IonEncoding::Binary_1_0 => &[0xE0, 0x01, 0x00, 0xEA],
+            IonEncoding::Binary_1_1 => &[0xE0, 0x01, 0x01, 0xEA],
+            IonEncoding::Text_1_0 | IonEncoding::Text_1_1 => &[],
+        };
+        let element = Element::read_one([ivm, bytes].concat())?;
+        if element.ion_type() != self.ion_type {
+            return IonResult::decoding_error(format!(
+                "expected deferred {} but found {}",
+                self.ion_type,
+                element.ion_type()
+            ));
+        }
+        Ok(element)
+    }
+}
+
+/// A side table recording which of the values in an [`Element`] tree were deferred.
+///
+/// Each deferred value is identified by its path: the sequence of child indexes that leads from
+/// the root to the placeholder null, as in [`ElementSpans::get`](crate::ElementSpans::get). The
+/// children of a struct are indexed in the order of [`Struct::fields`](crate::Struct::fields).
+/// Paths are recorded in the order in which the values appear in the source.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeferredValues {
+    values: Vec<(Vec<usize>, DeferredValue)>,
+}
+
+impl DeferredValues {
+    /// Returns the deferred value at `path`, if the value at that path was deferred.
+    pub fn get(&self, path: &[usize]) -> Option<&DeferredValue> {
+        // Paths are recorded in document order, which is also their lexicographic order.
+        self.values
+            .binary_search_by(|(other, _)| other.as_slice().cmp(path))
+            .ok()
+            .map(|index| &self.values[index].1)
+    }
+
+    /// Returns an iterator over the path and location of each deferred value.
+    pub fn iter(&self) -> impl Iterator<Item = (&[usize], &DeferredValue)> {
+        self.values
+            .iter()
+            .map(|(path, deferred)| (path.as_slice(), deferred))
+    }
+
+    /// The number of values that were deferred.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no values were deferred.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// Materializes `Element`s, replacing any non-null string, clob, or blob whose encoding is longer
+/// than `threshold` bytes with a null of the same type. The location of each replaced value is
+/// recorded in [`DeferredValues`].
+pub(crate) struct Deferral {
+    threshold: usize,
+    deferred_values: DeferredValues,
+}
+
+impl Deferral {
+    pub(crate) fn new(threshold: usize) -> Self {
+        Self {
+            threshold,
+            deferred_values: DeferredValues::default(),
+        }
+    }
+
+    /// Returns the values that have been deferred, leaving the list of deferred values empty.
+    pub(crate) fn take_deferred_values(&mut self) -> DeferredValues {
+        std::mem::take(&mut self.deferred_values)
+    }
+}
+
+impl<'top> ElementHooks<'top, AnyEncoding> for Deferral {
+    fn substitute(
+        &mut self,
+        value: LazyValue<'top, AnyEncoding>,
+        path: &[usize],
+    ) -> IonResult<Option<Value>> {
+        let ion_type = value.ion_type();
+        if value.is_null() || !matches!(ion_type, IonType::String | IonType::Clob | IonType::Blob) {
+            return Ok(None);
+        }
+        let Some(raw) = value.raw() else {
+            // Values produced by a macro expansion have no encoding of their own to refer to.
+            return Ok(None);
+        };
+        // The value span's offset is not reliable across encodings; use its length to find where
+        // the unannotated value begins within the value's overall range.
+        let end = raw.range().end;
+        let start = end - raw.value_span().bytes().len();
+        if end - start <= self.threshold {
+            return Ok(None);
+        }
+        let deferred = DeferredValue::new(ion_type, raw.encoding(), start..end);
+        self.deferred_values.values.push((path.to_vec(), deferred));
+        Ok(Some(Value::Null(ion_type)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ion_data::IonEq;
+    use crate::{v1_0, AnyEncoding, Reader};
+
+    const SOURCE: &str = r#"
+        {
+            id: 7,
+            summary: "short",
+            body: annotated::"a string that is comfortably longer than the threshold",
+            attachments: [{{ aGVsbG8sIHdvcmxkISBoZWxsbywgd29ybGQh }}, {{ aGk= }}],
+            raw: (clob {{"some clob data that is also quite long"}}),
+        }
+    "#;
+
+    fn read_deferring(
+        source: impl AsRef<[u8]>,
+        threshold: usize,
+    ) -> IonResult<Vec<(Element, DeferredValues)>> {
+        Reader::new(AnyEncoding, source.as_ref())?.read_all_elements_deferring(threshold)
+    }
+
+    #[rstest]
+    #[case::text(SOURCE.as_bytes().to_vec())]
+    #[case::binary(Element::read_all(SOURCE)?.encode_as(v1_0::Binary)?)]
+    fn large_values_are_deferred(#[case] source: Vec<u8>) -> IonResult<()> {
+        let elements = read_deferring(&source, 16)?;
+        let (element, deferred_values) = &elements[0];
+        let fields = element.expect_struct()?;
+        assert_eq!(fields.get("id").unwrap().expect_i64()?, 7);
+        assert_eq!(fields.get("summary").unwrap().expect_string()?, "short");
+        assert_eq!(deferred_values.len(), 3);
+        assert!(deferred_values.get(&[1]).is_none());
+
+        // The placeholder is a typed null that keeps the value's annotations.
+        let body = fields.get("body").unwrap();
+        assert!(body.annotations().contains("annotated"));
+        assert_eq!(body.value(), &Value::Null(IonType::String));
+        let deferred = deferred_values.get(&[2]).unwrap();
+        assert_eq!(deferred.ion_type(), IonType::String);
+        assert_eq!(
+            deferred.load(&source)?,
+            Element::string("a string that is comfortably longer than the threshold")
+        );
+
+        let attachments = fields.get("attachments").unwrap().expect_list()?;
+        assert!(attachments.get(0).unwrap().is_null());
+        let first = deferred_values.get(&[3, 0]).unwrap();
+        assert_eq!(
+            first.load(&source)?,
+            Element::blob(b"hello, world! hello, world!")
+        );
+        assert_eq!(attachments.get(1).unwrap().expect_blob()?, b"hi");
+        assert!(deferred_values.get(&[3, 1]).is_none());
+
+        let clob = fields.get("raw").unwrap().expect_sexp()?.get(1).unwrap();
+        assert_eq!(clob.value(), &Value::Null(IonType::Clob));
+        assert_eq!(
+            deferred_values.get(&[4, 1]).unwrap().ion_type(),
+            IonType::Clob
+        );
+
+        let paths: Vec<&[usize]> = deferred_values.iter().map(|(path, _)| path).collect();
+        assert_eq!(paths, [&[2][..], &[3, 0], &[4, 1]]);
+        Ok(())
+    }
+
+    #[test]
+    fn nothing_is_deferred_below_the_threshold() -> IonResult<()> {
+        let elements = read_deferring(SOURCE, usize::MAX)?;
+        assert!(elements.iter().all(|(_, deferred)| deferred.is_empty()));
+        let elements: Vec<Element> = elements.into_iter().map(|(element, _)| element).collect();
+        assert_eq!(
+            elements,
+            Element::read_all(SOURCE)?.into_iter().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn placeholders_are_ordinary_nulls() -> IonResult<()> {
+        let source = r#"1 "hello, world""#;
+        let elements = read_deferring(source, 4)?;
+        let (element, deferred_values) = &elements[1];
+        let deferred = deferred_values.get(&[]).unwrap();
+        assert_eq!(deferred.offset(), 2);
+        assert_eq!(deferred.length(), 14);
+        assert_eq!(&source[deferred.range()], r#""hello, world""#);
+        // Placeholders can be displayed, compared, and written like any other null.
+        assert_eq!(element.to_string(), "null.string");
+        assert!(element.ion_eq(&Element::null(IonType::String)));
+        assert_eq!(
+            Element::read_one(element.encode_as(v1_0::Text)?)?,
+            Element::null(IonType::String)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn loading_from_the_wrong_source_fails() -> IonResult<()> {
+        let elements = read_deferring(r#"1 "hello, world""#, 4)?;
+        let deferred = elements[1].1.get(&[]).unwrap();
+        // Too short
+        assert!(deferred.load("1").is_err());
+        // Contains a value of a different type at the same position
+        assert!(deferred.load("1 [1, 2, 3, 4, 5, 6]").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,278 @@
+//! Provides [`fold_stream`], a resumable way to aggregate the values in an Ion stream.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::element::reader::ElementReader;
+use crate::result::IonFailure;
+use crate::{ion_struct, Element, IntoAnnotatedElement, IonError, IonResult};
+
+/// The annotation on the struct stored in a checkpoint file.
+const CHECKPOINT_ANNOTATION: &str = "fold_checkpoint";
+
+/// Aggregate state that can be saved to (and restored from) a checkpoint file by
+/// [`fold_stream_with_checkpoints`].
+pub trait FoldState: Sized {
+    /// Converts the state to an `Element` that can be written to a checkpoint file.
+    fn to_element(&self) -> IonResult<Element>;
+
+    /// Restores the state from the `Element` read from a checkpoint file.
+    fn from_element(element: Element) -> IonResult<Self>;
+}
+
+impl FoldState for Element {
+    fn to_element(&self) -> IonResult<Element> {
+        Ok(self.clone())
+    }
+
+    fn from_element(element: Element) -> IonResult<Self> {
+        Ok(element)
+    }
+}
+
+/// Configures how often [`fold_stream_with_checkpoints`] saves its progress and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointConfig {
+    path: PathBuf,
+    interval: usize,
+}
+
+impl CheckpointConfig {
+    /// The number of values folded between checkpoints unless otherwise specified.
+    pub const DEFAULT_INTERVAL: usize = 10_000;
+
+    /// Constructs a configuration that saves checkpoints to the text Ion file at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            interval: Self::DEFAULT_INTERVAL,
+        }
+    }
+
+    /// Saves a checkpoint each time another `interval` values have been folded. An interval of
+    /// `0` only saves a checkpoint once the end of the stream is reached.
+    pub fn with_interval(mut self, interval: usize) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn interval(&self) -> usize {
+        self.interval
+    }
+}
+
+/// Reads every value in `reader`, combining each with the accumulated `state` by calling `f`.
+/// Returns the final state once the stream is exhausted.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # #[cfg(feature = "experimental-element-apis")]
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::{fold_stream, AnyEncoding, Reader};
+///
+/// let mut reader = Reader::new(AnyEncoding, "1 2 3 4")?;
+/// let sum = fold_stream(&mut reader, 0, |sum, element| Ok(sum + element.expect_i64()?))?;
+/// assert_eq!(sum, 10);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "experimental-element-apis"))]
+/// # fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub fn fold_stream<R, S, F>(reader: &mut R, mut state: S, mut f: F) -> IonResult<S>
+where
+    R: ElementReader + ?Sized,
+    F: FnMut(S, Element) -> IonResult<S>,
+{
+    while let Some(element) = reader.read_next_element()? {
+        state = f(state, element)?;
+    }
+    Ok(state)
+}
+
+/// Like [`fold_stream`], but periodically saves the accumulated state (along with the number of
+/// values folded so far) to the checkpoint file described by `config`.
+///
+/// If the checkpoint file already exists when this function is called, the saved state is
+/// restored (and `state` is ignored) and the values that had already been folded are skipped.
+/// This allows a job that was interrupted to be restarted with the same arguments and resume
+/// where its last checkpoint left off. A final checkpoint is saved when the end of the stream is
+/// reached, so restarting a job that has already completed returns its result without folding
+/// any values.
+pub fn fold_stream_with_checkpoints<R, S, F>(
+    reader: &mut R,
+    state: S,
+    config: &CheckpointConfig,
+    mut f: F,
+) -> IonResult<S>
+where
+    R: ElementReader + ?Sized,
+    S: FoldState,
+    F: FnMut(S, Element) -> IonResult<S>,
+{
+    let (mut position, mut state) = match read_checkpoint(config.path())? {
+        Some((position, saved_state)) => (position, S::from_element(saved_state)?),
+        None => (0, state),
+    };
+    for skipped in 0..position {
+        if !reader.skip_next_element()? {
+            return IonResult::illegal_operation(format!(
+                "checkpoint was saved after {position} values, but the stream only has {skipped}"
+            ));
+        }
+    }
+    while let Some(element) = reader.read_next_element()? {
+        state = f(state, element)?;
+        position += 1;
+        if config.interval > 0 && position % config.interval == 0 {
+            write_checkpoint(config.path(), position, &state)?;
+        }
+    }
+    write_checkpoint(config.path(), position, &state)?;
+    Ok(state)
+}
+
+/// Reads the `(position, state)` pair from the checkpoint file at `path`, if it exists.
+fn read_checkpoint(path: &Path) -> IonResult<Option<(usize, Element)>> {
+    let data = match fs::read(path) {
+        Ok(data) => data,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let checkpoint = Element::read_one(data)?;
+    let fields = checkpoint
+        .annotations()
+        .contains(CHECKPOINT_ANNOTATION)
+        .then(|| checkpoint.as_struct())
+        .flatten()
+        .ok_or_else(|| {
+            IonError::decoding_error(format!("{} is not a checkpoint file", path.display()))
+        })?;
+    let position = fields
+        .get("position")
+        .and_then(|position| position.as_i64())
+        .and_then(|position| usize::try_from(position).ok())
+        .ok_or_else(|| IonError::decoding_error("checkpoint has an invalid `position` field"))?;
+    let state = fields
+        .get("state")
+        .ok_or_else(|| IonError::decoding_error("checkpoint has no `state` field"))?;
+    Ok(Some((position, state.clone())))
+}
+
+/// Writes a checkpoint to a temporary file and then moves it to `path`, ensuring that an
+/// interruption never leaves a partially written checkpoint behind.
+fn write_checkpoint<S: FoldState>(path: &Path, position: usize, state: &S) -> IonResult<()> {
+    let checkpoint = ion_struct! {
+        "position": position as u64,
+        "state": state.to_element()?,
+    }
+    .with_annotations([CHECKPOINT_ANNOTATION]);
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    fs::write(&temp_path, format!("{checkpoint}\n"))?;
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnyEncoding, Reader};
+
+    #[derive(Debug, PartialEq)]
+    struct Sum(i64);
+
+    impl FoldState for Sum {
+        fn to_element(&self) -> IonResult<Element> {
+            Ok(self.0.into())
+        }
+
+        fn from_element(element: Element) -> IonResult<Self> {
+            Ok(Sum(element.expect_i64()?))
+        }
+    }
+
+    fn add(sum: Sum, element: Element) -> IonResult<Sum> {
+        Ok(Sum(sum.0 + element.expect_i64()?))
+    }
+
+    #[test]
+    fn fold_without_checkpoints() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "1 2 3 4 5")?;
+        assert_eq!(fold_stream(&mut reader, Sum(0), add)?, Sum(15));
+        Ok(())
+    }
+
+    #[test]
+    fn fold_errors_are_propagated() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "1 two 3")?;
+        assert!(fold_stream(&mut reader, Sum(0), add).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn resume_from_checkpoint() -> IonResult<()> {
+        let directory = tempfile::tempdir()?;
+        let config = CheckpointConfig::new(directory.path().join("sum.ion")).with_interval(2);
+        let data = "1 2 3 4 5";
+
+        // Fail partway through the stream; the checkpoint after the fourth value is kept.
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let result = fold_stream_with_checkpoints(&mut reader, Sum(0), &config, |sum, element| {
+            if element.expect_i64()? == 5 {
+                return IonResult::illegal_operation("simulated failure");
+            }
+            add(sum, element)
+        });
+        assert!(result.is_err());
+        let checkpoint = Element::read_one(fs::read(config.path())?)?;
+        assert_eq!(
+            checkpoint,
+            ion_struct! {"position": 4, "state": 10}.with_annotations([CHECKPOINT_ANNOTATION])
+        );
+
+        // Restarting only folds the remaining value.
+        let mut folded = Vec::new();
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let sum = fold_stream_with_checkpoints(&mut reader, Sum(0), &config, |sum, element| {
+            folded.push(element.expect_i64()?);
+            add(sum, element)
+        })?;
+        assert_eq!(sum, Sum(15));
+        assert_eq!(folded, vec![5]);
+
+        // Once the stream has been completely folded, restarting returns the final state.
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let sum = fold_stream_with_checkpoints(&mut reader, Sum(0), &config, |_, _| {
+            panic!("no values should be folded")
+        })?;
+        assert_eq!(sum, Sum(15));
+        Ok(())
+    }
+
+    #[test]
+    fn checkpoint_past_end_of_stream_is_an_error() -> IonResult<()> {
+        let directory = tempfile::tempdir()?;
+        let config = CheckpointConfig::new(directory.path().join("sum.ion"));
+        let mut reader = Reader::new(AnyEncoding, "1 2 3")?;
+        fold_stream_with_checkpoints(&mut reader, Sum(0), &config, add)?;
+
+        let mut reader = Reader::new(AnyEncoding, "1 2")?;
+        assert!(fold_stream_with_checkpoints(&mut reader, Sum(0), &config, add).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_checkpoint_file_is_an_error() -> IonResult<()> {
+        let directory = tempfile::tempdir()?;
+        let config = CheckpointConfig::new(directory.path().join("sum.ion"));
+        fs::write(config.path(), "{position: 1, state: 1}")?;
+        let mut reader = Reader::new(AnyEncoding, "1 2 3")?;
+        assert!(fold_stream_with_checkpoints(&mut reader, Sum(0), &config, add).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+//! Provides [`TextInterner`], which allows the `Element`s in a document to share a single copy of
+//! each distinct piece of text.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::element::materialize::ElementHooks;
+use crate::lazy::decoder::Decoder;
+use crate::{Annotations, Element, Sequence, Str, Symbol, SymbolId, SymbolToken, Value};
+
+/// A pool of text that can be shared by the field names, annotations, symbols, and strings of many
+/// `Element`s.
+///
+/// Large documents often repeat the same field names and symbol values thousands of times. When
+/// they are materialized as usual, each occurrence is stored as a separate allocation. Reading
+/// with an interner (see
+/// [`ElementReader::read_next_element_interned`](crate::ElementReader::read_next_element_interned))
+/// stores each distinct piece of text once and has every occurrence refer to it.
+///
+/// The pool keeps each piece of text that it has seen alive until the interner is dropped or
+/// [cleared](Self::clear). An interner can be reused across several readers so that the documents
+/// they produce also share their text.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # #[cfg(feature = "experimental-reader-writer")]
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::{AnyEncoding, ElementReader, Reader, TextInterner};
+///
+/// let mut interner = TextInterner::new();
+/// let mut reader = Reader::new(AnyEncoding, r#"{color: red} {color: red} {color: "red"}"#)?;
+/// let elements = reader.read_all_elements_interned(&mut interner)?;
+/// assert_eq!(elements.len(), 3);
+///
+/// // `color` and `red` are each stored once.
+/// assert_eq!(interner.len(), 2);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "experimental-reader-writer"))]
+/// # fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TextInterner {
+    pool: HashSet<Arc<str>>,
+}
+
+impl TextInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the pooled copy of `text`, adding it to the pool if necessary.
+    pub fn intern(&mut self, text: &str) -> Arc<str> {
+        if let Some(pooled) = self.pool.get(text) {
+            return Arc::clone(pooled);
+        }
+        let pooled: Arc<str> = text.into();
+        self.pool.insert(Arc::clone(&pooled));
+        pooled
+    }
+
+    /// Returns the number of distinct pieces of text in the pool.
+    pub fn len(&self) -> usize {
+        self.pool.len()
+    }
+
+    /// Returns `true` if the pool is empty.
+    pub fn is_empty(&self) -> bool {
+        self.pool.is_empty()
+    }
+
+    /// Removes all of the text from the pool. Elements that refer to pooled text are unaffected.
+    pub fn clear(&mut self) {
+        self.pool.clear()
+    }
+
+    /// Returns a copy of `element` whose text refers to the pool.
+    pub fn intern_element(&mut self, element: &Element) -> Element {
+        let value = match element.value() {
+            Value::Symbol(symbol) => {
+                Value::Symbol(self.intern_symbol(symbol.text(), symbol.symbol_id()))
+            }
+            Value::String(text) => Value::String(Str::shared(self.intern(text.text()))),
+            Value::List(sequence) => Value::List(self.intern_sequence(sequence)),
+            Value::SExp(sequence) => Value::SExp(self.intern_sequence(sequence)),
+            Value::Struct(strukt) => Value::Struct(
+                strukt
+                    .fields()
+                    .map(|(name, value)| {
+                        let name = self.intern_symbol(name.text(), name.symbol_id());
+                        (name, self.intern_element(value))
+                    })
+                    .collect(),
+            ),
+            value => value.clone(),
+        };
+        let annotations: Annotations = element
+            .annotations()
+            .iter()
+            .map(|annotation| self.intern_symbol(annotation.text(), annotation.symbol_id()))
+            .collect();
+        Element::new(annotations, value)
+    }
+
+    fn intern_sequence(&mut self, sequence: &Sequence) -> Sequence {
+        sequence
+            .elements()
+            .map(|element| self.intern_element(element))
+            .collect()
+    }
+
+    fn intern_symbol(&mut self, text: Option<&str>, symbol_id: Option<SymbolId>) -> Symbol {
+        let symbol = match text {
+            Some(text) => Symbol::shared(self.intern(text)),
+            None => Symbol::unknown_text(),
+        };
+        symbol.with_encoding(symbol_id)
+    }
+}
+
+// Materializing with an interner stores the text of symbols and strings in the pool.
+impl<'top, D: Decoder> ElementHooks<'top, D> for TextInterner {
+    fn symbol(&mut self, symbol: SymbolToken<'top>) -> Symbol {
+        self.intern_symbol(symbol.text(), symbol.symbol_id())
+    }
+
+    fn string(&mut self, text: &str) -> Str {
+        Str::shared(self.intern(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, AnyEncoding, Element, ElementReader, IonResult, Reader};
+
+    const DATA: &str = r#"
+        {name: "widget", tags: [red, blue], owner: team::"red"}
+        {name: "gadget", tags: [red], owner: team::"blue"}
+    "#;
+
+    // Returns the address of a symbol's text; symbols that share their text have the same address.
+    fn text_address(element: &Element) -> *const u8 {
+        element.as_symbol().unwrap().text().unwrap().as_ptr()
+    }
+
+    #[test]
+    fn repeated_text_is_shared() -> IonResult<()> {
+        let mut interner = TextInterner::new();
+        let mut reader = Reader::new(AnyEncoding, DATA)?;
+        let elements = reader.read_all_elements_interned(&mut interner)?;
+        assert_eq!(elements, Element::read_all(DATA)?);
+        // name, widget, tags, red, blue, owner, team, gadget
+        assert_eq!(interner.len(), 8);
+
+        let first = elements
+            .get(0)
+            .unwrap()
+            .as_struct()
+            .unwrap()
+            .get("tags")
+            .unwrap();
+        let second = elements
+            .get(1)
+            .unwrap()
+            .as_struct()
+            .unwrap()
+            .get("tags")
+            .unwrap();
+        let first_red = text_address(first.as_sequence().unwrap().get(0).unwrap());
+        let second_red = text_address(second.as_sequence().unwrap().get(0).unwrap());
+        assert_eq!(first_red, second_red);
+        assert_eq!(first_red, interner.intern("red").as_ptr());
+        Ok(())
+    }
+
+    #[test]
+    fn interner_can_be_shared_by_readers() -> IonResult<()> {
+        let mut interner = TextInterner::new();
+        let binary = Element::read_all(DATA)?.encode_as(v1_0::Binary)?;
+        let mut text_reader = Reader::new(AnyEncoding, DATA)?;
+        let mut binary_reader = Reader::new(AnyEncoding, binary)?;
+        let from_text = text_reader.read_all_elements_interned(&mut interner)?;
+        let from_binary = binary_reader.read_all_elements_interned(&mut interner)?;
+        assert_eq!(from_text, from_binary);
+        assert_eq!(interner.len(), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn intern_element() -> IonResult<()> {
+        let element = Element::read_one("foo::[bar, {bar: \"foo\"}, $0]")?;
+        let mut interner = TextInterner::new();
+        assert_eq!(interner.intern_element(&element), element);
+        assert_eq!(interner.len(), 2);
+        interner.clear();
+        assert!(interner.is_empty());
+        Ok(())
+    }
+}
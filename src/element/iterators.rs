@@ -47,11 +47,11 @@ create_new_slice_iterator_type!(
 /// Consuming iterator for [`Annotations`](crate::element::Annotations).
 #[derive(Debug, Clone)]
 pub struct AnnotationsIntoIter {
-    into_iter: std::vec::IntoIter<Symbol>,
+    into_iter: smallvec::IntoIter<[Symbol; 1]>,
 }
 
 impl AnnotationsIntoIter {
-    pub(crate) fn new(into_iter: std::vec::IntoIter<Symbol>) -> Self {
+    pub(crate) fn new(into_iter: smallvec::IntoIter<[Symbol; 1]>) -> Self {
         Self { into_iter }
     }
 }
@@ -0,0 +1,360 @@
+//! Builds trees of values from [`LazyValue`]s.
+//!
+//! Each of the ways in which a reader can materialize a value (as an ordinary [`Element`], with
+//! interned text, with shared lob payloads, with its spans, with large values deferred, into
+//! recycled storage, or in an arena) walks the value in the same way. [`materialize`] performs
+//! that walk, and an [`ElementBuilder`] decides how each node of the resulting tree is made.
+
+use std::vec::Drain;
+
+use crate::lazy::decoder::Decoder;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::{
+    Annotations, Bytes, Element, IonResult, IonType, Sequence, Str, Struct, Symbol, SymbolToken,
+    Value,
+};
+
+/// Makes the nodes of the tree that [`materialize`] builds.
+///
+/// The tree is built from the bottom up: the children of a container are built (and held by
+/// [`BuildStacks`]) before the container itself, which receives them all at once.
+pub(crate) trait ElementBuilder<'top, D: Decoder> {
+    /// An annotated node of the tree.
+    type Element;
+    /// An annotation or field name.
+    type Symbol;
+    /// A node's value, to which annotations have not yet been added.
+    type Value;
+
+    /// Called before `value` is read. `path` holds the child indexes that lead from the top-level
+    /// value to `value`; the children of a struct are indexed in the order they were read. If
+    /// this returns `Some`, the result is used as the node's value and `value` is not read. (Its
+    /// annotations are still added to the node.)
+    fn substitute(
+        &mut self,
+        value: LazyValue<'top, D>,
+        path: &[usize],
+    ) -> IonResult<Option<Self::Value>>;
+
+    /// Makes an annotation, a field name, or the symbol held by a symbol value.
+    fn symbol(&mut self, symbol: SymbolToken<'top>) -> Self::Symbol;
+
+    /// Makes the value of a non-null symbol from the symbol that [`symbol`](Self::symbol) made.
+    fn symbol_value(&mut self, symbol: Self::Symbol) -> Self::Value;
+
+    /// Makes the value of a scalar other than a symbol, or of a null. Non-null containers are made
+    /// by [`sequence`](Self::sequence) and [`structure`](Self::structure) instead.
+    fn scalar(&mut self, value: ValueRef<'top, D>) -> IonResult<Self::Value>;
+
+    /// Makes a list or s-expression (as indicated by `ion_type`) from its children.
+    fn sequence(&mut self, ion_type: IonType, children: Drain<'_, Self::Element>) -> Self::Value;
+
+    fn structure(&mut self, fields: Drain<'_, (Self::Symbol, Self::Element)>) -> Self::Value;
+
+    fn element(
+        &mut self,
+        annotations: Drain<'_, Self::Symbol>,
+        value: Self::Value,
+    ) -> Self::Element;
+
+    /// Called once the node for `value`, which has `num_children` children, has been made.
+    fn finish(&mut self, value: LazyValue<'top, D>, num_children: usize);
+}
+
+/// An [`ElementBuilder`] that makes [`Element`]s. By default, each method makes the same
+/// `Element` that converting the `LazyValue` with `Element::try_from` would; implementations
+/// override the steps they customize.
+pub(crate) trait ElementHooks<'top, D: Decoder> {
+    /// See [`ElementBuilder::substitute`].
+    fn substitute(
+        &mut self,
+        _value: LazyValue<'top, D>,
+        _path: &[usize],
+    ) -> IonResult<Option<Value>> {
+        Ok(None)
+    }
+
+    /// Makes an annotation, field name, or symbol value. The resulting `Symbol` records the
+    /// symbol ID (if any) with which it was encoded.
+    fn symbol(&mut self, symbol: SymbolToken<'top>) -> Symbol {
+        symbol.into()
+    }
+
+    fn string(&mut self, text: &str) -> Str {
+        text.into()
+    }
+
+    /// Makes the payload of a blob or clob.
+    fn lob(&mut self, data: &[u8]) -> Bytes {
+        data.into()
+    }
+
+    fn sequence(&mut self, elements: Drain<'_, Element>) -> Sequence {
+        Sequence::new(elements)
+    }
+
+    fn structure(&mut self, fields: Drain<'_, (Symbol, Element)>) -> Struct {
+        Struct::from_iter(fields)
+    }
+
+    /// See [`ElementBuilder::finish`].
+    fn finish(&mut self, _value: LazyValue<'top, D>, _num_children: usize) {}
+}
+
+impl<'top, D: Decoder, H: ElementHooks<'top, D>> ElementBuilder<'top, D> for H {
+    type Element = Element;
+    type Symbol = Symbol;
+    type Value = Value;
+
+    fn substitute(
+        &mut self,
+        value: LazyValue<'top, D>,
+        path: &[usize],
+    ) -> IonResult<Option<Value>> {
+        ElementHooks::substitute(self, value, path)
+    }
+
+    fn symbol(&mut self, symbol: SymbolToken<'top>) -> Symbol {
+        ElementHooks::symbol(self, symbol)
+    }
+
+    fn symbol_value(&mut self, symbol: Symbol) -> Value {
+        Value::Symbol(symbol)
+    }
+
+    fn scalar(&mut self, value: ValueRef<'top, D>) -> IonResult<Value> {
+        let value = match value {
+            ValueRef::String(text) => Value::String(self.string(text.text())),
+            ValueRef::Blob(bytes) => Value::Blob(self.lob(bytes.data())),
+            ValueRef::Clob(bytes) => Value::Clob(self.lob(bytes.data())),
+            value_ref => Value::try_from(value_ref)?,
+        };
+        Ok(value)
+    }
+
+    fn sequence(&mut self, ion_type: IonType, children: Drain<'_, Element>) -> Value {
+        let sequence = ElementHooks::sequence(self, children);
+        match ion_type {
+            IonType::SExp => Value::SExp(sequence),
+            _ => Value::List(sequence),
+        }
+    }
+
+    fn structure(&mut self, fields: Drain<'_, (Symbol, Element)>) -> Value {
+        Value::Struct(ElementHooks::structure(self, fields))
+    }
+
+    fn element(&mut self, annotations: Drain<'_, Symbol>, value: Value) -> Element {
+        let element = Element::from(value);
+        if annotations.len() == 0 {
+            return element;
+        }
+        element.with_annotations(annotations.collect::<Annotations>())
+    }
+
+    fn finish(&mut self, value: LazyValue<'top, D>, num_children: usize) {
+        ElementHooks::finish(self, value, num_children)
+    }
+}
+
+/// Makes ordinary `Element`s.
+pub(crate) struct PlainElements;
+
+impl<'top, D: Decoder> ElementHooks<'top, D> for PlainElements {}
+
+/// Counts the nested values (those below the top level) of the most recent tree built by
+/// [`materialize`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct NestedValues {
+    // Indexed by `IonType as usize`
+    by_type: [usize; 13],
+    max_depth: usize,
+}
+
+impl NestedValues {
+    /// The number of nested values of the given type.
+    pub(crate) fn count(&self, ion_type: IonType) -> usize {
+        self.by_type[ion_type as usize]
+    }
+
+    /// The depth of the most deeply nested value, or zero if there were no nested values.
+    pub(crate) fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    fn record(&mut self, ion_type: IonType, depth: usize) {
+        self.by_type[ion_type as usize] += 1;
+        self.max_depth = self.max_depth.max(depth);
+    }
+}
+
+/// Holds the pieces of the nodes that are being built. The stacks are empty between calls to
+/// [`materialize`], but they keep their capacity, so a reader can reuse one set of stacks for each
+/// value it materializes instead of allocating new ones.
+pub(crate) struct BuildStacks<E, S> {
+    elements: Vec<E>,
+    fields: Vec<(S, E)>,
+    annotations: Vec<S>,
+    path: Vec<usize>,
+    nested_values: NestedValues,
+}
+
+impl<E, S> Default for BuildStacks<E, S> {
+    fn default() -> Self {
+        Self {
+            elements: Vec::new(),
+            fields: Vec::new(),
+            annotations: Vec::new(),
+            path: Vec::new(),
+            nested_values: NestedValues::default(),
+        }
+    }
+}
+
+/// Builds a tree from `value` and all of its nested values using `builder`.
+pub(crate) fn materialize<'top, D: Decoder, B: ElementBuilder<'top, D>>(
+    builder: &mut B,
+    stacks: &mut BuildStacks<B::Element, B::Symbol>,
+    value: LazyValue<'top, D>,
+) -> IonResult<B::Element> {
+    // If an earlier call failed, it may have left partially built nodes behind.
+    stacks.elements.clear();
+    stacks.fields.clear();
+    stacks.annotations.clear();
+    stacks.path.clear();
+    stacks.nested_values = NestedValues::default();
+    stacks.build(builder, value)
+}
+
+impl<E, S> BuildStacks<E, S> {
+    /// Counts the nested values of the tree built by the most recent call to [`materialize`].
+    pub(crate) fn nested_values(&self) -> &NestedValues {
+        &self.nested_values
+    }
+
+    fn build<'top, D, B>(&mut self, builder: &mut B, value: LazyValue<'top, D>) -> IonResult<E>
+    where
+        D: Decoder,
+        B: ElementBuilder<'top, D, Element = E, Symbol = S>,
+    {
+        if !self.path.is_empty() {
+            self.nested_values.record(value.ion_type(), self.path.len());
+        }
+        let annotations_start = self.annotations.len();
+        for annotation in value.annotations().tokens() {
+            let annotation = builder.symbol(annotation?);
+            self.annotations.push(annotation);
+        }
+        let mut num_children = 0;
+        let node_value = match builder.substitute(value, &self.path)? {
+            Some(node_value) => node_value,
+            None => match value.read()? {
+                ValueRef::List(list) => {
+                    let start = self.build_children(builder, list.iter())?;
+                    num_children = self.elements.len() - start;
+                    builder.sequence(IonType::List, self.elements.drain(start..))
+                }
+                ValueRef::SExp(sexp) => {
+                    let start = self.build_children(builder, sexp.iter())?;
+                    num_children = self.elements.len() - start;
+                    builder.sequence(IonType::SExp, self.elements.drain(start..))
+                }
+                ValueRef::Struct(strukt) => {
+                    let start = self.fields.len();
+                    for (index, field) in strukt.iter().enumerate() {
+                        let field = field?;
+                        let name = builder.symbol(field.name_token()?);
+                        self.path.push(index);
+                        let element = self.build(builder, field.value());
+                        self.path.pop();
+                        self.fields.push((name, element?));
+                    }
+                    num_children = self.fields.len() - start;
+                    builder.structure(self.fields.drain(start..))
+                }
+                ValueRef::Symbol(_) => {
+                    // Unlike the `SymbolRef` that `read` produces, the token retains the symbol ID.
+                    let token = value
+                        .symbol_token()?
+                        .expect("a non-null symbol has a token");
+                    let symbol = builder.symbol(token);
+                    builder.symbol_value(symbol)
+                }
+                value_ref => builder.scalar(value_ref)?,
+            },
+        };
+        let element = builder.element(self.annotations.drain(annotations_start..), node_value);
+        builder.finish(value, num_children);
+        Ok(element)
+    }
+
+    /// Builds each of `children`, leaving them on the element stack. Returns the position of the
+    /// first child in the stack.
+    fn build_children<'top, D, B>(
+        &mut self,
+        builder: &mut B,
+        children: impl Iterator<Item = IonResult<LazyValue<'top, D>>>,
+    ) -> IonResult<usize>
+    where
+        D: Decoder,
+        B: ElementBuilder<'top, D, Element = E, Symbol = S>,
+    {
+        let start = self.elements.len();
+        for (index, child) in children.enumerate() {
+            let child = child?;
+            self.path.push(index);
+            let element = self.build(builder, child);
+            self.path.pop();
+            self.elements.push(element?);
+        }
+        Ok(start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, AnyEncoding, Reader};
+
+    #[test]
+    fn plain_elements_match_converted_elements() -> IonResult<()> {
+        let source = r#"
+            a::b::{x: [1, (2 c::3)], y: {z: null.list}, "": $0}
+            [] () {} "text" {{ aGk= }} {{ "clob" }} null.struct
+        "#;
+        let expected = Element::read_all(source)?;
+        for data in [
+            source.as_bytes().to_vec(),
+            expected.encode_as(v1_0::Binary)?,
+        ] {
+            let mut reader = Reader::new(AnyEncoding, data)?;
+            let mut stacks = BuildStacks::default();
+            let mut elements = Vec::new();
+            while let Some(value) = reader.next()? {
+                elements.push(materialize(&mut PlainElements, &mut stacks, value)?);
+            }
+            assert_eq!(elements, expected.elements().cloned().collect::<Vec<_>>());
+            // The stacks are left empty, ready for the next value.
+            assert!(stacks.elements.is_empty() && stacks.fields.is_empty());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn counts_nested_values() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "a::{x: [1, (2 c::3)], y: null.list} 4")?;
+        let mut stacks = BuildStacks::default();
+        materialize(&mut PlainElements, &mut stacks, reader.next()?.unwrap())?;
+        let nested = stacks.nested_values();
+        assert_eq!(nested.count(IonType::Int), 3);
+        assert_eq!(nested.count(IonType::List), 2);
+        assert_eq!(nested.count(IonType::SExp), 1);
+        assert_eq!(nested.count(IonType::Struct), 0);
+        assert_eq!(nested.max_depth(), 3);
+        // Each call counts only the nested values of the tree it builds.
+        materialize(&mut PlainElements, &mut stacks, reader.next()?.unwrap())?;
+        assert_eq!(stacks.nested_values(), &NestedValues::default());
+        Ok(())
+    }
+}
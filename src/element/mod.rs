@@ -15,26 +15,53 @@
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::io;
+use std::ops::Index;
 
 pub use annotations::{Annotations, IntoAnnotations};
+pub use deferred::{DeferredValue, DeferredValues};
+pub use interner::TextInterner;
 pub use sequence::Sequence;
 
 use crate::{ion_data, Decimal, Int, IonError, IonResult, IonType, Str, Symbol, Timestamp};
+use crate::{v1_0, TextFormat};
 use crate::{Blob, Bytes, Clob, List, SExp, Struct};
 // Re-export the Value variant types and traits so they can be accessed directly from this module.
 use crate::element::builders::{SequenceBuilder, StructBuilder};
+use crate::element::materialize::{materialize, BuildStacks};
 use crate::element::reader::ElementReader;
 use crate::ion_data::{IonEq, IonOrd};
 use crate::lazy::any_encoding::AnyEncoding;
+use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoding::Encoding;
 use crate::lazy::reader::Reader;
 use crate::lazy::streaming_raw_reader::{IonInput, IonSlice};
 use crate::result::IonFailure;
+use crate::symbol_ref::AsSymbolRef;
 use crate::text::text_formatter::FmtValueFormatter;
 use crate::write_config::WriteConfig;
 
 mod annotations;
+#[cfg(feature = "arbitrary")]
+pub(crate) mod arbitrary_element;
+pub(crate) mod arena;
+pub(crate) mod convenience;
+pub(crate) mod deferred;
+pub(crate) mod fold;
+pub(crate) mod interner;
 pub(crate) mod iterators;
+pub(crate) mod materialize;
+#[cfg(feature = "rayon")]
+pub(crate) mod parallel;
+pub(crate) mod pointer;
+pub(crate) mod query;
+pub(crate) mod retention;
+pub(crate) mod reuse;
+pub(crate) mod shared;
+pub(crate) mod size;
+pub(crate) mod spans;
+pub(crate) mod stats;
+#[cfg(feature = "bytes")]
+pub(crate) mod zero_copy;
 
 pub mod builders;
 pub mod element_writer;
@@ -145,10 +172,246 @@ impl Value {
             Struct(_) => IonType::Struct,
         }
     }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null(_))
+    }
+
+    pub fn as_null(&self) -> Option<IonType> {
+        match self {
+            Value::Null(ion_type) => Some(*ion_type),
+            _ => None,
+        }
+    }
+
+    pub fn expect_null(&self) -> IonResult<IonType> {
+        self.as_null().ok_or_else(|| self.expected(IonType::Null))
+    }
+
+    pub fn as_int(&self) -> Option<&Int> {
+        match self {
+            Value::Int(i) => Some(i),
+            _ => None,
+        }
+    }
+
+    pub fn expect_int(&self) -> IonResult<&Int> {
+        self.as_int().ok_or_else(|| self.expected(IonType::Int))
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Int(i) => i.as_i64(),
+            _ => None,
+        }
+    }
+
+    pub fn expect_i64(&self) -> IonResult<i64> {
+        match self {
+            Value::Int(i) => i.expect_i64(),
+            _ => Err(self.expected(IonType::Int)),
+        }
+    }
+
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Value::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    pub fn expect_float(&self) -> IonResult<f64> {
+        self.as_float().ok_or_else(|| self.expected(IonType::Float))
+    }
+
+    pub fn as_decimal(&self) -> Option<Decimal> {
+        match self {
+            Value::Decimal(d) => Some(*d),
+            _ => None,
+        }
+    }
+
+    pub fn expect_decimal(&self) -> IonResult<Decimal> {
+        self.as_decimal()
+            .ok_or_else(|| self.expected(IonType::Decimal))
+    }
+
+    pub fn as_timestamp(&self) -> Option<Timestamp> {
+        match self {
+            Value::Timestamp(t) => Some(*t),
+            _ => None,
+        }
+    }
+
+    pub fn expect_timestamp(&self) -> IonResult<Timestamp> {
+        self.as_timestamp()
+            .ok_or_else(|| self.expected(IonType::Timestamp))
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Value::String(text) => Some(text.as_ref()),
+            Value::Symbol(sym) => sym.text(),
+            _ => None,
+        }
+    }
+
+    pub fn expect_text(&self) -> IonResult<&str> {
+        self.as_text()
+            .ok_or_else(|| self.expected_kind("a text value"))
+    }
+
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            Value::String(text) => Some(text.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn expect_string(&self) -> IonResult<&str> {
+        self.as_string()
+            .ok_or_else(|| self.expected(IonType::String))
+    }
+
+    pub fn as_symbol(&self) -> Option<&Symbol> {
+        match self {
+            Value::Symbol(sym) => Some(sym),
+            _ => None,
+        }
+    }
+
+    pub fn expect_symbol(&self) -> IonResult<&Symbol> {
+        self.as_symbol()
+            .ok_or_else(|| self.expected(IonType::Symbol))
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn expect_bool(&self) -> IonResult<bool> {
+        self.as_bool().ok_or_else(|| self.expected(IonType::Bool))
+    }
+
+    pub fn as_lob(&self) -> Option<&[u8]> {
+        match self {
+            Value::Blob(bytes) | Value::Clob(bytes) => Some(bytes.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn expect_lob(&self) -> IonResult<&[u8]> {
+        self.as_lob()
+            .ok_or_else(|| self.expected_kind("a lob value"))
+    }
+
+    pub fn as_blob(&self) -> Option<&[u8]> {
+        match self {
+            Value::Blob(bytes) => Some(bytes.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn expect_blob(&self) -> IonResult<&[u8]> {
+        self.as_blob().ok_or_else(|| self.expected(IonType::Blob))
+    }
+
+    pub fn as_clob(&self) -> Option<&[u8]> {
+        match self {
+            Value::Clob(bytes) => Some(bytes.as_ref()),
+            _ => None,
+        }
+    }
+
+    pub fn expect_clob(&self) -> IonResult<&[u8]> {
+        self.as_clob().ok_or_else(|| self.expected(IonType::Clob))
+    }
+
+    pub fn as_sequence(&self) -> Option<&Sequence> {
+        match self {
+            Value::SExp(s) | Value::List(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn expect_sequence(&self) -> IonResult<&Sequence> {
+        self.as_sequence()
+            .ok_or_else(|| self.expected_kind("a sequence value"))
+    }
+
+    pub fn as_list(&self) -> Option<&Sequence> {
+        match self {
+            Value::List(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn expect_list(&self) -> IonResult<&Sequence> {
+        self.as_list().ok_or_else(|| self.expected(IonType::List))
+    }
+
+    pub fn as_sexp(&self) -> Option<&Sequence> {
+        match self {
+            Value::SExp(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn expect_sexp(&self) -> IonResult<&Sequence> {
+        self.as_sexp().ok_or_else(|| self.expected(IonType::SExp))
+    }
+
+    pub fn as_struct(&self) -> Option<&Struct> {
+        match self {
+            Value::Struct(structure) => Some(structure),
+            _ => None,
+        }
+    }
+
+    pub fn expect_struct(&self) -> IonResult<&Struct> {
+        self.as_struct()
+            .ok_or_else(|| self.expected(IonType::Struct))
+    }
+
+    fn expected(&self, expected: IonType) -> IonError {
+        self.expected_kind(format_args!("a(n) {expected}"))
+    }
+
+    /// Constructs the error returned by the `expect_*` accessors.
+    fn expected_kind(&self, expected: impl Display) -> IonError {
+        IonError::decoding_error(format!(
+            "expected {expected}, found a(n) {}",
+            self.ion_type()
+        ))
+    }
+}
+
+/// Writes `value` to `f` as indented, multi-line text Ion. This is the alternate form (`{:#}`) of
+/// the `Display` implementations for `Element`, `Value` and the container types.
+///
+/// Returns `None` if the value cannot be encoded, in which case the caller should fall back to
+/// compact text.
+pub(crate) fn fmt_pretty<V: WriteAsIon>(
+    value: V,
+    f: &mut Formatter<'_>,
+) -> Option<std::fmt::Result> {
+    let text = v1_0::Text
+        .with_format(TextFormat::Pretty)
+        .encode(value)
+        .ok()?;
+    Some(f.write_str(text.trim_end()))
 }
 
 impl Display for Value {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            if let Some(result) = fmt_pretty(self, f) {
+                return result;
+            }
+        }
         let mut ivf = FmtValueFormatter { output: f };
         match &self {
             Value::Null(ion_type) => ivf.format_null(*ion_type),
@@ -357,9 +620,19 @@ impl Element {
     }
 
     fn expected(&self, expected: IonType) -> IonError {
+        self.expected_kind(format_args!("a(n) {expected}"))
+    }
+
+    /// Constructs the error returned by the `expect_*` accessors. The message includes this
+    /// element's annotations (if any) to make it easier to locate the offending value.
+    fn expected_kind(&self, expected: impl Display) -> IonError {
+        let annotations: String = self
+            .annotations
+            .iter()
+            .map(|annotation| format!("{annotation}::"))
+            .collect();
         IonError::decoding_error(format!(
-            "expected a(n) {}, found a(n) {}",
-            expected,
+            "expected {expected}, found a(n) {annotations}{}",
             self.ion_type()
         ))
     }
@@ -444,15 +717,57 @@ impl Element {
         Element::new(annotations.into_annotations(), self.value)
     }
 
+    /// Returns a copy of this [`Element`] with the provided annotations appended to its existing
+    /// annotations sequence.
+    ///
+    /// ```
+    /// use ion_rs::{Element, IntoAnnotations};
+    /// let element = Element::read_one("foo::5").unwrap();
+    /// let element = element.with_added_annotations(["redacted"]);
+    /// assert_eq!(element.annotations(), &["foo", "redacted"].into_annotations());
+    /// ```
+    pub fn with_added_annotations<I: IntoAnnotations>(mut self, annotations: I) -> Self {
+        self.annotations.extend(annotations.into_annotations());
+        self
+    }
+
+    /// Appends the provided annotation to this [`Element`]'s annotations sequence, preserving any
+    /// annotations that were already present.
+    ///
+    /// ```
+    /// use ion_rs::{Element, IntoAnnotations};
+    /// let mut element = Element::read_one("foo::5").unwrap();
+    /// element.add_annotation("redacted");
+    /// assert_eq!(element.annotations(), &["foo", "redacted"].into_annotations());
+    /// ```
+    pub fn add_annotation<S: Into<Symbol>>(&mut self, annotation: S) {
+        self.annotations.extend([annotation]);
+    }
+
     pub fn is_null(&self) -> bool {
-        matches!(&self.value, Value::Null(_))
+        self.value.is_null()
+    }
+
+    /// If this [`Element`] is a null, returns the [`IonType`] of that null. Otherwise, returns
+    /// `None`.
+    ///
+    /// ```
+    /// use ion_rs::{Element, IonType};
+    /// let element = Element::read_one("null.string").unwrap();
+    /// assert_eq!(element.as_null(), Some(IonType::String));
+    /// let element: Element = "hello".into();
+    /// assert_eq!(element.as_null(), None);
+    /// ```
+    pub fn as_null(&self) -> Option<IonType> {
+        self.value.as_null()
+    }
+
+    pub fn expect_null(&self) -> IonResult<IonType> {
+        self.as_null().ok_or_else(|| self.expected(IonType::Null))
     }
 
     pub fn as_int(&self) -> Option<&Int> {
-        match &self.value {
-            Value::Int(i) => Some(i),
-            _ => None,
-        }
+        self.value.as_int()
     }
 
     pub fn expect_int(&self) -> IonResult<&Int> {
@@ -460,24 +775,15 @@ impl Element {
     }
 
     pub fn as_i64(&self) -> Option<i64> {
-        match &self.value {
-            Value::Int(i) => i.as_i64(),
-            _ => None,
-        }
+        self.value.as_i64()
     }
 
     pub fn expect_i64(&self) -> IonResult<i64> {
-        match &self.value {
-            Value::Int(i) => i.expect_i64(),
-            _ => Err(self.expected(IonType::Int)),
-        }
+        self.expect_int()?.expect_i64()
     }
 
     pub fn as_float(&self) -> Option<f64> {
-        match &self.value {
-            Value::Float(f) => Some(*f),
-            _ => None,
-        }
+        self.value.as_float()
     }
 
     pub fn expect_float(&self) -> IonResult<f64> {
@@ -485,10 +791,7 @@ impl Element {
     }
 
     pub fn as_decimal(&self) -> Option<Decimal> {
-        match &self.value {
-            Value::Decimal(d) => Some(*d),
-            _ => None,
-        }
+        self.value.as_decimal()
     }
 
     pub fn expect_decimal(&self) -> IonResult<Decimal> {
@@ -497,10 +800,7 @@ impl Element {
     }
 
     pub fn as_timestamp(&self) -> Option<Timestamp> {
-        match &self.value {
-            Value::Timestamp(t) => Some(*t),
-            _ => None,
-        }
+        self.value.as_timestamp()
     }
 
     pub fn expect_timestamp(&self) -> IonResult<Timestamp> {
@@ -509,27 +809,16 @@ impl Element {
     }
 
     pub fn as_text(&self) -> Option<&str> {
-        match &self.value {
-            Value::String(text) => Some(text.as_ref()),
-            Value::Symbol(sym) => sym.text(),
-            _ => None,
-        }
+        self.value.as_text()
     }
 
     pub fn expect_text(&self) -> IonResult<&str> {
-        self.as_text().ok_or_else(|| {
-            IonError::decoding_error(format!(
-                "expected a text value, found a(n) {}",
-                self.ion_type()
-            ))
-        })
+        self.as_text()
+            .ok_or_else(|| self.expected_kind("a text value"))
     }
 
     pub fn as_string(&self) -> Option<&str> {
-        match &self.value {
-            Value::String(text) => Some(text.as_ref()),
-            _ => None,
-        }
+        self.value.as_string()
     }
 
     pub fn expect_string(&self) -> IonResult<&str> {
@@ -538,10 +827,7 @@ impl Element {
     }
 
     pub fn as_symbol(&self) -> Option<&Symbol> {
-        match &self.value {
-            Value::Symbol(sym) => Some(sym),
-            _ => None,
-        }
+        self.value.as_symbol()
     }
 
     pub fn expect_symbol(&self) -> IonResult<&Symbol> {
@@ -550,10 +836,7 @@ impl Element {
     }
 
     pub fn as_bool(&self) -> Option<bool> {
-        match &self.value {
-            Value::Bool(b) => Some(*b),
-            _ => None,
-        }
+        self.value.as_bool()
     }
 
     pub fn expect_bool(&self) -> IonResult<bool> {
@@ -561,26 +844,16 @@ impl Element {
     }
 
     pub fn as_lob(&self) -> Option<&[u8]> {
-        match &self.value {
-            Value::Blob(bytes) | Value::Clob(bytes) => Some(bytes.as_ref()),
-            _ => None,
-        }
+        self.value.as_lob()
     }
 
     pub fn expect_lob(&self) -> IonResult<&[u8]> {
-        self.as_lob().ok_or_else(|| {
-            IonError::decoding_error(format!(
-                "expected a lob value, found a(n) {}",
-                self.ion_type()
-            ))
-        })
+        self.as_lob()
+            .ok_or_else(|| self.expected_kind("a lob value"))
     }
 
     pub fn as_blob(&self) -> Option<&[u8]> {
-        match &self.value {
-            Value::Blob(bytes) => Some(bytes.as_ref()),
-            _ => None,
-        }
+        self.value.as_blob()
     }
 
     pub fn expect_blob(&self) -> IonResult<&[u8]> {
@@ -588,10 +861,7 @@ impl Element {
     }
 
     pub fn as_clob(&self) -> Option<&[u8]> {
-        match &self.value {
-            Value::Clob(bytes) => Some(bytes.as_ref()),
-            _ => None,
-        }
+        self.value.as_clob()
     }
 
     pub fn expect_clob(&self) -> IonResult<&[u8]> {
@@ -599,26 +869,16 @@ impl Element {
     }
 
     pub fn as_sequence(&self) -> Option<&Sequence> {
-        match &self.value {
-            Value::SExp(s) | Value::List(s) => Some(s),
-            _ => None,
-        }
+        self.value.as_sequence()
     }
 
     pub fn expect_sequence(&self) -> IonResult<&Sequence> {
-        self.as_sequence().ok_or_else(|| {
-            IonError::decoding_error(format!(
-                "expected a sequence value, found a(n) {}",
-                self.ion_type()
-            ))
-        })
+        self.as_sequence()
+            .ok_or_else(|| self.expected_kind("a sequence value"))
     }
 
     pub fn as_list(&self) -> Option<&Sequence> {
-        match &self.value {
-            Value::List(s) => Some(s),
-            _ => None,
-        }
+        self.value.as_list()
     }
 
     pub fn expect_list(&self) -> IonResult<&Sequence> {
@@ -626,10 +886,7 @@ impl Element {
     }
 
     pub fn as_sexp(&self) -> Option<&Sequence> {
-        match &self.value {
-            Value::SExp(s) => Some(s),
-            _ => None,
-        }
+        self.value.as_sexp()
     }
 
     pub fn expect_sexp(&self) -> IonResult<&Sequence> {
@@ -637,10 +894,7 @@ impl Element {
     }
 
     pub fn as_struct(&self) -> Option<&Struct> {
-        match &self.value {
-            Value::Struct(structure) => Some(structure),
-            _ => None,
-        }
+        self.value.as_struct()
     }
 
     pub fn expect_struct(&self) -> IonResult<&Struct> {
@@ -648,6 +902,179 @@ impl Element {
             .ok_or_else(|| self.expected(IonType::Struct))
     }
 
+    /// Consumes this element, returning an iterator that moves each of its child elements out of
+    /// the container. The children of a list or s-expression are its elements and the children of
+    /// a struct are its field values; to keep the field names, iterate over the [`Struct`] itself
+    /// instead. Any other value has no children. The element's annotations are discarded.
+    ///
+    /// ```
+    /// use ion_rs::Element;
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// let element = Element::read_one("{a: 1, b: [2]}")?;
+    /// let children: Vec<Element> = element.into_children().collect();
+    /// assert_eq!(children, [Element::int(1), Element::read_one("[2]")?]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_children(self) -> <Sequence as IntoIterator>::IntoIter {
+        let children = match self.value {
+            Value::List(sequence) | Value::SExp(sequence) => sequence,
+            Value::Struct(fields) => fields.into_iter().map(|(_name, value)| value).collect(),
+            _ => Sequence::from(Vec::new()),
+        };
+        children.into_iter()
+    }
+
+    /// If this element is a struct containing a field with the specified name, returns that
+    /// field's value. Otherwise, returns `None`. As with [`Struct::get`], if more than one field
+    /// has that name, the value of the _last_ such field is returned.
+    ///
+    /// ```
+    /// use ion_rs::Element;
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// let element = Element::read_one("{name: \"Alice\", address: {city: \"Seattle\"}}")?;
+    /// assert_eq!(element.get("name"), Some(&Element::string("Alice")));
+    /// assert_eq!(
+    ///     element.get("address").and_then(|address| address.get("city")),
+    ///     Some(&Element::string("Seattle"))
+    /// );
+    /// assert_eq!(element.get("age"), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get<A: AsSymbolRef>(&self, field_name: A) -> Option<&Element> {
+        self.as_struct().and_then(|strukt| strukt.get(field_name))
+    }
+
+    /// Returns the nested value that `pointer` refers to, or `None` if there is no such value or
+    /// the pointer is malformed.
+    ///
+    /// Pointers follow the syntax of [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901): the
+    /// empty string refers to this element, and each step into a nested value is written as a `/`
+    /// followed by a struct field name or a list or s-expression position, as in
+    /// `/store/books/3/title`. A `/` in a field name is written as `~1` and a `~` as `~0`. If more
+    /// than one field in a struct has the same name, the _last_ such field is used, as in
+    /// [`get`](Self::get). Because pointers are plain strings, they can be read from configuration
+    /// files or command line arguments.
+    ///
+    /// ```
+    /// use ion_rs::Element;
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// let element = Element::read_one(r#"{store: {books: [{title: "Dune"}], "a/b": 1}}"#)?;
+    /// assert_eq!(element.pointer("/store/books/0/title"), Some(&Element::string("Dune")));
+    /// assert_eq!(element.pointer("/store/a~1b"), Some(&Element::int(1)));
+    /// assert_eq!(element.pointer("/store/books/1/title"), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pointer(&self, pointer: &str) -> Option<&Element> {
+        pointer::resolve(self, pointer)
+    }
+
+    /// Compiles `query` and returns the values within this element that it selects. See
+    /// [`Query`](crate::Query) for the query syntax; to run the same query against many elements,
+    /// compile it once with [`Query::new`](crate::Query::new) instead.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # #[cfg(feature = "experimental-element-apis")]
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    ///
+    /// let element = Element::read_one("{foo: [{bar: 1, baz: 2}, {bar: 3, baz: 4}]}")?;
+    /// assert_eq!(element.select("foo[*].bar where baz > 3")?, vec![&Element::int(3)]);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "experimental-element-apis"))]
+    /// # fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    #[cfg(feature = "experimental-element-apis")]
+    pub fn select(&self, query: &str) -> IonResult<Vec<&Element>> {
+        Ok(query::Query::new(query)?.select(self))
+    }
+
+    // When the experimental-element-apis feature is disabled, this method is `pub(crate)` instead
+    // of `pub`
+    #[cfg(not(feature = "experimental-element-apis"))]
+    pub(crate) fn select(&self, query: &str) -> IonResult<Vec<&Element>> {
+        Ok(query::Query::new(query)?.select(self))
+    }
+
+    /// Returns counters describing this `Element` tree: the number of values of each Ion type, the
+    /// maximum nesting depth, and the number of bytes held in strings and lobs.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-element-apis")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, IonType};
+    ///
+    /// let element = Element::read_one(r#"{a: [1, 2, "three"], b: {{ aGVsbG8= }}}"#)?;
+    /// let stats = element.stats();
+    /// assert_eq!(stats.values(IonType::Int), 2);
+    /// assert_eq!(stats.total_values(), 6);
+    /// assert_eq!(stats.max_depth(), 2);
+    /// assert_eq!(stats.string_bytes(), 5);
+    /// assert_eq!(stats.lob_bytes(), 5);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-element-apis"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    #[cfg(feature = "experimental-element-apis")]
+    pub fn stats(&self) -> stats::ElementStats {
+        stats::ElementStats::of(self)
+    }
+
+    // When the experimental-element-apis feature is disabled, this method is `pub(crate)` instead
+    // of `pub`
+    #[cfg(not(feature = "experimental-element-apis"))]
+    pub(crate) fn stats(&self) -> stats::ElementStats {
+        stats::ElementStats::of(self)
+    }
+
+    /// Returns the number of bytes that this `Element` would occupy if it were encoded as binary
+    /// Ion 1.0 in a stream described by `symbol_table_policy`, without encoding it. This allows
+    /// values to be packed into size-limited records before they are serialized.
+    ///
+    /// The size includes the Ion version marker and any local symbol table that the stream
+    /// would need.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-element-apis")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, Element, SymbolTablePolicy};
+    ///
+    /// let element = Element::read_one("{id: 42, status: active}")?;
+    /// let size = element.binary_size_hint(&SymbolTablePolicy::Standalone)?;
+    /// assert_eq!(size, element.encode_as(v1_0::Binary)?.len());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-element-apis"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    #[cfg(feature = "experimental-element-apis")]
+    pub fn binary_size_hint(
+        &self,
+        symbol_table_policy: &size::SymbolTablePolicy,
+    ) -> IonResult<usize> {
+        size::binary_size(self, symbol_table_policy)
+    }
+
+    // When the experimental-element-apis feature is disabled, this method is `pub(crate)` instead
+    // of `pub`
+    #[cfg(not(feature = "experimental-element-apis"))]
+    pub(crate) fn binary_size_hint(
+        &self,
+        symbol_table_policy: &size::SymbolTablePolicy,
+    ) -> IonResult<usize> {
+        size::binary_size(self, symbol_table_policy)
+    }
+
     /// Reads a single Ion [`Element`] from the provided data source.
     ///
     /// If the data source is empty, returns `Ok(None)`.
@@ -676,6 +1103,51 @@ impl Element {
             .into())
     }
 
+    /// Reads all available [`Element`]s from the provided data source. Each `Element` is paired
+    /// with an [`ElementSpans`](spans::ElementSpans) tree recording where in the source it and
+    /// each of its nested values were found, allowing tools like schema validators and linters to
+    /// report problems at their original location.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    ///
+    /// let source = "{\n  name: \"widget\",\n  price: -1,\n}";
+    /// let (element, spans) = Element::read_all_with_spans(source)?.remove(0);
+    /// let price = element.as_struct().unwrap().get("price").unwrap();
+    /// assert_eq!(price, &Element::int(-1));
+    ///
+    /// // `price` is the struct's second field
+    /// let span = spans.child(1).unwrap().span().unwrap();
+    /// assert_eq!(&source[span.range()], "-1");
+    /// assert_eq!(span.start_line_and_column(), Some((3, 10)));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn read_all_with_spans<A: AsRef<[u8]>>(
+        data: A,
+    ) -> IonResult<Vec<(Element, spans::ElementSpans)>> {
+        let data = data.as_ref();
+        let mut reader = Reader::new(AnyEncoding, IonSlice::new(data))?;
+        let mut values = Vec::new();
+        let mut lines = None;
+        let mut stacks = BuildStacks::default();
+        while let Some(value) = reader.next()? {
+            let is_text = matches!(value.raw(), Some(raw) if raw.encoding().is_text());
+            if lines.is_none() && is_text {
+                lines = Some(spans::LineIndex::new(data));
+            }
+            let mut recorder = spans::SpanRecorder::new(lines.as_ref());
+            let element = materialize(&mut recorder, &mut stacks, value)?;
+            let spans = recorder
+                .take_spans()
+                .expect("materializing a value records its spans");
+            values.push((element, spans));
+        }
+        Ok(values)
+    }
+
     /// Returns an iterator over the Elements in the provided Ion data source.
     /// If the data source cannot be read or contains invalid Ion data, this method
     /// will return an `Err`.
@@ -751,6 +1223,11 @@ impl Element {
 
 impl Display for Element {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        if f.alternate() {
+            if let Some(result) = fmt_pretty(self, f) {
+                return result;
+            }
+        }
         let mut ivf = FmtValueFormatter { output: f };
 
         // display for annotations of this element
@@ -769,6 +1246,36 @@ impl PartialEq for Element {
 
 impl Eq for Element {}
 
+// Allows `element[index]` syntax for lists and s-expressions. Like indexing into a `Vec`, this
+// panics if the element is not a sequence or if `index` is out of bounds.
+impl Index<usize> for Element {
+    type Output = Element;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.as_sequence() {
+            Some(sequence) => &sequence[index],
+            None => panic!("cannot index into a(n) {} by position", self.ion_type()),
+        }
+    }
+}
+
+// Allows `element["name"]` syntax for structs, so that nested values can be reached with
+// expressions like `doc["users"][0]["name"]`. This panics if the element is not a struct or if
+// the struct has no field with that name.
+impl Index<&str> for Element {
+    type Output = Element;
+
+    fn index(&self, field_name: &str) -> &Self::Output {
+        match self.as_struct() {
+            Some(strukt) => &strukt[field_name],
+            None => panic!(
+                "cannot index into a(n) {} by field name '{field_name}'",
+                self.ion_type()
+            ),
+        }
+    }
+}
+
 // This implementation allows APIs that require an Into<Element> to accept references to an existing
 // Element.
 impl<'a> From<&'a Element> for Element {
@@ -800,7 +1307,7 @@ mod tests {
 
     use crate::element::annotations::IntoAnnotations;
     use crate::{ion_list, ion_sexp, ion_struct, Decimal, Int, IonType, Symbol, Timestamp};
-    use crate::{Annotations, Element, IntoAnnotatedElement, Struct};
+    use crate::{Annotations, Element, IntoAnnotatedElement, Struct, Value};
 
     /// Makes a timestamp from an RFC-3339 string and panics if it can't
     fn make_timestamp<T: AsRef<str>>(text: T) -> Timestamp {
@@ -835,6 +1342,24 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn added_annotations_are_appended() {
+        let element = 10i64.with_annotations(["foo"]);
+        let element = element.with_added_annotations(["bar", "baz"]);
+        assert_eq!(
+            element.annotations(),
+            &["foo", "bar", "baz"].into_annotations()
+        );
+
+        let mut element: Element = 10i64.into();
+        element.add_annotation("redacted");
+        element.add_annotation(Symbol::unknown_text());
+        assert_eq!(
+            element.annotations(),
+            &[Symbol::from("redacted"), Symbol::unknown_text()].into_annotations()
+        );
+    }
+
     struct CaseSym {
         eq_annotations: Vec<Symbol>,
         ne_annotations: Vec<Symbol>,
@@ -1059,7 +1584,11 @@ mod tests {
             elem: Element::from(IonType::Null), // null.null
             ion_type: IonType::Null,
             ops: vec![IsNull],
-            op_assert: Box::new(|e: &Element| assert!(e.is_null())),
+            op_assert: Box::new(|e: &Element| {
+                assert!(e.is_null());
+                assert_eq!(e.as_null(), Some(IonType::Null));
+                assert_eq!(e.expect_null().unwrap(), IonType::Null);
+            }),
         }
     }
 
@@ -1259,7 +1788,14 @@ mod tests {
     fn element_accessors(#[case] input_case: Case) {
         // table of negative assertions for each operation
         let neg_table: Vec<(ElemOp, ElemAssertFn)> = vec![
-            (IsNull, Box::new(|e| assert!(!e.is_null()))),
+            (
+                IsNull,
+                Box::new(|e| {
+                    assert!(!e.is_null());
+                    assert_eq!(None, e.as_null());
+                    assert!(e.expect_null().is_err());
+                }),
+            ),
             (AsBool, Box::new(|e| assert_eq!(None, e.as_bool()))),
             (
                 AsAnyInt,
@@ -1303,6 +1839,127 @@ mod tests {
         let itself = &input_case.elem;
         assert_eq!(&input_case.elem, itself);
     }
+
+    #[rstest]
+    #[case::unannotated("5", "expected a(n) string, found a(n) int")]
+    #[case::annotated("foo::bar::5", "expected a(n) string, found a(n) 'foo'::'bar'::int")]
+    fn expect_error_describes_element(#[case] text: &str, #[case] expected_message: &str) {
+        let element = Element::read_one(text).unwrap();
+        let error = element.expect_string().unwrap_err();
+        assert!(
+            error.to_string().contains(expected_message),
+            "unexpected error message: {error}"
+        );
+    }
+
+    #[test]
+    fn value_accessors() {
+        let element =
+            Element::read_one("annotated::{a: [1, \"two\", three], b: null.int}").unwrap();
+        let value = element.value();
+        let fields = value.expect_struct().unwrap();
+        assert_eq!(
+            fields.get("b").unwrap().value().as_null(),
+            Some(IonType::Int)
+        );
+        let list = fields.get("a").unwrap().value();
+        assert!(list.as_sexp().is_none());
+        let children = list.expect_list().unwrap();
+        assert_eq!(children.get(0).unwrap().value().as_i64(), Some(1));
+        assert_eq!(
+            children.get(1).unwrap().value().expect_string().unwrap(),
+            "two"
+        );
+        assert_eq!(children.get(2).unwrap().value().as_text(), Some("three"));
+        assert!(Value::from(2.5f64).as_float().is_some());
+        assert!(!Value::from(true).is_null());
+    }
+
+    #[test]
+    fn value_expect_error() {
+        let error = Value::from(5).expect_string().unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("expected a(n) string, found a(n) int"),
+            "unexpected error message: {error}"
+        );
+        let error = Value::from(5).expect_sequence().unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("expected a sequence value, found a(n) int"));
+        // An element's error also describes its annotations
+        let element = Element::read_one("foo::5").unwrap();
+        assert_eq!(element.expect_i64().unwrap(), 5);
+        let error = Element::read_one("foo::a")
+            .unwrap()
+            .expect_i64()
+            .unwrap_err();
+        assert!(error.to_string().contains("found a(n) 'foo'::symbol"));
+    }
+
+    #[test]
+    fn expect_error_for_type_category() {
+        let element = Element::read_one("foo::{}").unwrap();
+        let error = element.expect_sequence().unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("expected a sequence value, found a(n) 'foo'::struct"));
+    }
+
+    #[rstest]
+    #[case::field("{a: 1, b: 2}", "b", Some(Element::int(2)))]
+    #[case::repeated_field("{a: 1, a: 2}", "a", Some(Element::int(2)))]
+    #[case::missing_field("{a: 1}", "b", None)]
+    #[case::null_struct("null.struct", "a", None)]
+    #[case::list("[a]", "a", None)]
+    #[case::symbol("a", "a", None)]
+    fn get_field(#[case] text: &str, #[case] field_name: &str, #[case] expected: Option<Element>) {
+        let element = Element::read_one(text).unwrap();
+        assert_eq!(element.get(field_name), expected.as_ref());
+    }
+
+    #[test]
+    fn index_into_nested_containers() {
+        let doc = Element::read_one(
+            r#"{users: [{name: "Alice", tags: (a b)}, {name: "Bob"}], name: "people"}"#,
+        )
+        .unwrap();
+        assert_eq!(doc["users"][0]["name"], Element::string("Alice"));
+        assert_eq!(doc["users"][1]["name"], Element::string("Bob"));
+        assert_eq!(doc["users"][0]["tags"][1], Element::symbol("b"));
+        assert_eq!(doc["name"], Element::string("people"));
+    }
+
+    #[rstest]
+    #[case::missing_field("{a: 1}", "b", None)]
+    #[case::field_of_list("[1]", "a", None)]
+    #[case::position_out_of_bounds("[1]", "", Some(1))]
+    #[case::position_of_struct("{a: 1}", "", Some(0))]
+    #[case::position_of_null_list("null.list", "", Some(0))]
+    #[should_panic]
+    fn index_panics(#[case] text: &str, #[case] field_name: &str, #[case] position: Option<usize>) {
+        let element = Element::read_one(text).unwrap();
+        let _value = match position {
+            Some(position) => &element[position],
+            None => &element[field_name],
+        };
+    }
+
+    #[rstest]
+    #[case::list("a::[1, [2], 3]", &["1", "[2]", "3"])]
+    #[case::sexp("(1 (2) 3)", &["1", "(2)", "3"])]
+    #[case::struct_("{a: 1, b: [2], a: 3}", &["1", "[2]", "3"])]
+    #[case::scalar("a::1", &[])]
+    #[case::null_list("null.list", &[])]
+    fn children_of_owned_element(#[case] text: &str, #[case] expected: &[&str]) {
+        let children: Vec<Element> = Element::read_one(text).unwrap().into_children().collect();
+        let expected: Vec<Element> = expected
+            .iter()
+            .map(|text| Element::read_one(text).unwrap())
+            .collect();
+        assert_eq!(children, expected);
+    }
 }
 
 #[cfg(test)]
@@ -1450,6 +2107,88 @@ mod value_tests {
         assert!(expected_element.ion_eq(&actual_element));
     }
 
+    #[test]
+    fn alternate_display_is_pretty() -> IonResult<()> {
+        let element = Element::read_one("a::{foo: [1, (b c)], bar: 2}")?;
+        let expected =
+            "a::{\n  foo: [\n    1,\n    (\n      b\n      c\n    ),\n  ],\n  bar: 2,\n}";
+        assert_eq!(format!("{element:#}"), expected);
+        assert_eq!(format!("{:#}", element.value()), &expected[3..]);
+        let struct_ = element.expect_struct()?;
+        assert_eq!(format!("{struct_:#}"), &expected[3..]);
+        let list = List::from(struct_.get("foo").unwrap().expect_list()?.clone());
+        assert_eq!(format!("{list:#}"), "[\n  1,\n  (\n    b\n    c\n  ),\n]");
+        let sexp = ion_sexp!(1 2);
+        assert_eq!(format!("{sexp:#}"), "(\n  1\n  2\n)");
+        // Scalars are the same in either form, and the compact form is unchanged
+        assert_eq!(format!("{:#}", Element::string("hi")), "\"hi\"");
+        assert_eq!(format!("{element}"), "a::{foo: [1, (b c)], bar: 2}");
+        Ok(())
+    }
+
+    // Checks the text and symbol ID of each symbol in `$10::{$11: $10, $0: bar, baz: $4}`, where
+    // `$10` is `foo` and `$11` has unknown text.
+    fn assert_symbol_ids_retained(element: &Element) {
+        let text_and_id = |symbol: &Symbol| (symbol.text().map(str::to_owned), symbol.symbol_id());
+        let expected =
+            |text: Option<&str>, symbol_id: Option<usize>| (text.map(str::to_owned), symbol_id);
+        assert_eq!(
+            text_and_id(element.annotations().iter().next().unwrap()),
+            expected(Some("foo"), Some(10))
+        );
+        let fields: Vec<_> = element
+            .expect_struct()
+            .unwrap()
+            .fields()
+            .map(|(name, value)| (text_and_id(name), text_and_id(value.as_symbol().unwrap())))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                (expected(None, Some(11)), expected(Some("foo"), Some(10))),
+                (expected(None, Some(0)), expected(Some("bar"), None)),
+                (expected(Some("baz"), None), expected(Some("name"), Some(4))),
+            ]
+        );
+    }
+
+    #[test]
+    fn symbol_ids_are_retained() -> crate::IonResult<()> {
+        use crate::{AnyEncoding, ElementReader, Reader, TextInterner};
+
+        let data = r#"
+            $ion_symbol_table::{symbols: ["foo", null]}
+            $10::{$11: $10, $0: bar, baz: $4}
+        "#;
+        assert_symbol_ids_retained(&Element::read_one(data)?);
+
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let element = Element::try_from(reader.expect_next()?)?;
+        assert_symbol_ids_retained(&element);
+
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let element = reader
+            .read_next_element_interned(&mut TextInterner::new())?
+            .unwrap();
+        assert_symbol_ids_retained(&element);
+        // Copying an element into an interner also retains its symbol IDs.
+        assert_symbol_ids_retained(&TextInterner::new().intern_element(&element));
+
+        // Recycled symbols take on the symbol IDs of the values they are reused for.
+        let mut reader = Reader::new(AnyEncoding, [data, data].concat())?;
+        let mut element = Element::from(ion_struct! {"foo": Symbol::owned("bar")});
+        while reader.read_next_element_into(&mut element)? {
+            assert_symbol_ids_retained(&element);
+        }
+
+        // Symbol IDs do not affect equality.
+        assert_eq!(
+            Element::read_one(data)?,
+            Element::read_one("foo::{$0: foo, $0: bar, baz: name}")?
+        );
+        Ok(())
+    }
+
     #[rstest]
     #[case::i8(42i8)]
     #[case::i8_neg(-42i8)]
@@ -0,0 +1,356 @@
+//! An [`ElementReader`] that decodes the top-level values of a binary Ion 1.0 stream in parallel
+//! on a [rayon](https://docs.rs/rayon) thread pool.
+
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+
+use rayon::prelude::*;
+
+use crate::constants::v1_0::SYSTEM_SYMBOLS;
+use crate::element::reader::{ElementIterator, ElementReader};
+use crate::lazy::decoder::HasRange;
+use crate::lazy::reader::Reader;
+use crate::lazy::system_reader::SystemReader;
+use crate::lazy::system_stream_item::SystemStreamItem;
+use crate::result::IonFailure;
+use crate::{v1_0, Element, IonError, IonResult, IonType, List, Sequence, Symbol};
+
+const IVM: [u8; 4] = [0xE0, 0x01, 0x00, 0xEA];
+const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+// The number of chunks scanned per worker thread before the next batch is decoded.
+const CHUNKS_PER_THREAD: usize = 4;
+
+/// A contiguous run of top-level values that share a symbol table.
+struct Chunk {
+    // An IVM followed by a symbol table that defines the symbols the values can refer to.
+    prefix: Arc<[u8]>,
+    range: Range<usize>,
+}
+
+impl Chunk {
+    /// Decodes the chunk's values. If decoding fails, returns the values that preceded the failure
+    /// along with the error.
+    fn decode(&self, data: &[u8]) -> (Vec<Element>, Option<IonError>) {
+        let mut buffer = Vec::with_capacity(self.prefix.len() + self.range.len());
+        buffer.extend_from_slice(&self.prefix);
+        buffer.extend_from_slice(&data[self.range.clone()]);
+        let mut reader = match Reader::new(v1_0::Binary, buffer) {
+            Ok(reader) => reader,
+            Err(error) => return (Vec::new(), Some(error)),
+        };
+        let mut elements = Vec::new();
+        loop {
+            match reader.read_next_element() {
+                Ok(Some(element)) => elements.push(element),
+                Ok(None) => return (elements, None),
+                Err(error) => return (elements, Some(error)),
+            }
+        }
+    }
+}
+
+/// Reads a binary Ion 1.0 stream into [`Element`]s, decoding batches of top-level values on the
+/// current [rayon](https://docs.rs/rayon) thread pool.
+///
+/// The reader scans ahead in the stream to find the boundaries of its top-level values without
+/// decoding them, groups them into chunks of roughly [`chunk_size`](Self::with_chunk_size) bytes,
+/// and decodes several chunks at once. Each chunk is decoded independently using a copy of the
+/// symbol table that was active at its position in the stream, so streams with multiple local
+/// symbol tables, symbol table appends and version markers are read exactly as they would be by
+/// [`Element::read_all`]. Elements are produced in stream order.
+///
+/// To decode on a pool other than rayon's global pool, use the reader inside
+/// [`ThreadPool::install`](rayon::ThreadPool::install).
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::{v1_0, Element, ElementReader, ParallelElementReader};
+///
+/// let elements = Element::read_all("{name: foo} {name: bar} [1, 2, 3]")?;
+/// let binary = elements.encode_as(v1_0::Binary)?;
+/// let mut reader = ParallelElementReader::new(&binary)?.with_chunk_size(16);
+/// assert_eq!(reader.read_all_elements()?, elements);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ParallelElementReader<'data> {
+    data: &'data [u8],
+    scanner: SystemReader<v1_0::Binary, &'data [u8]>,
+    chunk_size: usize,
+    // The prefix for the current symbol table segment, if any of its values have been scanned.
+    prefix: Option<Arc<[u8]>>,
+    decoded: VecDeque<Element>,
+    // An error to report once the elements that precede it have been returned.
+    pending_error: Option<IonError>,
+    is_exhausted: bool,
+}
+
+impl<'data> ParallelElementReader<'data> {
+    /// Constructs a reader for the provided binary Ion 1.0 stream. Returns an error if the data
+    /// does not begin with a binary Ion 1.0 version marker.
+    pub fn new(data: &'data [u8]) -> IonResult<Self> {
+        if !data.starts_with(&IVM) {
+            return IonResult::illegal_operation(
+                "ParallelElementReader requires a binary Ion 1.0 stream",
+            );
+        }
+        Ok(Self {
+            data,
+            scanner: SystemReader::new(v1_0::Binary, data)?,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            prefix: None,
+            decoded: VecDeque::new(),
+            pending_error: None,
+            is_exhausted: false,
+        })
+    }
+
+    /// Sets the number of bytes of encoded values that are decoded together as a single unit of
+    /// work. A chunk always holds at least one value. The default is 1 MiB.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Scans up to `max_chunks` chunks of values. If scanning fails, returns the chunks that
+    /// preceded the failure along with the error.
+    fn scan(&mut self, max_chunks: usize) -> (Vec<Chunk>, Option<IonError>) {
+        let mut chunks: Vec<Chunk> = Vec::new();
+        // Whether the last chunk in `chunks` can hold more values.
+        let mut is_open = false;
+        loop {
+            if !is_open && chunks.len() == max_chunks {
+                break;
+            }
+            let range = match self.scanner.next_item() {
+                Ok(SystemStreamItem::Value(value)) => value.raw().map(|raw| raw.range()),
+                Ok(SystemStreamItem::EndOfStream(_)) => {
+                    self.is_exhausted = true;
+                    break;
+                }
+                // Any other item begins a segment that uses a different symbol table.
+                Ok(_) => None,
+                Err(error) => {
+                    self.is_exhausted = true;
+                    return (chunks, Some(error));
+                }
+            };
+            let Some(range) = range else {
+                self.prefix = None;
+                is_open = false;
+                continue;
+            };
+            if is_open {
+                let chunk = chunks.last_mut().unwrap();
+                chunk.range.end = range.end;
+                is_open = chunk.range.len() < self.chunk_size;
+                continue;
+            }
+            let prefix = match &self.prefix {
+                Some(prefix) => Arc::clone(prefix),
+                None => match self.segment_prefix() {
+                    Ok(prefix) => self.prefix.insert(prefix).clone(),
+                    Err(error) => return (chunks, Some(error)),
+                },
+            };
+            is_open = range.len() < self.chunk_size;
+            chunks.push(Chunk { prefix, range });
+        }
+        (chunks, None)
+    }
+
+    /// Encodes a version marker followed by a local symbol table holding all of the symbols that
+    /// the scanner's current symbol table defines beyond the system symbols.
+    fn segment_prefix(&self) -> IonResult<Arc<[u8]>> {
+        let symbols = &self.scanner.symbol_table().symbols()[SYSTEM_SYMBOLS.len()..];
+        if symbols.is_empty() {
+            return Ok(Arc::from(IVM.as_slice()));
+        }
+        let symbols: Vec<Element> = symbols
+            .iter()
+            .map(|symbol| match symbol.text() {
+                Some(text) => Element::string(text),
+                None => Element::null(IonType::Null),
+            })
+            .collect();
+        let symbol_table = Element::struct_builder()
+            .with_field(
+                "symbols",
+                Element::from(List::from(Sequence::from(symbols))),
+            )
+            .build();
+        let symbol_table =
+            Element::from(symbol_table).with_annotations([Symbol::from("$ion_symbol_table")]);
+        Ok(Arc::from(symbol_table.encode_as(v1_0::Binary)?))
+    }
+
+    /// Scans and decodes the next batch of chunks.
+    fn fill(&mut self) {
+        let (chunks, scan_error) = self.scan(rayon::current_num_threads() * CHUNKS_PER_THREAD);
+        let data = self.data;
+        let results: Vec<(Vec<Element>, Option<IonError>)> =
+            chunks.par_iter().map(|chunk| chunk.decode(data)).collect();
+        for (elements, error) in results {
+            // The values that precede an error are still returned before it is reported.
+            self.decoded.extend(elements);
+            if let Some(error) = error {
+                self.is_exhausted = true;
+                self.pending_error = Some(error);
+                return;
+            }
+        }
+        self.pending_error = scan_error;
+    }
+}
+
+impl<'data> ElementReader for ParallelElementReader<'data> {
+    type ElementIterator<'a> = ElementIterator<'a, Self> where Self: 'a;
+
+    fn read_next_element(&mut self) -> IonResult<Option<Element>> {
+        loop {
+            if let Some(element) = self.decoded.pop_front() {
+                return Ok(Some(element));
+            }
+            if let Some(error) = self.pending_error.take() {
+                return Err(error);
+            }
+            if self.is_exhausted {
+                return Ok(None);
+            }
+            self.fill();
+        }
+    }
+
+    fn elements(&mut self) -> Self::ElementIterator<'_> {
+        ElementIterator { reader: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn read_in_parallel(data: &[u8], chunk_size: usize) -> IonResult<Sequence> {
+        ParallelElementReader::new(data)?
+            .with_chunk_size(chunk_size)
+            .read_all_elements()
+    }
+
+    #[rstest]
+    #[case::one_per_chunk(1)]
+    #[case::small_chunks(64)]
+    #[case::default(DEFAULT_CHUNK_SIZE)]
+    fn matches_sequential_reader(#[case] chunk_size: usize) -> IonResult<()> {
+        let text: String = (0..500)
+            .map(|i| {
+                format!(
+                    "sym{i}::{{id: {i}, name: \"value {i}\", tags: [t{}, t{}]}} ",
+                    i % 7,
+                    i
+                )
+            })
+            .collect();
+        let binary = Element::read_all(text)?.encode_as(v1_0::Binary)?;
+        assert_eq!(
+            read_in_parallel(&binary, chunk_size)?,
+            Element::read_all(&binary)?
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(8)]
+    #[case(DEFAULT_CHUNK_SIZE)]
+    fn multiple_symbol_tables(#[case] chunk_size: usize) -> IonResult<()> {
+        let mut binary = Element::read_all("a b {c: d}")?.encode_as(v1_0::Binary)?;
+        // A second stream with a different symbol table, preceded by its own version marker
+        binary.extend(Element::read_all("e::f [g, a]")?.encode_as(v1_0::Binary)?);
+        // A symbol table that doesn't import the previous one, without a version marker
+        binary.extend(Element::read_all("1")?.encode_as(v1_0::Binary)?);
+        binary.extend_from_slice(&[
+            0xE9, 0x81, 0x83, // $ion_symbol_table::
+            0xD6, 0x87, // {symbols:
+            0xB4, 0x83, b'x', b'y', b'z', // ["xyz"]
+            0x71, 0x0A, // $10
+        ]);
+        // A symbol table append
+        binary.extend_from_slice(&[
+            0xEB, 0x81, 0x83, // $ion_symbol_table::
+            0xD8, 0x86, 0x71, 0x03, // {imports: $ion_symbol_table,
+            0x87, 0xB3, 0x82, b'h', b'i', // symbols: ["hi"]
+            0x71, 0x0A, 0x71, 0x0B, // $10 $11
+        ]);
+        let expected = Element::read_all(&binary)?;
+        assert_eq!(
+            expected,
+            Element::read_all("a b {c: d} e::f [g, a] 1 xyz xyz hi")?
+        );
+        assert_eq!(read_in_parallel(&binary, chunk_size)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn symbols_with_unknown_text() -> IonResult<()> {
+        let mut binary = IVM.to_vec();
+        binary.extend_from_slice(&[
+            0xE8, 0x81, 0x83, // $ion_symbol_table::
+            0xD5, 0x87, // {symbols:
+            0xB3, 0x0F, 0x81, b'a', // [null, "a"]
+            0x71, 0x0A, 0x71, 0x0B, // $10 $11
+        ]);
+        assert_eq!(read_in_parallel(&binary, 1)?, Element::read_all(&binary)?);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_stream() -> IonResult<()> {
+        assert!(read_in_parallel(&IVM, 1)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn requires_binary_ion_1_0() {
+        assert!(ParallelElementReader::new(b"{a: 1}").is_err());
+        assert!(ParallelElementReader::new(&[0xE0, 0x01, 0x01, 0xEA]).is_err());
+    }
+
+    #[test]
+    fn reports_errors_after_preceding_values() -> IonResult<()> {
+        let values: String = (0..100).map(|i| format!("{i} ")).collect();
+        let mut binary = Element::read_all(values)?.encode_as(v1_0::Binary)?;
+        // A truncated string
+        binary.extend_from_slice(&[0x85, b'a']);
+        let mut reader = ParallelElementReader::new(&binary)?.with_chunk_size(4);
+        for i in 0..100i64 {
+            assert_eq!(reader.read_next_element()?, Some(Element::from(i)));
+        }
+        assert!(reader.read_next_element().is_err());
+        assert_eq!(reader.read_next_element()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn reports_decoding_errors_after_preceding_values_in_chunk() -> IonResult<()> {
+        let values: String = (0..10).map(|i| format!("{i} ")).collect();
+        let mut binary = Element::read_all(values)?.encode_as(v1_0::Binary)?;
+        // A string that is not valid UTF-8, which is found when the chunk is decoded rather than
+        // when it is scanned, followed by a value that is never returned
+        binary.extend_from_slice(&[0x82, 0xFF, 0xFE, 0x21, 0x01]);
+        let mut reader = ParallelElementReader::new(&binary)?;
+        for i in 0..10i64 {
+            assert_eq!(reader.read_next_element()?, Some(Element::from(i)));
+        }
+        assert!(reader.read_next_element().is_err());
+        assert_eq!(reader.read_next_element()?, None);
+        Ok(())
+    }
+}
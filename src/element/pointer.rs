@@ -0,0 +1,110 @@
+//! Resolves JSON Pointer-style paths (see [RFC 6901](https://www.rfc-editor.org/rfc/rfc6901))
+//! against an [`Element`].
+
+use std::borrow::Cow;
+
+use crate::Element;
+
+/// Returns the value that `pointer` refers to within `element`, or `None` if the pointer is
+/// malformed or does not refer to a value.
+pub(crate) fn resolve<'a>(element: &'a Element, pointer: &str) -> Option<&'a Element> {
+    if pointer.is_empty() {
+        return Some(element);
+    }
+    // Each reference token is introduced by a `/`.
+    let tokens = pointer.strip_prefix('/')?;
+    tokens.split('/').try_fold(element, |parent, token| {
+        let token = unescape(token)?;
+        if let Some(strukt) = parent.as_struct() {
+            strukt.get(token.as_ref())
+        } else {
+            parent.as_sequence()?.get(parse_index(&token)?)
+        }
+    })
+}
+
+/// Replaces the escape sequences `~1` and `~0` in `token` with `/` and `~` respectively. Returns
+/// `None` if `token` contains a `~` that does not begin one of those sequences.
+fn unescape(token: &str) -> Option<Cow<'_, str>> {
+    if !token.contains('~') {
+        return Some(Cow::Borrowed(token));
+    }
+    let mut text = String::with_capacity(token.len());
+    let mut chars = token.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '~' => match chars.next()? {
+                '0' => text.push('~'),
+                '1' => text.push('/'),
+                _ => return None,
+            },
+            c => text.push(c),
+        }
+    }
+    Some(Cow::Owned(text))
+}
+
+/// Parses a token that refers to a position in a sequence. As in RFC 6901, the position must be
+/// written in decimal without leading zeros.
+fn parse_index(token: &str) -> Option<usize> {
+    let is_canonical = token == "0" || !token.starts_with('0');
+    if token.is_empty() || !is_canonical || !token.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    token.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::Element;
+
+    const DOCUMENT: &str = r#"
+        {
+            store: {
+                books: [
+                    {title: "Dune", tags: (sci fi)},
+                    {title: "Emma"},
+                ],
+                "a/b": 1,
+                "m~n": 2,
+                "": 3,
+                "7": 4,
+                dup: 5,
+                dup: 6,
+            },
+        }
+    "#;
+
+    #[rstest]
+    #[case::whole_document("", DOCUMENT)]
+    #[case::nested_field("/store/books/1/title", "\"Emma\"")]
+    #[case::sexp_child("/store/books/0/tags/1", "fi")]
+    #[case::escaped_slash("/store/a~1b", "1")]
+    #[case::escaped_tilde("/store/m~0n", "2")]
+    #[case::empty_field_name("/store/", "3")]
+    #[case::numeric_field_name("/store/7", "4")]
+    #[case::repeated_field_name("/store/dup", "6")]
+    fn resolves(#[case] pointer: &str, #[case] expected: &str) {
+        let document = Element::read_one(DOCUMENT).unwrap();
+        let expected = Element::read_one(expected).unwrap();
+        assert_eq!(document.pointer(pointer), Some(&expected));
+    }
+
+    #[rstest]
+    #[case::missing_leading_slash("store")]
+    #[case::missing_field("/store/price")]
+    #[case::index_out_of_bounds("/store/books/2")]
+    #[case::leading_zero("/store/books/01")]
+    #[case::negative_index("/store/books/-1")]
+    #[case::field_of_list("/store/books/title")]
+    #[case::child_of_scalar("/store/a~1b/0")]
+    #[case::unescaped_slash("/store/a/b")]
+    #[case::invalid_escape("/store/m~2n")]
+    #[case::trailing_tilde("/store/m~")]
+    fn does_not_resolve(#[case] pointer: &str) {
+        let document = Element::read_one(DOCUMENT).unwrap();
+        assert_eq!(document.pointer(pointer), None);
+    }
+}
@@ -0,0 +1,695 @@
+//! A small query language for selecting values from [`Element`] trees and streams of elements.
+//!
+//! A query is a path followed by an optional `where` clause, as in `foo[*].bar where baz > 3`.
+//! See [`Query`] for the syntax.
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::element::reader::ElementReader;
+use crate::result::IonFailure;
+use crate::{Decimal, Element, IonError, IonResult, IonType};
+
+/// A compiled query that selects values from an [`Element`].
+///
+/// # Syntax
+///
+/// A query begins with a path, which is a series of steps from the element being queried to the
+/// values that it selects:
+///
+/// * `name` or `.name` selects the values of the struct fields called `name`. If more than one
+///   field has that name, each of their values is selected. Names that are not identifiers can be
+///   quoted in brackets, as in `["first name"]`.
+/// * `[3]` selects the value at position `3` of a list or s-expression.
+/// * `[*]` or `.*` selects every child value of a list, s-expression, or struct.
+/// * `@` selects the element itself and is only needed for queries that have no other steps.
+///
+/// Steps that do not apply (for example, a field name applied to a list) select nothing.
+///
+/// The path can be followed by `where` and a condition. The condition filters the values selected
+/// by the path up to and including its last wildcard (or the element itself if there is no
+/// wildcard), and the rest of the path is evaluated only for the values that pass. In
+/// `foo[*].bar where baz > 3`, each child of `foo` whose `baz` field is greater than 3 contributes
+/// its `bar` field to the results.
+///
+/// Conditions compare two operands with `=`, `!=`, `<`, `<=`, `>` or `>=`, and can be combined with
+/// `and`, `or`, `not` and parentheses. Each operand is either a path relative to the value being
+/// filtered or a literal: a number, a timestamp, a quoted string or symbol, `true`, `false`, or
+/// `null`. Because an unquoted name is a path, symbols must be quoted: `level = 'error'` compares
+/// the `level` field with the symbol `error`, while `level = error` compares it with the `error`
+/// field. A comparison holds if it holds for any of the values that its paths select, so it never
+/// holds if a path selects nothing. Ints, decimals, and floats are compared by their numeric
+/// value; strings and symbols by their text; and nulls of any type are equal to each other.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # #[cfg(feature = "experimental-element-apis")]
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::{Element, Query};
+/// let log = Element::read_one(r#"
+///     {
+///         requests: [
+///             {path: "/", status: 200, ms: 12},
+///             {path: "/login", status: 503, ms: 3004},
+///             {path: "/search", status: 500, ms: 87},
+///         ]
+///     }
+/// "#)?;
+///
+/// let query = Query::new("requests[*].path where status >= 500 and ms > 100")?;
+/// assert_eq!(query.select(&log), vec![&Element::string("/login")]);
+///
+/// // `Element::select` compiles and runs a query in a single call.
+/// assert_eq!(log.select("requests[1].status")?, vec![&Element::int(503)]);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "experimental-element-apis"))]
+/// # fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    // The steps leading to the values that the filter is applied to.
+    source: Vec<Step>,
+    // The steps applied to each value that passes the filter.
+    projection: Vec<Step>,
+    filter: Option<Condition>,
+}
+
+impl Query {
+    /// Compiles the query in `text`, returning an error if it is not a valid query.
+    pub fn new(text: &str) -> IonResult<Self> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            position: 0,
+        };
+        let mut source = parser.parse_path()?;
+        let filter = if parser.next_if(&Token::Where) {
+            Some(parser.parse_condition()?)
+        } else {
+            None
+        };
+        if let Some(token) = parser.peek() {
+            return IonResult::decoding_error(format!("invalid query: unexpected '{token}'"));
+        }
+        let split = source
+            .iter()
+            .rposition(|step| *step == Step::Wildcard)
+            .map_or(0, |index| index + 1);
+        let projection = source.split_off(split);
+        Ok(Query {
+            source,
+            projection,
+            filter,
+        })
+    }
+
+    /// Returns the values within `element` that the query selects.
+    pub fn select<'a>(&self, element: &'a Element) -> Vec<&'a Element> {
+        let candidates = evaluate_path(&self.source, element);
+        let mut results = Vec::new();
+        for candidate in candidates {
+            if let Some(filter) = &self.filter {
+                if !filter.matches(candidate) {
+                    continue;
+                }
+            }
+            results.extend(evaluate_path(&self.projection, candidate));
+        }
+        results
+    }
+
+    /// Reads each of the remaining top-level elements from `reader` and returns an iterator over
+    /// the values that the query selects from them. Elements are read as the iterator advances, so
+    /// streams of any size can be queried. If the reader encounters an error, the iterator yields
+    /// that error and then ends.
+    pub fn select_from<'a, R: ElementReader + ?Sized>(
+        &'a self,
+        reader: &'a mut R,
+    ) -> impl Iterator<Item = IonResult<Element>> + 'a {
+        let mut pending = VecDeque::new();
+        let mut is_done = false;
+        std::iter::from_fn(move || loop {
+            if let Some(value) = pending.pop_front() {
+                return Some(Ok(value));
+            }
+            if is_done {
+                return None;
+            }
+            match reader.read_next_element() {
+                Ok(Some(element)) => {
+                    pending.extend(self.select(&element).into_iter().cloned());
+                }
+                Ok(None) => return None,
+                // The reader cannot continue past invalid data, so the error ends the iteration.
+                Err(error) => {
+                    is_done = true;
+                    return Some(Err(error));
+                }
+            }
+        })
+    }
+}
+
+impl FromStr for Query {
+    type Err = IonError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Query::new(text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    Field(String),
+    Index(usize),
+    Wildcard,
+}
+
+fn evaluate_path<'a>(steps: &[Step], element: &'a Element) -> Vec<&'a Element> {
+    let mut values = vec![element];
+    for step in steps {
+        let mut next = Vec::new();
+        for value in values {
+            match step {
+                Step::Field(name) => {
+                    if let Some(strukt) = value.as_struct() {
+                        next.extend(strukt.get_all(name.as_str()));
+                    }
+                }
+                Step::Index(index) => {
+                    if let Some(child) = value.as_sequence().and_then(|s| s.get(*index)) {
+                        next.push(child);
+                    }
+                }
+                Step::Wildcard => {
+                    if let Some(sequence) = value.as_sequence() {
+                        next.extend(sequence.elements());
+                    } else if let Some(strukt) = value.as_struct() {
+                        next.extend(strukt.fields().map(|(_name, child)| child));
+                    }
+                }
+            }
+        }
+        values = next;
+    }
+    values
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    fn holds(self, ordering: Option<Ordering>) -> bool {
+        use Ordering::*;
+        match self {
+            Comparison::Eq => ordering == Some(Equal),
+            Comparison::Ne => ordering != Some(Equal),
+            Comparison::Lt => ordering == Some(Less),
+            Comparison::Le => matches!(ordering, Some(Less | Equal)),
+            Comparison::Gt => ordering == Some(Greater),
+            Comparison::Ge => matches!(ordering, Some(Greater | Equal)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Path(Vec<Step>),
+    Literal(Box<Element>),
+}
+
+impl Operand {
+    fn evaluate<'a>(&'a self, element: &'a Element) -> Vec<&'a Element> {
+        match self {
+            Operand::Path(steps) => evaluate_path(steps, element),
+            Operand::Literal(literal) => vec![literal],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    Compare(Operand, Comparison, Operand),
+    Not(Box<Condition>),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+impl Condition {
+    fn matches(&self, element: &Element) -> bool {
+        match self {
+            Condition::Compare(left, comparison, right) => {
+                let right = right.evaluate(element);
+                left.evaluate(element).into_iter().any(|left| {
+                    right
+                        .iter()
+                        .any(|right| comparison.holds(compare(left, right)))
+                })
+            }
+            Condition::Not(condition) => !condition.matches(element),
+            Condition::And(left, right) => left.matches(element) && right.matches(element),
+            Condition::Or(left, right) => left.matches(element) || right.matches(element),
+        }
+    }
+}
+
+/// Compares two values, returning `None` if they cannot be ordered relative to one another.
+fn compare(left: &Element, right: &Element) -> Option<Ordering> {
+    use IonType::*;
+    if left.is_null() || right.is_null() {
+        return (left.is_null() && right.is_null()).then_some(Ordering::Equal);
+    }
+    match (left.ion_type(), right.ion_type()) {
+        (Int, Int) => Some(left.as_int()?.cmp(right.as_int()?)),
+        (Int | Decimal, Int | Decimal) => Some(to_decimal(left)?.cmp(&to_decimal(right)?)),
+        (Int | Decimal | Float, Int | Decimal | Float) => {
+            to_f64(left)?.partial_cmp(&to_f64(right)?)
+        }
+        (String | Symbol, String | Symbol) => Some(left.as_text()?.cmp(right.as_text()?)),
+        (Timestamp, Timestamp) => left.as_timestamp()?.partial_cmp(&right.as_timestamp()?),
+        (Bool, Bool) => Some(left.as_bool()?.cmp(&right.as_bool()?)),
+        _ => (left == right).then_some(Ordering::Equal),
+    }
+}
+
+// Converts an int or decimal to a `Decimal` so that the two types can be compared exactly.
+fn to_decimal(element: &Element) -> Option<Decimal> {
+    element
+        .as_int()
+        .map(|int| Decimal::from(*int))
+        .or_else(|| element.as_decimal())
+}
+
+fn to_f64(element: &Element) -> Option<f64> {
+    element
+        .as_float()
+        .or_else(|| to_decimal(element).map(|decimal| decimal.to_f64()))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Identifier(String),
+    Literal(Element),
+    Dot,
+    LeftBracket,
+    RightBracket,
+    LeftParen,
+    RightParen,
+    Star,
+    At,
+    Comparison(Comparison),
+    Where,
+    And,
+    Or,
+    Not,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Identifier(name) => write!(f, "{name}"),
+            Token::Literal(literal) => write!(f, "{literal}"),
+            Token::Dot => write!(f, "."),
+            Token::LeftBracket => write!(f, "["),
+            Token::RightBracket => write!(f, "]"),
+            Token::LeftParen => write!(f, "("),
+            Token::RightParen => write!(f, ")"),
+            Token::Star => write!(f, "*"),
+            Token::At => write!(f, "@"),
+            Token::Comparison(comparison) => {
+                let text = match comparison {
+                    Comparison::Eq => "=",
+                    Comparison::Ne => "!=",
+                    Comparison::Lt => "<",
+                    Comparison::Le => "<=",
+                    Comparison::Gt => ">",
+                    Comparison::Ge => ">=",
+                };
+                write!(f, "{text}")
+            }
+            Token::Where => write!(f, "where"),
+            Token::And => write!(f, "and"),
+            Token::Or => write!(f, "or"),
+            Token::Not => write!(f, "not"),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> IonResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '.' => Token::Dot,
+            '[' => Token::LeftBracket,
+            ']' => Token::RightBracket,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '*' => Token::Star,
+            '@' => Token::At,
+            '=' | '!' | '<' | '>' => {
+                let followed_by_equals = chars.next_if(|(_, next)| *next == '=').is_some();
+                let comparison = match (c, followed_by_equals) {
+                    ('=', _) => Comparison::Eq,
+                    ('!', true) => Comparison::Ne,
+                    ('<', false) => Comparison::Lt,
+                    ('<', true) => Comparison::Le,
+                    ('>', false) => Comparison::Gt,
+                    ('>', true) => Comparison::Ge,
+                    _ => return IonResult::decoding_error("invalid query: expected '!='"),
+                };
+                Token::Comparison(comparison)
+            }
+            '"' | '\'' => {
+                let mut escaped = false;
+                let end = loop {
+                    match chars.next() {
+                        Some((index, next)) if next == c && !escaped => break index + 1,
+                        Some((_, next)) => escaped = next == '\\' && !escaped,
+                        None => {
+                            return IonResult::decoding_error(
+                                "invalid query: unterminated quoted text",
+                            )
+                        }
+                    }
+                };
+                Token::Literal(parse_literal(&text[start..end])?)
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '+' => {
+                let mut end = start + c.len_utf8();
+                while let Some((index, next)) = chars
+                    .next_if(|(_, next)| next.is_ascii_alphanumeric() || "._+-:".contains(*next))
+                {
+                    end = index + next.len_utf8();
+                }
+                let literal = &text[start..end];
+                let element = parse_literal(literal)?;
+                if !matches!(
+                    element.ion_type(),
+                    IonType::Int | IonType::Decimal | IonType::Float | IonType::Timestamp
+                ) {
+                    return IonResult::decoding_error(format!(
+                        "invalid query: '{literal}' is not a number or timestamp"
+                    ));
+                }
+                Token::Literal(element)
+            }
+            c if c.is_alphabetic() || c == '_' || c == '$' => {
+                let mut end = start + c.len_utf8();
+                while let Some((index, next)) = chars
+                    .next_if(|(_, next)| next.is_alphanumeric() || *next == '_' || *next == '$')
+                {
+                    end = index + next.len_utf8();
+                }
+                let word = &text[start..end];
+                match word.to_ascii_lowercase().as_str() {
+                    "where" => Token::Where,
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    _ => match word {
+                        "true" => Token::Literal(Element::boolean(true)),
+                        "false" => Token::Literal(Element::boolean(false)),
+                        "null" => Token::Literal(Element::null(IonType::Null)),
+                        _ => Token::Identifier(word.to_owned()),
+                    },
+                }
+            }
+            c => {
+                return IonResult::decoding_error(format!("invalid query: unexpected '{c}'"));
+            }
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn parse_literal(text: &str) -> IonResult<Element> {
+    Element::read_one(text)
+        .map_err(|_| IonError::decoding_error(format!("invalid query: invalid literal '{text}'")))
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn next_if(&mut self, expected: &Token) -> bool {
+        let matches = self.peek() == Some(expected);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+
+    fn expect(&mut self, expected: Token) -> IonResult<()> {
+        if self.next_if(&expected) {
+            return Ok(());
+        }
+        match self.peek() {
+            Some(token) => IonResult::decoding_error(format!(
+                "invalid query: expected '{expected}' but found '{token}'"
+            )),
+            None => IonResult::decoding_error(format!(
+                "invalid query: expected '{expected}' but the query ended"
+            )),
+        }
+    }
+
+    fn parse_path(&mut self) -> IonResult<Vec<Step>> {
+        let mut steps = Vec::new();
+        match self.advance() {
+            Some(Token::At) => {}
+            // A leading `.name` or `.*` step is parsed by the loop below.
+            Some(Token::Dot) => self.position -= 1,
+            Some(Token::Identifier(name)) => steps.push(Step::Field(name)),
+            Some(Token::Star) => steps.push(Step::Wildcard),
+            Some(Token::LeftBracket) => steps.push(self.parse_bracketed_step()?),
+            Some(token) => {
+                return IonResult::decoding_error(format!(
+                    "invalid query: expected a path but found '{token}'"
+                ))
+            }
+            None => return IonResult::decoding_error("invalid query: expected a path"),
+        }
+        loop {
+            if self.next_if(&Token::Dot) {
+                let step = match self.advance() {
+                    Some(Token::Identifier(name)) => Step::Field(name),
+                    Some(Token::Star) => Step::Wildcard,
+                    _ => {
+                        return IonResult::decoding_error(
+                            "invalid query: expected a field name or '*' after '.'",
+                        )
+                    }
+                };
+                steps.push(step);
+            } else if self.next_if(&Token::LeftBracket) {
+                steps.push(self.parse_bracketed_step()?);
+            } else {
+                return Ok(steps);
+            }
+        }
+    }
+
+    // Parses the rest of a `[...]` step after its opening bracket.
+    fn parse_bracketed_step(&mut self) -> IonResult<Step> {
+        let step = match self.advance() {
+            Some(Token::Star) => Step::Wildcard,
+            Some(Token::Literal(literal)) => {
+                if let Some(name) = literal.as_text() {
+                    Step::Field(name.to_owned())
+                } else if let Some(index) = literal.as_int().and_then(|int| int.as_usize()) {
+                    Step::Index(index)
+                } else {
+                    return IonResult::decoding_error(format!(
+                        "invalid query: '{literal}' is not a position or field name"
+                    ));
+                }
+            }
+            _ => {
+                return IonResult::decoding_error(
+                    "invalid query: expected a position, quoted field name, or '*' after '['",
+                )
+            }
+        };
+        self.expect(Token::RightBracket)?;
+        Ok(step)
+    }
+
+    fn parse_condition(&mut self) -> IonResult<Condition> {
+        let mut condition = self.parse_conjunction()?;
+        while self.next_if(&Token::Or) {
+            let right = self.parse_conjunction()?;
+            condition = Condition::Or(Box::new(condition), Box::new(right));
+        }
+        Ok(condition)
+    }
+
+    fn parse_conjunction(&mut self) -> IonResult<Condition> {
+        let mut condition = self.parse_unary_condition()?;
+        while self.next_if(&Token::And) {
+            let right = self.parse_unary_condition()?;
+            condition = Condition::And(Box::new(condition), Box::new(right));
+        }
+        Ok(condition)
+    }
+
+    fn parse_unary_condition(&mut self) -> IonResult<Condition> {
+        if self.next_if(&Token::Not) {
+            return Ok(Condition::Not(Box::new(self.parse_unary_condition()?)));
+        }
+        if self.next_if(&Token::LeftParen) {
+            let condition = self.parse_condition()?;
+            self.expect(Token::RightParen)?;
+            return Ok(condition);
+        }
+        let left = self.parse_operand()?;
+        let comparison = match self.advance() {
+            Some(Token::Comparison(comparison)) => comparison,
+            _ => return IonResult::decoding_error("invalid query: expected a comparison operator"),
+        };
+        let right = self.parse_operand()?;
+        Ok(Condition::Compare(left, comparison, right))
+    }
+
+    fn parse_operand(&mut self) -> IonResult<Operand> {
+        if let Some(Token::Literal(literal)) = self.peek() {
+            let literal = literal.clone();
+            self.position += 1;
+            return Ok(Operand::Literal(Box::new(literal)));
+        }
+        Ok(Operand::Path(self.parse_path()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{AnyEncoding, Reader};
+
+    const LOG: &str = r#"
+        {
+            service: "checkout",
+            requests: [
+                {path: "/", status: 200, ms: 12, tags: [fast]},
+                {path: "/login", status: 503, ms: 3004.5, tags: [slow, retry]},
+                {path: "/search", status: 500, ms: 87d0, tags: []},
+                {path: "/cart", status: null, ms: 40e0},
+            ],
+            at: 2024-05-01T10:00Z,
+        }
+    "#;
+
+    fn select(query: &str) -> IonResult<Vec<Element>> {
+        let log = Element::read_one(LOG)?;
+        let results = Query::new(query)?
+            .select(&log)
+            .into_iter()
+            .cloned()
+            .collect();
+        Ok(results)
+    }
+
+    fn elements(text: &str) -> Vec<Element> {
+        Element::read_all(text).unwrap().into_iter().collect()
+    }
+
+    #[rstest]
+    #[case::field("service", "\"checkout\"")]
+    #[case::dotted_field(".service", "\"checkout\"")]
+    #[case::itself("@ where service = \"checkout\"", LOG)]
+    #[case::index("requests[1].path", "\"/login\"")]
+    #[case::quoted_field("[\"requests\"][0]['path']", "\"/\"")]
+    #[case::wildcard("requests[*].status", "200 503 500 null")]
+    #[case::dotted_wildcard("requests[0].*", "\"/\" 200 12 [fast]")]
+    #[case::nested_wildcards("requests[*].tags[*]", "fast slow retry")]
+    #[case::missing("requests[9].path", "")]
+    #[case::filter_int("requests[*].path where status >= 500", "\"/login\" \"/search\"")]
+    #[case::filter_mixed_numbers("requests[*].path where ms > 50", "\"/login\" \"/search\"")]
+    #[case::filter_decimal("requests[*].path where ms = 12.0", "\"/\"")]
+    #[case::filter_text("requests[*].status where path = '/search'", "500")]
+    #[case::filter_null("requests[*].path where status = null", "\"/cart\"")]
+    #[case::filter_any("requests[*].path where tags[*] = 'retry'", "\"/login\"")]
+    #[case::filter_and("requests[*].ms where status >= 500 and ms < 100", "87d0")]
+    #[case::filter_or("requests[*].ms where status = 200 or ms = 40", "12 40e0")]
+    #[case::filter_not("requests[*].path where not (status >= 500)", "\"/\" \"/cart\"")]
+    #[case::precedence(
+        "requests[*].path where status = 200 or status = 500 and ms < 10",
+        "\"/\""
+    )]
+    #[case::filter_root("service where at < 2025-01-01T", "\"checkout\"")]
+    #[case::filter_root_fails("service where at > 2025-01-01T", "")]
+    #[case::keywords_in_any_case("requests[*].status WHERE path = \"/\" AND ms > 0", "200")]
+    #[case::literal_on_left("requests[*].path where 100 > ms", "\"/\" \"/search\" \"/cart\"")]
+    fn selects(#[case] query: &str, #[case] expected: &str) -> IonResult<()> {
+        assert_eq!(select(query)?, elements(expected));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::dangling_dot("requests.")]
+    #[case::unclosed_bracket("requests[0")]
+    #[case::negative_index("requests[-1]")]
+    #[case::missing_condition("requests where")]
+    #[case::missing_operator("requests where status 200")]
+    #[case::unclosed_paren("requests where (status = 200")]
+    #[case::unterminated_string("requests where path = \"/")]
+    #[case::invalid_character("requests where status # 200")]
+    #[case::trailing_tokens("requests[0] path")]
+    #[case::lone_dot(".")]
+    #[case::lone_bang("requests where status ! 200")]
+    fn rejects(#[case] query: &str) {
+        assert!(Query::new(query).is_err(), "{query:?} should be rejected");
+    }
+
+    #[test]
+    fn select_from_reader() -> IonResult<()> {
+        let stream = r#"
+            {level: info, message: "started"}
+            {level: error, message: "disk full"}
+            {level: error, message: "retrying"}
+        "#;
+        let query: Query = "message where level = 'error'".parse()?;
+        let mut reader = Reader::new(AnyEncoding, stream)?;
+        let messages = query
+            .select_from(&mut reader)
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(messages, elements("\"disk full\" \"retrying\""));
+        Ok(())
+    }
+
+    #[test]
+    fn select_from_reports_errors() -> IonResult<()> {
+        let query = Query::new("@")?;
+        let mut reader = Reader::new(AnyEncoding, "1 2 {")?;
+        let results: Vec<_> = query.select_from(&mut reader).collect();
+        assert_eq!(results.len(), 3);
+        assert!(results[2].is_err());
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@
 //! as slices or files.
 
 use crate::result::{IonFailure, IonResult};
-use crate::{Element, Sequence};
+use crate::{Element, Sequence, TextInterner};
 
 /// Reads Ion data into [`Element`] instances.
 ///
@@ -20,6 +20,56 @@ pub trait ElementReader {
     /// If an error occurs while the data is being read, returns `Err(IonError)`.
     fn read_next_element(&mut self) -> IonResult<Option<Element>>;
 
+    /// Like [Self::read_next_element], but the next Ion value is stored in `element`, replacing its
+    /// previous contents. Returns `Ok(true)` if a value was read or `Ok(false)` if there is no more
+    /// data left to be read, in which case `element` is unchanged.
+    ///
+    /// Readers that support it recycle the storage that `element` already holds--the text of
+    /// strings, the bytes of lobs, and the children of containers--instead of allocating new
+    /// storage for each value. Reusing a single `Element` across iterations of a loop that reads
+    /// many similarly shaped values can therefore avoid most of the cost of allocation. If an error
+    /// occurs, the contents of `element` are unspecified.
+    ///
+    /// The default implementation reads a new `Element` and moves it into `element`.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{AnyEncoding, Element, ElementReader, Reader};
+    ///
+    /// let mut reader = Reader::new(AnyEncoding, r#"{id: 1, tags: ["a"]} {id: 2, tags: ["b", "c"]}"#)?;
+    /// let mut record = Element::null(ion_rs::IonType::Null);
+    /// let mut tag_count = 0;
+    /// while reader.read_next_element_into(&mut record)? {
+    ///     tag_count += record.as_struct().unwrap().get("tags").unwrap().as_sequence().unwrap().len();
+    /// }
+    /// assert_eq!(tag_count, 3);
+    /// assert_eq!(record, Element::read_one(r#"{id: 2, tags: ["b", "c"]}"#)?);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    fn read_next_element_into(&mut self, element: &mut Element) -> IonResult<bool> {
+        match self.read_next_element()? {
+            Some(next) => {
+                *element = next;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Advances past the next Ion value without returning it. Returns `Ok(true)` if a value was
+    /// skipped or `Ok(false)` if there is no more data left to be read.
+    ///
+    /// The default implementation materializes the value and discards it; readers that can step
+    /// over a value without decoding its contents should override this method.
+    fn skip_next_element(&mut self) -> IonResult<bool> {
+        Ok(self.read_next_element()?.is_some())
+    }
+
     /// Returns an iterator over the [Element]s in the data stream.
     fn elements(&mut self) -> Self::ElementIterator<'_>;
 
@@ -67,12 +117,37 @@ pub trait ElementReader {
     fn read_all_elements(&mut self) -> IonResult<Sequence> {
         self.elements().collect()
     }
+
+    /// Like [Self::read_next_element], but the text of the resulting `Element`'s field names,
+    /// annotations, symbols, and strings is stored in (or shared with) the provided
+    /// [`TextInterner`].
+    ///
+    /// The default implementation materializes the value as usual and then copies it into the
+    /// interner; readers that can intern text as it is read should override this method.
+    fn read_next_element_interned(
+        &mut self,
+        interner: &mut TextInterner,
+    ) -> IonResult<Option<Element>> {
+        Ok(self
+            .read_next_element()?
+            .map(|element| interner.intern_element(&element)))
+    }
+
+    /// Reads all of the remaining values using
+    /// [`read_next_element_interned`](Self::read_next_element_interned).
+    fn read_all_elements_interned(&mut self, interner: &mut TextInterner) -> IonResult<Sequence> {
+        let mut elements = Vec::new();
+        while let Some(element) = self.read_next_element_interned(interner)? {
+            elements.push(element);
+        }
+        Ok(elements.into())
+    }
 }
 
 /// Holds a reference to a given [ElementReader] implementation and yields one [Element] at a time
 /// until the stream is exhausted or invalid data is encountered.
 pub struct ElementIterator<'a, R: ElementReader + ?Sized> {
-    reader: &'a mut R,
+    pub(crate) reader: &'a mut R,
 }
 
 impl<'a, R: ElementReader + ?Sized> Iterator for ElementIterator<'a, R> {
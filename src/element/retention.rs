@@ -0,0 +1,241 @@
+//! Provides [`RetentionFilter`], which selects the values in a stream whose timestamps fall within
+//! a window of time.
+
+use crate::element::reader::ElementReader;
+use crate::result::IonFailure;
+use crate::{Element, IonResult, Timestamp};
+
+/// What a [`RetentionFilter`] should do with a value that does not have a timestamp at its path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissingTimestamp {
+    /// Keep the value.
+    Retain,
+    /// Discard the value.
+    Drop,
+    /// Stop filtering and return an error.
+    #[default]
+    Error,
+}
+
+/// Retains the values in a stream whose timestamp (found by following a path of field names)
+/// falls within a window of time.
+///
+/// Timestamps are compared by the instant that they represent, so values recorded with different
+/// offsets or with different fractional seconds precision are placed in the window correctly.
+/// A timestamp with an unknown offset (`-00:00`) is treated as UTC, and a timestamp with less than
+/// full precision (for example, `2024-05T`) represents the first instant of its period.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # #[cfg(feature = "experimental-element-apis")]
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::{AnyEncoding, Reader, RetentionFilter, Timestamp};
+///
+/// let data = r#"
+///     {id: 1, meta: {created: 2023-12-31T23:30-02:00}}
+///     {id: 2, meta: {created: 2024-06-15T}}
+///     {id: 3, meta: {created: 2025-01-01T00:00Z}}
+/// "#;
+/// let filter = RetentionFilter::new(["meta", "created"])
+///     .not_before(Timestamp::with_year(2024).build()?)
+///     .before(Timestamp::with_year(2025).build()?);
+///
+/// let mut reader = Reader::new(AnyEncoding, data)?;
+/// let ids = filter
+///     .filter(&mut reader)
+///     .map(|element| element?.as_struct().unwrap().get("id").unwrap().expect_i64())
+///     .collect::<IonResult<Vec<i64>>>()?;
+///
+/// // 2023-12-31T23:30-02:00 is 2024-01-01T01:30Z, so the first value is in the window.
+/// assert_eq!(ids, vec![1, 2]);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "experimental-element-apis"))]
+/// # fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetentionFilter {
+    path: Vec<String>,
+    start: Option<Timestamp>,
+    end: Option<Timestamp>,
+    inverted: bool,
+    missing: MissingTimestamp,
+}
+
+impl RetentionFilter {
+    /// Constructs a filter that finds each value's timestamp by following `path`, a sequence of
+    /// struct field names. If `path` is empty, each value must itself be a timestamp.
+    ///
+    /// The window is initially unbounded, so every value with a timestamp is retained.
+    pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(path: I) -> Self {
+        Self {
+            path: path.into_iter().map(Into::into).collect(),
+            start: None,
+            end: None,
+            inverted: false,
+            missing: MissingTimestamp::default(),
+        }
+    }
+
+    /// Sets the (inclusive) start of the window.
+    pub fn not_before(mut self, start: Timestamp) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the (exclusive) end of the window.
+    pub fn before(mut self, end: Timestamp) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Drops the values whose timestamps fall within the window and retains the rest.
+    pub fn inverted(mut self) -> Self {
+        self.inverted = !self.inverted;
+        self
+    }
+
+    /// Sets how values without a timestamp at the configured path are handled. A `null.timestamp`
+    /// counts as a missing timestamp. By default, such values cause an error.
+    pub fn on_missing(mut self, missing: MissingTimestamp) -> Self {
+        self.missing = missing;
+        self
+    }
+
+    /// Returns `true` if `timestamp` falls within the window.
+    pub fn contains(&self, timestamp: &Timestamp) -> bool {
+        self.start.as_ref().map_or(true, |start| timestamp >= start)
+            && self.end.as_ref().map_or(true, |end| timestamp < end)
+    }
+
+    /// Returns `true` if `element` should be retained.
+    pub fn retains(&self, element: &Element) -> IonResult<bool> {
+        let mut value = Some(element);
+        for name in &self.path {
+            value = value
+                .and_then(|value| value.as_struct())
+                .and_then(|fields| fields.get(name.as_str()));
+        }
+        match value.and_then(|value| value.as_timestamp()) {
+            Some(timestamp) => Ok(self.contains(&timestamp) != self.inverted),
+            None => match self.missing {
+                MissingTimestamp::Retain => Ok(true),
+                MissingTimestamp::Drop => Ok(false),
+                MissingTimestamp::Error => IonResult::decoding_error(format!(
+                    "value has no timestamp at path '{}': {element}",
+                    self.path.join(".")
+                )),
+            },
+        }
+    }
+
+    /// Returns an iterator over the values in `reader` that should be retained.
+    pub fn filter<'a, R: ElementReader + ?Sized>(
+        &'a self,
+        reader: &'a mut R,
+    ) -> RetainedElements<'a, R> {
+        RetainedElements {
+            filter: self,
+            reader,
+        }
+    }
+}
+
+/// An iterator over the values in a stream that a [`RetentionFilter`] retains. Values are read
+/// one at a time, so the stream does not need to fit in memory.
+pub struct RetainedElements<'a, R: ElementReader + ?Sized> {
+    filter: &'a RetentionFilter,
+    reader: &'a mut R,
+}
+
+impl<'a, R: ElementReader + ?Sized> Iterator for RetainedElements<'a, R> {
+    type Item = IonResult<Element>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let element = match self.reader.read_next_element() {
+                Ok(Some(element)) => element,
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            };
+            match self.filter.retains(&element) {
+                Ok(true) => return Some(Ok(element)),
+                Ok(false) => continue,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{AnyEncoding, Reader};
+
+    fn retained(filter: &RetentionFilter, data: &str) -> IonResult<Vec<Element>> {
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        filter.filter(&mut reader).collect()
+    }
+
+    fn window() -> IonResult<RetentionFilter> {
+        Ok(RetentionFilter::new(["at"])
+            .not_before(Timestamp::with_ymd(2024, 1, 1).build()?)
+            .before(Timestamp::with_ymd(2024, 2, 1).build()?))
+    }
+
+    #[rstest]
+    #[case::start_is_inclusive("2024-01-01T", true)]
+    #[case::end_is_exclusive("2024-02-01T", false)]
+    #[case::lower_precision("2024-01T", true)]
+    #[case::year_precision_before_start("2023T", false)]
+    #[case::positive_offset_before_start("2024-01-01T00:30+01:00", false)]
+    #[case::negative_offset_after_start("2023-12-31T23:30-01:00", true)]
+    #[case::unknown_offset("2024-01-31T23:59:59.999999999-00:00", true)]
+    #[case::fractional_seconds_at_end("2024-02-01T00:00:00.000Z", false)]
+    fn window_boundaries(#[case] timestamp: &str, #[case] expected: bool) -> IonResult<()> {
+        let element = Element::read_one(format!("{{at: {timestamp}}}"))?;
+        assert_eq!(window()?.retains(&element)?, expected);
+        assert_eq!(window()?.inverted().retains(&element)?, !expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::retain(MissingTimestamp::Retain, 3)]
+    #[case::drop(MissingTimestamp::Drop, 1)]
+    fn missing_timestamps(
+        #[case] missing: MissingTimestamp,
+        #[case] expected: usize,
+    ) -> IonResult<()> {
+        let data = r#"{at: 2024-01-15T} {at: null.timestamp} {when: 2024-01-15T}"#;
+        let filter = window()?.on_missing(missing);
+        assert_eq!(retained(&filter, data)?.len(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_timestamp_is_an_error_by_default() -> IonResult<()> {
+        let data = r#"{at: 2024-01-15T} {at: "2024-01-15"}"#;
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let filter = window()?;
+        let mut retained = filter.filter(&mut reader);
+        assert!(retained.next().unwrap().is_ok());
+        assert!(retained.next().unwrap().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn empty_path() -> IonResult<()> {
+        let filter =
+            RetentionFilter::new(Vec::<String>::new()).before(Timestamp::with_year(2000).build()?);
+        let retained = retained(&filter, "1999T 2000T 1970-01-01T")?;
+        assert_eq!(
+            retained,
+            Element::read_all("1999T 1970-01-01T")?
+                .into_iter()
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+}
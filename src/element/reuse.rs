@@ -0,0 +1,221 @@
+//! Materializes values into recycled `Element` storage.
+
+use std::collections::{HashSet, VecDeque};
+use std::mem;
+use std::vec::Drain;
+
+use crate::element::materialize::ElementHooks;
+use crate::lazy::decoder::Decoder;
+use crate::{Bytes, Element, Sequence, Str, Struct, Symbol, SymbolToken, Value};
+
+/// Holds the storage of `Element`s that are no longer needed and materializes new `Element`s
+/// using it.
+///
+/// [`recycle`](Self::recycle) takes an `Element` apart, keeping the text of its strings, the bytes
+/// of its lobs, the storage of its containers, and its symbols. Materializing with the recycler
+/// then draws on that storage before allocating more. Storage is handed out in the order in which
+/// it was recycled, so when successive values have similar shapes, each part of a new value tends
+/// to receive the storage of the corresponding part of the old one.
+#[derive(Default)]
+pub(crate) struct Recycler {
+    strings: VecDeque<String>,
+    lobs: VecDeque<Vec<u8>>,
+    sequences: VecDeque<Vec<Element>>,
+    structs: VecDeque<Struct>,
+    // The symbols of the most recently recycled element, which are reused by any new symbols that
+    // have the same text.
+    symbols: HashSet<Symbol>,
+}
+
+impl Recycler {
+    /// Takes `element` apart, keeping its storage for reuse.
+    pub(crate) fn recycle(&mut self, element: Element) {
+        self.symbols.clear();
+        self.recycle_element(element);
+    }
+
+    fn recycle_element(&mut self, element: Element) {
+        let Element {
+            mut annotations,
+            value,
+        } = element;
+        for annotation in annotations.symbols_mut().drain(..) {
+            self.recycle_symbol(annotation);
+        }
+        match value {
+            Value::Symbol(symbol) => self.recycle_symbol(symbol),
+            Value::String(text) => self.strings.extend(text.into_reusable_string()),
+            Value::Blob(bytes) | Value::Clob(bytes) => self.lobs.extend(bytes.into_reusable_vec()),
+            Value::List(mut sequence) | Value::SExp(mut sequence) => {
+                let mut elements = mem::take(sequence.elements_mut());
+                for child in elements.drain(..) {
+                    self.recycle_element(child);
+                }
+                self.sequences.push_back(elements);
+            }
+            Value::Struct(mut strukt) => {
+                for (name, value) in strukt.drain_fields() {
+                    self.recycle_symbol(name);
+                    self.recycle_element(value);
+                }
+                self.structs.push_back(strukt);
+            }
+            _ => {}
+        }
+    }
+
+    fn recycle_symbol(&mut self, symbol: Symbol) {
+        // A symbol with unknown text and a symbol whose text is empty are looked up by the same
+        // key, so only symbols with known text are kept.
+        if symbol.text().is_some() {
+            self.symbols.insert(symbol);
+        }
+    }
+}
+
+impl<'top, D: Decoder> ElementHooks<'top, D> for Recycler {
+    fn symbol(&mut self, symbol: SymbolToken<'top>) -> Symbol {
+        match symbol.text().and_then(|text| self.symbols.get(text)) {
+            // Cloning a symbol shares its text.
+            Some(recycled) => recycled.clone().with_encoding(symbol.symbol_id()),
+            None => symbol.into(),
+        }
+    }
+
+    fn string(&mut self, text: &str) -> Str {
+        let mut string = self.strings.pop_front().unwrap_or_default();
+        string.clear();
+        string.push_str(text);
+        string.into()
+    }
+
+    fn lob(&mut self, data: &[u8]) -> Bytes {
+        let mut bytes = self.lobs.pop_front().unwrap_or_default();
+        bytes.clear();
+        bytes.extend_from_slice(data);
+        bytes.into()
+    }
+
+    fn sequence(&mut self, elements: Drain<'_, Element>) -> Sequence {
+        let mut sequence = self.sequences.pop_front().unwrap_or_default();
+        sequence.extend(elements);
+        sequence.into()
+    }
+
+    fn structure(&mut self, fields: Drain<'_, (Symbol, Element)>) -> Struct {
+        let mut strukt = self
+            .structs
+            .pop_front()
+            .unwrap_or_else(|| Struct::builder().build());
+        strukt.extend_fields(fields);
+        strukt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, AnyEncoding, ElementReader, IonResult, IonType, Reader};
+
+    // Each value's shape differs from the one before it in some way.
+    const SOURCE: &str = r#"
+        {id: 1, name: "first", tags: [a, b], payload: {{ aGVsbG8= }}}
+        {id: 2, name: "second record", tags: [c], payload: {{ "clob" }}}
+        {id: 3, tags: (d e f), name: null.string, extra: x::y::{nested: [1, [2]]}}
+        rec::{id: 4, name: "fourth", tags: [], payload: {{ }}}
+        [1, "two", three, {four: 4}]
+        ("one" 2)
+        "plain string"
+        sym
+        null
+        {}
+    "#;
+
+    fn read_reusing(data: &[u8]) -> IonResult<Vec<Element>> {
+        let mut reader = Reader::new(AnyEncoding, data)?;
+        let mut element = Element::null(IonType::Null);
+        let mut elements = Vec::new();
+        while reader.read_next_element_into(&mut element)? {
+            elements.push(element.clone());
+        }
+        Ok(elements)
+    }
+
+    #[test]
+    fn reused_elements_match_fresh_elements() -> IonResult<()> {
+        let expected = Element::read_all(SOURCE)?;
+        assert_eq!(
+            read_reusing(SOURCE.as_bytes())?,
+            expected.elements().cloned().collect::<Vec<_>>()
+        );
+        let binary = expected.encode_as(v1_0::Binary)?;
+        assert_eq!(
+            read_reusing(&binary)?,
+            expected.elements().cloned().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn storage_is_recycled() -> IonResult<()> {
+        let mut reader = Reader::new(
+            AnyEncoding,
+            r#"{name: "a long enough name", tags: [x, y]} {name: "short", tags: [z]}"#,
+        )?;
+        let mut element = Element::null(IonType::Null);
+        assert!(reader.read_next_element_into(&mut element)?);
+        let fields = element.as_struct().unwrap();
+        let name = fields.get("name").unwrap().as_string().unwrap().as_ptr();
+        let tags = fields
+            .get("tags")
+            .unwrap()
+            .as_sequence()
+            .unwrap()
+            .get(0)
+            .unwrap() as *const _;
+        let field_name = fields.iter().next().unwrap().0.clone();
+
+        assert!(reader.read_next_element_into(&mut element)?);
+        let fields = element.as_struct().unwrap();
+        assert_eq!(fields.get("name").unwrap().as_string(), Some("short"));
+        assert_eq!(
+            fields.get("name").unwrap().as_string().unwrap().as_ptr(),
+            name
+        );
+        let new_tags = fields.get("tags").unwrap().as_sequence().unwrap();
+        assert_eq!(new_tags.len(), 1);
+        assert_eq!(new_tags.get(0).unwrap() as *const _, tags);
+        // The field name's text was unchanged, so the same symbol was kept.
+        let new_field_name = fields.iter().next().unwrap().0;
+        assert_eq!(
+            new_field_name.text().unwrap().as_ptr(),
+            field_name.text().unwrap().as_ptr()
+        );
+
+        assert!(!reader.read_next_element_into(&mut element)?);
+        assert_eq!(element, Element::read_one(r#"{name: "short", tags: [z]}"#)?);
+        Ok(())
+    }
+
+    #[test]
+    fn struct_index_is_rebuilt() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "{a: 1, b: 2} {b: 3, c: 4, c: 5}")?;
+        let mut element = Element::null(IonType::Null);
+        reader.read_next_element_into(&mut element)?;
+        reader.read_next_element_into(&mut element)?;
+        let fields = element.as_struct().unwrap();
+        assert!(fields.get("a").is_none());
+        assert_eq!(fields.get("b"), Some(&Element::int(3)));
+        assert_eq!(fields.get_all("c").count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn errors_are_reported() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "{a: 1} {a: [1, 2")?;
+        let mut element = Element::null(IonType::Null);
+        assert!(reader.read_next_element_into(&mut element)?);
+        assert!(reader.read_next_element_into(&mut element).is_err());
+        Ok(())
+    }
+}
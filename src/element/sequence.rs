@@ -7,6 +7,7 @@ use crate::write_config::WriteConfig;
 use crate::IonResult;
 use std::cmp::Ordering;
 use std::io;
+use std::ops::{Index, RangeBounds};
 
 /// An iterable, addressable series of Ion [`Element`]s.
 ///
@@ -31,6 +32,11 @@ impl Sequence {
         SequenceBuilder::with_initial_elements(&self.elements)
     }
 
+    /// Provides mutable access to the elements so that they can be overwritten in place.
+    pub(crate) fn elements_mut(&mut self) -> &mut Vec<Element> {
+        &mut self.elements
+    }
+
     pub fn elements(&self) -> SequenceIterator<'_> {
         SequenceIterator::new(&self.elements)
     }
@@ -51,6 +57,98 @@ impl Sequence {
         self.elements()
     }
 
+    /// Sorts the elements using Ion's total ordering (see [`IonData`](crate::IonData)). The sort
+    /// is stable, so Ion equivalent elements keep their relative order. Nested containers are not
+    /// sorted.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    ///
+    /// let mut sequence = Element::read_all("3 b 1 a 2.0 [2] [1]")?;
+    /// sequence.sort();
+    /// assert_eq!(sequence, Element::read_all("1 3 2.0 a b [1] [2]")?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn sort(&mut self) {
+        self.elements.sort_by(IonOrd::ion_cmp)
+    }
+
+    /// Sorts the elements using the provided comparison function. The sort is stable.
+    pub fn sort_by<F: FnMut(&Element, &Element) -> Ordering>(&mut self, compare: F) {
+        self.elements.sort_by(compare)
+    }
+
+    /// Removes each element for which `keep` returns `false`, preserving the order of the
+    /// remaining elements.
+    pub fn retain<F: FnMut(&Element) -> bool>(&mut self, keep: F) {
+        self.elements.retain(keep)
+    }
+
+    /// Replaces the elements in `range` with those in `replacement`, returning the elements that
+    /// were removed. The replacement does not need to be the same length as the range.
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Element;
+    ///
+    /// let mut sequence = Element::read_all("1 2 3 4")?;
+    /// let removed = sequence.splice(1..3, [Element::symbol("two_and_three")]);
+    /// assert_eq!(removed, Element::read_all("2 3")?);
+    /// assert_eq!(sequence, Element::read_all("1 two_and_three 4")?);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn splice<R, E, I>(&mut self, range: R, replacement: I) -> Sequence
+    where
+        R: RangeBounds<usize>,
+        E: Into<Element>,
+        I: IntoIterator<Item = E>,
+    {
+        self.elements
+            .splice(range, replacement.into_iter().map(Into::into))
+            .collect()
+    }
+
+    /// Removes consecutive elements that are Ion equivalent, keeping the first of each run.
+    /// Sorting the sequence first (see [`Self::sort`]) removes all duplicates.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, IonData};
+    ///
+    /// // `1.0` and `1.00` are not Ion equivalent, but `nan` and `nan` are
+    /// let mut sequence = Element::read_all("a a 1.0 1.0 1.00 a nan nan")?;
+    /// sequence.dedup();
+    /// let expected = Element::read_all("a 1.0 1.00 a nan")?;
+    /// assert!(IonData::eq(&sequence, &expected));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a.ion_eq(b))
+    }
+
+    /// Removes consecutive elements for which `same` returns `true`, keeping the first of each
+    /// run. `same` is passed each element along with the last element that was kept.
+    pub fn dedup_by<F: FnMut(&Element, &Element) -> bool>(&mut self, mut same: F) {
+        self.elements.dedup_by(|element, kept| same(element, kept))
+    }
+
+    /// Returns a copy of this sequence whose elements are sorted using Ion's total ordering. See
+    /// [`Self::sort`].
+    pub fn sorted(&self) -> Sequence {
+        let mut sorted = self.clone();
+        sorted.sort();
+        sorted
+    }
+
     /// ```
     ///# use ion_rs::IonResult;
     ///# fn main() -> IonResult<()> {
@@ -108,6 +206,15 @@ impl Sequence {
     }
 }
 
+// Allows `sequence[index]` syntax, which panics if `index` is out of bounds
+impl Index<usize> for Sequence {
+    type Output = Element;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.elements[index]
+    }
+}
+
 impl AsRef<Sequence> for Sequence {
     fn as_ref(&self) -> &Sequence {
         self
@@ -133,8 +240,7 @@ impl<'a> IntoIterator for &'a Sequence {
     }
 }
 
-// TODO: This currently clones elements. We should change `Sequence` to wrap a VecDeque so we can
-//       pop from the front.
+// Moves each element out of the sequence without cloning it.
 impl IntoIterator for Sequence {
     type Item = Element;
     // TODO: Change once `impl Trait` type aliases are stable
@@ -143,8 +249,7 @@ impl IntoIterator for Sequence {
 
     fn into_iter(self) -> Self::IntoIter {
         OwnedSequenceIterator {
-            current: 0,
-            sequence: self,
+            elements: self.elements.into_iter(),
         }
     }
 }
@@ -168,19 +273,25 @@ impl IonOrd for Sequence {
 }
 
 pub struct OwnedSequenceIterator {
-    current: usize,
-    sequence: Sequence,
+    elements: std::vec::IntoIter<Element>,
 }
 
 impl Iterator for OwnedSequenceIterator {
     type Item = Element;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(element) = self.sequence.get(self.current) {
-            self.current += 1;
-            Some(element.clone())
-        } else {
-            None
-        }
+        self.elements.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for OwnedSequenceIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.elements.next_back()
     }
 }
+
+impl ExactSizeIterator for OwnedSequenceIterator {}
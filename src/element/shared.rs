@@ -0,0 +1,180 @@
+//! Provides [`SharedElement`], a reference-counted [`Element`] that can be cloned cheaply and
+//! shared between threads.
+
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::Element;
+
+/// A reference-counted [`Element`].
+///
+/// Cloning an `Element` copies its entire tree. Cloning a `SharedElement` only increments a
+/// reference count, so a large document can be read once and handed to any number of worker
+/// threads. A `SharedElement` dereferences to the `Element` it holds, so all of `Element`'s
+/// accessors are available on it directly.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # #[cfg(feature = "experimental-element-apis")]
+/// # fn main() -> IonResult<()> {
+/// use std::thread;
+/// use ion_rs::{Element, SharedElement};
+///
+/// let document = SharedElement::new(Element::read_one("{orders: [1, 2, 3]}")?);
+/// let workers: Vec<_> = (0..3)
+///     .map(|index| {
+///         let document = document.clone();
+///         thread::spawn(move || {
+///             let orders = document.as_struct().unwrap().get("orders").unwrap();
+///             orders.as_sequence().unwrap().get(index).unwrap().expect_i64()
+///         })
+///     })
+///     .collect();
+/// let total: i64 = workers
+///     .into_iter()
+///     .map(|worker| worker.join().unwrap())
+///     .sum::<IonResult<i64>>()?;
+/// assert_eq!(total, 6);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "experimental-element-apis"))]
+/// # fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Clone)]
+pub struct SharedElement {
+    element: Arc<Element>,
+}
+
+impl SharedElement {
+    pub fn new(element: Element) -> Self {
+        Self {
+            element: Arc::new(element),
+        }
+    }
+
+    pub fn as_element(&self) -> &Element {
+        &self.element
+    }
+
+    /// Returns the `Element`, cloning it only if other `SharedElement`s still refer to it.
+    pub fn into_element(self) -> Element {
+        Arc::try_unwrap(self.element).unwrap_or_else(|element| (*element).clone())
+    }
+
+    /// Returns a mutable reference to the `Element`. If other `SharedElement`s refer to it, it is
+    /// cloned first so that they are unaffected.
+    pub fn make_mut(&mut self) -> &mut Element {
+        Arc::make_mut(&mut self.element)
+    }
+
+    /// Returns `true` if `self` and `other` refer to the same `Element` (rather than merely to
+    /// equal ones).
+    pub fn ptr_eq(&self, other: &SharedElement) -> bool {
+        Arc::ptr_eq(&self.element, &other.element)
+    }
+
+    /// Returns the number of `SharedElement`s that refer to this `Element`.
+    pub fn share_count(&self) -> usize {
+        Arc::strong_count(&self.element)
+    }
+}
+
+impl Deref for SharedElement {
+    type Target = Element;
+
+    fn deref(&self) -> &Self::Target {
+        &self.element
+    }
+}
+
+impl AsRef<Element> for SharedElement {
+    fn as_ref(&self) -> &Element {
+        &self.element
+    }
+}
+
+impl From<Element> for SharedElement {
+    fn from(element: Element) -> Self {
+        SharedElement::new(element)
+    }
+}
+
+impl From<SharedElement> for Element {
+    fn from(shared: SharedElement) -> Self {
+        shared.into_element()
+    }
+}
+
+impl Debug for SharedElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self.as_element(), f)
+    }
+}
+
+impl Display for SharedElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.as_element(), f)
+    }
+}
+
+impl PartialEq for SharedElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || self.element == other.element
+    }
+}
+
+impl Eq for SharedElement {}
+
+impl PartialEq<Element> for SharedElement {
+    fn eq(&self, other: &Element) -> bool {
+        self.as_element() == other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IonResult, Value};
+
+    #[test]
+    fn clones_share_the_element() -> IonResult<()> {
+        let shared = SharedElement::new(Element::read_one("[1, {a: 2}]")?);
+        let clone = shared.clone();
+        assert!(shared.ptr_eq(&clone));
+        assert_eq!(shared.share_count(), 2);
+        assert_eq!(shared, clone);
+        drop(clone);
+        assert_eq!(shared.share_count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn into_element() -> IonResult<()> {
+        let element = Element::read_one("foo::bar")?;
+        let shared = SharedElement::from(element.clone());
+        let clone = shared.clone();
+        // `clone` still refers to the element, so it is copied.
+        assert_eq!(shared.into_element(), element);
+        // `clone` is now the only reference, so the element is moved out.
+        assert_eq!(Element::from(clone), element);
+        Ok(())
+    }
+
+    #[test]
+    fn make_mut_copies_on_write() -> IonResult<()> {
+        let mut shared = SharedElement::new(Element::read_one("1")?);
+        let original = shared.clone();
+        *shared.make_mut() = Element::from(Value::Int(2.into()));
+        assert!(!shared.ptr_eq(&original));
+        assert_eq!(original, Element::int(1));
+        assert_eq!(shared, Element::int(2));
+        Ok(())
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_and_sync<T: Send + Sync>() {}
+        assert_send_and_sync::<SharedElement>();
+    }
+}
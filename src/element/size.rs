@@ -0,0 +1,256 @@
+use std::io;
+
+use crate::binary::decimal::DecimalBinaryEncoder;
+use crate::binary::timestamp::TimestampBinaryEncoder;
+use crate::binary::uint;
+use crate::binary::uint::DecodedUInt;
+use crate::binary::var_uint::VarUInt;
+use crate::lazy::encoder::binary::v1_0::value_writer::MAX_INLINE_LENGTH;
+use crate::symbol_table::SymbolTable;
+use crate::{ion_struct, Element, IonResult, Sequence, SharedSymbolTable, Symbol, SymbolId, Value};
+
+/// The length of the binary Ion 1.0 version marker.
+const IVM_LENGTH: usize = 4;
+
+/// Describes the stream in which a value will be encoded when estimating its size with
+/// [`Element::binary_size_hint`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum SymbolTablePolicy {
+    /// The value is encoded as a complete binary Ion 1.0 stream: an Ion version marker, a local
+    /// symbol table (if needed) defining each symbol the value uses that is not a system symbol, and the
+    /// value itself. This is what [`Element::encode_as`] produces for
+    /// [`v1_0::Binary`](crate::v1_0::Binary).
+    #[default]
+    Standalone,
+    /// Like [`SymbolTablePolicy::Standalone`], but the stream begins with a local symbol table
+    /// that imports the given shared symbol tables (see
+    /// [`WriteConfig::with_symbol_table_import`](crate::WriteConfig::with_symbol_table_import)).
+    /// Symbols found in the imported tables are not defined in the stream.
+    Imports(Vec<SharedSymbolTable>),
+}
+
+/// Computes the size of a value's binary Ion 1.0 encoding, assigning symbol IDs in the same
+/// order that a [`Writer`](crate::Writer) would.
+struct SizeEstimator {
+    symbol_table: SymbolTable,
+    num_pending_symbols: usize,
+}
+
+impl SizeEstimator {
+    fn new(imports: &[SharedSymbolTable]) -> Self {
+        let mut symbol_table = SymbolTable::new();
+        for symbol in imports.iter().flat_map(|table| table.symbols()) {
+            match symbol.text() {
+                Some(_) => symbol_table.add_symbol(symbol.clone()),
+                None => symbol_table.add_placeholder(),
+            };
+        }
+        Self {
+            symbol_table,
+            num_pending_symbols: 0,
+        }
+    }
+
+    fn sid_for(&mut self, symbol: &Symbol) -> SymbolId {
+        let Some(text) = symbol.text() else {
+            return 0;
+        };
+        match self.symbol_table.sid_for(&text) {
+            Some(sid) => sid,
+            None => {
+                self.num_pending_symbols += 1;
+                self.symbol_table.add_symbol_for_text(text)
+            }
+        }
+    }
+
+    fn element_size(&mut self, element: &Element) -> IonResult<usize> {
+        if element.annotations().is_empty() {
+            return self.value_size(element.value());
+        }
+        let annotations_length: usize = element
+            .annotations()
+            .iter()
+            .map(|annotation| var_uint_size(self.sid_for(annotation)))
+            .sum();
+        let wrapped_length = var_uint_size(annotations_length)
+            + annotations_length
+            + self.value_size(element.value())?;
+        Ok(header_size(wrapped_length) + wrapped_length)
+    }
+
+    fn value_size(&mut self, value: &Value) -> IonResult<usize> {
+        let size = match value {
+            Value::Null(_) | Value::Bool(_) => 1,
+            Value::Int(int) => {
+                let magnitude = uint::encode(int.unsigned_abs().data).as_bytes().len();
+                header_size(magnitude) + magnitude
+            }
+            Value::Float(float) => {
+                if *float == 0f64 && !float.is_sign_negative() {
+                    1
+                } else if *float as f32 as f64 == *float {
+                    5
+                } else {
+                    9
+                }
+            }
+            Value::Decimal(decimal) => io::sink().encode_decimal_value(decimal)?,
+            Value::Timestamp(timestamp) => io::sink().encode_timestamp_value(timestamp)?,
+            Value::Symbol(symbol) => {
+                let sid = self.sid_for(symbol);
+                let length = DecodedUInt::write_u64(&mut io::sink(), sid as u64)?;
+                header_size(length) + length
+            }
+            Value::String(text) => lob_size(text.text().len()),
+            Value::Clob(bytes) | Value::Blob(bytes) => lob_size(bytes.as_ref().len()),
+            Value::List(sequence) | Value::SExp(sequence) => {
+                let mut length = 0;
+                for child in sequence {
+                    length += self.element_size(child)?;
+                }
+                header_size(length) + length
+            }
+            Value::Struct(strukt) => {
+                let mut length = 0;
+                for (name, child) in strukt {
+                    length += var_uint_size(self.sid_for(name));
+                    length += self.element_size(child)?;
+                }
+                header_size(length) + length
+            }
+        };
+        Ok(size)
+    }
+
+    /// Returns the size of the local symbol tables that a writer would emit ahead of the values
+    /// sized so far: one declaring `imports` (if any), followed by one appending any new symbols.
+    fn local_symbol_tables_size(&mut self, imports: &[SharedSymbolTable]) -> IonResult<usize> {
+        let mut size = 0;
+        if !imports.is_empty() {
+            let imports = imports.iter().map(|table| {
+                ion_struct! {
+                    "name": table.name(),
+                    "version": table.version() as i64,
+                    "max_id": table.symbols().len() as i64,
+                }
+            });
+            size += self.local_symbol_table_size(Value::List(Sequence::new(imports)), [])?;
+        }
+        if self.num_pending_symbols > 0 {
+            let first_pending = self.symbol_table.len() - self.num_pending_symbols;
+            let symbols: Vec<Element> = self
+                .symbol_table
+                .symbols_tail(first_pending)
+                .iter()
+                .map(|symbol| Element::string(symbol.text().unwrap_or_default()))
+                .collect();
+            let imports = Symbol::from("$ion_symbol_table");
+            size += self.local_symbol_table_size(imports, symbols)?;
+        }
+        Ok(size)
+    }
+
+    fn local_symbol_table_size(
+        &mut self,
+        imports: impl Into<Element>,
+        symbols: impl IntoIterator<Item = Element>,
+    ) -> IonResult<usize> {
+        let lst = Element::from(ion_struct! {
+            "imports": imports.into(),
+            "symbols": Value::List(Sequence::new(symbols)),
+        })
+        .with_annotations(["$ion_symbol_table"]);
+        // Every symbol in the LST is a system symbol, so sizing it does not add new symbols.
+        self.element_size(&lst)
+    }
+}
+
+/// Returns the number of bytes needed for the type descriptor and length of a value whose body
+/// is `length` bytes long.
+fn header_size(length: usize) -> usize {
+    if length <= MAX_INLINE_LENGTH {
+        1
+    } else {
+        1 + var_uint_size(length)
+    }
+}
+
+fn lob_size(length: usize) -> usize {
+    header_size(length) + length
+}
+
+fn var_uint_size(value: usize) -> usize {
+    VarUInt::encoded_size_of(value as u64)
+}
+
+/// Returns the number of bytes that `element` occupies when encoded as binary Ion 1.0 in a stream
+/// described by `policy`.
+pub(crate) fn binary_size(element: &Element, policy: &SymbolTablePolicy) -> IonResult<usize> {
+    let imports = match policy {
+        SymbolTablePolicy::Standalone => &[][..],
+        SymbolTablePolicy::Imports(imports) => imports.as_slice(),
+    };
+    let mut estimator = SizeEstimator::new(imports);
+    let value_size = estimator.element_size(element)?;
+    let lst_size = estimator.local_symbol_tables_size(imports)?;
+    Ok(IVM_LENGTH + lst_size + value_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{v1_0, WriteConfig};
+
+    #[rstest]
+    #[case::empty_struct("{}")]
+    #[case::system_symbols("name::{version: symbols}")]
+    #[case::scalars(
+        "null.int true -7 0 0e0 -0e0 1.5e0 0.1e0 nan 1.25 0d0 -0d3 2024-05-06T07:08:09.123Z"
+    )]
+    #[case::user_symbols("foo::bar::{baz: quux, baz: [foo, bar, $0]}")]
+    #[case::strings_and_lobs(
+        r#""short" "a string that is longer than thirteen bytes" {{ aGVsbG8= }} {{"clob"}}"#
+    )]
+    #[case::large_int("123456789012345678901234567890123456789")]
+    #[case::long_container("[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, (a b c d e f g h)]")]
+    fn standalone_size_matches_encoding(#[case] ion: &str) -> IonResult<()> {
+        for element in Element::read_all(ion)? {
+            let expected = element.encode_as(v1_0::Binary)?.len();
+            let size = element.binary_size_hint(&SymbolTablePolicy::Standalone)?;
+            assert_eq!(size, expected, "size of {element}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn many_symbols_use_multi_byte_symbol_ids() -> IonResult<()> {
+        let fields = (0..200).map(|i| (format!("field{i}"), Element::symbol(format!("v{i}"))));
+        let element: Element = crate::Struct::from_iter(fields).into();
+        let expected = element.encode_as(v1_0::Binary)?.len();
+        assert_eq!(
+            element.binary_size_hint(&SymbolTablePolicy::Standalone)?,
+            expected
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn imported_symbols_are_not_defined() -> IonResult<()> {
+        let table = SharedSymbolTable::new("shared", 2, ["foo", "bar"])?;
+        let config = WriteConfig::<v1_0::Binary>::new().with_symbol_table_import(table.clone());
+        let policy = SymbolTablePolicy::Imports(vec![table]);
+        // The second value only uses imported symbols, so no symbols are appended
+        for element in Element::read_all("foo::{bar: baz} foo::bar")? {
+            let expected = element.encode_as(config.clone())?.len();
+            assert_eq!(
+                element.binary_size_hint(&policy)?,
+                expected,
+                "size of {element}"
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+use std::ops::Range;
+
+use crate::element::materialize::ElementHooks;
+use crate::lazy::any_encoding::AnyEncoding;
+use crate::lazy::decoder::HasRange;
+use crate::lazy::value::LazyValue;
+
+/// The location in the source data from which an [`Element`](crate::Element) was read.
+///
+/// The byte range covers the value's entire encoding, including any annotations. When the source
+/// is text Ion, the span also records the (1-based) line and column at which the encoding begins
+/// and ends; columns are counted in Unicode scalar values. Binary sources have no lines, so
+/// [`start_line_and_column`](Self::start_line_and_column) and
+/// [`end_line_and_column`](Self::end_line_and_column) return `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceSpan {
+    range: Range<usize>,
+    start: Option<(usize, usize)>,
+    end: Option<(usize, usize)>,
+}
+
+impl SourceSpan {
+    /// The range of source bytes that encode the value.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The offset in the source at which the value's encoding begins.
+    pub fn offset(&self) -> usize {
+        self.range.start
+    }
+
+    /// The number of bytes used to encode the value.
+    pub fn length(&self) -> usize {
+        self.range.len()
+    }
+
+    /// If the source was text, returns the line and column at which the value's encoding begins.
+    pub fn start_line_and_column(&self) -> Option<(usize, usize)> {
+        self.start
+    }
+
+    /// If the source was text, returns the line and column immediately following the value's
+    /// encoding.
+    pub fn end_line_and_column(&self) -> Option<(usize, usize)> {
+        self.end
+    }
+}
+
+/// A side table recording the [`SourceSpan`] of an [`Element`](crate::Element) and of each of its
+/// nested values.
+///
+/// The tree mirrors the shape of the `Element` it describes: the spans of a list's or s-expression's
+/// children appear in the same order as the child elements, and the spans of a struct's fields
+/// appear in the order in which the fields were read (which is the order of
+/// [`Struct::fields`](crate::Struct::fields)). Values that were produced by a macro expansion rather than read directly
+/// from the source do not have a span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementSpans {
+    span: Option<SourceSpan>,
+    children: Vec<ElementSpans>,
+}
+
+impl ElementSpans {
+    /// The location of the value itself, if it was read directly from the source.
+    pub fn span(&self) -> Option<&SourceSpan> {
+        self.span.as_ref()
+    }
+
+    /// The spans of the value's children. Scalars and null containers have no children.
+    pub fn children(&self) -> &[ElementSpans] {
+        &self.children
+    }
+
+    /// Returns the spans of the child at `index`, if it exists.
+    pub fn child(&self, index: usize) -> Option<&ElementSpans> {
+        self.children.get(index)
+    }
+
+    /// Follows `path`, a sequence of child indexes, from this value to a nested value and returns
+    /// its spans. An empty path refers to this value.
+    pub fn get(&self, path: &[usize]) -> Option<&ElementSpans> {
+        path.iter()
+            .try_fold(self, |spans, index| spans.children.get(*index))
+    }
+}
+
+/// Maps byte offsets in a text source to line and column numbers.
+pub(crate) struct LineIndex<'a> {
+    text: &'a [u8],
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub(crate) fn new(text: &'a [u8]) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(
+                text.iter()
+                    .enumerate()
+                    .filter(|(_, byte)| **byte == b'\n')
+                    .map(|(offset, _)| offset + 1),
+            )
+            .collect();
+        Self { text, line_starts }
+    }
+
+    /// Returns the 1-based line and column of the byte at `offset`.
+    pub(crate) fn line_and_column(&self, offset: usize) -> (usize, usize) {
+        let line = self.line_starts.partition_point(|start| *start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let end = offset.min(self.text.len());
+        let column = String::from_utf8_lossy(&self.text[line_start..end])
+            .chars()
+            .count();
+        (line, column + 1)
+    }
+}
+
+/// Materializes `Element`s, recording the spans of the source data from which each value and
+/// each of its nested values was read.
+pub(crate) struct SpanRecorder<'a> {
+    lines: Option<&'a LineIndex<'a>>,
+    // The spans of values whose parent has not been finished yet
+    spans: Vec<ElementSpans>,
+}
+
+impl<'a> SpanRecorder<'a> {
+    pub(crate) fn new(lines: Option<&'a LineIndex<'a>>) -> Self {
+        Self {
+            lines,
+            spans: Vec::new(),
+        }
+    }
+
+    /// Returns the spans of the most recently materialized top-level value.
+    pub(crate) fn take_spans(&mut self) -> Option<ElementSpans> {
+        self.spans.pop()
+    }
+}
+
+impl<'a, 'top> ElementHooks<'top, AnyEncoding> for SpanRecorder<'a> {
+    fn finish(&mut self, value: LazyValue<'top, AnyEncoding>, num_children: usize) {
+        let span = value.raw().map(|raw| {
+            let range = raw.range();
+            let start = self.lines.map(|lines| lines.line_and_column(range.start));
+            let end = self.lines.map(|lines| lines.line_and_column(range.end));
+            SourceSpan { range, start, end }
+        });
+        let children = self.spans.split_off(self.spans.len() - num_children);
+        self.spans.push(ElementSpans { span, children });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, Element, IonResult, IonType};
+
+    const SOURCE: &str = "foo::{\n  name: \"ion\",\n  tags: [a, b],\n}\n// ✓ done\n(x 1)";
+
+    #[test]
+    fn text_spans_have_lines_and_columns() -> IonResult<()> {
+        let values = Element::read_all_with_spans(SOURCE)?;
+        assert_eq!(values.len(), 2);
+        let (element, spans) = &values[0];
+        assert_eq!(
+            element,
+            &Element::read_one("foo::{name: \"ion\", tags: [a, b]}")?
+        );
+
+        let root = spans.span().unwrap();
+        assert_eq!(root.offset(), 0);
+        assert_eq!(root.start_line_and_column(), Some((1, 1)));
+        assert_eq!(root.end_line_and_column(), Some((4, 2)));
+
+        let name = spans.child(0).unwrap().span().unwrap();
+        assert_eq!(&SOURCE[name.range()], "\"ion\"");
+        assert_eq!(name.start_line_and_column(), Some((2, 9)));
+
+        let b = spans.get(&[1, 1]).unwrap().span().unwrap();
+        assert_eq!(&SOURCE[b.range()], "b");
+        assert_eq!(b.start_line_and_column(), Some((3, 13)));
+        assert!(spans.get(&[1, 1]).unwrap().children().is_empty());
+        assert!(spans.get(&[1, 2]).is_none());
+
+        let (sexp, spans) = &values[1];
+        assert_eq!(sexp.ion_type(), IonType::SExp);
+        assert_eq!(spans.children().len(), 2);
+        assert_eq!(spans.span().unwrap().start_line_and_column(), Some((6, 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let lines = LineIndex::new("“a” b\nc".as_bytes());
+        assert_eq!(lines.line_and_column(0), (1, 1));
+        // `b` follows three multi-byte quotation marks
+        assert_eq!(lines.line_and_column("“a” ".len()), (1, 5));
+        assert_eq!(lines.line_and_column("“a” b\n".len()), (2, 1));
+    }
+
+    #[test]
+    fn binary_spans_have_no_lines() -> IonResult<()> {
+        let binary = Element::read_all(SOURCE)?.encode_as(v1_0::Binary)?;
+        let values = Element::read_all_with_spans(&binary)?;
+        let (element, spans) = &values[1];
+        assert_eq!(element, &Element::read_one("(x 1)")?);
+        let one = spans.child(1).unwrap().span().unwrap();
+        assert_eq!(one.start_line_and_column(), None);
+        assert_eq!(&binary[one.range()], &[0x21, 0x01]);
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Element, IonType, Value};
+
+/// Counters describing the shape and size of an [`Element`] tree. See [`Element::stats`].
+///
+/// The counters can be used to plan capacity for a document or to reject pathological documents,
+/// such as those that are very deeply nested or that hold very large payloads, before processing
+/// them further.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ElementStats {
+    // Indexed by `IonType as usize`
+    values_by_type: [usize; 13],
+    max_depth: usize,
+    string_bytes: usize,
+    lob_bytes: usize,
+}
+
+impl ElementStats {
+    /// Returns the number of values of the given type in the tree, including typed nulls.
+    pub fn values(&self, ion_type: IonType) -> usize {
+        self.values_by_type[ion_type as usize]
+    }
+
+    /// Returns the number of values of any type in the tree, including the root.
+    pub fn total_values(&self) -> usize {
+        self.values_by_type.iter().sum()
+    }
+
+    /// Returns the greatest container nesting depth in the tree. The root has a depth of zero and
+    /// the children of a root container have a depth of one.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Returns the total number of UTF-8 bytes in the tree's string values.
+    pub fn string_bytes(&self) -> usize {
+        self.string_bytes
+    }
+
+    /// Returns the total number of bytes in the tree's clob and blob values.
+    pub fn lob_bytes(&self) -> usize {
+        self.lob_bytes
+    }
+
+    pub(crate) fn of(element: &Element) -> Self {
+        let mut stats = ElementStats::default();
+        stats.record(element, 0);
+        stats
+    }
+
+    fn record(&mut self, element: &Element, depth: usize) {
+        self.values_by_type[element.ion_type() as usize] += 1;
+        self.max_depth = self.max_depth.max(depth);
+        match element.value() {
+            Value::String(text) => self.string_bytes += text.text().len(),
+            Value::Clob(bytes) | Value::Blob(bytes) => self.lob_bytes += bytes.as_ref().len(),
+            Value::List(sequence) | Value::SExp(sequence) => sequence
+                .elements()
+                .for_each(|child| self.record(child, depth + 1)),
+            Value::Struct(fields) => fields
+                .fields()
+                .for_each(|(_, child)| self.record(child, depth + 1)),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IonResult;
+
+    #[test]
+    fn counts_values_depth_and_payload_bytes() -> IonResult<()> {
+        let element = Element::read_one(
+            r#"{name: "héllo", tags: [a, "b", null.string], data: {{ aGVsbG8= }}, raw: ({{"xy"}})}"#,
+        )?;
+        let stats = element.stats();
+        assert_eq!(stats.values(IonType::Struct), 1);
+        assert_eq!(stats.values(IonType::String), 3);
+        assert_eq!(stats.values(IonType::Symbol), 1);
+        assert_eq!(stats.values(IonType::List), 1);
+        assert_eq!(stats.values(IonType::SExp), 1);
+        assert_eq!(stats.values(IonType::Blob), 1);
+        assert_eq!(stats.values(IonType::Clob), 1);
+        assert_eq!(stats.values(IonType::Int), 0);
+        assert_eq!(stats.total_values(), 9);
+        assert_eq!(stats.max_depth(), 2);
+        // "héllo" is 6 UTF-8 bytes; "b" is 1
+        assert_eq!(stats.string_bytes(), 7);
+        assert_eq!(stats.lob_bytes(), 7);
+        Ok(())
+    }
+
+    #[test]
+    fn scalar_has_depth_zero() {
+        let stats = Element::int(5).stats();
+        assert_eq!(stats.total_values(), 1);
+        assert_eq!(stats.values(IonType::Int), 1);
+        assert_eq!(stats.max_depth(), 0);
+        assert_eq!(stats.string_bytes(), 0);
+    }
+}
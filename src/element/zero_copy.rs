@@ -0,0 +1,132 @@
+//! Materializes `Element`s whose blob and clob payloads are slices of a `bytes::Bytes` input.
+
+use crate::element::materialize::ElementHooks;
+use crate::lazy::decoder::Decoder;
+use crate::Bytes;
+
+/// Materializes `Element`s whose blob and clob payloads refer to the region of `source` in which
+/// they were found rather than to a copy of it, where possible.
+pub(crate) struct SharingSource<'a> {
+    source: &'a ::bytes::Bytes,
+}
+
+impl<'a> SharingSource<'a> {
+    pub(crate) fn new(source: &'a ::bytes::Bytes) -> Self {
+        Self { source }
+    }
+}
+
+impl<'a, 'top, D: Decoder> ElementHooks<'top, D> for SharingSource<'a> {
+    fn lob(&mut self, data: &[u8]) -> Bytes {
+        share(self.source, data).into()
+    }
+}
+
+/// Returns a `bytes::Bytes` holding `data`. If `data` lies within `source`, the result shares
+/// `source`'s buffer; otherwise (for example, if the payload was decoded from base64 text into a
+/// scratch buffer), `data` is copied.
+fn share(source: &::bytes::Bytes, data: &[u8]) -> ::bytes::Bytes {
+    let source_start = source.as_ptr() as usize;
+    let data_start = data.as_ptr() as usize;
+    if data_start >= source_start && data_start + data.len() <= source_start + source.len() {
+        source.slice_ref(data)
+    } else {
+        ::bytes::Bytes::copy_from_slice(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{v1_0, AnyEncoding, Element, ElementReader, IonResult, Reader, Value};
+
+    const SOURCE: &str = r#"
+        payload::{
+            name: "report.pdf",
+            contents: {{ aGVsbG8sIHdvcmxkIQ== }},
+            parts: [{{ "part one" }}, (sig {{ aGk= }})],
+            empty: {{ }},
+        }
+        null.blob
+    "#;
+
+    fn binary_source() -> IonResult<::bytes::Bytes> {
+        let elements = Element::read_all(SOURCE)?;
+        Ok(crate::to_binary(&elements)?.into())
+    }
+
+    // Whether `bytes` is a view of a region within `source`.
+    fn is_within(bytes: &[u8], source: &[u8]) -> bool {
+        source.as_ptr_range().contains(&bytes.as_ptr())
+    }
+
+    #[test]
+    fn binary_lobs_are_not_copied() -> IonResult<()> {
+        let source = binary_source()?;
+        let mut reader = Reader::new(AnyEncoding, source.clone())?;
+        let elements = reader.read_all_elements_sharing()?;
+        assert_eq!(
+            elements,
+            Element::read_all(SOURCE)?.into_iter().collect::<Vec<_>>()
+        );
+
+        let fields = elements[0].expect_struct()?;
+        let contents = fields.get("contents").unwrap().as_blob().unwrap();
+        assert_eq!(contents, b"hello, world!");
+        assert!(is_within(contents, &source));
+
+        let parts = fields.get("parts").unwrap().as_sequence().unwrap();
+        let part_one = parts.get(0).unwrap().as_clob().unwrap();
+        assert_eq!(part_one, b"part one");
+        assert!(is_within(part_one, &source));
+        let signature = parts.get(1).unwrap().as_sequence().unwrap().get(1).unwrap();
+        assert!(is_within(signature.as_blob().unwrap(), &source));
+        Ok(())
+    }
+
+    #[test]
+    fn shared_payload_outlives_reader() -> IonResult<()> {
+        let source = binary_source()?;
+        let element = Reader::new(v1_0::Binary, source.clone())?
+            .read_next_element_sharing()?
+            .unwrap();
+        let contents = element.as_struct().unwrap().get("contents").unwrap();
+        let Value::Blob(blob) = contents.value() else {
+            panic!("expected a blob, found {contents}");
+        };
+        let payload = ::bytes::Bytes::from(blob.clone());
+        drop(element);
+        drop(source);
+        assert_eq!(payload, &b"hello, world!"[..]);
+        Ok(())
+    }
+
+    #[test]
+    fn decoded_text_lobs_are_copied() -> IonResult<()> {
+        let source = ::bytes::Bytes::from(SOURCE);
+        let elements = Reader::new(AnyEncoding, source.clone())?.read_all_elements_sharing()?;
+        assert_eq!(
+            elements,
+            Element::read_all(SOURCE)?.into_iter().collect::<Vec<_>>()
+        );
+        // The base64 text had to be decoded, so the payload cannot refer to the source.
+        let contents = elements[0].as_struct().unwrap().get("contents").unwrap();
+        assert!(!is_within(contents.as_blob().unwrap(), &source));
+        Ok(())
+    }
+
+    #[test]
+    fn updates_reader_stats() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, binary_source()?)?;
+        reader.read_all_elements_sharing()?;
+        assert_eq!(reader.stats().top_level_values(), 2);
+        assert_eq!(reader.stats().values(crate::IonType::Blob), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn elements_can_be_read_from_bytes() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, binary_source()?)?;
+        assert_eq!(reader.read_all_elements()?.len(), 2);
+        Ok(())
+    }
+}
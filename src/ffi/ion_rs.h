@@ -0,0 +1,127 @@
+/*
+ * C declarations for the `ion_rs` Element API, which is available when the crate is built with
+ * the `experimental-ffi` feature. See the documentation of the `ion_rs::ffi` module for the
+ * conventions that apply to every function: ownership of handles, borrowed text and error
+ * reporting.
+ */
+
+#ifndef ION_RS_H
+#define ION_RS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The outcome of a call to a function that can fail. */
+typedef enum {
+    ION_STATUS_OK = 0,
+    ION_STATUS_INVALID_ARGUMENT = 1,
+    ION_STATUS_TYPE_MISMATCH = 2,
+    ION_STATUS_DECODING = 3,
+    ION_STATUS_ENCODING = 4,
+    ION_STATUS_ILLEGAL_OPERATION = 5,
+    ION_STATUS_IO = 6,
+    ION_STATUS_INTERNAL = 7,
+} IonStatus;
+
+/* The codes that represent each Ion type. */
+#define ION_TYPE_NULL 0u
+#define ION_TYPE_BOOL 1u
+#define ION_TYPE_INT 2u
+#define ION_TYPE_FLOAT 3u
+#define ION_TYPE_DECIMAL 4u
+#define ION_TYPE_TIMESTAMP 5u
+#define ION_TYPE_SYMBOL 6u
+#define ION_TYPE_STRING 7u
+#define ION_TYPE_CLOB 8u
+#define ION_TYPE_BLOB 9u
+#define ION_TYPE_LIST 10u
+#define ION_TYPE_SEXP 11u
+#define ION_TYPE_STRUCT 12u
+
+/* The codes that represent each output format. */
+#define ION_FORMAT_TEXT_COMPACT 0u
+#define ION_FORMAT_TEXT_LINES 1u
+#define ION_FORMAT_TEXT_PRETTY 2u
+#define ION_FORMAT_BINARY 3u
+
+/* An Ion value, including its annotations. */
+typedef struct IonElement IonElement;
+/* A series of elements: a stream's top-level values, or the contents of a list or s-expression. */
+typedef struct IonSequence IonSequence;
+/* The fields of a struct element. */
+typedef struct IonStruct IonStruct;
+
+/* A field name and value used to construct a struct with `ion_element_new_struct`. */
+typedef struct {
+    const uint8_t *name;
+    size_t name_len;
+    IonElement *value;
+} IonField;
+
+/* A buffer of bytes allocated by ion_rs. It must be released with `ion_buffer_free`. */
+typedef struct {
+    uint8_t *data;
+    size_t len;
+} IonBuffer;
+
+/* Errors and memory management */
+const char *ion_last_error_message(void);
+void ion_buffer_free(IonBuffer buffer);
+void ion_element_free(IonElement *element);
+void ion_sequence_free(IonSequence *sequence);
+
+/* Reading and writing */
+IonStatus ion_element_read_one(const uint8_t *data, size_t len, IonElement **element);
+IonStatus ion_sequence_read_all(const uint8_t *data, size_t len, IonSequence **sequence);
+IonStatus ion_element_write(const IonElement *element, uint32_t format, IonBuffer *buffer);
+IonStatus ion_sequence_write(const IonSequence *sequence, uint32_t format, IonBuffer *buffer);
+IonStatus ion_element_to_text(const IonElement *element, IonBuffer *buffer);
+
+/* Building */
+IonStatus ion_element_new_null(uint32_t ion_type, IonElement **element);
+IonElement *ion_element_new_bool(bool value);
+IonElement *ion_element_new_int(int64_t value);
+IonElement *ion_element_new_float(double value);
+IonElement *ion_element_new_decimal(int64_t coefficient, int64_t exponent);
+IonStatus ion_element_new_string(const uint8_t *data, size_t len, IonElement **element);
+IonStatus ion_element_new_symbol(const uint8_t *data, size_t len, IonElement **element);
+IonStatus ion_element_new_blob(const uint8_t *data, size_t len, IonElement **element);
+IonStatus ion_element_new_clob(const uint8_t *data, size_t len, IonElement **element);
+IonStatus ion_element_new_list(IonElement *const *elements, size_t count, IonElement **element);
+IonStatus ion_element_new_sexp(IonElement *const *elements, size_t count, IonElement **element);
+IonStatus ion_element_new_struct(const IonField *fields, size_t count, IonElement **element);
+IonStatus ion_sequence_new(IonElement *const *elements, size_t count, IonSequence **sequence);
+IonStatus ion_element_add_annotation(IonElement *element, const uint8_t *data, size_t len);
+IonElement *ion_element_clone(const IonElement *element);
+
+/* Inspecting */
+uint32_t ion_element_type(const IonElement *element);
+bool ion_element_is_null(const IonElement *element);
+bool ion_element_equals(const IonElement *element, const IonElement *other);
+IonStatus ion_element_as_bool(const IonElement *element, bool *value);
+IonStatus ion_element_as_int(const IonElement *element, int64_t *value);
+IonStatus ion_element_as_float(const IonElement *element, double *value);
+IonStatus ion_element_as_text(const IonElement *element, const uint8_t **data, size_t *len);
+IonStatus ion_element_as_lob(const IonElement *element, const uint8_t **data, size_t *len);
+const IonSequence *ion_element_as_sequence(const IonElement *element);
+const IonStruct *ion_element_as_struct(const IonElement *element);
+size_t ion_element_annotation_count(const IonElement *element);
+IonStatus ion_element_annotation(const IonElement *element, size_t index, const uint8_t **data,
+                                 size_t *len);
+size_t ion_sequence_len(const IonSequence *sequence);
+const IonElement *ion_sequence_get(const IonSequence *sequence, size_t index);
+size_t ion_struct_len(const IonStruct *struct_);
+IonStatus ion_struct_field(const IonStruct *struct_, size_t index, const uint8_t **name,
+                           size_t *name_len, const IonElement **value);
+const IonElement *ion_struct_get(const IonStruct *struct_, const uint8_t *name, size_t name_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ION_RS_H */
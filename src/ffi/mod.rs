@@ -0,0 +1,1077 @@
+//! A C ABI for the [`Element`] API, allowing programs written in other languages to read, inspect,
+//! build and write Ion data using `ion_rs`.
+//!
+//! The declarations in `ion_rs.h` (next to this module's source) describe the same API in C. To
+//! produce a library that C programs can link against, build this crate as a `cdylib` or
+//! `staticlib` with the `experimental-ffi` feature enabled, for example:
+//!
+//! ```shell
+//! cargo rustc --release --features experimental-ffi --crate-type cdylib
+//! ```
+//!
+//! # Conventions
+//!
+//! * Elements, sequences and structs are opaque handles. Handles returned by `ion_*_new*` and
+//!   `ion_*_read*` functions are owned by the caller and must be released with the matching
+//!   `ion_*_free` function. Handles returned by accessors (for example [`ion_sequence_get`]) are
+//!   borrowed from their parent and are only valid for as long as the parent is.
+//! * Text is passed as a pointer to UTF-8 bytes and a length; it does not need to be
+//!   NUL-terminated. Text returned by accessors is borrowed in the same way as handles.
+//! * Functions that can fail return an [`IonStatus`]. When the status is not `ION_STATUS_OK`, a
+//!   description of the failure can be retrieved with [`ion_last_error_message`].
+//! * Unless stated otherwise, pointer arguments must be valid and non-null. Functions that return
+//!   an `IonStatus` report `ION_STATUS_INVALID_ARGUMENT` instead of dereferencing a null pointer.
+
+// Every function in this module dereferences raw pointers provided by the caller; the safety
+// requirements that apply to all of them are described in the module documentation above.
+#![allow(clippy::missing_safety_doc)]
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::{ptr, slice, str};
+
+use crate::{
+    v1_0, Decimal, Element, IonError, IonType, Sequence, Struct, Symbol, TextFormat, Value,
+};
+
+/// The outcome of a call to a function that can fail.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IonStatus {
+    Ok = 0,
+    /// A pointer was null, text was not valid UTF-8, or a code or index was out of range.
+    InvalidArgument = 1,
+    /// The element was not of the type required by the function (or was a null).
+    TypeMismatch = 2,
+    /// The input was not valid Ion.
+    Decoding = 3,
+    /// The value could not be serialized.
+    Encoding = 4,
+    /// An operation that is not allowed was attempted.
+    IllegalOperation = 5,
+    /// An I/O error occurred.
+    Io = 6,
+    /// `ion_rs` failed unexpectedly. This indicates a bug.
+    Internal = 7,
+}
+
+/// An owned Ion value, including its annotations.
+#[repr(transparent)]
+pub struct IonElement(Element);
+
+/// An owned or borrowed series of elements: a stream's top-level values, or the contents of a list
+/// or s-expression.
+#[repr(transparent)]
+pub struct IonSequence(Sequence);
+
+/// The fields of a borrowed struct element.
+#[repr(transparent)]
+pub struct IonStruct(Struct);
+
+/// A field name and value used to construct a struct with [`ion_element_new_struct`].
+#[repr(C)]
+pub struct IonField {
+    pub name: *const u8,
+    pub name_len: usize,
+    pub value: *mut IonElement,
+}
+
+/// A buffer of bytes allocated by `ion_rs`. It must be released with [`ion_buffer_free`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct IonBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+// The codes that represent each Ion type.
+pub const ION_TYPE_NULL: u32 = 0;
+pub const ION_TYPE_BOOL: u32 = 1;
+pub const ION_TYPE_INT: u32 = 2;
+pub const ION_TYPE_FLOAT: u32 = 3;
+pub const ION_TYPE_DECIMAL: u32 = 4;
+pub const ION_TYPE_TIMESTAMP: u32 = 5;
+pub const ION_TYPE_SYMBOL: u32 = 6;
+pub const ION_TYPE_STRING: u32 = 7;
+pub const ION_TYPE_CLOB: u32 = 8;
+pub const ION_TYPE_BLOB: u32 = 9;
+pub const ION_TYPE_LIST: u32 = 10;
+pub const ION_TYPE_SEXP: u32 = 11;
+pub const ION_TYPE_STRUCT: u32 = 12;
+
+// The codes that represent each output format.
+pub const ION_FORMAT_TEXT_COMPACT: u32 = 0;
+pub const ION_FORMAT_TEXT_LINES: u32 = 1;
+pub const ION_FORMAT_TEXT_PRETTY: u32 = 2;
+pub const ION_FORMAT_BINARY: u32 = 3;
+
+fn ion_type_code(ion_type: IonType) -> u32 {
+    match ion_type {
+        IonType::Null => ION_TYPE_NULL,
+        IonType::Bool => ION_TYPE_BOOL,
+        IonType::Int => ION_TYPE_INT,
+        IonType::Float => ION_TYPE_FLOAT,
+        IonType::Decimal => ION_TYPE_DECIMAL,
+        IonType::Timestamp => ION_TYPE_TIMESTAMP,
+        IonType::Symbol => ION_TYPE_SYMBOL,
+        IonType::String => ION_TYPE_STRING,
+        IonType::Clob => ION_TYPE_CLOB,
+        IonType::Blob => ION_TYPE_BLOB,
+        IonType::List => ION_TYPE_LIST,
+        IonType::SExp => ION_TYPE_SEXP,
+        IonType::Struct => ION_TYPE_STRUCT,
+    }
+}
+
+fn ion_type_from_code(code: u32) -> FfiResult<IonType> {
+    let ion_type = match code {
+        ION_TYPE_NULL => IonType::Null,
+        ION_TYPE_BOOL => IonType::Bool,
+        ION_TYPE_INT => IonType::Int,
+        ION_TYPE_FLOAT => IonType::Float,
+        ION_TYPE_DECIMAL => IonType::Decimal,
+        ION_TYPE_TIMESTAMP => IonType::Timestamp,
+        ION_TYPE_SYMBOL => IonType::Symbol,
+        ION_TYPE_STRING => IonType::String,
+        ION_TYPE_CLOB => IonType::Clob,
+        ION_TYPE_BLOB => IonType::Blob,
+        ION_TYPE_LIST => IonType::List,
+        ION_TYPE_SEXP => IonType::SExp,
+        ION_TYPE_STRUCT => IonType::Struct,
+        _ => {
+            return Err(FfiError::invalid_argument(format!(
+                "unknown Ion type code {code}"
+            )))
+        }
+    };
+    Ok(ion_type)
+}
+
+/// A failure to be reported to the caller as an [`IonStatus`] and an error message.
+struct FfiError {
+    status: IonStatus,
+    message: String,
+}
+
+type FfiResult<T> = Result<T, FfiError>;
+
+impl FfiError {
+    fn invalid_argument(message: impl Into<String>) -> Self {
+        Self {
+            status: IonStatus::InvalidArgument,
+            message: message.into(),
+        }
+    }
+
+    fn type_mismatch(message: impl Into<String>) -> Self {
+        Self {
+            status: IonStatus::TypeMismatch,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<IonError> for FfiError {
+    fn from(error: IonError) -> Self {
+        let status = match &error {
+            IonError::Io(_) => IonStatus::Io,
+            IonError::Incomplete(_) | IonError::Decoding(_) => IonStatus::Decoding,
+            IonError::Encoding(_) => IonStatus::Encoding,
+            IonError::IllegalOperation(_) => IonStatus::IllegalOperation,
+        };
+        Self {
+            status,
+            message: error.to_string(),
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, recording the error message (if any) so that it can be retrieved with
+/// [`ion_last_error_message`]. Panics are reported as [`IonStatus::Internal`] rather than
+/// unwinding into the caller.
+fn ffi_call(f: impl FnOnce() -> FfiResult<()>) -> IonStatus {
+    let error = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => return IonStatus::Ok,
+        Ok(Err(error)) => error,
+        Err(_) => FfiError {
+            status: IonStatus::Internal,
+            message: "ion_rs panicked".to_string(),
+        },
+    };
+    // Error messages can include text from the input, which may contain NUL bytes.
+    let message = CString::new(error.message.replace('\0', "\\0"))
+        .expect("NUL bytes were escaped from the message");
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+    error.status
+}
+
+unsafe fn borrow<'a, T>(pointer: *const T, name: &str) -> FfiResult<&'a T> {
+    pointer
+        .as_ref()
+        .ok_or_else(|| FfiError::invalid_argument(format!("{name} must not be null")))
+}
+
+unsafe fn output<'a, T>(pointer: *mut T, name: &str) -> FfiResult<&'a mut T> {
+    pointer
+        .as_mut()
+        .ok_or_else(|| FfiError::invalid_argument(format!("{name} must not be null")))
+}
+
+/// Returns the bytes described by `data` and `len`. `data` may only be null if `len` is zero.
+unsafe fn bytes<'a>(data: *const u8, len: usize) -> FfiResult<&'a [u8]> {
+    match (data.is_null(), len) {
+        (true, 0) => Ok(&[]),
+        (true, _) => Err(FfiError::invalid_argument(
+            "data must not be null unless its length is zero",
+        )),
+        (false, _) => Ok(slice::from_raw_parts(data, len)),
+    }
+}
+
+unsafe fn text<'a>(data: *const u8, len: usize) -> FfiResult<&'a str> {
+    str::from_utf8(bytes(data, len)?)
+        .map_err(|e| FfiError::invalid_argument(format!("text is not valid UTF-8: {e}")))
+}
+
+/// Writes a borrowed view of `text` (or a null pointer if the text is unknown) to the output
+/// parameters.
+unsafe fn write_text(text: Option<&str>, data: *mut *const u8, len: *mut usize) -> FfiResult<()> {
+    let (data, len) = (output(data, "data")?, output(len, "len")?);
+    match text {
+        Some(text) => {
+            *data = text.as_ptr();
+            *len = text.len();
+        }
+        None => {
+            *data = ptr::null();
+            *len = 0;
+        }
+    }
+    Ok(())
+}
+
+fn into_handle(element: Element) -> *mut IonElement {
+    Box::into_raw(Box::new(IonElement(element)))
+}
+
+fn borrowed_element(element: &Element) -> *const IonElement {
+    // IonElement is a transparent wrapper around Element, so their layouts are identical.
+    element as *const Element as *const IonElement
+}
+
+fn encode(sequence: &Sequence, format: u32) -> FfiResult<Vec<u8>> {
+    let bytes = match format {
+        ION_FORMAT_TEXT_COMPACT => sequence
+            .encode_as(v1_0::Text.with_format(TextFormat::Compact))?
+            .into_bytes(),
+        ION_FORMAT_TEXT_LINES => sequence
+            .encode_as(v1_0::Text.with_format(TextFormat::Lines))?
+            .into_bytes(),
+        ION_FORMAT_TEXT_PRETTY => sequence
+            .encode_as(v1_0::Text.with_format(TextFormat::Pretty))?
+            .into_bytes(),
+        ION_FORMAT_BINARY => sequence.encode_as(v1_0::Binary)?,
+        _ => {
+            return Err(FfiError::invalid_argument(format!(
+                "unknown format code {format}"
+            )))
+        }
+    };
+    Ok(bytes)
+}
+
+/// Takes ownership of `count` element handles from the array at `elements`.
+unsafe fn take_elements(elements: *const *mut IonElement, count: usize) -> FfiResult<Vec<Element>> {
+    let handles = if count == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(borrow(elements, "elements")?, count)
+    };
+    if handles.iter().any(|handle| handle.is_null()) {
+        return Err(FfiError::invalid_argument("elements must not be null"));
+    }
+    Ok(handles
+        .iter()
+        .map(|handle| Box::from_raw(*handle).0)
+        .collect())
+}
+
+/// Returns a description of the most recent failure on the calling thread, or null if no
+/// function has failed. The message is valid until the next failure on the same thread.
+#[no_mangle]
+pub extern "C" fn ion_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Releases a buffer returned by one of the `ion_*_write` functions.
+#[no_mangle]
+pub unsafe extern "C" fn ion_buffer_free(buffer: IonBuffer) {
+    if !buffer.data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+            buffer.data,
+            buffer.len,
+        )));
+    }
+}
+
+/// Reads the single value in the text or binary Ion stream `data`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_read_one(
+    data: *const u8,
+    len: usize,
+    element: *mut *mut IonElement,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = output(element, "element")?;
+        *element = into_handle(Element::read_one(bytes(data, len)?)?);
+        Ok(())
+    })
+}
+
+/// Reads all of the values in the text or binary Ion stream `data`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_sequence_read_all(
+    data: *const u8,
+    len: usize,
+    sequence: *mut *mut IonSequence,
+) -> IonStatus {
+    ffi_call(|| {
+        let sequence = output(sequence, "sequence")?;
+        let elements = Element::read_all(bytes(data, len)?)?;
+        *sequence = Box::into_raw(Box::new(IonSequence(elements)));
+        Ok(())
+    })
+}
+
+/// Serializes `element` as a stream in the requested format (one of the `ION_FORMAT_*` codes).
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_write(
+    element: *const IonElement,
+    format: u32,
+    buffer: *mut IonBuffer,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = borrow(element, "element")?;
+        let buffer = output(buffer, "buffer")?;
+        let encoded = encode(&Sequence::new([element.0.clone()]), format)?;
+        *buffer = new_buffer(encoded);
+        Ok(())
+    })
+}
+
+/// Serializes the elements in `sequence` as a stream in the requested format (one of the
+/// `ION_FORMAT_*` codes).
+#[no_mangle]
+pub unsafe extern "C" fn ion_sequence_write(
+    sequence: *const IonSequence,
+    format: u32,
+    buffer: *mut IonBuffer,
+) -> IonStatus {
+    ffi_call(|| {
+        let sequence = borrow(sequence, "sequence")?;
+        let buffer = output(buffer, "buffer")?;
+        *buffer = new_buffer(encode(&sequence.0, format)?);
+        Ok(())
+    })
+}
+
+fn new_buffer(bytes: Vec<u8>) -> IonBuffer {
+    let len = bytes.len();
+    let data = Box::into_raw(bytes.into_boxed_slice()) as *mut u8;
+    IonBuffer { data, len }
+}
+
+/// Releases an element. Does nothing if `element` is null.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_free(element: *mut IonElement) {
+    if !element.is_null() {
+        drop(Box::from_raw(element));
+    }
+}
+
+/// Releases a sequence and the elements in it. Does nothing if `sequence` is null.
+#[no_mangle]
+pub unsafe extern "C" fn ion_sequence_free(sequence: *mut IonSequence) {
+    if !sequence.is_null() {
+        drop(Box::from_raw(sequence));
+    }
+}
+
+/// Returns an owned copy of `element`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_clone(element: *const IonElement) -> *mut IonElement {
+    into_handle((*element).0.clone())
+}
+
+/// Returns `true` if the two elements are equivalent Ion values with the same annotations.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_equals(
+    element: *const IonElement,
+    other: *const IonElement,
+) -> bool {
+    (*element).0 == (*other).0
+}
+
+/// Creates a null of the type with the specified code.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_new_null(
+    ion_type: u32,
+    element: *mut *mut IonElement,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = output(element, "element")?;
+        *element = into_handle(Element::null(ion_type_from_code(ion_type)?));
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn ion_element_new_bool(value: bool) -> *mut IonElement {
+    into_handle(Element::boolean(value))
+}
+
+#[no_mangle]
+pub extern "C" fn ion_element_new_int(value: i64) -> *mut IonElement {
+    into_handle(Element::int(value))
+}
+
+#[no_mangle]
+pub extern "C" fn ion_element_new_float(value: f64) -> *mut IonElement {
+    into_handle(Element::float(value))
+}
+
+/// Creates a decimal with the value `coefficient * 10^exponent`.
+#[no_mangle]
+pub extern "C" fn ion_element_new_decimal(coefficient: i64, exponent: i64) -> *mut IonElement {
+    into_handle(Element::decimal(Decimal::new(coefficient, exponent)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_new_string(
+    data: *const u8,
+    len: usize,
+    element: *mut *mut IonElement,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = output(element, "element")?;
+        *element = into_handle(Element::string(text(data, len)?));
+        Ok(())
+    })
+}
+
+/// Creates a symbol with the provided text. If `data` is null, the symbol's text is unknown
+/// (`$0`).
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_new_symbol(
+    data: *const u8,
+    len: usize,
+    element: *mut *mut IonElement,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = output(element, "element")?;
+        let symbol = match data.is_null() {
+            true => Symbol::unknown_text(),
+            false => Symbol::from(text(data, len)?),
+        };
+        *element = into_handle(Element::symbol(symbol));
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_new_blob(
+    data: *const u8,
+    len: usize,
+    element: *mut *mut IonElement,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = output(element, "element")?;
+        *element = into_handle(Element::blob(bytes(data, len)?));
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_new_clob(
+    data: *const u8,
+    len: usize,
+    element: *mut *mut IonElement,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = output(element, "element")?;
+        *element = into_handle(Element::clob(bytes(data, len)?));
+        Ok(())
+    })
+}
+
+/// Creates a list containing `count` elements from the array `elements`. On success, the list
+/// takes ownership of the elements and they must not be freed by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_new_list(
+    elements: *const *mut IonElement,
+    count: usize,
+    element: *mut *mut IonElement,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = output(element, "element")?;
+        let elements = take_elements(elements, count)?;
+        *element = into_handle(Value::List(Sequence::new(elements)).into());
+        Ok(())
+    })
+}
+
+/// Creates an s-expression containing `count` elements from the array `elements`. On success, the
+/// s-expression takes ownership of the elements and they must not be freed by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_new_sexp(
+    elements: *const *mut IonElement,
+    count: usize,
+    element: *mut *mut IonElement,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = output(element, "element")?;
+        let elements = take_elements(elements, count)?;
+        *element = into_handle(Value::SExp(Sequence::new(elements)).into());
+        Ok(())
+    })
+}
+
+/// Creates a struct with `count` fields from the array `fields`. On success, the struct takes
+/// ownership of the field values and they must not be freed by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_new_struct(
+    fields: *const IonField,
+    count: usize,
+    element: *mut *mut IonElement,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = output(element, "element")?;
+        let fields = if count == 0 {
+            &[]
+        } else {
+            slice::from_raw_parts(borrow(fields, "fields")?, count)
+        };
+        // Validate every field before taking ownership of any of the values.
+        let mut names = Vec::with_capacity(fields.len());
+        for field in fields {
+            names.push(text(field.name, field.name_len)?);
+            if field.value.is_null() {
+                return Err(FfiError::invalid_argument("field values must not be null"));
+            }
+        }
+        let values = fields.iter().map(|field| Box::from_raw(field.value).0);
+        let struct_: Struct = names.into_iter().zip(values).collect();
+        *element = into_handle(struct_.into());
+        Ok(())
+    })
+}
+
+/// Creates a sequence containing `count` elements from the array `elements`, which can be written
+/// as a stream with [`ion_sequence_write`]. On success, the sequence takes ownership of the
+/// elements and they must not be freed by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn ion_sequence_new(
+    elements: *const *mut IonElement,
+    count: usize,
+    sequence: *mut *mut IonSequence,
+) -> IonStatus {
+    ffi_call(|| {
+        let sequence = output(sequence, "sequence")?;
+        let elements = take_elements(elements, count)?;
+        *sequence = Box::into_raw(Box::new(IonSequence(Sequence::new(elements))));
+        Ok(())
+    })
+}
+
+/// Returns the code of the element's Ion type. Nulls have the code of their type, so `null.int`
+/// is `ION_TYPE_INT`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_type(element: *const IonElement) -> u32 {
+    ion_type_code((*element).0.ion_type())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_is_null(element: *const IonElement) -> bool {
+    (*element).0.is_null()
+}
+
+/// Fails with `ION_STATUS_TYPE_MISMATCH` unless the element is a non-null `expected` value.
+fn expect_type(element: &Element, expected: IonType) -> FfiResult<()> {
+    if element.ion_type() != expected || element.is_null() {
+        return Err(FfiError::type_mismatch(format!(
+            "expected a non-null {expected}, found {element}"
+        )));
+    }
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_as_bool(
+    element: *const IonElement,
+    value: *mut bool,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = &borrow(element, "element")?.0;
+        expect_type(element, IonType::Bool)?;
+        *output(value, "value")? = element.as_bool().unwrap();
+        Ok(())
+    })
+}
+
+/// Gets the value of an int. Fails with `ION_STATUS_TYPE_MISMATCH` if the element is not an int
+/// or if its value does not fit in an `int64_t`.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_as_int(
+    element: *const IonElement,
+    value: *mut i64,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = &borrow(element, "element")?.0;
+        expect_type(element, IonType::Int)?;
+        *output(value, "value")? = element.as_i64().ok_or_else(|| {
+            FfiError::type_mismatch(format!("int {element} does not fit in an int64_t"))
+        })?;
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_as_float(
+    element: *const IonElement,
+    value: *mut f64,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = &borrow(element, "element")?.0;
+        expect_type(element, IonType::Float)?;
+        *output(value, "value")? = element.as_float().unwrap();
+        Ok(())
+    })
+}
+
+/// Gets a borrowed view of the text of a string or symbol. If the element is a symbol with
+/// unknown text, `*data` is set to null.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_as_text(
+    element: *const IonElement,
+    data: *mut *const u8,
+    len: *mut usize,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = &borrow(element, "element")?.0;
+        let text = match element.value() {
+            Value::String(text) => Some(text.text()),
+            Value::Symbol(symbol) => symbol.text(),
+            _ => {
+                return Err(FfiError::type_mismatch(format!(
+                    "expected a non-null string or symbol, found {element}"
+                )))
+            }
+        };
+        write_text(text, data, len)
+    })
+}
+
+/// Gets a borrowed view of the bytes of a blob or clob.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_as_lob(
+    element: *const IonElement,
+    data: *mut *const u8,
+    len: *mut usize,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = &borrow(element, "element")?.0;
+        let bytes = element.as_lob().ok_or_else(|| {
+            FfiError::type_mismatch(format!("expected a non-null blob or clob, found {element}"))
+        })?;
+        *output(data, "data")? = bytes.as_ptr();
+        *output(len, "len")? = bytes.len();
+        Ok(())
+    })
+}
+
+/// Returns a borrowed view of the elements in a list or s-expression, or null if the element is
+/// not a non-null list or s-expression.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_as_sequence(element: *const IonElement) -> *const IonSequence {
+    match (*element).0.as_sequence() {
+        Some(sequence) => sequence as *const Sequence as *const IonSequence,
+        None => ptr::null(),
+    }
+}
+
+/// Returns a borrowed view of the fields of a struct, or null if the element is not a non-null
+/// struct.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_as_struct(element: *const IonElement) -> *const IonStruct {
+    match (*element).0.as_struct() {
+        Some(struct_) => struct_ as *const Struct as *const IonStruct,
+        None => ptr::null(),
+    }
+}
+
+/// Returns the serialized text Ion representation of a decimal, timestamp, or any other element.
+/// The text is written to `buffer`, which must be released with [`ion_buffer_free`].
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_to_text(
+    element: *const IonElement,
+    buffer: *mut IonBuffer,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = borrow(element, "element")?;
+        *output(buffer, "buffer")? = new_buffer(element.0.to_string().into_bytes());
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_annotation_count(element: *const IonElement) -> usize {
+    (*element).0.annotations().len()
+}
+
+/// Gets a borrowed view of the text of the annotation at `index`. If the annotation's text is
+/// unknown, `*data` is set to null.
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_annotation(
+    element: *const IonElement,
+    index: usize,
+    data: *mut *const u8,
+    len: *mut usize,
+) -> IonStatus {
+    ffi_call(|| {
+        let annotations = borrow(element, "element")?.0.annotations();
+        let annotation = annotations.iter().nth(index).ok_or_else(|| {
+            FfiError::invalid_argument(format!(
+                "annotation index {index} is out of range; the element has {} annotations",
+                annotations.len()
+            ))
+        })?;
+        write_text(annotation.text(), data, len)
+    })
+}
+
+/// Appends an annotation to the element's annotations. If `data` is null, the annotation's text
+/// is unknown (`$0`).
+#[no_mangle]
+pub unsafe extern "C" fn ion_element_add_annotation(
+    element: *mut IonElement,
+    data: *const u8,
+    len: usize,
+) -> IonStatus {
+    ffi_call(|| {
+        let element = &mut output(element, "element")?.0;
+        let annotation = match data.is_null() {
+            true => Symbol::unknown_text(),
+            false => Symbol::from(text(data, len)?),
+        };
+        let mut annotations: Vec<Symbol> = element.annotations().iter().cloned().collect();
+        annotations.push(annotation);
+        let unannotated = std::mem::replace(element, Element::null(IonType::Null));
+        *element = unannotated.with_annotations(annotations);
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_sequence_len(sequence: *const IonSequence) -> usize {
+    (*sequence).0.len()
+}
+
+/// Returns a borrowed view of the element at `index`, or null if `index` is out of range.
+#[no_mangle]
+pub unsafe extern "C" fn ion_sequence_get(
+    sequence: *const IonSequence,
+    index: usize,
+) -> *const IonElement {
+    match (*sequence).0.get(index) {
+        Some(element) => borrowed_element(element),
+        None => ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ion_struct_len(struct_: *const IonStruct) -> usize {
+    (*struct_).0.len()
+}
+
+/// Gets borrowed views of the name and value of the field at `index`. If the field name's text is
+/// unknown, `*name` is set to null.
+#[no_mangle]
+pub unsafe extern "C" fn ion_struct_field(
+    struct_: *const IonStruct,
+    index: usize,
+    name: *mut *const u8,
+    name_len: *mut usize,
+    value: *mut *const IonElement,
+) -> IonStatus {
+    ffi_call(|| {
+        let struct_ = &borrow(struct_, "struct")?.0;
+        let (field_name, field_value) = struct_.field_at(index).ok_or_else(|| {
+            FfiError::invalid_argument(format!(
+                "field index {index} is out of range; the struct has {} fields",
+                struct_.len()
+            ))
+        })?;
+        write_text(field_name.text(), name, name_len)?;
+        *output(value, "value")? = borrowed_element(field_value);
+        Ok(())
+    })
+}
+
+/// Returns a borrowed view of the value of the last field named `name`, or null if the struct
+/// has no such field.
+#[no_mangle]
+pub unsafe extern "C" fn ion_struct_get(
+    struct_: *const IonStruct,
+    name: *const u8,
+    name_len: usize,
+) -> *const IonElement {
+    let Ok(name) = text(name, name_len) else {
+        return ptr::null();
+    };
+    match (*struct_).0.get(name) {
+        Some(element) => borrowed_element(element),
+        None => ptr::null(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+
+    fn read_one(ion: &str) -> *mut IonElement {
+        let mut element = ptr::null_mut();
+        let status = unsafe { ion_element_read_one(ion.as_ptr(), ion.len(), &mut element) };
+        assert_eq!(status, IonStatus::Ok);
+        element
+    }
+
+    unsafe fn text_of(data: *const u8, len: usize) -> &'static str {
+        str::from_utf8(slice::from_raw_parts(data, len)).unwrap()
+    }
+
+    unsafe fn write(element: *const IonElement, format: u32) -> Vec<u8> {
+        let mut buffer = IonBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        };
+        assert_eq!(
+            ion_element_write(element, format, &mut buffer),
+            IonStatus::Ok
+        );
+        let bytes = slice::from_raw_parts(buffer.data, buffer.len).to_vec();
+        ion_buffer_free(buffer);
+        bytes
+    }
+
+    #[test]
+    fn inspect_elements() {
+        unsafe {
+            let element = read_one(r#"a::{name: "Alice", age: 42, tags: [x, y], score: 2.5e0}"#);
+            assert_eq!(ion_element_type(element), ION_TYPE_STRUCT);
+            assert_eq!(ion_element_annotation_count(element), 1);
+            let (mut data, mut len) = (ptr::null(), 0);
+            let status = ion_element_annotation(element, 0, &mut data, &mut len);
+            assert_eq!(status, IonStatus::Ok);
+            assert_eq!(text_of(data, len), "a");
+
+            let struct_ = ion_element_as_struct(element);
+            assert_eq!(ion_struct_len(struct_), 4);
+            let (mut name, mut name_len, mut value) = (ptr::null(), 0, ptr::null());
+            let status = ion_struct_field(struct_, 0, &mut name, &mut name_len, &mut value);
+            assert_eq!(status, IonStatus::Ok);
+            assert_eq!(text_of(name, name_len), "name");
+            assert_eq!(
+                ion_element_as_text(value, &mut data, &mut len),
+                IonStatus::Ok
+            );
+            assert_eq!(text_of(data, len), "Alice");
+
+            let age = ion_struct_get(struct_, "age".as_ptr(), 3);
+            let mut int = 0i64;
+            assert_eq!(ion_element_as_int(age, &mut int), IonStatus::Ok);
+            assert_eq!(int, 42);
+            let mut float = 0f64;
+            let score = ion_struct_get(struct_, "score".as_ptr(), 5);
+            assert_eq!(ion_element_as_float(score, &mut float), IonStatus::Ok);
+            assert_eq!(float, 2.5);
+
+            let tags = ion_element_as_sequence(ion_struct_get(struct_, "tags".as_ptr(), 4));
+            assert_eq!(ion_sequence_len(tags), 2);
+            let y = ion_sequence_get(tags, 1);
+            assert_eq!(ion_element_as_text(y, &mut data, &mut len), IonStatus::Ok);
+            assert_eq!(text_of(data, len), "y");
+            assert!(ion_sequence_get(tags, 2).is_null());
+            assert!(ion_struct_get(struct_, "missing".as_ptr(), 7).is_null());
+
+            ion_element_free(element);
+        }
+    }
+
+    #[test]
+    fn build_and_write_elements() {
+        unsafe {
+            let mut name = ptr::null_mut();
+            assert_eq!(
+                ion_element_new_string("Bob".as_ptr(), 3, &mut name),
+                IonStatus::Ok
+            );
+            let mut items = ptr::null_mut();
+            let elements = [ion_element_new_int(1), ion_element_new_bool(true)];
+            let status = ion_element_new_list(elements.as_ptr(), elements.len(), &mut items);
+            assert_eq!(status, IonStatus::Ok);
+            let fields = [
+                IonField {
+                    name: "name".as_ptr(),
+                    name_len: 4,
+                    value: name,
+                },
+                IonField {
+                    name: "items".as_ptr(),
+                    name_len: 5,
+                    value: items,
+                },
+                IonField {
+                    name: "price".as_ptr(),
+                    name_len: 5,
+                    value: ion_element_new_decimal(125, -2),
+                },
+            ];
+            let mut struct_ = ptr::null_mut();
+            let status = ion_element_new_struct(fields.as_ptr(), fields.len(), &mut struct_);
+            assert_eq!(status, IonStatus::Ok);
+            let status = ion_element_add_annotation(struct_, "order".as_ptr(), 5);
+            assert_eq!(status, IonStatus::Ok);
+
+            let expected = read_one(r#"order::{name: "Bob", items: [1, true], price: 1.25}"#);
+            assert!(ion_element_equals(struct_, expected));
+            for format in [
+                ION_FORMAT_TEXT_COMPACT,
+                ION_FORMAT_TEXT_LINES,
+                ION_FORMAT_TEXT_PRETTY,
+                ION_FORMAT_BINARY,
+            ] {
+                let bytes = write(struct_, format);
+                assert_eq!(Element::read_one(bytes).unwrap(), (*expected).0);
+            }
+
+            let copy = ion_element_clone(struct_);
+            ion_element_free(struct_);
+            assert!(ion_element_equals(copy, expected));
+            ion_element_free(copy);
+            ion_element_free(expected);
+        }
+    }
+
+    #[test]
+    fn read_and_write_sequences() {
+        unsafe {
+            let ion = "1 two \"three\"";
+            let mut sequence = ptr::null_mut();
+            let status = ion_sequence_read_all(ion.as_ptr(), ion.len(), &mut sequence);
+            assert_eq!(status, IonStatus::Ok);
+            assert_eq!(ion_sequence_len(sequence), 3);
+            let mut buffer = IonBuffer {
+                data: ptr::null_mut(),
+                len: 0,
+            };
+            let status = ion_sequence_write(sequence, ION_FORMAT_BINARY, &mut buffer);
+            assert_eq!(status, IonStatus::Ok);
+            let bytes = slice::from_raw_parts(buffer.data, buffer.len);
+            assert_eq!(Element::read_all(bytes).unwrap(), (*sequence).0);
+            ion_buffer_free(buffer);
+            ion_sequence_free(sequence);
+
+            let elements = [ion_element_new_int(1), ion_element_new_float(2.5)];
+            let status = ion_sequence_new(elements.as_ptr(), elements.len(), &mut sequence);
+            assert_eq!(status, IonStatus::Ok);
+            let status = ion_sequence_write(sequence, ION_FORMAT_TEXT_COMPACT, &mut buffer);
+            assert_eq!(status, IonStatus::Ok);
+            let bytes = slice::from_raw_parts(buffer.data, buffer.len);
+            assert_eq!(str::from_utf8(bytes).unwrap(), "1 2.5e0 ");
+            ion_buffer_free(buffer);
+            ion_sequence_free(sequence);
+        }
+    }
+
+    #[test]
+    fn nulls_and_unknown_text() {
+        unsafe {
+            let mut null_int = ptr::null_mut();
+            assert_eq!(
+                ion_element_new_null(ION_TYPE_INT, &mut null_int),
+                IonStatus::Ok
+            );
+            assert_eq!(ion_element_type(null_int), ION_TYPE_INT);
+            assert!(ion_element_is_null(null_int));
+            let mut int = 0;
+            assert_eq!(
+                ion_element_as_int(null_int, &mut int),
+                IonStatus::TypeMismatch
+            );
+            ion_element_free(null_int);
+
+            let mut symbol = ptr::null_mut();
+            let status = ion_element_new_symbol(ptr::null(), 0, &mut symbol);
+            assert_eq!(status, IonStatus::Ok);
+            let (mut data, mut len) = (ptr::null(), 1);
+            assert_eq!(
+                ion_element_as_text(symbol, &mut data, &mut len),
+                IonStatus::Ok
+            );
+            assert!(data.is_null());
+            assert_eq!(len, 0);
+            ion_element_free(symbol);
+        }
+    }
+
+    #[test]
+    fn failures_are_reported() {
+        unsafe {
+            let mut element = ptr::null_mut();
+            let ion = "{a: 1";
+            let status = ion_element_read_one(ion.as_ptr(), ion.len(), &mut element);
+            assert_eq!(status, IonStatus::Decoding);
+            assert!(element.is_null());
+            assert!(!ion_last_error_message().is_null());
+
+            let status = ion_element_new_null(99, &mut element);
+            assert_eq!(status, IonStatus::InvalidArgument);
+            let message = CStr::from_ptr(ion_last_error_message());
+            assert_eq!(message.to_str().unwrap(), "unknown Ion type code 99");
+
+            let invalid_utf8 = [0xFFu8];
+            let status = ion_element_new_string(invalid_utf8.as_ptr(), 1, &mut element);
+            assert_eq!(status, IonStatus::InvalidArgument);
+
+            let status = ion_element_read_one(ptr::null(), 5, &mut element);
+            assert_eq!(status, IonStatus::InvalidArgument);
+
+            let int = ion_element_new_int(i64::MAX);
+            let mut float = 0f64;
+            assert_eq!(
+                ion_element_as_float(int, &mut float),
+                IonStatus::TypeMismatch
+            );
+            assert!(ion_element_as_sequence(int).is_null());
+            ion_element_free(int);
+
+            let elements = [ion_element_new_int(1), ptr::null_mut()];
+            let status = ion_element_new_list(elements.as_ptr(), elements.len(), &mut element);
+            assert_eq!(status, IonStatus::InvalidArgument);
+            // Ownership of the elements stays with the caller when construction fails.
+            ion_element_free(elements[0]);
+        }
+    }
+}
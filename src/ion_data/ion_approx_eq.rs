@@ -0,0 +1,257 @@
+use std::ops::Deref;
+
+use crate::ion_data::{ion_eq_f64, IonEq};
+use crate::{Element, Sequence, Struct, Value};
+
+/// How far apart two `float` values may be while still being considered equivalent by
+/// [`IonData::approx_eq`](crate::IonData::approx_eq).
+///
+/// Two floats are considered equivalent if they are Ion equivalent or if the difference between
+/// them is no greater than either the absolute tolerance or the relative tolerance multiplied by
+/// the larger of their magnitudes. Both tolerances default to zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FloatTolerance {
+    absolute: f64,
+    relative: f64,
+}
+
+impl FloatTolerance {
+    /// Creates a tolerance that accepts floats whose difference is at most `absolute`.
+    pub fn absolute(absolute: f64) -> Self {
+        Self::default().with_absolute(absolute)
+    }
+
+    /// Creates a tolerance that accepts floats whose difference is at most `relative` times the
+    /// larger of their magnitudes.
+    pub fn relative(relative: f64) -> Self {
+        Self::default().with_relative(relative)
+    }
+
+    pub fn with_absolute(mut self, absolute: f64) -> Self {
+        self.absolute = absolute;
+        self
+    }
+
+    pub fn with_relative(mut self, relative: f64) -> Self {
+        self.relative = relative;
+        self
+    }
+
+    /// Returns `true` if `this` and `that` are equivalent within this tolerance.
+    pub fn accepts(&self, this: f64, that: f64) -> bool {
+        if ion_eq_f64(&this, &that) {
+            return true;
+        }
+        // If either value is NaN or infinite, the difference is NaN or infinite and the
+        // comparisons below are false.
+        let difference = (this - that).abs();
+        difference <= self.absolute || difference <= self.relative * this.abs().max(that.abs())
+    }
+}
+
+/// Options that relax Ion's structural equivalence. See
+/// [`IonData::eq_with`](crate::IonData::eq_with).
+///
+/// By default, no rules are relaxed and values are compared using Ion equivalence.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct IonEqOptions {
+    float_tolerance: FloatTolerance,
+    ignore_annotations: bool,
+    interchangeable_text: bool,
+}
+
+impl IonEqOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compares `float` values using the given [`FloatTolerance`].
+    pub fn with_float_tolerance(mut self, tolerance: FloatTolerance) -> Self {
+        self.float_tolerance = tolerance;
+        self
+    }
+
+    /// Controls whether the annotations on values (at any depth) are ignored.
+    pub fn with_annotations_ignored(mut self, enabled: bool) -> Self {
+        self.ignore_annotations = enabled;
+        self
+    }
+
+    /// Controls whether a string and a symbol with the same text are considered equivalent.
+    /// Field names and annotations are always symbols and are unaffected.
+    pub fn with_interchangeable_text(mut self, enabled: bool) -> Self {
+        self.interchangeable_text = enabled;
+        self
+    }
+}
+
+/// Like [`IonEq`], but the rules of Ion equivalence can be relaxed using [`IonEqOptions`].
+pub trait IonApproxEq {
+    fn ion_approx_eq(&self, other: &Self, options: &IonEqOptions) -> bool;
+}
+
+impl<R: Deref> IonApproxEq for R
+where
+    R::Target: IonApproxEq,
+{
+    fn ion_approx_eq(&self, other: &Self, options: &IonEqOptions) -> bool {
+        R::Target::ion_approx_eq(self, other, options)
+    }
+}
+
+impl<T: IonApproxEq> IonApproxEq for [T] {
+    fn ion_approx_eq(&self, other: &Self, options: &IonEqOptions) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(v1, v2)| v1.ion_approx_eq(v2, options))
+    }
+}
+
+impl IonApproxEq for Element {
+    fn ion_approx_eq(&self, other: &Self, options: &IonEqOptions) -> bool {
+        (options.ignore_annotations || self.annotations() == other.annotations())
+            && self.value().ion_approx_eq(other.value(), options)
+    }
+}
+
+impl IonApproxEq for Value {
+    fn ion_approx_eq(&self, other: &Self, options: &IonEqOptions) -> bool {
+        use Value::*;
+        match (self, other) {
+            (Float(this), Float(that)) => options.float_tolerance.accepts(*this, *that),
+            (String(string), Symbol(symbol)) | (Symbol(symbol), String(string))
+                if options.interchangeable_text =>
+            {
+                symbol.text() == Some(string.text())
+            }
+            (List(this), List(that)) | (SExp(this), SExp(that)) => {
+                this.ion_approx_eq(that, options)
+            }
+            (Struct(this), Struct(that)) => this.ion_approx_eq(that, options),
+            _ => self.ion_eq(other),
+        }
+    }
+}
+
+impl IonApproxEq for Sequence {
+    fn ion_approx_eq(&self, other: &Self, options: &IonEqOptions) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(v1, v2)| v1.ion_approx_eq(v2, options))
+    }
+}
+
+impl IonApproxEq for Struct {
+    fn ion_approx_eq(&self, other: &Self, options: &IonEqOptions) -> bool {
+        // Like `Struct`'s `PartialEq` implementation, this checks that each field has an
+        // equivalent counterpart with the same name in both directions.
+        fn fields_approx_eq(this: &Struct, that: &Struct, options: &IonEqOptions) -> bool {
+            this.fields().all(|(name, value)| {
+                this.get_all(name).count() == that.get_all(name).count()
+                    && that
+                        .get_all(name)
+                        .any(|other_value| value.ion_approx_eq(other_value, options))
+            })
+        }
+        self.len() == other.len()
+            && fields_approx_eq(self, other, options)
+            && fields_approx_eq(other, self, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::IonResult;
+
+    fn assert_approx_eq(
+        this: &str,
+        that: &str,
+        options: IonEqOptions,
+        expected: bool,
+    ) -> IonResult<()> {
+        let this = Element::read_one(this)?;
+        let that = Element::read_one(that)?;
+        assert_eq!(this.ion_approx_eq(&that, &options), expected);
+        assert_eq!(that.ion_approx_eq(&this, &options), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::exact("1e0", "1e0", FloatTolerance::default(), true)]
+    #[case::nan("nan", "nan", FloatTolerance::default(), true)]
+    #[case::last_bit(
+        "0.30000000000000004e0",
+        "0.3e0",
+        FloatTolerance::relative(1e-12),
+        true
+    )]
+    #[case::last_bit_without_tolerance(
+        "0.30000000000000004e0",
+        "0.3e0",
+        FloatTolerance::default(),
+        false
+    )]
+    #[case::absolute("1.05e0", "1e0", FloatTolerance::absolute(0.1), true)]
+    #[case::outside_absolute("1.5e0", "1e0", FloatTolerance::absolute(0.1), false)]
+    #[case::relative("1001e0", "1000e0", FloatTolerance::relative(0.01), true)]
+    #[case::infinities("+inf", "-inf", FloatTolerance::absolute(f64::MAX), false)]
+    #[case::nested(
+        "a::{x: [1.0000001e0, (2e0)], y: 3e0}",
+        "a::{y: 3.0000001e0, x: [1e0, (2e0)]}",
+        FloatTolerance::absolute(1e-6),
+        true
+    )]
+    #[case::annotations_are_exact("a::1e0", "b::1e0", FloatTolerance::absolute(1.0), false)]
+    #[case::other_types_are_exact("1.0", "1.00", FloatTolerance::absolute(1.0), false)]
+    #[case::repeated_fields(
+        "{x: 1e0, x: 1e0}",
+        "{x: 1e0, x: 9e0}",
+        FloatTolerance::absolute(1e-6),
+        false
+    )]
+    fn compare_with_tolerance(
+        #[case] this: &str,
+        #[case] that: &str,
+        #[case] tolerance: FloatTolerance,
+        #[case] expected: bool,
+    ) -> IonResult<()> {
+        let options = IonEqOptions::new().with_float_tolerance(tolerance);
+        assert_approx_eq(this, that, options, expected)
+    }
+
+    #[rstest]
+    #[case::top_level("a::b::1", "1", true)]
+    #[case::nested("{x: a::[b::1, (c::2)]}", "{x: [1, (2)]}", true)]
+    #[case::values_still_compared("a::1", "a::2", false)]
+    #[case::text_still_distinct("\"a\"", "a", false)]
+    fn compare_ignoring_annotations(
+        #[case] this: &str,
+        #[case] that: &str,
+        #[case] expected: bool,
+    ) -> IonResult<()> {
+        let options = IonEqOptions::new().with_annotations_ignored(true);
+        assert_approx_eq(this, that, options, expected)
+    }
+
+    #[rstest]
+    #[case::string_and_symbol("\"abc\"", "abc", true)]
+    #[case::nested("{k: [\"a\", b]}", "{k: [a, \"b\"]}", true)]
+    #[case::different_text("\"abc\"", "abd", false)]
+    #[case::unknown_text("\"$0\"", "$0", false)]
+    #[case::annotations_still_compared("x::\"a\"", "y::a", false)]
+    fn compare_with_interchangeable_text(
+        #[case] this: &str,
+        #[case] that: &str,
+        #[case] expected: bool,
+    ) -> IonResult<()> {
+        let options = IonEqOptions::new().with_interchangeable_text(true);
+        assert_approx_eq(this, that, options, expected)
+    }
+}
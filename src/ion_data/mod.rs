@@ -1,3 +1,4 @@
+mod ion_approx_eq;
 mod ion_eq;
 mod ion_ord;
 
@@ -5,6 +6,8 @@ use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 
+pub(crate) use ion_approx_eq::IonApproxEq;
+pub use ion_approx_eq::{FloatTolerance, IonEqOptions};
 pub(crate) use ion_eq::{ion_eq_bool, ion_eq_f64, IonEq};
 pub(crate) use ion_ord::{ion_cmp_bool, ion_cmp_f64, IonOrd};
 
@@ -50,6 +53,53 @@ impl<T: IonEq> IonData<T> {
     }
 }
 
+impl<T: IonApproxEq> IonData<T> {
+    /// Checks if two values are equivalent according to Ion's structural equivalence, except that
+    /// `float` values only need to be within the given `tolerance` of each other. This is useful
+    /// for comparing data containing floats that were recomputed and may differ in their last
+    /// few bits.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, FloatTolerance, IonData};
+    ///
+    /// let expected = Element::read_one("{mean: 0.3e0, samples: 3}")?;
+    /// let actual = Element::read_one("{mean: 0.30000000000000004e0, samples: 3}")?;
+    /// assert!(!IonData::eq(&expected, &actual));
+    /// assert!(IonData::approx_eq(&expected, &actual, FloatTolerance::relative(1e-12)));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn approx_eq<R: Deref<Target = T>>(a: R, b: R, tolerance: FloatTolerance) -> bool {
+        let options = IonEqOptions::new().with_float_tolerance(tolerance);
+        T::ion_approx_eq(a.deref(), b.deref(), &options)
+    }
+
+    /// Checks if two values are equivalent according to Ion's structural equivalence, relaxed
+    /// according to the given [`IonEqOptions`]. For example, records from producers that annotate
+    /// their values differently can be compared by their data alone.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, IonData, IonEqOptions};
+    ///
+    /// let a = Element::read_one(r#"order::{id: 7, status: "shipped"}"#)?;
+    /// let b = Element::read_one(r#"{id: 7, status: shipped}"#)?;
+    /// let options = IonEqOptions::new()
+    ///     .with_annotations_ignored(true)
+    ///     .with_interchangeable_text(true);
+    /// assert!(!IonData::eq(&a, &b));
+    /// assert!(IonData::eq_with(&a, &b, &options));
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn eq_with<R: Deref<Target = T>>(a: R, b: R, options: &IonEqOptions) -> bool {
+        T::ion_approx_eq(a.deref(), b.deref(), options)
+    }
+}
+
 impl<T: IonEq> PartialEq for IonData<T> {
     fn eq(&self, other: &Self) -> bool {
         self.0.ion_eq(&other.0)
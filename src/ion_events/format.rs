@@ -0,0 +1,309 @@
+use crate::ion_events::{EventStream, EventType, IonEvent};
+use crate::result::IonFailure;
+use crate::{
+    v1_0, Annotations, Element, Encoding, Int, IonError, IonResult, IonType, Sequence, Struct,
+    Symbol, Value, WriteConfig,
+};
+
+/// The symbol that begins every encoded event stream.
+const EVENT_STREAM_MARKER: &str = "$ion_event_stream";
+
+impl EventStream {
+    /// Reads an event stream that was encoded in the event stream format (text or binary Ion).
+    pub fn read(data: impl AsRef<[u8]>) -> IonResult<Self> {
+        let elements = Element::read_all(data)?;
+        let mut elements = elements.iter();
+        match elements.next() {
+            Some(marker)
+                if marker.as_symbol().and_then(Symbol::text) == Some(EVENT_STREAM_MARKER) => {}
+            _ => {
+                return IonResult::decoding_error(format!(
+                    "an event stream must begin with the symbol '{EVENT_STREAM_MARKER}'"
+                ))
+            }
+        }
+        let events = elements
+            .map(IonEvent::from_element)
+            .collect::<IonResult<Vec<_>>>()?;
+        Ok(Self::new(events))
+    }
+
+    /// Encodes this event stream in the event stream format using the provided configuration.
+    pub fn encode_as<E: Encoding, C: Into<WriteConfig<E>>>(
+        &self,
+        config: C,
+    ) -> IonResult<E::Output> {
+        let mut elements = vec![Element::symbol(EVENT_STREAM_MARKER)];
+        for event in &self.events {
+            elements.push(event.to_element()?);
+        }
+        Sequence::new(elements).encode_as(config)
+    }
+}
+
+impl IonEvent {
+    /// Returns the struct that represents this event in the event stream format.
+    fn to_element(&self) -> IonResult<Element> {
+        let mut fields = vec![("event_type", Element::symbol(self.event_type.name()))];
+        if let Some(ion_type) = self.ion_type {
+            fields.push(("ion_type", Element::symbol(ion_type_name(ion_type))));
+        }
+        if let Some(field_name) = &self.field_name {
+            fields.push(("field_name", symbol_token_to_element(field_name)));
+        }
+        if !self.annotations.is_empty() {
+            let tokens: Sequence = self
+                .annotations
+                .iter()
+                .map(symbol_token_to_element)
+                .collect();
+            fields.push(("annotations", Value::List(tokens).into()));
+        }
+        if let Some(value) = &self.value {
+            fields.push(("value_text", Element::string(value.to_string())));
+            let bytes = value.encode_as(v1_0::Binary)?;
+            let bytes: Sequence = bytes.iter().map(|byte| Element::int(*byte)).collect();
+            fields.push(("value_binary", Value::List(bytes).into()));
+        }
+        fields.push(("depth", Element::int(self.depth as u64)));
+        Ok(Value::Struct(Struct::from_iter(fields)).into())
+    }
+
+    /// Reads an event from its representation in the event stream format.
+    fn from_element(element: &Element) -> IonResult<IonEvent> {
+        let event = element.as_struct().ok_or_else(|| {
+            IonError::decoding_error(format!("expected an event struct, found {element}"))
+        })?;
+
+        let event_type = match event.get("event_type").and_then(Element::as_symbol) {
+            Some(symbol) => symbol.text().and_then(EventType::from_name),
+            None => None,
+        }
+        .ok_or_else(|| {
+            IonError::decoding_error(format!("event does not have a valid event_type: {element}"))
+        })?;
+        let depth = match event.get("depth") {
+            Some(depth) => depth
+                .as_int()
+                .and_then(Int::as_usize)
+                .ok_or_else(|| IonError::decoding_error(format!("invalid event depth: {depth}")))?,
+            None => 0,
+        };
+        let mut ion_event = IonEvent::new(event_type, depth);
+
+        if let Some(ion_type) = event.get("ion_type") {
+            let ion_type = ion_type
+                .as_symbol()
+                .and_then(Symbol::text)
+                .and_then(ion_type_from_name)
+                .ok_or_else(|| IonError::decoding_error(format!("invalid ion_type: {ion_type}")))?;
+            ion_event = ion_event.with_ion_type(ion_type);
+        }
+        if let Some(field_name) = event.get("field_name") {
+            ion_event = ion_event.with_field_name(symbol_token_from_element(field_name)?);
+        }
+        if let Some(annotations) = event.get("annotations") {
+            let annotations = annotations
+                .as_list()
+                .ok_or_else(|| {
+                    IonError::decoding_error(format!("invalid event annotations: {annotations}"))
+                })?
+                .iter()
+                .map(symbol_token_from_element)
+                .collect::<IonResult<Vec<Symbol>>>()?;
+            ion_event = ion_event.with_annotations(Annotations::from(annotations));
+        }
+
+        let value = match (event.get("value_text"), event.get("value_binary")) {
+            (Some(text), _) => {
+                let text = text.as_string().ok_or_else(|| {
+                    IonError::decoding_error(format!("invalid event value_text: {text}"))
+                })?;
+                Some(Element::read_one(text)?)
+            }
+            (None, Some(binary)) => Some(Element::read_one(bytes_from_element(binary)?)?),
+            (None, None) => None,
+        };
+        if let Some(value) = value {
+            if !value.annotations().is_empty() {
+                return IonResult::decoding_error(format!(
+                    "event values cannot have annotations: {value}"
+                ));
+            }
+            if let Some(ion_type) = ion_event.ion_type {
+                if ion_type != value.ion_type() {
+                    return IonResult::decoding_error(format!(
+                        "event has ion_type {} but its value is a {}",
+                        ion_type_name(ion_type),
+                        value.ion_type()
+                    ));
+                }
+            }
+            ion_event = ion_event.with_value(value);
+        }
+        Ok(ion_event)
+    }
+}
+
+fn ion_type_name(ion_type: IonType) -> &'static str {
+    match ion_type {
+        IonType::Null => "NULL",
+        IonType::Bool => "BOOL",
+        IonType::Int => "INT",
+        IonType::Float => "FLOAT",
+        IonType::Decimal => "DECIMAL",
+        IonType::Timestamp => "TIMESTAMP",
+        IonType::Symbol => "SYMBOL",
+        IonType::String => "STRING",
+        IonType::Clob => "CLOB",
+        IonType::Blob => "BLOB",
+        IonType::List => "LIST",
+        IonType::SExp => "SEXP",
+        IonType::Struct => "STRUCT",
+    }
+}
+
+fn ion_type_from_name(name: &str) -> Option<IonType> {
+    let ion_type = match name {
+        "NULL" => IonType::Null,
+        "BOOL" => IonType::Bool,
+        "INT" => IonType::Int,
+        "FLOAT" => IonType::Float,
+        "DECIMAL" => IonType::Decimal,
+        "TIMESTAMP" => IonType::Timestamp,
+        "SYMBOL" => IonType::Symbol,
+        "STRING" => IonType::String,
+        "CLOB" => IonType::Clob,
+        "BLOB" => IonType::Blob,
+        "LIST" => IonType::List,
+        "SEXP" => IonType::SExp,
+        "STRUCT" => IonType::Struct,
+        _ => return None,
+    };
+    Some(ion_type)
+}
+
+/// Returns the symbol token struct that represents `symbol`. Symbols with unknown text are
+/// represented by an empty struct.
+fn symbol_token_to_element(symbol: &Symbol) -> Element {
+    let fields: Vec<(&str, Element)> = match symbol.text() {
+        Some(text) => vec![("text", Element::string(text))],
+        None => Vec::new(),
+    };
+    Value::Struct(Struct::from_iter(fields)).into()
+}
+
+fn symbol_token_from_element(element: &Element) -> IonResult<Symbol> {
+    let token = element.as_struct().ok_or_else(|| {
+        IonError::decoding_error(format!("expected a symbol token struct, found {element}"))
+    })?;
+    match token.get("text") {
+        None => Ok(Symbol::unknown_text()),
+        Some(text) if text.is_null() => Ok(Symbol::unknown_text()),
+        Some(text) => text
+            .as_text()
+            .map(Symbol::from)
+            .ok_or_else(|| IonError::decoding_error(format!("invalid symbol token text: {text}"))),
+    }
+}
+
+fn bytes_from_element(element: &Element) -> IonResult<Vec<u8>> {
+    let invalid = || IonError::decoding_error(format!("invalid event value_binary: {element}"));
+    element
+        .as_list()
+        .ok_or_else(invalid)?
+        .iter()
+        .map(|byte| {
+            byte.as_i64()
+                .and_then(|byte| u8::try_from(byte).ok())
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const DATA: &str = r#"
+        null.int true 1 -2.5e0 3.0 2024-01-01T foo "bar" {{"baz"}} {{aGVsbG8=}}
+        a::b::[1, (2 3), {c: d::4, $0: 5}] null.struct
+    "#;
+
+    #[test]
+    fn scalar_event_fields() -> IonResult<()> {
+        let events = EventStream::from_ion("a::1")?;
+        let expected = Element::read_one(
+            r#"{
+                event_type: SCALAR,
+                ion_type: INT,
+                annotations: [{text: "a"}],
+                value_text: "1",
+                value_binary: [224, 1, 0, 234, 33, 1],
+                depth: 0,
+            }"#,
+        )?;
+        assert_eq!(events.events()[0].to_element()?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::text(EventStream::from_ion(DATA)?.encode_as(v1_0::Text)?.into_bytes())]
+    #[case::binary(EventStream::from_ion(DATA)?.encode_as(v1_0::Binary)?)]
+    fn encoded_events_round_trip(#[case] encoded: Vec<u8>) -> IonResult<()> {
+        let expected = EventStream::from_ion(DATA)?;
+        let events = EventStream::read(encoded)?;
+        assert_eq!(events, expected);
+        assert_eq!(events.to_elements()?, Element::read_all(DATA)?);
+        Ok(())
+    }
+
+    #[test]
+    fn read_event_stream() -> IonResult<()> {
+        // Other implementations may include symbol table events, an `imports` field, and only one
+        // of `value_text` and `value_binary`.
+        let events = EventStream::read(
+            r#"
+            $ion_event_stream
+            {event_type: SYMBOL_TABLE, imports: [], depth: 0}
+            {event_type: CONTAINER_START, ion_type: STRUCT, annotations: [{text: "x"}], depth: 0}
+            {event_type: SCALAR, ion_type: SYMBOL, field_name: {text: "a"}, value_text: "b", imports: [], depth: 1}
+            {event_type: SCALAR, ion_type: INT, field_name: {}, value_binary: [224, 1, 0, 234, 33, 7], depth: 1}
+            {event_type: CONTAINER_END, ion_type: STRUCT, depth: 0}
+            {event_type: STREAM_END, depth: 0}
+            "#,
+        )?;
+        assert_eq!(events.events().len(), 6);
+        assert_eq!(
+            events.events()[3].field_name(),
+            Some(&Symbol::unknown_text())
+        );
+        assert_eq!(
+            events.to_elements()?,
+            Element::read_all("x::{a: b, $0: 7}")?
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::missing_marker("{event_type: STREAM_END, depth: 0}")]
+    #[case::not_a_struct("$ion_event_stream STREAM_END")]
+    #[case::unknown_event_type("$ion_event_stream {event_type: VALUE, depth: 0}")]
+    #[case::unknown_ion_type(
+        "$ion_event_stream {event_type: SCALAR, ion_type: NUMBER, value_text: \"1\"}"
+    )]
+    #[case::mismatched_ion_type(
+        "$ion_event_stream {event_type: SCALAR, ion_type: STRING, value_text: \"1\"}"
+    )]
+    #[case::annotated_value("$ion_event_stream {event_type: SCALAR, value_text: \"a::1\"}")]
+    #[case::invalid_value_binary("$ion_event_stream {event_type: SCALAR, value_binary: [256]}")]
+    #[case::invalid_depth("$ion_event_stream {event_type: STREAM_END, depth: -1}")]
+    #[case::invalid_field_name(
+        "$ion_event_stream {event_type: SCALAR, field_name: a, value_text: \"1\"}"
+    )]
+    fn invalid_event_streams_are_rejected(#[case] ion: &str) {
+        assert!(EventStream::read(ion).is_err());
+    }
+}
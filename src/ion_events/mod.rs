@@ -0,0 +1,479 @@
+//! Reading and writing Ion data as streams of events.
+//!
+//! An [`EventStream`] describes Ion data as a flat sequence of [`IonEvent`]s: one for each scalar
+//! value, one for the start and the end of each container, and one marking the end of the
+//! stream. This is the representation used by the cross-implementation conformance tooling
+//! (`ion-test-driver`) to compare the behavior of different Ion implementations, and it is also a
+//! convenient way to inspect the structure of a stream one step at a time.
+//!
+//! Event streams are themselves encoded as Ion: the symbol `$ion_event_stream` followed by one
+//! struct per event. For example, the stream `point::[1]` is described by:
+//!
+//! ```ion
+//! $ion_event_stream
+//! {event_type: CONTAINER_START, ion_type: LIST, annotations: [{text: "point"}], depth: 0}
+//! {event_type: SCALAR, ion_type: INT, value_text: "1", value_binary: [224, 1, 0, 234, 33, 1], depth: 1}
+//! {event_type: CONTAINER_END, ion_type: LIST, depth: 0}
+//! {event_type: STREAM_END, depth: 0}
+//! ```
+//!
+//! Field names and annotations are written as symbol token structs (`{text: "foo"}`); a token
+//! with unknown text is written as an empty struct. The `value_text` field holds the scalar in
+//! Ion text, and `value_binary` holds the bytes of a binary Ion 1.0 stream containing only the
+//! scalar. When reading, `value_text` is used if it is present.
+//!
+//! ## Examples
+//! ```rust
+//! use ion_rs::ion_events::{EventStream, EventType};
+//! use ion_rs::{v1_0, Element, IonResult};
+//!
+//! # fn main() -> IonResult<()> {
+//! let events = EventStream::from_ion("foo::{a: [1, 2]}")?;
+//! let event_types: Vec<EventType> = events.iter().map(|e| e.event_type()).collect();
+//! assert_eq!(event_types.len(), 7);
+//! assert_eq!(event_types[0], EventType::ContainerStart);
+//! assert_eq!(event_types[6], EventType::StreamEnd);
+//!
+//! // Write the events out in the event stream format and read them back in...
+//! let encoded = events.encode_as(v1_0::Text)?;
+//! let decoded = EventStream::read(encoded)?;
+//! assert_eq!(decoded, events);
+//!
+//! // ...then turn them back into the values they describe.
+//! assert_eq!(decoded.to_elements()?, Element::read_all("foo::{a: [1, 2]}")?);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::slice;
+
+use crate::result::IonFailure;
+use crate::{Annotations, Element, IonError, IonResult, IonType, Sequence, Struct, Symbol, Value};
+
+mod format;
+
+/// The kinds of events that can appear in an [`EventStream`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventType {
+    /// The beginning of a non-null list, s-expression, or struct.
+    ContainerStart,
+    /// The end of the container most recently started.
+    ContainerEnd,
+    /// A scalar value or a null of any type, including null containers.
+    Scalar,
+    /// A symbol table. Symbol tables do not describe application data; they are ignored when an
+    /// event stream is converted to values.
+    SymbolTable,
+    /// The end of the stream.
+    StreamEnd,
+}
+
+impl EventType {
+    /// The name used for this event type in the `event_type` field of an encoded event.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventType::ContainerStart => "CONTAINER_START",
+            EventType::ContainerEnd => "CONTAINER_END",
+            EventType::Scalar => "SCALAR",
+            EventType::SymbolTable => "SYMBOL_TABLE",
+            EventType::StreamEnd => "STREAM_END",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<EventType> {
+        let event_type = match name {
+            "CONTAINER_START" => EventType::ContainerStart,
+            "CONTAINER_END" => EventType::ContainerEnd,
+            "SCALAR" => EventType::Scalar,
+            "SYMBOL_TABLE" => EventType::SymbolTable,
+            "STREAM_END" => EventType::StreamEnd,
+            _ => return None,
+        };
+        Some(event_type)
+    }
+}
+
+impl Display for EventType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A single step in an [`EventStream`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IonEvent {
+    event_type: EventType,
+    ion_type: Option<IonType>,
+    field_name: Option<Symbol>,
+    annotations: Annotations,
+    // For scalar events, the value without its annotations.
+    value: Option<Element>,
+    depth: usize,
+}
+
+impl IonEvent {
+    /// Constructs an event of the specified type at the specified container depth. Top-level
+    /// values have a depth of zero.
+    pub fn new(event_type: EventType, depth: usize) -> Self {
+        Self {
+            event_type,
+            ion_type: None,
+            field_name: None,
+            annotations: Annotations::empty(),
+            value: None,
+            depth,
+        }
+    }
+
+    pub fn with_ion_type(mut self, ion_type: IonType) -> Self {
+        self.ion_type = Some(ion_type);
+        self
+    }
+
+    pub fn with_field_name(mut self, field_name: impl Into<Symbol>) -> Self {
+        self.field_name = Some(field_name.into());
+        self
+    }
+
+    pub fn with_annotations(mut self, annotations: impl Into<Annotations>) -> Self {
+        self.annotations = annotations.into();
+        self
+    }
+
+    /// Sets the value of a scalar event. Any annotations on `value` are moved to the event, and
+    /// the event's Ion type is set to that of `value`.
+    pub fn with_value(mut self, value: Element) -> Self {
+        let (value, annotations) = strip_annotations(value);
+        if !annotations.is_empty() {
+            self.annotations = annotations;
+        }
+        self.ion_type = Some(value.ion_type());
+        self.value = Some(value);
+        self
+    }
+
+    pub fn event_type(&self) -> EventType {
+        self.event_type
+    }
+
+    /// The Ion type of the value or container this event describes. Stream end and symbol table
+    /// events do not have an Ion type.
+    pub fn ion_type(&self) -> Option<IonType> {
+        self.ion_type
+    }
+
+    /// The field name of the value or container this event describes, if it is inside a struct.
+    pub fn field_name(&self) -> Option<&Symbol> {
+        self.field_name.as_ref()
+    }
+
+    pub fn annotations(&self) -> &Annotations {
+        &self.annotations
+    }
+
+    /// For scalar events, the value (without annotations) that the event describes.
+    pub fn value(&self) -> Option<&Element> {
+        self.value.as_ref()
+    }
+
+    /// The number of containers that enclose the value this event describes.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+/// A sequence of [`IonEvent`]s describing a stream of Ion values.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventStream {
+    events: Vec<IonEvent>,
+}
+
+impl EventStream {
+    pub fn new(events: Vec<IonEvent>) -> Self {
+        Self { events }
+    }
+
+    /// Reads all of the values in `data` (text or binary Ion) and returns the events that
+    /// describe them.
+    pub fn from_ion(data: impl AsRef<[u8]>) -> IonResult<Self> {
+        Ok(Self::from_elements(&Element::read_all(data)?))
+    }
+
+    /// Returns the events that describe the provided values, followed by a stream end event.
+    pub fn from_elements<'a>(elements: impl IntoIterator<Item = &'a Element>) -> Self {
+        let mut events = Vec::new();
+        for element in elements {
+            push_events(&mut events, None, element, 0);
+        }
+        events.push(IonEvent::new(EventType::StreamEnd, 0));
+        Self { events }
+    }
+
+    pub fn events(&self) -> &[IonEvent] {
+        &self.events
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, IonEvent> {
+        self.events.iter()
+    }
+
+    /// Reconstructs the values that this event stream describes.
+    ///
+    /// Returns an error if the events are not well-formed; for example, if a container is ended
+    /// before it is started, or if a value inside a struct does not have a field name.
+    pub fn to_elements(&self) -> IonResult<Sequence> {
+        let mut top_level = Vec::new();
+        let mut containers: Vec<ContainerInProgress> = Vec::new();
+        for event in &self.events {
+            match event.event_type {
+                EventType::Scalar => {
+                    let value = event.value.clone().ok_or_else(|| {
+                        IonError::decoding_error("found a scalar event without a value")
+                    })?;
+                    let value = value.with_annotations(event.annotations.clone());
+                    add_value(&mut top_level, &mut containers, event, value)?;
+                }
+                EventType::ContainerStart => match event.ion_type {
+                    Some(ion_type) if ion_type.is_container() => {
+                        containers.push(ContainerInProgress {
+                            start_event: event,
+                            values: Vec::new(),
+                        })
+                    }
+                    _ => {
+                        return IonResult::decoding_error(format!(
+                            "found a container start event with a non-container Ion type: {:?}",
+                            event.ion_type
+                        ))
+                    }
+                },
+                EventType::ContainerEnd => {
+                    let container = containers.pop().ok_or_else(|| {
+                        IonError::decoding_error(
+                            "found a container end event that did not match a container start",
+                        )
+                    })?;
+                    let start_event = container.start_event;
+                    if event.ion_type.is_some() && event.ion_type != start_event.ion_type {
+                        return IonResult::decoding_error(format!(
+                            "found a container end event of type {:?} for a container of type {:?}",
+                            event.ion_type, start_event.ion_type
+                        ));
+                    }
+                    let value = container.into_element()?;
+                    add_value(&mut top_level, &mut containers, start_event, value)?;
+                }
+                EventType::SymbolTable => {}
+                EventType::StreamEnd => {
+                    if !containers.is_empty() {
+                        return IonResult::decoding_error(
+                            "found a stream end event inside a container",
+                        );
+                    }
+                }
+            }
+        }
+        if !containers.is_empty() {
+            return IonResult::decoding_error("event stream ended inside a container");
+        }
+        Ok(Sequence::new(top_level))
+    }
+}
+
+impl<'a> IntoIterator for &'a EventStream {
+    type Item = &'a IonEvent;
+    type IntoIter = slice::Iter<'a, IonEvent>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl From<Vec<IonEvent>> for EventStream {
+    fn from(events: Vec<IonEvent>) -> Self {
+        Self::new(events)
+    }
+}
+
+/// Splits `element` into its value and its annotations.
+fn strip_annotations(element: Element) -> (Element, Annotations) {
+    let annotations = element.annotations().clone();
+    let value = Element::from(element.value().clone());
+    (value, annotations)
+}
+
+/// Appends the events describing `element` (and any values nested inside it) to `events`.
+fn push_events(
+    events: &mut Vec<IonEvent>,
+    field_name: Option<&Symbol>,
+    element: &Element,
+    depth: usize,
+) {
+    let with_field_name = |event: IonEvent| match field_name {
+        Some(name) => event.with_field_name(name.clone()),
+        None => event,
+    };
+    let ion_type = element.ion_type();
+    if element.is_null() || !ion_type.is_container() {
+        let event = IonEvent::new(EventType::Scalar, depth).with_value(element.clone());
+        events.push(with_field_name(event));
+        return;
+    }
+    events.push(with_field_name(
+        IonEvent::new(EventType::ContainerStart, depth)
+            .with_ion_type(ion_type)
+            .with_annotations(element.annotations().clone()),
+    ));
+    match element.value() {
+        Value::List(sequence) | Value::SExp(sequence) => {
+            for child in sequence {
+                push_events(events, None, child, depth + 1);
+            }
+        }
+        Value::Struct(strukt) => {
+            for (name, child) in strukt {
+                push_events(events, Some(name), child, depth + 1);
+            }
+        }
+        _ => unreachable!("non-null value of a container type must be a container"),
+    }
+    events.push(IonEvent::new(EventType::ContainerEnd, depth).with_ion_type(ion_type));
+}
+
+// A container whose start event has been seen but whose end event has not.
+struct ContainerInProgress<'a> {
+    start_event: &'a IonEvent,
+    values: Vec<(Option<Symbol>, Element)>,
+}
+
+impl<'a> ContainerInProgress<'a> {
+    fn into_element(self) -> IonResult<Element> {
+        let value = match self.start_event.ion_type {
+            Some(IonType::List) => Value::List(self.values.into_iter().map(|(_, v)| v).collect()),
+            Some(IonType::SExp) => Value::SExp(self.values.into_iter().map(|(_, v)| v).collect()),
+            _ => {
+                let fields = self
+                    .values
+                    .into_iter()
+                    .map(|(name, value)| {
+                        let name = name.ok_or_else(|| {
+                            IonError::decoding_error("found a struct field without a field name")
+                        })?;
+                        Ok((name, value))
+                    })
+                    .collect::<IonResult<Vec<_>>>()?;
+                Value::Struct(Struct::from_iter(fields))
+            }
+        };
+        Ok(Element::from(value).with_annotations(self.start_event.annotations.clone()))
+    }
+}
+
+/// Adds a completed value to the innermost container in progress or, if there isn't one, to the
+/// list of top-level values.
+fn add_value(
+    top_level: &mut Vec<Element>,
+    containers: &mut [ContainerInProgress<'_>],
+    event: &IonEvent,
+    value: Element,
+) -> IonResult<()> {
+    match containers.last_mut() {
+        Some(container) => container.values.push((event.field_name.clone(), value)),
+        None if event.depth != 0 => {
+            return IonResult::decoding_error(format!(
+                "found a top-level event with a depth of {}",
+                event.depth
+            ))
+        }
+        None => top_level.push(value),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ion_data::IonEq;
+
+    fn event_types(events: &EventStream) -> Vec<EventType> {
+        events.iter().map(|event| event.event_type()).collect()
+    }
+
+    #[test]
+    fn events_for_nested_values() -> IonResult<()> {
+        use EventType::*;
+        let events = EventStream::from_ion("a::{b: [1, c::two], d: null.list} 3")?;
+        assert_eq!(
+            event_types(&events),
+            vec![
+                ContainerStart,
+                ContainerStart,
+                Scalar,
+                Scalar,
+                ContainerEnd,
+                Scalar,
+                ContainerEnd,
+                Scalar,
+                StreamEnd
+            ]
+        );
+        let depths: Vec<usize> = events.iter().map(|event| event.depth()).collect();
+        assert_eq!(depths, vec![0, 1, 2, 2, 1, 1, 0, 0, 0]);
+
+        let events = events.events();
+        assert_eq!(events[0].ion_type(), Some(IonType::Struct));
+        assert!(events[0].annotations().contains("a"));
+        assert_eq!(events[1].field_name(), Some(&Symbol::from("b")));
+        assert_eq!(events[3].value(), Some(&Element::symbol("two")));
+        assert!(events[3].annotations().contains("c"));
+        assert_eq!(events[5].field_name(), Some(&Symbol::from("d")));
+        assert_eq!(events[5].ion_type(), Some(IonType::List));
+        assert_eq!(events[5].value(), Some(&Element::null(IonType::List)));
+        assert_eq!(events[8].ion_type(), None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::scalars("null true 1 2.5e0 3.0 2024-01-01T $0 foo \"bar\" {{\"baz\"}} {{aGVsbG8=}}")]
+    #[case::typed_nulls("null.bool null.int null.sexp null.struct")]
+    #[case::containers("[] () {} [1, (2 3), {a: 4, a: 5, $0: 6}]")]
+    #[case::annotations("a::1 b::c::[d::2] $0::{e: f::g::()}")]
+    fn values_round_trip(#[case] ion: &str) -> IonResult<()> {
+        let expected = Element::read_all(ion)?;
+        let actual = EventStream::from_ion(ion)?.to_elements()?;
+        assert!(
+            actual.ion_eq(&expected),
+            "expected {expected:?}, found {actual:?}"
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::unmatched_end(vec![IonEvent::new(EventType::ContainerEnd, 0)])]
+    #[case::unended_container(vec![
+        IonEvent::new(EventType::ContainerStart, 0).with_ion_type(IonType::List),
+    ])]
+    #[case::scalar_without_value(vec![IonEvent::new(EventType::Scalar, 0)])]
+    #[case::scalar_container_start(vec![
+        IonEvent::new(EventType::ContainerStart, 0).with_ion_type(IonType::Int),
+    ])]
+    #[case::mismatched_end(vec![
+        IonEvent::new(EventType::ContainerStart, 0).with_ion_type(IonType::List),
+        IonEvent::new(EventType::ContainerEnd, 0).with_ion_type(IonType::SExp),
+    ])]
+    #[case::field_without_name(vec![
+        IonEvent::new(EventType::ContainerStart, 0).with_ion_type(IonType::Struct),
+        IonEvent::new(EventType::Scalar, 1).with_value(Element::int(1)),
+        IonEvent::new(EventType::ContainerEnd, 0).with_ion_type(IonType::Struct),
+    ])]
+    #[case::nested_top_level_value(vec![
+        IonEvent::new(EventType::Scalar, 1).with_value(Element::int(1)),
+    ])]
+    fn malformed_events_are_rejected(#[case] events: Vec<IonEvent>) {
+        assert!(EventStream::new(events).to_elements().is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::ion_lint::{format_path, Diagnostic, LintConfig, LintReport, LintRule, PathTracker};
+use crate::lazy::decoder::{HasRange, LazyRawValue};
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::lazy::walk::ValueContext;
+use crate::text::lossless::PathComponent;
+use crate::{
+    v1_0, AnyEncoding, Element, IonEncoding, IonResult, IonType, Reader, TextFormat, Value,
+};
+
+/// Checks Ion data streams against the rules in a [`LintConfig`].
+#[derive(Debug, Clone, Default)]
+pub struct Linter {
+    config: LintConfig,
+}
+
+impl Linter {
+    pub fn new(config: LintConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &LintConfig {
+        &self.config
+    }
+
+    /// Reads all of the values in `input`, returning a report of every rule violation found.
+    ///
+    /// The input is read incrementally, so streams that do not fit in memory can be linted. The
+    /// memory used grows with the number of distinct field paths in the stream and with the
+    /// number of diagnostics reported, but not with the number of values.
+    ///
+    /// If the data cannot be read, returns `Err(IonError)`; lint rules only apply to valid Ion.
+    pub fn lint<Input: IonInput>(&self, input: Input) -> IonResult<LintReport> {
+        let mut reader = Reader::new(AnyEncoding, input)?;
+        let mut state = LintState {
+            config: &self.config,
+            paths: PathTracker::default(),
+            field_names: Vec::new(),
+            field_types: HashMap::new(),
+            diagnostics: Vec::new(),
+        };
+        reader.walk(|context, value| {
+            state.visit(context, value)?;
+            Ok(true)
+        })?;
+        Ok(LintReport {
+            diagnostics: state.diagnostics,
+        })
+    }
+}
+
+struct LintState<'a> {
+    config: &'a LintConfig,
+    paths: PathTracker,
+    // For each of the values on the path to the current value, the names of the fields that have
+    // been visited so far in that value (if it is a struct).
+    field_names: Vec<Vec<String>>,
+    // For each field path (see `field_key`), the type of the first non-null value found at that
+    // path and that value's path.
+    field_types: HashMap<String, (IonType, Vec<PathComponent>)>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> LintState<'a> {
+    fn report(&mut self, rule: LintRule, span: Option<Range<usize>>, message: String) {
+        if let Some(severity) = self.config.severity(rule) {
+            self.diagnostics.push(Diagnostic {
+                rule,
+                severity,
+                message,
+                path: self.paths.path().to_vec(),
+                span,
+            })
+        }
+    }
+
+    fn enabled(&self, rule: LintRule) -> bool {
+        self.config.severity(rule).is_some()
+    }
+
+    fn visit(
+        &mut self,
+        context: &ValueContext<'_>,
+        value: LazyValue<'_, AnyEncoding>,
+    ) -> IonResult<()> {
+        let span = value.raw().map(|raw| raw.range());
+        self.paths.visit(context);
+        if context.parent_type() == Some(IonType::Struct) {
+            self.check_field_name(context, &span);
+        }
+        // Forget the field names of the previous value at this depth and of its descendants.
+        let depth = context.depth();
+        self.field_names.truncate(depth);
+        self.field_names.push(Vec::new());
+
+        if self.enabled(LintRule::UnknownSymbolText) {
+            for annotation in value.annotations() {
+                if annotation?.text().is_none() {
+                    self.report(
+                        LintRule::UnknownSymbolText,
+                        span.clone(),
+                        "annotation has unknown text".to_string(),
+                    );
+                }
+            }
+        }
+        if let Some(PathComponent::Field(_)) = self.paths.path().last() {
+            self.check_field_type(value.ion_type(), value.is_null(), &span);
+        }
+
+        match value.read()? {
+            // The children of containers are checked when `walk` visits them.
+            ValueRef::List(_) | ValueRef::SExp(_) | ValueRef::Struct(_) => {}
+            ValueRef::Symbol(symbol) if symbol.text().is_none() => {
+                self.report(
+                    LintRule::UnknownSymbolText,
+                    span,
+                    "symbol value has unknown text".to_string(),
+                );
+            }
+            value_ref => {
+                if self.enabled(LintRule::NonCanonicalEncoding) {
+                    self.check_canonical(value, Value::try_from(value_ref)?, span)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_field_name(&mut self, context: &ValueContext<'_>, span: &Option<Range<usize>>) {
+        let Some(name) = context.field_name() else {
+            return;
+        };
+        let Some(name) = name.text() else {
+            self.report(
+                LintRule::UnknownSymbolText,
+                span.clone(),
+                "field name has unknown text".to_string(),
+            );
+            return;
+        };
+        // The struct's own entry is at the depth above its fields.
+        let seen = &mut self.field_names[context.depth() - 1];
+        if seen.iter().any(|seen_name| seen_name == name) {
+            let message = format!("struct has more than one field named '{name}'");
+            self.report(LintRule::DuplicateFields, span.clone(), message);
+        } else {
+            seen.push(name.to_owned());
+        }
+    }
+
+    fn check_field_type(&mut self, ion_type: IonType, is_null: bool, span: &Option<Range<usize>>) {
+        if is_null || !self.enabled(LintRule::MixedFieldTypes) {
+            return;
+        }
+        let name = field_key(self.paths.path());
+        match self.field_types.get(&name) {
+            None => {
+                let path = self.paths.path().to_vec();
+                self.field_types.insert(name, (ion_type, path));
+            }
+            Some((first_type, _)) if *first_type == ion_type => {}
+            Some((first_type, first_path)) => {
+                let message = format!(
+                    "field '{name}' is a(n) {ion_type}, but was a(n) {first_type} at {}",
+                    format_path(first_path)
+                );
+                self.report(LintRule::MixedFieldTypes, span.clone(), message);
+            }
+        }
+    }
+
+    fn check_canonical(
+        &mut self,
+        lazy_value: LazyValue<'_, AnyEncoding>,
+        value: Value,
+        span: Option<Range<usize>>,
+    ) -> IonResult<()> {
+        let Some(raw) = lazy_value.raw() else {
+            // Values produced by macro expansion have no encoding of their own.
+            return Ok(());
+        };
+        let encoded = raw.value_span().bytes();
+        let message = match raw.encoding() {
+            IonEncoding::Text_1_0 | IonEncoding::Text_1_1 => {
+                let canonical = Element::from(value.clone())
+                    .encode_as(v1_0::Text.with_format(TextFormat::Compact))?;
+                // The writer follows each top-level value with a space.
+                let canonical = canonical.trim_end();
+                (encoded != canonical.as_bytes()).then(|| {
+                    format!(
+                        "{} is not in canonical form; expected `{canonical}`",
+                        value.ion_type()
+                    )
+                })
+            }
+            // A binary symbol's encoding depends on the symbol table, not on its text.
+            IonEncoding::Binary_1_0 if value.ion_type() == IonType::Symbol => None,
+            IonEncoding::Binary_1_0 => {
+                // Skip the IVM at the head of the re-encoded stream.
+                let canonical_length =
+                    Element::from(value.clone()).encode_as(v1_0::Binary)?.len() - 4;
+                (encoded.len() > canonical_length).then(|| {
+                    format!(
+                        "{} is encoded in {} bytes; its canonical encoding is {canonical_length} bytes",
+                        value.ion_type(),
+                        encoded.len()
+                    )
+                })
+            }
+            // Ion 1.1 binary offers several equally valid encodings for most values.
+            _ => None,
+        };
+        if let Some(message) = message {
+            self.report(LintRule::NonCanonicalEncoding, span, message);
+        }
+        Ok(())
+    }
+}
+
+/// Identifies the fields whose values are expected to share a type: the path to the field
+/// without the index of the top-level value it belongs to, and with each index within a list or
+/// s-expression replaced by `[]`. For example, `[3].items[2].id` becomes `items[].id`.
+fn field_key(path: &[PathComponent]) -> String {
+    let mut key = String::new();
+    for component in &path[1..] {
+        match component {
+            PathComponent::Index(_) => key.push_str("[]"),
+            PathComponent::Field(name) => {
+                if !key.is_empty() {
+                    key.push('.');
+                }
+                key.push_str(name);
+            }
+        }
+    }
+    key
+}
@@ -0,0 +1,456 @@
+//! Configurable lint rules and profiling for Ion data streams.
+//!
+//! A [`Linter`] reads a stream of Ion data (text or binary) and reports any values that violate
+//! the rules it has been configured to check. Each problem is reported as a [`Diagnostic`] that
+//! names the rule, the path to the offending value, and the range of input bytes it occupies,
+//! making the results suitable both for display to humans and for gating automated data drops.
+//!
+//! A [`Profiler`] reads a stream and reports its largest values and most deeply nested paths,
+//! which helps to track down the individual records that cause trouble for downstream consumers.
+//!
+//! ## Examples
+//! ```rust
+//! use ion_rs::IonResult;
+//! use ion_rs::ion_lint::{LintConfig, LintRule, Linter, Severity};
+//!
+//! # fn main() -> IonResult<()> {
+//! let data = r#"
+//!     {id: 1, name: "widget"}
+//!     {id: "2", name: "gadget", name: "gizmo"}
+//! "#;
+//!
+//! let config = LintConfig::new().with_rule(LintRule::MixedFieldTypes, Severity::Error);
+//! let linter = Linter::new(config);
+//! let report = linter.lint(data)?;
+//! assert!(report.has_errors());
+//!
+//! let rules: Vec<LintRule> = report.diagnostics().iter().map(|d| d.rule()).collect();
+//! assert_eq!(rules, vec![LintRule::MixedFieldTypes, LintRule::DuplicateFields]);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::walk::ValueContext;
+use crate::text::lossless::PathComponent;
+use crate::IonResult;
+
+mod linter;
+mod profiler;
+
+pub use linter::Linter;
+pub use profiler::{profile, ProfileReport, ProfiledValue, Profiler};
+
+/// The problems that a [`Linter`] can be configured to detect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LintRule {
+    /// A struct contains more than one field with the same name.
+    DuplicateFields,
+    /// Values stored under the same field name do not all have the same Ion type. Null values
+    /// are not considered.
+    MixedFieldTypes,
+    /// A symbol value, annotation, or field name has unknown text (for example: `$0`).
+    UnknownSymbolText,
+    /// A scalar is not written in its canonical form. In text, this is the form that the text
+    /// writer would produce (for example: `0x10` instead of `16`). In binary Ion 1.0, this is an
+    /// encoding that is longer than necessary.
+    NonCanonicalEncoding,
+}
+
+impl LintRule {
+    /// All of the available rules.
+    pub const ALL: [LintRule; 4] = [
+        LintRule::DuplicateFields,
+        LintRule::MixedFieldTypes,
+        LintRule::UnknownSymbolText,
+        LintRule::NonCanonicalEncoding,
+    ];
+
+    /// The name used to identify this rule in diagnostics.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintRule::DuplicateFields => "duplicate-fields",
+            LintRule::MixedFieldTypes => "mixed-field-types",
+            LintRule::UnknownSymbolText => "unknown-symbol-text",
+            LintRule::NonCanonicalEncoding => "non-canonical-encoding",
+        }
+    }
+
+    /// The severity with which this rule is reported by [`LintConfig::new`].
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            LintRule::DuplicateFields | LintRule::UnknownSymbolText => Severity::Error,
+            LintRule::MixedFieldTypes | LintRule::NonCanonicalEncoding => Severity::Warning,
+        }
+    }
+}
+
+impl Display for LintRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// How seriously a rule violation should be treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// The set of rules that a [`Linter`] will check, along with the severity of each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintConfig {
+    rules: Vec<(LintRule, Severity)>,
+}
+
+impl LintConfig {
+    /// Constructs a configuration that checks every rule at its
+    /// [default severity](LintRule::default_severity).
+    pub fn new() -> Self {
+        let rules = LintRule::ALL
+            .iter()
+            .map(|rule| (*rule, rule.default_severity()))
+            .collect();
+        Self { rules }
+    }
+
+    /// Constructs a configuration that does not check any rules.
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Enables `rule`, reporting any violations with the specified `severity`.
+    pub fn with_rule(mut self, rule: LintRule, severity: Severity) -> Self {
+        match self.rules.iter_mut().find(|(r, _)| *r == rule) {
+            Some((_, s)) => *s = severity,
+            None => self.rules.push((rule, severity)),
+        }
+        self
+    }
+
+    /// Disables `rule`.
+    pub fn without_rule(mut self, rule: LintRule) -> Self {
+        self.rules.retain(|(r, _)| *r != rule);
+        self
+    }
+
+    /// Returns the severity with which `rule` is reported, or `None` if it is not enabled.
+    pub fn severity(&self, rule: LintRule) -> Option<Severity> {
+        self.rules
+            .iter()
+            .find(|(r, _)| *r == rule)
+            .map(|(_, severity)| *severity)
+    }
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A single rule violation found by a [`Linter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    rule: LintRule,
+    severity: Severity,
+    message: String,
+    path: Vec<PathComponent>,
+    span: Option<Range<usize>>,
+}
+
+impl Diagnostic {
+    /// The rule that was violated.
+    pub fn rule(&self) -> LintRule {
+        self.rule
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// A human-readable description of the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The path from the top level of the stream to the offending value. The first component is
+    /// always the index of a top-level value.
+    pub fn path(&self) -> &[PathComponent] {
+        &self.path
+    }
+
+    /// The range of input bytes occupied by the offending value (including its annotations), if
+    /// the value appeared literally in the input. Values produced by macro expansion do not have
+    /// a span.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}[{}] at {}",
+            self.severity,
+            self.rule,
+            format_path(&self.path)
+        )?;
+        if let Some(span) = &self.span {
+            write!(f, " (bytes {}..{})", span.start, span.end)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Renders a path as it would be written in a diagnostic: `[0].items[2].name`.
+fn format_path(path: &[PathComponent]) -> String {
+    let mut text = String::new();
+    for component in path {
+        match component {
+            PathComponent::Index(index) => text.push_str(&format!("[{index}]")),
+            PathComponent::Field(name) => text.push_str(&format!(".{name}")),
+        }
+    }
+    text
+}
+
+/// Tracks the path to the value most recently visited by [`Reader::walk`](crate::Reader::walk).
+#[derive(Debug, Default)]
+struct PathTracker {
+    path: Vec<PathComponent>,
+    // For each depth up to and including that of the current value, the index of the next value
+    // that will be visited at that depth.
+    next_indexes: Vec<usize>,
+}
+
+impl PathTracker {
+    /// Updates the path to lead to the value described by `context`, which must be the next
+    /// value visited after the previous call.
+    fn visit(&mut self, context: &ValueContext<'_>) {
+        let depth = context.depth();
+        self.next_indexes.truncate(depth + 1);
+        if self.next_indexes.len() == depth {
+            // This is the first child of the previous value.
+            self.next_indexes.push(0);
+        }
+        let index = self.next_indexes[depth];
+        self.next_indexes[depth] += 1;
+        // Fields with unknown text are identified by their position instead.
+        let component = match context.field_name().and_then(|name| name.text()) {
+            Some(name) => PathComponent::Field(name.to_owned()),
+            None => PathComponent::Index(index),
+        };
+        self.path.truncate(depth);
+        self.path.push(component);
+    }
+
+    fn path(&self) -> &[PathComponent] {
+        &self.path
+    }
+}
+
+/// The diagnostics produced by linting a stream, in the order the offending values were found.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintReport {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl LintReport {
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Returns `true` if no rules were violated.
+    pub fn is_clean(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    /// Returns `true` if any violation was reported with [`Severity::Error`].
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity == Severity::Error)
+    }
+}
+
+impl IntoIterator for LintReport {
+    type Item = Diagnostic;
+    type IntoIter = std::vec::IntoIter<Diagnostic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.diagnostics.into_iter()
+    }
+}
+
+/// Lints the provided Ion data using [`LintConfig::new`].
+pub fn lint<Input: IonInput>(input: Input) -> IonResult<LintReport> {
+    Linter::new(LintConfig::new()).lint(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn rules(report: &LintReport) -> Vec<LintRule> {
+        report.diagnostics().iter().map(|d| d.rule()).collect()
+    }
+
+    #[test]
+    fn clean_data_has_no_diagnostics() -> IonResult<()> {
+        let report = lint(r#"{name: "a", tags: [x, y]} {name: "b", tags: null}"#)?;
+        assert!(report.is_clean());
+        assert!(!report.has_errors());
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_fields() -> IonResult<()> {
+        let data = r#"[{a: 1, b: 2, a: 3}]"#;
+        let report = lint(data)?;
+        assert_eq!(rules(&report), vec![LintRule::DuplicateFields]);
+        let diagnostic = &report.diagnostics()[0];
+        assert_eq!(diagnostic.severity(), Severity::Error);
+        assert_eq!(
+            diagnostic.path(),
+            &[PathComponent::Index(0), 0.into(), "a".into()]
+        );
+        assert_eq!(&data[diagnostic.span().unwrap()], "3");
+        assert_eq!(
+            diagnostic.to_string(),
+            "error[duplicate-fields] at [0][0].a (bytes 17..18): struct has more than one field named 'a'"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn mixed_field_types() -> IonResult<()> {
+        let data = r#"{id: 1} {id: null.string} {nested: {id: "2"}} {id: "3"}"#;
+        let report = lint(data)?;
+        assert_eq!(rules(&report), vec![LintRule::MixedFieldTypes]);
+        let diagnostic = &report.diagnostics()[0];
+        assert_eq!(diagnostic.severity(), Severity::Warning);
+        assert_eq!(
+            diagnostic.message(),
+            "field 'id' is a(n) string, but was a(n) int at [0].id"
+        );
+        assert_eq!(&data[diagnostic.span().unwrap()], r#""3""#);
+        Ok(())
+    }
+
+    #[test]
+    fn mixed_field_types_are_keyed_by_path() -> IonResult<()> {
+        // Each `id` is at a different path, so their types are not compared with one another.
+        let report = lint(r#"{id: 1, parent: {id: "a"}, children: [{id: b}, {id: c}]}"#)?;
+        assert!(report.is_clean(), "{report:?}");
+
+        let report = lint(r#"{items: [{id: 1}, {id: 2}]} {items: [{id: "3"}]}"#)?;
+        assert_eq!(rules(&report), vec![LintRule::MixedFieldTypes]);
+        assert_eq!(
+            report.diagnostics()[0].message(),
+            "field 'items[].id' is a(n) string, but was a(n) int at [0].items[0].id"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn paths_of_nested_values() -> IonResult<()> {
+        let report = lint("[a, ($0 {b: 1, b: 2})] {c: [{d: $0}]}")?;
+        let paths: Vec<String> = report
+            .diagnostics()
+            .iter()
+            .map(|d| format_path(d.path()))
+            .collect();
+        assert_eq!(paths, vec!["[0][1][0]", "[0][1][1].b", "[1].c[0].d"]);
+        Ok(())
+    }
+
+    #[test]
+    fn lint_streamed_input() -> IonResult<()> {
+        let data: &[u8] = b"{a: 1, a: 2}";
+        let report = lint(crate::IonStream::new(data))?;
+        assert_eq!(rules(&report), vec![LintRule::DuplicateFields]);
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_symbol_text() -> IonResult<()> {
+        let report = lint("$0 $0::1 {$0: 2}")?;
+        assert_eq!(rules(&report), vec![LintRule::UnknownSymbolText; 3]);
+        assert_eq!(
+            report.diagnostics()[2].path(),
+            &[PathComponent::Index(2), PathComponent::Index(0)]
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::hex_int("0x10", true)]
+    #[case::underscored_int("1_000", true)]
+    #[case::long_string("'''hello'''", true)]
+    #[case::quoted_symbol("'hello'", true)]
+    #[case::escaped_string(r#""\x41""#, true)]
+    #[case::int("16", false)]
+    #[case::float("1.5e0", false)]
+    #[case::string(r#""hello""#, false)]
+    #[case::symbol("hello", false)]
+    #[case::annotated_int("foo::16", false)]
+    fn non_canonical_text(#[case] data: &str, #[case] expect_diagnostic: bool) -> IonResult<()> {
+        let report = lint(data)?;
+        assert_eq!(!report.is_clean(), expect_diagnostic, "{report:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn non_canonical_binary() -> IonResult<()> {
+        const IVM: [u8; 4] = [0xE0, 0x01, 0x00, 0xEA];
+        // The int 1, encoded using a two-byte magnitude
+        let overlong = [IVM.as_slice(), &[0x22, 0x00, 0x01]].concat();
+        let report = lint(overlong)?;
+        assert_eq!(rules(&report), vec![LintRule::NonCanonicalEncoding]);
+        assert_eq!(report.diagnostics()[0].span(), Some(4..7));
+
+        let canonical = [IVM.as_slice(), &[0x21, 0x01]].concat();
+        assert!(lint(canonical)?.is_clean());
+        Ok(())
+    }
+
+    #[test]
+    fn configure_rules() -> IonResult<()> {
+        let data = "{a: 0x01, a: 2}";
+        assert_eq!(
+            rules(&lint(data)?),
+            vec![LintRule::NonCanonicalEncoding, LintRule::DuplicateFields]
+        );
+
+        let linter = Linter::new(LintConfig::new().without_rule(LintRule::NonCanonicalEncoding));
+        assert_eq!(rules(&linter.lint(data)?), vec![LintRule::DuplicateFields]);
+
+        let linter = Linter::new(
+            LintConfig::empty().with_rule(LintRule::NonCanonicalEncoding, Severity::Error),
+        );
+        let report = linter.lint(data)?;
+        assert_eq!(rules(&report), vec![LintRule::NonCanonicalEncoding]);
+        assert!(report.has_errors());
+
+        assert!(Linter::new(LintConfig::empty()).lint(data)?.is_clean());
+        Ok(())
+    }
+}
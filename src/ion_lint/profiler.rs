@@ -0,0 +1,391 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use crate::ion_lint::{format_path, PathTracker};
+use crate::lazy::decoder::HasRange;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::lazy::walk::ValueContext;
+use crate::text::lossless::PathComponent;
+use crate::{AnyEncoding, IonResult, IonType, Reader};
+
+/// Finds the largest values and the deepest paths in an Ion data stream.
+///
+/// The profiler only remembers the top `limit` values in each category, so the memory it uses
+/// does not grow with the size of the stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Profiler {
+    limit: usize,
+}
+
+impl Profiler {
+    /// The number of values reported in each category unless otherwise specified.
+    pub const DEFAULT_LIMIT: usize = 10;
+
+    /// Constructs a profiler that reports (at most) `limit` values in each category.
+    pub fn new(limit: usize) -> Self {
+        Self { limit }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Reads all of the values in `input`, returning the largest and most deeply nested among
+    /// them. The input is read incrementally, so streams that do not fit in memory can be
+    /// profiled.
+    pub fn profile<Input: IonInput>(&self, input: Input) -> IonResult<ProfileReport> {
+        let mut reader = Reader::new(AnyEncoding, input)?;
+        let mut state = ProfileState {
+            paths: PathTracker::default(),
+            previous: None,
+            largest: TopN::new(self.limit),
+            deepest: TopN::new(self.limit),
+            value_count: 0,
+            max_depth: 0,
+        };
+        reader.walk(|context, value| {
+            state.visit(context, value);
+            Ok(true)
+        })?;
+        // The last value visited cannot have had any children.
+        state.offer_deepest();
+        Ok(ProfileReport {
+            largest: state.largest.into_sorted_vec(),
+            deepest: state.deepest.into_sorted_vec(),
+            value_count: state.value_count,
+            max_depth: state.max_depth,
+        })
+    }
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_LIMIT)
+    }
+}
+
+/// A value singled out by a [`Profiler`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfiledValue {
+    path: Vec<PathComponent>,
+    ion_type: IonType,
+    span: Option<Range<usize>>,
+}
+
+impl ProfiledValue {
+    /// The path from the top level of the stream to the value. The first component is always the
+    /// index of a top-level value.
+    pub fn path(&self) -> &[PathComponent] {
+        &self.path
+    }
+
+    pub fn ion_type(&self) -> IonType {
+        self.ion_type
+    }
+
+    /// The number of containers in which the value is nested. Top-level values have a depth of `0`.
+    pub fn depth(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    /// The range of input bytes occupied by the value (including its annotations), if the value
+    /// appeared literally in the input. Values produced by macro expansion do not have a span.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+
+    /// The offset of the first input byte occupied by the value, if it has a [span](Self::span).
+    pub fn offset(&self) -> Option<usize> {
+        self.span.as_ref().map(|span| span.start)
+    }
+
+    /// The number of input bytes occupied by the value, if it has a [span](Self::span).
+    pub fn encoded_length(&self) -> Option<usize> {
+        self.span.as_ref().map(|span| span.len())
+    }
+}
+
+impl Display for ProfiledValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}", format_path(&self.path), self.ion_type)?;
+        if let Some(span) = &self.span {
+            write!(f, ", {} bytes at offset {}", span.len(), span.start)?;
+        }
+        write!(f, ", depth {})", self.depth())
+    }
+}
+
+/// The results of profiling a stream with a [`Profiler`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProfileReport {
+    largest: Vec<ProfiledValue>,
+    deepest: Vec<ProfiledValue>,
+    value_count: usize,
+    max_depth: usize,
+}
+
+impl ProfileReport {
+    /// The values with the longest encodings, largest first. Containers are measured including
+    /// their child values, so a top-level value is always at least as large as anything inside
+    /// it. Values produced by macro expansion are not considered.
+    pub fn largest(&self) -> &[ProfiledValue] {
+        &self.largest
+    }
+
+    /// The most deeply nested values, deepest first. Only values without children (scalars and
+    /// empty containers) are considered so that each entry describes a distinct path.
+    pub fn deepest(&self) -> &[ProfiledValue] {
+        &self.deepest
+    }
+
+    /// The total number of values in the stream, at any depth.
+    pub fn value_count(&self) -> usize {
+        self.value_count
+    }
+
+    /// The depth of the most deeply nested value in the stream.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+}
+
+/// Profiles the provided Ion data using [`Profiler::default`].
+pub fn profile<Input: IonInput>(input: Input) -> IonResult<ProfileReport> {
+    Profiler::default().profile(input)
+}
+
+struct ProfileState {
+    paths: PathTracker,
+    // The depth, type, and span of the value that was visited most recently. Whether it had any
+    // children is only known once the next value is visited.
+    previous: Option<(usize, IonType, Option<Range<usize>>)>,
+    largest: TopN,
+    deepest: TopN,
+    value_count: usize,
+    max_depth: usize,
+}
+
+impl ProfileState {
+    fn visit(&mut self, context: &ValueContext<'_>, value: LazyValue<'_, AnyEncoding>) {
+        let depth = context.depth();
+        if matches!(self.previous, Some((previous_depth, ..)) if depth <= previous_depth) {
+            // The previous value was not followed by a child, so it did not have any.
+            self.offer_deepest();
+        }
+        self.paths.visit(context);
+
+        let span = value.raw().map(|raw| raw.range());
+        self.value_count += 1;
+        self.max_depth = self.max_depth.max(depth);
+        if let Some(length) = span.as_ref().map(|span| span.len()) {
+            let path = self.paths.path();
+            self.largest
+                .offer(length, || profiled(path, value.ion_type(), &span));
+        }
+        self.previous = Some((depth, value.ion_type(), span));
+    }
+
+    /// Offers the previous value as one of the deepest. The path must still lead to that value.
+    fn offer_deepest(&mut self) {
+        if let Some((depth, ion_type, span)) = self.previous.take() {
+            let path = self.paths.path();
+            self.deepest
+                .offer(depth, || profiled(path, ion_type, &span));
+        }
+    }
+}
+
+fn profiled(
+    path: &[PathComponent],
+    ion_type: IonType,
+    span: &Option<Range<usize>>,
+) -> ProfiledValue {
+    ProfiledValue {
+        path: path.to_vec(),
+        ion_type,
+        span: span.clone(),
+    }
+}
+
+/// Retains the `limit` values with the highest keys. When keys are tied, the value that was
+/// offered first is preferred.
+struct TopN {
+    limit: usize,
+    // A min-heap, so the entry that will be evicted next is always at the top.
+    entries: BinaryHeap<Reverse<Ranked>>,
+    next_sequence: usize,
+}
+
+impl TopN {
+    fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            entries: BinaryHeap::new(),
+            next_sequence: 0,
+        }
+    }
+
+    /// Retains the value created by `make_value` if `key` is among the top `limit` keys seen so
+    /// far. The value is only created (and its path only cloned) if it will be retained.
+    fn offer(&mut self, key: usize, make_value: impl FnOnce() -> ProfiledValue) {
+        if self.limit == 0 {
+            return;
+        }
+        if self.entries.len() == self.limit {
+            match self.entries.peek() {
+                Some(Reverse(lowest)) if key > lowest.key => {
+                    self.entries.pop();
+                }
+                _ => return,
+            }
+        }
+        self.entries.push(Reverse(Ranked {
+            key,
+            sequence: self.next_sequence,
+            value: make_value(),
+        }));
+        self.next_sequence += 1;
+    }
+
+    /// Returns the retained values, highest key first.
+    fn into_sorted_vec(self) -> Vec<ProfiledValue> {
+        // Sorting the `Reverse` wrappers in ascending order puts the highest keys first.
+        self.entries
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(ranked)| ranked.value)
+            .collect()
+    }
+}
+
+struct Ranked {
+    key: usize,
+    sequence: usize,
+    value: ProfiledValue,
+}
+
+impl Ranked {
+    // Higher keys rank higher; among equal keys, earlier values rank higher.
+    fn rank(&self) -> (usize, Reverse<usize>) {
+        (self.key, Reverse(self.sequence))
+    }
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.rank() == other.rank()
+    }
+}
+
+impl Eq for Ranked {}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{v1_0, Element};
+
+    const DATA: &str = r#"
+        {id: 1, tags: [a, b]}
+        {id: 2, payload: "a much longer string than anything else in the stream", nested: {a: {b: [[]]}}}
+        [1, 2, 3]
+    "#;
+
+    fn paths(values: &[ProfiledValue]) -> Vec<String> {
+        values
+            .iter()
+            .map(|value| format_path(value.path()))
+            .collect()
+    }
+
+    #[test]
+    fn largest_values() -> IonResult<()> {
+        let report = Profiler::new(3).profile(DATA)?;
+        assert_eq!(paths(report.largest()), vec!["[1]", "[1].payload", "[0]"]);
+        let payload = &report.largest()[1];
+        assert_eq!(payload.ion_type(), IonType::String);
+        assert_eq!(
+            &DATA[payload.span().unwrap()],
+            r#""a much longer string than anything else in the stream""#
+        );
+        assert_eq!(report.value_count(), 16);
+        Ok(())
+    }
+
+    #[test]
+    fn deepest_paths() -> IonResult<()> {
+        let report = Profiler::new(2).profile(DATA)?;
+        assert_eq!(
+            paths(report.deepest()),
+            vec!["[1].nested.a.b[0]", "[0].tags[0]"]
+        );
+        assert_eq!(report.deepest()[0].depth(), 4);
+        assert_eq!(report.max_depth(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_offsets() -> IonResult<()> {
+        let data = Element::read_all(DATA)?.encode_as(v1_0::Binary)?;
+        let report = Profiler::new(2).profile(&data)?;
+        let payload = &report.largest()[1];
+        assert_eq!(format_path(payload.path()), "[1].payload");
+        // Prepend an IVM so the string's encoding can be read on its own.
+        let element = Element::read_one([&data[..4], &data[payload.span().unwrap()]].concat())?;
+        assert_eq!(
+            element,
+            Element::string("a much longer string than anything else in the stream")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn memory_is_bounded_by_the_limit() -> IonResult<()> {
+        let data = "[1, 22, 333, 4444, 55555, 666666]";
+        let report = Profiler::new(2).profile(data)?;
+        assert_eq!(report.largest().len(), 2);
+        assert_eq!(report.deepest().len(), 2);
+        assert!(Profiler::new(0).profile(data)?.largest().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn empty_containers_are_leaves() -> IonResult<()> {
+        let report = Profiler::new(10).profile("{a: [], b: {c: null.list}} (())")?;
+        assert_eq!(paths(report.deepest()), vec!["[0].b.c", "[0].a", "[1][0]"]);
+        Ok(())
+    }
+
+    #[test]
+    fn streamed_input() -> IonResult<()> {
+        let data: &[u8] = DATA.as_bytes();
+        let report = Profiler::new(2).profile(crate::IonStream::new(data))?;
+        assert_eq!(paths(report.largest()), vec!["[1]", "[1].payload"]);
+        assert_eq!(report.value_count(), 16);
+        Ok(())
+    }
+
+    #[test]
+    fn display() -> IonResult<()> {
+        let report = profile(r#"{a: [1, "hello"]}"#)?;
+        assert_eq!(
+            report.deepest()[1].to_string(),
+            "[0].a[1] (string, 7 bytes at offset 8, depth 2)"
+        );
+        Ok(())
+    }
+}
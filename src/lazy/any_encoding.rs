@@ -3,6 +3,7 @@
 use std::fmt::Debug;
 use std::ops::Range;
 
+use crate::constants::v1_0;
 use crate::lazy::binary::raw::annotations_iterator::RawBinaryAnnotationsIterator as RawBinaryAnnotationsIterator_1_0;
 use crate::lazy::binary::raw::r#struct::{
     LazyRawBinaryFieldName_1_0, LazyRawBinaryStruct_1_0, RawBinaryStructIterator_1_0,
@@ -54,12 +55,26 @@ use crate::lazy::text::value::{
     LazyRawTextValue_1_0, LazyRawTextValue_1_1, LazyRawTextVersionMarker_1_0,
     LazyRawTextVersionMarker_1_1, RawTextAnnotationsIterator,
 };
-use crate::{Encoding, IonResult, IonType, RawSymbolRef};
+use crate::read_config::ReadConfig;
+use crate::result::IonFailure;
+use crate::{Encoding, Format, IonResult, IonType, RawSymbolRef, TextFormat};
 
 /// An implementation of the `LazyDecoder` trait that can read any encoding of Ion.
 #[derive(Debug, Clone, Copy)]
 pub struct AnyEncoding;
 
+impl AnyEncoding {
+    /// Returns a [`ReadConfig`] for a reader that reads input that does not begin with a binary
+    /// Ion version marker as the specified `format` instead of detecting it. This allows binary
+    /// Ion that lacks a version marker, as well as empty input, to be read as binary.
+    ///
+    /// If `format` is `Format::Text`, constructing a reader for input that begins with a binary
+    /// Ion 1.0 or 1.1 version marker fails instead of reading the input as binary.
+    pub fn with_format(self, format: Format) -> ReadConfig<AnyEncoding> {
+        ReadConfig::from(self).with_format(format)
+    }
+}
+
 // This family of types avoids boxing and dynamic dispatch by using enums of the supported formats
 // within each type. Trait methods are implemented by forwarding the call to the appropriate
 // underlying type.
@@ -300,19 +315,147 @@ impl<'top> Iterator for LazyRawAnyMacroArgsIterator<'top> {
 // ===== Readers ======
 
 /// A lazy raw reader that can decode both text and binary Ion.
+///
+/// When the reader encounters an Ion version marker (IVM) that changes the Ion version of the
+/// stream, it switches to a decoder for that version before reading the data that follows.
 pub struct LazyRawAnyReader<'data> {
+    // The input slice and the offset within the overall stream at which it begins. These are
+    // used to construct a reader for the new encoding when an IVM changes the Ion version.
+    input: &'data [u8],
+    stream_offset: usize,
     encoding: RawReaderKind<'data>,
+    // If the most recently read item was an IVM for a different Ion version, the encoding that
+    // must be used to read the rest of the stream.
+    encoding_after_ivm: Option<IonEncoding>,
 }
 
 impl<'data> LazyRawAnyReader<'data> {
-    fn detect_encoding(data: &[u8]) -> IonEncoding {
-        const BINARY_1_0_IVM: &[u8] = &[0xEA, 0x01, 0x00, 0xE0];
+    fn raw_reader_for(
+        data: &'data [u8],
+        offset: usize,
+        encoding: IonEncoding,
+    ) -> RawReaderKind<'data> {
+        match encoding {
+            IonEncoding::Text_1_0 => {
+                RawReaderKind::Text_1_0(LazyRawTextReader_1_0::resume_at_offset(data, offset, ()))
+            }
+            IonEncoding::Binary_1_0 => RawReaderKind::Binary_1_0(
+                LazyRawBinaryReader_1_0::resume_at_offset(data, offset, ()),
+            ),
+            IonEncoding::Text_1_1 => {
+                RawReaderKind::Text_1_1(LazyRawTextReader_1_1::resume_at_offset(data, offset, ()))
+            }
+            IonEncoding::Binary_1_1 => RawReaderKind::Binary_1_1(
+                LazyRawBinaryReader_1_1::resume_at_offset(data, offset, ()),
+            ),
+        }
+    }
 
-        match *data {
-            [0xE0, 0x01, 0x00, 0xEA, ..] => IonEncoding::Binary_1_0,
-            [0xE0, 0x01, 0x01, 0xEA, ..] => IonEncoding::Binary_1_1,
-            _ => IonEncoding::Text_1_0,
+    /// Returns the encoding that should be used to read the data following an IVM for the
+    /// specified Ion version. The text/binary format of the stream cannot be changed by an IVM.
+    fn encoding_for_ivm(current: IonEncoding, version: (u8, u8)) -> IonResult<IonEncoding> {
+        use IonEncoding::*;
+        let encoding = match (version, current.is_binary()) {
+            ((1, 0), false) => Text_1_0,
+            ((1, 0), true) => Binary_1_0,
+            ((1, 1), false) => Text_1_1,
+            ((1, 1), true) => Binary_1_1,
+            ((major, minor), _) => {
+                return IonResult::decoding_error(format!(
+                    "Ion version {major}.{minor} is not supported"
+                ))
+            }
+        };
+        Ok(encoding)
+    }
+
+    /// Replaces the active raw reader with one for `new_encoding` that resumes reading where the
+    /// active raw reader left off.
+    fn switch_encoding(&mut self, new_encoding: IonEncoding) {
+        let position = self.position();
+        let local_offset = position - self.stream_offset;
+        self.encoding = Self::raw_reader_for(&self.input[local_offset..], position, new_encoding);
+        self.input = &self.input[local_offset..];
+        self.stream_offset = position;
+    }
+
+    fn read_next_item<'top>(
+        &'top mut self,
+        context: EncodingContextRef<'top>,
+        allow_partial_lob: bool,
+    ) -> IonResult<LazyRawStreamItem<'top, AnyEncoding>>
+    where
+        'data: 'top,
+    {
+        // If the last item we read was an IVM that changed the Ion version, switch to a raw
+        // reader for the new encoding before reading any further.
+        if let Some(new_encoding) = self.encoding_after_ivm.take() {
+            self.switch_encoding(new_encoding);
+        }
+        let current_encoding = self.encoding();
+        use RawReaderKind::*;
+        let item: LazyRawStreamItem<'top, AnyEncoding> = match &mut self.encoding {
+            Text_1_0(r) => r.next(context)?.into(),
+            Binary_1_0(r) => {
+                if allow_partial_lob {
+                    r.next_allowing_partial_lob()?.into()
+                } else {
+                    r.next()?.into()
+                }
+            }
+            Text_1_1(r) => r.next(context)?.into(),
+            Binary_1_1(r) => r.next(context)?.into(),
+        };
+        if let LazyRawStreamItem::<AnyEncoding>::VersionMarker(marker) = item {
+            let new_encoding = Self::encoding_for_ivm(current_encoding, marker.version())?;
+            if new_encoding != current_encoding {
+                self.encoding_after_ivm = Some(new_encoding);
+            }
         }
+        Ok(item)
+    }
+
+    /// Returns the binary encoding whose IVM begins `data`, if any.
+    fn detect_encoding(data: &[u8]) -> Option<IonEncoding> {
+        match *data {
+            [0xE0, 0x01, 0x00, 0xEA, ..] => Some(IonEncoding::Binary_1_0),
+            [0xE0, 0x01, 0x01, 0xEA, ..] => Some(IonEncoding::Binary_1_1),
+            _ => None,
+        }
+    }
+}
+
+/// Inspects the beginning of `data` to determine whether it contains binary or text Ion.
+///
+/// Binary Ion streams begin with an Ion 1.0 or 1.1 version marker (IVM); any other input,
+/// including input that begins with the IVM of an unsupported Ion version, is read as text.
+/// Because the layout of text Ion cannot be detected, text input is reported as
+/// `Format::Text(TextFormat::default())`.
+///
+/// Returns `None` if `data` is empty or is too short to tell whether it begins with a binary IVM.
+/// Readers assume that such input is text; to read it as binary Ion instead, configure the reader
+/// using [`AnyEncoding::with_format`].
+///
+/// ```
+/// use ion_rs::{detect_format, Format, TextFormat};
+///
+/// let text = Some(Format::Text(TextFormat::default()));
+/// assert_eq!(detect_format(&[0xE0, 0x01, 0x00, 0xEA, 0x21, 0x05]), Some(Format::Binary));
+/// assert_eq!(detect_format(&[0xE0, 0x01, 0x01, 0xEA]), Some(Format::Binary));
+/// assert_eq!(detect_format(b"foo::5"), text);
+/// // Readers do not recognize the IVMs of other Ion versions
+/// assert_eq!(detect_format(&[0xE0, 0x01, 0x02, 0xEA]), text);
+/// assert_eq!(detect_format(&[]), None);
+/// assert_eq!(detect_format(&[0xE0, 0x01]), None);
+/// ```
+pub fn detect_format(data: &[u8]) -> Option<Format> {
+    if LazyRawAnyReader::detect_encoding(data).is_some() {
+        return Some(Format::Binary);
+    }
+    match *data {
+        // A prefix of a supported binary IVM
+        [] | [0xE0] | [0xE0, 0x01] | [0xE0, 0x01, 0x00 | 0x01] => None,
+        _ => Some(Format::Text(TextFormat::default())),
     }
 }
 
@@ -323,7 +466,7 @@ pub enum RawReaderKind<'data> {
     Binary_1_1(LazyRawBinaryReader_1_1<'data>),
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum IonEncoding {
     // In the absence of a binary IVM, readers must assume Ion 1.0 text data until a
@@ -356,6 +499,16 @@ impl IonEncoding {
         }
     }
 
+    /// Returns whether this encoding is text or binary. Text encodings are reported as
+    /// `Format::Text(TextFormat::default())`.
+    pub fn format(&self) -> Format {
+        if self.is_binary() {
+            Format::Binary
+        } else {
+            Format::Text(TextFormat::default())
+        }
+    }
+
     pub fn version(&self) -> (u8, u8) {
         use IonEncoding::*;
         match self {
@@ -363,44 +516,22 @@ impl IonEncoding {
             Text_1_1 | Binary_1_1 => (1, 1),
         }
     }
-}
-
-impl<'data> From<LazyRawTextReader_1_0<'data>> for LazyRawAnyReader<'data> {
-    fn from(reader: LazyRawTextReader_1_0<'data>) -> Self {
-        LazyRawAnyReader {
-            encoding: RawReaderKind::Text_1_0(reader),
-        }
-    }
-}
-
-impl<'data> From<LazyRawTextReader_1_1<'data>> for LazyRawAnyReader<'data> {
-    fn from(reader: LazyRawTextReader_1_1<'data>) -> Self {
-        LazyRawAnyReader {
-            encoding: RawReaderKind::Text_1_1(reader),
-        }
-    }
-}
-
-impl<'data> From<LazyRawBinaryReader_1_0<'data>> for LazyRawAnyReader<'data> {
-    fn from(reader: LazyRawBinaryReader_1_0<'data>) -> Self {
-        LazyRawAnyReader {
-            encoding: RawReaderKind::Binary_1_0(reader),
-        }
-    }
-}
 
-impl<'data> From<LazyRawBinaryReader_1_1<'data>> for LazyRawAnyReader<'data> {
-    fn from(reader: LazyRawBinaryReader_1_1<'data>) -> Self {
-        LazyRawAnyReader {
-            encoding: RawReaderKind::Binary_1_1(reader),
+    /// Returns the text of each symbol in this encoding's system symbol table, indexed by symbol
+    /// ID. (`$0` has no text.) Local symbols are assigned IDs starting after the last of these.
+    pub fn system_symbols(&self) -> &'static [Option<&'static str>] {
+        use IonEncoding::*;
+        match self {
+            Text_1_0 | Binary_1_0 => v1_0::SYSTEM_SYMBOLS,
+            // Ion 1.1 streams are read with the Ion 1.0 system symbols in the active symbol table.
+            Text_1_1 | Binary_1_1 => v1_0::SYSTEM_SYMBOLS,
         }
     }
 }
 
 impl<'data> LazyRawReader<'data, AnyEncoding> for LazyRawAnyReader<'data> {
     fn new(data: &'data [u8]) -> Self {
-        let reader_type = Self::detect_encoding(data);
-        Self::resume_at_offset(data, 0, reader_type)
+        Self::resume_at_offset(data, 0, IonEncoding::default())
     }
 
     fn resume_at_offset(
@@ -409,24 +540,19 @@ impl<'data> LazyRawReader<'data, AnyEncoding> for LazyRawAnyReader<'data> {
         mut raw_reader_type: IonEncoding,
     ) -> Self {
         if offset == 0 {
-            // If we're at the beginning of the stream, the provided `raw_reader_type` may be a
-            // default. We need to inspect the bytes to see if we should override it.
-            raw_reader_type = Self::detect_encoding(data);
-        }
-        match raw_reader_type {
-            IonEncoding::Text_1_0 => {
-                LazyRawTextReader_1_0::resume_at_offset(data, offset, ()).into()
-            }
-            IonEncoding::Binary_1_0 => {
-                LazyRawBinaryReader_1_0::resume_at_offset(data, offset, ()).into()
-            }
-            IonEncoding::Text_1_1 => {
-                LazyRawTextReader_1_0::resume_at_offset(data, offset, ()).into()
-            }
-            IonEncoding::Binary_1_1 => {
-                LazyRawBinaryReader_1_1::resume_at_offset(data, offset, ()).into()
+            // If we're at the beginning of the stream, the provided `raw_reader_type` is either a
+            // default or the format that the reader was configured to use. A binary IVM
+            // overrides it.
+            if let Some(encoding) = Self::detect_encoding(data) {
+                raw_reader_type = encoding;
             }
         }
+        LazyRawAnyReader {
+            input: data,
+            stream_offset: offset,
+            encoding: Self::raw_reader_for(data, offset, raw_reader_type),
+            encoding_after_ivm: None,
+        }
     }
 
     fn next<'top>(
@@ -436,15 +562,18 @@ impl<'data> LazyRawReader<'data, AnyEncoding> for LazyRawAnyReader<'data> {
     where
         'data: 'top,
     {
-        use RawReaderKind::*;
-        match &mut self.encoding {
-            Text_1_0(r) => Ok(r.next(context)?.into()),
-            Binary_1_0(r) => Ok(r.next()?.into()),
-            Text_1_1(r) => Ok(r.next(context)?.into()),
-            Binary_1_1(r) => Ok(r.next(context)?.into()),
-        }
+        self.read_next_item(context, false)
     }
 
+    fn next_allowing_partial_lob<'top>(
+        &'top mut self,
+        context: EncodingContextRef<'top>,
+    ) -> IonResult<LazyRawStreamItem<'top, AnyEncoding>>
+    where
+        'data: 'top,
+    {
+        self.read_next_item(context, true)
+    }
     #[inline]
     fn save_state(&self) -> <AnyEncoding as Decoder>::ReaderSavedState {
         self.encoding()
@@ -461,6 +590,10 @@ impl<'data> LazyRawReader<'data, AnyEncoding> for LazyRawAnyReader<'data> {
     }
 
     fn encoding(&self) -> IonEncoding {
+        // If an IVM has changed the Ion version, report the encoding of the data that follows it.
+        if let Some(new_encoding) = self.encoding_after_ivm {
+            return new_encoding;
+        }
         use RawReaderKind::*;
         match &self.encoding {
             Text_1_0(_) => IonEncoding::Text_1_0,
@@ -856,6 +989,22 @@ impl<'top> LazyRawValue<'top, AnyEncoding> for LazyRawAnyValue<'top> {
             LazyRawValueKind::Binary_1_1(v) => v.value_span(),
         }
     }
+
+    fn is_encoded_as_f32(&self) -> bool {
+        match &self.encoding {
+            LazyRawValueKind::Text_1_0(v) => v.is_encoded_as_f32(),
+            LazyRawValueKind::Binary_1_0(v) => v.is_encoded_as_f32(),
+            LazyRawValueKind::Text_1_1(v) => v.is_encoded_as_f32(),
+            LazyRawValueKind::Binary_1_1(v) => v.is_encoded_as_f32(),
+        }
+    }
+
+    fn partial_lob_payload(&self) -> Option<&'top [u8]> {
+        match &self.encoding {
+            LazyRawValueKind::Binary_1_0(v) => v.partial_lob_payload(),
+            _ => None,
+        }
+    }
 }
 
 // ===== Annotations =====
@@ -1569,4 +1718,46 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn version_markers_change_the_encoding() -> IonResult<()> {
+        fn test_input(data: &[u8], is_binary: bool) -> IonResult<()> {
+            let encoding_context = EncodingContext::empty();
+            let context = encoding_context.get_ref();
+
+            let mut reader = LazyRawAnyReader::new(data);
+            for (version, value) in [((1, 0), 1), ((1, 1), 2), ((1, 0), 3)] {
+                assert_eq!(reader.next(context)?.expect_ivm()?.version(), version);
+                let value_ref = reader.next(context)?.expect_value()?;
+                assert_eq!(value_ref.encoding().version(), version);
+                assert_eq!(value_ref.encoding().is_binary(), is_binary);
+                assert_eq!(value_ref.read()?.expect_i64()?, value);
+            }
+            assert!(matches!(
+                reader.next(context)?,
+                LazyRawStreamItem::<AnyEncoding>::EndOfStream(_)
+            ));
+            Ok(())
+        }
+
+        test_input(b"$ion_1_0 1 $ion_1_1 2 $ion_1_0 3", false)?;
+        #[rustfmt::skip]
+        let binary_data = [
+            0xE0, 0x01, 0x00, 0xEA, 0x21, 0x01,
+            0xE0, 0x01, 0x01, 0xEA, 0x61, 0x02,
+            0xE0, 0x01, 0x00, 0xEA, 0x21, 0x03,
+        ];
+        test_input(&binary_data, true)?;
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_version_markers_are_rejected() {
+        let encoding_context = EncodingContext::empty();
+        let context = encoding_context.get_ref();
+        let mut reader = LazyRawAnyReader::new(b"$ion_1_0 1 $ion_2_0 2");
+        assert!(reader.next(context).is_ok());
+        assert!(reader.next(context).is_ok());
+        assert!(reader.next(context).is_err());
+    }
 }
@@ -21,6 +21,36 @@ use crate::{Int, IonError, IonResult, IonType};
 
 const MAX_INT_SIZE_IN_BYTES: usize = mem::size_of::<i128>();
 
+/// Decodes a `VarUInt` or `VarInt` that ends within the first eight bytes of `bytes` by operating
+/// on all eight bytes at once. `first_byte_mask` selects the magnitude bits of the first byte,
+/// which a `VarInt` shares with its sign bit.
+///
+/// Returns the decoded magnitude and the encoding's size in bytes, or `None` if `bytes` is shorter
+/// than eight bytes or the encoding does not end within them. Callers should fall back to a
+/// byte-by-byte loop in that case.
+#[inline(always)]
+fn read_var_magnitude_word(bytes: &[u8], first_byte_mask: u8) -> Option<(u64, usize)> {
+    const END_FLAGS: u64 = 0x8080_8080_8080_8080;
+    let word = u64::from_le_bytes(bytes.get(..8)?.try_into().unwrap());
+    // The first byte whose high bit is set is the last byte of the encoding.
+    let end_flags = word & END_FLAGS;
+    if end_flags == 0 {
+        return None;
+    }
+    let num_bytes = (end_flags.trailing_zeros() / 8 + 1) as usize;
+    let unused_bits = 64 - 8 * num_bytes as u32;
+    // Keep only the magnitude bits of the encoding's bytes, then reorder them so that the first
+    // byte is the most significant.
+    let magnitude_bits = 0x7F7F_7F7F_7F7F_7F00 | first_byte_mask as u64;
+    let groups = (word & magnitude_bits & (u64::MAX >> unused_bits)).swap_bytes() >> unused_bits;
+    // Each byte now holds seven bits of the magnitude. Pack adjacent groups together, doubling
+    // their width at each step: 7 bits in 8, 14 bits in 16, and finally 28 bits in 32.
+    let packed = ((groups & 0x7F00_7F00_7F00_7F00) >> 1) | (groups & 0x007F_007F_007F_007F);
+    let packed = ((packed & 0x3FFF_0000_3FFF_0000) >> 2) | (packed & 0x0000_3FFF_0000_3FFF);
+    let packed = ((packed & 0x0FFF_FFFF_0000_0000) >> 4) | (packed & 0x0000_0000_0FFF_FFFF);
+    Some((packed, num_bytes))
+}
+
 /// A buffer of unsigned bytes that can be cheaply copied and which defines methods for parsing
 /// the various encoding elements of a binary Ion stream.
 ///
@@ -202,7 +232,7 @@ impl<'a> ImmutableBuffer<'a> {
             } else {
                 let second_byte = data[1];
                 if second_byte < HIGHEST_BIT_VALUE {
-                    return self.read_var_uint_slow();
+                    return self.read_var_uint_multi_byte();
                 }
                 let lower_seven = (LOWER_7_BITMASK & second_byte) as usize;
                 magnitude <<= 7;
@@ -217,6 +247,25 @@ impl<'a> ImmutableBuffer<'a> {
         self.read_var_uint_slow()
     }
 
+    /// Reads a `VarUInt` that is at least three bytes long, decoding it a word at a time if the
+    /// buffer holds enough bytes to do so.
+    #[inline]
+    fn read_var_uint_multi_byte(self) -> ParseResult<'a, VarUInt> {
+        const LOWER_7_BITMASK: u8 = 0b0111_1111;
+        if let Some((magnitude, num_bytes)) = read_var_magnitude_word(self.bytes(), LOWER_7_BITMASK)
+        {
+            // On targets where `usize` is narrower than the decoded magnitude, leave oversized
+            // encodings to the slow path so that it can report them.
+            if num_bytes * 7 <= usize::BITS as usize {
+                return Ok((
+                    VarUInt::new(magnitude as usize, num_bytes),
+                    self.consume(num_bytes),
+                ));
+            }
+        }
+        self.read_var_uint_slow()
+    }
+
     #[cold]
     pub fn read_var_uint_slow(self) -> ParseResult<'a, VarUInt> {
         const BITS_PER_ENCODED_BYTE: usize = 7;
@@ -258,6 +307,7 @@ impl<'a> ImmutableBuffer<'a> {
     /// returns an `Ok(_)` containing its [VarInt] representation.
     ///
     /// See: <https://amazon-ion.github.io/ion-docs/docs/binary.html#varuint-and-varint-fields>
+    #[inline]
     pub fn read_var_int(self) -> ParseResult<'a, VarInt> {
         const BITS_PER_ENCODED_BYTE: usize = 7;
         const STORAGE_SIZE_IN_BITS: usize = mem::size_of::<i64>() * 8;
@@ -290,6 +340,26 @@ impl<'a> ImmutableBuffer<'a> {
             ));
         }
 
+        // As with `VarUInt`s, two-byte encodings are common enough to warrant their own path.
+        if let Some(&second_byte) = self.bytes().get(1) {
+            if second_byte >= HIGHEST_BIT_VALUE {
+                magnitude <<= 7;
+                magnitude |= (second_byte & LOWER_7_BITMASK) as i64;
+                return Ok((
+                    VarInt::new(magnitude * sign, is_negative, 2),
+                    self.consume(2),
+                ));
+            }
+        }
+
+        if let Some((magnitude, num_bytes)) = read_var_magnitude_word(self.bytes(), LOWER_6_BITMASK)
+        {
+            return Ok((
+                VarInt::new(magnitude as i64 * sign, is_negative, num_bytes),
+                self.consume(num_bytes),
+            ));
+        }
+
         let mut encoded_size_in_bytes = 1;
         // Whether we found the terminating byte in this buffer.
         let mut terminated = false;
@@ -607,7 +677,7 @@ impl<'a> ImmutableBuffer<'a> {
         let matched_field_id = input.slice(0, field_id_var_uint.size_in_bytes());
         let field_name = LazyRawBinaryFieldName_1_0::new(field_id, matched_field_id);
 
-        let field_value = input_after_field_id.read_value(type_descriptor)?;
+        let field_value = input_after_field_id.read_value(type_descriptor, false)?;
         Ok(Some(LazyRawFieldExpr::NameValue(field_name, field_value)))
     }
 
@@ -645,6 +715,20 @@ impl<'a> ImmutableBuffer<'a> {
     /// Reads a value without a field name from the buffer. This is applicable in lists, s-expressions,
     /// and at the top level.
     pub(crate) fn peek_sequence_value(self) -> IonResult<Option<LazyRawBinaryValue_1_0<'a>>> {
+        self.peek_value(false)
+    }
+
+    /// Like [`peek_sequence_value`](Self::peek_sequence_value), but a blob or clob whose body
+    /// extends past the end of the buffer is returned with only the part of its body that is
+    /// available instead of being reported as incomplete. See
+    /// [`LazyRawBinaryValue_1_0::partial_lob_payload`].
+    pub(crate) fn peek_sequence_value_allowing_partial_lob(
+        self,
+    ) -> IonResult<Option<LazyRawBinaryValue_1_0<'a>>> {
+        self.peek_value(true)
+    }
+
+    fn peek_value(self, allow_partial_lob: bool) -> IonResult<Option<LazyRawBinaryValue_1_0<'a>>> {
         if self.is_empty() {
             return Ok(None);
         }
@@ -661,16 +745,21 @@ impl<'a> ImmutableBuffer<'a> {
             // Otherwise, there's a value.
             type_descriptor = input.peek_type_descriptor()?;
         }
-        Ok(Some(input.read_value(type_descriptor)?))
+        Ok(Some(input.read_value(type_descriptor, allow_partial_lob)?))
     }
 
     /// Reads a value from the buffer. The caller must confirm that the buffer is not empty and that
-    /// the next byte (`type_descriptor`) is not a NOP.
-    fn read_value(self, type_descriptor: TypeDescriptor) -> IonResult<LazyRawBinaryValue_1_0<'a>> {
+    /// the next byte (`type_descriptor`) is not a NOP. If `allow_partial_lob` is true, a blob or
+    /// clob whose body is not entirely in the buffer is not considered incomplete.
+    fn read_value(
+        self,
+        type_descriptor: TypeDescriptor,
+        allow_partial_lob: bool,
+    ) -> IonResult<LazyRawBinaryValue_1_0<'a>> {
         if type_descriptor.is_annotation_wrapper() {
-            self.read_annotated_value(type_descriptor)
+            self.read_annotated_value(type_descriptor, allow_partial_lob)
         } else {
-            self.read_value_without_annotations(type_descriptor)
+            self.read_value_without_annotations(type_descriptor, allow_partial_lob)
         }
     }
 
@@ -679,6 +768,7 @@ impl<'a> ImmutableBuffer<'a> {
     fn read_value_without_annotations(
         self,
         type_descriptor: TypeDescriptor,
+        allow_partial_lob: bool,
     ) -> IonResult<LazyRawBinaryValue_1_0<'a>> {
         let input = self;
         let header = type_descriptor
@@ -693,7 +783,10 @@ impl<'a> ImmutableBuffer<'a> {
                 + length_length as usize
                 + value_length;
 
-        if total_length > input.len() {
+        // Once its header has been read, a lob's body can be read a piece at a time.
+        let is_partial_lob =
+            allow_partial_lob && matches!(header.ion_type, IonType::Blob | IonType::Clob);
+        if total_length > input.len() && !is_partial_lob {
             return IonResult::incomplete(
                 "the stream ended unexpectedly in the middle of a value",
                 header_offset,
@@ -724,6 +817,7 @@ impl<'a> ImmutableBuffer<'a> {
     fn read_annotated_value(
         self,
         mut type_descriptor: TypeDescriptor,
+        allow_partial_lob: bool,
     ) -> IonResult<LazyRawBinaryValue_1_0<'a>> {
         let input = self;
         let (wrapper, input_after_annotations) = input.read_annotations_wrapper(type_descriptor)?;
@@ -738,8 +832,8 @@ impl<'a> ImmutableBuffer<'a> {
             return IonResult::decoding_error("found a NOP inside an annotations wrapper");
         }
 
-        let mut lazy_value =
-            input_after_annotations.read_value_without_annotations(type_descriptor)?;
+        let mut lazy_value = input_after_annotations
+            .read_value_without_annotations(type_descriptor, allow_partial_lob)?;
         if wrapper.expected_value_length != lazy_value.encoded_value.total_length() {
             return IonResult::decoding_error(
                 "value length did not match length declared by annotations wrapper",
@@ -856,6 +950,78 @@ mod tests {
             .expect_err("This should have failed due to overflow.");
     }
 
+    // Values whose encodings span each possible size, surrounded by each possible amount of
+    // trailing data, so that both the word-at-a-time and byte-by-byte decoders are exercised.
+    const VAR_INT_MAGNITUDES: &[u64] = &[
+        0,
+        63,
+        64,
+        127,
+        128,
+        8_191,
+        16_383,
+        1_984_385,
+        (1 << 28) - 1,
+        1 << 35,
+        (1 << 49) - 1,
+        (1 << 55) + 12_345,
+        (1 << 56) - 1,
+        1 << 56,
+        (1 << 62) - 1,
+    ];
+
+    fn with_trailing_bytes(encoding: &[u8], trailing: usize) -> Vec<u8> {
+        let mut bytes = encoding.to_vec();
+        // Trailing bytes with their high bit set would look like the end of the encoding if they
+        // were not ignored.
+        bytes.extend((0..trailing).map(|i| if i % 2 == 0 { 0xFF } else { 0x00 }));
+        bytes
+    }
+
+    #[test]
+    fn read_var_uint_all_sizes() -> IonResult<()> {
+        for magnitude in VAR_INT_MAGNITUDES.iter().copied().chain([u64::MAX >> 1]) {
+            let mut encoding = Vec::new();
+            let size = VarUInt::write_u64(&mut encoding, magnitude)?;
+            for trailing in 0..=8 {
+                let bytes = with_trailing_bytes(&encoding, trailing);
+                let (var_uint, remaining) = ImmutableBuffer::new(&bytes).read_var_uint()?;
+                assert_eq!(var_uint.value() as u64, magnitude);
+                assert_eq!(var_uint.size_in_bytes(), size);
+                assert_eq!(remaining.len(), trailing);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_var_int_all_sizes() -> IonResult<()> {
+        for magnitude in VAR_INT_MAGNITUDES.iter().copied() {
+            for value in [magnitude as i64, -(magnitude as i64)] {
+                let mut encoding = Vec::new();
+                let size = VarInt::write_i64(&mut encoding, value)?;
+                for trailing in 0..=8 {
+                    let bytes = with_trailing_bytes(&encoding, trailing);
+                    let (var_int, remaining) = ImmutableBuffer::new(&bytes).read_var_int()?;
+                    assert_eq!(var_int.value(), value);
+                    assert_eq!(var_int.size_in_bytes(), size);
+                    assert_eq!(remaining.len(), trailing);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_var_int_negative_zero() -> IonResult<()> {
+        let bytes = with_trailing_bytes(&[0b0100_0000, 0b0000_0000, 0b1000_0000], 8);
+        let var_int = ImmutableBuffer::new(&bytes).read_var_int()?.0;
+        assert_eq!(var_int.size_in_bytes(), 3);
+        assert_eq!(var_int.value(), 0);
+        assert!(var_int.is_negative_zero());
+        Ok(())
+    }
+
     #[test]
     fn read_var_int_zero() -> IonResult<()> {
         let buffer = ImmutableBuffer::new(&[0b1000_0000]);
@@ -0,0 +1,390 @@
+#![allow(non_camel_case_types)]
+//! A reader that reports how each item in a binary Ion 1.0 stream is encoded.
+
+use std::ops::Range;
+
+use crate::lazy::binary::immutable_buffer::ImmutableBuffer;
+use crate::lazy::binary::raw::type_descriptor::TypeDescriptor;
+use crate::lazy::decoder::RawVersionMarker;
+use crate::lazy::span::Span;
+use crate::result::IonFailure;
+use crate::{IonResult, IonType};
+
+/// The kinds of items reported by a [`RawBinaryInspector_1_0`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InspectedKind {
+    /// An Ion version marker.
+    VersionMarker,
+    /// A NOP pad. In a struct, a NOP pad is preceded by a field ID like any other field value.
+    NopPad,
+    /// A value of the specified type, including typed nulls.
+    Value(IonType),
+}
+
+/// The encoding of a single item in a binary Ion 1.0 stream, as reported by a
+/// [`RawBinaryInspector_1_0`].
+///
+/// Each encoded item has up to five components, which appear in the following order:
+///
+/// `[ field ID? | annotations wrapper? | type descriptor | length? | body ]`
+///
+/// Components shown with a `?` may be empty.
+#[derive(Debug, Clone, Copy)]
+pub struct InspectedItem_1_0<'data> {
+    kind: InspectedKind,
+    depth: usize,
+    field_id: Span<'data>,
+    annotations: Span<'data>,
+    header: Span<'data>,
+    body: Span<'data>,
+}
+
+impl<'data> InspectedItem_1_0<'data> {
+    pub fn kind(&self) -> InspectedKind {
+        self.kind
+    }
+
+    /// The number of containers in which the item is nested. Top-level items have a depth of `0`.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the bytes of the item's field ID `VarUInt`. This is empty unless the item is in a
+    /// struct.
+    pub fn field_id_span(&self) -> Span<'data> {
+        self.field_id
+    }
+
+    /// Returns the bytes of the item's annotations wrapper (its opcode, lengths, and annotation
+    /// sequence), which is empty if the item does not have annotations.
+    pub fn annotations_span(&self) -> Span<'data> {
+        self.annotations
+    }
+
+    /// Returns the bytes of the item's header: its type descriptor followed by its length (if the
+    /// length is not encoded in the type descriptor). For a version marker, this is the complete
+    /// version marker.
+    pub fn header_span(&self) -> Span<'data> {
+        self.header
+    }
+
+    /// Returns the item's type descriptor byte.
+    pub fn type_descriptor(&self) -> u8 {
+        self.header.bytes()[0]
+    }
+
+    /// Returns the low nibble of the item's type descriptor byte.
+    pub fn length_code(&self) -> u8 {
+        self.type_descriptor() & 0x0F
+    }
+
+    /// Returns the bytes of the `VarUInt` length that follows the item's type descriptor. This is
+    /// empty if the length is represented by the type descriptor's [length code](Self::length_code).
+    pub fn length_span(&self) -> Span<'data> {
+        let start = self.header.range().start + 1;
+        if self.kind == InspectedKind::VersionMarker {
+            return Span::with_offset(start, &[]);
+        }
+        Span::with_offset(start, &self.header.bytes()[1..])
+    }
+
+    /// Returns the bytes of the item's body: the content that follows its header. For a container,
+    /// this is the encoding of its child items.
+    pub fn body_span(&self) -> Span<'data> {
+        self.body
+    }
+
+    /// Returns the input stream index range that contains the complete item, from its field ID
+    /// (if any) through the end of its body.
+    pub fn range(&self) -> Range<usize> {
+        self.field_id.range().start..self.body.range().end
+    }
+}
+
+/// A low-level reader that visits each of the items in a binary Ion 1.0 stream in depth-first
+/// order and reports how each one is encoded instead of interpreting it. Unlike the other readers,
+/// it reports NOP pads. This is intended for debugging and for tools that display the encoding of
+/// a stream, like hex dumps.
+///
+/// Containers are visited automatically: the item for a non-null container is followed by the
+/// items for its children.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-tooling-apis")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::v1_0::{InspectedKind, RawBinaryInspector};
+/// use ion_rs::IonType;
+///
+/// // An IVM, a one-byte NOP pad, and the list `[5]`
+/// let data = [0xE0, 0x01, 0x00, 0xEA, 0x00, 0xB2, 0x21, 0x05];
+/// let mut inspector = RawBinaryInspector::new(&data);
+///
+/// let ivm = inspector.next()?.unwrap();
+/// assert_eq!(ivm.kind(), InspectedKind::VersionMarker);
+/// let nop = inspector.next()?.unwrap();
+/// assert_eq!(nop.kind(), InspectedKind::NopPad);
+/// let list = inspector.next()?.unwrap();
+/// assert_eq!(list.kind(), InspectedKind::Value(IonType::List));
+/// assert_eq!(list.type_descriptor(), 0xB2);
+/// assert_eq!(list.length_code(), 2);
+/// assert_eq!(list.body_span().range(), 6..8);
+/// let int = inspector.next()?.unwrap();
+/// assert_eq!(int.depth(), 1);
+/// assert_eq!(int.header_span().bytes(), &[0x21]);
+/// assert_eq!(int.body_span().bytes(), &[0x05]);
+/// assert!(inspector.next()?.is_none());
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-tooling-apis"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub struct RawBinaryInspector_1_0<'data> {
+    // The unread portion of the top-level stream followed by that of each container being
+    // visited, innermost last.
+    frames: Vec<Frame<'data>>,
+}
+
+struct Frame<'data> {
+    remaining: ImmutableBuffer<'data>,
+    is_struct: bool,
+}
+
+impl<'data> RawBinaryInspector_1_0<'data> {
+    pub fn new(data: &'data [u8]) -> Self {
+        let top_level = Frame {
+            remaining: ImmutableBuffer::new(data),
+            is_struct: false,
+        };
+        Self {
+            frames: vec![top_level],
+        }
+    }
+
+    /// Returns the next item in the stream or `Ok(None)` if the end of the stream has been
+    /// reached.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> IonResult<Option<InspectedItem_1_0<'data>>> {
+        // Discard the containers whose items have all been visited.
+        while self.frames.len() > 1 && self.frames.last().unwrap().remaining.is_empty() {
+            self.frames.pop();
+        }
+        let depth = self.frames.len() - 1;
+        let frame = self.frames.last_mut().unwrap();
+        let input = frame.remaining;
+        if input.is_empty() {
+            return Ok(None);
+        }
+
+        let (field_id, input) = if frame.is_struct {
+            let (field_id, after_field_id) = input.read_var_uint()?;
+            if after_field_id.is_empty() {
+                return IonResult::incomplete("found a field ID but no value", input.offset());
+            }
+            (span_of(input, field_id.size_in_bytes()), after_field_id)
+        } else {
+            (span_of(input, 0), input)
+        };
+        let type_descriptor = input.peek_type_descriptor()?;
+
+        if depth == 0 && type_descriptor.is_ivm_start() {
+            let (marker, remaining) = input.read_ivm()?;
+            if marker.version() != (1, 0) {
+                let (major, minor) = marker.version();
+                return IonResult::decoding_error(format!(
+                    "only Ion 1.0 can be inspected, but found an IVM for Ion {major}.{minor}"
+                ));
+            }
+            frame.remaining = remaining;
+            let header = span_of(input, 4);
+            let item = Self::item(InspectedKind::VersionMarker, depth, field_id, header, &[]);
+            return Ok(Some(item));
+        }
+
+        if type_descriptor.is_nop() {
+            let (size, remaining) = input.read_nop_pad()?;
+            frame.remaining = remaining;
+            let header_length = Self::nop_header_length(input, type_descriptor)?;
+            let header = span_of(input, header_length);
+            let body = input.bytes_range(header_length, size - header_length);
+            let item = Self::item(InspectedKind::NopPad, depth, field_id, header, body);
+            return Ok(Some(item));
+        }
+
+        // `input` is not empty and does not begin with a NOP pad, so there is a value.
+        let value = input.peek_sequence_value()?.unwrap();
+        let encoded = &value.encoded_value;
+        let annotations = span_of(input, encoded.annotations_header_length as usize);
+        let header = span_of(
+            input.consume(annotations.range().len()),
+            encoded.header_length(),
+        );
+        let body_range = encoded.value_body_range();
+        let body = input.slice(body_range.start - input.offset(), body_range.len());
+        frame.remaining = input.consume(body_range.end - input.offset());
+
+        let ion_type = value.ion_type();
+        if ion_type.is_container() && !value.is_null() {
+            self.frames.push(Frame {
+                remaining: body,
+                is_struct: ion_type == IonType::Struct,
+            });
+        }
+        Ok(Some(InspectedItem_1_0 {
+            kind: InspectedKind::Value(ion_type),
+            depth,
+            field_id,
+            annotations,
+            header,
+            body: span_of(body, body.len()),
+        }))
+    }
+
+    fn nop_header_length(
+        input: ImmutableBuffer<'data>,
+        type_descriptor: TypeDescriptor,
+    ) -> IonResult<usize> {
+        let (length, _) = input.consume(1).read_length(type_descriptor.length_code)?;
+        Ok(1 + length.size_in_bytes())
+    }
+
+    fn item(
+        kind: InspectedKind,
+        depth: usize,
+        field_id: Span<'data>,
+        header: Span<'data>,
+        body: &'data [u8],
+    ) -> InspectedItem_1_0<'data> {
+        let header_end = header.range().end;
+        InspectedItem_1_0 {
+            kind,
+            depth,
+            field_id,
+            annotations: Span::with_offset(header.range().start, &[]),
+            header,
+            body: Span::with_offset(header_end, body),
+        }
+    }
+}
+
+/// Returns a span containing the first `length` bytes of `input`.
+fn span_of(input: ImmutableBuffer<'_>, length: usize) -> Span<'_> {
+    Span::with_offset(input.offset(), input.bytes_range(0, length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
+    use crate::lazy::encoder::value_writer::{
+        AnnotatableWriter, SequenceWriter, StructWriter, ValueWriter,
+    };
+
+    fn inspect(data: &[u8]) -> IonResult<Vec<InspectedItem_1_0<'_>>> {
+        let mut inspector = RawBinaryInspector_1_0::new(data);
+        let mut items = Vec::new();
+        while let Some(item) = inspector.next()? {
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    #[test]
+    fn reports_the_components_of_each_item() -> IonResult<()> {
+        let mut writer = LazyRawBinaryWriter_1_0::new(Vec::new())?;
+        let mut fields = writer
+            .value_writer()
+            .with_annotations(10)?
+            .struct_writer()?;
+        fields.write(11, "a string that needs a VarUInt length")?;
+        fields.write(12, [true])?;
+        fields.close()?;
+        writer.write_nop_pad(20)?;
+        writer.value_writer().write_null(IonType::Int)?;
+        let data = writer.close()?;
+        let items = inspect(&data)?;
+
+        use InspectedKind::*;
+        let summary: Vec<_> = items
+            .iter()
+            .map(|item| {
+                (
+                    item.kind(),
+                    item.depth(),
+                    item.field_id_span().bytes().len(),
+                    item.annotations_span().bytes().len(),
+                    item.length_span().bytes().len(),
+                    item.body_span().bytes().len(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (VersionMarker, 0, 0, 0, 0, 0),
+                (Value(IonType::Struct), 0, 0, 4, 1, 42),
+                (Value(IonType::String), 1, 1, 0, 1, 36),
+                (Value(IonType::List), 1, 1, 0, 0, 1),
+                (Value(IonType::Bool), 2, 0, 0, 0, 0),
+                (NopPad, 0, 0, 0, 1, 18),
+                (Value(IonType::Int), 0, 0, 0, 0, 0),
+            ]
+        );
+
+        // The spans of each item are contiguous and together cover the entire stream
+        let mut position = 0;
+        for item in &items {
+            let spans = [
+                item.field_id_span(),
+                item.annotations_span(),
+                item.header_span(),
+                item.body_span(),
+            ];
+            for span in spans {
+                assert_eq!(span.range().start, position, "{item:?}");
+                assert_eq!(span.bytes(), &data[span.range()]);
+                position = span.range().end;
+            }
+            if item.kind() == Value(IonType::Struct) || item.kind() == Value(IonType::List) {
+                position = item.body_span().range().start;
+            }
+        }
+        assert_eq!(position, data.len());
+
+        let string = &items[2];
+        assert_eq!(string.type_descriptor(), 0x8E);
+        assert_eq!(string.length_code(), 0x0E);
+        assert_eq!(string.header_span().bytes(), &[0x8E, 0xA4]);
+        assert_eq!(
+            string.range(),
+            string.field_id_span().range().start..string.body_span().range().end
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reports_nop_pads_in_structs() -> IonResult<()> {
+        // {$10: <1-byte NOP>, $11: true}
+        let data = [0xD4, 0x8A, 0x00, 0x8B, 0x11];
+        let items = inspect(&data)?;
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[1].kind(), InspectedKind::NopPad);
+        assert_eq!(items[1].field_id_span().bytes(), &[0x8A]);
+        assert_eq!(items[2].kind(), InspectedKind::Value(IonType::Bool));
+        assert_eq!(items[2].field_id_span().bytes(), &[0x8B]);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_other_ion_versions() {
+        let data = [0xE0, 0x01, 0x01, 0xEA];
+        assert!(inspect(&data).is_err());
+    }
+
+    #[test]
+    fn reports_incomplete_data() {
+        // A string whose body is cut short
+        let data = [0x83, b'a'];
+        assert!(inspect(&data).is_err());
+    }
+}
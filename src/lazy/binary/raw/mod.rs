@@ -1,4 +1,5 @@
 pub mod annotations_iterator;
+pub mod inspect;
 pub mod nibbles;
 pub mod reader;
 pub mod sequence;
@@ -33,7 +33,7 @@ impl<'data> LazyRawBinaryReader_1_0<'data> {
     }
 
     /// Helper method called by [`Self::next`]. Reads the current stream item as an Ion version
-    /// marker. If the version is not 1.0, returns an [`crate::IonError::Decoding`].
+    /// marker. If the version is not 1.0 or 1.1, returns an [`crate::IonError::Decoding`].
     fn read_ivm<'top>(
         &mut self,
         buffer: ImmutableBuffer<'data>,
@@ -43,9 +43,9 @@ impl<'data> LazyRawBinaryReader_1_0<'data> {
     {
         let (marker, _buffer_after_ivm) = buffer.read_ivm()?;
         let (major, minor) = marker.version();
-        if (major, minor) != (1, 0) {
+        if !matches!((major, minor), (1, 0) | (1, 1)) {
             return IonResult::decoding_error(format!(
-                "unsupported version of Ion: v{major}.{minor}; only 1.0 is supported"
+                "unsupported version of Ion: v{major}.{minor}; only 1.0 and 1.1 are supported"
             ));
         }
         self.data.buffer = buffer;
@@ -58,11 +58,17 @@ impl<'data> LazyRawBinaryReader_1_0<'data> {
     fn read_value<'top>(
         &mut self,
         buffer: ImmutableBuffer<'data>,
+        allow_partial_lob: bool,
     ) -> IonResult<LazyRawStreamItem<'top, BinaryEncoding_1_0>>
     where
         'data: 'top,
     {
-        let lazy_value = match ImmutableBuffer::peek_sequence_value(buffer)? {
+        let value = if allow_partial_lob {
+            buffer.peek_sequence_value_allowing_partial_lob()?
+        } else {
+            buffer.peek_sequence_value()?
+        };
+        let lazy_value = match value {
             Some(lazy_value) => lazy_value,
             None => {
                 return Ok(LazyRawStreamItem::<BinaryEncoding_1_0>::EndOfStream(
@@ -76,6 +82,28 @@ impl<'data> LazyRawBinaryReader_1_0<'data> {
     }
 
     pub fn next<'top>(&'top mut self) -> IonResult<LazyRawStreamItem<'top, BinaryEncoding_1_0>>
+    where
+        'data: 'top,
+    {
+        self.read_next_item(false)
+    }
+
+    /// Like [`next`](Self::next), but a top-level blob or clob whose body extends past the end of
+    /// the input is returned with only the part of its body that is available. See
+    /// [`LazyRawBinaryValue_1_0::partial_lob_payload`].
+    pub fn next_allowing_partial_lob<'top>(
+        &'top mut self,
+    ) -> IonResult<LazyRawStreamItem<'top, BinaryEncoding_1_0>>
+    where
+        'data: 'top,
+    {
+        self.read_next_item(true)
+    }
+
+    fn read_next_item<'top>(
+        &'top mut self,
+        allow_partial_lob: bool,
+    ) -> IonResult<LazyRawStreamItem<'top, BinaryEncoding_1_0>>
     where
         'data: 'top,
     {
@@ -105,7 +133,7 @@ impl<'data> LazyRawBinaryReader_1_0<'data> {
             return self.read_ivm(buffer);
         }
 
-        self.read_value(buffer)
+        self.read_value(buffer, allow_partial_lob)
     }
 }
 
@@ -133,6 +161,16 @@ impl<'data> LazyRawReader<'data, BinaryEncoding_1_0> for LazyRawBinaryReader_1_0
         self.next()
     }
 
+    fn next_allowing_partial_lob<'top>(
+        &'top mut self,
+        _context: EncodingContextRef<'top>,
+    ) -> IonResult<LazyRawStreamItem<'top, BinaryEncoding_1_0>>
+    where
+        'data: 'top,
+    {
+        self.next_allowing_partial_lob()
+    }
+
     fn position(&self) -> usize {
         self.data.buffer.offset() + self.data.bytes_to_skip
     }
@@ -503,7 +503,7 @@ impl<'a> ImmutableBuffer<'a> {
             args_cache.push(value_expr);
         }
         let macro_id_encoded_length = buffer_after_id.offset() - self.offset();
-        let args_length = args_buffer.offset() + args_buffer.len() - buffer_after_id.offset();
+        let args_length = args_buffer.offset() - buffer_after_id.offset();
         let e_expression_buffer = self.slice(0, macro_id_encoded_length + args_length);
 
         let e_expression = RawBinaryEExpression_1_1::new(
@@ -541,6 +541,7 @@ mod tests {
     use crate::lazy::expanded::macro_evaluator::RawEExpression;
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::text::raw::v1_1::reader::MacroAddress;
+    use crate::HasSpan;
 
     fn input_test<A: AsRef<[u8]>>(input: A) {
         let empty_context = EncodingContext::empty();
@@ -703,4 +704,33 @@ mod tests {
 
         eexp_test(macro_source, encode_eexp_fn, args_test)
     }
+
+    #[test]
+    fn eexp_span_excludes_following_data() -> IonResult<()> {
+        let macro_source = r#"
+            (macro greet (name)
+                (make_string "Hello, " name "!")
+            )
+        "#;
+
+        #[rustfmt::skip]
+        let encode_eexp_fn = |address: MacroAddress| vec![
+            address as u8,
+            // === 3-byte string ====
+            0x93,
+            // B     o     b
+            0x42, 0x6F, 0x62,
+            // === 1-byte int (not part of the e-expression) ===
+            0x61, 0x01,
+        ];
+
+        let span_test = |eexp: RawBinaryEExpression_1_1| {
+            // The e-expression's span covers its macro ID and its argument, but not the int.
+            assert_eq!(eexp.range(), 0..5);
+            assert_eq!(eexp.span().bytes().len(), 5);
+            Ok(())
+        };
+
+        eexp_test(macro_source, encode_eexp_fn, span_test)
+    }
 }
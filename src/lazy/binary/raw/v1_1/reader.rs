@@ -44,9 +44,9 @@ impl<'data> LazyRawBinaryReader_1_1<'data> {
     {
         let (marker, buffer_after_ivm) = buffer.read_ivm()?;
         let (major, minor) = marker.version();
-        if (major, minor) != (1, 1) {
+        if !matches!((major, minor), (1, 0) | (1, 1)) {
             return IonResult::decoding_error(format!(
-                "unsupported version of Ion: v{major}.{minor}; only 1.1 is supported by this reader",
+                "unsupported version of Ion: v{major}.{minor}; only 1.0 and 1.1 are supported",
             ));
         }
         self.local_offset = buffer_after_ivm.offset() - self.stream_offset;
@@ -873,6 +873,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn struct_switches_to_flex_sym_field_names() -> IonResult<()> {
+        use crate::lazy::decoder::LazyRawFieldName;
+
+        #[rustfmt::skip]
+        let data: &[u8] = &[
+            // { $0 (switch to FlexSym field names) "foo": 1, $11: 2 }
+            0xDA, 0x01,
+            0xFB, 0x66, 0x6F, 0x6F, 0x61, 0x01,
+            0x17, 0x61, 0x02,
+        ];
+        let expected: &[(RawSymbolRef, i64)] = &[("foo".into(), 1), (11usize.into(), 2)];
+
+        let encoding_context = EncodingContext::empty();
+        let context = encoding_context.get_ref();
+        let mut reader = LazyRawBinaryReader_1_1::new(data);
+        let strukt = reader
+            .next(context)?
+            .expect_value()?
+            .read()?
+            .expect_struct()?;
+        let mut fields = Vec::new();
+        for field in strukt.iter() {
+            let (name, value) = field?.expect_name_value()?;
+            fields.push((name.read()?, value.read()?.expect_i64()?));
+        }
+        assert_eq!(fields, expected);
+        Ok(())
+    }
+
     fn structs() -> IonResult<()> {
         use crate::lazy::decoder::{LazyRawFieldExpr, LazyRawFieldName};
 
@@ -32,6 +32,12 @@ impl<'top> LazyRawBinaryFieldName_1_1<'top> {
             matched,
         }
     }
+
+    // In a struct whose field names are encoded as symbol addresses, the address zero switches the
+    // struct to FlexSym-encoded field names.
+    fn is_flex_sym_mode_switch(&self) -> bool {
+        self.field_name == RawSymbolRef::SymbolId(0)
+    }
 }
 
 impl<'top> HasSpan<'top> for LazyRawBinaryFieldName_1_1<'top> {
@@ -119,6 +125,7 @@ impl<'top> LazyRawStruct<'top, BinaryEncoding_1_1> for LazyRawBinaryStruct_1_1<'
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
 enum StructType {
     FlexSym,
     SymbolAddress,
@@ -215,12 +222,25 @@ impl<'top> RawBinaryStructIterator_1_1<'top> {
     /// Helper function called from [`Self::next`] to parse the current field and value from the
     /// struct. On success, returns both the field pair via [`LazyRawFieldExpr`] as well as the
     /// total bytes needed to skip the field.
-    fn peek_field(&self) -> IonResult<Option<(LazyRawFieldExpr<'top, BinaryEncoding_1_1>, usize)>> {
+    ///
+    /// If the struct switches from symbol address encoding to FlexSym encoding, the iterator's
+    /// `struct_type` is updated so that subsequent field names are parsed as FlexSyms.
+    fn peek_field(
+        &mut self,
+    ) -> IonResult<Option<(LazyRawFieldExpr<'top, BinaryEncoding_1_1>, usize)>> {
         let mut buffer = self.source;
         loop {
             // Peek at our field name.
             let peek_result = match self.struct_type {
-                StructType::SymbolAddress => Self::peek_field_symbol_addr(buffer)?,
+                StructType::SymbolAddress => match Self::peek_field_symbol_addr(buffer)? {
+                    // A symbol address of zero indicates that the remaining field names in the
+                    // struct are encoded as FlexSyms.
+                    Some((field_name, after_name)) if field_name.is_flex_sym_mode_switch() => {
+                        self.struct_type = StructType::FlexSym;
+                        Self::peek_field_flexsym(after_name)?
+                    }
+                    peek_result => peek_result,
+                },
                 StructType::FlexSym => Self::peek_field_flexsym(buffer)?,
             };
 
@@ -137,6 +137,12 @@ impl<'top> LazyRawValue<'top, BinaryEncoding_1_1> for LazyRawBinaryValue_1_1<'to
         let local_range = (range.start - self.input.offset())..(range.end - self.input.offset());
         Span::with_offset(range.start, &self.input.bytes()[local_range])
     }
+
+    fn is_encoded_as_f32(&self) -> bool {
+        self.ion_type() == IonType::Float
+            && !self.is_null()
+            && self.encoded_value.value_body_length == 4
+    }
 }
 
 impl<'top> LazyRawBinaryValue_1_1<'top> {
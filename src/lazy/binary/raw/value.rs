@@ -86,10 +86,7 @@ pub type ValueParseResult<'top, F> = IonResult<RawValueRef<'top, F>>;
 
 impl<'top> HasSpan<'top> for LazyRawBinaryValue_1_0<'top> {
     fn span(&self) -> Span<'top> {
-        let range = self.range();
-        // Subtract the `offset()` of the ImmutableBuffer to get the local indexes for start/end
-        let local_range = (range.start - self.input.offset())..(range.end - self.input.offset());
-        Span::with_offset(range.start, &self.input.bytes()[local_range])
+        self.buffered_span(self.range())
     }
 }
 
@@ -130,9 +127,17 @@ impl<'top> LazyRawValue<'top, BinaryEncoding_1_0> for LazyRawBinaryValue_1_0<'to
     }
 
     fn value_span(&self) -> Span<'top> {
-        let range = self.encoded_value.unannotated_value_range();
-        let local_range = (range.start - self.input.offset())..(range.end - self.input.offset());
-        Span::with_offset(range.start, &self.input.bytes()[local_range])
+        self.buffered_span(self.encoded_value.unannotated_value_range())
+    }
+
+    fn is_encoded_as_f32(&self) -> bool {
+        self.ion_type() == IonType::Float
+            && !self.is_null()
+            && self.encoded_value.value_body_length() == 4
+    }
+
+    fn partial_lob_payload(&self) -> Option<&'top [u8]> {
+        self.partial_lob_payload()
     }
 }
 
@@ -213,11 +218,7 @@ impl<'a, 'top> EncodedBinaryValueData_1_0<'a, 'top> {
     /// Returns the encoded bytes that represent the complete value, including its opcode, length,
     /// and body.
     pub fn span(&self) -> Span<'top> {
-        let stream_range = self.range();
-        let offset = self.value.input.offset();
-        let local_range = stream_range.start - offset..stream_range.end - offset;
-        let bytes = &self.value.input.bytes()[local_range];
-        Span::with_offset(stream_range.start, bytes)
+        self.value.buffered_span(self.range())
     }
 
     /// Returns the input stream index range that contains the bytes representing the
@@ -269,9 +270,8 @@ impl<'a, 'top> EncodedBinaryValueData_1_0<'a, 'top> {
     /// that follows its opcode and length).
     pub fn body_span(&self) -> Span<'top> {
         let body_range = self.body_range();
-        let body_length = body_range.len();
-        let value_bytes = self.span().bytes();
-        let body_bytes = &value_bytes[value_bytes.len() - body_length..];
+        let body_offset = self.value.encoded_value.header_length();
+        let body_bytes = &self.span().bytes()[body_offset..];
         Span::with_offset(body_range.start, body_bytes)
     }
 }
@@ -363,6 +363,37 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
         }
     }
 
+    /// Returns the bytes of the stream `range` that are in the input buffer, which is all of them
+    /// unless this value is a partly buffered lob.
+    fn buffered_span(&self, range: Range<usize>) -> Span<'top> {
+        // Subtract the `offset()` of the ImmutableBuffer to get the local indexes for start/end
+        let start = range.start - self.input.offset();
+        let end = (range.end - self.input.offset()).min(self.input.len());
+        Span::with_offset(range.start, &self.input.bytes()[start..end])
+    }
+
+    /// If this value is a blob or clob whose body extends past the end of the input buffer,
+    /// returns the part of its body that is available. A value like this is only read at the top
+    /// level of a stream whose buffer cannot grow to hold it; see
+    /// [`LazyRawReader::next_allowing_partial_lob`](crate::lazy::decoder::LazyRawReader::next_allowing_partial_lob).
+    pub fn partial_lob_payload(&self) -> Option<&'top [u8]> {
+        if self.input.len() >= self.encoded_value.total_length() {
+            return None;
+        }
+        Some(self.available_body().bytes())
+    }
+
+    /// Returns the body of this blob or clob, which must be in the input buffer in full.
+    fn lob_body(&self) -> IonResult<&'top [u8]> {
+        if self.partial_lob_payload().is_some() {
+            return IonResult::decoding_error(format!(
+                "the {} is too large for the input buffer; use `LazyValue::lob_reader` to read it",
+                self.ion_type()
+            ));
+        }
+        Ok(self.value_body())
+    }
+
     /// Returns the encoded byte slice representing this value's data.
     pub fn value_body(&self) -> &'top [u8] {
         let value_total_length = self.encoded_value.total_length();
@@ -597,14 +628,14 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
     /// Helper method called by [`Self::read`]. Reads the current value as a blob.
     fn read_blob(&self) -> ValueParseResult<'top, BinaryEncoding_1_0> {
         debug_assert!(self.encoded_value.ion_type() == IonType::Blob);
-        let bytes = self.value_body();
+        let bytes = self.lob_body()?;
         Ok(RawValueRef::Blob(bytes.into()))
     }
 
     /// Helper method called by [`Self::read`]. Reads the current value as a clob.
     fn read_clob(&self) -> ValueParseResult<'top, BinaryEncoding_1_0> {
         debug_assert!(self.encoded_value.ion_type() == IonType::Clob);
-        let bytes = self.value_body();
+        let bytes = self.lob_body()?;
         Ok(RawValueRef::Clob(bytes.into()))
     }
 
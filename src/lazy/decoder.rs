@@ -356,6 +356,21 @@ pub trait LazyRawReader<'data, D: Decoder>: Sized {
     where
         'data: 'top;
 
+    /// Like [`next`](Self::next), but if the next item is a top-level blob or clob whose body
+    /// extends past the end of the input, it is returned with only the part of its body that is
+    /// available instead of being reported as incomplete. See
+    /// [`LazyRawValue::partial_lob_payload`]. Encodings that cannot do this return the same result
+    /// as `next`.
+    fn next_allowing_partial_lob<'top>(
+        &'top mut self,
+        context: EncodingContextRef<'top>,
+    ) -> IonResult<LazyRawStreamItem<'top, D>>
+    where
+        'data: 'top,
+    {
+        self.next(context)
+    }
+
     fn save_state(&self) -> D::ReaderSavedState {
         D::ReaderSavedState::default()
     }
@@ -384,6 +399,20 @@ pub trait LazyRawValue<'top, D: Decoder>:
     fn annotations_span(&self) -> Span<'top>;
 
     fn value_span(&self) -> Span<'top>;
+
+    /// Returns `true` if this value is a non-null float that was encoded using 32 bits. Text Ion
+    /// does not record the width of a float, so text values always return `false`.
+    fn is_encoded_as_f32(&self) -> bool {
+        false
+    }
+
+    /// If this value is a blob or clob that was too large to be buffered in full, returns the part
+    /// of its payload that is in the input buffer. (See
+    /// [`BufferStrategy::with_max_capacity`](crate::BufferStrategy::with_max_capacity).) Returns
+    /// `None` for all other values.
+    fn partial_lob_payload(&self) -> Option<&'top [u8]> {
+        None
+    }
 }
 
 pub trait LazyRawSequence<'top, D: Decoder>:
@@ -25,6 +25,8 @@ pub struct BinaryContainerWriter_1_0<'value, 'top> {
     parent_buffer: &'value mut BumpVec<'top, u8>,
     // In binary Ion 1.0, only symbol IDs can be used as annotations.
     annotations: Option<BumpVec<'top, SymbolId>>,
+    // Passed along to the value writers for the container's child values.
+    compact_floats: bool,
 }
 
 impl<'value, 'top> BinaryContainerWriter_1_0<'value, 'top> {
@@ -32,6 +34,7 @@ impl<'value, 'top> BinaryContainerWriter_1_0<'value, 'top> {
         type_code: u8,
         allocator: &'top BumpAllocator,
         buffer: &'value mut BumpVec<'top, u8>,
+        compact_floats: bool,
     ) -> Self {
         Self {
             type_code,
@@ -42,6 +45,7 @@ impl<'value, 'top> BinaryContainerWriter_1_0<'value, 'top> {
             ),
             parent_buffer: buffer,
             annotations: None,
+            compact_floats,
         }
     }
 
@@ -68,8 +72,11 @@ impl<'value, 'top> BinaryContainerWriter_1_0<'value, 'top> {
     }
 
     pub fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
-        let value_writer =
-            BinaryValueWriter_1_0::new(self.allocator, &mut self.child_values_buffer);
+        let value_writer = BinaryValueWriter_1_0::new(
+            self.allocator,
+            &mut self.child_values_buffer,
+            self.compact_floats,
+        );
         value.write_as_ion(value_writer)?;
         Ok(self)
     }
@@ -174,12 +181,14 @@ impl<'value, 'top> BinaryListWriter_1_0<'value, 'top> {
     pub(crate) fn new(
         allocator: &'top BumpAllocator,
         buffer: &'value mut BumpVec<'top, u8>,
+        compact_floats: bool,
     ) -> Self {
         const LIST_TYPE_CODE: u8 = 0xB0;
         BinaryListWriter_1_0::with_container_writer(BinaryContainerWriter_1_0::new(
             LIST_TYPE_CODE,
             allocator,
             buffer,
+            compact_floats,
         ))
     }
 
@@ -203,6 +212,7 @@ impl<'value, 'top> MakeValueWriter for BinaryListWriter_1_0<'value, 'top> {
         BinaryValueWriter_1_0::new(
             self.container_writer.allocator,
             &mut self.container_writer.child_values_buffer,
+            self.container_writer.compact_floats,
         )
     }
 }
@@ -222,6 +232,7 @@ impl<'value, 'top> MakeValueWriter for BinarySExpWriter_1_0<'value, 'top> {
         BinaryValueWriter_1_0::new(
             self.container_writer.allocator,
             &mut self.container_writer.child_values_buffer,
+            self.container_writer.compact_floats,
         )
     }
 }
@@ -248,9 +259,11 @@ impl<'value, 'top> BinarySExpWriter_1_0<'value, 'top> {
     pub(crate) fn new(
         allocator: &'top BumpAllocator,
         buffer: &'value mut BumpVec<'top, u8>,
+        compact_floats: bool,
     ) -> Self {
         const SEXP_TYPE_CODE: u8 = 0xC0;
-        let container_writer = BinaryContainerWriter_1_0::new(SEXP_TYPE_CODE, allocator, buffer);
+        let container_writer =
+            BinaryContainerWriter_1_0::new(SEXP_TYPE_CODE, allocator, buffer, compact_floats);
         Self::with_container_writer(container_writer)
     }
 
@@ -283,12 +296,14 @@ impl<'value, 'top> BinaryStructWriter_1_0<'value, 'top> {
     pub(crate) fn new(
         allocator: &'top BumpAllocator,
         buffer: &'value mut BumpVec<'top, u8>,
+        compact_floats: bool,
     ) -> Self {
         const STRUCT_TYPE_CODE: u8 = 0xD0;
         Self::with_container_writer(BinaryContainerWriter_1_0::new(
             STRUCT_TYPE_CODE,
             allocator,
             buffer,
+            compact_floats,
         ))
     }
 
@@ -330,6 +345,7 @@ impl<'value, 'top> MakeValueWriter for BinaryStructWriter_1_0<'value, 'top> {
         BinaryValueWriter_1_0::new(
             self.container_writer.allocator,
             &mut self.container_writer.child_values_buffer,
+            self.container_writer.compact_floats,
         )
     }
 }
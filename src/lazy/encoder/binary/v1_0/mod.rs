@@ -4,6 +4,7 @@ use crate::lazy::encoding::BinaryEncoding_1_0;
 use std::io::Write;
 
 mod container_writers;
+pub mod seeking_writer;
 pub mod value_writer;
 pub mod writer;
 
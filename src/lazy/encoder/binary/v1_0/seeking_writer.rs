@@ -0,0 +1,633 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use bumpalo::collections::Vec as BumpVec;
+use bumpalo::Bump as BumpAllocator;
+
+use crate::binary::var_uint::VarUInt;
+use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
+use crate::lazy::encoder::binary::v1_0::value_writer::{BinaryValueWriter_1_0, MAX_INLINE_LENGTH};
+use crate::lazy::encoder::lob_writer;
+use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
+use crate::lazy::encoder::value_writer::{
+    AnnotatableWriter, SequenceWriter, StructWriter, ValueWriter,
+};
+use crate::lazy::encoder::write_as_ion::WriteAsIon;
+use crate::lazy::never::Never;
+use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
+use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::result::IonFailure;
+use crate::{Decimal, Int, IonResult, IonType, RawSymbolRef, Timestamp};
+
+/// The number of bytes reserved for the length of each container and annotations wrapper. A
+/// 5-byte VarUInt can represent lengths of up to 32GiB.
+const RESERVED_LENGTH_SIZE: usize = 5;
+const MAX_RESERVED_LENGTH: u64 = (1 << (7 * RESERVED_LENGTH_SIZE)) - 1;
+
+/// A "raw"-level binary Ion 1.0 writer for seekable outputs like files.
+///
+/// Unlike [`LazyRawBinaryWriter_1_0`](super::writer::LazyRawBinaryWriter_1_0), which encodes each
+/// top-level value in memory so that container lengths are known before they are written, this
+/// writer writes values directly to its output. When a container is opened, space for its length
+/// is reserved; when the container is closed, the writer seeks back and fills in the length.
+/// Memory use therefore stays flat regardless of the size of the containers being written, at
+/// the cost of a few bytes per container and two seeks each time a container is closed.
+///
+/// Like the other raw writers, this writer does not manage a symbol table; symbol values, field
+/// names, and annotations must be written as symbol IDs. To write values that use symbol text,
+/// use [`Writer::write_unbuffered`](crate::Writer::write_unbuffered), which writes through this
+/// writer after adding the value's symbols to its symbol table.
+///
+/// A container writer that is dropped without being closed leaves a placeholder length in the
+/// output. When that happens, the writer is poisoned: any further attempt to write a value, close
+/// a container, or close the writer returns an error.
+pub struct LazyRawSeekingBinaryWriter_1_0<W: Write + Seek> {
+    output: W,
+    // The position in `output` at which the next byte will be written.
+    position: u64,
+    // Scratch space in which scalar values are encoded before being written to `output`.
+    allocator: BumpAllocator,
+    // Whether `f64` values that can be losslessly represented as an `f32` are written using the
+    // 4-byte float encoding.
+    compact_floats: bool,
+    // Whether a container writer was dropped without being closed, leaving its length unset.
+    is_poisoned: bool,
+}
+
+impl<W: Write + Seek> LazyRawSeekingBinaryWriter_1_0<W> {
+    /// Constructs a new writer and writes an Ion 1.0 Version Marker to output at its current
+    /// position.
+    pub fn new(output: W) -> IonResult<Self> {
+        let mut writer = Self::without_version_marker(output)?;
+        writer.write_bytes(&[0xE0, 0x01, 0x00, 0xEA])?;
+        Ok(writer)
+    }
+
+    /// Constructs a new writer that continues a stream whose version marker has already been
+    /// written to `output`.
+    pub(crate) fn without_version_marker(mut output: W) -> IonResult<Self> {
+        let position = output.stream_position()?;
+        Ok(Self {
+            output,
+            position,
+            allocator: BumpAllocator::new(),
+            compact_floats: true,
+            is_poisoned: false,
+        })
+    }
+
+    /// Writes the given Rust value to the output stream as a top-level value.
+    pub fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
+        value.write_as_ion(self.value_writer())?;
+        Ok(self)
+    }
+
+    /// Calls [`Write::flush`] on the output.
+    pub fn flush(&mut self) -> IonResult<()> {
+        self.output.flush()?;
+        Ok(())
+    }
+
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    fn value_writer(&mut self) -> SeekingValueWriter_1_0<'_, W> {
+        SeekingValueWriter_1_0 {
+            writer: self,
+            annotations: AnnotationsVec::new(),
+        }
+    }
+
+    /// Returns an error if the writer has been poisoned by a container that was not closed.
+    fn check_not_poisoned(&self) -> IonResult<()> {
+        if self.is_poisoned {
+            return IonResult::illegal_operation(
+                "the writer cannot be used after a container writer was dropped without being \
+                 closed",
+            );
+        }
+        Ok(())
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> IonResult<()> {
+        self.check_not_poisoned()?;
+        self.output.write_all(bytes)?;
+        self.position += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Writes a placeholder for a length that will later be set by [`Self::patch_length`],
+    /// returning the placeholder's position.
+    fn reserve_length(&mut self) -> IonResult<u64> {
+        let position = self.position;
+        self.write_bytes(&[0u8; RESERVED_LENGTH_SIZE])?;
+        Ok(position)
+    }
+
+    /// Overwrites the placeholder at `position` with the number of bytes that have been written
+    /// since the placeholder.
+    fn patch_length(&mut self, position: u64) -> IonResult<()> {
+        self.check_not_poisoned()?;
+        let length = self.position - position - RESERVED_LENGTH_SIZE as u64;
+        if length > MAX_RESERVED_LENGTH {
+            return IonResult::encoding_error(format!(
+                "a container's length ({length} bytes) exceeds the maximum of {MAX_RESERVED_LENGTH}"
+            ));
+        }
+        // Encode the length as a VarUInt padded with leading zero bytes.
+        let mut encoded = [0u8; RESERVED_LENGTH_SIZE];
+        for (index, byte) in encoded.iter_mut().rev().enumerate() {
+            *byte = ((length >> (7 * index)) & 0x7F) as u8;
+        }
+        encoded[RESERVED_LENGTH_SIZE - 1] |= 0x80;
+
+        self.output.seek(SeekFrom::Start(position))?;
+        self.output.write_all(&encoded)?;
+        self.output.seek(SeekFrom::Start(self.position))?;
+        Ok(())
+    }
+}
+
+impl<W: Write + Seek> MakeValueWriter for LazyRawSeekingBinaryWriter_1_0<W> {
+    type ValueWriter<'a> = SeekingValueWriter_1_0<'a, W> where Self: 'a;
+
+    fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        self.value_writer()
+    }
+}
+
+impl<W: Write + Seek> SequenceWriter for LazyRawSeekingBinaryWriter_1_0<W> {
+    type Resources = W;
+
+    fn close(mut self) -> IonResult<Self::Resources> {
+        self.check_not_poisoned()?;
+        self.flush()?;
+        Ok(self.output)
+    }
+}
+
+pub struct SeekingValueWriter_1_0<'value, W: Write + Seek> {
+    writer: &'value mut LazyRawSeekingBinaryWriter_1_0<W>,
+    annotations: AnnotationsVec<'value>,
+}
+
+impl<'value, W: Write + Seek> SeekingValueWriter_1_0<'value, W> {
+    /// Encodes a scalar value in scratch space and then writes it (and its annotations) to the
+    /// output.
+    fn write_scalar(
+        self,
+        encode: impl FnOnce(BinaryValueWriter_1_0<'_, '_>) -> IonResult<()>,
+    ) -> IonResult<()> {
+        self.writer.check_not_poisoned()?;
+        let annotations = self.encode_annotations()?;
+        let LazyRawSeekingBinaryWriter_1_0 {
+            output,
+            position,
+            allocator,
+            compact_floats,
+            ..
+        } = self.writer;
+        let mut buffer = BumpVec::new_in(&*allocator);
+        encode(BinaryValueWriter_1_0::new(
+            allocator,
+            &mut buffer,
+            *compact_floats,
+        ))?;
+
+        let mut header = Vec::new();
+        if !annotations.is_empty() {
+            let wrapper_length = annotations.len() + buffer.len();
+            if wrapper_length <= MAX_INLINE_LENGTH {
+                header.push(0xE0 | wrapper_length as u8);
+            } else {
+                header.push(0xEE);
+                VarUInt::write_u64(&mut header, wrapper_length as u64)?;
+            }
+            header.extend_from_slice(&annotations);
+        }
+        output.write_all(&header)?;
+        output.write_all(buffer.as_slice())?;
+        *position += (header.len() + buffer.len()) as u64;
+        drop(buffer);
+        allocator.reset();
+        Ok(())
+    }
+
+    /// Writes a lob (and its annotations) directly to the output, copying its `length`-byte
+    /// payload from `source`. Unlike other scalars, the lob is not encoded in scratch space first;
+    /// its encoded length is known before its payload is read.
+    fn write_lob_from_reader(
+        self,
+        ion_type: IonType,
+        length: u64,
+        source: impl Read,
+    ) -> IonResult<()> {
+        let annotations = self.encode_annotations()?;
+        let mut lob_header = Vec::new();
+        lob_writer::write_binary_1_0_lob_header(&mut lob_header, ion_type, length)?;
+        let mut header = Vec::new();
+        if !annotations.is_empty() {
+            let wrapper_length = (annotations.len() + lob_header.len()) as u64 + length;
+            if wrapper_length <= MAX_INLINE_LENGTH as u64 {
+                header.push(0xE0 | wrapper_length as u8);
+            } else {
+                header.push(0xEE);
+                VarUInt::write_u64(&mut header, wrapper_length)?;
+            }
+            header.extend_from_slice(&annotations);
+        }
+        header.extend_from_slice(&lob_header);
+        let writer = self.writer;
+        writer.write_bytes(&header)?;
+        lob_writer::copy_payload(length, source, |chunk| writer.write_bytes(chunk))
+    }
+
+    /// Returns the encoding of the annotations sequence and its length, or an empty `Vec` if
+    /// there are no annotations.
+    fn encode_annotations(&self) -> IonResult<Vec<u8>> {
+        if self.annotations.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut sequence = Vec::new();
+        for annotation in &self.annotations {
+            let RawSymbolRef::SymbolId(sid) = annotation.as_raw_symbol_token_ref() else {
+                return IonResult::encoding_error(
+                    "binary Ion 1.0 cannot encode text literal annotations",
+                );
+            };
+            VarUInt::write_u64(&mut sequence, sid as u64)?;
+        }
+        let mut encoded = Vec::with_capacity(sequence.len() + 1);
+        VarUInt::write_u64(&mut encoded, sequence.len() as u64)?;
+        encoded.extend_from_slice(&sequence);
+        Ok(encoded)
+    }
+
+    /// Writes the header of a container (and of its annotations wrapper, if any) with
+    /// placeholders for their lengths.
+    fn container_writer(
+        self,
+        type_descriptor: u8,
+    ) -> IonResult<SeekingContainerWriter_1_0<'value, W>> {
+        let annotations = self.encode_annotations()?;
+        let wrapper_length_position = if annotations.is_empty() {
+            None
+        } else {
+            self.writer.write_bytes(&[0xEE])?;
+            let position = self.writer.reserve_length()?;
+            self.writer.write_bytes(&annotations)?;
+            Some(position)
+        };
+        self.writer.write_bytes(&[type_descriptor])?;
+        let length_position = self.writer.reserve_length()?;
+        Ok(SeekingContainerWriter_1_0 {
+            writer: self.writer,
+            length_position,
+            wrapper_length_position,
+            is_closed: false,
+        })
+    }
+}
+
+impl<'value, W: Write + Seek> AnnotatableWriter for SeekingValueWriter_1_0<'value, W> {
+    type AnnotatedValueWriter<'a> = SeekingValueWriter_1_0<'a, W> where Self: 'a;
+
+    fn with_annotations<'a>(
+        self,
+        annotations: impl AnnotationSeq<'a>,
+    ) -> IonResult<Self::AnnotatedValueWriter<'a>>
+    where
+        Self: 'a,
+    {
+        Ok(SeekingValueWriter_1_0 {
+            writer: self.writer,
+            annotations: annotations.into_annotations_vec(),
+        })
+    }
+}
+
+/// Takes a series of `TYPE => METHOD` pairs, generating a function for each that encodes the
+/// value using the corresponding method of [`BinaryValueWriter_1_0`].
+macro_rules! write_scalar_with {
+    ($($value_type:ty => $method:ident),* $(,)?) => {
+        $(
+            fn $method(self, value: $value_type) -> IonResult<()> {
+                self.write_scalar(|value_writer| value_writer.$method(value))
+            }
+        )*
+    };
+}
+
+impl<'value, W: Write + Seek> ValueWriter for SeekingValueWriter_1_0<'value, W> {
+    type ListWriter = SeekingSequenceWriter_1_0<'value, W>;
+    type SExpWriter = SeekingSequenceWriter_1_0<'value, W>;
+    type StructWriter = SeekingStructWriter_1_0<'value, W>;
+    // Ion 1.0
+    type EExpWriter = Never;
+    const IS_HUMAN_READABLE: bool = false;
+
+    write_scalar_with!(
+        IonType => write_null,
+        bool => write_bool,
+        i64 => write_i64,
+        &Int => write_int,
+        f32 => write_f32,
+        f64 => write_f64,
+        &Decimal => write_decimal,
+        &Timestamp => write_timestamp,
+        impl AsRef<str> => write_string,
+        impl AsRawSymbolRef => write_symbol,
+        impl AsRef<[u8]> => write_clob,
+        impl AsRef<[u8]> => write_blob,
+    );
+
+    fn write_blob_from_reader(self, length: u64, source: impl Read) -> IonResult<()> {
+        self.write_lob_from_reader(IonType::Blob, length, source)
+    }
+
+    fn write_clob_from_reader(self, length: u64, source: impl Read) -> IonResult<()> {
+        self.write_lob_from_reader(IonType::Clob, length, source)
+    }
+
+    fn list_writer(self) -> IonResult<Self::ListWriter> {
+        Ok(SeekingSequenceWriter_1_0 {
+            container: self.container_writer(0xBE)?,
+        })
+    }
+
+    fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
+        Ok(SeekingSequenceWriter_1_0 {
+            container: self.container_writer(0xCE)?,
+        })
+    }
+
+    fn struct_writer(self) -> IonResult<Self::StructWriter> {
+        Ok(SeekingStructWriter_1_0 {
+            container: self.container_writer(0xDE)?,
+        })
+    }
+
+    fn eexp_writer<'a>(self, _macro_id: impl Into<MacroIdRef<'a>>) -> IonResult<Self::EExpWriter> {
+        IonResult::encoding_error("binary Ion 1.0 does not support macros")
+    }
+}
+
+/// A container whose length (and annotations wrapper length) will be set when it is closed.
+struct SeekingContainerWriter_1_0<'value, W: Write + Seek> {
+    writer: &'value mut LazyRawSeekingBinaryWriter_1_0<W>,
+    length_position: u64,
+    wrapper_length_position: Option<u64>,
+    // Whether the lengths have been set
+    is_closed: bool,
+}
+
+impl<'value, W: Write + Seek> SeekingContainerWriter_1_0<'value, W> {
+    fn close(mut self) -> IonResult<()> {
+        self.writer.patch_length(self.length_position)?;
+        if let Some(position) = self.wrapper_length_position {
+            self.writer.patch_length(position)?;
+        }
+        self.is_closed = true;
+        Ok(())
+    }
+}
+
+impl<'value, W: Write + Seek> Drop for SeekingContainerWriter_1_0<'value, W> {
+    fn drop(&mut self) {
+        // The container's placeholder lengths were never set, so the output is not valid Ion.
+        if !self.is_closed {
+            self.writer.is_poisoned = true;
+        }
+    }
+}
+
+/// Writes the child values of a list or s-expression directly to the output.
+pub struct SeekingSequenceWriter_1_0<'value, W: Write + Seek> {
+    container: SeekingContainerWriter_1_0<'value, W>,
+}
+
+impl<'value, W: Write + Seek> MakeValueWriter for SeekingSequenceWriter_1_0<'value, W> {
+    type ValueWriter<'a> = SeekingValueWriter_1_0<'a, W> where Self: 'a;
+
+    fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        self.container.writer.value_writer()
+    }
+}
+
+impl<'value, W: Write + Seek> SequenceWriter for SeekingSequenceWriter_1_0<'value, W> {
+    type Resources = ();
+
+    fn close(self) -> IonResult<Self::Resources> {
+        self.container.close()
+    }
+}
+
+/// Writes the fields of a struct directly to the output.
+pub struct SeekingStructWriter_1_0<'value, W: Write + Seek> {
+    container: SeekingContainerWriter_1_0<'value, W>,
+}
+
+impl<'value, W: Write + Seek> FieldEncoder for SeekingStructWriter_1_0<'value, W> {
+    fn encode_field_name(&mut self, name: impl AsRawSymbolRef) -> IonResult<()> {
+        let RawSymbolRef::SymbolId(sid) = name.as_raw_symbol_token_ref() else {
+            return IonResult::encoding_error(
+                "binary Ion 1.0 cannot encode text literal field names",
+            );
+        };
+        let mut encoded = Vec::new();
+        VarUInt::write_u64(&mut encoded, sid as u64)?;
+        self.container.writer.write_bytes(&encoded)
+    }
+}
+
+impl<'value, W: Write + Seek> MakeValueWriter for SeekingStructWriter_1_0<'value, W> {
+    type ValueWriter<'a> = SeekingValueWriter_1_0<'a, W> where Self: 'a;
+
+    fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        self.container.writer.value_writer()
+    }
+}
+
+impl<'value, W: Write + Seek> StructWriter for SeekingStructWriter_1_0<'value, W> {
+    const IS_HUMAN_READABLE: bool = false;
+
+    fn close(self) -> IonResult<()> {
+        self.container.close()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::lazy::encoder::annotate::Annotatable;
+    use crate::lazy::encoder::write_as_ion::WriteAsSExp;
+    use crate::{ion_list, Element, IonData, Sequence, Timestamp};
+
+    fn writer_test(
+        expected: &str,
+        test: impl FnOnce(&mut LazyRawSeekingBinaryWriter_1_0<Cursor<Vec<u8>>>) -> IonResult<()>,
+    ) -> IonResult<()> {
+        let expected = Element::read_all(expected)?;
+        let mut writer = LazyRawSeekingBinaryWriter_1_0::new(Cursor::new(Vec::new()))?;
+        test(&mut writer)?;
+        let buffer = writer.close()?.into_inner();
+        let actual = Element::read_all(buffer)?;
+        assert!(
+            IonData::eq(&expected, &actual),
+            "Actual \n    {actual:?}\nwas not equal to\n    {expected:?}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_scalars() -> IonResult<()> {
+        let expected = r#"
+            1
+            false
+            3e0
+            "foo"
+            name
+            $4::2023-11-09T
+            {{4AEA6g==}}
+        "#;
+        writer_test(expected, |writer| {
+            writer
+                .write(1)?
+                .write(false)?
+                .write(3f32)?
+                .write("foo")?
+                .write(RawSymbolRef::SymbolId(4))?
+                .write(Timestamp::with_ymd(2023, 11, 9).build()?.annotated_with(4))?
+                .write([0xE0u8, 0x01, 0x00, 0xEA])?;
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn write_containers() -> IonResult<()> {
+        let expected = r#"
+            []
+            ()
+            {}
+            $4::[1, $5::(2 3), {$6: [], $7: $4::$5::{$4: "foo"}}]
+            {$4: 1, $5: [2, 3], $6: $7::(4 5)}
+            $4::$5::[[[[]]]]
+        "#;
+        writer_test(expected, |writer| {
+            let empty_sequence: &[i32] = &[];
+            writer
+                .write(empty_sequence)?
+                .write(empty_sequence.as_sexp())?
+                .struct_writer()?
+                .close()?;
+
+            let mut list = writer.value_writer().with_annotations(4)?.list_writer()?;
+            list.write(1)?.write([2, 3].as_sexp().annotated_with(5))?;
+            let mut strukt = list.struct_writer()?;
+            strukt.write(6, empty_sequence)?;
+            let mut nested = strukt
+                .field_writer(7)
+                .with_annotations([4, 5])?
+                .struct_writer()?;
+            nested.write(4, "foo")?;
+            nested.close()?;
+            strukt.close()?;
+            list.close()?;
+
+            let mut strukt = writer.struct_writer()?;
+            strukt
+                .write(4, 1)?
+                .write(5, [2, 3])?
+                .write(6, [4, 5].as_sexp().annotated_with(7))?;
+            strukt.close()?;
+
+            writer.write([[[empty_sequence]]].annotated_with([4, 5]))?;
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn write_lobs_from_readers() -> IonResult<()> {
+        let payload: Vec<u8> = (0..200u8).collect();
+        let mut writer = LazyRawSeekingBinaryWriter_1_0::new(Cursor::new(Vec::new()))?;
+        writer
+            .value_writer()
+            .write_blob_from_reader(3, b"abc".as_slice())?;
+        let mut list = writer.list_writer()?;
+        list.value_writer()
+            .with_annotations(4)?
+            .write_clob_from_reader(payload.len() as u64, payload.as_slice())?;
+        list.value_writer()
+            .with_annotations([4, 5])?
+            .write_blob_from_reader(2, b"de".as_slice())?;
+        list.close()?;
+        let output = writer.close()?.into_inner();
+
+        let expected = Sequence::new([
+            Element::blob(b"abc"),
+            // $4 and $5 are `name` and `version`
+            ion_list![
+                Element::clob(payload).with_annotations(["name"]),
+                Element::blob(b"de").with_annotations(["name", "version"]),
+            ]
+            .into(),
+        ]);
+        let actual = Element::read_all(output)?;
+        assert!(
+            IonData::eq(&expected, &actual),
+            "Actual \n    {actual:?}\nwas not equal to\n    {expected:?}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_large_container() -> IonResult<()> {
+        // Start writing partway through the output
+        let mut output = Cursor::new(vec![0u8; 10]);
+        output.set_position(10);
+        let mut writer = LazyRawSeekingBinaryWriter_1_0::new(output)?;
+        let mut list = writer.list_writer()?;
+        for i in 0..100_000 {
+            list.write(i)?;
+        }
+        list.close()?;
+        writer.write(true)?;
+        let output = writer.close()?.into_inner();
+
+        let values = Element::read_all(&output[10..])?;
+        let expected: Sequence = (0..100_000).map(Element::from).collect();
+        assert_eq!(values.get(0).unwrap().as_sequence(), Some(&expected));
+        assert_eq!(values.get(1), Some(&Element::from(true)));
+        Ok(())
+    }
+
+    #[test]
+    fn text_symbols_are_rejected() -> IonResult<()> {
+        let mut writer = LazyRawSeekingBinaryWriter_1_0::new(Cursor::new(Vec::new()))?;
+        assert!(writer
+            .value_writer()
+            .with_annotations("foo")?
+            .list_writer()
+            .is_err());
+        let mut strukt = writer.struct_writer()?;
+        assert!(strukt.write("foo", 1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn unclosed_container_poisons_writer() -> IonResult<()> {
+        let mut writer = LazyRawSeekingBinaryWriter_1_0::new(Cursor::new(Vec::new()))?;
+        let mut outer = writer.list_writer()?;
+        let mut inner = outer.struct_writer()?;
+        inner.write(4, 1)?;
+        // The struct is dropped without being closed, leaving its length unset.
+        drop(inner);
+        assert!(outer.write(2).is_err());
+        assert!(outer.close().is_err());
+        assert!(writer.write(3).is_err());
+        assert!(writer.close().is_err());
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use std::io::Read;
 use std::mem;
 
 use bumpalo::collections::Vec as BumpVec;
@@ -12,6 +13,7 @@ use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
 use crate::lazy::encoder::binary::v1_0::container_writers::{
     BinaryListWriter_1_0, BinarySExpWriter_1_0, BinaryStructWriter_1_0,
 };
+use crate::lazy::encoder::lob_writer;
 use crate::lazy::encoder::private::Sealed;
 use crate::lazy::encoder::value_writer::ValueWriter;
 use crate::lazy::encoder::value_writer::{delegate_value_writer_to_self, AnnotatableWriter};
@@ -34,16 +36,21 @@ const DEFAULT_CONTAINER_BUFFER_SIZE: usize = 512;
 pub struct BinaryValueWriter_1_0<'value, 'top> {
     allocator: &'top BumpAllocator,
     encoding_buffer: &'value mut BumpVec<'top, u8>,
+    // Whether `f64` values that can be losslessly represented as an `f32` are written using the
+    // 4-byte float encoding.
+    compact_floats: bool,
 }
 
 impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
     pub fn new(
         allocator: &'top BumpAllocator,
         encoding_buffer: &'value mut BumpVec<'top, u8>,
+        compact_floats: bool,
     ) -> BinaryValueWriter_1_0<'value, 'top> {
         BinaryValueWriter_1_0 {
             allocator,
             encoding_buffer,
+            compact_floats,
         }
     }
 
@@ -182,7 +189,7 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
 
         // See if this value can be losslessly encoded in 4 bytes instead of 8
         let float32 = value as f32;
-        if float32 as f64 == value {
+        if self.compact_floats && float32 as f64 == value {
             // No data lost during cast; write it as an f32 instead.
             return self.write_f32(float32);
         }
@@ -240,10 +247,19 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
         self.write_lob(bytes, 0xA0)
     }
 
+    pub fn write_clob_from_reader(self, length: u64, source: impl Read) -> IonResult<()> {
+        lob_writer::write_binary_1_0_lob(self.encoding_buffer, IonType::Clob, length, source)
+    }
+
+    pub fn write_blob_from_reader(self, length: u64, source: impl Read) -> IonResult<()> {
+        lob_writer::write_binary_1_0_lob(self.encoding_buffer, IonType::Blob, length, source)
+    }
+
     fn list_writer(self) -> IonResult<BinaryListWriter_1_0<'value, 'top>> {
         Ok(BinaryListWriter_1_0::new(
             self.allocator,
             self.encoding_buffer,
+            self.compact_floats,
         ))
     }
 
@@ -251,6 +267,7 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
         Ok(BinarySExpWriter_1_0::new(
             self.allocator,
             self.encoding_buffer,
+            self.compact_floats,
         ))
     }
 
@@ -258,6 +275,7 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
         Ok(BinaryStructWriter_1_0::new(
             self.allocator,
             self.encoding_buffer,
+            self.compact_floats,
         ))
     }
 }
@@ -278,6 +296,7 @@ impl<'value, 'top> AnnotatableWriter for BinaryValueWriter_1_0<'value, 'top> {
             self.allocator,
             annotations.into_annotations_vec(),
             self.encoding_buffer,
+            self.compact_floats,
         ))
     }
 }
@@ -297,6 +316,7 @@ pub struct BinaryAnnotatedValueWriter_1_0<'value, 'top> {
     annotations: AnnotationsVec<'value>,
     allocator: &'top BumpAllocator,
     output_buffer: &'value mut BumpVec<'top, u8>,
+    compact_floats: bool,
 }
 
 impl<'value, 'top> BinaryAnnotatedValueWriter_1_0<'value, 'top> {
@@ -304,11 +324,13 @@ impl<'value, 'top> BinaryAnnotatedValueWriter_1_0<'value, 'top> {
         allocator: &'top BumpAllocator,
         annotations: AnnotationsVec<'value>,
         encoding_buffer: &'value mut BumpVec<'top, u8>,
+        compact_floats: bool,
     ) -> BinaryAnnotatedValueWriter_1_0<'value, 'top> {
         BinaryAnnotatedValueWriter_1_0 {
             annotations,
             allocator,
             output_buffer: encoding_buffer,
+            compact_floats,
         }
     }
 }
@@ -328,6 +350,7 @@ macro_rules! annotate_and_delegate_1_0 {
                 $crate::lazy::encoder::binary::v1_0::value_writer::BinaryValueWriter_1_0::new(
                     self.allocator,
                     &mut buffer,
+                    self.compact_floats,
                 );
             value_writer.$method(value)?;
             self.annotate_encoded_value(buffer.as_slice())
@@ -372,6 +395,20 @@ impl<'value, 'top> BinaryAnnotatedValueWriter_1_0<'value, 'top> {
         Ok(())
     }
 
+    /// Encodes a lob read from `source` and then wraps it in this writer's annotations. (The
+    /// annotations wrapper's length prefix includes the lob's encoded length, so the lob is
+    /// encoded first.)
+    fn annotate_lob_from_reader(
+        mut self,
+        ion_type: IonType,
+        length: u64,
+        source: impl Read,
+    ) -> IonResult<()> {
+        let mut buffer = BumpVec::new_in(self.allocator);
+        lob_writer::write_binary_1_0_lob(&mut buffer, ion_type, length, source)?;
+        self.annotate_encoded_value(buffer.as_slice())
+    }
+
     fn encode_annotations_sequence(&self, buffer: &'_ mut BumpVec<'_, u8>) -> IonResult<()> {
         for annotation in &self.annotations {
             let RawSymbolRef::SymbolId(sid) = annotation.as_raw_symbol_token_ref() else {
@@ -403,6 +440,7 @@ impl<'value, 'top> AnnotatableWriter for BinaryAnnotatedValueWriter_1_0<'value,
             annotations: annotations.into_annotations_vec(),
             allocator: self.allocator,
             output_buffer: self.output_buffer,
+            compact_floats: self.compact_floats,
         })
     }
 }
@@ -430,16 +468,22 @@ impl<'value, 'top> ValueWriter for BinaryAnnotatedValueWriter_1_0<'value, 'top>
         impl AsRef<[u8]> => write_clob,
         impl AsRef<[u8]> => write_blob,
     );
+    fn write_blob_from_reader(self, length: u64, source: impl Read) -> IonResult<()> {
+        self.annotate_lob_from_reader(IonType::Blob, length, source)
+    }
+    fn write_clob_from_reader(self, length: u64, source: impl Read) -> IonResult<()> {
+        self.annotate_lob_from_reader(IonType::Clob, length, source)
+    }
     fn list_writer(self) -> IonResult<Self::ListWriter> {
-        BinaryListWriter_1_0::new(self.allocator, self.output_buffer)
+        BinaryListWriter_1_0::new(self.allocator, self.output_buffer, self.compact_floats)
             .with_annotations(self.annotations)
     }
     fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
-        BinarySExpWriter_1_0::new(self.allocator, self.output_buffer)
+        BinarySExpWriter_1_0::new(self.allocator, self.output_buffer, self.compact_floats)
             .with_annotations(self.annotations)
     }
     fn struct_writer(self) -> IonResult<Self::StructWriter> {
-        BinaryStructWriter_1_0::new(self.allocator, self.output_buffer)
+        BinaryStructWriter_1_0::new(self.allocator, self.output_buffer, self.compact_floats)
             .with_annotations(self.annotations)
     }
     fn eexp_writer<'a>(self, _macro_id: impl Into<MacroIdRef<'a>>) -> IonResult<Self::EExpWriter> {
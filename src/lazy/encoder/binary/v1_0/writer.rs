@@ -4,6 +4,7 @@ use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump as BumpAllocator;
 use delegate::delegate;
 
+use crate::binary::var_uint::VarUInt;
 use crate::lazy::encoder::binary::v1_0::value_writer::BinaryValueWriter_1_0;
 use crate::lazy::encoder::private::Sealed;
 use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
@@ -11,6 +12,7 @@ use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::LazyRawWriter;
 use crate::lazy::encoding::Encoding;
+use crate::result::IonFailure;
 use crate::unsafe_helpers::{mut_ref_to_ptr, ptr_to_mut_ref};
 use crate::write_config::{WriteConfig, WriteConfigKind};
 use crate::IonResult;
@@ -34,6 +36,9 @@ pub struct LazyRawBinaryWriter_1_0<W: Write> {
     // is set to a meaningful address. This allows us to refer to the contents of the buffer across
     // multiple mutable calls of `write` and `value_writer()`.
     encoding_buffer_ptr: Option<*mut ()>,
+    // Whether `f64` values that can be losslessly represented as an `f32` are written using the
+    // 4-byte float encoding.
+    compact_floats: bool,
 }
 
 /// The initial size of the backing array for the writer's bump allocator.
@@ -50,6 +55,7 @@ impl<W: Write> LazyRawBinaryWriter_1_0<W> {
             output,
             allocator: BumpAllocator::with_capacity(DEFAULT_BUMP_SIZE),
             encoding_buffer_ptr: None,
+            compact_floats: true,
         })
     }
 
@@ -69,6 +75,7 @@ impl<W: Write> LazyRawBinaryWriter_1_0<W> {
             output,
             allocator,
             encoding_buffer_ptr,
+            ..
         } = self;
 
         let encoding_buffer = match encoding_buffer_ptr {
@@ -88,26 +95,99 @@ impl<W: Write> LazyRawBinaryWriter_1_0<W> {
         Ok(())
     }
 
-    pub(crate) fn value_writer(&mut self) -> BinaryValueWriter_1_0<'_, '_> {
-        let top_level = match self.encoding_buffer_ptr {
+    /// Writes NOP padding that occupies exactly `size` bytes. Readers skip over NOP padding, so it
+    /// can be used to align the values that follow or to reserve space that will later be
+    /// overwritten in place.
+    pub fn write_nop_pad(&mut self, size: usize) -> IonResult<&mut Self> {
+        encode_nop_pad(self.top_level_buffer(), size);
+        Ok(self)
+    }
+
+    /// Writes `value` followed by as much NOP padding as is needed for the two to occupy exactly
+    /// `width` bytes. If the encoded value is larger than `width`, nothing is written and an
+    /// error is returned.
+    pub fn write_padded<V: WriteAsIon>(&mut self, value: V, width: usize) -> IonResult<&mut Self> {
+        self.pad_to_width(width, |writer| value.write_as_ion(writer.value_writer()))
+    }
+
+    /// Calls `write` to encode a top-level value and then pads its encoding to `width` bytes.
+    pub(crate) fn pad_to_width(
+        &mut self,
+        width: usize,
+        write: impl FnOnce(&mut Self) -> IonResult<()>,
+    ) -> IonResult<&mut Self> {
+        let start = self.top_level_buffer().len();
+        write(self)?;
+        let encoded_size = self.top_level_buffer().len() - start;
+        if encoded_size > width {
+            self.top_level_buffer().truncate(start);
+            return IonResult::encoding_error(format!(
+                "the encoded value ({encoded_size} bytes) does not fit in {width} bytes"
+            ));
+        }
+        self.write_nop_pad(width - encoded_size)
+    }
+
+    /// Returns a pointer to the top-level encoding buffer, allocating the buffer if necessary.
+    fn top_level_buffer_ptr(&mut self) -> *mut () {
+        match self.encoding_buffer_ptr {
             // If the `encoding_buffer_ptr` is set, we already allocated an encoding buffer on
-            // a previous call to `value_writer()`. Dereference the pointer and continue encoding
-            // to that buffer.
-            Some(ptr) => unsafe { ptr_to_mut_ref::<'_, BumpVec<'_, u8>>(ptr) },
+            // a previous call. Continue encoding to that buffer.
+            Some(ptr) => ptr,
             // Otherwise, allocate a new encoding buffer and set the pointer to refer to it.
             None => {
                 let buffer = self
                     .allocator
-                    .alloc_with(|| BumpVec::new_in(&self.allocator));
-                self.encoding_buffer_ptr = Some(mut_ref_to_ptr(buffer));
-                buffer
+                    .alloc_with(|| BumpVec::<u8>::new_in(&self.allocator));
+                let ptr = mut_ref_to_ptr(buffer);
+                self.encoding_buffer_ptr = Some(ptr);
+                ptr
             }
-        };
-        let annotated_value_writer = BinaryValueWriter_1_0::new(&self.allocator, top_level);
+        }
+    }
+
+    fn top_level_buffer(&mut self) -> &mut BumpVec<'_, u8> {
+        unsafe { ptr_to_mut_ref::<'_, BumpVec<'_, u8>>(self.top_level_buffer_ptr()) }
+    }
+
+    pub(crate) fn value_writer(&mut self) -> BinaryValueWriter_1_0<'_, '_> {
+        let top_level =
+            unsafe { ptr_to_mut_ref::<'_, BumpVec<'_, u8>>(self.top_level_buffer_ptr()) };
+        let annotated_value_writer =
+            BinaryValueWriter_1_0::new(&self.allocator, top_level, self.compact_floats);
         annotated_value_writer
     }
 }
 
+/// Encodes NOP padding that occupies exactly `size` bytes.
+///
+/// See: <https://amazon-ion.github.io/ion-docs/docs/binary.html#nop-pad>
+fn encode_nop_pad(buffer: &mut BumpVec<'_, u8>, mut size: usize) {
+    while size > 0 {
+        // A pad of up to 14 bytes stores its length in the type descriptor.
+        if size <= 14 {
+            buffer.push((size - 1) as u8);
+            buffer.resize(buffer.len() + size - 1, 0);
+            return;
+        }
+        // Longer pads have a VarUInt length. Look for a length whose encoding brings the pad to
+        // the requested size.
+        for length_size in 1..size - 1 {
+            let length = size - 1 - length_size;
+            if VarUInt::encoded_size_of(length as u64) == length_size {
+                buffer.push(0x0E);
+                let _ = VarUInt::write_u64(buffer, length as u64);
+                buffer.resize(buffer.len() + length, 0);
+                return;
+            }
+        }
+        // Some sizes fall between the largest pad with an N-byte length and the smallest pad
+        // with an (N+1)-byte length. Write a one-byte pad and try again.
+        buffer.push(0x00);
+        size -= 1;
+    }
+}
+
 impl<W: Write> Sealed for LazyRawBinaryWriter_1_0<W> {}
 
 impl<W: Write> LazyRawWriter<W> for LazyRawBinaryWriter_1_0<W> {
@@ -121,7 +201,11 @@ impl<W: Write> LazyRawWriter<W> for LazyRawBinaryWriter_1_0<W> {
             WriteConfigKind::Text(_) => {
                 unreachable!("Text writer can not be created from binary encoding")
             }
-            WriteConfigKind::Binary(_) => LazyRawBinaryWriter_1_0::new(output),
+            WriteConfigKind::Binary(binary_config) => {
+                let mut writer = LazyRawBinaryWriter_1_0::new(output)?;
+                writer.compact_floats = binary_config.compact_floats;
+                Ok(writer)
+            }
         }
     }
 
@@ -1,3 +1,5 @@
+use std::io::Read;
+
 use arrayvec::ArrayVec;
 use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump as BumpAllocator;
@@ -11,6 +13,7 @@ use crate::lazy::encoder::binary::v1_1::fixed_int::FixedInt;
 use crate::lazy::encoder::binary::v1_1::fixed_uint::FixedUInt;
 use crate::lazy::encoder::binary::v1_1::flex_sym::FlexSym;
 use crate::lazy::encoder::binary::v1_1::{flex_int::FlexInt, flex_uint::FlexUInt};
+use crate::lazy::encoder::lob_writer;
 use crate::lazy::encoder::private::Sealed;
 use crate::lazy::encoder::value_writer::ValueWriter;
 use crate::lazy::encoder::value_writer::{delegate_value_writer_to_self, AnnotatableWriter};
@@ -576,6 +579,14 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
         self.write_lob(0xFE, value)
     }
 
+    pub fn write_clob_from_reader(self, length: u64, source: impl Read) -> IonResult<()> {
+        lob_writer::write_binary_1_1_lob(self.encoding_buffer, IonType::Clob, length, source)
+    }
+
+    pub fn write_blob_from_reader(self, length: u64, source: impl Read) -> IonResult<()> {
+        lob_writer::write_binary_1_1_lob(self.encoding_buffer, IonType::Blob, length, source)
+    }
+
     fn write_lob<A: AsRef<[u8]>>(mut self, opcode: u8, value: A) -> IonResult<()> {
         let bytes = value.as_ref();
         self.push_byte(opcode);
@@ -616,16 +627,21 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
         macro_id: impl Into<MacroIdRef<'a>>,
     ) -> IonResult<<Self as ValueWriter>::EExpWriter> {
         match macro_id.into() {
-            MacroIdRef::LocalName(_name) => {
-                // This would be handled by the system writer
-                todo!("macro invocation by name");
+            MacroIdRef::LocalName(name) => {
+                // Names are resolved to addresses by the application-level writer, which has
+                // access to the macro table.
+                return IonResult::encoding_error(format!(
+                    "the raw binary writer cannot invoke macro '{name}' by name; use its address"
+                ));
             }
             MacroIdRef::LocalAddress(address) if address < 64 => {
                 // Invoke this ID with a one-byte opcode
                 self.encoding_buffer.push(address as u8);
             }
-            MacroIdRef::LocalAddress(_address) => {
-                todo!("macros with addresses higher than 64");
+            MacroIdRef::LocalAddress(address) => {
+                return IonResult::encoding_error(format!(
+                    "macro addresses of 64 or higher are not yet supported (found {address})"
+                ));
             }
         }
         Ok(BinaryEExpWriter_1_1::new(
@@ -786,6 +802,16 @@ impl<'value, 'top> ValueWriter for BinaryAnnotatedValueWriter_1_1<'value, 'top>
         impl AsRef<[u8]> => write_blob,
     );
 
+    fn write_blob_from_reader(mut self, length: u64, source: impl Read) -> IonResult<()> {
+        self.encode_annotations();
+        self.value_writer().write_blob_from_reader(length, source)
+    }
+
+    fn write_clob_from_reader(mut self, length: u64, source: impl Read) -> IonResult<()> {
+        self.encode_annotations();
+        self.value_writer().write_clob_from_reader(length, source)
+    }
+
     fn list_writer(mut self) -> IonResult<Self::ListWriter> {
         self.encode_annotations();
         self.value_writer().list_writer()
@@ -901,6 +927,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_after_flush() -> IonResult<()> {
+        // Flushing empties the top-level encoding buffer; values written afterward must not be
+        // appended to the bytes that were already flushed.
+        encoding_test(
+            |writer: &mut LazyRawBinaryWriter_1_1<&mut Vec<u8>>| {
+                writer.write(1)?;
+                writer.flush()?;
+                writer.write(2)?;
+                Ok(())
+            },
+            &[0x61, 0x01, 0x61, 0x02],
+        )
+    }
+
     #[test]
     fn write_bools() -> IonResult<()> {
         let test_cases: &[(bool, &[u8])] = &[(true, &[0x6E]), (false, &[0x6F])];
@@ -4,6 +4,8 @@ use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump as BumpAllocator;
 use delegate::delegate;
 
+use crate::binary::var_uint::VarUInt;
+use crate::lazy::encoder::binary::v1_1::flex_uint::FlexUInt;
 use crate::lazy::encoder::binary::v1_1::value_writer::BinaryValueWriter_1_1;
 use crate::lazy::encoder::private::Sealed;
 use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
@@ -11,6 +13,7 @@ use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::LazyRawWriter;
 use crate::lazy::encoding::Encoding;
+use crate::result::IonFailure;
 use crate::unsafe_helpers::{mut_ref_to_ptr, ptr_to_mut_ref};
 use crate::write_config::{WriteConfig, WriteConfigKind};
 use crate::IonResult;
@@ -81,31 +84,76 @@ impl<W: Write> LazyRawBinaryWriter_1_1<W> {
         output.write_all(encoding_buffer)?;
         // Flush the output sink, which may have its own buffers.
         output.flush()?;
+        // Now that we've written the encoding buffer's contents to output, clear it.
+        self.encoding_buffer_ptr = None;
         // Clear the allocator. A new encoding buffer will be allocated on the next write.
         allocator.reset();
         Ok(())
     }
 
-    // All methods called on the writer are inherently happening at the top level. At the top level,
-    // the lifetimes `'value` and `'top` are identical. In this method signature, '_ is used for both.
-    pub(crate) fn value_writer(&mut self) -> BinaryValueWriter_1_1<'_, '_> {
-        let top_level = match self.encoding_buffer_ptr {
+    /// Writes NOP padding that occupies exactly `size` bytes. Readers skip over NOP padding, so it
+    /// can be used to align the values that follow or to reserve space that will later be
+    /// overwritten in place.
+    pub fn write_nop_pad(&mut self, size: usize) -> IonResult<&mut Self> {
+        encode_nop_pad(self.top_level_buffer(), size);
+        Ok(self)
+    }
+
+    /// Writes `value` followed by as much NOP padding as is needed for the two to occupy exactly
+    /// `width` bytes. If the encoded value is larger than `width`, nothing is written and an
+    /// error is returned.
+    pub fn write_padded<V: WriteAsIon>(&mut self, value: V, width: usize) -> IonResult<&mut Self> {
+        self.pad_to_width(width, |writer| value.write_as_ion(writer.value_writer()))
+    }
+
+    /// Calls `write` to encode a top-level value and then pads its encoding to `width` bytes.
+    pub(crate) fn pad_to_width(
+        &mut self,
+        width: usize,
+        write: impl FnOnce(&mut Self) -> IonResult<()>,
+    ) -> IonResult<&mut Self> {
+        let start = self.top_level_buffer().len();
+        write(self)?;
+        let encoded_size = self.top_level_buffer().len() - start;
+        if encoded_size > width {
+            self.top_level_buffer().truncate(start);
+            return IonResult::encoding_error(format!(
+                "the encoded value ({encoded_size} bytes) does not fit in {width} bytes"
+            ));
+        }
+        self.write_nop_pad(width - encoded_size)
+    }
+
+    /// Returns a pointer to the top-level encoding buffer, allocating the buffer if necessary.
+    fn top_level_buffer_ptr(&mut self) -> *mut () {
+        match self.encoding_buffer_ptr {
             // If the `encoding_buffer_ptr` is set, we already allocated an encoding buffer on
-            // a previous call to `value_writer()`. Dereference the pointer and continue encoding
-            // to that buffer.
-            Some(ptr) => unsafe { ptr_to_mut_ref::<'_, BumpVec<'_, u8>>(ptr) },
+            // a previous call. Continue encoding to that buffer.
+            Some(ptr) => ptr,
             // Otherwise, allocate a new encoding buffer and set the pointer to refer to it.
             None => {
                 let buffer = self.allocator.alloc_with(|| {
                     // Use half of the bump allocator's backing array as an encoding space for this
                     // top level value. The other half of the bump can be used for incidental
                     // bookkeeping.
-                    BumpVec::with_capacity_in(DEFAULT_BUMP_SIZE / 2, &self.allocator)
+                    BumpVec::<u8>::with_capacity_in(DEFAULT_BUMP_SIZE / 2, &self.allocator)
                 });
-                self.encoding_buffer_ptr = Some(mut_ref_to_ptr(buffer));
-                buffer
+                let ptr = mut_ref_to_ptr(buffer);
+                self.encoding_buffer_ptr = Some(ptr);
+                ptr
             }
-        };
+        }
+    }
+
+    fn top_level_buffer(&mut self) -> &mut BumpVec<'_, u8> {
+        unsafe { ptr_to_mut_ref::<'_, BumpVec<'_, u8>>(self.top_level_buffer_ptr()) }
+    }
+
+    // All methods called on the writer are inherently happening at the top level. At the top level,
+    // the lifetimes `'value` and `'top` are identical. In this method signature, '_ is used for both.
+    pub(crate) fn value_writer(&mut self) -> BinaryValueWriter_1_1<'_, '_> {
+        let top_level =
+            unsafe { ptr_to_mut_ref::<'_, BumpVec<'_, u8>>(self.top_level_buffer_ptr()) };
         BinaryValueWriter_1_1::new(
             &self.allocator,
             top_level,
@@ -115,6 +163,32 @@ impl<W: Write> LazyRawBinaryWriter_1_1<W> {
     }
 }
 
+/// Encodes NOP padding that occupies exactly `size` bytes.
+fn encode_nop_pad(buffer: &mut BumpVec<'_, u8>, mut size: usize) {
+    while size > 0 {
+        // A one-byte pad is just the opcode.
+        if size == 1 {
+            buffer.push(0xEC);
+            return;
+        }
+        // Longer pads have a FlexUInt length. Look for a length whose encoding brings the pad to
+        // the requested size. Like a VarUInt, a FlexUInt encodes 7 bits of magnitude per byte.
+        for length_size in 1..size {
+            let length = size - 1 - length_size;
+            if VarUInt::encoded_size_of(length as u64) == length_size {
+                buffer.push(0xED);
+                let _ = FlexUInt::write(buffer, length as u64);
+                buffer.resize(buffer.len() + length, 0);
+                return;
+            }
+        }
+        // Some sizes fall between the largest pad with an N-byte length and the smallest pad
+        // with an (N+1)-byte length. Write a one-byte pad and try again.
+        buffer.push(0xEC);
+        size -= 1;
+    }
+}
+
 impl<W: Write> Sealed for LazyRawBinaryWriter_1_1<W> {}
 
 impl<W: Write> LazyRawWriter<W> for LazyRawBinaryWriter_1_1<W> {
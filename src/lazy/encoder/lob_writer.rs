@@ -0,0 +1,353 @@
+//! Writes blobs and clobs whose payloads are streamed from an [`io::Read`] implementation, and
+//! provides [`Base64Encoder`], which encodes a blob's payload as base64 text a piece at a time.
+
+use std::io;
+use std::io::{Read, Write};
+
+use crate::binary::var_uint::VarUInt;
+use crate::lazy::encoder::binary::v1_0::value_writer::MAX_INLINE_LENGTH;
+use crate::lazy::encoder::binary::v1_1::flex_uint::FlexUInt;
+use crate::result::IonFailure;
+use crate::text::text_formatter::STRING_ESCAPE_CODES;
+use crate::text::whitespace_config::WhitespaceConfig;
+use crate::{IonResult, IonType};
+
+// The number of payload bytes read from the source at a time. This is a multiple of 3 so that
+// each full chunk encodes to base64 without padding.
+const CHUNK_SIZE: usize = 8 * 1024 - 2;
+
+// The number of input bytes encoded at a time by `Base64Encoder`. This is a multiple of 3.
+const BASE64_BATCH_SIZE: usize = 768;
+
+/// How a top-level lob is written in a particular encoding.
+pub(crate) enum LobFraming {
+    Binary_1_0,
+    Binary_1_1,
+    Text {
+        whitespace_config: &'static WhitespaceConfig,
+        blob_line_width: Option<usize>,
+    },
+}
+
+/// Writes a top-level blob or clob to `output`, copying its `length`-byte payload from `source`.
+/// At most `length` bytes are read from `source`; if it ends before providing that many, an
+/// error is returned and `output` is left holding an incomplete value.
+pub(crate) fn write_lob_from_reader<W: Write>(
+    output: &mut W,
+    framing: LobFraming,
+    ion_type: IonType,
+    length: u64,
+    source: impl Read,
+) -> IonResult<()> {
+    match framing {
+        LobFraming::Binary_1_0 => write_binary_1_0_lob(output, ion_type, length, source),
+        LobFraming::Binary_1_1 => write_binary_1_1_lob(output, ion_type, length, source),
+        LobFraming::Text {
+            whitespace_config,
+            blob_line_width,
+        } => {
+            if ion_type == IonType::Clob {
+                write_text_clob(output, length, source)?;
+            } else {
+                let indentation = whitespace_config.indentation;
+                write_text_blob(output, length, source, blob_line_width, indentation, 0)?;
+            }
+            write!(
+                output,
+                "{}",
+                whitespace_config.space_between_top_level_values
+            )?;
+            Ok(())
+        }
+    }
+}
+
+/// Writes a blob or clob in binary Ion 1.0, copying its `length`-byte payload from `source`.
+pub(crate) fn write_binary_1_0_lob<W: Write>(
+    output: &mut W,
+    ion_type: IonType,
+    length: u64,
+    source: impl Read,
+) -> IonResult<()> {
+    write_binary_1_0_lob_header(output, ion_type, length)?;
+    copy_payload(length, source, |chunk| Ok(output.write_all(chunk)?))
+}
+
+/// Writes the type descriptor and length of a binary Ion 1.0 blob or clob, which the lob's
+/// `length`-byte payload will follow.
+pub(crate) fn write_binary_1_0_lob_header<W: Write>(
+    output: &mut W,
+    ion_type: IonType,
+    length: u64,
+) -> IonResult<()> {
+    let type_code: u8 = match ion_type {
+        IonType::Clob => 0x90,
+        _ => 0xA0,
+    };
+    if length <= MAX_INLINE_LENGTH as u64 {
+        output.write_all(&[type_code | length as u8])?;
+    } else {
+        output.write_all(&[type_code | 0x0E])?;
+        VarUInt::write_u64(output, length)?;
+    }
+    Ok(())
+}
+
+/// Writes a blob or clob in binary Ion 1.1, copying its `length`-byte payload from `source`.
+pub(crate) fn write_binary_1_1_lob<W: Write>(
+    output: &mut W,
+    ion_type: IonType,
+    length: u64,
+    source: impl Read,
+) -> IonResult<()> {
+    let opcode: u8 = match ion_type {
+        IonType::Clob => 0xFF,
+        _ => 0xFE,
+    };
+    output.write_all(&[opcode])?;
+    FlexUInt::write(output, length)?;
+    copy_payload(length, source, |chunk| Ok(output.write_all(chunk)?))
+}
+
+/// Writes a text clob, escaping its `length`-byte payload as it is copied from `source`.
+pub(crate) fn write_text_clob<W: Write>(
+    output: &mut W,
+    length: u64,
+    source: impl Read,
+) -> IonResult<()> {
+    output.write_all(b"{{\"")?;
+    copy_payload(length, source, |chunk| {
+        write_escaped_clob_bytes(output, chunk)
+    })?;
+    output.write_all(b"\"}}")?;
+    Ok(())
+}
+
+/// Writes a text blob, base64-encoding its `length`-byte payload as it is copied from `source`.
+///
+/// If the base64 text is longer than `blob_line_width`, it is split into lines of that width,
+/// each indented one level deeper than the blob (which is nested `depth` levels deep); the
+/// closing `}}` is aligned with the blob.
+pub(crate) fn write_text_blob<W: Write>(
+    output: &mut W,
+    length: u64,
+    source: impl Read,
+    blob_line_width: Option<usize>,
+    indentation: &str,
+    depth: usize,
+) -> IonResult<()> {
+    let encoded_length = (length + 2) / 3 * 4;
+    output.write_all(b"{{")?;
+    let mut encoder = Base64Encoder::new(&mut *output);
+    let wrapped = match blob_line_width {
+        Some(width) if encoded_length > width as u64 => {
+            let line_start = format!("\n{}", indentation.repeat(depth + 1));
+            encoder = encoder.with_line_width(width, line_start);
+            true
+        }
+        _ => false,
+    };
+    copy_payload(length, source, |chunk| encoder.append(chunk))?;
+    encoder.finish()?;
+    if wrapped {
+        write!(output, "\n{}", indentation.repeat(depth))?;
+    }
+    output.write_all(b"}}")?;
+    Ok(())
+}
+
+/// Reads exactly `length` bytes from `source` into a `Vec<u8>`.
+pub(crate) fn read_payload(length: u64, source: impl Read) -> IonResult<Vec<u8>> {
+    let mut payload = Vec::new();
+    copy_payload(length, source, |chunk| {
+        payload.extend_from_slice(chunk);
+        Ok(())
+    })?;
+    Ok(payload)
+}
+
+/// Reads exactly `length` bytes from `source`, passing them to `write_chunk` a piece at a time.
+pub(crate) fn copy_payload(
+    length: u64,
+    source: impl Read,
+    mut write_chunk: impl FnMut(&[u8]) -> IonResult<()>,
+) -> IonResult<()> {
+    let mut source = source.take(length);
+    let mut buffer = vec![0u8; length.min(CHUNK_SIZE as u64) as usize];
+    let mut remaining = length;
+    while remaining > 0 {
+        let num_read = match source.read(&mut buffer) {
+            Ok(0) => {
+                return IonResult::encoding_error(format!(
+                    "the lob's source ended after {} of its {length} bytes",
+                    length - remaining
+                ))
+            }
+            Ok(num_read) => num_read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error.into()),
+        };
+        write_chunk(&buffer[..num_read])?;
+        remaining -= num_read as u64;
+    }
+    Ok(())
+}
+
+fn write_escaped_clob_bytes(output: &mut impl Write, bytes: &[u8]) -> IonResult<()> {
+    let mut unescaped_start = 0;
+    for (index, byte) in bytes.iter().enumerate() {
+        let escape_code = STRING_ESCAPE_CODES[*byte as usize];
+        if !escape_code.is_empty() {
+            output.write_all(&bytes[unescaped_start..index])?;
+            output.write_all(escape_code.as_bytes())?;
+            unescaped_start = index + 1;
+        }
+    }
+    output.write_all(&bytes[unescaped_start..])?;
+    Ok(())
+}
+
+/// Encodes bytes as base64 text, writing the text to an [`io::Write`] implementation as the
+/// bytes are appended.
+///
+/// Appended bytes that do not complete a 3-byte group are held until more bytes are appended or
+/// the encoder is [finished](Self::finish), so a payload may be appended in chunks of any size
+/// and the resulting text is the same as if the payload had been encoded all at once.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::Base64Encoder;
+///
+/// let mut encoder = Base64Encoder::new(Vec::new());
+/// encoder.append(b"hello")?;
+/// encoder.append(b", world!")?;
+/// let text = encoder.finish()?;
+/// assert_eq!(text, b"aGVsbG8sIHdvcmxkIQ==");
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub struct Base64Encoder<W: Write> {
+    output: W,
+    // Appended bytes that do not yet form a complete 3-byte group
+    pending: [u8; 3],
+    num_pending: usize,
+    // If set, the text is split into lines of at most `width` characters, each of which begins
+    // with `line_start`.
+    line_width: Option<(usize, String)>,
+    column: usize,
+}
+
+impl<W: Write> Base64Encoder<W> {
+    /// Constructs an encoder that writes base64 text to `output`.
+    pub fn new(output: W) -> Self {
+        Self {
+            output,
+            pending: [0u8; 3],
+            num_pending: 0,
+            line_width: None,
+            column: 0,
+        }
+    }
+
+    /// Splits the text into lines of at most `width` (which must be greater than zero)
+    /// characters, writing `line_start` ahead of each of them.
+    pub(crate) fn with_line_width(mut self, width: usize, line_start: String) -> Self {
+        self.line_width = Some((width, line_start));
+        // Start a new line before the first character.
+        self.column = width;
+        self
+    }
+
+    /// Encodes `bytes`, writing as much of the resulting text as possible to the output.
+    pub fn append(&mut self, mut bytes: &[u8]) -> IonResult<()> {
+        if self.num_pending > 0 {
+            let length = (3 - self.num_pending).min(bytes.len());
+            self.pending[self.num_pending..][..length].copy_from_slice(&bytes[..length]);
+            self.num_pending += length;
+            bytes = &bytes[length..];
+            if self.num_pending < 3 {
+                return Ok(());
+            }
+            let group = self.pending;
+            self.num_pending = 0;
+            self.encode(&group)?;
+        }
+        let complete_length = bytes.len() / 3 * 3;
+        for batch in bytes[..complete_length].chunks(BASE64_BATCH_SIZE) {
+            self.encode(batch)?;
+        }
+        let remainder = &bytes[complete_length..];
+        self.pending[..remainder.len()].copy_from_slice(remainder);
+        self.num_pending = remainder.len();
+        Ok(())
+    }
+
+    /// Encodes any bytes that are being held (adding padding as needed) and returns the output.
+    pub fn finish(mut self) -> IonResult<W> {
+        if self.num_pending > 0 {
+            let group = self.pending;
+            self.encode(&group[..self.num_pending])?;
+        }
+        Ok(self.output)
+    }
+
+    // Encodes up to `BASE64_BATCH_SIZE` bytes and writes the text to the output.
+    fn encode(&mut self, bytes: &[u8]) -> IonResult<()> {
+        let mut text = [0u8; BASE64_BATCH_SIZE / 3 * 4];
+        let length = base64::encode_config_slice(bytes, base64::STANDARD, &mut text);
+        let mut text = &text[..length];
+        let Some((width, line_start)) = &self.line_width else {
+            self.output.write_all(text)?;
+            return Ok(());
+        };
+        while !text.is_empty() {
+            if self.column == *width {
+                self.output.write_all(line_start.as_bytes())?;
+                self.column = 0;
+            }
+            let length = (width - self.column).min(text.len());
+            self.output.write_all(&text[..length])?;
+            self.column += length;
+            text = &text[length..];
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunked_base64_matches_one_shot_encoding() -> IonResult<()> {
+        let payload: Vec<u8> = (0..2000u32).map(|i| (i % 253) as u8).collect();
+        for length in [0, 1, 2, 3, 4, 5, 767, 768, 769, 2000] {
+            let payload = &payload[..length];
+            let expected = base64::encode(payload);
+            for chunk_size in [1, 2, 3, 4, 7, 1000] {
+                let mut encoder = Base64Encoder::new(Vec::new());
+                for chunk in payload.chunks(chunk_size) {
+                    encoder.append(chunk)?;
+                }
+                assert_eq!(String::from_utf8(encoder.finish()?).unwrap(), expected);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn wrapped_base64_starts_each_line() -> IonResult<()> {
+        let mut encoder = Base64Encoder::new(Vec::new()).with_line_width(6, "\n> ".to_string());
+        encoder.append(b"hello, ")?;
+        encoder.append(b"world!")?;
+        assert_eq!(
+            String::from_utf8(encoder.finish()?).unwrap(),
+            "\n> aGVsbG\n> 8sIHdv\n> cmxkIQ\n> =="
+        );
+        Ok(())
+    }
+}
@@ -12,7 +12,9 @@ use crate::IonResult;
 pub mod annotate;
 pub mod annotation_seq;
 pub mod binary;
+pub(crate) mod lob_writer;
 pub mod text;
+pub mod validating;
 pub mod value_writer;
 pub mod write_as_ion;
 pub mod writer;
@@ -1,4 +1,5 @@
 use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
+use crate::lazy::encoder::lob_writer;
 use crate::lazy::encoder::private::Sealed;
 use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
 use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
@@ -16,7 +17,7 @@ use crate::types::{ContainerType, ParentType};
 use crate::{Decimal, Int, IonResult, IonType, RawSymbolRef, Timestamp};
 use delegate::delegate;
 use std::fmt::Formatter;
-use std::io::Write;
+use std::io::{Read, Write};
 
 pub struct TextValueWriter_1_0<'value, W: Write + 'value> {
     pub(crate) writer: &'value mut LazyRawTextWriter_1_0<W>,
@@ -28,6 +29,9 @@ pub struct TextValueWriter_1_0<'value, W: Write + 'value> {
     pub(crate) parent_type: ParentType,
     // If `true`, this value had annotations and so should not write its own indentation.
     pub(crate) has_annotations: bool,
+    // If set, this whitespace is written before the value and nothing is written after it.
+    // E-expression arguments are separated this way so that no whitespace precedes the `)`.
+    pub(crate) space_before: Option<&'static str>,
 }
 
 pub(crate) fn write_symbol_token<O: Write, A: AsRawSymbolRef>(
@@ -63,6 +67,7 @@ impl<'value, W: Write + 'value> TextValueWriter_1_0<'value, W> {
             value_delimiter: delimiter,
             parent_type,
             has_annotations: false,
+            space_before: None,
         }
     }
 
@@ -76,6 +81,7 @@ impl<'value, W: Write + 'value> TextValueWriter_1_0<'value, W> {
             // Either way, there's nothing to do here.
             return Ok(());
         }
+        self.write_space_before()?;
         if !indentation.is_empty() {
             for _ in 0..self.depth {
                 write!(self.output(), "{indentation}")?;
@@ -98,8 +104,44 @@ impl<'value, W: Write> TextValueWriter_1_0<'value, W> {
         self.value_delimiter
     }
 
+    /// Writes a blob's base64 text split across lines of at most `width` characters. Each line is
+    /// indented one level deeper than the blob itself; the closing `}}` is aligned with the blob.
+    fn write_wrapped_blob_text(&mut self, encoded: &str, width: usize) -> IonResult<()> {
+        let indentation = self.whitespace_config().indentation;
+        let depth = self.depth;
+        write!(self.output(), "{{{{")?;
+        // Base64 text is always ASCII, so splitting it at any byte offset is safe.
+        for line in encoded.as_bytes().chunks(width) {
+            writeln!(self.output())?;
+            for _ in 0..=depth {
+                write!(self.output(), "{indentation}")?;
+            }
+            self.output().write_all(line)?;
+        }
+        writeln!(self.output())?;
+        for _ in 0..depth {
+            write!(self.output(), "{indentation}")?;
+        }
+        write!(self.output(), "}}}}")?;
+        Ok(())
+    }
+
+    /// If this value is an e-expression argument, writes the whitespace that separates it from
+    /// the preceding argument (or from the macro ID). Annotated values write it before their
+    /// annotations.
+    pub(crate) fn write_space_before(&mut self) -> IonResult<()> {
+        if let (Some(space_before), false) = (self.space_before, self.has_annotations) {
+            write!(self.output(), "{space_before}")?;
+        }
+        Ok(())
+    }
+
     #[inline]
     fn write_delimiter_text(&mut self) -> IonResult<()> {
+        if self.space_before.is_some() {
+            // The whitespace separating this value from the next was written before it.
+            return Ok(());
+        }
         let space_between = match self.depth {
             0 => self.whitespace_config().space_between_top_level_values,
             _ => self.whitespace_config().space_between_nested_values,
@@ -123,14 +165,14 @@ impl<'value, W: Write> TextAnnotatedValueWriter_1_0<'value, W> {
         // After indenting, we set the `has_annotations` flag to `true` so the value won't write
         // indentation a second time.
         self.value_writer.has_annotations = !self.annotations.is_empty();
-        let output = &mut self.value_writer.writer.output;
+        let writer = &mut *self.value_writer.writer;
         for annotation in self.annotations {
             match annotation.as_raw_symbol_token_ref() {
                 RawSymbolRef::Text(token) => {
-                    write_symbol_token(output, token)?;
-                    write!(output, "::")
+                    writer.write_symbol_token(token)?;
+                    write!(writer.output, "::")
                 }
-                RawSymbolRef::SymbolId(sid) => write!(output, "${sid}::"),
+                RawSymbolRef::SymbolId(sid) => write!(writer.output, "${sid}::"),
             }?;
         }
 
@@ -160,6 +202,12 @@ pub(crate) struct TextContainerWriter_1_0<'a, W: Write> {
     container_type: ContainerType,
     value_delimiter: &'static str,
     trailing_delimiter: &'static str,
+    // If set, this whitespace is written before each child value instead of after it, as in an
+    // e-expression.
+    space_before_values: Option<&'static str>,
+    // If `false`, the whitespace separating this container from its next sibling was written
+    // before the container, so nothing is written after its closing delimiter.
+    pub(crate) writes_trailing_space: bool,
 }
 
 impl<'a, W: Write> Drop for TextContainerWriter_1_0<'a, W> {
@@ -193,6 +241,8 @@ impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
             has_been_closed: false,
             value_delimiter,
             trailing_delimiter,
+            space_before_values: None,
+            writes_trailing_space: true,
         };
         if parent_type != ParentType::Struct {
             container_writer.write_indentation(depth)?;
@@ -208,6 +258,36 @@ impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
         Ok(container_writer)
     }
 
+    /// Creates a writer for an e-expression that begins with `opening_delimiter`. Unlike other
+    /// containers, an e-expression writes the whitespace that separates its arguments before each
+    /// one, so its closing `)` immediately follows the last argument.
+    pub(crate) fn new_eexp(
+        writer: &'a mut LazyRawTextWriter_1_0<W>,
+        depth: usize,
+        parent_type: ParentType,
+        opening_delimiter: &str,
+        trailing_delimiter: &'static str,
+    ) -> IonResult<Self> {
+        let space_before_values = writer.whitespace_config.space_between_nested_values;
+        let mut container_writer = Self {
+            writer,
+            depth,
+            parent_type,
+            // Arguments are written using s-expression syntax.
+            container_type: ContainerType::SExp,
+            has_been_closed: false,
+            value_delimiter: "",
+            trailing_delimiter,
+            space_before_values: Some(space_before_values),
+            writes_trailing_space: true,
+        };
+        if parent_type != ParentType::Struct {
+            container_writer.write_indentation(depth)?;
+        }
+        write!(container_writer.writer.output, "{opening_delimiter}")?;
+        Ok(container_writer)
+    }
+
     /// Writes the `indentation` string set in the whitespace config to output `depth` times.
     fn write_indentation(&mut self, depth: usize) -> IonResult<()> {
         let indentation = self.whitespace_config().indentation;
@@ -234,11 +314,13 @@ impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
             _ => self.whitespace_config().space_between_nested_values,
         };
         let trailing_delimiter = self.trailing_delimiter;
-        self.write_indentation(self.depth)?;
-        write!(
-            self.output(),
-            "{closing_delimiter}{trailing_delimiter}{space_between}"
-        )?;
+        if self.space_before_values.is_none() {
+            self.write_indentation(self.depth)?;
+        }
+        write!(self.output(), "{closing_delimiter}")?;
+        if self.writes_trailing_space {
+            write!(self.output(), "{trailing_delimiter}{space_between}")?;
+        }
         self.has_been_closed = true;
         Ok(())
     }
@@ -259,6 +341,7 @@ impl<'a, W: Write> TextContainerWriter_1_0<'a, W> {
             value_delimiter: self.value_delimiter,
             parent_type: self.container_type.into(),
             has_annotations: false,
+            space_before: self.space_before_values,
         }
     }
 }
@@ -415,7 +498,7 @@ impl<'a, W: Write> FieldEncoder for TextStructWriter_1_0<'a, W> {
         self.container_writer
             .write_indentation(self.container_writer.depth + 1)?;
         // Write the field name
-        write_symbol_token(self.container_writer.output(), name)?;
+        self.container_writer.writer.write_symbol_token(name)?;
         let space_after_field_name = self
             .container_writer
             .whitespace_config()
@@ -438,6 +521,7 @@ impl<'value, W: Write> MakeValueWriter for TextStructWriter_1_0<'value, W> {
             value_delimiter: ",",
             parent_type: ParentType::Struct,
             has_annotations: false,
+            space_before: None,
         }
     }
 }
@@ -506,24 +590,9 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
     // Ion 1.0 does not support macros
     type EExpWriter = Never;
     fn write_null(mut self, ion_type: IonType) -> IonResult<()> {
-        use crate::IonType::*;
         self.write_indentation()?;
 
-        let null_text = match ion_type {
-            Null => "null",
-            Bool => "null.bool",
-            Int => "null.int",
-            Float => "null.float",
-            Decimal => "null.decimal",
-            Timestamp => "null.timestamp",
-            Symbol => "null.symbol",
-            String => "null.string",
-            Blob => "null.blob",
-            Clob => "null.clob",
-            List => "null.list",
-            SExp => "null.sexp",
-            Struct => "null.struct",
-        };
+        let null_text = ion_type.null_text();
         write!(self.output(), "{null_text}")?;
         self.write_delimiter_text()
     }
@@ -590,7 +659,11 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
 
     fn write_timestamp(mut self, value: &Timestamp) -> IonResult<()> {
         self.write_indentation()?;
-        write!(self.output(), "{value}")?;
+        let fractional_seconds_precision = self.writer.fractional_seconds_precision;
+        let mut io_shim = IoValueFormatter::new(self.output());
+        let format_result = value.format_with_precision(&mut io_shim, fractional_seconds_precision);
+        io_shim.into_result()?;
+        format_result?;
         self.write_delimiter_text()
     }
 
@@ -604,7 +677,7 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
 
     fn write_symbol(mut self, value: impl AsRawSymbolRef) -> IonResult<()> {
         self.write_indentation()?;
-        write_symbol_token(self.output(), value)?;
+        self.writer.write_symbol_token(value)?;
         self.write_delimiter_text()
     }
 
@@ -632,33 +705,70 @@ impl<'value, W: Write> ValueWriter for TextValueWriter_1_0<'value, W> {
         // * A {} pair used by the format string to indicate where the base64-encoded bytes
         //   should be inserted.
         // * The closing }} from a text Ion blob, with each brace doubled to escape it.
-        write!(self.output(), "{{{{{}}}}}", base64::encode(value))?;
+        let encoded = base64::encode(value);
+        match self.writer.blob_line_width {
+            Some(width) if encoded.len() > width => {
+                self.write_wrapped_blob_text(&encoded, width)?
+            }
+            _ => write!(self.output(), "{{{{{encoded}}}}}")?,
+        }
         self.write_delimiter_text()
     }
 
-    fn list_writer(self) -> IonResult<Self::ListWriter> {
-        TextListWriter_1_0::new(
+    fn write_clob_from_reader(mut self, length: u64, source: impl Read) -> IonResult<()> {
+        self.write_indentation()?;
+        lob_writer::write_text_clob(self.output(), length, source)?;
+        self.write_delimiter_text()
+    }
+
+    fn write_blob_from_reader(mut self, length: u64, source: impl Read) -> IonResult<()> {
+        self.write_indentation()?;
+        let blob_line_width = self.writer.blob_line_width;
+        let indentation = self.whitespace_config().indentation;
+        let depth = self.depth;
+        lob_writer::write_text_blob(
+            self.output(),
+            length,
+            source,
+            blob_line_width,
+            indentation,
+            depth,
+        )?;
+        self.write_delimiter_text()
+    }
+
+    fn list_writer(mut self) -> IonResult<Self::ListWriter> {
+        self.write_space_before()?;
+        let mut list_writer = TextListWriter_1_0::new(
             self.writer,
             self.depth,
             self.parent_type,
             self.value_delimiter,
-        )
+        )?;
+        list_writer.container_writer.writes_trailing_space = self.space_before.is_none();
+        Ok(list_writer)
     }
-    fn sexp_writer(self) -> IonResult<Self::SExpWriter> {
-        TextSExpWriter_1_0::new(
+    fn sexp_writer(mut self) -> IonResult<Self::SExpWriter> {
+        self.write_space_before()?;
+        let mut sexp_writer = TextSExpWriter_1_0::new(
             self.writer,
             self.depth,
             self.parent_type,
             self.value_delimiter,
-        )
+        )?;
+        sexp_writer.container_writer.writes_trailing_space = self.space_before.is_none();
+        Ok(sexp_writer)
     }
-    fn struct_writer(self) -> IonResult<Self::StructWriter> {
-        TextStructWriter_1_0::new(
+    fn struct_writer(mut self) -> IonResult<Self::StructWriter> {
+        self.write_space_before()?;
+        let mut struct_writer = TextStructWriter_1_0::new(
             self.writer,
             self.depth,
             self.parent_type,
             self.value_delimiter,
-        )
+        )?;
+        struct_writer.container_writer.writes_trailing_space = self.space_before.is_none();
+        Ok(struct_writer)
     }
     fn eexp_writer<'a>(self, _macro_id: impl Into<MacroIdRef<'a>>) -> IonResult<Self::EExpWriter> {
         IonResult::encoding_error("macros are not supported in Ion 1.0")
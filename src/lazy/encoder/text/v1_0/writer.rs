@@ -1,24 +1,31 @@
+use std::collections::HashMap;
 use std::io::Write;
 
 use delegate::delegate;
 
-use crate::lazy::encoder::text::v1_0::value_writer::TextValueWriter_1_0;
+use crate::lazy::encoder::lob_writer::LobFraming;
+use crate::lazy::encoder::text::v1_0::value_writer::{write_symbol_token, TextValueWriter_1_0};
 use crate::lazy::encoder::value_writer::internal::MakeValueWriter;
 use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::LazyRawWriter;
 use crate::lazy::encoding::{Encoding, TextEncoding_1_0};
+use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::text::text_formatter::IoValueFormatter;
 use crate::text::whitespace_config::{
     WhitespaceConfig, COMPACT_WHITESPACE_CONFIG, LINES_WHITESPACE_CONFIG, PRETTY_WHITESPACE_CONFIG,
 };
 use crate::types::ParentType;
-use crate::write_config::WriteConfigKind;
-use crate::{IonResult, TextFormat, WriteConfig};
+use crate::write_config::{SymbolStyle, WriteConfigKind};
+use crate::{FractionalSecondsPrecision, IonResult, RawSymbolRef, TextFormat, WriteConfig};
 
 /// A raw text Ion 1.0 writer.
 pub struct LazyRawTextWriter_1_0<W: Write> {
     pub(crate) output: W,
     pub(crate) whitespace_config: &'static WhitespaceConfig,
+    pub(crate) fractional_seconds_precision: FractionalSecondsPrecision,
+    pub(crate) blob_line_width: Option<usize>,
+    pub(crate) symbol_styles: HashMap<String, SymbolStyle>,
 }
 
 impl<W: Write> LazyRawTextWriter_1_0<W> {
@@ -39,6 +46,30 @@ impl<W: Write> LazyRawTextWriter_1_0<W> {
         Ok(())
     }
 
+    /// Writes a symbol value, field name, or annotation, honoring any
+    /// [`SymbolStyle::QuotedText`] overrides.
+    pub(crate) fn write_symbol_token(&mut self, token: impl AsRawSymbolRef) -> IonResult<()> {
+        let token = token.as_raw_symbol_token_ref();
+        match token {
+            RawSymbolRef::Text(text)
+                if self.symbol_styles.get(text) == Some(&SymbolStyle::QuotedText) =>
+            {
+                let mut io_shim = IoValueFormatter::new(&mut self.output);
+                let _ = io_shim.value_formatter().format_quoted_symbol_text(text);
+                io_shim.into_result()
+            }
+            _ => write_symbol_token(&mut self.output, token),
+        }
+    }
+
+    /// Describes how this writer frames top-level lobs whose payloads are streamed.
+    pub(crate) fn lob_framing(&self) -> LobFraming {
+        LobFraming::Text {
+            whitespace_config: self.whitespace_config,
+            blob_line_width: self.blob_line_width,
+        }
+    }
+
     /// Helper method to construct this format's `ValueWriter` implementation.
     #[inline]
     fn value_writer(&mut self) -> TextValueWriter_1_0<'_, W> {
@@ -90,6 +121,9 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_0<W> {
                 Ok(LazyRawTextWriter_1_0 {
                     output,
                     whitespace_config,
+                    fractional_seconds_precision: text_config.fractional_seconds_precision,
+                    blob_line_width: text_config.blob_line_width,
+                    symbol_styles: config.symbol_styles.clone(),
                 })
             }
             WriteConfigKind::Binary(_) => {
@@ -118,7 +152,14 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_0<W> {
 #[cfg(test)]
 mod tests {
     use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
-    use crate::{v1_1, Annotatable, ElementReader, IonData, IonResult, Reader, SequenceWriter};
+    use crate::lazy::encoder::writer::Writer;
+    use crate::lazy::encoder::LazyRawWriter;
+    use crate::lazy::encoding::TextEncoding_1_0;
+    use crate::{
+        ion_struct, v1_0, v1_1, Annotatable, Element, ElementReader, FractionalSecondsPrecision,
+        IonData, IonError, IonResult, Reader, SequenceWriter, Symbol, SymbolStyle, TextFormat,
+        Timestamp, WriteConfig,
+    };
 
     #[test]
     fn write_annotated_values() -> IonResult<()> {
@@ -150,4 +191,104 @@ mod tests {
         assert!(IonData::eq(&expected, &actual));
         Ok(())
     }
+
+    #[test]
+    fn write_timestamps_with_fractional_seconds_precision() -> IonResult<()> {
+        let config = WriteConfig::<TextEncoding_1_0>::new(TextFormat::Lines)
+            .with_fractional_seconds_precision(FractionalSecondsPrecision::Exact(3));
+        let mut writer = LazyRawTextWriter_1_0::build(config, vec![])?;
+        writer
+            .write(Timestamp::with_ymd(2024, 5, 6).with_hms(7, 8, 9).build()?)?
+            .write(
+                Timestamp::with_ymd(2024, 5, 6)
+                    .with_hms(7, 8, 9)
+                    .with_nanoseconds(123456789)
+                    .build()?,
+            )?
+            .write(Timestamp::with_ymd(2024, 5, 6).build()?)?;
+        let encoded_text = String::from_utf8(writer.close()?).unwrap();
+        assert_eq!(
+            encoded_text,
+            "2024-05-06T07:08:09.000-00:00\n2024-05-06T07:08:09.123-00:00\n2024-05-06T\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_blobs_with_line_width() -> IonResult<()> {
+        let config =
+            WriteConfig::<TextEncoding_1_0>::new(TextFormat::Pretty).with_blob_line_width(8);
+        let mut writer = LazyRawTextWriter_1_0::build(config, vec![])?;
+        writer
+            .write("hello".as_bytes())?
+            .write([b"hello, world!".as_slice()])?;
+        let encoded_text = String::from_utf8(writer.close()?).unwrap();
+        let expected = "{{aGVsbG8=}}\n[\n  {{\n    aGVsbG8s\n    IHdvcmxk\n    IQ==\n  }},\n]\n";
+        assert_eq!(encoded_text, expected);
+
+        let mut reader = Reader::new(v1_1::Text, encoded_text)?;
+        let mut expected = Reader::new(v1_1::Text, "{{aGVsbG8=}} [{{aGVsbG8sIHdvcmxkIQ==}}]")?;
+        assert!(IonData::eq(
+            &reader.read_all_elements()?,
+            &expected.read_all_elements()?
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn write_quoted_symbol_style() -> IonResult<()> {
+        let config = WriteConfig::<TextEncoding_1_0>::new(TextFormat::Compact)
+            .with_symbol_style("foo", SymbolStyle::QuotedText);
+        let mut writer = LazyRawTextWriter_1_0::build(config, vec![])?;
+        writer
+            .write(Symbol::owned("foo").annotated_with(["foo", "bar"]))?
+            .write(&Element::from(ion_struct! {"foo": Symbol::owned("bar")}))?;
+        let encoded_text = String::from_utf8(writer.close()?).unwrap();
+        assert_eq!(encoded_text, "'foo'::bar::'foo' {'foo': bar, } ");
+        Ok(())
+    }
+
+    #[test]
+    fn write_symbol_id_style() -> IonResult<()> {
+        let config = WriteConfig::<TextEncoding_1_0>::new(TextFormat::Compact)
+            .with_symbol_style("foo", SymbolStyle::SymbolId);
+        let mut writer = Writer::new(config, vec![])?;
+        let value: Element =
+            ion_struct! {"foo": Element::symbol("foo").with_annotations(["foo", "bar"])}.into();
+        writer.write(&value)?;
+        let encoded_text = String::from_utf8(writer.close()?).unwrap();
+        // Only `foo` is added to the symbol table; `bar` is still written as text.
+        assert!(encoded_text.ends_with("{$10: $10::bar::$10, } "));
+        assert_eq!(Element::read_one(encoded_text)?, value);
+        Ok(())
+    }
+
+    #[test]
+    fn write_without_system_values() -> IonResult<()> {
+        let config =
+            WriteConfig::<TextEncoding_1_0>::new(TextFormat::Compact).with_system_values(false);
+        let mut writer = Writer::new(config.clone(), vec![])?;
+        writer
+            .write(1.annotated_with("foo"))?
+            .write(Symbol::owned("bar"))?;
+        let encoded_text = String::from_utf8(writer.close()?).unwrap();
+        assert_eq!(encoded_text, "foo::1 bar ");
+
+        // A symbol written as a symbol ID would need a symbol table.
+        let config = config.with_symbol_style("foo", SymbolStyle::SymbolId);
+        let mut writer = Writer::new(config, vec![])?;
+        writer.write(Symbol::owned("foo"))?;
+        assert!(matches!(writer.flush(), Err(IonError::IllegalOperation(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn write_text_style_in_binary_falls_back_to_symbol_id() -> IonResult<()> {
+        let config = WriteConfig::<v1_0::Binary>::new().with_symbol_style("foo", SymbolStyle::Text);
+        let mut writer = Writer::new(config, vec![])?;
+        writer.write(Symbol::owned("foo"))?;
+        let encoded = writer.close()?;
+        assert_eq!(Element::read_one(encoded)?, Element::symbol("foo"));
+        Ok(())
+    }
 }
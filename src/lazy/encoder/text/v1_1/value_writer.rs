@@ -11,7 +11,7 @@ use crate::lazy::encoder::value_writer::{
 use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
-use crate::types::{ContainerType, ParentType};
+use crate::types::ParentType;
 use crate::{Decimal, Int, IonResult, IonType, Timestamp};
 use delegate::delegate;
 use std::io::Write;
@@ -63,6 +63,8 @@ impl<'value, W: Write + 'value> ValueWriter for TextValueWriter_1_1<'value, W> {
             fn write_symbol(self, value: impl AsRawSymbolRef) -> IonResult<()>;
             fn write_clob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
             fn write_blob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
+            fn write_blob_from_reader(self, length: u64, source: impl std::io::Read) -> IonResult<()>;
+            fn write_clob_from_reader(self, length: u64, source: impl std::io::Read) -> IonResult<()>;
         }
     }
 
@@ -84,21 +86,25 @@ impl<'value, W: Write + 'value> ValueWriter for TextValueWriter_1_1<'value, W> {
         })
     }
 
-    fn eexp_writer<'a>(self, macro_id: impl Into<MacroIdRef<'a>>) -> IonResult<Self::EExpWriter> {
-        TextEExpWriter_1_1::new(
+    fn eexp_writer<'a>(
+        mut self,
+        macro_id: impl Into<MacroIdRef<'a>>,
+    ) -> IonResult<Self::EExpWriter> {
+        self.value_writer_1_0.write_space_before()?;
+        let writes_trailing_space = self.value_writer_1_0.space_before.is_none();
+        let mut eexp_writer = TextEExpWriter_1_1::new(
             self.value_writer_1_0.writer,
             self.value_writer_1_0.depth,
             self.value_writer_1_0.parent_type,
-            // Pretend we're in a sexp for syntax purposes
-            ContainerType::SExp,
             // TODO: Reusable buffer
             format!("(:{}", macro_id.into()).as_str(),
-            " ",
             match self.value_writer_1_0.parent_type {
                 ParentType::Struct | ParentType::List => ",",
                 _ => "",
             },
-        )
+        )?;
+        eexp_writer.container_writer.writes_trailing_space = writes_trailing_space;
+        Ok(eexp_writer)
     }
 }
 
@@ -139,6 +145,8 @@ impl<'value, W: Write + 'value> ValueWriter for TextAnnotatedValueWriter_1_1<'va
             fn write_symbol(self, value: impl AsRawSymbolRef) -> IonResult<()>;
             fn write_clob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
             fn write_blob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
+            fn write_blob_from_reader(self, length: u64, source: impl std::io::Read) -> IonResult<()>;
+            fn write_clob_from_reader(self, length: u64, source: impl std::io::Read) -> IonResult<()>;
         }
     }
 
@@ -247,18 +255,14 @@ impl<'value, W: Write> TextEExpWriter_1_1<'value, W> {
         writer: &'value mut LazyRawTextWriter_1_0<W>,
         depth: usize,
         parent_type: ParentType,
-        container_type: ContainerType,
         opening_delimiter: &str,
-        value_delimiter: &'static str,
         trailing_delimiter: &'static str,
     ) -> IonResult<Self> {
-        let container_writer = TextContainerWriter_1_0::new(
+        let container_writer = TextContainerWriter_1_0::new_eexp(
             writer,
             depth,
             parent_type,
-            container_type,
             opening_delimiter,
-            value_delimiter,
             trailing_delimiter,
         )?;
         Ok(Self { container_writer })
@@ -61,15 +61,20 @@ impl<W: Write> LazyRawWriter<W> for LazyRawTextWriter_1_1<W> {
                     TextFormat::Lines => &LINES_WHITESPACE_CONFIG,
                     TextFormat::Pretty => &PRETTY_WHITESPACE_CONFIG,
                 };
-                write!(
-                    output,
-                    "$ion_1_1{}",
-                    whitespace_config.space_between_top_level_values
-                )?;
+                if text_config.write_system_values {
+                    write!(
+                        output,
+                        "$ion_1_1{}",
+                        whitespace_config.space_between_top_level_values
+                    )?;
+                }
                 Ok(LazyRawTextWriter_1_1 {
                     writer_1_0: LazyRawTextWriter_1_0 {
                         output,
                         whitespace_config,
+                        fractional_seconds_precision: text_config.fractional_seconds_precision,
+                        blob_line_width: text_config.blob_line_width,
+                        symbol_styles: config.symbol_styles.clone(),
                     },
                 })
             }
@@ -98,15 +103,18 @@ mod tests {
     use crate::lazy::encoder::text::v1_1::writer::LazyRawTextWriter_1_1;
     use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter, ValueWriter};
     use crate::lazy::encoder::write_as_ion::WriteAsSExp;
+    use crate::lazy::encoder::writer::Writer;
     use crate::lazy::encoder::LazyRawWriter;
+    use crate::lazy::encoding::TextEncoding_1_1;
     use crate::lazy::expanded::macro_evaluator::RawEExpression;
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::text::raw::v1_1::reader::{LazyRawTextReader_1_1, MacroIdRef};
     use crate::symbol_ref::AsSymbolRef;
     use crate::{
         v1_1, Annotatable, Decimal, ElementReader, IonData, IonResult, IonType, Null, RawSymbolRef,
-        Reader, Timestamp,
+        Reader, TextFormat, Timestamp, WriteConfig,
     };
+    use rstest::rstest;
 
     #[test]
     fn write_scalars() -> IonResult<()> {
@@ -318,6 +326,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn eexp_arguments_are_separated_before_each_argument() -> IonResult<()> {
+        let config = WriteConfig::<TextEncoding_1_1>::new(TextFormat::Compact);
+        let mut writer = LazyRawTextWriter_1_1::build(config, vec![])?;
+        let mut macro_args = writer.eexp_writer("foo")?;
+        macro_args
+            .write(1)?
+            .write(1.annotated_with("a"))?
+            .write(["b", "c"].as_sexp())?;
+        let mut nested_args = macro_args.eexp_writer("bar")?;
+        nested_args.write(2)?;
+        nested_args.close()?;
+        macro_args.write_list([3])?;
+        macro_args.close()?;
+        writer.eexp_writer("baz")?.close()?;
+        writer.write(4)?;
+        let encoded_text = String::from_utf8(writer.close()?).unwrap();
+        assert!(
+            encoded_text.ends_with(r#"(:foo 1 a::1 ("b" "c" ) (:bar 2) [3, ]) (:baz) 4 "#),
+            "{encoded_text}"
+        );
+        Ok(())
+    }
+
     #[test]
     fn write_annotated_values() -> IonResult<()> {
         const NO_ANNOTATIONS: [&str; 0] = [];
@@ -349,4 +381,19 @@ mod tests {
         assert!(IonData::eq(&expected, &actual));
         Ok(())
     }
+
+    #[rstest]
+    #[case::with_system_values(true, "$ion_1_1 foo::1 bar ")]
+    #[case::without_system_values(false, "foo::1 bar ")]
+    fn write_system_values(#[case] enabled: bool, #[case] expected: &str) -> IonResult<()> {
+        let config =
+            WriteConfig::<TextEncoding_1_1>::new(TextFormat::Compact).with_system_values(enabled);
+        let mut writer = Writer::new(config, vec![])?;
+        writer
+            .write(1.annotated_with("foo"))?
+            .write("bar".as_symbol_ref())?;
+        let encoded_text = String::from_utf8(writer.close()?).unwrap();
+        assert_eq!(encoded_text, expected);
+        Ok(())
+    }
 }
@@ -0,0 +1,276 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::lazy::encoder::write_as_ion::WriteAsIon;
+use crate::lazy::encoder::LazyRawWriter;
+use crate::result::IonFailure;
+use crate::{Element, IonData, IonError, IonResult, Sequence};
+
+/// A function that is called each time a [`ValidatingWriter`] detects a mismatch.
+type MismatchHook = Box<dyn FnMut(&WriteMismatch)>;
+
+/// Wraps a raw writer, re-reading each value as soon as it is written and comparing it to the
+/// value that the caller intended to write.
+///
+/// This is a debugging aid for code that encodes values using the raw writer APIs directly
+/// (for example, custom [`WriteAsIon`] implementations). Because every value is flushed and
+/// read back before the next one is written, it is considerably slower than writing normally.
+///
+/// Values are compared using Ion equivalence (see [`IonData`]). By default, a mismatch causes
+/// the write to fail with an encoding error; a hook installed with
+/// [`on_mismatch`](Self::on_mismatch) can record mismatches and allow writing to continue instead.
+///
+/// Raw writers do not manage a symbol table, so symbols written as symbol IDs are read back using
+/// the system symbol table.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # #[cfg(all(feature = "experimental-tooling-apis", feature = "experimental-reader-writer"))]
+/// # fn main() -> IonResult<()> {
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use ion_rs::v1_0::RawTextWriter;
+/// use ion_rs::{Element, LazyRawWriter, ValidatingWriter, ValueWriter, WriteAsIon};
+///
+/// // A custom encoder with a bug: it truncates the temperature.
+/// struct Celsius(f64);
+///
+/// impl WriteAsIon for Celsius {
+///     fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+///         writer.with_annotations("celsius")?.write(self.0 as i64)
+///     }
+/// }
+///
+/// let mismatches = Rc::new(RefCell::new(Vec::new()));
+/// let captured = Rc::clone(&mismatches);
+/// let mut writer = ValidatingWriter::new(RawTextWriter::new(Vec::new())?)
+///     .on_mismatch(move |mismatch| captured.borrow_mut().push(mismatch.clone()));
+///
+/// writer.write_expecting(Celsius(21.0), &Element::read_one("celsius::21")?)?;
+/// writer.write_expecting(Celsius(21.5), &Element::read_one("celsius::21.5e0")?)?;
+///
+/// assert_eq!(writer.mismatch_count(), 1);
+/// assert_eq!(mismatches.borrow()[0].expected(), &Element::read_one("celsius::21.5e0")?);
+/// # Ok(())
+/// # }
+/// # #[cfg(not(all(feature = "experimental-tooling-apis", feature = "experimental-reader-writer")))]
+/// # fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub struct ValidatingWriter<W: LazyRawWriter<Vec<u8>>> {
+    writer: W,
+    // The bytes that the writer emitted when it was constructed (typically a version marker).
+    // They are prepended to each newly written value so it can be read on its own.
+    preamble: Vec<u8>,
+    // The number of output bytes that have already been read back.
+    validated: usize,
+    on_mismatch: Option<MismatchHook>,
+    mismatch_count: usize,
+}
+
+impl<W: LazyRawWriter<Vec<u8>>> ValidatingWriter<W> {
+    /// Wraps `writer`, which should not have written any values yet.
+    pub fn new(writer: W) -> Self {
+        let preamble = writer.output().clone();
+        let validated = preamble.len();
+        Self {
+            writer,
+            preamble,
+            validated,
+            on_mismatch: None,
+            mismatch_count: 0,
+        }
+    }
+
+    /// Calls `hook` for each mismatch instead of returning an error.
+    pub fn on_mismatch(mut self, hook: impl FnMut(&WriteMismatch) + 'static) -> Self {
+        self.on_mismatch = Some(Box::new(hook));
+        self
+    }
+
+    /// Writes `element` and checks that it can be read back.
+    pub fn write_element(&mut self, element: &Element) -> IonResult<&mut Self> {
+        self.write_expecting(element, element)
+    }
+
+    /// Writes `value` and checks that reading it back produces a value that is Ion equal to
+    /// `expected`.
+    pub fn write_expecting<V: WriteAsIon>(
+        &mut self,
+        value: V,
+        expected: &Element,
+    ) -> IonResult<&mut Self> {
+        self.writer.write(value)?;
+        self.writer.flush()?;
+        let encoding = self.writer.output()[self.validated..].to_vec();
+        self.validated = self.writer.output().len();
+
+        let actual = Element::read_all([self.preamble.as_slice(), encoding.as_slice()].concat());
+        let matches = match &actual {
+            Ok(actual) => actual.len() == 1 && IonData::eq(actual.get(0).unwrap(), expected),
+            Err(_) => false,
+        };
+        if matches {
+            return Ok(self);
+        }
+
+        self.mismatch_count += 1;
+        let mismatch = WriteMismatch {
+            expected: expected.clone(),
+            actual,
+            encoding,
+        };
+        match self.on_mismatch.as_mut() {
+            Some(hook) => {
+                hook(&mismatch);
+                Ok(self)
+            }
+            None => IonResult::encoding_error(mismatch.to_string()),
+        }
+    }
+
+    /// The number of mismatches that have been detected.
+    pub fn mismatch_count(&self) -> usize {
+        self.mismatch_count
+    }
+
+    /// The encoded output, all of which has been validated.
+    pub fn output(&self) -> &Vec<u8> {
+        self.writer.output()
+    }
+
+    /// Closes the underlying writer, returning its output.
+    pub fn close(self) -> IonResult<Vec<u8>> {
+        self.writer.close()
+    }
+}
+
+impl<W: LazyRawWriter<Vec<u8>>> Debug for ValidatingWriter<W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValidatingWriter")
+            .field("validated", &self.validated)
+            .field("mismatch_count", &self.mismatch_count)
+            .finish()
+    }
+}
+
+/// A value that a [`ValidatingWriter`] did not encode as intended.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriteMismatch {
+    expected: Element,
+    actual: IonResult<Sequence>,
+    encoding: Vec<u8>,
+}
+
+impl WriteMismatch {
+    /// The value that the caller intended to write.
+    pub fn expected(&self) -> &Element {
+        &self.expected
+    }
+
+    /// The values that were read back from the encoding, or the error that was raised while
+    /// reading it. A correct encoding produces exactly one value.
+    pub fn actual(&self) -> Result<&Sequence, &IonError> {
+        self.actual.as_ref()
+    }
+
+    /// The bytes that the writer emitted for the value.
+    pub fn encoding(&self) -> &[u8] {
+        &self.encoding
+    }
+}
+
+impl Display for WriteMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wrote a value that did not match {}: ", self.expected)?;
+        match &self.actual {
+            Ok(actual) if actual.is_empty() => write!(f, "no value was read back"),
+            Ok(actual) => {
+                write!(f, "read back")?;
+                for element in actual.elements() {
+                    write!(f, " {element}")?;
+                }
+                Ok(())
+            }
+            Err(e) => write!(f, "the encoding could not be read back: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0;
+    use crate::lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0;
+    use crate::lazy::encoder::value_writer::ValueWriter;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // Writes a string as a symbol, which is not Ion equal to the intended string.
+    struct SymbolForString(&'static str);
+
+    impl WriteAsIon for SymbolForString {
+        fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+            writer.write_symbol(self.0)
+        }
+    }
+
+    const DATA: &str = r#"1 foo::"bar" [1.5, {a: 2024T}] null.blob"#;
+
+    #[test]
+    fn valid_output_passes() -> IonResult<()> {
+        let mut writer = ValidatingWriter::new(LazyRawTextWriter_1_0::new(Vec::new())?);
+        for element in Element::read_all(DATA)? {
+            writer.write_element(&element)?;
+        }
+        assert_eq!(writer.mismatch_count(), 0);
+        assert_eq!(
+            Element::read_all(writer.close()?)?,
+            Element::read_all(DATA)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn binary_values_are_read_with_the_version_marker() -> IonResult<()> {
+        let mut writer = ValidatingWriter::new(LazyRawBinaryWriter_1_0::new(Vec::new())?);
+        for element in Element::read_all("1 [true, 2.5e0] (null.int)")? {
+            writer.write_element(&element)?;
+        }
+        assert_eq!(writer.mismatch_count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn mismatch_is_an_error_by_default() -> IonResult<()> {
+        let mut writer = ValidatingWriter::new(LazyRawTextWriter_1_0::new(Vec::new())?);
+        let result = writer.write_expecting(SymbolForString("hi"), &Element::string("hi"));
+        assert!(matches!(result, Err(IonError::Encoding(_))));
+        assert_eq!(writer.mismatch_count(), 1);
+        // The writer can still be used afterward.
+        writer.write_element(&Element::int(5))?;
+        assert_eq!(writer.mismatch_count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn hook_captures_mismatches() -> IonResult<()> {
+        let mismatches = Rc::new(RefCell::new(Vec::new()));
+        let captured = Rc::clone(&mismatches);
+        let mut writer = ValidatingWriter::new(LazyRawTextWriter_1_0::new(Vec::new())?)
+            .on_mismatch(move |mismatch| captured.borrow_mut().push(mismatch.clone()));
+        writer
+            .write_element(&Element::int(1))?
+            .write_expecting(SymbolForString("hi"), &Element::string("hi"))?;
+
+        let mismatches = mismatches.borrow();
+        assert_eq!(mismatches.len(), 1);
+        let mismatch = &mismatches[0];
+        assert_eq!(mismatch.expected(), &Element::string("hi"));
+        assert_eq!(mismatch.actual().unwrap(), &Element::read_all("hi")?);
+        assert_eq!(mismatch.encoding(), b"hi\n");
+        assert_eq!(
+            mismatch.to_string(),
+            "wrote a value that did not match \"hi\": read back hi"
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
+use std::io::Read;
+
 use crate::lazy::encoder::annotation_seq::{AnnotationSeq, AnnotationsVec};
+use crate::lazy::encoder::lob_writer;
 use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
@@ -40,6 +43,9 @@ pub trait AnnotatableWriter {
     where
         Self: 'a;
 
+    /// Returns a writer for a value with the provided annotations. Each annotation may be
+    /// specified as text or as a symbol ID; an application writer reports an error if a symbol ID
+    /// is not in its symbol table.
     fn with_annotations<'a>(
         self,
         annotations: impl AnnotationSeq<'a>,
@@ -64,10 +70,33 @@ pub trait ValueWriter: AnnotatableWriter + Sized {
     fn write_decimal(self, value: &Decimal) -> IonResult<()>;
     fn write_timestamp(self, value: &Timestamp) -> IonResult<()>;
     fn write_string(self, value: impl AsRef<str>) -> IonResult<()>;
+    /// Writes a symbol value, which may be specified as text or as a symbol ID (for example,
+    /// `RawSymbolRef::SymbolId(14)`). Symbol IDs are written as-is, so they must refer to a symbol
+    /// in the writer's symbol table.
     fn write_symbol(self, value: impl AsRawSymbolRef) -> IonResult<()>;
     fn write_clob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
     fn write_blob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
 
+    /// Writes a blob whose `length`-byte payload is read from `source` a chunk at a time, rather
+    /// than requiring it to be collected in a `Vec<u8>` first. Exactly `length` bytes are read
+    /// from `source`; if it ends sooner, an error is returned.
+    ///
+    /// Like any other value, the encoded blob is buffered along with the top-level value that
+    /// contains it until the writer is flushed. To write a top-level blob directly to the output,
+    /// use [`Writer::write_blob_from_reader`](crate::Writer::write_blob_from_reader).
+    ///
+    /// The provided implementation reads the complete payload and then calls
+    /// [`write_blob`](Self::write_blob).
+    fn write_blob_from_reader(self, length: u64, source: impl Read) -> IonResult<()> {
+        self.write_blob(lob_writer::read_payload(length, source)?)
+    }
+
+    /// Writes a clob whose `length`-byte payload is read from `source` a chunk at a time. See
+    /// [`write_blob_from_reader`](Self::write_blob_from_reader).
+    fn write_clob_from_reader(self, length: u64, source: impl Read) -> IonResult<()> {
+        self.write_clob(lob_writer::read_payload(length, source)?)
+    }
+
     fn list_writer(self) -> IonResult<Self::ListWriter>;
     fn sexp_writer(self) -> IonResult<Self::SExpWriter>;
     fn struct_writer(self) -> IonResult<Self::StructWriter>;
@@ -170,6 +199,8 @@ macro_rules! delegate_value_writer_to {
                 fn write_symbol(self, value: impl AsRawSymbolRef) -> IonResult<()>;
                 fn write_clob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
                 fn write_blob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
+                fn write_blob_from_reader(self, length: u64, source: impl std::io::Read) -> IonResult<()>;
+                fn write_clob_from_reader(self, length: u64, source: impl std::io::Read) -> IonResult<()>;
                 fn list_writer(self) -> IonResult<Self::ListWriter>;
                 fn sexp_writer(self) -> IonResult<Self::SExpWriter>;
                 fn struct_writer(self) -> IonResult<Self::StructWriter>;
@@ -309,7 +340,8 @@ impl<'field, StructWriterType: StructWriter> ValueWriter
 
 pub trait StructWriter: FieldEncoder + MakeValueWriter + Sized {
     const IS_HUMAN_READABLE: bool;
-    /// Writes a struct field using the provided name/value pair.
+    /// Writes a struct field using the provided name/value pair. Like symbol values, the field
+    /// name may be specified as text or as a symbol ID.
     fn write<A: AsRawSymbolRef, V: WriteAsIon>(
         &mut self,
         name: A,
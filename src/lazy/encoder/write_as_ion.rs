@@ -17,15 +17,19 @@
 use std::io;
 use std::marker::PhantomData;
 
+use crate::element::shared::SharedElement;
 use crate::lazy::decoder::Decoder;
 use crate::lazy::encoder::annotation_seq::AnnotationsVec;
 use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter, ValueWriter};
 use crate::lazy::encoding::Encoding;
-use crate::lazy::value::LazyValue;
+use crate::lazy::expanded::ExpandedValueRef;
+use crate::lazy::value::{LazyValue, SymbolIdPreservingValue};
 use crate::lazy::value_ref::ValueRef;
+use crate::result::IonFailure;
 use crate::{
-    Blob, Clob, Decimal, Element, Int, IonResult, IonType, LazyList, LazySExp, LazyStruct, Null,
-    RawSymbolRef, Symbol, SymbolRef, Timestamp, Value, WriteConfig,
+    Blob, Clob, Decimal, Element, Int, IonResult, IonType, LazyList, LazySExp, LazyStruct, List,
+    Null, RawSymbolRef, SExp, SharedSymbolTable, Struct, Symbol, SymbolRef, Timestamp, Value,
+    WriteConfig,
 };
 
 /// Defines how a Rust type should be serialized as Ion in terms of the methods available
@@ -84,6 +88,12 @@ impl WriteAsIon for &Element {
     }
 }
 
+impl WriteAsIon for SharedElement {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        self.as_element().write_as_ion(writer)
+    }
+}
+
 // ===== WriteAsIonValue implementations for common types =====
 
 macro_rules! impl_write_as_ion_value {
@@ -122,6 +132,7 @@ impl_write_as_ion_value!(
     i16 => write_i64 with self as *self as i64,
     i32 => write_i64 with self as *self as i64,
     i64 => write_i64 with self as *self,
+    i128 => write_int with self as &Int::from(*self),
     isize => write_i64 with self as *self as i64,
     u16 => write_i64 with self as i64::from(*self),
     u32 => write_i64 with self as i64::from(*self),
@@ -140,6 +151,21 @@ impl_write_as_ion_value!(
     Clob => write_clob,
 );
 
+/// An Ion int is represented as an [`Int`], which holds an `i128`. Values greater than `i128::MAX`
+/// (for example, most UUIDs stored as a `u128`) cannot be represented and produce an encoding
+/// error; write them as a string or blob instead.
+impl WriteAsIon for u128 {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        match i128::try_from(*self) {
+            Ok(value) => writer.write_int(&Int::from(value)),
+            Err(_) => IonResult::encoding_error(format!(
+                "cannot write u128 value {self} as an Ion int; values greater than i128::MAX are \
+                 not supported"
+            )),
+        }
+    }
+}
+
 impl<'b> WriteAsIon for RawSymbolRef<'b> {
     #[inline]
     fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
@@ -250,6 +276,41 @@ impl_write_as_ion_value_for_sexp_type_hint!(Vec<T>, T);
 impl_write_as_ion_value_for_sexp_type_hint!(&[T], T);
 impl_write_as_ion_value_for_sexp_type_hint!([T; N], T, const N: usize);
 
+impl WriteAsIon for List {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        writer.write_list(self)
+    }
+}
+
+impl WriteAsIon for SExp {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        writer.write_sexp(self)
+    }
+}
+
+impl WriteAsIon for Struct {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        writer.write_struct(self.iter())
+    }
+}
+
+/// Writes the table in the serialized form of a shared symbol table, which can be read back using
+/// `SharedSymbolTable`'s implementation of `TryFrom<Element>`.
+impl WriteAsIon for SharedSymbolTable {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        let mut table = writer
+            .with_annotations("$ion_shared_symbol_table")?
+            .struct_writer()?;
+        table
+            .write("name", self.name())?
+            .write("version", self.version())?;
+        let mut symbols = table.field_writer("symbols").list_writer()?;
+        symbols.write_all(self.symbols().iter().map(Symbol::text))?;
+        symbols.close()?;
+        table.close()
+    }
+}
+
 impl WriteAsIon for Value {
     fn write_as_ion<V: ValueWriter>(&self, value_writer: V) -> IonResult<()> {
         use Value::*;
@@ -286,6 +347,58 @@ impl<'a, D: Decoder> WriteAsIon for LazyValue<'a, D> {
     }
 }
 
+impl<'a, D: Decoder> WriteAsIon for SymbolIdPreservingValue<'a, D> {
+    fn write_as_ion<V: ValueWriter>(&self, writer: V) -> IonResult<()> {
+        let value = self.value();
+        if value.has_annotations() {
+            let mut annotations = AnnotationsVec::new();
+            for annotation in value.expanded().annotations() {
+                annotations.push(annotation?);
+            }
+            write_preserving_symbol_ids(value, writer.with_annotations(annotations)?)
+        } else {
+            write_preserving_symbol_ids(value, writer)
+        }
+    }
+}
+
+/// Writes the body of `value`, using the symbol IDs found in its encoding for symbol values and
+/// (recursively) for the annotations and field names of any nested values.
+fn write_preserving_symbol_ids<D: Decoder, V: ValueWriter>(
+    value: LazyValue<'_, D>,
+    writer: V,
+) -> IonResult<()> {
+    match value.read()? {
+        ValueRef::Symbol(_) => match value.expanded().read()? {
+            ExpandedValueRef::Symbol(raw_symbol) => writer.write_symbol(raw_symbol),
+            _ => unreachable!("a symbol value's expanded value is also a symbol"),
+        },
+        ValueRef::List(list) => {
+            let mut list_writer = writer.list_writer()?;
+            for child in &list {
+                list_writer.write(child?.with_symbol_ids())?;
+            }
+            list_writer.close()
+        }
+        ValueRef::SExp(sexp) => {
+            let mut sexp_writer = writer.sexp_writer()?;
+            for child in &sexp {
+                sexp_writer.write(child?.with_symbol_ids())?;
+            }
+            sexp_writer.close()
+        }
+        ValueRef::Struct(strukt) => {
+            let mut struct_writer = writer.struct_writer()?;
+            for field in &strukt {
+                let field = field?;
+                struct_writer.write(field.raw_name()?, field.value().with_symbol_ids())?;
+            }
+            struct_writer.close()
+        }
+        other => other.write_as_ion(writer),
+    }
+}
+
 impl<'a, D: Decoder> WriteAsIon for ValueRef<'a, D> {
     fn write_as_ion<V: ValueWriter>(&self, value_writer: V) -> IonResult<()> {
         use ValueRef::*;
@@ -1,10 +1,16 @@
-use std::io::Write;
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom, Write};
 
+use bumpalo::Bump as BumpAllocator;
 use delegate::delegate;
 use ice_code::ice as cold_path;
 
-use crate::constants::v1_0::system_symbol_ids;
+use crate::constants::v1_0::{system_symbol_ids, SYSTEM_SYMBOLS};
+use crate::lazy::decoder::Decoder;
 use crate::lazy::encoder::annotation_seq::AnnotationSeq;
+use crate::lazy::encoder::binary::v1_0::seeking_writer::LazyRawSeekingBinaryWriter_1_0;
+use crate::lazy::encoder::lob_writer;
+use crate::lazy::encoder::lob_writer::LobFraming;
 use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
 use crate::lazy::encoder::value_writer::{
     AnnotatableWriter, EExpWriter, SequenceWriter, StructWriter, ValueWriter,
@@ -12,22 +18,41 @@ use crate::lazy::encoder::value_writer::{
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::{LazyRawWriter, SymbolCreationPolicy};
 use crate::lazy::encoding::{
-    BinaryEncoding_1_0, BinaryEncoding_1_1, Encoding, TextEncoding_1_0, TextEncoding_1_1,
+    BinaryEncoding_1_0, BinaryEncoding_1_1, Encoding, EncodingWithMacroSupport, TextEncoding_1_0,
+    TextEncoding_1_1,
 };
-use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
+use crate::lazy::expanded::compiler::TemplateCompiler;
+use crate::lazy::expanded::macro_table::MacroTable;
+use crate::lazy::expanded::EncodingContext as CompileContext;
+use crate::lazy::text::raw::v1_1::reader::{MacroAddress, MacroIdRef};
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
-use crate::write_config::WriteConfig;
+use crate::write_config::{SymbolStyle, WriteConfig};
 use crate::{
-    Decimal, Element, ElementWriter, Int, IonResult, IonType, RawSymbolRef, Symbol, SymbolId,
-    SymbolTable, Timestamp, Value,
+    ion_sexp, Decimal, Element, ElementWriter, Int, IonResult, IonType, LazyValue, RawSymbolRef,
+    SharedSymbolTable, Symbol, SymbolId, SymbolTable, Timestamp, Value,
 };
 
+// The annotation that identifies an Ion 1.1 encoding directive
+const ION_ENCODING: &str = "$ion_encoding";
+
+// The number of top-level values that `Writer::write_all_elements` writes between flushes
+const WRITE_ELEMENTS_FLUSH_INTERVAL: usize = 1024;
+
 pub(crate) struct EncodingContext {
     symbol_table: SymbolTable,
     num_pending_symbols: usize,
+    // If `true`, the next LST will define the symbol table from scratch instead of appending to it.
+    replaces_symbol_table: bool,
     symbol_creation_policy: SymbolCreationPolicy,
     supports_text_tokens: bool,
+    symbol_styles: HashMap<String, SymbolStyle>,
+    // The macros that the writer can invoke. Macros are added using `Writer::register_template`.
+    macro_table: MacroTable,
+    // The shared symbol tables that are imported at the beginning of each stream.
+    imports: Vec<SharedSymbolTable>,
+    // If `true`, the next LST will import the shared symbol tables in `imports`.
+    declares_imports: bool,
 }
 
 impl EncodingContext {
@@ -35,12 +60,60 @@ impl EncodingContext {
         symbol_table: SymbolTable,
         symbol_creation_policy: SymbolCreationPolicy,
         supports_text_tokens: bool,
+        symbol_styles: HashMap<String, SymbolStyle>,
+        imports: Vec<SharedSymbolTable>,
     ) -> Self {
-        Self {
+        let mut context = Self {
             symbol_table,
             num_pending_symbols: 0,
+            replaces_symbol_table: false,
             symbol_creation_policy,
             supports_text_tokens,
+            symbol_styles,
+            macro_table: MacroTable::new(),
+            imports,
+            declares_imports: false,
+        };
+        context.add_imported_symbols();
+        context
+    }
+
+    /// Adds the symbols of each imported shared symbol table to the symbol table (which must only
+    /// contain the system symbols) and makes a note to declare the imports in the next LST.
+    fn add_imported_symbols(&mut self) {
+        for table in &self.imports {
+            for symbol in table.symbols() {
+                match symbol.text() {
+                    Some(_) => self.symbol_table.add_symbol(symbol.clone()),
+                    None => self.symbol_table.add_placeholder(),
+                };
+            }
+        }
+        self.declares_imports = !self.imports.is_empty();
+    }
+
+    /// Returns `true` if a symbol with the given text should be written as inline text rather
+    /// than as a symbol ID.
+    fn writes_inline_text(&self, text: &str) -> bool {
+        if !self.supports_text_tokens {
+            return false;
+        }
+        match self.symbol_styles.get(text) {
+            Some(SymbolStyle::Text | SymbolStyle::QuotedText) => true,
+            Some(SymbolStyle::SymbolId) => false,
+            None => self.symbol_creation_policy == SymbolCreationPolicy::WriteProvidedToken,
+        }
+    }
+
+    /// Returns the symbol ID for `text`, adding it to the symbol table (and making a note to
+    /// add it to the LST on the next call to `flush()`) if necessary.
+    fn sid_for_text(&mut self, text: &str) -> SymbolId {
+        match self.symbol_table.sid_for(&text) {
+            Some(sid) => sid,
+            None => {
+                self.num_pending_symbols += 1;
+                self.symbol_table.add_symbol_for_text(text)
+            }
         }
     }
 }
@@ -50,6 +123,14 @@ pub struct Writer<E: Encoding, Output: Write> {
     encoding_context: EncodingContext,
     data_writer: E::Writer<Vec<u8>>,
     directive_writer: E::Writer<Vec<u8>>,
+    // The bytes (if any) that the encoding writes at the beginning of a stream, like an IVM.
+    stream_header: Vec<u8>,
+    // The ID and length of the symbol table that was most recently passed to `sync_symbol_table`,
+    // which `write_with_symbol_ids` uses to skip synchronizing with a table that has not changed.
+    synced_symbol_table: Option<(usize, usize)>,
+    // Whether symbol tables and encoding directives may be written; see
+    // `WriteConfig::with_system_values`.
+    writes_system_values: bool,
     output: Output,
 }
 
@@ -62,7 +143,12 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
     /// Constructs a writer for the requested encoding using the provided configuration.
     pub fn new(config: impl Into<WriteConfig<E>>, output: Output) -> IonResult<Self> {
         let config = config.into();
+        let symbol_styles = config.symbol_styles.clone();
+        let imports = config.symbol_table_imports.clone();
+        let symbol_creation_policy = config.symbol_creation_policy();
+        let writes_system_values = config.writes_system_values();
         let directive_writer = E::Writer::build(config.clone(), vec![])?;
+        let stream_header = directive_writer.output().clone();
         let mut data_writer = E::Writer::build(config, vec![])?;
         // Erase the IVM that's created by default
         data_writer.output_mut().clear();
@@ -70,19 +156,89 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
         let symbol_table = SymbolTable::new();
         let encoding_context = EncodingContext::new(
             symbol_table,
-            E::DEFAULT_SYMBOL_CREATION_POLICY,
+            symbol_creation_policy,
             E::SUPPORTS_TEXT_TOKENS,
+            symbol_styles,
+            imports,
         );
         let mut writer = Writer {
             encoding_context,
             data_writer,
             directive_writer,
+            stream_header,
+            synced_symbol_table: None,
+            writes_system_values,
             output,
         };
         writer.flush()?;
         Ok(writer)
     }
 
+    /// Discards any values that have been written since the last call to [`flush`](Self::flush)
+    /// and begins a new stream on the same output, allowing the writer's buffers to be reused.
+    ///
+    /// The new stream starts with the encoding's version marker (if it has one) and an empty
+    /// symbol table, so symbols and macros defined before the reset are forgotten and the values
+    /// that follow can be read without any of the data that preceded them.
+    pub fn reset(&mut self) -> IonResult<()> {
+        // Flushing the raw writers clears their internal state; their output is then discarded.
+        self.data_writer.flush()?;
+        self.data_writer.output_mut().clear();
+        self.directive_writer.flush()?;
+        self.directive_writer.output_mut().clear();
+
+        let context = &mut self.encoding_context;
+        let previous_len = context.symbol_table.len();
+        context.symbol_table.reset();
+        let had_user_symbols = previous_len > context.symbol_table.len();
+        context.num_pending_symbols = 0;
+        // Without a version marker, a reader would carry the old symbols into the new stream.
+        // Make sure the next symbol table replaces them.
+        context.replaces_symbol_table = self.stream_header.is_empty() && had_user_symbols;
+        context.macro_table = MacroTable::new();
+        context.add_imported_symbols();
+        self.synced_symbol_table = None;
+
+        self.directive_writer
+            .output_mut()
+            .extend_from_slice(&self.stream_header);
+        self.flush()
+    }
+
+    /// Flushes the current stream to this writer's output and then replaces the output with
+    /// `output`, returning the previous one. The writer is [reset](Self::reset) so that `output`
+    /// receives a complete stream of its own.
+    ///
+    /// This allows an application that encodes many small documents to reuse a single writer
+    /// instead of constructing a new one for each document.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::v1_0::Binary;
+    /// use ion_rs::{Element, Writer};
+    ///
+    /// let mut writer = Writer::new(Binary, Vec::new())?;
+    /// writer.write("foo")?;
+    /// let first = writer.reuse(Vec::new())?;
+    /// writer.write("bar")?;
+    /// let second = writer.close()?;
+    ///
+    /// assert_eq!(Element::read_one(first)?, Element::from("foo"));
+    /// assert_eq!(Element::read_one(second)?, Element::from("bar"));
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn reuse(&mut self, output: Output) -> IonResult<Output> {
+        self.flush()?;
+        let previous = std::mem::replace(&mut self.output, output);
+        self.reset()?;
+        Ok(previous)
+    }
+
     pub fn output(&self) -> &Output {
         &self.output
     }
@@ -98,11 +254,77 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
         <Self as SequenceWriter>::write(self, value)
     }
 
+    /// Writes each of the provided values at the top level of the stream as an [`Element`] and then
+    /// flushes the writer. Values may be `Element`s or anything else that can be converted into one.
+    /// (References to `Element`s are cloned; [`ElementWriter::write_elements`] writes them
+    /// without cloning, but does not flush.)
+    ///
+    /// The writer is also flushed periodically while the values are being written, so exporting a
+    /// long sequence of values does not buffer the entire encoding in memory.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::v1_0::Binary;
+    /// use ion_rs::{ion_struct, Element, Writer};
+    ///
+    /// let mut writer = Writer::new(Binary, Vec::new())?;
+    /// writer.write_all_elements((0..3).map(|id| ion_struct! {"id": id}))?;
+    ///
+    /// let elements = Element::read_all(writer.output())?;
+    /// assert_eq!(elements, Element::read_all("{id: 0} {id: 1} {id: 2}")?);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn write_all_elements<I>(&mut self, values: I) -> IonResult<&mut Self>
+    where
+        I: IntoIterator,
+        I::Item: Into<Element>,
+    {
+        for (index, value) in values.into_iter().enumerate() {
+            self.write(&value.into())?;
+            if (index + 1) % WRITE_ELEMENTS_FLUSH_INTERVAL == 0 {
+                self.flush()?;
+            }
+        }
+        self.flush()?;
+        Ok(self)
+    }
+
+    /// Flushes any buffered values and then writes a top-level lob directly to the output,
+    /// streaming its `length`-byte payload from `source`.
+    fn write_lob_from_reader(
+        &mut self,
+        framing: LobFraming,
+        ion_type: IonType,
+        length: u64,
+        source: impl Read,
+    ) -> IonResult<&mut Self> {
+        // Values that were written earlier must reach the output ahead of the lob.
+        self.flush()?;
+        lob_writer::write_lob_from_reader(&mut self.output, framing, ion_type, length, source)?;
+        Ok(self)
+    }
+
     /// Writes bytes of previously encoded values to the output stream.
     pub fn flush(&mut self) -> IonResult<()> {
-        if self.encoding_context.num_pending_symbols > 0 {
+        if self.encoding_context.num_pending_symbols > 0
+            || self.encoding_context.replaces_symbol_table
+            || self.encoding_context.declares_imports
+        {
+            if !self.writes_system_values {
+                return IonResult::illegal_operation(
+                    "the values written require a symbol table, but the writer was configured \
+                     not to write system values",
+                );
+            }
             self.write_lst_append()?;
             self.encoding_context.num_pending_symbols = 0;
+            self.encoding_context.replaces_symbol_table = false;
+            self.encoding_context.declares_imports = false;
         }
 
         self.directive_writer.flush()?;
@@ -122,7 +344,138 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
         Ok(self.output)
     }
 
-    /// Helper method to encode an LST append containing pending symbols.
+    /// Makes this writer's symbol table match the provided `symbol_table`, which is typically the
+    /// symbol table of a reader whose values are being rewritten. Once the tables match, symbol
+    /// IDs read from the input (see [`LazyValue::with_symbol_ids`](crate::LazyValue::with_symbol_ids))
+    /// can be written as-is and will refer to the same text.
+    ///
+    /// If this writer's symbol table is a prefix of `symbol_table`, the missing symbols will be
+    /// appended to it in the next local symbol table. Otherwise, any buffered data is flushed and
+    /// the next local symbol table replaces the writer's symbol table entirely.
+    ///
+    /// This compares the two tables symbol by symbol, so it should only be called when the input's
+    /// symbol table may have changed.
+    pub fn sync_symbol_table(&mut self, symbol_table: &SymbolTable) -> IonResult<()> {
+        let target = symbol_table.symbols();
+        if !target.starts_with(self.encoding_context.symbol_table.symbols()) {
+            self.flush()?;
+            let context = &mut self.encoding_context;
+            context.symbol_table.reset();
+            // The target table's symbols will be defined locally instead of imported.
+            context.declares_imports = false;
+            if !target.starts_with(context.symbol_table.symbols()) {
+                return IonResult::illegal_operation(
+                    "cannot synchronize with a symbol table that does not begin with the system symbols",
+                );
+            }
+            context.replaces_symbol_table = true;
+        }
+
+        let context = &mut self.encoding_context;
+        for symbol in &target[context.symbol_table.len()..] {
+            match symbol.text() {
+                Some(_) => context.symbol_table.add_symbol(symbol.clone()),
+                None => context.symbol_table.add_placeholder(),
+            };
+            context.num_pending_symbols += 1;
+        }
+        self.synced_symbol_table = Some((symbol_table.id(), symbol_table.len()));
+        Ok(())
+    }
+
+    /// Returns a shared symbol table with the given `name` and `version` that contains the
+    /// symbols in this writer's symbol table, including those of any tables it imports.
+    ///
+    /// Passing the result to [`WriteConfig::with_symbol_table_import`] allows later writers to
+    /// encode the same symbols without defining them again in each stream. Readers of those
+    /// streams need the table in their [`Catalog`](crate::Catalog); it can be distributed to them
+    /// separately or written once as an Ion value.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::v1_0::Binary;
+    /// use ion_rs::{
+    ///     AnyEncoding, Decoder, Element, ElementReader, MapCatalog, Reader, WriteConfig, Writer,
+    /// };
+    ///
+    /// let record = Element::read_one("{name: \"Alice\", status: active}")?;
+    ///
+    /// let mut writer = Writer::new(Binary, Vec::new())?;
+    /// writer.write(&record)?.flush()?;
+    /// let symbols = writer.export_symbols("records", 1)?;
+    ///
+    /// let config = WriteConfig::<Binary>::new().with_symbol_table_import(symbols.clone());
+    /// let mut writer = Writer::new(config, Vec::new())?;
+    /// writer.write(&record)?;
+    /// let encoded = writer.close()?;
+    /// // The symbols are imported rather than defined in the stream
+    /// assert!(!encoded.windows(6).any(|bytes| bytes == b"active"));
+    ///
+    /// let mut catalog = MapCatalog::new();
+    /// catalog.insert_table(symbols);
+    /// let mut reader = Reader::new(AnyEncoding.with_catalog(catalog), encoded)?;
+    /// assert_eq!(reader.read_next_element()?, Some(record));
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn export_symbols(
+        &self,
+        name: impl Into<String>,
+        version: usize,
+    ) -> IonResult<SharedSymbolTable> {
+        let symbols = self.encoding_context.symbol_table.symbols()[SYSTEM_SYMBOLS.len()..].to_vec();
+        SharedSymbolTable::new(name, version, symbols)
+    }
+
+    /// Writes `value` using the symbol IDs found in its encoding, first synchronizing this
+    /// writer's symbol table with the value's symbol table (see [`Self::sync_symbol_table`]).
+    /// The tables are only compared when the value's symbol table has been reset or extended
+    /// since the last synchronization.
+    ///
+    /// This allows tools that rewrite a stream to re-emit each value without re-encoding its
+    /// symbols. Note that the local symbol tables themselves are written by this writer, so they
+    /// may not be byte-for-byte identical to the ones in the input.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::v1_0::Binary;
+    /// use ion_rs::{Reader, Writer};
+    ///
+    /// let mut writer = Writer::new(Binary, Vec::new())?;
+    /// writer.write(&["foo", "bar"])?.write("bar")?;
+    /// let input = writer.close()?;
+    ///
+    /// let mut reader = Reader::new(Binary, &input)?;
+    /// let mut writer = Writer::new(Binary, Vec::new())?;
+    /// while let Some(value) = reader.next()? {
+    ///     writer.write_with_symbol_ids(value)?;
+    /// }
+    /// assert_eq!(writer.close()?, input);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn write_with_symbol_ids<D: Decoder>(
+        &mut self,
+        value: LazyValue<'_, D>,
+    ) -> IonResult<&mut Self> {
+        let symbol_table = value.symbol_table();
+        if self.synced_symbol_table != Some((symbol_table.id(), symbol_table.len())) {
+            self.sync_symbol_table(symbol_table)?;
+        }
+        self.write(value.with_symbol_ids())
+    }
+
+    /// Helper method to encode an LST append containing pending symbols. If the symbol table is
+    /// being replaced, the LST defines the pending symbols without importing the current table.
+    /// At the beginning of a stream, the LST imports any shared symbol tables instead.
     fn write_lst_append(&mut self) -> IonResult<()> {
         let Self {
             encoding_context,
@@ -138,8 +491,21 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
             .with_annotations(system_symbol_ids::ION_SYMBOL_TABLE)?
             .struct_writer()?;
 
-        lst.field_writer(system_symbol_ids::IMPORTS)
-            .write_symbol(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        if encoding_context.declares_imports {
+            let mut imports = lst.field_writer(system_symbol_ids::IMPORTS).list_writer()?;
+            for table in &encoding_context.imports {
+                let mut import = imports.struct_writer()?;
+                import
+                    .write(system_symbol_ids::NAME, table.name())?
+                    .write(system_symbol_ids::VERSION, table.version())?
+                    .write(system_symbol_ids::MAX_ID, table.symbols().len())?;
+                import.close()?;
+            }
+            imports.close()?;
+        } else if !encoding_context.replaces_symbol_table {
+            lst.field_writer(system_symbol_ids::IMPORTS)
+                .write_symbol(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        }
 
         let mut new_symbol_list = lst.field_writer(system_symbol_ids::SYMBOLS).list_writer()?;
 
@@ -156,6 +522,362 @@ impl<E: Encoding, Output: Write> Writer<E, Output> {
     }
 }
 
+impl<Output: Write> Writer<BinaryEncoding_1_0, Output> {
+    /// Writes a top-level blob whose `length`-byte payload is read from `source`, which might be
+    /// a file or a socket. The payload is copied to the output a chunk at a time, so it is never
+    /// held in memory all at once.
+    ///
+    /// Any values that were written earlier are flushed first. Exactly `length` bytes are read
+    /// from `source`; if it ends sooner, an error is returned and the output is left holding an
+    /// incomplete value.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use std::io::Cursor;
+    /// use ion_rs::v1_0::Binary;
+    /// use ion_rs::{Element, Writer};
+    ///
+    /// let payload = vec![7u8; 100_000];
+    /// let mut writer = Writer::new(Binary, Vec::new())?;
+    /// writer
+    ///     .write("before")?
+    ///     .write_blob_from_reader(payload.len() as u64, Cursor::new(&payload))?
+    ///     .write("after")?;
+    ///
+    /// let elements = Element::read_all(writer.close()?)?;
+    /// assert_eq!(elements[1], Element::blob(payload));
+    /// assert_eq!(elements[2], Element::from("after"));
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn write_blob_from_reader(
+        &mut self,
+        length: u64,
+        source: impl Read,
+    ) -> IonResult<&mut Self> {
+        self.write_lob_from_reader(LobFraming::Binary_1_0, IonType::Blob, length, source)
+    }
+
+    /// Writes a top-level clob whose `length`-byte payload is read from `source`. See
+    /// [`write_blob_from_reader`](Self::write_blob_from_reader).
+    pub fn write_clob_from_reader(
+        &mut self,
+        length: u64,
+        source: impl Read,
+    ) -> IonResult<&mut Self> {
+        self.write_lob_from_reader(LobFraming::Binary_1_0, IonType::Clob, length, source)
+    }
+
+    /// Writes NOP padding that occupies exactly `size` bytes. Readers skip over NOP padding, so it
+    /// can be used to align the values that follow or to reserve space that will later be
+    /// overwritten in place.
+    ///
+    /// Any local symbol table needed by buffered values is written ahead of them when the writer
+    /// is flushed, so offsets should be computed after calling [`flush`](Self::flush).
+    pub fn write_nop_pad(&mut self, size: usize) -> IonResult<&mut Self> {
+        self.data_writer.write_nop_pad(size)?;
+        Ok(self)
+    }
+
+    /// Writes `value` followed by as much NOP padding as is needed for the two to occupy exactly
+    /// `width` bytes. This allows fixed-width records to be written and later patched in place.
+    /// If the encoded value is larger than `width`, nothing is written and an error is returned.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::v1_0::Binary;
+    /// use ion_rs::{Element, Writer};
+    ///
+    /// let mut writer = Writer::new(Binary, Vec::new())?;
+    /// let start = writer.output().len();
+    /// writer.write_padded(1, 16)?.write_padded("two", 16)?.flush()?;
+    /// assert_eq!(writer.output().len() - start, 32);
+    /// assert!(writer.write_padded("a long string value", 16).is_err());
+    ///
+    /// let values = Element::read_all(writer.close()?)?;
+    /// assert_eq!(values, Element::read_all("1 \"two\"")?);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn write_padded<V: WriteAsIon>(&mut self, value: V, width: usize) -> IonResult<&mut Self> {
+        let Self {
+            encoding_context,
+            data_writer,
+            ..
+        } = self;
+        data_writer.pad_to_width(width, |writer| {
+            value.write_as_ion(ApplicationValueWriter::new(
+                encoding_context,
+                writer.value_writer(),
+            ))
+        })?;
+        Ok(self)
+    }
+}
+
+impl<Output: Write + Seek> Writer<BinaryEncoding_1_0, Output> {
+    /// Writes `value` at the top level directly to the output instead of encoding it in memory
+    /// first. Space is reserved for the length of each container, and the writer seeks back to
+    /// fill it in when the container is closed (see [`LazyRawSeekingBinaryWriter_1_0`]). This
+    /// keeps memory use flat when writing very large containers to a seekable output like a file.
+    ///
+    /// Any values that were written earlier are flushed first. Because the local symbol table
+    /// must precede the values that use its symbols, `value` is encoded twice: once to add its
+    /// symbols to the symbol table, and once to write it. It must write the same data both
+    /// times, so it cannot stream lob payloads from an `io::Read` source.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use std::io::Cursor;
+    /// use ion_rs::v1_0::Binary;
+    /// use ion_rs::{Element, Writer};
+    ///
+    /// let header = Element::read_one("{sensor: \"a\", unit: celsius}")?;
+    /// let readings: Vec<i64> = (0..10_000).collect();
+    /// let mut writer = Writer::new(Binary, Cursor::new(Vec::new()))?;
+    /// writer
+    ///     .write_unbuffered(&header)?
+    ///     .write_unbuffered(readings.as_slice())?;
+    ///
+    /// let values = Element::read_all(writer.close()?.into_inner())?;
+    /// assert_eq!(values[0], header);
+    /// assert_eq!(values[1].as_sequence().unwrap().len(), 10_000);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn write_unbuffered<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
+        // Encode the value without keeping its encoding, adding any new symbols it uses to the
+        // symbol table.
+        let mut symbol_collector =
+            LazyRawSeekingBinaryWriter_1_0::without_version_marker(DiscardedOutput::default())?;
+        value.write_as_ion(ApplicationValueWriter::new(
+            &mut self.encoding_context,
+            symbol_collector.make_value_writer(),
+        ))?;
+        // Write the symbol table that defines them, then the value itself.
+        self.flush()?;
+        let mut raw_writer =
+            LazyRawSeekingBinaryWriter_1_0::without_version_marker(&mut self.output)?;
+        value.write_as_ion(ApplicationValueWriter::new(
+            &mut self.encoding_context,
+            raw_writer.make_value_writer(),
+        ))?;
+        raw_writer.flush()?;
+        Ok(self)
+    }
+}
+
+/// A seekable output that discards the bytes written to it, keeping track only of its length.
+#[derive(Default)]
+struct DiscardedOutput {
+    position: u64,
+    length: u64,
+}
+
+impl Write for DiscardedOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.position += buf.len() as u64;
+        self.length = self.length.max(self.position);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for DiscardedOutput {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.length.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        self.position = position.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "cannot seek before the beginning of the output",
+            )
+        })?;
+        Ok(self.position)
+    }
+}
+
+impl<Output: Write> Writer<BinaryEncoding_1_1, Output> {
+    /// Writes a top-level blob whose `length`-byte payload is read from `source`. See
+    /// [`Writer::<BinaryEncoding_1_0, _>::write_blob_from_reader`](Writer::write_blob_from_reader).
+    pub fn write_blob_from_reader(
+        &mut self,
+        length: u64,
+        source: impl Read,
+    ) -> IonResult<&mut Self> {
+        self.write_lob_from_reader(LobFraming::Binary_1_1, IonType::Blob, length, source)
+    }
+
+    /// Writes a top-level clob whose `length`-byte payload is read from `source`. See
+    /// [`Writer::<BinaryEncoding_1_0, _>::write_blob_from_reader`](Writer::write_blob_from_reader).
+    pub fn write_clob_from_reader(
+        &mut self,
+        length: u64,
+        source: impl Read,
+    ) -> IonResult<&mut Self> {
+        self.write_lob_from_reader(LobFraming::Binary_1_1, IonType::Clob, length, source)
+    }
+
+    /// Writes NOP padding that occupies exactly `size` bytes. See
+    /// [`Writer::<BinaryEncoding_1_0, _>::write_nop_pad`](Writer::write_nop_pad).
+    pub fn write_nop_pad(&mut self, size: usize) -> IonResult<&mut Self> {
+        self.data_writer.write_nop_pad(size)?;
+        Ok(self)
+    }
+
+    /// Writes `value` followed by as much NOP padding as is needed for the two to occupy exactly
+    /// `width` bytes. If the encoded value is larger than `width`, nothing is written and an
+    /// error is returned.
+    pub fn write_padded<V: WriteAsIon>(&mut self, value: V, width: usize) -> IonResult<&mut Self> {
+        let Self {
+            encoding_context,
+            data_writer,
+            ..
+        } = self;
+        data_writer.pad_to_width(width, |writer| {
+            value.write_as_ion(ApplicationValueWriter::new(
+                encoding_context,
+                writer.value_writer(),
+            ))
+        })?;
+        Ok(self)
+    }
+}
+
+impl<Output: Write> Writer<TextEncoding_1_0, Output> {
+    /// Writes a top-level blob whose `length`-byte payload is read from `source`. The payload is
+    /// base64-encoded a chunk at a time, honoring the configured blob line width. See
+    /// [`Writer::<BinaryEncoding_1_0, _>::write_blob_from_reader`](Writer::write_blob_from_reader).
+    pub fn write_blob_from_reader(
+        &mut self,
+        length: u64,
+        source: impl Read,
+    ) -> IonResult<&mut Self> {
+        let framing = self.data_writer.lob_framing();
+        self.write_lob_from_reader(framing, IonType::Blob, length, source)
+    }
+
+    /// Writes a top-level clob whose `length`-byte payload is read from `source`. See
+    /// [`Writer::<BinaryEncoding_1_0, _>::write_blob_from_reader`](Writer::write_blob_from_reader).
+    pub fn write_clob_from_reader(
+        &mut self,
+        length: u64,
+        source: impl Read,
+    ) -> IonResult<&mut Self> {
+        let framing = self.data_writer.lob_framing();
+        self.write_lob_from_reader(framing, IonType::Clob, length, source)
+    }
+}
+
+impl<Output: Write> Writer<TextEncoding_1_1, Output> {
+    /// Writes a top-level blob whose `length`-byte payload is read from `source`. See
+    /// [`Writer::<TextEncoding_1_0, _>::write_blob_from_reader`](Writer::write_blob_from_reader).
+    pub fn write_blob_from_reader(
+        &mut self,
+        length: u64,
+        source: impl Read,
+    ) -> IonResult<&mut Self> {
+        let framing = self.data_writer.writer_1_0.lob_framing();
+        self.write_lob_from_reader(framing, IonType::Blob, length, source)
+    }
+
+    /// Writes a top-level clob whose `length`-byte payload is read from `source`. See
+    /// [`Writer::<TextEncoding_1_0, _>::write_blob_from_reader`](Writer::write_blob_from_reader).
+    pub fn write_clob_from_reader(
+        &mut self,
+        length: u64,
+        source: impl Read,
+    ) -> IonResult<&mut Self> {
+        let framing = self.data_writer.writer_1_0.lob_framing();
+        self.write_lob_from_reader(framing, IonType::Clob, length, source)
+    }
+}
+
+impl<E: Encoding + EncodingWithMacroSupport, Output: Write> Writer<E, Output> {
+    /// Compiles the provided template macro definition and adds it to the writer's macro table,
+    /// returning the address at which it can be invoked. The definition is written to the output
+    /// in an encoding directive that appends it to the reader's macro table, so it can be invoked
+    /// by name or address using [`ValueWriter::eexp_writer`] for the rest of the stream.
+    ///
+    /// The template body can invoke any macro that was registered before it.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::v1_1::Binary;
+    /// use ion_rs::{ElementReader, Reader, SequenceWriter, ValueWriter, Writer};
+    ///
+    /// let mut writer = Writer::new(Binary, Vec::new())?;
+    /// writer.register_template("(macro point (x y) {x: x, y: y})")?;
+    /// for i in 0..3 {
+    ///     let mut point = writer.value_writer().eexp_writer("point")?;
+    ///     point.write(i)?.write(i * 10)?;
+    ///     point.close()?;
+    /// }
+    /// let encoded = writer.close()?;
+    ///
+    /// let mut reader = Reader::new(Binary, &encoded)?;
+    /// let point = reader.expect_next()?.read()?.expect_struct()?;
+    /// assert_eq!(point.get_expected("y")?.expect_i64()?, 0);
+    /// assert_eq!(reader.read_all_elements()?.len(), 2);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn register_template(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
+        if !self.writes_system_values {
+            return IonResult::illegal_operation(
+                "cannot register a template because the writer was configured not to write \
+                 system values",
+            );
+        }
+        // Compile the template to validate it against the macros defined so far.
+        let macro_table = std::mem::take(&mut self.encoding_context.macro_table);
+        let compile_context =
+            CompileContext::new(macro_table, SymbolTable::new(), BumpAllocator::new());
+        let template =
+            TemplateCompiler::compile_from_text(compile_context.get_ref(), template_definition);
+        self.encoding_context.macro_table = compile_context.macro_table;
+        let address = self.encoding_context.macro_table.add_macro(template?)?;
+
+        // Append the definition to the reader's macro table, leaving its symbol table unchanged.
+        let definition = Element::read_one(template_definition)?;
+        let symbol_table_clause: Element =
+            ion_sexp!(Symbol::from("symbol_table") Symbol::from(ION_ENCODING)).into();
+        let macro_table_clause: Element =
+            ion_sexp!(Symbol::from("macro_table") Symbol::from(ION_ENCODING) definition).into();
+        let mut directive = self
+            .directive_writer
+            .value_writer()
+            .with_annotations(ION_ENCODING)?
+            .sexp_writer()?;
+        directive.write_element(&symbol_table_clause)?;
+        directive.write_element(&macro_table_clause)?;
+        directive.close()?;
+        Ok(address)
+    }
+}
+
 impl<E: Encoding, Output: Write> MakeValueWriter for Writer<E, Output> {
     type ValueWriter<'a> = ApplicationValueWriter<'a, <E::Writer<Vec<u8>> as MakeValueWriter>::ValueWriter<'a>>
     where
@@ -202,41 +924,35 @@ impl<'value, V: ValueWriter> AnnotatableWriter for ApplicationValueWriter<'value
     type AnnotatedValueWriter<'a> = ApplicationValueWriter<'a, V::AnnotatedValueWriter<'a>> where Self: 'a;
 
     fn with_annotations<'a>(
-        mut self,
+        self,
         annotations: impl AnnotationSeq<'a>,
     ) -> IonResult<Self::AnnotatedValueWriter<'a>>
     where
         Self: 'a,
     {
-        if self.encoding.symbol_creation_policy == SymbolCreationPolicy::WriteProvidedToken {
-            // Store the tokens as they are. Text will be written as text, symbol IDs will be written
-            // as symbol IDs. TODO: Lookup SIDs to see if they have text?
-            return Ok(ApplicationValueWriter {
-                encoding: self.encoding,
-                raw_value_writer: self.raw_value_writer.with_annotations(annotations)?,
-            });
-        }
-
-        // Otherwise, we're going to write everything as a symbol ID. Replace all text tokens in the
-        // annotations with the corresponding symbol ID, creating a new one if necessary.
+        // If the writer is configured to store text tokens as they are, text will be written as
+        // text. Otherwise, replace each text token that won't be written inline with the
+        // corresponding symbol ID, creating a new one if necessary. Symbol IDs are always written
+        // as symbol IDs once they have been bounds checked.
+        let writes_provided_tokens = self.encoding.symbol_creation_policy
+            == SymbolCreationPolicy::WriteProvidedToken
+            && self.encoding.symbol_styles.is_empty();
         let mut annotations = annotations.into_annotations_vec();
         for annotation in &mut annotations {
-            let sid: SymbolId = match annotation.as_raw_symbol_token_ref() {
-                // The token is already a symbol ID.
-                RawSymbolRef::SymbolId(sid) => sid,
-                // The token is text...
+            match annotation.as_raw_symbol_token_ref() {
+                RawSymbolRef::SymbolId(symbol_id) => {
+                    if !self.encoding.symbol_table.sid_is_valid(symbol_id) {
+                        return cold_path!(IonResult::encoding_error(format!(
+                            "annotation symbol ID ${symbol_id} is out of bounds"
+                        )));
+                    }
+                }
                 RawSymbolRef::Text(text) => {
-                    if let Some(sid) = self.symbol_table().sid_for(&text) {
-                        //...that was already in the symbol table.
-                        sid
-                    } else {
-                        // ...that we need to add to the symbol table.
-                        self.encoding.num_pending_symbols += 1;
-                        self.symbol_table().add_symbol_for_text(text)
+                    if !writes_provided_tokens && !self.encoding.writes_inline_text(text) {
+                        *annotation = RawSymbolRef::SymbolId(self.encoding.sid_for_text(text));
                     }
                 }
-            };
-            *annotation = RawSymbolRef::SymbolId(sid);
+            }
         }
 
         Ok(ApplicationValueWriter {
@@ -266,6 +982,8 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
             fn write_string(self, value: impl AsRef<str>) -> IonResult<()>;
             fn write_clob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
             fn write_blob(self, value: impl AsRef<[u8]>) -> IonResult<()>;
+            fn write_blob_from_reader(self, length: u64, source: impl std::io::Read) -> IonResult<()>;
+            fn write_clob_from_reader(self, length: u64, source: impl std::io::Read) -> IonResult<()>;
         }
     }
 
@@ -285,25 +1003,12 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
         };
 
         // If the writer can write it as inline text, do so.
-        if self.encoding.supports_text_tokens
-            && self.encoding.symbol_creation_policy == SymbolCreationPolicy::WriteProvidedToken
-        {
+        if self.encoding.writes_inline_text(text) {
             return self.raw_value_writer.write_symbol(text);
         }
 
-        // Otherwise, see if the symbol is already in the symbol table.
-        let symbol_id = match self.symbol_table().sid_for(&text) {
-            // If so, use the existing ID.
-            Some(sid) => sid,
-            // If not, add it to the symbol table and make a note to add it to the LST on the next
-            // call to `flush()`. Use the new ID.
-            None => {
-                self.encoding.num_pending_symbols += 1;
-                self.symbol_table().add_symbol_for_text(text)
-            }
-        };
-
-        // Finally, write out the SID.
+        // Otherwise, write out the symbol's SID.
+        let symbol_id = self.encoding.sid_for_text(text);
         self.raw_value_writer.write_symbol(symbol_id)
     }
 
@@ -329,6 +1034,31 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
     }
 
     fn eexp_writer<'a>(self, macro_id: impl Into<MacroIdRef<'a>>) -> IonResult<Self::EExpWriter> {
+        let macro_id = macro_id.into();
+        let address = match macro_id {
+            MacroIdRef::LocalName(name) => self.encoding.macro_table.address_for_name(name),
+            MacroIdRef::LocalAddress(address) => self
+                .encoding
+                .macro_table
+                .macro_at_address(address)
+                .map(|_| address),
+        };
+        let Some(address) = address else {
+            let description = match macro_id {
+                MacroIdRef::LocalName(name) => format!("named '{name}'"),
+                MacroIdRef::LocalAddress(address) => format!("at address {address}"),
+            };
+            return IonResult::encoding_error(format!(
+                "cannot invoke the macro {description}; it is not in the writer's macro table"
+            ));
+        };
+        // Text encodings can refer to the macro using the ID the caller provided. Binary encodings
+        // always invoke it by address.
+        let macro_id = if V::IS_HUMAN_READABLE {
+            macro_id
+        } else {
+            MacroIdRef::LocalAddress(address)
+        };
         Ok(ApplicationEExpWriter::new(
             self.encoding,
             self.raw_value_writer.eexp_writer(macro_id)?,
@@ -380,25 +1110,12 @@ impl<'value, V: ValueWriter> FieldEncoder for ApplicationStructWriter<'value, V>
         };
 
         // If the writer can write it as inline text, do so.
-        if self.encoding.supports_text_tokens
-            && self.encoding.symbol_creation_policy == SymbolCreationPolicy::WriteProvidedToken
-        {
+        if self.encoding.writes_inline_text(text) {
             return self.raw_struct_writer.encode_field_name(text);
         }
 
-        // Otherwise, see if the symbol is already in the symbol table.
-        let symbol_id = match self.encoding.symbol_table.sid_for(&text) {
-            // If so, use the existing ID.
-            Some(sid) => sid,
-            // If not, add it to the symbol table and make a note to add it to the LST on the next
-            // call to `flush()`. Use the new ID.
-            None => {
-                self.encoding.num_pending_symbols += 1;
-                self.encoding.symbol_table.add_symbol_for_text(text)
-            }
-        };
-
-        // Finally, write out the SID.
+        // Otherwise, write out the field name's SID.
+        let symbol_id = self.encoding.sid_for_text(text);
         self.raw_struct_writer.encode_field_name(symbol_id)
     }
 }
@@ -527,3 +1244,591 @@ impl<S: SequenceWriter> ElementWriter for S {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::lazy::encoder::annotate::Annotatable;
+    use crate::lazy::encoder::value_writer::ValueWriter;
+    use crate::{
+        ion_struct, v1_0, v1_1, AnyEncoding, ElementReader, IonError, MapCatalog, Reader, Sequence,
+        TextFormat,
+    };
+
+    fn write_points<E: Encoding + EncodingWithMacroSupport>(
+        config: impl Into<WriteConfig<E>>,
+    ) -> IonResult<Vec<u8>> {
+        let mut writer = Writer::new(config, Vec::new())?;
+        let address = writer.register_template("(macro point (x y) {x: x, y: y})")?;
+        writer.register_template("(macro origin () (point 0 0))")?;
+        let mut point = writer.value_writer().eexp_writer("point")?;
+        point.write(1)?.write(2)?;
+        point.close()?;
+        let mut point = writer.value_writer().eexp_writer(address)?;
+        point.write(3)?.write(4)?;
+        point.close()?;
+        writer.value_writer().eexp_writer("origin")?.close()?;
+        writer.close()
+    }
+
+    fn expected_points() -> Sequence {
+        Sequence::new([
+            ion_struct! {"x": 1, "y": 2},
+            ion_struct! {"x": 3, "y": 4},
+            ion_struct! {"x": 0, "y": 0},
+        ])
+    }
+
+    #[test]
+    fn binary_macro_invocations_round_trip() -> IonResult<()> {
+        let encoded = write_points(v1_1::Binary)?;
+        let mut reader = Reader::new(v1_1::Binary, encoded)?;
+        assert_eq!(reader.read_all_elements()?, expected_points());
+        Ok(())
+    }
+
+    #[test]
+    fn text_macro_invocations_round_trip() -> IonResult<()> {
+        let encoded = write_points(v1_1::Text)?;
+        let text = String::from_utf8(encoded).unwrap();
+        assert!(text.contains("(:point 1 2)"), "{text}");
+        let mut reader = Reader::new(v1_1::Text, text)?;
+        assert_eq!(reader.read_all_elements()?, expected_points());
+        Ok(())
+    }
+
+    #[test]
+    fn macro_invocations_are_smaller_than_values() -> IonResult<()> {
+        let readings =
+            (0..100).map(|i| Element::from(ion_struct! {"sensor": "thermometer", "reading": i}));
+        let plain = BinaryEncoding_1_1::encode_all(&Sequence::new(readings.clone()))?;
+
+        let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+        writer.register_template(
+            "(macro reading (value) {sensor: \"thermometer\", reading: value})",
+        )?;
+        for i in 0..100 {
+            let mut reading = writer.value_writer().eexp_writer("reading")?;
+            reading.write(i)?;
+            reading.close()?;
+        }
+        let templated = writer.close()?;
+
+        assert!(templated.len() < plain.len() / 2);
+        let mut reader = Reader::new(v1_1::Binary, templated)?;
+        assert_eq!(reader.read_all_elements()?, Sequence::new(readings));
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_macros_cannot_be_invoked() -> IonResult<()> {
+        let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+        assert!(writer.value_writer().eexp_writer("point").is_err());
+        assert!(writer.value_writer().eexp_writer(100).is_err());
+        assert!(writer
+            .register_template("(macro point (x y) (undefined x y))")
+            .is_err());
+        // A failed registration does not disturb the macros that were already defined.
+        writer.register_template("(macro point (x y) [x, y])")?;
+        assert!(writer.register_template("(macro point () [])").is_err());
+        writer
+            .value_writer()
+            .eexp_writer("point")?
+            .write(1)?
+            .write(2)?;
+        Ok(())
+    }
+
+    #[test]
+    fn inline_symbol_text() -> IonResult<()> {
+        let value = Element::read_one("annotation::{field: symbol}")?;
+        let config = WriteConfig::<BinaryEncoding_1_1>::new().with_inline_symbol_text(true);
+        let inline = config.encode(&value)?;
+        let with_sids = BinaryEncoding_1_1::encode(&value)?;
+        // Without a symbol table, the text is only written once.
+        assert!(inline.len() < with_sids.len());
+        for encoded in [inline, with_sids] {
+            let mut reader = Reader::new(v1_1::Binary, encoded)?;
+            assert_eq!(reader.read_all_elements()?, Sequence::new([value.clone()]));
+        }
+        Ok(())
+    }
+
+    fn reuse_starts_new_streams<E: Encoding>(config: impl Into<WriteConfig<E>>) -> IonResult<()> {
+        let config = config.into();
+        let first = Element::read_one("foo::{bar: baz}")?;
+        let second = Element::read_one("[quux, foo]")?;
+
+        let mut writer = Writer::new(config.clone(), Vec::new())?;
+        writer.write(&first)?;
+        let first_output = writer.reuse(Vec::new())?;
+        writer.write(&second)?;
+        let second_output = writer.close()?;
+
+        assert_eq!(Element::read_all(first_output)?, Sequence::new([first]));
+        // The second stream is the same as one written by a new writer.
+        let mut fresh = Writer::new(config, Vec::new())?;
+        fresh.write(&second)?;
+        assert_eq!(second_output, fresh.close()?);
+        assert_eq!(Element::read_all(second_output)?, Sequence::new([second]));
+        Ok(())
+    }
+
+    #[test]
+    fn reuse() -> IonResult<()> {
+        reuse_starts_new_streams(v1_0::Text)?;
+        reuse_starts_new_streams(v1_0::Binary)?;
+        reuse_starts_new_streams(v1_1::Text)?;
+        reuse_starts_new_streams(v1_1::Binary)
+    }
+
+    #[test]
+    fn reset_discards_unflushed_values() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer.write("kept")?.flush()?;
+        writer.write(Symbol::from("discarded"))?.reset()?;
+        writer.write(Symbol::from("new"))?;
+        let output = writer.close()?;
+        assert!(!output.windows(9).any(|bytes| bytes == b"discarded"));
+        assert_eq!(
+            Element::read_all(output)?,
+            Sequence::new([Element::from("kept"), Element::from(Symbol::from("new"))])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reset_forgets_macros() -> IonResult<()> {
+        let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+        writer.register_template("(macro point (x y) {x: x, y: y})")?;
+        writer.reset()?;
+        assert!(writer.value_writer().eexp_writer("point").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn reset_replaces_symbols_without_a_version_marker() -> IonResult<()> {
+        // Text Ion 1.0 streams do not begin with a version marker, so the symbols from before the
+        // reset have to be replaced explicitly.
+        let config = WriteConfig::<TextEncoding_1_0>::new(crate::TextFormat::Compact)
+            .with_symbol_style("foo", SymbolStyle::SymbolId)
+            .with_symbol_style("bar", SymbolStyle::SymbolId);
+        let mut writer = Writer::new(config, Vec::new())?;
+        writer.write(Symbol::from("foo"))?.flush()?;
+        writer.reset()?;
+        writer.write(Symbol::from("bar"))?;
+        let output = writer.close()?;
+        assert_eq!(
+            Element::read_all(output)?,
+            Sequence::new([Symbol::from("foo"), Symbol::from("bar")])
+        );
+        Ok(())
+    }
+
+    fn imports_symbols<E: Encoding>(config: WriteConfig<E>, exported: &[&str]) -> IonResult<()> {
+        let records = Element::read_all("{name: a, status: active} {name: b, status: inactive}")?;
+        let mut writer = Writer::new(config.clone(), Vec::new())?;
+        writer.write(records.get(0).unwrap())?.flush()?;
+        let symbols = writer.export_symbols("records", 2)?;
+        let exported: Vec<Symbol> = exported.iter().copied().map(Symbol::from).collect();
+        assert_eq!(symbols.symbols(), exported.as_slice());
+
+        let config = config.with_symbol_table_import(symbols.clone());
+        let mut writer = Writer::new(config, Vec::new())?;
+        let mut streams = Vec::new();
+        for record in records.iter() {
+            writer.write(record)?;
+            streams.push(writer.reuse(Vec::new())?);
+        }
+        // Only the symbols missing from the import are defined locally.
+        assert!(!streams[1].windows(6).any(|bytes| bytes == b"status"));
+        assert!(streams[1].windows(8).any(|bytes| bytes == b"inactive"));
+
+        for (stream, record) in streams.into_iter().zip(records.iter()) {
+            let mut catalog = MapCatalog::new();
+            catalog.insert_table(symbols.clone());
+            let mut reader = Reader::new(AnyEncoding.with_catalog(catalog), stream)?;
+            assert_eq!(reader.read_all_elements()?, Sequence::new([record.clone()]));
+        }
+        Ok(())
+    }
+
+    fn write_by_symbol_id<E: Encoding>(config: WriteConfig<E>) -> IonResult<()> {
+        // With the system symbols, `foo`, `bar` and `baz` are $10, $11 and $12
+        let table = SharedSymbolTable::new("shared", 1, ["foo", "bar", "baz"])?;
+        let mut writer = Writer::new(
+            config.clone().with_symbol_table_import(table.clone()),
+            Vec::new(),
+        )?;
+        writer.write(RawSymbolRef::SymbolId(11).annotated_with([10, 12]))?;
+        let mut strukt = writer.value_writer().struct_writer()?;
+        strukt.write(10, RawSymbolRef::SymbolId(12))?;
+        strukt.close()?;
+        let encoded = writer.close()?;
+
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(table);
+        let mut reader = Reader::new(AnyEncoding.with_catalog(catalog), encoded)?;
+        assert_eq!(
+            reader.read_all_elements()?,
+            Element::read_all("foo::baz::bar {foo: baz}")?
+        );
+
+        // Without the import, the symbol IDs are out of bounds
+        let mut writer = Writer::new(config, Vec::new())?;
+        let Err(error) = writer.write(true.annotated_with([10])) else {
+            panic!("wrote an annotation with an out-of-bounds symbol ID");
+        };
+        assert!(matches!(error, IonError::Encoding(_)), "{error:?}");
+        assert!(writer
+            .value_writer()
+            .write_symbol(RawSymbolRef::SymbolId(10))
+            .is_err());
+        let mut strukt = writer.value_writer().struct_writer()?;
+        assert!(strukt.write(10, true).is_err());
+        strukt.close()
+    }
+
+    #[test]
+    fn symbol_ids_can_be_written() -> IonResult<()> {
+        write_by_symbol_id(WriteConfig::<BinaryEncoding_1_0>::new())?;
+        write_by_symbol_id(WriteConfig::<TextEncoding_1_0>::new(TextFormat::Compact))
+    }
+
+    #[test]
+    fn symbol_table_imports() -> IonResult<()> {
+        // `name` is a system symbol, so it is not exported.
+        imports_symbols(
+            WriteConfig::<BinaryEncoding_1_0>::new(),
+            &["a", "status", "active"],
+        )?;
+        // The text writer only adds symbols to its symbol table if they are written as IDs.
+        imports_symbols(
+            WriteConfig::<TextEncoding_1_0>::new(crate::TextFormat::Compact)
+                .with_symbol_style("status", SymbolStyle::SymbolId)
+                .with_symbol_style("inactive", SymbolStyle::SymbolId),
+            &["status"],
+        )
+    }
+
+    #[test]
+    fn shared_symbol_table_round_trip() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        writer
+            .write(&Element::read_one("foo::{bar: $0}")?)?
+            .flush()?;
+        let table = writer.export_symbols("table", 3)?;
+        let element = Element::read_one(BinaryEncoding_1_0::encode(&table)?)?;
+        assert_eq!(
+            element.annotations().first(),
+            Some("$ion_shared_symbol_table")
+        );
+        assert_eq!(SharedSymbolTable::try_from(element)?, table);
+        Ok(())
+    }
+
+    #[test]
+    fn unbuffered_values_use_the_symbol_table() -> IonResult<()> {
+        let readings: Vec<String> = (0..1_000)
+            .map(|i| format!("{{id: {i}, status: active}}"))
+            .collect();
+        let source = format!(
+            r#"
+                before::{{status: active}}
+                reading::{{sensor: temperature, values: [1, 2, 3], tags: (indoor status)}}
+                readings::before::[{}]
+                after::sensor
+            "#,
+            readings.join(", ")
+        );
+        let expected = Element::read_all(source)?;
+
+        let mut writer = Writer::new(v1_0::Binary, Cursor::new(Vec::new()))?;
+        writer
+            .write(&expected[0])?
+            .write_unbuffered(&expected[1])?
+            .write_unbuffered(&expected[2])?
+            .write(&expected[3])?;
+        let actual = Element::read_all(writer.close()?.into_inner())?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn nop_pads_have_the_requested_size() -> IonResult<()> {
+        for size in 0..300 {
+            let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+            writer.write_nop_pad(size)?.write(true)?;
+            let output_1_0 = writer.close()?;
+            let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+            writer.write_nop_pad(size)?.write(true)?;
+            let output_1_1 = writer.close()?;
+            for output in [output_1_0, output_1_1] {
+                // The IVM, the padding, and a one-byte boolean
+                assert_eq!(output.len(), 4 + size + 1, "size {size}");
+                assert_eq!(Element::read_all(output)?, Sequence::new([true]));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn padded_values_have_a_fixed_width() -> IonResult<()> {
+        // Values without symbols, which would add a symbol table to the output
+        let values = Element::read_all("1 \"bar\" [2, 3.5, [null.int]] null")?;
+        let mut writer = Writer::new(v1_1::Binary, Vec::new())?;
+        writer.flush()?;
+        let start = writer.output().len();
+        for value in values.iter() {
+            writer.write_padded(value, 200)?;
+        }
+        writer.flush()?;
+        assert_eq!(writer.output().len() - start, values.len() * 200);
+        assert!(writer.write_padded("x".repeat(200), 200).is_err());
+        assert_eq!(Element::read_all(writer.close()?)?, values);
+        Ok(())
+    }
+
+    #[test]
+    fn write_all_elements_flushes_periodically() -> IonResult<()> {
+        // Records the bytes of each non-empty write made to it
+        struct WriteLog(Vec<Vec<u8>>);
+        impl Write for WriteLog {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                if !buf.is_empty() {
+                    self.0.push(buf.to_vec());
+                }
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let count = WRITE_ELEMENTS_FLUSH_INTERVAL * 2 + 1;
+        let expected: Vec<Element> = (0..count)
+            .map(|id| ion_struct! {"id": id as i64}.into())
+            .collect();
+        for encoded in [
+            Writer::new(v1_0::Text, Vec::new())?
+                .write_all_elements(&expected)?
+                .output()
+                .clone(),
+            Writer::new(v1_0::Binary, Vec::new())?
+                .write_all_elements(expected.iter().cloned())?
+                .output()
+                .clone(),
+        ] {
+            assert_eq!(Element::read_all(encoded)?, Sequence::new(&expected));
+        }
+
+        // Text Ion 1.0 has no symbol tables, so each flush makes a single write of the values that
+        // were written since the last one.
+        let mut writer = Writer::new(v1_0::Text, WriteLog(Vec::new()))?;
+        let writes_before = writer.output().0.len();
+        writer.write_all_elements(&expected)?;
+        let values_per_write = writer.output().0[writes_before..]
+            .iter()
+            .map(|bytes| Ok(Element::read_all(bytes)?.len()))
+            .collect::<IonResult<Vec<_>>>()?;
+        // Once after each full interval and once at the end
+        let interval = WRITE_ELEMENTS_FLUSH_INTERVAL;
+        assert_eq!(values_per_write, [interval, interval, 1]);
+        Ok(())
+    }
+
+    // Each encoding's `Writer` has its own inherent `write_*_from_reader` methods.
+    trait StreamLob {
+        fn stream_lob(&mut self, ion_type: IonType, payload: &[u8]) -> IonResult<()>;
+    }
+
+    macro_rules! impl_stream_lob {
+        ($($encoding:ty),* $(,)?) => {
+            $(
+                impl StreamLob for Writer<$encoding, Vec<u8>> {
+                    fn stream_lob(&mut self, ion_type: IonType, payload: &[u8]) -> IonResult<()> {
+                        let length = payload.len() as u64;
+                        match ion_type {
+                            IonType::Blob => self.write_blob_from_reader(length, payload)?,
+                            _ => self.write_clob_from_reader(length, payload)?,
+                        };
+                        Ok(())
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_stream_lob!(
+        BinaryEncoding_1_0,
+        BinaryEncoding_1_1,
+        TextEncoding_1_0,
+        TextEncoding_1_1,
+    );
+
+    const LOB_LENGTHS: [usize; 7] = [0, 1, 2, 13, 14, 200, 20_000];
+
+    fn lob_payload(length: usize) -> Vec<u8> {
+        (0..length).map(|i| (i % 251) as u8).collect()
+    }
+
+    // Writes a blob and a clob holding each of several payloads, once streaming them from a
+    // reader and once using `Writer::write`, and checks that the two outputs are identical.
+    fn assert_streamed_lobs_match<E: Encoding>(config: impl Into<WriteConfig<E>>) -> IonResult<()>
+    where
+        Writer<E, Vec<u8>>: StreamLob,
+    {
+        let config = config.into();
+        let mut streamed = Writer::new(config.clone(), Vec::new())?;
+        let mut buffered = Writer::new(config, Vec::new())?;
+        let mut expected = Vec::new();
+        for length in LOB_LENGTHS {
+            let payload = lob_payload(length);
+            for lob in [Element::blob(&payload), Element::clob(&payload)] {
+                // The symbol must be defined in a symbol table that precedes the lob.
+                let before = Element::symbol(format!("before{length}"));
+                streamed.write(&before)?;
+                streamed.stream_lob(lob.ion_type(), &payload)?;
+                streamed.write(length as i64)?;
+                // A streamed lob is written to the output after the values before it are flushed.
+                buffered.write(&before)?.flush()?;
+                buffered.write(&lob)?.flush()?;
+                buffered.write(length as i64)?;
+                expected.extend([before, lob, Element::int(length as i64)]);
+            }
+        }
+        let streamed = streamed.close()?;
+        assert_eq!(streamed, buffered.close()?);
+        assert_eq!(Element::read_all(streamed)?, Sequence::new(expected));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::binary_1_0(assert_streamed_lobs_match(v1_0::Binary))]
+    #[case::binary_1_1(assert_streamed_lobs_match(v1_1::Binary))]
+    #[case::text_1_0_compact(assert_streamed_lobs_match(v1_0::Text.with_format(TextFormat::Compact)))]
+    #[case::text_1_0_wrapped(assert_streamed_lobs_match(
+        WriteConfig::<TextEncoding_1_0>::new(TextFormat::Pretty).with_blob_line_width(20)
+    ))]
+    #[case::text_1_1_lines(assert_streamed_lobs_match(v1_1::Text.with_format(TextFormat::Lines)))]
+    #[case::text_1_1_wrapped(assert_streamed_lobs_match(
+        WriteConfig::<TextEncoding_1_1>::new(TextFormat::Lines).with_blob_line_width(7)
+    ))]
+    fn streamed_lobs_match_buffered_lobs(#[case] result: IonResult<()>) -> IonResult<()> {
+        result
+    }
+
+    // Writes lobs nested in containers and annotated, streaming them from a reader if `stream`
+    // is true and writing them from a slice otherwise.
+    fn write_nested_lobs<E: Encoding>(config: &WriteConfig<E>, stream: bool) -> IonResult<Vec<u8>> {
+        fn write_lob<V: ValueWriter>(
+            value_writer: V,
+            ion_type: IonType,
+            payload: &[u8],
+            stream: bool,
+        ) -> IonResult<()> {
+            let length = payload.len() as u64;
+            match (ion_type, stream) {
+                (IonType::Blob, true) => value_writer.write_blob_from_reader(length, payload),
+                (IonType::Blob, false) => value_writer.write_blob(payload),
+                (_, true) => value_writer.write_clob_from_reader(length, payload),
+                (_, false) => value_writer.write_clob(payload),
+            }
+        }
+
+        let mut writer = Writer::new(config.clone(), Vec::new())?;
+        for length in LOB_LENGTHS {
+            let payload = lob_payload(length);
+            let mut list = writer.list_writer()?;
+            write_lob(list.value_writer(), IonType::Blob, &payload, stream)?;
+            let annotated = list.value_writer().with_annotations("clob")?;
+            write_lob(annotated, IonType::Clob, &payload, stream)?;
+            let mut strukt = list.struct_writer()?;
+            write_lob(strukt.field_writer("blob"), IonType::Blob, &payload, stream)?;
+            let annotated = strukt.field_writer("clob").with_annotations("nested")?;
+            write_lob(annotated, IonType::Clob, &payload, stream)?;
+            strukt.close()?;
+            list.close()?;
+        }
+        writer.close()
+    }
+
+    fn assert_nested_streamed_lobs_match<E: Encoding>(
+        config: impl Into<WriteConfig<E>>,
+    ) -> IonResult<()> {
+        let config = config.into();
+        let streamed = write_nested_lobs(&config, true)?;
+        assert_eq!(streamed, write_nested_lobs(&config, false)?);
+        let values = Element::read_all(streamed)?;
+        let payloads = values.iter().map(|list| {
+            let list = list.as_sequence().unwrap();
+            let strukt = list.get(2).unwrap().as_struct().unwrap();
+            strukt.get("clob").unwrap().as_lob().unwrap().to_vec()
+        });
+        assert!(payloads.eq(LOB_LENGTHS.into_iter().map(lob_payload)));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::binary_1_0(assert_nested_streamed_lobs_match(v1_0::Binary))]
+    #[case::binary_1_1(assert_nested_streamed_lobs_match(v1_1::Binary))]
+    #[case::text_1_0(assert_nested_streamed_lobs_match(v1_0::Text))]
+    #[case::text_1_0_wrapped(assert_nested_streamed_lobs_match(
+        WriteConfig::<TextEncoding_1_0>::new(TextFormat::Pretty).with_blob_line_width(20)
+    ))]
+    #[case::text_1_1(assert_nested_streamed_lobs_match(v1_1::Text))]
+    fn nested_streamed_lobs_match_buffered_lobs(#[case] result: IonResult<()>) -> IonResult<()> {
+        result
+    }
+
+    #[test]
+    fn streamed_lobs_are_not_buffered() -> IonResult<()> {
+        // Records the length of each write made to it
+        struct WriteLog(Vec<usize>);
+        impl Write for WriteLog {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.push(buf.len());
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let payload = vec![0xA5u8; 1_000_000];
+        let length = payload.len() as u64;
+        let mut binary_writer = Writer::new(v1_0::Binary, WriteLog(Vec::new()))?;
+        binary_writer.write_blob_from_reader(length, payload.as_slice())?;
+        let mut text_writer = Writer::new(v1_0::Text, WriteLog(Vec::new()))?;
+        text_writer.write_blob_from_reader(length, payload.as_slice())?;
+        for writes in [&binary_writer.output().0, &text_writer.output().0] {
+            assert!(writes.iter().all(|length| *length < 64 * 1024));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn short_lob_sources_are_an_error() -> IonResult<()> {
+        let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+        assert!(writer
+            .write_blob_from_reader(10, b"too short".as_slice())
+            .is_err());
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        assert!(writer
+            .write_clob_from_reader(10, b"too short".as_slice())
+            .is_err());
+        // Bytes beyond the requested length are not read.
+        let mut source = b"hello, world".as_slice();
+        let mut writer = Writer::new(v1_1::Text, Vec::new())?;
+        writer.write_clob_from_reader(5, &mut source)?;
+        assert_eq!(source, b", world");
+        assert_eq!(Element::read_one(writer.close()?)?, Element::clob("hello"));
+        Ok(())
+    }
+}
@@ -195,6 +195,7 @@ impl<'top> TextEncoding<'top> for TextEncoding_1_1 {}
 /// Marker trait for encodings that support macros.
 pub trait EncodingWithMacroSupport {}
 impl EncodingWithMacroSupport for TextEncoding_1_1 {}
+impl EncodingWithMacroSupport for BinaryEncoding_1_1 {}
 
 impl Decoder for BinaryEncoding_1_0 {
     type Reader<'data> = LazyRawBinaryReader_1_0<'data>;
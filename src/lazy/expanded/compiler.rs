@@ -63,13 +63,30 @@ impl TemplateCompiler {
         context: EncodingContextRef,
         expression: &str,
     ) -> IonResult<TemplateMacro> {
-        // TODO: This is a rudimentary implementation that panics instead of performing thorough
-        //       validation. Where it does surface errors, the messages are too terse.
         let mut reader = Reader::new(v1_1::Text, expression.as_bytes())?;
         let invocation = reader.expect_next()?.read()?.expect_sexp()?;
+        Self::compile_from_sexp(context, invocation)
+    }
+
+    /// Like [`Self::compile_from_text`], but compiles a `(macro ...)` s-expression that has already
+    /// been read from an Ion stream, such as one found in an encoding directive. Macro invocations
+    /// in the template body are resolved using the macro table in `context`.
+    pub fn compile_from_sexp<D: Decoder>(
+        context: EncodingContextRef<'_>,
+        invocation: LazySExp<'_, D>,
+    ) -> IonResult<TemplateMacro> {
+        // TODO: This is a rudimentary implementation that does not perform thorough validation.
+        //       Where it does surface errors, the messages are too terse.
         let mut values = invocation.iter();
+        let missing = |part: &str| {
+            IonError::decoding_error(format!("macro definition is missing its {part}"))
+        };
 
-        let macro_keyword = values.next().expect("macro ID")?.read()?.expect_symbol()?;
+        let macro_keyword = values
+            .next()
+            .ok_or_else(|| missing("`macro` keyword"))??
+            .read()?
+            .expect_symbol()?;
         if macro_keyword != "macro" {
             return IonResult::decoding_error(
                 "macro compilation expects a sexp starting with the keyword `macro`",
@@ -78,7 +95,7 @@ impl TemplateCompiler {
 
         // TODO: Enforce 'identifier' syntax subset of symbol
         // TODO: Syntactic support address IDs like `(:14 ...)`
-        let template_name = match values.next().expect("template name")?.read()? {
+        let template_name = match values.next().ok_or_else(|| missing("name"))??.read()? {
             ValueRef::Symbol(s) if s.text().is_none() => {
                 return IonResult::decoding_error("$0 is not a valid macro name")
             }
@@ -93,7 +110,7 @@ impl TemplateCompiler {
 
         let params = values
             .next()
-            .expect("parameters sexp")?
+            .ok_or_else(|| missing("parameters"))??
             .read()?
             .expect_sexp()?;
 
@@ -103,15 +120,14 @@ impl TemplateCompiler {
                 param_result?
                     .read()?
                     .expect_symbol()?
-                    .text()
-                    .unwrap()
+                    .expect_text()?
                     .to_string(),
                 ParameterEncoding::Tagged,
             );
             compiled_params.push(compiled_param);
         }
         let signature = MacroSignature::new(compiled_params);
-        let body = values.next().expect("template body")?;
+        let body = values.next().ok_or_else(|| missing("body"))??;
         let mut compiled_body = TemplateBody {
             expressions: Vec::new(),
             annotations_storage: Vec::new(),
@@ -136,7 +152,7 @@ impl TemplateCompiler {
     ///
     /// If `is_quoted` is true, nested symbols and s-expressions will not be interpreted.
     fn compile_value<'top, D: Decoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         is_quoted: bool,
@@ -209,7 +225,7 @@ impl TemplateCompiler {
 
     /// Helper method for visiting all of the child expressions in a list.
     fn compile_list<'top, D: Decoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         is_quoted: bool,
@@ -237,7 +253,7 @@ impl TemplateCompiler {
 
     /// Helper method for visiting all of the child expressions in a sexp.
     fn compile_sexp<'top, D: Decoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         is_quoted: bool,
@@ -271,7 +287,7 @@ impl TemplateCompiler {
     /// Adds a `lazy_sexp` that has been determined to represent a macro invocation to the
     /// TemplateBody.
     fn compile_macro<'top, D: Decoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         lazy_sexp: LazySExp<'top, D>,
@@ -310,7 +326,7 @@ impl TemplateCompiler {
     /// Given a `LazyValue` that represents a macro ID (name or address), attempts to resolve the
     /// ID to a macro address.
     fn name_and_address_from_id_expr<'top, D: Decoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         id_expr: Option<IonResult<LazyValue<'top, D>>>,
     ) -> IonResult<(Option<String>, usize)> {
         match id_expr {
@@ -351,7 +367,7 @@ impl TemplateCompiler {
     /// without interpretation. `lazy_sexp` itself is the `quote` macro, and does not get added
     /// to the template body as there is nothing more for it to do at evaluation time.
     fn compile_quoted_elements<'top, D: Decoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         lazy_sexp: LazySExp<'top, D>,
@@ -374,7 +390,7 @@ impl TemplateCompiler {
 
     /// Adds `lazy_sexp` to the template body without interpretation.
     fn compile_quoted_sexp<'top, D: Decoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         annotations_range: Range<usize>,
@@ -418,7 +434,7 @@ impl TemplateCompiler {
 
     /// Recursively adds all of the expressions in `lazy_struct` to the `TemplateBody`.
     fn compile_struct<'top, D: Decoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         is_quoted: bool,
@@ -44,7 +44,7 @@ use sequence::{LazyExpandedList, LazyExpandedSExp};
 use crate::element::iterators::SymbolsIterator;
 use crate::lazy::any_encoding::IonEncoding;
 use crate::lazy::bytes_ref::BytesRef;
-use crate::lazy::decoder::{Decoder, LazyRawValue};
+use crate::lazy::decoder::{Decoder, LazyRawValue, RawVersionMarker};
 use crate::lazy::encoding::RawValueLiteral;
 use crate::lazy::expanded::compiler::TemplateCompiler;
 use crate::lazy::expanded::macro_evaluator::{MacroEvaluator, RawEExpression};
@@ -58,12 +58,13 @@ use crate::lazy::r#struct::LazyStruct;
 use crate::lazy::raw_value_ref::RawValueRef;
 use crate::lazy::sequence::{LazyList, LazySExp};
 use crate::lazy::str_ref::StrRef;
-use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
+use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader, UnbufferedInput};
 use crate::lazy::system_reader::{PendingLst, SystemReader};
 use crate::lazy::system_stream_item::SystemStreamItem;
 use crate::lazy::text::raw::v1_1::reader::MacroAddress;
 use crate::lazy::value::LazyValue;
 use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::read_config::SymbolTableLimits;
 use crate::result::IonFailure;
 use crate::{
     AnyEncoding, Catalog, Decimal, Int, IonResult, IonType, RawSymbolRef, SymbolTable, Timestamp,
@@ -95,6 +96,9 @@ pub struct EncodingContext {
     pub(crate) macro_table: MacroTable,
     pub(crate) symbol_table: SymbolTable,
     pub(crate) allocator: BumpAllocator,
+    // Set when the reader returns a lob that was too large to buffer in full; a `LobReader` reads
+    // the rest of the lob's payload from this input.
+    pub(crate) unbuffered_input: Cell<Option<UnbufferedInput>>,
 }
 
 impl EncodingContext {
@@ -107,6 +111,7 @@ impl EncodingContext {
             macro_table,
             symbol_table,
             allocator,
+            unbuffered_input: Cell::new(None),
         }
     }
 
@@ -228,31 +233,37 @@ pub struct ExpandingReader<Encoding: Decoder, Input: IonInput> {
     //      overhead to each access. Given that this is the hottest path in the code and that a
     //      fix is inbound, I think this use of `unsafe` is warranted for now.
     //
-    // Holds information found in symbol tables and encoding directives (TODO) that can be applied
+    // Holds information found in symbol tables and encoding directives that can be applied
     // to the encoding context the next time the reader is between top-level expressions.
     pending_lst: UnsafeCell<PendingLst>,
     encoding_context: UnsafeCell<EncodingContext>,
     catalog: Box<dyn Catalog>,
+    // The macro table that a version marker installs: the system macros followed by any templates
+    // that were added using `register_template`.
+    registered_macros: MacroTable,
 }
 
 impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
     pub(crate) fn new(
         raw_reader: StreamingRawReader<Encoding, Input>,
         catalog: Box<dyn Catalog>,
+        symbol_table_limits: SymbolTableLimits,
     ) -> Self {
         Self {
             raw_reader: raw_reader.into(),
             evaluator_ptr: None.into(),
             encoding_context: EncodingContext::empty().into(),
-            pending_lst: PendingLst::new().into(),
+            pending_lst: PendingLst::with_limits(symbol_table_limits).into(),
             catalog,
+            registered_macros: MacroTable::new(),
         }
     }
 
-    // TODO: This method is temporary. It will be removed when the ability to read 1.1 encoding
-    //       directives from the input stream is available. Until then, template creation is manual.
+    // TODO: This method is temporary. Ion 1.1 streams can define templates using encoding
+    //       directives; this allows tests to define templates without encoding one.
     pub fn register_template(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
         let template_macro: TemplateMacro = self.compile_template(template_definition)?;
+        self.registered_macros.add_macro(template_macro.clone())?;
         self.add_macro(template_macro)
     }
 
@@ -288,6 +299,18 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         context.allocator.reset();
     }
 
+    /// Returns the index of the first byte in the input stream that has not yet been read.
+    pub(crate) fn stream_position(&self) -> usize {
+        let raw_reader = unsafe { &*self.raw_reader.get() };
+        raw_reader.stream_position()
+    }
+
+    /// Returns the data source from which the reader is pulling its input.
+    pub(crate) fn input(&self) -> &Input::DataSource {
+        let raw_reader = unsafe { &*self.raw_reader.get() };
+        raw_reader.input()
+    }
+
     pub fn pending_lst(&self) -> &PendingLst {
         // If the user is able to call this method, the PendingLst is not being modified and it's
         // safe to immutably reference.
@@ -331,6 +354,7 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
             // We're setting the symbols list, not appending to it.
             symbol_table.reset();
         }
+        pending_lst.record_applied_sizes();
         // `drain()` empties the pending `imported_symbols` and `symbols` lists
         for symbol in pending_lst.imported_symbols.drain(..) {
             symbol_table.add_symbol(symbol);
@@ -359,6 +383,24 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
             };
             return Ok(SystemStreamItem::SymbolTable(lazy_struct));
         }
+        // If this is an Ion 1.1 stream and the value is an encoding directive...
+        let raw_reader = unsafe { &*self.raw_reader.get() };
+        if raw_reader.encoding().version() == (1, 1)
+            && SystemReader::<_, Input>::is_encoding_directive_sexp(&value)?
+        {
+            // ...record the symbol and macro tables it defines in our `pending_lst`.
+            let pending_lst = unsafe { &mut *self.pending_lst.get() };
+            let directive = LazySExp {
+                expanded_sexp: value.read()?.expect_sexp()?,
+            };
+            SystemReader::<_, Input>::process_encoding_directive(
+                pending_lst,
+                value.context,
+                directive,
+            )?;
+            pending_lst.has_changes = true;
+            return Ok(SystemStreamItem::EncodingDirective(directive));
+        }
         // Otherwise, it's an application value.
         let lazy_value = LazyValue::new(value);
         return Ok(SystemStreamItem::Value(lazy_value));
@@ -385,10 +427,13 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         let pending_lst: &mut PendingLst = unsafe { &mut *self.pending_lst.get() };
         if pending_lst.has_changes {
             // SAFETY: Nothing else holds a reference to the `EncodingContext`'s contents, so we can use the
-            //         `UnsafeCell` to get a mutable reference to its symbol table.
-            let symbol_table: &mut SymbolTable =
-                &mut unsafe { &mut *self.encoding_context.get() }.symbol_table;
-            Self::apply_pending_lst(pending_lst, symbol_table);
+            //         `UnsafeCell` to get a mutable reference to its symbol and macro tables.
+            let encoding_context: &mut EncodingContext =
+                unsafe { &mut *self.encoding_context.get() };
+            if let Some(macro_table) = pending_lst.macro_table.take() {
+                encoding_context.macro_table = macro_table;
+            }
+            Self::apply_pending_lst(pending_lst, &mut encoding_context.symbol_table);
         }
     }
 
@@ -399,12 +444,11 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
     pub fn next_value(&mut self) -> IonResult<Option<LazyValue<Encoding>>> {
         loop {
             match self.next_item()? {
-                SystemStreamItem::VersionMarker(_marker) => {
-                    // TODO: Handle version changes 1.0 <-> 1.1
-                }
-                SystemStreamItem::SymbolTable(_) => {
-                    // The symbol table is processed by `next_item` before it is returned. There's
-                    // nothing to be done here.
+                SystemStreamItem::VersionMarker(_)
+                | SystemStreamItem::SymbolTable(_)
+                | SystemStreamItem::EncodingDirective(_) => {
+                    // Version markers, symbol tables, and encoding directives are processed by
+                    // `next_item` before they are returned. There's nothing to be done here.
                 }
                 SystemStreamItem::Value(value) => return Ok(Some(value)),
                 SystemStreamItem::EndOfStream(_) => return Ok(None),
@@ -441,7 +485,25 @@ impl<Encoding: Decoder, Input: IonInput> ExpandingReader<Encoding, Input> {
             use crate::lazy::raw_stream_item::RawStreamItem::*;
             let raw_reader = unsafe { &mut *self.raw_reader.get() };
             match raw_reader.next(context_ref)? {
-                VersionMarker(marker) => return Ok(SystemStreamItem::VersionMarker(marker)),
+                VersionMarker(marker) => {
+                    // Readers for a single Ion version cannot switch versions mid-stream. Readers
+                    // of `AnyEncoding` report the encoding that the IVM selected.
+                    let encoding = unsafe { &*self.raw_reader.get() }.encoding();
+                    let (major, minor) = marker.version();
+                    if encoding.version() != (major, minor) {
+                        return IonResult::decoding_error(format!(
+                            "found an Ion {major}.{minor} version marker in a stream being read \
+                             as {}",
+                            encoding.name()
+                        ));
+                    }
+                    // An IVM resets the encoding context; symbols and macros defined in the stream
+                    // before it are not available to the data that follows it. Templates that were
+                    // registered with the reader remain available.
+                    let pending_lst = unsafe { &mut *self.pending_lst.get() };
+                    pending_lst.reset(self.registered_macros.clone());
+                    return Ok(SystemStreamItem::VersionMarker(marker));
+                }
                 // We got our value; return it.
                 Value(raw_value) => {
                     let value = LazyExpandedValue::from_literal(context_ref, raw_value);
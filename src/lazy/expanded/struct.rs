@@ -113,6 +113,22 @@ impl<'top, D: Decoder> LazyExpandedFieldName<'top, D> {
         }
     }
 
+    /// Returns `true` if this field name's text is `text`. Unlike [`read`](Self::read), a name
+    /// with unknown text does not produce an error; it simply does not match.
+    pub(crate) fn has_text(&self, text: &str) -> IonResult<bool> {
+        match self {
+            LazyExpandedFieldName::RawName(context, name) => match name.read()? {
+                RawSymbolRef::Text(name_text) => Ok(name_text == text),
+                RawSymbolRef::SymbolId(sid) => {
+                    Ok(context.symbol_table().text_for(sid) == Some(text))
+                }
+            },
+            LazyExpandedFieldName::TemplateName(_template_ref, symbol_ref) => {
+                Ok(symbol_ref.text() == Some(text))
+            }
+        }
+    }
+
     pub(crate) fn read_raw(&self) -> IonResult<RawSymbolRef<'top>> {
         match self {
             LazyExpandedFieldName::RawName(_, name) => name.read(),
@@ -0,0 +1,337 @@
+//! Provides [`LobReader`], which reads the payload of a blob or clob a piece at a time.
+
+use std::io;
+use std::ops::Range;
+
+use crate::lazy::streaming_raw_reader::UnbufferedInput;
+use crate::result::IonFailure;
+use crate::{IonResult, IonType};
+
+// The number of base64 characters decoded at a time. This is a multiple of 4.
+const BASE64_BATCH_SIZE: usize = 1024;
+
+/// Reads the payload of a blob or clob a chunk at a time. See
+/// [`LazyValue::lob_reader`](crate::LazyValue::lob_reader).
+///
+/// A `LobReader` reads from the value's encoding, which the [`Reader`](crate::Reader) usually
+/// holds in its input buffer in full. It avoids making a second, decoded copy of the payload:
+/// * Binary lobs are copied out of the input buffer as requested. When reading binary Ion 1.0
+///   from an [`IonStream`](crate::IonStream) whose buffer has reached its
+///   [maximum capacity](crate::BufferStrategy::with_max_capacity), only the start of a lob's
+///   payload is buffered; the rest is read directly from the stream's source as requested.
+/// * Base64-encoded text blobs are decoded a piece at a time as they are read.
+/// * Text clobs, which may contain escape sequences, are decoded in full when the `LobReader` is
+///   created, so they do require a decoded copy.
+///
+/// `LobReader` implements [`io::Read`], allowing the payload to be piped to a destination with
+/// [`io::copy`].
+pub struct LobReader<'top> {
+    ion_type: IonType,
+    source: LobSource<'top>,
+}
+
+enum LobSource<'top> {
+    // Payload bytes that can be copied as-is
+    Bytes(&'top [u8]),
+    // The start of a binary lob's payload, which is in the input buffer, and the input from which
+    // the rest of the payload is read once the buffered bytes have been copied
+    PartlyBuffered {
+        buffered: &'top [u8],
+        input: UnbufferedInput,
+    },
+    // The base64 text of a text blob that has not been decoded yet, and any decoded bytes that
+    // did not fit in the caller's buffer
+    Base64 {
+        text: &'top [u8],
+        pending: [u8; 3],
+        pending_range: Range<usize>,
+    },
+}
+
+impl<'top> LobReader<'top> {
+    pub(crate) fn from_bytes(ion_type: IonType, bytes: &'top [u8]) -> Self {
+        Self {
+            ion_type,
+            source: LobSource::Bytes(bytes),
+        }
+    }
+
+    /// Constructs a reader for a binary lob whose payload begins with `buffered` and continues in
+    /// `input`.
+    pub(crate) fn from_partly_buffered(
+        ion_type: IonType,
+        buffered: &'top [u8],
+        input: UnbufferedInput,
+    ) -> Self {
+        Self {
+            ion_type,
+            source: LobSource::PartlyBuffered { buffered, input },
+        }
+    }
+
+    /// Constructs a reader that decodes `text`, the base64 content of a text blob (which may
+    /// contain whitespace).
+    pub(crate) fn from_base64(text: &'top [u8]) -> Self {
+        Self {
+            ion_type: IonType::Blob,
+            source: LobSource::Base64 {
+                text,
+                pending: [0u8; 3],
+                pending_range: 0..0,
+            },
+        }
+    }
+
+    /// The type of the lob being read: either [`IonType::Blob`] or [`IonType::Clob`].
+    pub fn ion_type(&self) -> IonType {
+        self.ion_type
+    }
+
+    /// Copies the next bytes of the payload into `buffer`, returning the number of bytes that were
+    /// copied. Returns `Ok(0)` once the payload has been read in full or if `buffer` is empty.
+    ///
+    /// If the payload is base64 text that turns out to be invalid, or if the input ends before the
+    /// rest of a partly buffered payload could be read, returns an error.
+    pub fn read_chunk(&mut self, buffer: &mut [u8]) -> IonResult<usize> {
+        match &mut self.source {
+            LobSource::Bytes(bytes) => Ok(copy_bytes(bytes, buffer)),
+            LobSource::PartlyBuffered { buffered, input } => {
+                if buffered.is_empty() {
+                    // SAFETY: `input` refers to the data source of the reader that returned this
+                    // lob's value. The `LobReader` cannot outlive that value, which borrows the
+                    // reader, so the data source has not moved or advanced.
+                    return unsafe { input.read(buffer) };
+                }
+                Ok(copy_bytes(buffered, buffer))
+            }
+            LobSource::Base64 {
+                text,
+                pending,
+                pending_range,
+            } => {
+                let mut written = 0;
+                loop {
+                    // Hand out any bytes left over from the last group that was decoded.
+                    let length = pending_range.len().min(buffer.len() - written);
+                    buffer[written..written + length]
+                        .copy_from_slice(&pending[pending_range.start..][..length]);
+                    pending_range.start += length;
+                    written += length;
+                    if written == buffer.len() || text.is_empty() {
+                        return Ok(written);
+                    }
+                    // Decode as much as fits directly into the buffer. Whenever less than one
+                    // group's worth of space remains, decode a single group into `pending`.
+                    let space = buffer.len() - written;
+                    let batch_size = (space / 3 * 4).clamp(4, BASE64_BATCH_SIZE);
+                    let mut batch = [0u8; BASE64_BATCH_SIZE];
+                    let batch_length = next_base64_batch(text, &mut batch[..batch_size]);
+                    let batch = &batch[..batch_length];
+                    // Padding may only appear at the end of the base64 text.
+                    if !text.is_empty() && batch.contains(&b'=') {
+                        return invalid_base64();
+                    }
+                    if space >= (batch_length + 3) / 4 * 3 {
+                        written += decode_base64(batch, &mut buffer[written..])?;
+                    } else {
+                        let length = decode_base64(batch, pending)?;
+                        *pending_range = 0..length;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Copies as many bytes from the beginning of `bytes` as fit in `buffer`, removing them from
+/// `bytes`. Returns the number of bytes copied.
+fn copy_bytes(bytes: &mut &[u8], buffer: &mut [u8]) -> usize {
+    let length = bytes.len().min(buffer.len());
+    buffer[..length].copy_from_slice(&bytes[..length]);
+    *bytes = &bytes[length..];
+    length
+}
+
+/// Moves up to `batch.len()` non-whitespace characters from the beginning of `text` into `batch`,
+/// returning the number of characters moved. Any whitespace that follows them is skipped too.
+fn next_base64_batch(text: &mut &[u8], batch: &mut [u8]) -> usize {
+    let mut batch_length = 0;
+    let mut consumed = 0;
+    for byte in text.iter() {
+        if batch_length == batch.len() {
+            break;
+        }
+        consumed += 1;
+        if !byte.is_ascii_whitespace() {
+            batch[batch_length] = *byte;
+            batch_length += 1;
+        }
+    }
+    let trailing_whitespace = text[consumed..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_whitespace())
+        .count();
+    *text = &text[consumed + trailing_whitespace..];
+    batch_length
+}
+
+fn decode_base64(base64_text: &[u8], output: &mut [u8]) -> IonResult<usize> {
+    base64::decode_config_slice(base64_text, base64::STANDARD, output).or_else(|_| invalid_base64())
+}
+
+fn invalid_base64<T>() -> IonResult<T> {
+    IonResult::decoding_error("failed to read blob with invalid base64 data")
+}
+
+impl<'top> io::Read for LobReader<'top> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.read_chunk(buf)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use rstest::rstest;
+
+    use crate::{
+        v1_0, AnyEncoding, BufferStrategy, Element, IonResult, IonStream, IonType, Reader, Sequence,
+    };
+
+    // Reads the payload of each top-level lob in `source`, `chunk_size` bytes at a time.
+    fn read_lobs_in_chunks(source: &[u8], chunk_size: usize) -> IonResult<Vec<Vec<u8>>> {
+        let mut reader = Reader::new(AnyEncoding, source)?;
+        let mut payloads = Vec::new();
+        let mut chunk = vec![0u8; chunk_size];
+        while let Some(value) = reader.next()? {
+            let mut lob = value.lob_reader()?;
+            assert_eq!(lob.ion_type(), value.ion_type());
+            let mut payload = Vec::new();
+            loop {
+                let length = lob.read_chunk(&mut chunk)?;
+                if length == 0 {
+                    break;
+                }
+                payload.extend_from_slice(&chunk[..length]);
+            }
+            payloads.push(payload);
+        }
+        Ok(payloads)
+    }
+
+    const SOURCE: &str = r#"
+        {{ aGVsbG8sIHdvcmxkIQ== }}
+        {{aGk=}}
+        {{ aGVs bG8s
+           IHdv cmxk IQ }}
+        annotated::{{ T25lIHJpbmcgdG8gcnVsZSB0aGVtIGFsbCwgb25lIHJpbmcgdG8gZmluZCB0aGVt }}
+        {{ }}
+        {{ "a clob" }}
+        {{ '''a long''' ''' clob''' }}
+    "#;
+
+    #[test]
+    fn chunks_match_payloads() -> IonResult<()> {
+        let elements = Element::read_all(SOURCE)?;
+        let expected: Vec<Vec<u8>> = elements
+            .iter()
+            .map(|element| element.as_lob().unwrap().to_vec())
+            .collect();
+        let binary = elements.encode_as(v1_0::Binary)?;
+        for chunk_size in 1..=20 {
+            assert_eq!(
+                read_lobs_in_chunks(SOURCE.as_bytes(), chunk_size)?,
+                expected
+            );
+            assert_eq!(read_lobs_in_chunks(&binary, chunk_size)?, expected);
+        }
+        assert_eq!(read_lobs_in_chunks(SOURCE.as_bytes(), 4096)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn large_blob_is_decoded_in_batches() -> IonResult<()> {
+        let payload: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        let text = Element::blob(payload.as_slice()).encode_as(v1_0::Text)?;
+        let mut reader = Reader::new(AnyEncoding, text)?;
+        let mut copy = Vec::new();
+        reader
+            .expect_next()?
+            .lob_reader()?
+            .read_to_end(&mut copy)
+            .unwrap();
+        assert_eq!(copy, payload);
+        Ok(())
+    }
+
+    #[test]
+    fn blob_larger_than_the_buffer_is_read_from_the_source() -> IonResult<()> {
+        let payload: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        let elements = Sequence::from(vec![
+            Element::blob(payload.as_slice()),
+            Element::blob(payload.as_slice()),
+            Element::clob(payload.as_slice()),
+            Element::string("after the lobs"),
+        ]);
+        let binary = elements.encode_as(v1_0::Binary)?;
+        let strategy = BufferStrategy::new()
+            .with_initial_capacity(16)
+            .with_max_capacity(64);
+        let input = IonStream::with_buffer_strategy(Cursor::new(binary), strategy);
+        let mut reader = Reader::new(AnyEncoding, input)?;
+
+        // The payload does not fit in the buffer, so it cannot be read all at once...
+        let value = reader.expect_next()?;
+        assert_eq!(value.ion_type(), IonType::Blob);
+        assert!(value.read().is_err());
+        // ...but it can be read from the source a chunk at a time.
+        let mut copy = Vec::new();
+        value.lob_reader()?.read_to_end(&mut copy).unwrap();
+        assert_eq!(copy, payload);
+
+        // The reader skips the unread part of a lob's payload when it advances.
+        let mut chunk = [0u8; 100];
+        let mut lob = reader.expect_next()?.lob_reader()?;
+        lob.read_exact(&mut chunk).unwrap();
+        assert_eq!(chunk.as_slice(), &payload[..chunk.len()]);
+
+        let value = reader.expect_next()?;
+        assert_eq!(value.ion_type(), IonType::Clob);
+        assert_eq!(value.lob_reader()?.ion_type(), IonType::Clob);
+
+        assert_eq!(
+            reader.expect_next()?.read()?.expect_string()?,
+            "after the lobs"
+        );
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::invalid_character("{{ aGVs*G8= }}")]
+    #[case::padding_in_the_middle("{{ aGk=aGk= }}")]
+    #[case::wrong_length("{{ aGVsb }}")]
+    fn invalid_base64_is_an_error(#[case] source: &str) -> IonResult<()> {
+        // Some invalid base64 is rejected when the value is matched, the rest when it is decoded.
+        let mut reader = Reader::new(v1_0::Text, source)?;
+        let result = reader.expect_next().and_then(|value| {
+            let mut buffer = [0u8; 64];
+            value.lob_reader()?.read_chunk(&mut buffer)
+        });
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::null_blob("null.blob", IonType::Blob)]
+    #[case::string("\"hello\"", IonType::String)]
+    fn only_lobs_can_be_read(#[case] source: &str, #[case] ion_type: IonType) -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, source)?;
+        let value = reader.expect_next()?;
+        assert_eq!(value.ion_type(), ion_type);
+        assert!(value.lob_reader().is_err());
+        Ok(())
+    }
+}
@@ -9,10 +9,12 @@ pub(crate) mod encoder;
 pub(crate) mod encoding;
 pub(crate) mod expanded;
 pub(crate) mod lazy_value_cache;
+pub(crate) mod lob_reader;
 mod never;
 pub(crate) mod raw_stream_item;
 pub(crate) mod raw_value_ref;
 pub(crate) mod reader;
+pub(crate) mod reader_stats;
 pub(crate) mod sequence;
 pub(crate) mod span;
 pub(crate) mod str_ref;
@@ -21,5 +23,7 @@ pub(crate) mod r#struct;
 pub(crate) mod system_reader;
 pub(crate) mod system_stream_item;
 pub(crate) mod text;
+pub(crate) mod transcode;
 pub(crate) mod value;
 pub(crate) mod value_ref;
+pub(crate) mod walk;
@@ -1,15 +1,24 @@
 #![allow(non_camel_case_types)]
 
+use std::mem;
+
+use crate::element::deferred::{Deferral, DeferredValues};
+use crate::element::materialize::{materialize, BuildStacks, ElementBuilder, PlainElements};
 use crate::element::reader::ElementReader;
+use crate::element::reuse::Recycler;
+#[cfg(feature = "bytes")]
+use crate::element::zero_copy::SharingSource;
 use crate::element::Element;
-use crate::lazy::decoder::Decoder;
+use crate::lazy::decoder::{Decoder, HasRange};
+use crate::lazy::reader_stats::ReaderStats;
 use crate::lazy::streaming_raw_reader::IonInput;
 use crate::lazy::system_reader::SystemReader;
+use crate::lazy::system_stream_item::SystemStreamItem;
 use crate::lazy::text::raw::v1_1::reader::MacroAddress;
 use crate::lazy::value::LazyValue;
 use crate::read_config::ReadConfig;
 use crate::result::IonFailure;
-use crate::{AnyEncoding, IonEncoding, IonError, IonResult};
+use crate::{AnyEncoding, Format, IonEncoding, IonError, IonResult, IonType, Symbol, TextInterner};
 
 /// A binary reader that only reads each value that it visits upon request (that is: lazily).
 ///
@@ -63,6 +72,17 @@ use crate::{AnyEncoding, IonEncoding, IonError, IonResult};
 /// ```
 pub struct Reader<Encoding: Decoder, Input: IonInput> {
     system_reader: SystemReader<Encoding, Input>,
+    stats: ReaderStats,
+    // The index and starting offset of the segment that contains the most recently read value.
+    segment_index: usize,
+    segment_start: usize,
+    // Whether anything other than an IVM has been read since the current segment began.
+    segment_has_data: bool,
+    // Scratch space that is reused each time a value is materialized as an `Element`
+    element_stacks: BuildStacks<Element, Symbol>,
+    // The storage of `Element`s passed to `read_next_element_into`, which is reused for the values
+    // read into them
+    recycler: Recycler,
 }
 
 pub(crate) enum NextApplicationValue<'top, D: Decoder> {
@@ -75,6 +95,133 @@ impl<Input: IonInput> Reader<AnyEncoding, Input> {
     pub fn detected_encoding(&self) -> IonEncoding {
         self.system_reader.detected_encoding()
     }
+
+    /// Returns whether the reader is reading binary or text Ion. Unless the reader was configured
+    /// to use a particular format with [`AnyEncoding::with_format`], input that does not begin
+    /// with a binary Ion version marker (including empty input) is read as text.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{AnyEncoding, ElementReader, Format, Reader};
+    ///
+    /// // A binary Ion int with no Ion version marker
+    /// let data: &[u8] = &[0x21, 0x05];
+    /// let mut reader = Reader::new(AnyEncoding.with_format(Format::Binary), data)?;
+    /// assert_eq!(reader.detected_format(), Format::Binary);
+    /// assert_eq!(reader.read_one_element()?, 5.into());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn detected_format(&self) -> Format {
+        self.detected_encoding().format()
+    }
+
+    /// Like [`ElementReader::read_next_element`], but any string, clob, or blob whose encoding is
+    /// longer than `threshold` bytes is not materialized. The resulting `Element` tree holds a
+    /// null of the same type (with the same annotations) in its place, and the returned
+    /// [`DeferredValues`](crate::DeferredValues) record where each such value can be found in the
+    /// source. This allows applications to build a structural index of a large document cheaply
+    /// and to [`load`](crate::DeferredValue::load) payloads from the source later as they are
+    /// needed.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{AnyEncoding, Element, IonType, Reader};
+    ///
+    /// let source = r#"{name: "report.pdf", contents: {{ aGVsbG8sIHdvcmxkIQ== }}}"#;
+    /// let mut reader = Reader::new(AnyEncoding, source)?;
+    /// let (element, deferred_values) = reader.read_next_element_deferring(16)?.unwrap();
+    /// let fields = element.expect_struct()?;
+    ///
+    /// // The small string was read as usual...
+    /// assert_eq!(fields.get("name").unwrap().expect_string()?, "report.pdf");
+    ///
+    /// // ...but the blob was deferred. The second field holds a placeholder.
+    /// assert!(fields.get("contents").unwrap().is_null());
+    /// let contents = deferred_values.get(&[1]).unwrap();
+    /// assert_eq!(contents.ion_type(), IonType::Blob);
+    /// assert_eq!(contents.load(source)?, Element::blob(b"hello, world!"));
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn read_next_element_deferring(
+        &mut self,
+        threshold: usize,
+    ) -> IonResult<Option<(Element, DeferredValues)>> {
+        let mut deferral = Deferral::new(threshold);
+        let element = self.materialize_next(&mut deferral)?;
+        Ok(element.map(|element| (element, deferral.take_deferred_values())))
+    }
+
+    /// Reads all of the remaining top-level values using
+    /// [`read_next_element_deferring`](Self::read_next_element_deferring).
+    pub fn read_all_elements_deferring(
+        &mut self,
+        threshold: usize,
+    ) -> IonResult<Vec<(Element, DeferredValues)>> {
+        let mut elements = Vec::new();
+        while let Some(element) = self.read_next_element_deferring(threshold)? {
+            elements.push(element);
+        }
+        Ok(elements)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<Encoding: Decoder> Reader<Encoding, ::bytes::Bytes> {
+    /// Like [`ElementReader::read_next_element`], but the payload of each blob and clob whose
+    /// bytes appear verbatim in the input is a slice of the input [`bytes::Bytes`](::bytes::Bytes)
+    /// instead of a copy. Because the slices share the input's reference-counted buffer, large
+    /// binary payloads can be passed along (for example, to an async network stack) without
+    /// being copied. Payloads that had to be decoded, such as base64 blobs in text Ion, are
+    /// still copied.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, Element, Reader, Value};
+    ///
+    /// let element = Element::read_one("{name: \"report.pdf\", contents: {{ aGVsbG8sIHdvcmxkIQ== }}}")?;
+    /// let source = bytes::Bytes::from(ion_rs::to_binary(&element)?);
+    ///
+    /// let mut reader = Reader::new(v1_0::Binary, source.clone())?;
+    /// let element = reader.read_next_element_sharing()?.unwrap();
+    /// let contents = element.as_struct().unwrap().get("contents").unwrap();
+    /// let Value::Blob(blob) = contents.value() else { unreachable!() };
+    ///
+    /// // The blob is a view of the source's buffer.
+    /// let payload = bytes::Bytes::from(blob.clone());
+    /// assert_eq!(payload, &b"hello, world!"[..]);
+    /// assert!(source.as_ptr_range().contains(&payload.as_ptr()));
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn read_next_element_sharing(&mut self) -> IonResult<Option<Element>> {
+        // Cloning the source only increments its reference count.
+        let source = self.system_reader.expanding_reader.input().source().clone();
+        self.materialize_next(&mut SharingSource::new(&source))
+    }
+
+    /// Reads all of the remaining top-level values using
+    /// [`read_next_element_sharing`](Self::read_next_element_sharing).
+    pub fn read_all_elements_sharing(&mut self) -> IonResult<Vec<Element>> {
+        let mut elements = Vec::new();
+        while let Some(element) = self.read_next_element_sharing()? {
+            elements.push(element);
+        }
+        Ok(elements)
+    }
 }
 
 impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
@@ -112,7 +259,84 @@ impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
     #[allow(clippy::should_implement_trait)]
     // ^-- Clippy objects that the method name `next` will be confused for `Iterator::next()`
     pub fn next(&mut self) -> IonResult<Option<LazyValue<Encoding>>> {
-        self.system_reader.next_value()
+        let expanding_reader = &self.system_reader.expanding_reader;
+        loop {
+            let item = expanding_reader.next_item()?;
+            self.stats
+                .set_bytes_consumed(expanding_reader.stream_position());
+            match item {
+                SystemStreamItem::VersionMarker(marker) => {
+                    self.stats.record_version_marker();
+                    if self.segment_has_data {
+                        self.segment_index += 1;
+                        self.segment_start = marker.range().start;
+                        self.segment_has_data = false;
+                    }
+                }
+                SystemStreamItem::SymbolTable(_) | SystemStreamItem::EncodingDirective(_) => {
+                    self.stats.record_symbol_table();
+                    self.segment_has_data = true;
+                }
+                SystemStreamItem::Value(value) => {
+                    self.stats.record_top_level_value(value.ion_type());
+                    self.segment_has_data = true;
+                    return Ok(Some(value));
+                }
+                SystemStreamItem::EndOfStream(_) => return Ok(None),
+            }
+        }
+    }
+
+    /// Returns counters describing the data that this reader has processed so far.
+    pub fn stats(&self) -> &ReaderStats {
+        &self.stats
+    }
+
+    /// Resets all of this reader's [`stats`](Self::stats) other than
+    /// [`bytes_consumed`](ReaderStats::bytes_consumed) to zero. Applications that report the
+    /// counters as metrics periodically can call this after each report.
+    pub fn reset_stats(&mut self) {
+        let bytes_consumed = self.stats.bytes_consumed();
+        self.stats = ReaderStats::default();
+        self.stats.set_bytes_consumed(bytes_consumed);
+    }
+
+    /// Returns the zero-based index of the stream segment that contains the most recently read
+    /// value.
+    ///
+    /// An input may consist of several Ion streams that have been concatenated, each beginning
+    /// with its own Ion version marker and symbol table. Each version marker that follows other
+    /// data begins a new segment; a version marker at the beginning of the input (or directly
+    /// after another version marker) does not. This allows applications to attribute each value
+    /// to the stream it came from.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{AnyEncoding, ElementReader, Reader};
+    ///
+    /// let mut reader = Reader::new(AnyEncoding, "$ion_1_0 a b $ion_1_0 c")?;
+    /// reader.read_next_element()?;
+    /// assert_eq!(reader.segment_index(), 0);
+    /// reader.read_next_element()?;
+    /// assert_eq!(reader.segment_index(), 0);
+    /// reader.read_next_element()?;
+    /// assert_eq!(reader.segment_index(), 1);
+    /// assert_eq!(reader.segment_start(), 13);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn segment_index(&self) -> usize {
+        self.segment_index
+    }
+
+    /// Returns the offset of the first byte of the stream segment that contains the most recently
+    /// read value. See [`segment_index`](Self::segment_index).
+    pub fn segment_start(&self) -> usize {
+        self.segment_start
     }
 
     /// Like [`Self::next`], but returns an `IonError` if there are no more values in the stream.
@@ -120,6 +344,27 @@ impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
         self.next()?
             .ok_or_else(|| IonError::decoding_error("expected another top-level value"))
     }
+
+    /// Advances the reader past the next top-level value without reading its contents. In binary
+    /// Ion, the value's length prefix is used to jump directly to the end of its encoding; neither
+    /// its payload nor (if it is a container) its child values are visited.
+    ///
+    /// Returns `Ok(true)` if a value was skipped or `Ok(false)` if the end of the stream was
+    /// reached.
+    pub fn skip(&mut self) -> IonResult<bool> {
+        Ok(self.next()?.is_some())
+    }
+
+    /// Calls [`Self::skip`] until `count` values have been skipped or the end of the stream is
+    /// reached, returning the number of values that were skipped.
+    pub fn skip_values(&mut self, count: usize) -> IonResult<usize> {
+        for skipped in 0..count {
+            if !self.skip()? {
+                return Ok(skipped);
+            }
+        }
+        Ok(count)
+    }
 }
 
 impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
@@ -128,15 +373,59 @@ impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
         ion_data: Input,
     ) -> IonResult<Reader<Encoding, Input>> {
         let system_reader = SystemReader::new(config, ion_data)?;
-        Ok(Reader { system_reader })
+        Ok(Reader {
+            system_reader,
+            stats: ReaderStats::default(),
+            segment_index: 0,
+            segment_start: 0,
+            segment_has_data: false,
+            element_stacks: BuildStacks::default(),
+            recycler: Recycler::default(),
+        })
+    }
+
+    /// Materializes the next top-level value as an `Element` using `builder`. Returns `Ok(None)`
+    /// if there are no more values.
+    fn materialize_next<B>(&mut self, builder: &mut B) -> IonResult<Option<Element>>
+    where
+        B: for<'top> ElementBuilder<'top, Encoding, Element = Element, Symbol = Symbol>,
+    {
+        let element =
+            self.with_next_value(|lazy_value, stacks| materialize(builder, stacks, lazy_value))?;
+        if element.is_some() {
+            self.stats
+                .record_nested_values(self.element_stacks.nested_values());
+        }
+        Ok(element)
+    }
+
+    /// If there is another top-level value, passes it to `materialize` along with the reader's
+    /// scratch stacks and returns the result.
+    fn with_next_value<T>(
+        &mut self,
+        materialize: impl FnOnce(
+            LazyValue<'_, Encoding>,
+            &mut BuildStacks<Element, Symbol>,
+        ) -> IonResult<T>,
+    ) -> IonResult<Option<T>> {
+        // The stacks are moved out of the reader while the value (which borrows the reader) is
+        // being materialized.
+        let mut stacks = mem::take(&mut self.element_stacks);
+        let result = match self.next() {
+            Ok(Some(lazy_value)) => materialize(lazy_value, &mut stacks).map(Some),
+            Ok(None) => Ok(None),
+            Err(error) => Err(error),
+        };
+        self.element_stacks = stacks;
+        result
     }
 }
 
 impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
     // Temporary method for defining/testing templates. This method does not confirm that the
-    // reader's encoding supports macros--that check will happen when encoding directives are
-    // supported.
-    // TODO: Remove this when the reader can understand 1.1 encoding directives.
+    // reader's encoding supports macros. Ion 1.1 streams can define their own macros using
+    // encoding directives; note that the next directive in the stream will replace any
+    // templates registered this way.
     pub fn register_template(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
         self.system_reader
             .expanding_reader
@@ -154,11 +443,7 @@ impl<'iter, Encoding: Decoder, Input: IonInput> Iterator
     type Item = IonResult<Element>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.lazy_reader.next() {
-            Ok(None) => None,
-            Ok(Some(lazy_value)) => Some(lazy_value.try_into()),
-            Err(e) => Some(Err(e)),
-        }
+        self.lazy_reader.read_next_element().transpose()
     }
 }
 
@@ -166,12 +451,35 @@ impl<Encoding: Decoder, Input: IonInput> ElementReader for Reader<Encoding, Inpu
     type ElementIterator<'a> = LazyElementIterator<'a, Encoding, Input> where Self: 'a,;
 
     fn read_next_element(&mut self) -> IonResult<Option<Element>> {
-        let lazy_value = match self.next()? {
-            None => return Ok(None),
-            Some(lazy_value) => lazy_value,
-        };
-        let element: Element = lazy_value.try_into()?;
-        Ok(Some(element))
+        self.materialize_next(&mut PlainElements)
+    }
+
+    fn read_next_element_into(&mut self, element: &mut Element) -> IonResult<bool> {
+        let mut recycler = mem::take(&mut self.recycler);
+        let result = self.with_next_value(|lazy_value, stacks| {
+            // The element is only taken apart once there is a value to replace it with.
+            recycler.recycle(mem::replace(element, Element::null(IonType::Null)));
+            *element = materialize(&mut recycler, stacks, lazy_value)?;
+            Ok(())
+        });
+        self.recycler = recycler;
+        if result?.is_none() {
+            return Ok(false);
+        }
+        self.stats
+            .record_nested_values(self.element_stacks.nested_values());
+        Ok(true)
+    }
+
+    fn skip_next_element(&mut self) -> IonResult<bool> {
+        self.skip()
+    }
+
+    fn read_next_element_interned(
+        &mut self,
+        interner: &mut TextInterner,
+    ) -> IonResult<Option<Element>> {
+        self.materialize_next(interner)
     }
 
     fn elements(&mut self) -> Self::ElementIterator<'_> {
@@ -183,12 +491,15 @@ impl<Encoding: Decoder, Input: IonInput> ElementReader for Reader<Encoding, Inpu
 mod tests {
     use crate::element::element_writer::ElementWriter;
     use crate::element::Element;
+    use crate::lazy::decoder::RawVersionMarker;
     use crate::lazy::encoder::writer::Writer;
     use crate::lazy::encoding::BinaryEncoding_1_0;
     use crate::lazy::expanded::EncodingContext;
+    use crate::lazy::system_reader::SystemReader;
+    use crate::lazy::system_stream_item::SystemStreamItem;
     use crate::lazy::value_ref::ValueRef;
     use crate::write_config::WriteConfig;
-    use crate::{ion_list, ion_sexp, ion_struct, v1_0, AnyEncoding, Int, IonResult, IonType};
+    use crate::{ion_list, ion_sexp, ion_struct, v1_0, v1_1, AnyEncoding, Int, IonResult, IonType};
 
     use super::*;
 
@@ -284,12 +595,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn skip_values() -> IonResult<()> {
+        let data = to_binary_ion(r#"{{aGVsbG8=}} [1, [2, [3]]] {a: 1} "hi" 5"#)?;
+        let mut reader = Reader::new(v1_0::Binary, data)?;
+        assert!(reader.skip()?);
+        assert_eq!(reader.skip_values(2)?, 2);
+        assert_eq!(reader.read_next_element()?, Some(Element::string("hi")));
+        assert_eq!(reader.skip_values(3)?, 1);
+        assert!(!reader.skip()?);
+        Ok(())
+    }
+
+    #[test]
+    fn skip_does_not_decode_container_contents() -> IonResult<()> {
+        // A two-byte list containing illegal type descriptors (type code 15), followed by the int 5
+        let data: &[u8] = &[0xE0, 0x01, 0x00, 0xEA, 0xB2, 0xF0, 0xF0, 0x21, 0x05];
+        let mut reader = Reader::new(v1_0::Binary, data)?;
+        assert!(reader.skip_next_element()?);
+        assert_eq!(reader.read_next_element()?, Some(Element::int(5)));
+
+        let mut reader = Reader::new(v1_0::Binary, data)?;
+        assert!(reader.read_next_element().is_err());
+        Ok(())
+    }
+
     fn expand_macro_test(
         macro_source: &str,
         encode_macro_fn: impl FnOnce(MacroAddress) -> Vec<u8>,
         test_fn: impl FnOnce(Reader<AnyEncoding, &[u8]>) -> IonResult<()>,
     ) -> IonResult<()> {
-        // Because readers do not yet understand encoding directives, we'll pre-calculate the
+        // Rather than encoding a directive that defines the macro, we'll pre-calculate the
         // macro ID that will be assigned. Make an empty encoding context...
         let context = EncodingContext::empty();
         // ...and see how many macros it contains. This will change as development continues.
@@ -386,4 +722,381 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn text_encoding_directive_defines_macros() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_1
+            $ion_encoding::(
+                (macro_table
+                    (macro greet (name) (make_string "Hello, " name "!"))
+                    (macro greet_twice (name) (values (greet name) (greet name)))))
+            (:greet "Michelle")
+            (:greet_twice "Mike")
+        "#;
+        let mut reader = Reader::new(v1_1::Text, ion)?;
+        for expected in ["Hello, Michelle!", "Hello, Mike!", "Hello, Mike!"] {
+            assert_eq!(reader.expect_next()?.read()?.expect_string()?, expected);
+        }
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn text_encoding_directive_defines_symbols() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_1
+            $ion_encoding::((symbol_table ["foo", null, "bar"]))
+            $10 $11 $12
+            $ion_encoding::(
+                (symbol_table $ion_encoding ["baz"])
+                (macro_table (macro twice (x) (values x x))))
+            $10 $13
+            (:twice $12)
+        "#;
+        let mut reader = Reader::new(v1_1::Text, ion)?;
+        let mut symbols = Vec::new();
+        while let Some(value) = reader.next()? {
+            symbols.push(value.read()?.expect_symbol()?.text().map(str::to_owned));
+        }
+        let expected: Vec<Option<String>> = [
+            Some("foo"),
+            None,
+            Some("bar"),
+            Some("foo"),
+            Some("baz"),
+            Some("bar"),
+            Some("bar"),
+        ]
+        .iter()
+        .map(|text| text.map(str::to_owned))
+        .collect();
+        assert_eq!(symbols, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_encoding_directive_defines_symbols_and_macros() -> IonResult<()> {
+        use crate::lazy::encoder::binary::v1_1::writer::LazyRawBinaryWriter_1_1;
+        use crate::lazy::encoder::value_writer::SequenceWriter;
+        use crate::lazy::expanded::macro_table::MacroTable;
+
+        // The raw writer encodes the directive's symbols as inline text.
+        let directive = Element::read_one(
+            r#"
+            $ion_encoding::(
+                (symbol_table ["foo", null, "bar"])
+                (macro_table (macro greet (name) (make_string "Hello, " name "!"))))
+            "#,
+        )?;
+        let mut writer = LazyRawBinaryWriter_1_1::new(Vec::new())?;
+        writer.write(&directive)?;
+        let mut data = writer.close()?;
+        // The directive's macro is added after the system macros.
+        let greet_address = MacroTable::new().len();
+        #[rustfmt::skip]
+        data.extend_from_slice(&[
+            // $10 $11 $12
+            0xE1, 0x0A, 0xE1, 0x0B, 0xE1, 0x0C,
+            // (:greet "Mike")
+            greet_address as u8, 0x94, 0x4D, 0x69, 0x6B, 0x65,
+        ]);
+
+        let mut reader = Reader::new(v1_1::Binary, data.as_slice())?;
+        let mut symbols = Vec::new();
+        for _ in 0..3 {
+            let symbol = reader.expect_next()?.read()?.expect_symbol()?;
+            symbols.push(symbol.text().map(str::to_owned));
+        }
+        assert_eq!(
+            symbols,
+            vec![Some("foo".to_owned()), None, Some("bar".to_owned())]
+        );
+        let symbol_table = reader.system_reader.symbol_table();
+        assert_eq!(symbol_table.sid_for(&"foo"), Some(10));
+        assert_eq!(symbol_table.sid_for(&"bar"), Some(12));
+        let context = reader.system_reader.expanding_reader.context();
+        assert_eq!(
+            context.macro_table().address_for_name("greet"),
+            Some(greet_address)
+        );
+        assert_eq!(
+            reader.expect_next()?.read()?.expect_string()?,
+            "Hello, Mike!"
+        );
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn encoding_directive_without_macro_table_clears_macros() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_1
+            $ion_encoding::((macro_table (macro seventeen () 17)))
+            (:seventeen)
+            $ion_encoding::((macro_table $ion_encoding (macro eighteen () 18)))
+            (:seventeen)
+            (:eighteen)
+            $ion_encoding::((symbol_table ["foo"]))
+            (:seventeen)
+        "#;
+        let mut reader = Reader::new(v1_1::Text, ion)?;
+        for expected in [17, 17, 18] {
+            assert_eq!(reader.expect_next()?.read()?.expect_i64()?, expected);
+        }
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn encoding_directives_are_system_values() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_1
+            $ion_encoding::((macro_table (macro seventeen () 17)))
+            (:seventeen)
+        "#;
+        let mut reader = SystemReader::new(v1_1::Text, ion)?;
+        assert!(reader.next_item()?.version_marker().is_some());
+        let directive = reader.next_item()?.expect_encoding_directive()?;
+        assert_eq!(directive.iter().count(), 1);
+        assert_eq!(
+            reader.next_item()?.expect_value()?.read()?.expect_i64()?,
+            17
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn text_stream_can_change_ion_versions() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_0
+            1
+            $ion_1_1
+            $ion_encoding::((macro_table (macro two () 2)))
+            (:two)
+            $ion_1_0
+            3
+        "#;
+        let mut reader = Reader::new(AnyEncoding, ion)?;
+        for expected in [1, 2, 3] {
+            assert_eq!(reader.expect_next()?.read()?.expect_i64()?, expected);
+        }
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn binary_stream_can_change_ion_versions() -> IonResult<()> {
+        let mut data = to_binary_ion("foo")?;
+        #[rustfmt::skip]
+        data.extend_from_slice(&[
+            // === 1.1 IVM ===
+            0xE0, 0x01, 0x01, 0xEA,
+            // 1-byte int 17
+            0x61, 0x11,
+            // === 1.0 IVM ===
+            0xE0, 0x01, 0x00, 0xEA,
+            // 1-byte int 18
+            0x21, 0x12,
+        ]);
+        let mut reader = SystemReader::new(AnyEncoding, data.as_slice())?;
+        let mut versions = Vec::new();
+        let mut values = Vec::new();
+        loop {
+            match reader.next_item()? {
+                SystemStreamItem::VersionMarker(marker) => versions.push(marker.version()),
+                SystemStreamItem::Value(value) => values.push(Element::try_from(value)?),
+                SystemStreamItem::EndOfStream(_) => break,
+                _ => {}
+            }
+        }
+        assert_eq!(versions, vec![(1, 0), (1, 1), (1, 0)]);
+        assert_eq!(
+            values,
+            vec![Element::symbol("foo"), Element::int(17), Element::int(18)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn version_markers_reset_the_symbol_table() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_0
+            $ion_symbol_table::{symbols: ["foo"]}
+            $10
+            $ion_1_0
+            $10
+        "#;
+        let mut reader = Reader::new(v1_0::Text, ion)?;
+        assert_eq!(reader.expect_next()?.read()?.expect_symbol()?, "foo");
+        assert!(reader.expect_next()?.read().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn version_markers_reset_the_macro_table() -> IonResult<()> {
+        let ion = r#"
+            $ion_1_1
+            $ion_encoding::((macro_table (macro seventeen () 17)))
+            (:seventeen)
+            $ion_1_1
+            (:seventeen)
+        "#;
+        let mut reader = Reader::new(v1_1::Text, ion)?;
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 17);
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn registered_templates_survive_version_markers() -> IonResult<()> {
+        let ion = "(:seventeen) $ion_1_1 (:seventeen)";
+        let mut reader = Reader::new(v1_1::Text, ion)?;
+        reader.register_template("(macro seventeen () 17)")?;
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 17);
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 17);
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn single_version_readers_reject_version_changes() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, "$ion_1_0 1 $ion_1_1 2")?;
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn encoding_directives_are_ignored_in_ion_1_0() -> IonResult<()> {
+        let ion = "$ion_encoding::((symbol_table [\"foo\"])) $ion_symbol_table";
+        let mut reader = Reader::new(v1_0::Text, ion)?;
+        let value = reader.expect_next()?;
+        assert!(value.annotations().are(["$ion_encoding"])?);
+        assert_eq!(value.ion_type(), IonType::SExp);
+        Ok(())
+    }
+
+    #[test]
+    fn stats_count_system_values_and_bytes() -> IonResult<()> {
+        let mut data = Element::read_all("a b::c [d]")?.encode_as(v1_0::Binary)?;
+        data.extend(Element::read_all("e")?.encode_as(v1_0::Binary)?);
+        let mut reader = Reader::new(v1_0::Binary, &data)?;
+        reader.expect_next()?;
+        assert_eq!(reader.stats().version_markers(), 1);
+        assert_eq!(reader.stats().symbol_tables(), 1);
+        assert!(reader.stats().bytes_consumed() < data.len());
+        while reader.next()?.is_some() {}
+        let stats = reader.stats();
+        assert_eq!(stats.version_markers(), 2);
+        assert_eq!(stats.symbol_tables(), 2);
+        assert_eq!(stats.bytes_consumed(), data.len());
+        assert_eq!(stats.top_level_values(), 4);
+        assert_eq!(stats.values(IonType::Symbol), 3);
+        // Lazily visited values are only counted at the top level
+        assert_eq!(stats.values(IonType::List), 1);
+        assert_eq!(stats.total_values(), 4);
+        assert_eq!(stats.max_depth(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn stats_count_nested_values_of_elements() -> IonResult<()> {
+        let ion = "{a: [1, (b 2.5e0)], c: null.int} 3 []";
+        let mut reader = Reader::new(v1_0::Text, ion)?;
+        reader.read_all_elements()?;
+        let stats = reader.stats();
+        assert_eq!(stats.top_level_values(), 3);
+        assert_eq!(stats.values(IonType::Int), 3);
+        assert_eq!(stats.values(IonType::Float), 1);
+        assert_eq!(stats.values(IonType::List), 2);
+        assert_eq!(stats.total_values(), 9);
+        assert_eq!(stats.max_depth(), 3);
+        assert_eq!(stats.bytes_consumed(), ion.len());
+
+        reader.reset_stats();
+        assert_eq!(reader.stats().total_values(), 0);
+        assert_eq!(reader.stats().bytes_consumed(), ion.len());
+        Ok(())
+    }
+
+    #[test]
+    fn detected_format() -> IonResult<()> {
+        use crate::{Format, IonError, TextFormat};
+        let text = Format::Text(TextFormat::default());
+        let binary = to_binary_ion("foo 5")?;
+
+        let reader = Reader::new(AnyEncoding, "foo 5")?;
+        assert_eq!(reader.detected_format(), text);
+        let reader = Reader::new(AnyEncoding, binary.as_slice())?;
+        assert_eq!(reader.detected_format(), Format::Binary);
+        let reader = Reader::new(AnyEncoding, &[][..])?;
+        assert_eq!(reader.detected_format(), text);
+        let reader = Reader::new(AnyEncoding.with_format(Format::Binary), &[][..])?;
+        assert_eq!(reader.detected_format(), Format::Binary);
+        // Binary input contradicts an explicitly configured text format
+        let result = Reader::new(AnyEncoding.with_format(text), binary.as_slice());
+        assert!(matches!(result, Err(IonError::Decoding(_))));
+        let reader = Reader::new(AnyEncoding.with_format(text), "foo 5")?;
+        assert_eq!(reader.detected_format(), text);
+        // An IVM-prefixed binary stream can still be read when binary is requested
+        let mut reader = Reader::new(AnyEncoding.with_format(Format::Binary), binary.as_slice())?;
+        assert_eq!(reader.read_all_elements()?.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn read_binary_without_ivm() -> IonResult<()> {
+        // The IVM and the symbol table are followed by `5`, encoded as a one-byte int
+        let binary = to_binary_ion("foo 5")?;
+        let body = &binary[binary.len() - 2..];
+        assert_eq!(body, &[0x21, 0x05]);
+
+        let mut reader = Reader::new(AnyEncoding.with_format(Format::Binary), body)?;
+        assert_eq!(reader.read_one_element()?, Element::from(5));
+        // Without the configured format, the bytes are read as (invalid) text
+        let mut reader = Reader::new(AnyEncoding, body)?;
+        assert!(reader.read_all_elements().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn segments_of_concatenated_streams() -> IonResult<()> {
+        let first = to_binary_ion("foo bar")?;
+        let second = to_binary_ion("baz")?;
+        let mut data = first.clone();
+        data.extend_from_slice(&second);
+        data.extend_from_slice(&first);
+
+        let mut reader = Reader::new(AnyEncoding, data.as_slice())?;
+        let mut segments = Vec::new();
+        while let Some(element) = reader.read_next_element()? {
+            segments.push((element, reader.segment_index(), reader.segment_start()));
+        }
+        let symbol = |text: &str| Element::symbol(text);
+        let second_start = first.len();
+        let third_start = first.len() + second.len();
+        assert_eq!(
+            segments,
+            vec![
+                (symbol("foo"), 0, 0),
+                (symbol("bar"), 0, 0),
+                (symbol("baz"), 1, second_start),
+                (symbol("foo"), 2, third_start),
+                (symbol("bar"), 2, third_start),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn leading_version_markers_do_not_begin_segments() -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, "$ion_1_0 $ion_1_0 1 $ion_1_0 $ion_1_0 2")?;
+        reader.expect_next()?;
+        assert_eq!(reader.segment_index(), 0);
+        reader.expect_next()?;
+        assert_eq!(reader.segment_index(), 1);
+        assert_eq!(reader.segment_start(), 20);
+        Ok(())
+    }
 }
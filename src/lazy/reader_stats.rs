@@ -0,0 +1,113 @@
+//! Counters that describe the data a [`Reader`](crate::lazy::reader::Reader) has processed.
+
+use crate::element::materialize::NestedValues;
+use crate::IonType;
+
+/// Counters describing the data that a [`Reader`](crate::lazy::reader::Reader) has processed,
+/// suitable for reporting as metrics. See [`Reader::stats`](crate::lazy::reader::Reader::stats).
+///
+/// Because the reader is lazy, values are counted when the reader produces them:
+/// [`Reader::next`](crate::lazy::reader::Reader::next) counts each top-level value it returns,
+/// and the reader's [`ElementReader`](crate::ElementReader) methods also count each of the nested
+/// values in the elements they materialize. Nested values that an application visits through a
+/// [`LazyValue`](crate::lazy::value::LazyValue) are not counted.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{AnyEncoding, ElementReader, IonType, Reader};
+///
+/// let mut reader = Reader::new(AnyEncoding, "1 [2, {a: 3}] foo")?;
+/// reader.read_next_element()?;
+/// reader.read_next_element()?;
+/// reader.next()?;
+///
+/// let stats = reader.stats();
+/// assert_eq!(stats.top_level_values(), 3);
+/// assert_eq!(stats.values(IonType::Int), 3);
+/// assert_eq!(stats.values(IonType::Symbol), 1);
+/// assert_eq!(stats.total_values(), 6);
+/// assert_eq!(stats.max_depth(), 2);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReaderStats {
+    bytes_consumed: usize,
+    version_markers: usize,
+    symbol_tables: usize,
+    top_level_values: usize,
+    // Indexed by `IonType as usize`
+    values_by_type: [usize; 13],
+    max_depth: usize,
+}
+
+impl ReaderStats {
+    /// Returns the number of bytes of input that the reader has finished reading.
+    pub fn bytes_consumed(&self) -> usize {
+        self.bytes_consumed
+    }
+
+    /// Returns the number of Ion version markers the reader has encountered.
+    pub fn version_markers(&self) -> usize {
+        self.version_markers
+    }
+
+    /// Returns the number of times the reader has loaded a symbol table, either from a local symbol
+    /// table struct or from an Ion 1.1 encoding directive.
+    pub fn symbol_tables(&self) -> usize {
+        self.symbol_tables
+    }
+
+    /// Returns the number of top-level application values the reader has produced.
+    pub fn top_level_values(&self) -> usize {
+        self.top_level_values
+    }
+
+    /// Returns the number of values of the given type that the reader has produced, including
+    /// nested values in materialized elements and typed nulls.
+    pub fn values(&self, ion_type: IonType) -> usize {
+        self.values_by_type[ion_type as usize]
+    }
+
+    /// Returns the number of values of any type that the reader has produced.
+    pub fn total_values(&self) -> usize {
+        self.values_by_type.iter().sum()
+    }
+
+    /// Returns the greatest container nesting depth of the values the reader has produced. A
+    /// top-level value has a depth of zero and the children of a top-level container have a depth
+    /// of one.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    pub(crate) fn set_bytes_consumed(&mut self, bytes_consumed: usize) {
+        self.bytes_consumed = bytes_consumed;
+    }
+
+    pub(crate) fn record_version_marker(&mut self) {
+        self.version_markers += 1;
+    }
+
+    pub(crate) fn record_symbol_table(&mut self) {
+        self.symbol_tables += 1;
+    }
+
+    pub(crate) fn record_top_level_value(&mut self, ion_type: IonType) {
+        self.top_level_values += 1;
+        self.values_by_type[ion_type as usize] += 1;
+    }
+
+    /// Adds the nested values counted while materializing a top-level element, which must already
+    /// have been counted by [`record_top_level_value`](Self::record_top_level_value).
+    pub(crate) fn record_nested_values(&mut self, nested: &NestedValues) {
+        for ion_type in IonType::ALL {
+            self.values_by_type[ion_type as usize] += nested.count(ion_type);
+        }
+        self.max_depth = self.max_depth.max(nested.max_depth());
+    }
+}
@@ -1,4 +1,5 @@
-use std::cell::UnsafeCell;
+use std::cell::{Cell, RefCell, UnsafeCell};
+use std::fmt::{Debug, Formatter};
 use std::fs::File;
 use std::io;
 use std::io::{BufReader, Read, StdinLock};
@@ -7,7 +8,11 @@ use crate::lazy::any_encoding::IonEncoding;
 use crate::lazy::decoder::{Decoder, LazyRawReader};
 use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::raw_stream_item::LazyRawStreamItem;
-use crate::{AnyEncoding, IonError, IonResult, LazyRawValue};
+use crate::result::IonFailure;
+use crate::{IonError, IonResult, LazyRawValue};
+
+/// The length in bytes of a binary Ion version marker.
+const IVM_LENGTH: usize = 4;
 
 /// Wraps an implementation of [`IonDataSource`] and reads one top level value at a time from the input.
 pub struct StreamingRawReader<Encoding: Decoder, Input: IonInput> {
@@ -22,6 +27,8 @@ pub struct StreamingRawReader<Encoding: Decoder, Input: IonInput> {
     // of the stream's detected encoding from raw reader to raw reader. For all other encodings,
     // this is a zero-sized type and its associated operations are no-ops.
     saved_state: Encoding::ReaderSavedState,
+    // The Ion version and format of the most recently read stream item.
+    detected_encoding: IonEncoding,
     // The absolute position of the reader within the overall stream. This is the index of the first
     // byte that has not yet been read.
     stream_position: usize,
@@ -44,14 +51,30 @@ pub struct StreamingRawReader<Encoding: Decoder, Input: IonInput> {
     input: UnsafeCell<Input::DataSource>,
 }
 
-const DEFAULT_IO_BUFFER_SIZE: usize = 4 * 1024;
-
 impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
     pub fn new(encoding: Encoding, input: Input) -> IonResult<StreamingRawReader<Encoding, Input>> {
+        Self::with_initial_state(encoding, input, Default::default(), None)
+    }
+
+    /// Constructs a reader whose first raw reader begins in `initial_state`. For `AnyEncoding`,
+    /// this is the encoding used to read input that does not begin with a binary IVM. If a
+    /// `buffer_strategy` is provided, it replaces the input's own.
+    pub(crate) fn with_initial_state(
+        encoding: Encoding,
+        input: Input,
+        initial_state: Encoding::ReaderSavedState,
+        buffer_strategy: Option<BufferStrategy>,
+    ) -> IonResult<StreamingRawReader<Encoding, Input>> {
+        let mut input = input.into_data_source();
+        if let Some(strategy) = buffer_strategy {
+            input.set_buffer_strategy(strategy);
+        }
         let mut me = StreamingRawReader {
             encoding,
-            input: input.into_data_source().into(),
-            saved_state: Default::default(),
+            input: input.into(),
+            saved_state: initial_state,
+            // This will be replaced by `detect_encoding()` below.
+            detected_encoding: IonEncoding::Text_1_0,
             stream_position: 0,
         };
         me.detect_encoding()?;
@@ -59,14 +82,23 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
     }
 
     fn detect_encoding<'top>(&'top mut self) -> IonResult<()> {
-        if self.buffer_is_empty() {
-            self.pull_more_data_from_source()?;
+        // Binary Ion streams begin with a 4-byte version marker. A source may provide fewer bytes
+        // than that in a single read (or the buffer may be smaller than that to start), so keep
+        // reading until the whole marker would be visible or the source is exhausted.
+        while unsafe { &*self.input.get() }.buffer().len() < IVM_LENGTH {
+            if self.pull_more_data_from_source()? == 0 {
+                break;
+            }
         }
 
         let available_bytes = unsafe { &*self.input.get() }.buffer();
-        let reader =
-            <Encoding::Reader<'top> as LazyRawReader<'top, Encoding>>::new(available_bytes);
+        let reader = <Encoding::Reader<'top> as LazyRawReader<'top, Encoding>>::resume_at_offset(
+            available_bytes,
+            0,
+            self.saved_state,
+        );
         self.saved_state = reader.save_state();
+        self.detected_encoding = reader.encoding();
 
         Ok(())
     }
@@ -91,6 +123,22 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
         input.buffer().is_empty()
     }
 
+    /// Returns the index of the first byte in the stream that has not yet been read.
+    pub(crate) fn stream_position(&self) -> usize {
+        self.stream_position
+    }
+
+    /// Returns the data source from which the reader is pulling its input.
+    pub(crate) fn input(&self) -> &Input::DataSource {
+        // The input is only modified by `next`, which requires a mutable reference to the reader.
+        unsafe { &*self.input.get() }
+    }
+
+    /// Returns the Ion version and format of the most recently read stream item.
+    pub fn encoding(&self) -> IonEncoding {
+        self.detected_encoding
+    }
+
     pub fn next<'top>(
         &'top mut self,
         context: EncodingContextRef<'top>,
@@ -104,6 +152,9 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
             if self.buffer_is_empty() {
                 self.pull_more_data_from_source()?;
             }
+            // If the buffer cannot grow, a top-level lob that does not fit is returned with the
+            // part of its payload that is buffered; a `LobReader` reads the rest from the source.
+            let allow_partial_lob = unsafe { &*self.input.get() }.is_full();
 
             let available_bytes = unsafe { &*self.input.get() }.buffer();
             let unsafe_cell_reader = UnsafeCell::new(<Encoding::Reader<'top> as LazyRawReader<
@@ -116,7 +167,11 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
             ));
             let slice_reader = unsafe { &mut *unsafe_cell_reader.get() };
             let starting_position = slice_reader.position();
-            let result = slice_reader.next(context);
+            let result = if allow_partial_lob {
+                slice_reader.next_allowing_partial_lob(context)
+            } else {
+                slice_reader.next(context)
+            };
             // We're done modifying `slice_reader`, but we need to read some of its fields. These
             // fields are _not_ the data to which `result` holds a reference. We have to circumvent
             // the borrow checker's limitation (described in a comment on the StreamingRawReader type)
@@ -126,6 +181,7 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
             let end_position = slice_reader_ref.position();
             // For the RawAnyReader, remember what encoding we detected for next time.
             self.saved_state = slice_reader_ref.save_state();
+            self.detected_encoding = encoding;
 
             let bytes_read = end_position - starting_position;
             let input = unsafe { &mut *self.input.get() };
@@ -164,11 +220,13 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
                 //
                 // To avoid this, we perform a final check for text readers who have emptied their
                 // buffer: we do not consider the item complete unless the input source is exhausted.
+                // The same is true of the end of the stream, which the reader reports without
+                // consuming any whitespace or comments that remain in the buffer.
+                use crate::lazy::raw_stream_item::RawStreamItem::*;
                 if encoding.is_text()
-                    && bytes_read == available_bytes.len()
+                    && (bytes_read == available_bytes.len() || matches!(item, EndOfStream(_)))
                     && !input_source_exhausted
                 {
-                    use crate::lazy::raw_stream_item::RawStreamItem::*;
                     match item {
                         // Text containers and e-expressions have closing delimiters that allow us
                         // to tell that they're complete.
@@ -191,7 +249,17 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
                 }
 
                 // Mark those input bytes as having been consumed so they are not read again.
-                input.consume(bytes_read);
+                if bytes_read > available_bytes.len() {
+                    // The item is a partly buffered lob. The rest of its payload is still in the
+                    // source.
+                    input.consume(available_bytes.len());
+                    input.consume_unbuffered(bytes_read - available_bytes.len());
+                    context
+                        .unbuffered_input
+                        .set(Some(UnbufferedInput::new(&*input)));
+                } else {
+                    input.consume(bytes_read);
+                }
                 // Update the streaming reader's position to reflect the number of bytes we
                 // just read.
                 self.stream_position = end_position;
@@ -202,12 +270,6 @@ impl<Encoding: Decoder, Input: IonInput> StreamingRawReader<Encoding, Input> {
     }
 }
 
-impl<Input: IonInput> StreamingRawReader<AnyEncoding, Input> {
-    pub fn encoding(&self) -> IonEncoding {
-        self.saved_state
-    }
-}
-
 /// An input source--typically an implementation of either `AsRef<[u8]>` or `io::Read`--from which
 /// Ion can be read, paying the cost of buffering and I/O copies only when necessary.
 pub trait IonDataSource {
@@ -222,6 +284,158 @@ pub trait IonDataSource {
     /// Marks `number_of_bytes` in the buffer as having been read. The caller is responsible for
     /// confirming that the buffer contains at least `number_of_bytes` bytes.
     fn consume(&mut self, number_of_bytes: usize);
+
+    /// Configures how the data source sizes its buffer. Data sources that do not copy their input
+    /// into a buffer ignore the strategy, which is what the default implementation does.
+    fn set_buffer_strategy(&mut self, _strategy: BufferStrategy) {}
+
+    /// Returns `true` if the buffer holds as many bytes as its strategy allows, meaning that
+    /// [`fill_buffer`](Self::fill_buffer) cannot add more until some are consumed. Data sources
+    /// that do not copy their input into a buffer are never full, which is what the default
+    /// implementation reports.
+    fn is_full(&self) -> bool {
+        false
+    }
+
+    /// Marks the next `number_of_bytes` in the source, which follow the bytes in the buffer, as
+    /// belonging to a value that was too large to buffer. They can be read with
+    /// [`read_unbuffered`](Self::read_unbuffered) until the buffer is next filled, at which point
+    /// any that remain are skipped. This is only called on data sources that are
+    /// [full](Self::is_full).
+    fn consume_unbuffered(&mut self, _number_of_bytes: usize) {}
+
+    /// Reads the bytes marked by [`consume_unbuffered`](Self::consume_unbuffered) directly from
+    /// the source into `buffer`, bypassing the data source's own buffer. Returns the number of
+    /// bytes that were read, or `Ok(0)` once all of them have been read.
+    fn read_unbuffered(&self, _buffer: &mut [u8]) -> IonResult<usize> {
+        Ok(0)
+    }
+}
+
+/// A type-erased reference to the [`IonDataSource`] of a reader that has returned a partly
+/// buffered lob (see [`LazyRawValue::partial_lob_payload`]). A
+/// [`LobReader`](crate::lazy::lob_reader::LobReader) uses it to read the rest of the lob's payload.
+/// Erasing the data source's type allows the reference to be stored in the
+/// [`EncodingContext`](crate::lazy::expanded::EncodingContext), which does not depend on the
+/// reader's input type.
+#[derive(Clone, Copy)]
+pub(crate) struct UnbufferedInput {
+    data_source: *const (),
+    read: unsafe fn(*const (), &mut [u8]) -> IonResult<usize>,
+}
+
+impl UnbufferedInput {
+    fn new<Source: IonDataSource>(data_source: &Source) -> Self {
+        unsafe fn read<Source: IonDataSource>(
+            data_source: *const (),
+            buffer: &mut [u8],
+        ) -> IonResult<usize> {
+            (*data_source.cast::<Source>()).read_unbuffered(buffer)
+        }
+        Self {
+            data_source: (data_source as *const Source).cast(),
+            read: read::<Source>,
+        }
+    }
+
+    /// Reads the next bytes of the lob's payload from the source. See
+    /// [`IonDataSource::read_unbuffered`].
+    ///
+    /// # Safety
+    ///
+    /// The reader that returned the lob must not have been moved, dropped, or advanced since. This
+    /// holds as long as the lob's value, which borrows the reader, is still in use.
+    pub(crate) unsafe fn read(&self, buffer: &mut [u8]) -> IonResult<usize> {
+        (self.read)(self.data_source, buffer)
+    }
+}
+
+impl Debug for UnbufferedInput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "UnbufferedInput {{ data_source: {:?} }}",
+            self.data_source
+        )
+    }
+}
+
+/// Controls how an [`IonStream`] sizes the buffer into which it copies data from its
+/// [`io::Read`] source. See [`ReadConfig::with_buffer_strategy`](crate::ReadConfig::with_buffer_strategy).
+///
+/// The buffer must be able to hold at least one complete top-level value at a time (except for
+/// binary Ion 1.0 lobs; see [`with_max_capacity`](Self::with_max_capacity)). It starts out
+/// with the [initial capacity](Self::with_initial_capacity) and doubles in size whenever it runs
+/// out of room, up to the [maximum capacity](Self::with_max_capacity). Applications that decode
+/// many tiny messages can use a small initial capacity to avoid allocating space they will not
+/// use, while those that scan large files can use a large initial capacity and
+/// [read-ahead](Self::with_read_ahead) to make fewer, larger reads.
+///
+/// Inputs that are already in memory (like `&[u8]` or `String`) are not copied into a buffer and
+/// are unaffected by the strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferStrategy {
+    initial_capacity: usize,
+    max_capacity: usize,
+    read_ahead: usize,
+}
+
+impl Default for BufferStrategy {
+    fn default() -> Self {
+        Self {
+            initial_capacity: Self::DEFAULT_INITIAL_CAPACITY,
+            max_capacity: usize::MAX,
+            read_ahead: 1,
+        }
+    }
+}
+
+impl BufferStrategy {
+    const DEFAULT_INITIAL_CAPACITY: usize = 4 * 1024;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of bytes allocated for the buffer when data is first read from the
+    /// source. Defaults to 4KiB.
+    pub fn with_initial_capacity(mut self, initial_capacity: usize) -> Self {
+        self.initial_capacity = initial_capacity;
+        self
+    }
+
+    /// Sets the largest size to which the buffer may grow. If a single top-level value does not
+    /// fit in a buffer of this size, the reader raises an error instead of allocating more space.
+    /// By default, there is no limit.
+    ///
+    /// Binary Ion 1.0 blobs and clobs are the exception: once the buffer is full, a top-level lob
+    /// is returned with as much of its payload as was buffered, and
+    /// [`LazyValue::lob_reader`](crate::LazyValue::lob_reader) reads the rest directly from the
+    /// source.
+    pub fn with_max_capacity(mut self, max_capacity: usize) -> Self {
+        self.max_capacity = max_capacity;
+        self
+    }
+
+    /// Sets the minimum number of bytes that each refill requests from the source. If the buffer
+    /// has less free space than this, it grows (subject to the maximum capacity) before reading.
+    /// Defaults to 1, which means that the buffer only grows once it is full.
+    pub fn with_read_ahead(mut self, read_ahead: usize) -> Self {
+        self.read_ahead = read_ahead.max(1);
+        self
+    }
+
+    pub fn initial_capacity(&self) -> usize {
+        self.initial_capacity
+    }
+
+    pub fn max_capacity(&self) -> usize {
+        self.max_capacity
+    }
+
+    pub fn read_ahead(&self) -> usize {
+        self.read_ahead
+    }
 }
 
 /// A fixed slice of Ion data that does not grow; it wraps an implementation of `AsRef<[u8]>` such
@@ -245,6 +459,11 @@ impl<SliceType: AsRef<[u8]>> IonSlice<SliceType> {
         }
     }
 
+    /// Returns the value whose backing data is being read.
+    pub(crate) fn source(&self) -> &SliceType {
+        &self.source
+    }
+
     /// Helper method that returns the complete input stream's backing byte array, including bytes
     /// that have already been read/consumed.
     #[inline]
@@ -282,8 +501,9 @@ impl<SliceType: AsRef<[u8]>> IonDataSource for IonSlice<SliceType> {
 
 /// A buffered reader for types that don't implement AsRef<[u8]>
 pub struct IonStream<R: Read> {
-    // The input source
-    input: R,
+    // The input source. It is only borrowed through the `RefCell` by `read_unbuffered`, which
+    // reads from it while values in the buffer are still in use.
+    input: RefCell<R>,
     // A buffer containing a sliding window of data from `input`.
     buffer: Vec<u8>,
     // The index of the first occupied byte in the buffer. If position==limit, no bytes
@@ -291,19 +511,30 @@ pub struct IonStream<R: Read> {
     position: usize,
     // The index of the first unoccupied byte in the buffer *at or after* `position`.
     limit: usize,
+    // How the buffer is sized
+    strategy: BufferStrategy,
+    // The number of bytes in `input` that belong to a value too large to buffer and that have not
+    // been read yet. See `IonDataSource::consume_unbuffered`.
+    unbuffered_bytes: Cell<usize>,
 }
 
 impl<R: Read> IonStream<R> {
-    const DEFAULT_IO_BUFFER_SIZE: usize = 4 * 1024;
-
     pub fn new(input: R) -> Self {
+        Self::with_buffer_strategy(input, BufferStrategy::default())
+    }
+
+    /// Constructs an `IonStream` whose buffer is managed according to `strategy`.
+    pub fn with_buffer_strategy(input: R, strategy: BufferStrategy) -> Self {
         IonStream {
-            input,
-            buffer: vec![0u8; Self::DEFAULT_IO_BUFFER_SIZE],
+            input: input.into(),
+            // The buffer is allocated when data is first read
+            buffer: Vec::new(),
             // The index of the first occupied byte in the buffer
             position: 0,
             // The index of the first unoccupied byte in the buffer *at or after* `position`.
             limit: 0,
+            strategy,
+            unbuffered_bytes: Cell::new(0),
         }
     }
 }
@@ -320,6 +551,26 @@ impl<R: Read> IonStream<R> {
         self.buffer.copy_within(remaining_data_range, 0);
         debug_assert!(self.buffer().len() == self.limit - self.position);
     }
+
+    /// Grows the buffer so that it has at least `read_ahead` bytes of free space, or as much as
+    /// the maximum capacity allows. The bytes must be set to a value to avoid undefined behavior;
+    /// zero is a conventional choice. The value will never be used anyway.
+    fn grow_buffer(&mut self) -> IonResult<()> {
+        let strategy = &self.strategy;
+        let new_capacity = (self.buffer.len() * 2)
+            .max(strategy.initial_capacity)
+            .max(self.limit.saturating_add(strategy.read_ahead))
+            .min(strategy.max_capacity);
+        if new_capacity <= self.limit {
+            return IonResult::decoding_error(format!(
+                "the input buffer reached its maximum capacity ({} bytes) without holding a \
+                 complete value",
+                strategy.max_capacity
+            ));
+        }
+        self.buffer.resize(new_capacity.max(self.buffer.len()), 0);
+        Ok(())
+    }
 }
 
 impl<R: Read> IonDataSource for IonStream<R> {
@@ -333,15 +584,24 @@ impl<R: Read> IonDataSource for IonStream<R> {
             // space at the beginning of our buffer.
             self.shift_remaining_bytes_to_index_zero();
         }
-        if self.buffer.len() == self.limit {
-            // If we're out of space, double the size of the buffer and fill it with zeros
-            // before proceeding. (The bytes must be set to a value to avoid undefined behavior;
-            // zero is a conventional choice. The value will never be used anyway.)
-            self.buffer.resize(self.buffer.len() * 2, 0);
+        // Skip whatever is left of the payload of a value that was too large to buffer. That
+        // value consumed the whole buffer, so the buffer's space can hold the skipped bytes.
+        while *self.unbuffered_bytes.get_mut() > 0 {
+            debug_assert!(self.limit == 0 && !self.buffer.is_empty());
+            let length = (*self.unbuffered_bytes.get_mut()).min(self.buffer.len());
+            let bytes_read = self.input.get_mut().read(&mut self.buffer[..length])?;
+            if bytes_read == 0 {
+                return Ok(0);
+            }
+            *self.unbuffered_bytes.get_mut() -= bytes_read;
+        }
+        if self.buffer.len() - self.limit < self.strategy.read_ahead {
+            // If we're short on space, grow the buffer before proceeding.
+            self.grow_buffer()?;
         }
         // Attempt to read as many bytes as will fit in the currently allocated capacity beyond
         // `limit`.
-        let bytes_read = self.input.read(&mut self.buffer[self.limit..])?;
+        let bytes_read = self.input.get_mut().read(&mut self.buffer[self.limit..])?;
 
         // Update `self.limit` to mark the newly read in bytes as available.
         self.limit += bytes_read;
@@ -352,6 +612,34 @@ impl<R: Read> IonDataSource for IonStream<R> {
         self.position += number_of_bytes;
         debug_assert!(self.position <= self.limit);
     }
+
+    fn set_buffer_strategy(&mut self, strategy: BufferStrategy) {
+        self.strategy = strategy;
+    }
+
+    fn is_full(&self) -> bool {
+        self.limit - self.position >= self.strategy.max_capacity
+    }
+
+    fn consume_unbuffered(&mut self, number_of_bytes: usize) {
+        *self.unbuffered_bytes.get_mut() += number_of_bytes;
+    }
+
+    fn read_unbuffered(&self, buffer: &mut [u8]) -> IonResult<usize> {
+        let remaining = self.unbuffered_bytes.get();
+        let length = remaining.min(buffer.len());
+        if length == 0 {
+            return Ok(0);
+        }
+        let bytes_read = self.input.borrow_mut().read(&mut buffer[..length])?;
+        if bytes_read == 0 {
+            return IonResult::decoding_error(
+                "the stream ended unexpectedly in the middle of a lob's payload",
+            );
+        }
+        self.unbuffered_bytes.set(remaining - bytes_read);
+        Ok(bytes_read)
+    }
 }
 
 /// Types that can be used as a source of Ion data.
@@ -400,6 +688,15 @@ macro_rules! impl_ion_input_for_slice_types {
 
 impl_ion_input_for_slice_types!(&'a [u8], &'a str, String, &'a String, Vec<u8>, &'a Vec<u8>);
 
+#[cfg(feature = "bytes")]
+impl IonInput for ::bytes::Bytes {
+    type DataSource = IonSlice<Self>;
+
+    fn into_data_source(self) -> Self::DataSource {
+        IonSlice::new(self)
+    }
+}
+
 impl IonInput for File {
     type DataSource = IonStream<BufReader<Self>>;
 
@@ -450,6 +747,7 @@ mod tests {
     use crate::lazy::expanded::EncodingContext;
     use crate::lazy::raw_stream_item::LazyRawStreamItem;
     use crate::lazy::raw_value_ref::RawValueRef;
+    use crate::lazy::streaming_raw_reader::BufferStrategy;
     use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
     use crate::raw_symbol_ref::AsRawSymbolRef;
     use crate::{v1_0, Decimal, IonError, IonResult, IonStream, RawSymbolRef, RawVersionMarker};
@@ -492,6 +790,9 @@ mod tests {
         let empty_context = EncodingContext::empty();
         let context = empty_context.get_ref();
         let mut reader = StreamingRawReader::new(AnyEncoding, input).unwrap();
+        if reader.encoding().is_binary() {
+            let _ivm = reader.next(context)?.expect_ivm()?;
+        }
         expect_string(reader.next(context)?, "foo")?;
         expect_string(reader.next(context)?, "bar")?;
         expect_string(reader.next(context)?, "baz")?;
@@ -509,6 +810,17 @@ mod tests {
      */ "quuz"
     "#;
 
+    // The strings in `EXAMPLE_STREAM`, encoded as binary Ion 1.0
+    #[rustfmt::skip]
+    const BINARY_EXAMPLE_STREAM: &[u8] = &[
+        0xE0, 0x01, 0x00, 0xEA,
+        0x83, b'f', b'o', b'o',
+        0x83, b'b', b'a', b'r',
+        0x83, b'b', b'a', b'z',
+        0x84, b'q', b'u', b'u', b'x',
+        0x84, b'q', b'u', b'u', b'z',
+    ];
+
     #[test]
     fn read_slice() -> IonResult<()> {
         let str_ = EXAMPLE_STREAM;
@@ -617,4 +929,69 @@ mod tests {
 
         Ok(())
     }
+
+    /// An `io::Read` that records the size of each buffer it is asked to fill.
+    struct RecordingReader<R> {
+        input: R,
+        requested: Vec<usize>,
+    }
+
+    impl<R: Read> Read for &mut RecordingReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.requested.push(buf.len());
+            self.input.read(buf)
+        }
+    }
+
+    #[test]
+    fn buffer_grows_from_initial_capacity() -> IonResult<()> {
+        // Small capacities split the stream's values, comments, and whitespace (or the binary
+        // stream's version marker) across refills.
+        for stream in [EXAMPLE_STREAM.as_bytes(), BINARY_EXAMPLE_STREAM] {
+            for initial_capacity in 1..=16 {
+                let mut input = RecordingReader {
+                    input: Cursor::new(stream),
+                    requested: Vec::new(),
+                };
+                let strategy = BufferStrategy::new().with_initial_capacity(initial_capacity);
+                read_example_stream(IonStream::with_buffer_strategy(&mut input, strategy))?;
+                assert_eq!(input.requested[0], initial_capacity);
+                // The buffer only grows when it is full, so it never needs to be much larger than
+                // the stream.
+                assert!(input.requested.iter().all(|size| *size <= 128));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_ahead_requests_larger_reads() -> IonResult<()> {
+        let mut input = RecordingReader {
+            input: Cursor::new(EXAMPLE_STREAM),
+            requested: Vec::new(),
+        };
+        let strategy = BufferStrategy::new()
+            .with_initial_capacity(8)
+            .with_read_ahead(64);
+        read_example_stream(IonStream::with_buffer_strategy(&mut input, strategy))?;
+        assert!(input.requested.iter().all(|size| *size >= 64));
+        Ok(())
+    }
+
+    #[test]
+    fn values_must_fit_in_max_capacity() -> IonResult<()> {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let strategy = BufferStrategy::new()
+            .with_initial_capacity(4)
+            .with_max_capacity(16);
+        let input = IonStream::with_buffer_strategy(
+            Cursor::new(r#""short" "this string is too long""#),
+            strategy,
+        );
+        let mut reader = StreamingRawReader::new(AnyEncoding, input)?;
+        expect_string(reader.next(context)?, "short")?;
+        assert!(matches!(reader.next(context), Err(IonError::Decoding(_))));
+        Ok(())
+    }
 }
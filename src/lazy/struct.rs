@@ -14,7 +14,10 @@ use crate::lazy::expanded::LazyExpandedValue;
 use crate::lazy::value::{AnnotationsIterator, LazyValue};
 use crate::lazy::value_ref::ValueRef;
 use crate::result::IonFailure;
-use crate::{Annotations, Element, IntoAnnotatedElement, IonError, IonResult, Struct, SymbolRef};
+use crate::{
+    Annotations, Element, IntoAnnotatedElement, IonError, IonResult, RawSymbolRef, Struct,
+    SymbolRef, SymbolToken,
+};
 
 /// An as-of-yet unread binary Ion struct. `LazyStruct` is immutable; its fields and annotations
 /// can be read any number of times.
@@ -295,6 +298,20 @@ impl<'top, D: Decoder> LazyField<'top, D> {
         self.expanded_field.name().read()
     }
 
+    /// Returns the name of this field as it was encoded: either a symbol ID or inline text.
+    /// Unlike [`Self::name`], this does not consult the symbol table, so it succeeds even if the
+    /// symbol ID's text is unknown.
+    pub fn raw_name(&self) -> IonResult<RawSymbolRef<'top>> {
+        self.expanded_field.name().read_raw()
+    }
+
+    /// Returns a [`SymbolToken`] describing both the encoding of this field's name (symbol ID or
+    /// inline text) and its resolved text.
+    pub fn name_token(&self) -> IonResult<SymbolToken<'top>> {
+        let symbol_table = self.expanded_field.value().context.symbol_table();
+        SymbolToken::resolve(self.raw_name()?, symbol_table)
+    }
+
     /// Returns a lazy value representing the value of this field. To access the value's data,
     /// see [`LazyValue::read`].
     pub fn value(&self) -> LazyValue<'top, D> {
@@ -326,6 +343,51 @@ impl<'top, D: Decoder> StructIterator<'top, D> {
         let lazy_field = LazyField { expanded_field };
         Ok(Some(lazy_field))
     }
+
+    /// Advances the iterator to the next field with the specified name and returns it. Any fields
+    /// before the match are skipped without reading their values, and fields whose names have
+    /// unknown text are passed over rather than raising an error.
+    ///
+    /// Because the iterator is left positioned just after the match, calling `find_field` again
+    /// will find the next field with the same name, and calling [`next_field`](Self::next_field)
+    /// will continue with the field that follows it.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, Reader};
+    /// use ion_rs::v1_0::Binary;
+    ///
+    /// let ion_data = r#"{foo: [1, 2, 3], bar: 4, baz: {quux: 5}, bar: 6, qux: 7}"#;
+    /// let ion_bytes: Vec<u8> = Element::read_one(ion_data)?.encode_as(Binary)?;
+    /// let mut reader = Reader::new(Binary, ion_bytes)?;
+    ///
+    /// let lazy_struct = reader.expect_next()?.read()?.expect_struct()?;
+    /// let mut fields = lazy_struct.iter();
+    ///
+    /// // Skips over `foo` without reading its contents
+    /// let bar = fields.find_field("bar")?.unwrap();
+    /// assert_eq!(bar.value().read()?.expect_i64()?, 4);
+    ///
+    /// // Resumes the search after the first `bar`
+    /// let bar = fields.find_field("bar")?.unwrap();
+    /// assert_eq!(bar.value().read()?.expect_i64()?, 6);
+    ///
+    /// assert_eq!(fields.next_field()?.unwrap().name()?, "qux");
+    /// assert!(fields.find_field("bar")?.is_none());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn find_field(&mut self, name: &str) -> IonResult<Option<LazyField<'top, D>>> {
+        while let Some(field) = self.next_field()? {
+            if field.expanded_field.name().has_text(name)? {
+                return Ok(Some(field));
+            }
+        }
+        Ok(None)
+    }
 }
 
 impl<'top, D: Decoder> TryFrom<LazyStruct<'top, D>> for Struct {
@@ -335,7 +397,7 @@ impl<'top, D: Decoder> TryFrom<LazyStruct<'top, D>> for Struct {
         let mut builder = StructBuilder::new();
         for field in &lazy_struct {
             let field = field?;
-            builder = builder.with_field(field.name()?, Element::try_from(field.value())?);
+            builder = builder.with_field(field.name_token()?, Element::try_from(field.value())?);
         }
         Ok(builder.build())
     }
@@ -389,6 +451,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_field() -> IonResult<()> {
+        // `$0` has unknown text; it is skipped rather than raising an error.
+        let ion_data = to_binary_ion("{foo: 1, $0: 2, bar: 3, foo: 4}")?;
+        let mut reader = Reader::new(v1_0::Binary, ion_data)?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+        let mut fields = struct_.iter();
+        let bar = fields.find_field("bar")?.unwrap();
+        assert_eq!(bar.value().read()?, ValueRef::Int(3.into()));
+        let foo = fields.find_field("foo")?.unwrap();
+        assert_eq!(foo.value().read()?, ValueRef::Int(4.into()));
+        assert!(fields.next_field()?.is_none());
+        assert!(struct_.iter().find_field("quux")?.is_none());
+        Ok(())
+    }
+
     #[test]
     fn find_expected() -> IonResult<()> {
         let ion_data = to_binary_ion("{foo: 1, bar: 2, baz: 3}")?;
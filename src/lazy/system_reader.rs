@@ -2,23 +2,33 @@
 
 use crate::lazy::any_encoding::IonEncoding;
 use crate::lazy::decoder::Decoder;
-use crate::lazy::expanded::{ExpandedValueRef, ExpandingReader, LazyExpandedValue};
+use crate::lazy::expanded::compiler::TemplateCompiler;
+use crate::lazy::expanded::macro_table::MacroTable;
+use crate::lazy::expanded::{
+    EncodingContext, EncodingContextRef, ExpandedValueRef, ExpandingReader, LazyExpandedValue,
+};
+use crate::lazy::sequence::LazySExp;
 use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
 use crate::lazy::system_stream_item::SystemStreamItem;
 use crate::lazy::value::LazyValue;
-use crate::read_config::ReadConfig;
+use crate::lazy::value_ref::ValueRef;
+use crate::read_config::{ReadConfig, SymbolTableLimits};
 use crate::result::IonFailure;
 use crate::{
-    AnyEncoding, Catalog, Int, IonError, IonResult, IonType, LazyExpandedField, RawSymbolRef,
-    Symbol, SymbolTable,
+    AnyEncoding, Catalog, Format, Int, IonError, IonResult, IonType, LazyExpandedField,
+    RawSymbolRef, Symbol, SymbolTable,
 };
 use std::ops::Deref;
 use std::sync::Arc;
 
+use bumpalo::Bump as BumpAllocator;
+
 // Symbol IDs used for processing symbol table structs
 const ION_SYMBOL_TABLE: RawSymbolRef = RawSymbolRef::SymbolId(3);
 const IMPORTS: RawSymbolRef = RawSymbolRef::SymbolId(6);
 const SYMBOLS: RawSymbolRef = RawSymbolRef::SymbolId(7);
+// The annotation that identifies an Ion 1.1 encoding directive
+const ION_ENCODING: &str = "$ion_encoding";
 
 /// A binary reader that only reads each value that it visits upon request (that is: lazily).
 ///
@@ -86,17 +96,121 @@ pub struct PendingLst {
     pub(crate) is_lst_append: bool,
     pub(crate) symbols: Vec<Symbol>,
     pub(crate) imported_symbols: Vec<Symbol>,
+    // A macro table defined by an Ion 1.1 encoding directive. If this is `None`, the active macro
+    // table will not be changed.
+    pub(crate) macro_table: Option<MacroTable>,
+    // Bounds on the number of symbols and bytes of symbol text that the local symbol table may
+    // hold once the pending changes have been applied.
+    limits: SymbolTableLimits,
+    // The number of symbols and bytes of symbol text in the active local symbol table
+    active_symbols: usize,
+    active_text_bytes: usize,
+    // The number of bytes of text in `symbols`
+    pending_text_bytes: usize,
 }
 
 impl PendingLst {
     pub fn new() -> Self {
+        Self::with_limits(SymbolTableLimits::default())
+    }
+
+    pub(crate) fn with_limits(limits: SymbolTableLimits) -> Self {
         Self {
             has_changes: false,
             is_lst_append: false,
             symbols: Vec::new(),
             imported_symbols: Vec::new(),
+            macro_table: None,
+            limits,
+            active_symbols: 0,
+            active_text_bytes: 0,
+            pending_text_bytes: 0,
+        }
+    }
+
+    /// Discards any pending changes, schedules the symbol table to be restored to its initial
+    /// state, and schedules `macro_table` to replace the active macro table. This happens whenever
+    /// the reader encounters an Ion version marker.
+    pub(crate) fn reset(&mut self, macro_table: MacroTable) {
+        self.has_changes = true;
+        self.is_lst_append = false;
+        self.clear_symbols();
+        self.macro_table = Some(macro_table);
+    }
+
+    /// Discards any pending symbols.
+    pub(crate) fn clear_symbols(&mut self) {
+        self.symbols.clear();
+        self.imported_symbols.clear();
+        self.pending_text_bytes = 0;
+    }
+
+    /// Returns the number of symbols and bytes of symbol text that the local symbol table will
+    /// hold once the pending changes have been applied.
+    fn num_declared(&self) -> (usize, usize) {
+        let (symbols, text_bytes) = if self.is_lst_append {
+            (self.active_symbols, self.active_text_bytes)
+        } else {
+            (0, 0)
+        };
+        (
+            symbols + self.imported_symbols.len() + self.symbols.len(),
+            text_bytes + self.pending_text_bytes,
+        )
+    }
+
+    /// Returns an error if importing `num_symbols` more symbols would exceed the configured limit.
+    fn check_can_import(&self, num_symbols: usize) -> IonResult<()> {
+        let (num_declared, _) = self.num_declared();
+        if num_symbols > self.limits.max_symbols.saturating_sub(num_declared) {
+            return self.too_many_symbols();
+        }
+        Ok(())
+    }
+
+    /// Adds a symbol with the specified text (or unknown text) to the pending symbols, or returns
+    /// an error if doing so would exceed the configured limits.
+    fn push_symbol(&mut self, text: Option<&str>) -> IonResult<()> {
+        let (num_declared, num_text_bytes) = self.num_declared();
+        if num_declared >= self.limits.max_symbols {
+            return self.too_many_symbols();
+        }
+        let text_bytes = text.map(str::len).unwrap_or(0);
+        if text_bytes
+            > self
+                .limits
+                .max_symbol_text_bytes
+                .saturating_sub(num_text_bytes)
+        {
+            return IonResult::decoding_error(format!(
+                "the stream's local symbol table exceeded the reader's limit of {} bytes of \
+                 symbol text",
+                self.limits.max_symbol_text_bytes
+            ));
         }
+        self.pending_text_bytes += text_bytes;
+        let symbol = match text {
+            Some(text) => Symbol::shared(Arc::from(text)),
+            None => Symbol::unknown_text(),
+        };
+        self.symbols.push(symbol);
+        Ok(())
+    }
+
+    fn too_many_symbols(&self) -> IonResult<()> {
+        IonResult::decoding_error(format!(
+            "the stream's local symbol table exceeded the reader's limit of {} symbols",
+            self.limits.max_symbols
+        ))
+    }
+
+    /// Records the size of the local symbol table that results from applying the pending changes.
+    /// This must be called before the pending symbols are moved to the symbol table.
+    pub(crate) fn record_applied_sizes(&mut self) {
+        (self.active_symbols, self.active_text_bytes) = self.num_declared();
+        self.pending_text_bytes = 0;
     }
+
     pub fn local_symbols(&self) -> &[Symbol] {
         &self.symbols
     }
@@ -111,8 +225,20 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
         input: Input,
     ) -> IonResult<SystemReader<Encoding, Input>> {
         let config = config.into();
-        let raw_reader = StreamingRawReader::new(config.encoding(), input)?;
-        let expanding_reader = ExpandingReader::new(raw_reader, config.catalog);
+        let raw_reader = StreamingRawReader::with_initial_state(
+            config.encoding(),
+            input,
+            config.initial_state,
+            config.buffer_strategy,
+        )?;
+        if matches!(config.format, Some(Format::Text(_))) && raw_reader.encoding().is_binary() {
+            return IonResult::decoding_error(
+                "the reader was configured to read text Ion, but the input begins with a binary \
+                 Ion version marker",
+            );
+        }
+        let expanding_reader =
+            ExpandingReader::new(raw_reader, config.catalog, config.symbol_table_limits);
         Ok(SystemReader { expanding_reader })
     }
 
@@ -130,6 +256,26 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
         Ok(false)
     }
 
+    pub(crate) fn is_encoding_directive_sexp(
+        lazy_value: &'_ LazyExpandedValue<'_, Encoding>,
+    ) -> IonResult<bool> {
+        if lazy_value.ion_type() != IonType::SExp {
+            return Ok(false);
+        }
+        if let Some(symbol_ref) = lazy_value.annotations().next() {
+            // `$ion_encoding` is not in the Ion 1.0 system symbol table, so a symbol ID needs to be
+            // resolved against the active symbol table.
+            let is_directive = match symbol_ref? {
+                RawSymbolRef::Text(text) => text == ION_ENCODING,
+                RawSymbolRef::SymbolId(sid) => {
+                    lazy_value.context.symbol_table().text_for(sid) == Some(ION_ENCODING)
+                }
+            };
+            return Ok(is_directive);
+        };
+        Ok(false)
+    }
+
     pub fn symbol_table(&self) -> &SymbolTable {
         self.expanding_reader.context().symbol_table()
     }
@@ -230,10 +376,7 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
             //   // That means that "foo" and "bar" get appended to the pending LST,
             //   // but get discarded when the reader is parked on the third LST in the stream.
             //   $10 // <-- 'baz'
-            _ => {
-                pending_lst.symbols.clear();
-                pending_lst.imported_symbols.clear();
-            }
+            _ => pending_lst.clear_symbols(),
         };
         Ok(())
     }
@@ -246,14 +389,12 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
         if let ExpandedValueRef::List(list) = symbols.read()? {
             for symbol_text_result in list.iter() {
                 if let ExpandedValueRef::String(str_ref) = symbol_text_result?.read()? {
-                    pending_lst
-                        .symbols
-                        .push(Symbol::shared(Arc::from(str_ref.deref())))
+                    pending_lst.push_symbol(Some(str_ref.deref()))?
                 } else {
                     // If the value is null or a non-string, we reserve a spot for it in the symbol
                     // table (i.e. it gets a symbol ID) but there is no text associated with it.
                     // See: https://amazon-ion.github.io/ion-docs/docs/symbols.html
-                    pending_lst.symbols.push(Symbol::unknown_text())
+                    pending_lst.push_symbol(None)?
                 }
             }
         }
@@ -314,6 +455,7 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
                         _ => shared_table.symbols().len(),
                     };
 
+                    pending_lst.check_can_import(max_id)?;
                     let num_symbols_to_import = shared_table.symbols().len().min(max_id);
 
                     pending_lst
@@ -337,6 +479,122 @@ impl<Encoding: Decoder, Input: IonInput> SystemReader<Encoding, Input> {
 
         Ok(())
     }
+
+    // Traverses an Ion 1.1 encoding directive, populating the `PendingLst` with the symbol table and
+    // macro table that it defines. Clauses that are omitted from the directive cause the
+    // corresponding table to be emptied.
+    pub(crate) fn process_encoding_directive(
+        pending_lst: &mut PendingLst,
+        context: EncodingContextRef<'_>,
+        directive: LazySExp<'_, Encoding>,
+    ) -> IonResult<()> {
+        let mut symbol_table_clause: Option<LazySExp<Encoding>> = None;
+        let mut macro_table_clause: Option<LazySExp<Encoding>> = None;
+
+        for clause_result in directive.iter() {
+            let clause = clause_result?.read()?.expect_sexp()?;
+            let clause_name = match clause.iter().next() {
+                Some(name) => name?.read()?.expect_symbol()?,
+                None => {
+                    return IonResult::decoding_error("found an empty encoding directive clause")
+                }
+            };
+            let slot = if clause_name == "symbol_table" {
+                &mut symbol_table_clause
+            } else if clause_name == "macro_table" {
+                &mut macro_table_clause
+            } else {
+                return IonResult::decoding_error(format!(
+                    "found unsupported encoding directive clause '{}'",
+                    clause_name.text().unwrap_or("$0")
+                ));
+            };
+            if slot.is_some() {
+                return IonResult::decoding_error(format!(
+                    "found encoding directive with multiple '{}' clauses",
+                    clause_name.text().unwrap_or("$0")
+                ));
+            }
+            *slot = Some(clause);
+        }
+
+        // Any symbols or macros defined by earlier directives in the same expression are discarded.
+        pending_lst.clear_symbols();
+        pending_lst.is_lst_append = false;
+        if let Some(clause) = symbol_table_clause {
+            Self::process_symbol_table_clause(pending_lst, clause)?;
+        }
+        let macro_table = match macro_table_clause {
+            Some(clause) => Self::process_macro_table_clause(context, clause)?,
+            None => MacroTable::new(),
+        };
+        pending_lst.macro_table = Some(macro_table);
+        Ok(())
+    }
+
+    // Handles `(symbol_table ...)`, whose arguments are lists of symbol text. If the first argument
+    // is `$ion_encoding`, the symbols are appended to the active symbol table.
+    fn process_symbol_table_clause(
+        pending_lst: &mut PendingLst,
+        clause: LazySExp<'_, Encoding>,
+    ) -> IonResult<()> {
+        // Skip the clause name
+        for (index, arg_result) in clause.iter().skip(1).enumerate() {
+            match arg_result?.read()? {
+                ValueRef::Symbol(symbol) if index == 0 && symbol == ION_ENCODING => {
+                    pending_lst.is_lst_append = true;
+                }
+                ValueRef::List(list) => {
+                    for symbol_text_result in list.iter() {
+                        if let ValueRef::String(str_ref) = symbol_text_result?.read()? {
+                            pending_lst.push_symbol(Some(str_ref.deref()))?
+                        } else {
+                            // As in Ion 1.0 symbol tables, non-string values reserve a symbol ID
+                            // that has no associated text.
+                            pending_lst.push_symbol(None)?
+                        }
+                    }
+                }
+                other => {
+                    return IonResult::decoding_error(format!(
+                        "found unexpected value in a symbol_table clause: {other:?}"
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Handles `(macro_table ...)`, whose arguments are `(macro ...)` definitions. If the first
+    // argument is `$ion_encoding`, the definitions are appended to the active macro table.
+    fn process_macro_table_clause(
+        context: EncodingContextRef<'_>,
+        clause: LazySExp<'_, Encoding>,
+    ) -> IonResult<MacroTable> {
+        // Each template is compiled in a context whose macro table contains the macros defined
+        // before it, allowing later definitions to invoke earlier ones.
+        let mut compile_context =
+            EncodingContext::new(MacroTable::new(), SymbolTable::new(), BumpAllocator::new());
+        // Skip the clause name
+        for (index, arg_result) in clause.iter().skip(1).enumerate() {
+            match arg_result?.read()? {
+                ValueRef::Symbol(symbol) if index == 0 && symbol == ION_ENCODING => {
+                    compile_context.macro_table = context.macro_table().clone();
+                }
+                ValueRef::SExp(definition) => {
+                    let template =
+                        TemplateCompiler::compile_from_sexp(compile_context.get_ref(), definition)?;
+                    compile_context.macro_table.add_macro(template)?;
+                }
+                other => {
+                    return IonResult::decoding_error(format!(
+                        "found unexpected value in a macro_table clause: {other:?}"
+                    ))
+                }
+            }
+        }
+        Ok(compile_context.macro_table)
+    }
 }
 
 impl<Input: IonInput> SystemReader<AnyEncoding, Input> {
@@ -375,6 +633,7 @@ mod tests {
                     println!("ivm => v{}.{}", marker.major(), marker.minor())
                 }
                 SystemStreamItem::SymbolTable(ref s) => println!("symtab => {:?}", s),
+                SystemStreamItem::EncodingDirective(ref d) => println!("directive => {:?}", d),
                 SystemStreamItem::Value(ref v) => println!("value => {:?}", v.read()?),
                 SystemStreamItem::EndOfStream(_) => break,
             }
@@ -693,4 +952,94 @@ mod tests {
         assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "quuz");
         Ok(())
     }
+
+    fn system_reader_with_limits(
+        input: &str,
+        max_symbols: usize,
+        max_symbol_text_bytes: usize,
+    ) -> SystemReader<AnyEncoding, &str> {
+        let mut map_catalog = MapCatalog::new();
+        map_catalog.insert_table(SharedSymbolTable::new("shared_table", 1, ["foo"]).unwrap());
+        let config = AnyEncoding
+            .with_catalog(map_catalog)
+            .with_max_symbols(max_symbols)
+            .with_max_symbol_text_bytes(max_symbol_text_bytes);
+        SystemReader::new(config, input).unwrap()
+    }
+
+    #[test]
+    fn symbol_count_limit() -> IonResult<()> {
+        let mut reader = system_reader_with_limits(
+            r#"
+                $ion_symbol_table::{symbols: ["a", "b"]}
+                $11
+                $ion_symbol_table::{imports: $ion_symbol_table, symbols: [null]}
+                $12
+                // Not an append, so the previous symbols no longer count toward the limit
+                $ion_symbol_table::{imports: [{name: "shared_table", version: 1}], symbols: ["c", "d"]}
+                $12
+                $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["e"]}
+                $13
+            "#,
+            3,
+            usize::MAX,
+        );
+        assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "b");
+        assert_eq!(
+            reader.expect_next_value()?.read()?.expect_symbol()?,
+            SymbolRef::with_unknown_text()
+        );
+        assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "d");
+        let Err(error) = reader.next_value() else {
+            panic!("expected the limit to be exceeded");
+        };
+        assert!(error.to_string().contains("limit of 3 symbols"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_text_bytes_limit() -> IonResult<()> {
+        let mut reader = system_reader_with_limits(
+            r#"
+                $ion_symbol_table::{symbols: ["abc", "de"]}
+                $11
+                $ion_1_0
+                // The version marker resets the symbol table
+                $ion_symbol_table::{symbols: ["fghij"]}
+                $10
+                $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["k"]}
+                $11
+            "#,
+            usize::MAX,
+            5,
+        );
+        assert_eq!(reader.expect_next_value()?.read()?.expect_symbol()?, "de");
+        assert_eq!(
+            reader.expect_next_value()?.read()?.expect_symbol()?,
+            "fghij"
+        );
+        assert!(reader.next_value().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn import_padding_counts_toward_symbol_limit() {
+        let mut reader = system_reader_with_limits(
+            r#"
+                $ion_symbol_table::{
+                    imports: [{name: "shared_table", version: 1, max_id: 1000000000000}]
+                }
+                $10
+            "#,
+            1_000,
+            usize::MAX,
+        );
+        let Err(error) = reader.next_value() else {
+            panic!("expected the limit to be exceeded");
+        };
+        assert!(
+            error.to_string().contains("limit of 1000 symbols"),
+            "{error}"
+        );
+    }
 }
@@ -1,9 +1,11 @@
 use std::fmt::{Debug, Formatter};
 
-use crate::lazy::decoder::{Decoder, RawVersionMarker};
+use crate::lazy::decoder::{Decoder, LazyRawContainer, RawVersionMarker};
+use crate::lazy::expanded::sequence::ExpandedSExpSource;
 use crate::lazy::expanded::ExpandedValueSource;
 use crate::lazy::r#struct::LazyStruct;
 use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem};
+use crate::lazy::sequence::LazySExp;
 use crate::lazy::value::LazyValue;
 use crate::result::IonFailure;
 use crate::{IonError, IonResult};
@@ -16,6 +18,8 @@ pub enum SystemStreamItem<'top, D: Decoder> {
     VersionMarker(D::VersionMarker<'top>),
     /// An Ion symbol table encoded as a struct annotated with `$ion_symbol_table`.
     SymbolTable(LazyStruct<'top, D>),
+    /// An Ion 1.1 encoding directive encoded as an s-expression annotated with `$ion_encoding`.
+    EncodingDirective(LazySExp<'top, D>),
     /// An application-level Ion value
     Value(LazyValue<'top, D>),
     /// The end of the stream
@@ -29,6 +33,7 @@ impl<'top, D: Decoder> Debug for SystemStreamItem<'top, D> {
                 write!(f, "version marker v{}.{}", marker.major(), marker.minor())
             }
             SystemStreamItem::SymbolTable(_) => write!(f, "a symbol table"),
+            SystemStreamItem::EncodingDirective(_) => write!(f, "an encoding directive"),
             SystemStreamItem::Value(value) => write!(f, "{}", value.ion_type()),
             SystemStreamItem::EndOfStream(_) => write!(f, "<nothing>"),
         }
@@ -92,6 +97,25 @@ impl<'top, D: Decoder> SystemStreamItem<'top, D> {
         }
     }
 
+    /// If this item is an encoding directive, returns `Some(lazy_sexp)`. Otherwise, returns `None`.
+    pub fn as_encoding_directive(&self) -> Option<LazySExp<'top, D>> {
+        if let Self::EncodingDirective(sexp) = self {
+            Some(*sexp)
+        } else {
+            None
+        }
+    }
+
+    /// Like [`Self::as_encoding_directive`], but returns a [`IonError::Decoding`] if this item is
+    /// not an encoding directive.
+    pub fn expect_encoding_directive(self) -> IonResult<LazySExp<'top, D>> {
+        if let Self::EncodingDirective(sexp) = self {
+            Ok(sexp)
+        } else {
+            IonResult::decoding_error(format!("expected encoding directive, found {:?}", self))
+        }
+    }
+
     pub fn raw_stream_item(&self) -> Option<LazyRawStreamItem<'top, D>> {
         let item = match self {
             SystemStreamItem::VersionMarker(marker) => RawStreamItem::VersionMarker(*marker),
@@ -102,6 +126,12 @@ impl<'top, D: Decoder> SystemStreamItem<'top, D> {
                     Template(..) | Constructed(..) => return None,
                 }
             }
+            SystemStreamItem::EncodingDirective(directive) => match directive.expanded().source() {
+                ExpandedSExpSource::ValueLiteral(literal) => {
+                    RawStreamItem::Value(literal.as_value())
+                }
+                ExpandedSExpSource::Template(..) => return None,
+            },
             SystemStreamItem::Value(value) => {
                 use ExpandedValueSource::*;
                 match value.expanded().source {
@@ -4,10 +4,11 @@ use std::ops::{Range, RangeFrom, RangeTo};
 use std::slice::Iter;
 use std::str::FromStr;
 
+use memchr::memchr2;
 use nom::branch::alt;
 use nom::bytes::complete::{
-    is_a as complete_is_a, is_not as complete_is_not, tag as complete_tag,
-    take_while as complete_take_while, take_while1 as complete_take_while1,
+    is_a as complete_is_a, tag as complete_tag, take_while as complete_take_while,
+    take_while1 as complete_take_while1,
 };
 use nom::bytes::streaming::{is_a, tag, take_until, take_while_m_n};
 use nom::character::complete::{
@@ -86,6 +87,66 @@ const WHITESPACE_CHARACTERS: &[char] = &[
 /// Same as [WHITESPACE_CHARACTERS], but formatted as a string for use in some `nom` APIs
 pub(crate) const WHITESPACE_CHARACTERS_AS_STR: &str = " \t\r\n\x09\x0B\x0C";
 
+/// A lookup table indicating which bytes are [WHITESPACE_CHARACTERS].
+static IS_WHITESPACE: [bool; 256] = {
+    let mut table = [false; 256];
+    let mut index = 0;
+    while index < WHITESPACE_CHARACTERS_AS_STR.len() {
+        table[WHITESPACE_CHARACTERS_AS_STR.as_bytes()[index] as usize] = true;
+        index += 1;
+    }
+    table
+};
+
+/// Returns the number of whitespace bytes at the beginning of `bytes`.
+#[inline]
+fn leading_whitespace_len(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .position(|byte| !IS_WHITESPACE[*byte as usize])
+        .unwrap_or(bytes.len())
+}
+
+/// Returns the index of the first byte in `bytes` that is either `delimiter`, an escape (`\`), or
+/// an ASCII control character. These are the only bytes in a string or quoted symbol body that
+/// require any action; everything between them can be skipped.
+///
+/// The search examines eight bytes at a time using arithmetic on `u64`s, falling back to a
+/// byte-at-a-time search for the final few bytes.
+#[inline]
+fn find_text_special_byte(bytes: &[u8], delimiter: u8) -> Option<usize> {
+    const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+    const HIGH_BITS: u64 = u64::from_ne_bytes([0x80; 8]);
+    // Sets the high bit of each byte in `word` that is less than `n`, which must be <= 0x80.
+    // Borrows can also set the high bit of a byte that follows one that was less than `n`, but
+    // the flag for the first such byte is always accurate, which is all the caller needs.
+    #[inline]
+    fn flag_bytes_less_than(word: u64, n: u8) -> u64 {
+        word.wrapping_sub(ONES * n as u64) & !word & HIGH_BITS
+    }
+    let flag_bytes_equal_to =
+        |word: u64, byte: u8| flag_bytes_less_than(word ^ (ONES * byte as u64), 1);
+
+    let mut chunks = bytes.chunks_exact(8);
+    let mut index = 0;
+    for chunk in &mut chunks {
+        // Little endian order puts the first byte in the least significant position.
+        let word = u64::from_le_bytes(chunk.try_into().unwrap());
+        let flags = flag_bytes_less_than(word, 0x20)
+            | flag_bytes_equal_to(word, b'\\')
+            | flag_bytes_equal_to(word, delimiter);
+        if flags != 0 {
+            return Some(index + flags.trailing_zeros() as usize / 8);
+        }
+        index += 8;
+    }
+    chunks
+        .remainder()
+        .iter()
+        .position(|byte| *byte < 0x20 || *byte == b'\\' || *byte == delimiter)
+        .map(|position| index + position)
+}
+
 /// A slice of unsigned bytes that can be cheaply copied and which defines methods for parsing
 /// the various encoding elements of a text Ion stream.
 ///
@@ -209,7 +270,13 @@ impl<'top> TextBufferView<'top> {
     }
 
     pub fn match_whitespace(self) -> IonMatchResult<'top> {
-        complete_is_a(WHITESPACE_CHARACTERS_AS_STR)(self)
+        match leading_whitespace_len(self.bytes()) {
+            0 => Err(nom::Err::Error(IonParseError::from_error_kind(
+                self,
+                ErrorKind::IsA,
+            ))),
+            length => Ok((self.slice_to_end(length), self.slice(0, length))),
+        }
     }
 
     /// Always succeeds and consumes none of the input. Returns an empty slice of the buffer.
@@ -222,18 +289,34 @@ impl<'top> TextBufferView<'top> {
 
     /// Matches zero or more whitespace characters.
     pub fn match_optional_whitespace(self) -> IonMatchResult<'top> {
-        // Either match whitespace and return what follows or just return the input as-is.
         // This will always return `Ok`, but it is packaged as an IonMatchResult for compatability
         // with other parsers.
-        alt((Self::match_whitespace, Self::match_nothing))(self)
+        let length = leading_whitespace_len(self.bytes());
+        Ok((self.slice_to_end(length), self.slice(0, length)))
     }
 
     /// Matches any amount of contiguous comments and whitespace, including none.
     pub fn match_optional_comments_and_whitespace(self) -> IonMatchResult<'top> {
-        recognize(many0_count(alt((
-            Self::match_whitespace,
-            Self::match_comment,
-        ))))(self)
+        let mut remaining = self;
+        loop {
+            remaining = remaining.slice_to_end(leading_whitespace_len(remaining.bytes()));
+            // Every comment begins with a `/`.
+            if remaining.bytes().first() != Some(&b'/') {
+                break;
+            }
+            // A `/` at the end of the buffer may be the beginning of a comment whose remainder
+            // has not been read yet.
+            if remaining.len() == 1 {
+                return Err(nom::Err::Incomplete(Needed::Unknown));
+            }
+            match remaining.match_comment() {
+                Ok((after_comment, _comment)) => remaining = after_comment,
+                Err(nom::Err::Error(_)) => break,
+                Err(e) => return Err(e),
+            }
+        }
+        let length = remaining.offset() - self.offset();
+        Ok((remaining, self.slice(0, length)))
     }
 
     /// Matches a single
@@ -251,18 +334,11 @@ impl<'top> TextBufferView<'top> {
 
     /// Matches a single rest-of-the-line comment.
     fn match_rest_of_line_comment(self) -> IonMatchResult<'top> {
-        preceded(
-            // Matches a leading "//"...
-            complete_tag("//"),
-            // ...followed by either...
-            alt((
-                // ...one or more non-EOL characters...
-                complete_is_not("\r\n"),
-                // ...or any EOL character.
-                peek(recognize(complete_one_of("\r\n"))),
-                // In either case, the line ending will not be consumed.
-            )),
-        )(self)
+        // Matches a leading "//"...
+        let (body, _) = complete_tag("//")(self)?;
+        // ...followed by the rest of the line. The line ending will not be consumed.
+        let length = memchr2(b'\r', b'\n', body.bytes()).unwrap_or(body.len());
+        Ok((body.slice_to_end(length), body.slice(0, length)))
     }
 
     /// Matches a single multiline comment.
@@ -1011,12 +1087,25 @@ impl<'top> TextBufferView<'top> {
     /// If the input does not contain the entire e-expression, returns `IonError::Incomplete(_)`.
     pub fn match_e_expression(self) -> IonParseResult<'top, RawTextEExpression_1_1<'top>> {
         let (exp_body, _) = tag("(:")(self)?;
-        // TODO: Support macro ID kinds besides unqualified names
-        let (exp_body_after_id, (macro_id_bytes, _matched_symbol)) =
-            consumed(Self::match_identifier)(exp_body)?;
-        // Because the macro_id used identifier syntax, its bytes must be ASCII. We can safely unwrap.
+        // TODO: Support qualified macro IDs
+        let (exp_body_after_id, macro_id_bytes) =
+            alt((complete_digit1, recognize(Self::match_identifier)))(exp_body)?;
+        // Because the macro_id used identifier or address syntax, its bytes must be ASCII. We can
+        // safely unwrap.
         let macro_name = macro_id_bytes.as_text().unwrap();
-        let macro_id = MacroIdRef::LocalName(macro_name);
+        let macro_id = if macro_name.starts_with(|c: char| c.is_ascii_digit()) {
+            match macro_name.parse::<usize>() {
+                Ok(address) => MacroIdRef::LocalAddress(address),
+                Err(_) => {
+                    let error = InvalidInputError::new(self)
+                        .with_label("matching an e-expression")
+                        .with_description(format!("macro address {macro_name} is out of range"));
+                    return Err(nom::Err::Failure(IonParseError::Invalid(error)));
+                }
+            }
+        } else {
+            MacroIdRef::LocalName(macro_name)
+        };
 
         // The rest of the e-expression uses s-expression syntax. Scan ahead to find the end of this
         // expression.
@@ -1614,9 +1703,16 @@ impl<'top> TextBufferView<'top> {
         allow_unescaped_newlines: bool,
     ) -> IonParseResult<'top, (Self, bool)> {
         let mut contains_escaped_chars = false;
-        // This de-sugared syntax allows us to modify `iter` mid-loop.
-        let mut iter = self.bytes().iter().copied().enumerate();
-        while let Some((index, byte)) = iter.next() {
+        let bytes = self.bytes();
+        let mut index = 0;
+        // Skip directly to each byte that needs attention: an escape, the delimiter, or a control
+        // character.
+        while let Some(position) = bytes
+            .get(index..)
+            .and_then(|unscanned| find_text_special_byte(unscanned, delimiter))
+        {
+            index += position;
+            let byte = bytes[index];
             if byte == b'\\' {
                 // It's an escape sequence. For the purposes of finding the end delimiter, we can
                 // skip the next 1 byte unless this is \r\n, in which case we need to skip two.
@@ -1624,14 +1720,13 @@ impl<'top> TextBufferView<'top> {
                 // are always followed by ASCII letters, which aren't used as delimiters.
                 contains_escaped_chars = true;
                 // Peek at the next two bytes to see if this is a \r\n
-                let next_two_bytes = self.bytes().get(index + 1..index + 3);
-                let bytes_to_skip = if next_two_bytes == Some(&[b'\r', b'\n']) {
+                let next_two_bytes = bytes.get(index + 1..index + 3);
+                let bytes_to_skip = if next_two_bytes == Some(b"\r\n") {
                     2
                 } else {
                     1
                 };
-                // Eagerly skip the next iterator values
-                let _ = iter.nth(bytes_to_skip - 1);
+                index += 1 + bytes_to_skip;
                 continue;
             }
             if byte == delimiter {
@@ -1639,16 +1734,15 @@ impl<'top> TextBufferView<'top> {
                 let remaining = self.slice_to_end(index);
                 return Ok((remaining, (matched, contains_escaped_chars)));
             }
-            // If this is a control character, make sure it's a legal one.
-            if byte < 0x20 {
-                if byte == b'\r' {
-                    // Carriage returns are not actual escapes, but do require a substitution
-                    // as part of newline normalization when the string is read.
-                    contains_escaped_chars = true;
-                } else {
-                    self.validate_string_control_character(byte, index, allow_unescaped_newlines)?;
-                }
+            // Otherwise, it's a control character. Make sure it's a legal one.
+            if byte == b'\r' {
+                // Carriage returns are not actual escapes, but do require a substitution
+                // as part of newline normalization when the string is read.
+                contains_escaped_chars = true;
+            } else {
+                self.validate_string_control_character(byte, index, allow_unescaped_newlines)?;
             }
+            index += 1;
         }
         Err(nom::Err::Incomplete(Needed::Unknown))
     }
@@ -2767,6 +2861,8 @@ mod tests {
             "(:foo (1 2 3))",
             "(:foo \"foo\")",
             "(:foo foo)",
+            "(:14)",
+            "(:14 1 2 3)",
         ];
         for input in good_inputs {
             println!("test: {input}");
@@ -2901,6 +2997,145 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_find_text_special_byte() {
+        fn expected(bytes: &[u8], delimiter: u8) -> Option<usize> {
+            bytes
+                .iter()
+                .position(|b| *b < 0x20 || *b == b'\\' || *b == delimiter)
+        }
+        // Place each kind of special byte at every offset of inputs that span multiple words,
+        // including after bytes that are close to the special ones in value.
+        for special in [b'"', b'\\', b'\n', b'\r', 0x00, 0x1F] {
+            for filler in [b'a', b' ', b'!', b'#', b'[', b']', 0x7F, 0x80, 0xFF] {
+                for length in 0..20 {
+                    for position in 0..length {
+                        let mut bytes = vec![filler; length];
+                        bytes[position] = special;
+                        assert_eq!(
+                            find_text_special_byte(&bytes, b'"'),
+                            expected(&bytes, b'"'),
+                            "{bytes:?}"
+                        );
+                    }
+                    let bytes = vec![filler; length];
+                    assert_eq!(find_text_special_byte(&bytes, b'"'), None);
+                }
+            }
+        }
+        // Only the first special byte is reported, even when several share a word.
+        assert_eq!(
+            find_text_special_byte(b"abcdefg\\\x01\"hijk", b'"'),
+            Some(7)
+        );
+        assert_eq!(find_text_special_byte(b"abc\x01\x02\x00\"", b'"'), Some(3));
+    }
+
+    #[rstest]
+    #[case::short(r#""a""#, "a", false)]
+    #[case::long_without_escapes(
+        r#""0123456789abcdefghijklmnopqrstuvwxyz""#,
+        "0123456789abcdefghijklmnopqrstuvwxyz",
+        false
+    )]
+    #[case::escape_at_word_boundary(r#""0123456\"89abcdef""#, r#"0123456\"89abcdef"#, true)]
+    #[case::escaped_backslash_before_delimiter(r#""01234567\\""#, r#"01234567\\"#, true)]
+    #[case::escaped_crlf("\"0123456\\\r\n9abcdef\"", "0123456\\\r\n9abcdef", true)]
+    #[case::carriage_return("\"0123456789\rabc\"", "0123456789\rabc", true)]
+    fn test_match_text_until_unescaped(
+        #[case] input: &str,
+        #[case] expected_body: &str,
+        #[case] expected_escapes: bool,
+    ) {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let input = TextBufferView::new(context, &input.as_bytes()[1..]);
+        let (remaining, (matched, contains_escapes)) =
+            input.match_text_until_unescaped(b'"', false).unwrap();
+        assert_eq!(matched.as_text().unwrap(), expected_body);
+        assert_eq!(contains_escapes, expected_escapes);
+        assert_eq!(remaining.bytes(), b"\"");
+    }
+
+    #[rstest]
+    #[case::no_delimiter("abcdefghijklmnopqrstuvwxyz")]
+    #[case::escaped_delimiter(r#"abcdefghijklmnop\""#)]
+    #[case::trailing_escape(r#"abcdefghijklmnop\"#)]
+    fn test_match_text_until_unescaped_incomplete(#[case] input: &str) {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let input = TextBufferView::new(context, input.as_bytes());
+        assert!(matches!(
+            input.match_text_until_unescaped(b'"', false),
+            Err(nom::Err::Incomplete(_))
+        ));
+    }
+
+    #[test]
+    fn test_match_text_until_unescaped_rejects_control_characters() {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let input = TextBufferView::new(context, "0123456789\x01abc\"".as_bytes());
+        assert!(matches!(
+            input.match_text_until_unescaped(b'"', false),
+            Err(nom::Err::Failure(_))
+        ));
+        let input = TextBufferView::new(context, "0123456789\nabc\"".as_bytes());
+        assert!(input.match_text_until_unescaped(b'"', false).is_err());
+        assert!(input.match_text_until_unescaped(b'"', true).is_ok());
+    }
+
+    #[rstest]
+    #[case::nothing("", "")]
+    #[case::whitespace(" \t\r\n\x0B\x0C", "")]
+    #[case::line_comment("  // comment\n", "")]
+    #[case::line_comment_at_end("// comment", "")]
+    #[case::empty_line_comment("//\n", "")]
+    #[case::empty_line_comment_at_end("  //", "")]
+    #[case::block_comment("/* comment */", "")]
+    #[case::mixed(" // a\r\n /* b */\t/**/ // c\n  foo", "foo")]
+    #[case::stops_at_value("  1 // comment", "1 // comment")]
+    #[case::stops_at_lone_slash("  /foo", "/foo")]
+    fn test_match_optional_comments_and_whitespace(#[case] input: &str, #[case] expected: &str) {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let input = TextBufferView::new(context, input.as_bytes());
+        let (remaining, matched) = input.match_optional_comments_and_whitespace().unwrap();
+        assert_eq!(remaining.bytes(), expected.as_bytes());
+        assert_eq!(matched.len() + remaining.len(), input.len());
+    }
+
+    #[test]
+    fn test_match_comment_split_at_end_of_buffer() {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        // The `/` may be the first half of `//` or `/*`
+        let input = TextBufferView::new(context, b"  /");
+        assert!(matches!(
+            input.match_optional_comments_and_whitespace(),
+            Err(nom::Err::Incomplete(_))
+        ));
+        let input = TextBufferView::new(context, b"  /* unterminated");
+        assert!(matches!(
+            input.match_optional_comments_and_whitespace(),
+            Err(nom::Err::Incomplete(_))
+        ));
+    }
+
+    #[test]
+    fn test_match_whitespace() {
+        let empty_context = EncodingContext::empty();
+        let context = empty_context.get_ref();
+        let input = TextBufferView::new(context, b" \t\n x");
+        let (remaining, matched) = input.match_whitespace().unwrap();
+        assert_eq!(matched.bytes(), b" \t\n ");
+        assert_eq!(remaining.bytes(), b"x");
+        assert!(remaining.match_whitespace().is_err());
+        let (remaining, matched) = remaining.match_optional_whitespace().unwrap();
+        assert!(matched.is_empty());
+        assert_eq!(remaining.bytes(), b"x");
+    }
+
     fn test_match_text_until_unescaped_str() {
         let empty_context = EncodingContext::empty();
         let context = empty_context.get_ref();
@@ -77,12 +77,10 @@ impl<'data> LazyRawTextReader_1_0<'data> {
             )?;
 
         if let RawStreamItem::VersionMarker(version_marker) = matched_item {
-            // TODO: It is not the raw reader's responsibility to report this error. It should
-            //       surface the IVM to the caller, who can then either create a different reader
-            //       for the reported version OR raise an error.
-            //       See: https://github.com/amazon-ion/ion-rust/issues/644
+            // IVMs are surfaced to the caller, who can switch to a reader for the new Ion
+            // version or raise an error. Versions that no reader supports are rejected here.
             let (major, minor) = version_marker.version();
-            if (major, minor) != (1, 0) {
+            if !matches!((major, minor), (1, 0) | (1, 1)) {
                 return IonResult::decoding_error(format!(
                     "Ion version {major}.{minor} is not supported"
                 ));
@@ -0,0 +1,132 @@
+//! Copies a stream of Ion values from a [`Reader`] to a [`Writer`] without materializing them.
+
+use std::io::Write;
+
+use crate::lazy::decoder::Decoder;
+use crate::lazy::encoding::Encoding;
+use crate::lazy::reader::Reader;
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::{IonResult, Writer};
+
+// The number of top-level values that are written between flushes of the writer's buffers.
+const FLUSH_INTERVAL: usize = 1024;
+
+/// Writes each of the values remaining in `reader` to `writer`, returning the number of top-level
+/// values that were copied.
+///
+/// Values are read lazily and written as they are visited, so no [`Element`](crate::Element) is
+/// constructed for them. Annotations, typed nulls, and the text of field names and symbols are
+/// preserved; symbol IDs are not, so the output has its own symbol table and any combination of
+/// text and binary encodings can be used for the input and output. The writer is flushed
+/// periodically and once more after the last value, but it is not closed.
+///
+/// ```
+///# use ion_rs::IonResult;
+///# #[cfg(feature = "experimental-reader-writer")]
+///# fn main() -> IonResult<()> {
+/// use ion_rs::{transcode, v1_0, Element, Reader, Writer};
+///
+/// let text = "point::{x: 1, y: null.int} (a b)";
+/// let mut reader = Reader::new(v1_0::Text, text)?;
+/// let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+/// assert_eq!(transcode(&mut reader, &mut writer)?, 2);
+///
+/// let binary = writer.close()?;
+/// assert_eq!(Element::read_all(binary)?, Element::read_all(text)?);
+///# Ok(())
+///# }
+///# #[cfg(not(feature = "experimental-reader-writer"))]
+///# fn main() -> IonResult<()> { Ok(()) }
+/// ```
+pub fn transcode<D: Decoder, Input: IonInput, E: Encoding, Output: Write>(
+    reader: &mut Reader<D, Input>,
+    writer: &mut Writer<E, Output>,
+) -> IonResult<usize> {
+    let mut count = 0;
+    while let Some(value) = reader.next()? {
+        writer.write(value)?;
+        count += 1;
+        if count % FLUSH_INTERVAL == 0 {
+            writer.flush()?;
+        }
+    }
+    writer.flush()?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ion_data::IonEq;
+    use crate::{v1_0, v1_1, AnyEncoding, Element, Symbol, WriteConfig};
+
+    const ION: &str = r#"
+        $ion_symbol_table::{symbols: ["sym", "name", "unused"]}
+        null null.int a::b::null.struct
+        {name: "Alice", tags: [x, y::z], empty: ()}
+        $11::$10 $0 2024-01-01T00:00Z 1.5e0 2.50 {{ aGVsbG8= }} {{ "clob" }}
+        (+ 1 ( - 2 3))
+    "#;
+
+    fn transcode_to<E: Encoding + Into<WriteConfig<E>>>(
+        input: &[u8],
+        encoding: E,
+    ) -> IonResult<Vec<u8>> {
+        let mut reader = Reader::new(AnyEncoding, input)?;
+        let mut writer = Writer::new(encoding, Vec::new())?;
+        let count = transcode(&mut reader, &mut writer)?;
+        assert_eq!(count, Element::read_all(input)?.len());
+        writer.close()
+    }
+
+    fn text_to_binary() -> IonResult<Vec<u8>> {
+        transcode_to(ION.as_bytes(), v1_0::Binary)
+    }
+
+    #[rstest]
+    #[case::text_to_binary(text_to_binary())]
+    #[case::text_to_text(transcode_to(ION.as_bytes(), v1_0::Text))]
+    #[case::binary_to_text(transcode_to(&text_to_binary()?, v1_0::Text))]
+    #[case::binary_to_binary(transcode_to(&text_to_binary()?, v1_0::Binary))]
+    #[case::text_to_binary_1_1(transcode_to(ION.as_bytes(), v1_1::Binary))]
+    #[case::binary_1_1_to_text(transcode_to(&transcode_to(ION.as_bytes(), v1_1::Binary)?, v1_0::Text))]
+    fn preserves_values(#[case] output: IonResult<Vec<u8>>) -> IonResult<()> {
+        let expected = Element::read_all(ION)?;
+        let actual = Element::read_all(output?)?;
+        assert!(actual.ion_eq(&expected), "{actual:?} != {expected:?}");
+        // A symbol with unknown text is still written as `$0`
+        assert_eq!(
+            actual.get(5).unwrap().as_symbol(),
+            Some(&Symbol::unknown_text())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn binary_output_has_a_new_symbol_table() -> IonResult<()> {
+        let output = text_to_binary()?;
+        // The input's unused symbol is not carried over
+        assert!(!output.windows(6).any(|bytes| bytes == b"unused"));
+        Ok(())
+    }
+
+    #[test]
+    fn copies_many_values() -> IonResult<()> {
+        let text: String = (0..FLUSH_INTERVAL * 3 + 1)
+            .map(|i| format!("s{i}::{{f{i}: {i}}} "))
+            .collect();
+        let binary = transcode_to(text.as_bytes(), v1_0::Binary)?;
+        assert_eq!(Element::read_all(binary)?, Element::read_all(text)?);
+        Ok(())
+    }
+
+    #[test]
+    fn reports_invalid_input() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, "1 2 {")?;
+        let mut writer = Writer::new(v1_0::Text, Vec::new())?;
+        assert!(transcode(&mut reader, &mut writer).is_err());
+        Ok(())
+    }
+}
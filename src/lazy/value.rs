@@ -1,14 +1,16 @@
-use crate::lazy::decoder::Decoder;
+use crate::element::materialize::{materialize, BuildStacks, PlainElements};
+use crate::lazy::decoder::{Decoder, LazyRawValue};
 use crate::lazy::encoding::BinaryEncoding_1_0;
 use crate::lazy::expanded::{ExpandedAnnotationsIterator, ExpandedValueRef, LazyExpandedValue};
+use crate::lazy::lob_reader::LobReader;
 use crate::lazy::r#struct::LazyStruct;
 use crate::lazy::sequence::{LazyList, LazySExp};
 use crate::lazy::value_ref::ValueRef;
 use crate::result::IonFailure;
 use crate::symbol_ref::AsSymbolRef;
 use crate::{
-    Annotations, Element, ExpandedValueSource, IntoAnnotatedElement, IonError, IonResult, IonType,
-    RawSymbolRef, SymbolRef, SymbolTable, Value,
+    Annotations, Element, ExpandedValueSource, IonError, IonResult, IonType, RawSymbolRef, Symbol,
+    SymbolId, SymbolRef, SymbolTable, SymbolToken,
 };
 
 /// A value in a binary Ion stream whose header has been parsed but whose body (i.e. its data) has
@@ -187,6 +189,37 @@ impl<'top, D: Decoder> LazyValue<'top, D> {
         self.expanded_value.is_null()
     }
 
+    /// Returns `true` if this value is a float that was encoded in the data stream using 32 bits.
+    /// Reading a float always produces an `f64`; this hint indicates that converting it back to an
+    /// `f32` is lossless and that writing it with
+    /// [`ValueWriter::write_f32`](crate::ValueWriter::write_f32) preserves its original encoding.
+    ///
+    /// Text Ion does not record the width of a float, and values produced by macro expansion have
+    /// no encoding of their own; in both cases, this method returns `false`.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::v1_0::Binary;
+    /// use ion_rs::{Reader, SequenceWriter, Writer};
+    ///
+    /// let mut writer = Writer::new(Binary, Vec::new())?;
+    /// writer.write(1.5f32)?.write(0.1f64)?;
+    /// let binary_ion = writer.close()?;
+    ///
+    /// let mut reader = Reader::new(Binary, binary_ion)?;
+    /// assert!(reader.expect_next()?.is_encoded_as_f32());
+    /// assert!(!reader.expect_next()?.is_encoded_as_f32());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn is_encoded_as_f32(&self) -> bool {
+        self.raw().map_or(false, |raw| raw.is_encoded_as_f32())
+    }
+
     /// Returns an iterator over the annotations on this value. If this value has no annotations,
     /// the resulting iterator will be empty.
     ///
@@ -229,6 +262,57 @@ impl<'top, D: Decoder> LazyValue<'top, D> {
         self.expanded_value.has_annotations()
     }
 
+    /// If this value is a non-null symbol, returns `Some(token)` describing both its encoding
+    /// (symbol ID or inline text) and its resolved text. Otherwise, returns `None`.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Reader;
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, "$4 $0 foo 5")?;
+    ///
+    /// let token = reader.expect_next()?.symbol_token()?.unwrap();
+    /// assert_eq!((token.symbol_id(), token.text()), (Some(4), Some("name")));
+    ///
+    /// let token = reader.expect_next()?.symbol_token()?.unwrap();
+    /// assert!(token.is_symbol_zero());
+    ///
+    /// let token = reader.expect_next()?.symbol_token()?.unwrap();
+    /// assert_eq!((token.symbol_id(), token.text()), (None, Some("foo")));
+    ///
+    /// assert_eq!(reader.expect_next()?.symbol_token()?, None);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn symbol_token(&self) -> IonResult<Option<SymbolToken<'top>>> {
+        if self.ion_type() != IonType::Symbol || self.is_null() {
+            return Ok(None);
+        }
+        match self.expanded_value.read()? {
+            ExpandedValueRef::Symbol(raw_symbol) => {
+                SymbolToken::resolve(raw_symbol, self.expanded_value.context.symbol_table())
+                    .map(Some)
+            }
+            _ => unreachable!("a non-null symbol value's expanded value is also a symbol"),
+        }
+    }
+
+    /// Returns a view of this value that, when written, reproduces the symbol IDs used in its
+    /// encoding instead of resolving them to text. This applies to symbol values, annotations,
+    /// and field names throughout the value; symbols that were encoded as inline text are written
+    /// as text.
+    ///
+    /// The symbol IDs are only meaningful if the writer's symbol table matches this value's
+    /// symbol table. [`Writer::write_with_symbol_ids`](crate::Writer::write_with_symbol_ids)
+    /// synchronizes the two tables before writing the value.
+    pub fn with_symbol_ids(self) -> SymbolIdPreservingValue<'top, D> {
+        SymbolIdPreservingValue { value: self }
+    }
+
     /// Reads the body of this value (that is: its data) and returns it as a [`ValueRef`].
     /// ```
     ///# use ion_rs::IonResult;
@@ -305,19 +389,156 @@ impl<'top, D: Decoder> LazyValue<'top, D> {
         };
         Ok(value_ref)
     }
+
+    /// Reads the text of this value, which must be a string or a symbol with known text, without
+    /// copying it into a `String`.
+    ///
+    /// Binary Ion text and text Ion strings without escape sequences are borrowed directly from
+    /// the reader's input buffer. Text that contains escape sequences is decoded into scratch
+    /// space that the reader reuses for each top-level value. In either case, the returned `&str`
+    /// remains valid until the reader advances to the next top-level value.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, Reader};
+    ///
+    /// let mut reader = Reader::new(v1_0::Text, r#"["foo", bar, "b\x61z"]"#)?;
+    /// let list = reader.expect_next()?.read()?.expect_list()?;
+    /// let words = list
+    ///     .iter()
+    ///     .map(|value| value?.read_str())
+    ///     .collect::<IonResult<Vec<&str>>>()?;
+    /// assert_eq!(words, ["foo", "bar", "baz"]);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn read_str(&self) -> IonResult<&'top str> {
+        match self.read()? {
+            ValueRef::String(text) => Ok(text.text()),
+            ValueRef::Symbol(symbol) => symbol.text().ok_or_else(|| {
+                IonError::decoding_error("expected text but found a symbol with unknown text")
+            }),
+            other => IonResult::decoding_error(format!(
+                "expected a string or symbol, found {}",
+                describe(&other)
+            )),
+        }
+    }
+
+    /// Reads the bytes of this value, which must be a blob or clob, without copying them into a
+    /// `Vec<u8>`. Like [`read_str`](Self::read_str), the returned slice remains valid until the
+    /// reader advances to the next top-level value.
+    pub fn read_bytes(&self) -> IonResult<&'top [u8]> {
+        match self.read()? {
+            ValueRef::Blob(bytes) | ValueRef::Clob(bytes) => Ok(bytes.data()),
+            other => IonResult::decoding_error(format!(
+                "expected a blob or clob, found {}",
+                describe(&other)
+            )),
+        }
+    }
+
+    /// Returns a [`LobReader`] that reads the payload of this value, which must be a blob or clob,
+    /// a chunk at a time. This makes it possible to copy a binary lob or a text blob to a
+    /// destination like a file without first making a decoded copy of its payload in a `Vec<u8>`.
+    ///
+    /// Like every value, the lob's complete encoding is usually loaded into the reader's input
+    /// buffer before it is returned. The exception is a binary Ion 1.0 lob that does not fit in an
+    /// [`IonStream`](crate::IonStream) buffer with a
+    /// [maximum capacity](crate::BufferStrategy::with_max_capacity): the rest of its payload is
+    /// read from the stream's source as the `LobReader` is read, and [`read`](Self::read) cannot
+    /// be used. Text clobs are decoded in full. See [`LobReader`] for details.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{v1_0, Reader};
+    ///
+    /// let mut reader = Reader::new(v1_0::Text, "{{ aGVsbG8sIHdvcmxkIQ== }}")?;
+    /// let mut lob = reader.expect_next()?.lob_reader()?;
+    ///
+    /// let mut chunk = [0u8; 5];
+    /// let mut payload = Vec::new();
+    /// loop {
+    ///     let length = lob.read_chunk(&mut chunk)?;
+    ///     if length == 0 {
+    ///         break;
+    ///     }
+    ///     payload.extend_from_slice(&chunk[..length]);
+    /// }
+    /// assert_eq!(payload, b"hello, world!");
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn lob_reader(&self) -> IonResult<LobReader<'top>> {
+        if let Some(raw) = self.raw() {
+            let unbuffered_input = self.expanded_value.context.unbuffered_input.get();
+            if let (Some(buffered), Some(input)) = (raw.partial_lob_payload(), unbuffered_input) {
+                return Ok(LobReader::from_partly_buffered(
+                    self.ion_type(),
+                    buffered,
+                    input,
+                ));
+            }
+        }
+        if self.ion_type() == IonType::Blob && !self.is_null() {
+            if let Some(raw) = self.raw() {
+                // Binary lob encodings never begin with `{`, so this identifies a text blob,
+                // whose base64 content is found between its `{{` and `}}`.
+                let encoding = raw.value_span().bytes();
+                if let Some(content) = encoding
+                    .strip_prefix(b"{{")
+                    .and_then(|content| content.strip_suffix(b"}}"))
+                {
+                    return Ok(LobReader::from_base64(content));
+                }
+            }
+        }
+        match self.read()? {
+            ValueRef::Blob(bytes) => Ok(LobReader::from_bytes(IonType::Blob, bytes.data())),
+            ValueRef::Clob(bytes) => Ok(LobReader::from_bytes(IonType::Clob, bytes.data())),
+            other => IonResult::decoding_error(format!(
+                "expected a blob or clob, found {}",
+                describe(&other)
+            )),
+        }
+    }
+}
+
+// Describes the type of a value for use in an error message, distinguishing typed nulls.
+fn describe<D: Decoder>(value: &ValueRef<'_, D>) -> String {
+    match value {
+        ValueRef::Null(ion_type) => format!("null.{ion_type}"),
+        other => format!("a {}", other.ion_type()),
+    }
 }
 
 impl<'top, D: Decoder> TryFrom<LazyValue<'top, D>> for Element {
     type Error = IonError;
 
     fn try_from(lazy_value: LazyValue<'top, D>) -> Result<Self, Self::Error> {
-        let value: Value = lazy_value.read()?.try_into()?;
-        if lazy_value.has_annotations() {
-            let annotations: Annotations = lazy_value.annotations().try_into()?;
-            Ok(value.with_annotations(annotations))
-        } else {
-            Ok(value.into())
-        }
+        materialize(&mut PlainElements, &mut BuildStacks::default(), lazy_value)
+    }
+}
+
+/// A [`LazyValue`] that is written using the symbol IDs found in its encoding rather than their
+/// text. See [`LazyValue::with_symbol_ids`].
+#[derive(Copy, Clone)]
+pub struct SymbolIdPreservingValue<'top, D: Decoder> {
+    pub(crate) value: LazyValue<'top, D>,
+}
+
+impl<'top, D: Decoder> SymbolIdPreservingValue<'top, D> {
+    /// Returns the underlying [`LazyValue`].
+    pub fn value(&self) -> LazyValue<'top, D> {
+        self.value
     }
 }
 
@@ -415,6 +636,100 @@ impl<'top, D: Decoder> AnnotationsIterator<'top, D> {
             IonResult::decoding_error("value annotations did not match expected sequence")
         }
     }
+
+    /// Converts this iterator into one that yields a [`SymbolToken`] for each annotation,
+    /// describing both its encoding (symbol ID or inline text) and its resolved text.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Reader;
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, "$ion_symbol_table::{symbols: [null]} foo::$10::$0::5")?;
+    /// let lazy_value = reader.expect_next()?;
+    /// let tokens = lazy_value
+    ///     .annotations()
+    ///     .tokens()
+    ///     .map(|token| token.map(|t| (t.symbol_id(), t.text())))
+    ///     .collect::<IonResult<Vec<_>>>()?;
+    /// assert_eq!(tokens, vec![(None, Some("foo")), (Some(10), None), (Some(0), None)]);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn tokens(self) -> impl Iterator<Item = IonResult<SymbolToken<'top>>> {
+        let symbol_table = self.symbol_table;
+        self.expanded_annotations
+            .map(move |raw_annotation| SymbolToken::resolve(raw_annotation?, symbol_table))
+    }
+
+    /// Returns `Ok(true)` if any of this iterator's annotations is equal to the provided symbol,
+    /// or `Ok(false)` if not. The symbol may be a `&str`, a [`Symbol`](crate::Symbol), or a
+    /// `&SymbolRef`; symbols with unknown text (`$0`) match annotations whose text is also
+    /// unknown.
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Reader, Symbol, SymbolRef};
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// let mut reader = Reader::new(Text, "foo::$0::5")?;
+    /// let lazy_value = reader.expect_next()?;
+    ///
+    /// assert!(lazy_value.annotations().contains("foo")?);
+    /// assert!(lazy_value.annotations().contains(Symbol::from("foo"))?);
+    /// assert!(lazy_value.annotations().contains(&SymbolRef::with_unknown_text())?);
+    /// assert!(!lazy_value.annotations().contains("bar")?);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn contains<A: AsSymbolRef>(self, annotation: A) -> IonResult<bool> {
+        let annotation = annotation.as_symbol_ref();
+        for actual in self {
+            if actual? == annotation {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Returns `Ok(true)` if any of this iterator's annotations was encoded as the provided symbol
+    /// ID, or `Ok(false)` if not. Annotations that were encoded as inline text never match.
+    ///
+    /// Unlike [`Self::contains`], this method does not resolve annotations using the symbol table,
+    /// so it can be used to inspect data whose symbol IDs cannot be mapped to text (for example,
+    /// data that imports a shared symbol table which is not available in the catalog).
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::Reader;
+    /// use ion_rs::v1_0::Text;
+    ///
+    /// // Symbol ID 200 is not defined in the active symbol table.
+    /// let mut reader = Reader::new(Text, "$200::foo::5")?;
+    /// let lazy_value = reader.expect_next()?;
+    ///
+    /// assert!(lazy_value.annotations().contains_sid(200)?);
+    /// assert!(!lazy_value.annotations().contains_sid(10)?);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn contains_sid(self, sid: SymbolId) -> IonResult<bool> {
+        for raw_annotation in self.expanded_annotations {
+            if matches!(raw_annotation?, RawSymbolRef::SymbolId(actual) if actual == sid) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
 }
 
 impl<'top, D: Decoder> Iterator for AnnotationsIterator<'top, D> {
@@ -439,13 +754,10 @@ impl<'top, D: Decoder> TryFrom<AnnotationsIterator<'top, D>> for Annotations {
     type Error = IonError;
 
     fn try_from(iter: AnnotationsIterator<'top, D>) -> Result<Self, Self::Error> {
-        let annotations = iter
-            .map(|symbol_ref| match symbol_ref {
-                Ok(symbol_ref) => Ok(symbol_ref.to_owned()),
-                Err(e) => Err(e),
-            })
-            .collect::<IonResult<Vec<_>>>()?;
-        Ok(Annotations::from(annotations))
+        // Collecting directly into `Annotations` avoids allocating when there is only one.
+        iter.tokens()
+            .map(|token| token.map(Symbol::from))
+            .collect::<IonResult<Annotations>>()
     }
 }
 
@@ -455,9 +767,10 @@ mod tests {
     use rstest::*;
 
     use crate::lazy::binary::test_utilities::to_binary_ion;
+    use crate::lazy::encoding::Encoding;
     use crate::{
-        ion_list, ion_sexp, ion_struct, v1_0, Decimal, IonResult, IonType, Reader, Symbol,
-        Timestamp,
+        ion_list, ion_sexp, ion_struct, v1_0, AnyEncoding, Decimal, IonResult, IonType,
+        RawSymbolRef, Reader, Symbol, Timestamp, WriteConfig, Writer,
     };
     use crate::{Element, IntoAnnotatedElement};
 
@@ -481,6 +794,107 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn annotations_contain() -> IonResult<()> {
+        let ion_data = to_binary_ion("foo::$0::bar::5")?;
+        let mut reader = Reader::new(v1_0::Binary, ion_data)?;
+        let first = reader.expect_next()?;
+        assert!(first.annotations().contains("bar")?);
+        assert!(first.annotations().contains(Symbol::from("foo"))?);
+        assert!(first.annotations().contains(Symbol::unknown_text())?);
+        assert!(!first.annotations().contains("baz")?);
+
+        // `foo` and `bar` are the first two symbols added to the local symbol table.
+        assert!(first.annotations().contains_sid(0)?);
+        assert!(first.annotations().contains_sid(10)?);
+        assert!(first.annotations().contains_sid(11)?);
+        assert!(!first.annotations().contains_sid(12)?);
+        Ok(())
+    }
+
+    fn rewrite_with_symbol_ids<E: Encoding>(
+        input: impl AsRef<[u8]>,
+        config: impl Into<WriteConfig<E>>,
+    ) -> IonResult<Vec<u8>> {
+        let mut reader = Reader::new(AnyEncoding, input.as_ref())?;
+        let mut writer = Writer::new(config, Vec::new())?;
+        while let Some(value) = reader.next()? {
+            writer.write_with_symbol_ids(value)?;
+        }
+        writer.close()
+    }
+
+    #[test]
+    fn symbol_ids_round_trip() -> IonResult<()> {
+        let input = to_binary_ion(
+            "foo::{bar: baz, quux: [foo, bar::$0, (baz quux)]} $0 'a long symbol'::{}",
+        )?;
+        let output = rewrite_with_symbol_ids(&input, v1_0::Binary)?;
+        assert_eq!(output, input);
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_ids_are_written_even_when_text_is_known() -> IonResult<()> {
+        // The input's symbol table lists `foo` twice, so the second `foo` has symbol ID 11.
+        let input = r#"$ion_symbol_table::{symbols: ["foo", "foo"]} $11::{$11: $11}"#;
+        let output = rewrite_with_symbol_ids(input, v1_0::Text)?;
+        let output_text = std::str::from_utf8(&output).unwrap();
+        assert!(output_text.contains("$11::{$11: $11"), "{output_text}");
+        assert_eq!(Element::read_all(output)?, Element::read_all(input)?,);
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_table_replacement_is_rewritten() -> IonResult<()> {
+        let input = r#"
+            $ion_symbol_table::{symbols: ["a"]} $10
+            $ion_symbol_table::{symbols: ["b"]} $10
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["c"]} $10 $11
+        "#;
+        let output = rewrite_with_symbol_ids(input, v1_0::Binary)?;
+        assert_eq!(Element::read_all(output)?, Element::read_all(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_ids_without_text_are_preserved() -> IonResult<()> {
+        // The symbol table does not define text for `$10` or `$11`.
+        let input = r#"
+            $ion_symbol_table::{symbols: [null, null]}
+            $10::{$11: $10}
+        "#;
+        let output = rewrite_with_symbol_ids(input, v1_0::Text)?;
+        let mut reader = Reader::new(AnyEncoding, output)?;
+        let value = reader.expect_next()?;
+        assert!(value.annotations().contains_sid(10)?);
+        let field = value.read()?.expect_struct()?.iter().next().unwrap()?;
+        assert_eq!(field.raw_name()?, RawSymbolRef::SymbolId(11));
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_tokens() -> IonResult<()> {
+        // Converting to binary would go through `Element`, which does not preserve symbol IDs.
+        let ion_data = r#"$ion_symbol_table::{symbols: ["foo", null]} {$10: $11, $0: foo}"#;
+        let mut reader = Reader::new(v1_0::Text, ion_data)?;
+        let strukt = reader.expect_next()?.read()?.expect_struct()?;
+        let mut fields = strukt.iter();
+
+        let field = fields.next().unwrap()?;
+        let name = field.name_token()?;
+        assert_eq!((name.symbol_id(), name.text()), (Some(10), Some("foo")));
+        let value = field.value().symbol_token()?.unwrap();
+        assert_eq!((value.symbol_id(), value.text()), (Some(11), None));
+        assert!(!value.is_symbol_zero());
+
+        let field = fields.next().unwrap()?;
+        assert!(field.name_token()?.is_symbol_zero());
+        let value = field.value().symbol_token()?.unwrap();
+        assert_eq!((value.symbol_id(), value.text()), (None, Some("foo")));
+        Ok(())
+    }
+
     fn lazy_value_equals(ion_text: &str, expected: impl Into<Element>) -> IonResult<()> {
         let binary_ion = to_binary_ion(ion_text)?;
         let mut reader = Reader::new(v1_0::Binary, binary_ion)?;
@@ -540,6 +954,45 @@ mod tests {
         Ok(())
     }
 
+    #[rstest]
+    #[case::binary_f32(&[0xE0, 0x01, 0x00, 0xEA, 0x44, 0x3F, 0xC0, 0x00, 0x00], true)]
+    #[case::binary_f64(
+        &[0xE0, 0x01, 0x00, 0xEA, 0x48, 0x3F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        false
+    )]
+    #[case::binary_zero(&[0xE0, 0x01, 0x00, 0xEA, 0x40], false)]
+    #[case::binary_null_float(&[0xE0, 0x01, 0x00, 0xEA, 0x4F], false)]
+    #[case::binary_int(&[0xE0, 0x01, 0x00, 0xEA, 0x24, 0x3F, 0xC0, 0x00, 0x00], false)]
+    #[case::text_float("1.5e0".as_bytes(), false)]
+    fn is_encoded_as_f32(#[case] ion_data: &[u8], #[case] expected: bool) -> IonResult<()> {
+        let mut reader = Reader::new(AnyEncoding, ion_data)?;
+        assert_eq!(reader.expect_next()?.is_encoded_as_f32(), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::compact(true, true)]
+    #[case::not_compact(false, false)]
+    fn write_config_compact_floats(
+        #[case] compact_floats: bool,
+        #[case] expected: bool,
+    ) -> IonResult<()> {
+        let mut writer = WriteConfig::<v1_0::Binary>::new()
+            .with_compact_floats(compact_floats)
+            .build_writer(Vec::new())?;
+        writer.write(1.5f64)?.write(1.5f32)?.write(0.1f64)?;
+        let binary_ion = writer.close()?;
+        let mut reader = Reader::new(v1_0::Binary, binary_ion)?;
+        assert_eq!(reader.expect_next()?.is_encoded_as_f32(), expected);
+        // Values written as an `f32` always use the 4-byte encoding...
+        assert!(reader.expect_next()?.is_encoded_as_f32());
+        // ...and values that cannot be represented as an `f32` always use the 8-byte encoding.
+        let value = reader.expect_next()?;
+        assert!(!value.is_encoded_as_f32());
+        assert_eq!(value.read()?.expect_float()?, 0.1f64);
+        Ok(())
+    }
+
     #[rstest]
     #[case::negative_int("-1")]
     #[case::positive_int("1")]
@@ -562,4 +1015,35 @@ mod tests {
         assert!(matches!(result, Err(crate::IonError::Incomplete(_))));
         Ok(())
     }
+
+    #[test]
+    fn read_str_and_bytes() -> IonResult<()> {
+        let text = r#"["plain", "esc\u00e9ped", sym, 'quoted sym', {{aGk=}}, {{"clob"}}]"#;
+        let expected_text = ["plain", "esc\u{e9}ped", "sym", "quoted sym"];
+        for data in [text.as_bytes().to_vec(), to_binary_ion(text)?] {
+            let mut reader = Reader::new(AnyEncoding, data)?;
+            let list = reader.expect_next()?.read()?.expect_list()?;
+            let values = list.iter().collect::<IonResult<Vec<_>>>()?;
+            let strs = values[..4]
+                .iter()
+                .map(|value| value.read_str())
+                .collect::<IonResult<Vec<&str>>>()?;
+            assert_eq!(strs, expected_text);
+            assert_eq!(values[4].read_bytes()?, b"hi");
+            assert_eq!(values[5].read_bytes()?, b"clob");
+            assert!(values[0].read_bytes().is_err());
+            assert!(values[4].read_str().is_err());
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::unknown_symbol_text("$0")]
+    #[case::null_string("null.string")]
+    #[case::int("5")]
+    fn read_str_errors(#[case] text: &str) -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, text)?;
+        assert!(reader.expect_next()?.read_str().is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,285 @@
+//! Depth-first traversal of the values in a [`Reader`]'s stream.
+
+use crate::lazy::decoder::Decoder;
+use crate::lazy::r#struct::StructIterator;
+use crate::lazy::reader::Reader;
+use crate::lazy::sequence::{ListIterator, SExpIterator};
+use crate::lazy::streaming_raw_reader::IonInput;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::{IonResult, IonType, SymbolRef};
+
+/// Describes where a value visited by [`Reader::walk`] is located in the stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueContext<'a> {
+    depth: usize,
+    parent_type: Option<IonType>,
+    field_name: Option<SymbolRef<'a>>,
+}
+
+impl<'a> ValueContext<'a> {
+    /// The number of containers in which the value is nested. Top-level values have a depth of `0`.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The type of the container being traversed, or `None` for top-level values.
+    pub fn parent_type(&self) -> Option<IonType> {
+        self.parent_type
+    }
+
+    /// The value's field name, if its parent is a struct.
+    pub fn field_name(&self) -> Option<&SymbolRef<'a>> {
+        self.field_name.as_ref()
+    }
+
+    /// Whether the value is at the top level of the stream rather than nested in a container.
+    pub fn is_top_level(&self) -> bool {
+        self.depth == 0
+    }
+}
+
+impl<Encoding: Decoder, Input: IonInput> Reader<Encoding, Input> {
+    /// Visits each of the values remaining in the stream in depth-first order, passing `visitor`
+    /// a [`ValueContext`] that describes where the value is located along with the value itself.
+    ///
+    /// If `visitor` returns `Ok(true)` for a container, its child values are visited before the
+    /// container's next sibling. If it returns `Ok(false)`, the container's children are skipped.
+    /// The walk stops at the first error returned by the reader or by `visitor`.
+    ///
+    /// Nested values are visited without recursion, so deeply nested (or malicious) input cannot
+    /// overflow the call stack.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{AnyEncoding, IonType, Reader};
+    ///
+    /// let mut reader = Reader::new(AnyEncoding, "{a: [1, 2], b: {c: 3}} 4")?;
+    /// let mut lines = Vec::new();
+    /// reader.walk(|context, value| {
+    ///     let name = context.field_name().and_then(|name| name.text()).unwrap_or("-");
+    ///     let parent = context.parent_type().map(|t| t.to_string()).unwrap_or_default();
+    ///     lines.push(format!("{} {name} {} {parent}", context.depth(), value.ion_type()));
+    ///     // Don't visit the contents of nested structs
+    ///     Ok(!(value.ion_type() == IonType::Struct && context.depth() > 0))
+    /// })?;
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         "0 - struct ",
+    ///         "1 a list struct",
+    ///         "2 - int list",
+    ///         "2 - int list",
+    ///         "1 b struct struct",
+    ///         "0 - int ",
+    ///     ]
+    /// );
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn walk<F>(&mut self, mut visitor: F) -> IonResult<()>
+    where
+        F: FnMut(&ValueContext<'_>, LazyValue<'_, Encoding>) -> IonResult<bool>,
+    {
+        while let Some(value) = self.next()? {
+            walk_value(value, &mut visitor)?;
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the child values of a container that the walk has stepped into.
+enum Children<'top, D: Decoder> {
+    List(ListIterator<'top, D>),
+    SExp(SExpIterator<'top, D>),
+    Struct(StructIterator<'top, D>),
+}
+
+impl<'top, D: Decoder> Children<'top, D> {
+    fn ion_type(&self) -> IonType {
+        match self {
+            Children::List(_) => IonType::List,
+            Children::SExp(_) => IonType::SExp,
+            Children::Struct(_) => IonType::Struct,
+        }
+    }
+
+    /// Returns the next child value along with its field name, if it has one.
+    fn next(&mut self) -> Option<IonResult<(Option<SymbolRef<'top>>, LazyValue<'top, D>)>> {
+        let child = match self {
+            Children::List(children) => children.next()?.map(|child| (None, child)),
+            Children::SExp(children) => children.next()?.map(|child| (None, child)),
+            Children::Struct(fields) => fields
+                .next()?
+                .and_then(|field| Ok((Some(field.name()?), field.value()))),
+        };
+        Some(child)
+    }
+}
+
+/// Visits the top-level `value` and its nested values in depth-first order.
+///
+/// Rather than recursing into each container, the walk keeps a stack of the containers it has
+/// stepped into. Data can be nested arbitrarily deeply, and a recursive walk of untrusted input
+/// could overflow the call stack.
+fn walk_value<'top, D: Decoder, F>(value: LazyValue<'top, D>, visitor: &mut F) -> IonResult<()>
+where
+    F: FnMut(&ValueContext<'_>, LazyValue<'_, D>) -> IonResult<bool>,
+{
+    let mut stack: Vec<Children<'top, D>> = Vec::new();
+    let context = ValueContext {
+        depth: 0,
+        parent_type: None,
+        field_name: None,
+    };
+    visit(&context, value, visitor, &mut stack)?;
+    while let Some(children) = stack.last_mut() {
+        let parent_type = children.ion_type();
+        let Some(child) = children.next() else {
+            stack.pop();
+            continue;
+        };
+        let (field_name, child) = child?;
+        let context = ValueContext {
+            depth: stack.len(),
+            parent_type: Some(parent_type),
+            field_name,
+        };
+        visit(&context, child, visitor, &mut stack)?;
+    }
+    Ok(())
+}
+
+/// Passes `value` to `visitor` and, if the visitor steps into it, pushes its children onto
+/// `stack`.
+fn visit<'top, D: Decoder, F>(
+    context: &ValueContext<'_>,
+    value: LazyValue<'top, D>,
+    visitor: &mut F,
+    stack: &mut Vec<Children<'top, D>>,
+) -> IonResult<()>
+where
+    F: FnMut(&ValueContext<'_>, LazyValue<'_, D>) -> IonResult<bool>,
+{
+    if !visitor(context, value)? || value.is_null() {
+        return Ok(());
+    }
+    let children = match value.read()? {
+        ValueRef::List(list) => Children::List(list.iter()),
+        ValueRef::SExp(sexp) => Children::SExp(sexp.iter()),
+        ValueRef::Struct(strukt) => Children::Struct(strukt.iter()),
+        _ => return Ok(()),
+    };
+    stack.push(children);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::binary::var_uint::VarUInt;
+    use crate::result::IonFailure;
+    use crate::{v1_0, AnyEncoding, IonError};
+
+    type Visit = (usize, Option<IonType>, Option<String>, IonType);
+
+    fn walk(ion: &str, step_in: impl Fn(&Visit) -> bool) -> IonResult<Vec<Visit>> {
+        let mut reader = Reader::new(AnyEncoding, ion)?;
+        let mut visits = Vec::new();
+        reader.walk(|context, value| {
+            let visit = (
+                context.depth(),
+                context.parent_type(),
+                context
+                    .field_name()
+                    .and_then(|name| name.text())
+                    .map(String::from),
+                value.ion_type(),
+            );
+            let result = step_in(&visit);
+            visits.push(visit);
+            Ok(result)
+        })?;
+        Ok(visits)
+    }
+
+    #[test]
+    fn reports_depth_parent_type_and_field_name() -> IonResult<()> {
+        use IonType::*;
+        let visits = walk("{a: (b [c]), 'd': null.list} e", |_| true)?;
+        let name = |text: &str| Some(text.to_owned());
+        assert_eq!(
+            visits,
+            vec![
+                (0, None, None, Struct),
+                (1, Some(Struct), name("a"), SExp),
+                (2, Some(SExp), None, Symbol),
+                (2, Some(SExp), None, List),
+                (3, Some(List), None, Symbol),
+                (1, Some(Struct), name("d"), List),
+                (0, None, None, Symbol),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn skips_children_when_the_visitor_returns_false() -> IonResult<()> {
+        let visits = walk("[1, [2, [3]]] [4]", |(depth, ..)| *depth < 1)?;
+        let depths: Vec<usize> = visits.iter().map(|(depth, ..)| *depth).collect();
+        assert_eq!(depths, vec![0, 1, 1, 0, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn stops_at_the_first_error() -> IonResult<()> {
+        let mut reader = Reader::new(v1_0::Text, "1 2 3")?;
+        let mut count = 0;
+        let result = reader.walk(|_, _| {
+            count += 1;
+            if count == 2 {
+                return Err(IonError::decoding_error("stop"));
+            }
+            Ok(true)
+        });
+        assert!(result.is_err());
+        assert_eq!(count, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn walks_deeply_nested_values() -> IonResult<()> {
+        const DEPTH: usize = 100_000;
+        // Binary Ion lists, each holding the next, around an empty list. The headers are computed
+        // from the innermost list outward.
+        let mut headers = Vec::with_capacity(DEPTH);
+        let mut length = 1;
+        for _ in 0..DEPTH {
+            let header = if length <= 13 {
+                vec![0xB0 | length as u8]
+            } else {
+                let mut header = vec![0xBE];
+                VarUInt::write_u64(&mut header, length as u64)?;
+                header
+            };
+            length += header.len();
+            headers.push(header);
+        }
+        let mut data = vec![0xE0, 0x01, 0x00, 0xEA];
+        data.extend(headers.iter().rev().flatten());
+        data.push(0xB0);
+
+        let mut reader = Reader::new(v1_0::Binary, data)?;
+        let mut max_depth = 0;
+        reader.walk(|context, _| {
+            max_depth = max_depth.max(context.depth());
+            Ok(true)
+        })?;
+        assert_eq!(max_depth, DEPTH);
+        Ok(())
+    }
+}
@@ -140,12 +140,20 @@ use rstest_reuse;
 
 // Exposed to allow benchmark comparisons between the 1.0 primitives and 1.1 primitives
 pub use catalog::{Catalog, EmptyCatalog, MapCatalog};
+#[cfg(feature = "arbitrary")]
+pub use element::arbitrary_element::ArbitraryElementConfig;
 pub use element::builders::{SequenceBuilder, StructBuilder};
+pub use element::convenience::{
+    from_slice, from_str, to_binary, to_pretty_string, to_string, FromIonStream, ToIonStream,
+};
+#[cfg(feature = "rayon")]
+pub use element::parallel::ParallelElementReader;
+pub use element::spans::{ElementSpans, SourceSpan};
 pub use element::{
-    element_writer::ElementWriter, reader::ElementReader, Annotations, Element,
-    IntoAnnotatedElement, IntoAnnotations, Sequence, Value,
+    element_writer::ElementWriter, reader::ElementReader, Annotations, DeferredValue,
+    DeferredValues, Element, IntoAnnotatedElement, IntoAnnotations, Sequence, TextInterner, Value,
 };
-pub use ion_data::IonData;
+pub use ion_data::{FloatTolerance, IonData, IonEqOptions};
 
 #[doc(inline)]
 pub use result::{IonError, IonResult};
@@ -153,12 +161,31 @@ pub use shared_symbol_table::SharedSymbolTable;
 pub use symbol_ref::SymbolRef;
 #[doc(inline)]
 pub use types::{
-    decimal::Decimal, Blob, Bytes, Clob, Int, IonType, List, Null, SExp, Str, Struct, Symbol,
-    SymbolId, Timestamp, TimestampPrecision, UInt,
+    decimal::Decimal, Blob, Bytes, Clob, FractionalSecondsPrecision, Int, IonType, List, Null,
+    SExp, Str, Struct, Symbol, SymbolId, Timestamp, TimestampPrecision, UInt,
 };
 // Allow access to less commonly used types like decimal::coefficient::{Coefficient, Sign}
 pub use types::decimal;
 
+#[cfg(feature = "experimental-element-apis")]
+pub use element::arena::{ArenaElement, ArenaValue};
+#[cfg(feature = "experimental-element-apis")]
+pub use element::fold::{fold_stream, fold_stream_with_checkpoints, CheckpointConfig, FoldState};
+#[cfg(feature = "experimental-element-apis")]
+pub use element::query::Query;
+#[cfg(feature = "experimental-element-apis")]
+pub use element::retention::{MissingTimestamp, RetainedElements, RetentionFilter};
+#[cfg(feature = "experimental-element-apis")]
+pub use element::shared::SharedElement;
+#[cfg(feature = "experimental-element-apis")]
+pub use element::size::SymbolTablePolicy;
+#[cfg(feature = "experimental-element-apis")]
+pub use element::stats::ElementStats;
+
+#[cfg(feature = "experimental-tooling-apis")]
+pub use crate::text::comments::{AttachedComments, Comment, CommentKind, CommentedDocument};
+#[cfg(feature = "experimental-tooling-apis")]
+pub use crate::text::lossless::{LosslessTextDocument, PathComponent};
 #[cfg(feature = "experimental-tooling-apis")]
 pub use crate::text::text_formatter::{FmtValueFormatter, IoValueFormatter};
 
@@ -171,6 +198,7 @@ mod raw_symbol_ref;
 mod shared_symbol_table;
 mod symbol_ref;
 mod symbol_table;
+mod symbol_token;
 mod text;
 
 // Publicly-visible modules with nested items which users may choose to import
@@ -184,21 +212,27 @@ mod read_config;
 pub mod serde;
 pub(crate) mod unsafe_helpers;
 
+#[cfg(feature = "experimental-ffi")]
+pub mod ffi;
+#[cfg(feature = "experimental-ion-events")]
+pub mod ion_events;
 #[cfg(feature = "experimental-ion-hash")]
 pub mod ion_hash;
+#[cfg(feature = "experimental-ion-lint")]
+pub mod ion_lint;
 pub(crate) mod lazy;
 mod write_config;
 
-pub use crate::lazy::any_encoding::AnyEncoding;
+pub use crate::lazy::any_encoding::{detect_format, AnyEncoding};
 pub use crate::lazy::decoder::{HasRange, HasSpan};
 pub use crate::lazy::span::Span;
-pub use crate::write_config::WriteConfig;
+pub use crate::write_config::{SymbolStyle, WriteConfig};
 
 macro_rules! v1_x_reader_writer {
     ($visibility:vis) => {
         #[allow(unused_imports)]
         $visibility use crate::{
-            lazy::streaming_raw_reader::{IonInput, IonSlice, IonStream},
+            lazy::streaming_raw_reader::{BufferStrategy, IonInput, IonSlice, IonStream},
             lazy::decoder::Decoder,
             lazy::encoder::Encoder,
             lazy::encoding::Encoding,
@@ -206,9 +240,16 @@ macro_rules! v1_x_reader_writer {
             lazy::encoder::write_as_ion::WriteAsIon,
             lazy::encoder::writer::Writer,
             lazy::reader::Reader,
+            lazy::reader_stats::ReaderStats,
+            lazy::transcode::transcode,
+            read_config::ReadConfig,
+            lazy::walk::ValueContext,
             raw_symbol_ref::RawSymbolRef,
             symbol_table::SymbolTable,
-            lazy::value::LazyValue,
+            symbol_token::SymbolToken,
+            lazy::value::{LazyValue, SymbolIdPreservingValue},
+            lazy::lob_reader::LobReader,
+            lazy::encoder::lob_writer::Base64Encoder,
             lazy::value_ref::ValueRef,
             lazy::r#struct::{LazyStruct, LazyField},
             lazy::sequence::{LazyList, LazySExp},
@@ -264,6 +305,7 @@ macro_rules! v1_x_tooling_apis {
             lazy::encoder::{
                 LazyRawWriter
             },
+            lazy::encoder::validating::{ValidatingWriter, WriteMismatch},
             lazy::expanded::r#struct::{
                 LazyExpandedStruct, ExpandedStructSource,
                 LazyExpandedField,
@@ -288,11 +330,17 @@ macro_rules! v1_0_tooling_apis {
             lazy::binary::raw::type_descriptor::Header,
             lazy::raw_value_ref::RawValueRef,
             lazy::encoder::binary::v1_0::writer::LazyRawBinaryWriter_1_0 as RawBinaryWriter,
+            lazy::encoder::binary::v1_0::seeking_writer::LazyRawSeekingBinaryWriter_1_0 as RawSeekingBinaryWriter,
             lazy::encoder::text::v1_0::writer::LazyRawTextWriter_1_0 as RawTextWriter,
             lazy::binary::raw::sequence::{
                 LazyRawBinaryList_1_0 as LazyRawBinaryList,
                 LazyRawBinarySExp_1_0 as LazyRawBinarySExp
             },
+            lazy::binary::raw::inspect::{
+                InspectedItem_1_0 as InspectedItem,
+                InspectedKind,
+                RawBinaryInspector_1_0 as RawBinaryInspector
+            },
             lazy::binary::raw::r#struct::{LazyRawBinaryStruct_1_0 as LazyRawBinaryStruct, LazyRawBinaryFieldName_1_0 as LazyRawBinaryFieldName},
             lazy::binary::raw::value::{
                 LazyRawBinaryValue_1_0 as LazyRawBinaryValue,
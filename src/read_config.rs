@@ -1,14 +1,23 @@
 use crate::catalog::EmptyCatalog;
-use crate::lazy::any_encoding::AnyEncoding;
+use crate::lazy::any_encoding::{AnyEncoding, IonEncoding};
 use crate::lazy::encoding::{
     BinaryEncoding_1_0, BinaryEncoding_1_1, TextEncoding_1_0, TextEncoding_1_1,
 };
-use crate::{Catalog, Decoder};
+use crate::lazy::streaming_raw_reader::BufferStrategy;
+use crate::{Catalog, Decoder, Format};
 
 /// Provides configuration details for reader construction.
 pub struct ReadConfig<D: Decoder> {
     pub(crate) catalog: Box<dyn Catalog>,
     encoding: D,
+    pub(crate) symbol_table_limits: SymbolTableLimits,
+    // The state that the reader's raw reader starts in. For `AnyEncoding`, this is the encoding
+    // used to read input that does not begin with a binary IVM.
+    pub(crate) initial_state: D::ReaderSavedState,
+    // The format that the reader was explicitly configured to read, if any.
+    pub(crate) format: Option<Format>,
+    // If set, replaces the buffer strategy of the reader's input
+    pub(crate) buffer_strategy: Option<BufferStrategy>,
 }
 
 impl<D: Decoder> ReadConfig<D> {
@@ -20,12 +29,117 @@ impl<D: Decoder> ReadConfig<D> {
         ReadConfig {
             catalog: Box::new(catalog),
             encoding,
+            symbol_table_limits: SymbolTableLimits::default(),
+            initial_state: D::ReaderSavedState::default(),
+            format: None,
+            buffer_strategy: None,
         }
     }
 
     pub fn encoding(&self) -> D {
         self.encoding
     }
+
+    /// Sets the maximum number of symbols that the stream's local symbol table may hold at any
+    /// point, not counting system symbols. Symbols added by appending to the local symbol table
+    /// and symbols imported from shared symbol tables count toward the limit. If a symbol table
+    /// in the stream would exceed the limit, the reader raises an error instead of allocating
+    /// space for the additional symbols. By default, there is no limit.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{AnyEncoding, ReadConfig, Reader};
+    ///
+    /// let config = ReadConfig::from(AnyEncoding).with_max_symbols(2);
+    /// let data = "$ion_symbol_table::{symbols: [\"a\", \"b\", \"c\"]} $10";
+    /// let mut reader = Reader::new(config, data)?;
+    /// assert!(reader.next().is_err());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn with_max_symbols(mut self, max_symbols: usize) -> Self {
+        self.symbol_table_limits.max_symbols = max_symbols;
+        self
+    }
+
+    /// Sets the maximum number of bytes of (UTF-8) symbol text that the stream's local symbol
+    /// table may hold at any point. Text imported from shared symbol tables is provided by the
+    /// [`Catalog`] rather than the stream and does not count toward the limit. If a symbol table in
+    /// the stream would exceed the limit, the reader raises an error instead of allocating the
+    /// additional text. By default, there is no limit.
+    pub fn with_max_symbol_text_bytes(mut self, max_symbol_text_bytes: usize) -> Self {
+        self.symbol_table_limits.max_symbol_text_bytes = max_symbol_text_bytes;
+        self
+    }
+
+    /// Sets the strategy used to size the buffer into which the reader copies data from an
+    /// [`io::Read`](std::io::Read) source. Inputs that are already in memory are unaffected.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use std::io::Cursor;
+    /// use ion_rs::{AnyEncoding, BufferStrategy, ElementReader, IonStream, ReadConfig, Reader};
+    ///
+    /// // Small messages don't need a large buffer, but no message may exceed 64 bytes.
+    /// let strategy = BufferStrategy::new()
+    ///     .with_initial_capacity(16)
+    ///     .with_max_capacity(64);
+    /// let config = ReadConfig::from(AnyEncoding).with_buffer_strategy(strategy);
+    ///
+    /// let mut reader = Reader::new(config, IonStream::new(Cursor::new("{id: 1} {id: 2}")))?;
+    /// assert_eq!(reader.read_all_elements()?.len(), 2);
+    ///
+    /// let config = ReadConfig::from(AnyEncoding).with_buffer_strategy(strategy);
+    /// let long_message = format!("\"{}\"", "a".repeat(100));
+    /// let mut reader = Reader::new(config, IonStream::new(Cursor::new(long_message)))?;
+    /// assert!(reader.read_all_elements().is_err());
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn with_buffer_strategy(mut self, strategy: BufferStrategy) -> Self {
+        self.buffer_strategy = Some(strategy);
+        self
+    }
+}
+
+/// Bounds on the contents of a stream's local symbol table. See
+/// [`ReadConfig::with_max_symbols`] and [`ReadConfig::with_max_symbol_text_bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SymbolTableLimits {
+    pub(crate) max_symbols: usize,
+    pub(crate) max_symbol_text_bytes: usize,
+}
+
+impl Default for SymbolTableLimits {
+    fn default() -> Self {
+        Self {
+            max_symbols: usize::MAX,
+            max_symbol_text_bytes: usize::MAX,
+        }
+    }
+}
+
+impl ReadConfig<AnyEncoding> {
+    /// Configures the reader to read input that does not begin with a binary Ion version marker
+    /// as the specified `format` instead of assuming that it is text. If `format` is text, input
+    /// that begins with a binary Ion 1.0 or 1.1 version marker is rejected. See
+    /// [`AnyEncoding::with_format`].
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.initial_state = match format {
+            Format::Text(_) => IonEncoding::Text_1_0,
+            Format::Binary => IonEncoding::Binary_1_0,
+        };
+        self.format = Some(format);
+        self
+    }
 }
 
 impl From<TextEncoding_1_0> for ReadConfig<TextEncoding_1_0> {
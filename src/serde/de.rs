@@ -82,7 +82,18 @@ impl<'a, 'de> de::Deserializer<'de> for ValueDeserializer<'a, 'de> {
         match self.value.ion_type() {
             Null => self.deserialize_unit(visitor),
             Bool => self.deserialize_bool(visitor),
-            Int => self.deserialize_i64(visitor),
+            Int => {
+                // Visit the narrowest type that can hold the value so that visitors that only
+                // support 64-bit integers can still accept most values.
+                let int = self.value.read()?.expect_int()?;
+                if let Some(value) = int.as_i64() {
+                    visitor.visit_i64(value)
+                } else if let Some(value) = int.as_u64() {
+                    visitor.visit_u64(value)
+                } else {
+                    visitor.visit_i128(int.expect_i128()?)
+                }
+            }
             Float => self.deserialize_f64(visitor),
             Decimal => self.deserialize_newtype_struct(TUNNELED_DECIMAL_TYPE_NAME, visitor),
             Timestamp => self.deserialize_newtype_struct(TUNNELED_TIMESTAMP_TYPE_NAME, visitor),
@@ -214,6 +225,23 @@ impl<'a, 'de> de::Deserializer<'de> for ValueDeserializer<'a, 'de> {
         visitor.visit_u64(value)
     }
 
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_i128(self.value.read()?.expect_int()?.expect_i128()?)
+    }
+
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let value = self.value.read()?.expect_int()?.as_u128().ok_or_else(|| {
+            IonError::decoding_error("found an integer was out of bounds for a `u128`")
+        })?;
+        visitor.visit_u128(value)
+    }
+
     fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: Visitor<'de>,
@@ -16,26 +16,26 @@
 //!
 //! ## Mapping of Ion data types to Rust and serde data types
 //!
-//!| Ion data type | Rust data structure                  | Serde data type                                       |
-//!|---------------|--------------------------------------|-------------------------------------------------------|
-//!| int           | u64, i64, u32, i32, u16, i16, u8, i8 | u64, i64, u32, i32, u16, i16, u8, i8                  |
-//!| float         | f32, f64                             | f32, f64                                              |
-//!| decimal       | Decimal(Ion Element API)             | newtype_struct (with name as `$__ion_rs_decimal__`)   |
-//!| timestamp     | Timestamp(Ion Element API)           | newtype_struct (with name as `$__ion_rs_timestamp__`) |
-//!| blob          | byte array                           | byte array                                            |
-//!| clob          | byte array                           | byte array                                            |
-//!| bool          | bool                                 | bool                                                  |
-//!| symbol        | string                               | string                                                |
-//!| string        | string                               | string                                                |
-//!| struct        | struct                               | struct                                                |
-//!| list          | vector                               | seq                                                   |
-//!| null          | None                                 | unit                                                  |
+//!| Ion data type | Rust data structure                              | Serde data type                                       |
+//!|---------------|--------------------------------------------------|-------------------------------------------------------|
+//!| int           | i128, u128, u64, i64, u32, i32, u16, i16, u8, i8 | i128, u128, u64, i64, u32, i32, u16, i16, u8, i8      |
+//!| float         | f32, f64                                         | f32, f64                                              |
+//!| decimal       | Decimal(Ion Element API)                         | newtype_struct (with name as `$__ion_rs_decimal__`)   |
+//!| timestamp     | Timestamp(Ion Element API)                       | newtype_struct (with name as `$__ion_rs_timestamp__`) |
+//!| blob          | byte array                                       | byte array                                            |
+//!| clob          | byte array                                       | byte array                                            |
+//!| bool          | bool                                             | bool                                                  |
+//!| symbol        | string                                           | string                                                |
+//!| string        | string                                           | string                                                |
+//!| struct        | struct                                           | struct                                                |
+//!| list          | vector                                           | seq                                                   |
+//!| null          | None                                             | unit                                                  |
 //!
 //! ## Mapping of serde data types to Ion representation
 //!
 //!| Serde data type                                              | Ion representation                          |
 //!|--------------------------------------------------------------|---------------------------------------------|
-//!| u64, i64, u32, i32, u16, i16, u8, i8                         | int                                         |
+//!| i128, u128\*, u64, i64, u32, i32, u16, i16, u8, i8           | int                                         |
 //!| char, string, unit_variant                                   | string                                      |
 //!| byte-array                                                   | blob                                        |
 //!| option                                                       | None - null, Some - based on other mappings |
@@ -47,6 +47,9 @@
 //!| struct_variant                                               | struct with annotation                      |
 //!| tuple_variant                                                | list with annotation                        |
 //!
+//! \* Ion ints are limited to the range of an `i128`. A `u128` greater than `i128::MAX` (such as a
+//! UUID stored as a `u128`) cannot be serialized; serialize it as a string or byte array instead.
+//!
 //! _Note: Since the serde framework doesn't support [Ion decimal] and [Ion timestamp] types, distinct serialization
 //! and deserialization of these types are defined in this module. It uses `newtype_struct` with `$__ion_rs_decimal__`
 //! and `$__ion_rs_timestamp__` as struct names from [serde data model], to indicate serde framework to use Ion's
@@ -187,6 +190,40 @@
 //! }
 //! ```
 //!
+//! ## Example of transcoding JSON into Ion
+//! [`ValueSerializer`](ser::ValueSerializer) can be paired with a `Deserializer` for another
+//! format and the [`serde_transcode`](https://docs.rs/serde-transcode) crate to convert data into
+//! Ion without first materializing it as Rust values. A [`Writer`](crate::Writer) buffers the
+//! values written to it until it is flushed, so flushing periodically keeps memory usage bounded
+//! when converting a long stream of top-level values.
+//!```
+//! use std::io::BufRead;
+//! use ion_rs::{v1_0, Element, IonResult, SequenceWriter, Writer};
+//! use ion_rs::serde::ser::ValueSerializer;
+//!
+//! fn main() -> IonResult<()> {
+//!     // JSON Lines input; this could be any `BufRead`, such as a large file
+//!     let input = "{\"id\": 1, \"tags\": [\"a\"]}\n{\"id\": 2, \"tags\": []}\n".as_bytes();
+//!
+//!     let mut writer = Writer::new(v1_0::Binary, Vec::new())?;
+//!     for (count, line) in input.lines().enumerate() {
+//!         let line = line?;
+//!         let mut json = serde_json::Deserializer::from_str(&line);
+//!         serde_transcode::transcode(&mut json, ValueSerializer::new(writer.value_writer()))?;
+//!         if count % 1_000 == 999 {
+//!             writer.flush()?;
+//!         }
+//!     }
+//!     let ion = writer.close()?;
+//!
+//!     assert_eq!(
+//!         Element::read_all(ion)?,
+//!         Element::read_all("{id: 1, tags: [\"a\"]} {id: 2, tags: []}")?
+//!     );
+//!     Ok(())
+//! }
+//! ```
+//!
 //! [Ion annotations]: https://amazon-ion.github.io/ion-docs/docs/spec.html#annot
 //! [Ion SExpressions]: https://amazon-ion.github.io/ion-docs/docs/spec.html#sexp
 //! [Ion decimal]: https://amazon-ion.github.io/ion-docs/docs/spec.html#decimal
@@ -208,7 +245,12 @@ mod tests {
 
     use crate::serde::{from_ion, to_pretty, to_string};
 
-    use crate::{Decimal, Element, Timestamp};
+    use crate::lazy::encoding::Encoding;
+    use crate::result::IonFailure;
+    use crate::serde::ser::ValueSerializer;
+    use crate::{
+        v1_0, Decimal, Element, IonError, IonResult, SequenceWriter, Timestamp, WriteConfig, Writer,
+    };
     use chrono::{DateTime, FixedOffset, Utc};
     use serde::{Deserialize, Serialize};
     use serde_with::serde_as;
@@ -346,6 +388,88 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_128_bit_ints() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Ids {
+            signed: i128,
+            unsigned: u128,
+            small: u128,
+        }
+
+        let ids = Ids {
+            signed: i128::MIN + 1,
+            unsigned: i128::MAX as u128,
+            small: 7,
+        };
+        let text = to_string(&ids).unwrap();
+        assert_eq!(
+            Element::read_one(&text).unwrap(),
+            Element::read_one(
+                "{signed: -170141183460469231731687303715884105727, \
+                  unsigned: 170141183460469231731687303715884105727, small: 7}"
+            )
+            .unwrap()
+        );
+        assert_eq!(from_ion::<Ids, _>(text).unwrap(), ids);
+        let binary = to_binary(&ids).unwrap();
+        assert_eq!(from_ion::<Ids, _>(binary).unwrap(), ids);
+
+        // A u128 above i128::MAX cannot be represented as an Int
+        assert_eq!(
+            to_string(&(i128::MAX as u128)).unwrap().trim(),
+            "170141183460469231731687303715884105727"
+        );
+        let uuid: u128 = 0xf81d4fae_7dec_11d0_a765_00a0c91e6bf6;
+        for too_large in [i128::MAX as u128 + 1, uuid, u128::MAX] {
+            assert!(matches!(to_string(&too_large), Err(IonError::Encoding(_))));
+            assert!(matches!(to_binary(&too_large), Err(IonError::Encoding(_))));
+        }
+        assert!(from_ion::<u128, _>("-1").is_err());
+        assert_eq!(
+            from_ion::<u128, _>("18446744073709551616").unwrap(),
+            1u128 << 64
+        );
+    }
+
+    #[test]
+    fn transcode_json() -> IonResult<()> {
+        let json = r#"
+            {"id": 1, "name": "widget", "tags": ["a", "b"], "price": 1.5, "parent": null}
+            {"ok": true, "big": 18446744073709551615, "neg": -3, "esc": "\u00e9\n"}
+            {"nested": {"x": [{}, []]}}
+            [1] "s" 2
+        "#;
+        let expected = Element::read_all(
+            r#"
+            {id: 1, name: "widget", tags: ["a", "b"], price: 1.5e0, parent: null}
+            {ok: true, big: 18446744073709551615, neg: -3, esc: "é\n"}
+            {nested: {x: [{}, []]}}
+            [1] "s" 2
+        "#,
+        )?;
+
+        fn transcode<E: Encoding>(
+            json: &str,
+            config: impl Into<WriteConfig<E>>,
+        ) -> IonResult<Vec<u8>> {
+            let mut writer = Writer::new(config, vec![])?;
+            let mut de = serde_json::Deserializer::from_str(json);
+            for _ in 0..6 {
+                serde_transcode::transcode(&mut de, ValueSerializer::new(writer.value_writer()))?;
+                // Flushing between top-level values must not disturb the stream
+                writer.flush()?;
+            }
+            de.end()
+                .map_err(|e| IonError::decoding_error(e.to_string()))?;
+            writer.close()
+        }
+
+        assert_eq!(Element::read_all(transcode(json, v1_0::Text)?)?, expected);
+        assert_eq!(Element::read_all(transcode(json, v1_0::Binary)?)?, expected);
+        Ok(())
+    }
+
     #[test]
     fn test_symbol() {
         let i = r#"inches"#;
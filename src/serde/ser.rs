@@ -143,6 +143,19 @@ impl<'a, V: ValueWriter + 'a> ser::Serializer for ValueSerializer<'a, V> {
         self.value_writer.write(v)
     }
 
+    /// Serializes an `i128` as an Ion int.
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        self.value_writer.write(v)
+    }
+
+    /// Serializes a `u128` as an Ion int. Ion ints are represented as [`Int`](crate::Int)s, which
+    /// hold an `i128`, so values greater than `i128::MAX` (including most UUIDs stored as a `u128`)
+    /// cannot be serialized and produce an encoding error. Such values can be serialized as
+    /// strings or byte arrays instead, for example with `#[serde(with = "...")]`.
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        self.value_writer.write(v)
+    }
+
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
         self.value_writer.write(v)
     }
@@ -557,6 +570,14 @@ impl ser::Serializer for MapKeySerializer {
         Ok(v.to_string())
     }
 
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
         Ok(v.to_string())
     }
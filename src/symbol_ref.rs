@@ -1,6 +1,6 @@
 use crate::raw_symbol_ref::{AsRawSymbolRef, RawSymbolRef};
 use crate::result::IonFailure;
-use crate::{IonResult, Str, Symbol};
+use crate::{IonResult, Symbol};
 use std::borrow::Borrow;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
@@ -37,7 +37,7 @@ impl<'a> SymbolRef<'a> {
     pub fn to_owned(self) -> Symbol {
         match self.text {
             None => Symbol::unknown_text(),
-            Some(text) => Symbol::owned(Str::from(text)),
+            Some(text) => Symbol::from(text),
         }
     }
 
@@ -124,6 +124,14 @@ impl AsSymbolRef for &Symbol {
     }
 }
 
+// `SymbolRef` itself cannot implement `AsSymbolRef`; doing so would conflict with its
+// `PartialEq<A: AsSymbolRef>` implementation. A reference to a `SymbolRef` can be used instead.
+impl<'a, 'b> AsSymbolRef for &'b SymbolRef<'a> {
+    fn as_symbol_ref(&self) -> SymbolRef<'_> {
+        **self
+    }
+}
+
 impl<'a> AsRawSymbolRef for SymbolRef<'a> {
     fn as_raw_symbol_token_ref(&self) -> RawSymbolRef {
         match &self.text {
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use crate::constants::v1_0;
@@ -11,6 +12,16 @@ use crate::{Symbol, SymbolId};
 pub struct SymbolTable {
     symbols_by_id: Vec<Symbol>,
     ids_by_text: HashMap<Symbol, SymbolId>,
+    // Distinguishes this table's contents from those of any other table, or of this table before
+    // it was last reset. See `SymbolTable::id`.
+    id: usize,
+}
+
+// The source of `SymbolTable` IDs
+static NEXT_SYMBOL_TABLE_ID: AtomicUsize = AtomicUsize::new(0);
+
+fn next_symbol_table_id() -> usize {
+    NEXT_SYMBOL_TABLE_ID.fetch_add(1, Ordering::Relaxed)
 }
 
 impl Default for SymbolTable {
@@ -25,6 +36,7 @@ impl SymbolTable {
         let mut symbol_table = SymbolTable {
             symbols_by_id: Vec::with_capacity(v1_0::SYSTEM_SYMBOLS.len()),
             ids_by_text: HashMap::new(),
+            id: next_symbol_table_id(),
         };
         symbol_table.initialize();
         symbol_table
@@ -45,9 +57,17 @@ impl SymbolTable {
     pub(crate) fn reset(&mut self) {
         self.symbols_by_id.clear();
         self.ids_by_text.clear();
+        self.id = next_symbol_table_id();
         self.initialize();
     }
 
+    /// Returns a value that is unique to this table and changes each time the table is reset.
+    /// Symbols are otherwise only ever appended, so if a table's ID and length are unchanged, so
+    /// are its symbols.
+    pub(crate) fn id(&self) -> usize {
+        self.id
+    }
+
     /// adds `text` to the symbol table and returns the newly assigned [SymbolId].
     pub(crate) fn add_symbol_for_text<A: AsRef<str>>(&mut self, text: A) -> SymbolId {
         let arc: Arc<str> = Arc::from(text.as_ref());
@@ -0,0 +1,119 @@
+use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::result::IonFailure;
+use crate::{IonResult, RawSymbolRef, SymbolId, SymbolRef, SymbolTable};
+
+/// A symbol as it was encoded in an Ion stream, paired with its resolved text (if any).
+///
+/// Resolving a symbol ID to a [`SymbolRef`] discards the symbol ID, which makes `$0` and a symbol
+/// ID whose text is undefined in the symbol table indistinguishable. A `SymbolToken` retains both
+/// pieces of information:
+///
+/// | Encoded as          | `symbol_id()` | `text()`      |
+/// |---------------------|---------------|---------------|
+/// | inline text `foo`   | `None`        | `Some("foo")` |
+/// | `$10` (text `foo`)  | `Some(10)`    | `Some("foo")` |
+/// | `$10` (no text)     | `Some(10)`    | `None`        |
+/// | `$0`                | `Some(0)`     | `None`        |
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SymbolToken<'a> {
+    raw: RawSymbolRef<'a>,
+    text: Option<&'a str>,
+}
+
+impl<'a> SymbolToken<'a> {
+    /// Resolves `raw` using the provided symbol table. Returns an error if `raw` is a symbol ID
+    /// that is not in the table.
+    pub(crate) fn resolve(raw: RawSymbolRef<'a>, symbol_table: &'a SymbolTable) -> IonResult<Self> {
+        let text = match raw {
+            RawSymbolRef::SymbolId(sid) => match symbol_table.symbol_for(sid) {
+                Some(symbol) => symbol.text(),
+                None => {
+                    return IonResult::decoding_error(format!(
+                        "found a symbol ID (${sid}) that was not in the symbol table"
+                    ))
+                }
+            },
+            RawSymbolRef::Text(text) => Some(text),
+        };
+        Ok(SymbolToken { raw, text })
+    }
+
+    /// If this symbol was encoded as a symbol ID, returns `Some(symbol_id)`. If it was encoded as
+    /// inline text, returns `None`.
+    pub fn symbol_id(&self) -> Option<SymbolId> {
+        match self.raw {
+            RawSymbolRef::SymbolId(sid) => Some(sid),
+            RawSymbolRef::Text(_) => None,
+        }
+    }
+
+    /// If this symbol has known text, returns `Some(&str)`. Otherwise, returns `None`.
+    pub fn text(&self) -> Option<&'a str> {
+        self.text
+    }
+
+    /// Returns `true` if this symbol was encoded as symbol ID zero (`$0`).
+    pub fn is_symbol_zero(&self) -> bool {
+        self.symbol_id() == Some(0)
+    }
+
+    /// Returns the symbol as it was encoded: either a symbol ID or inline text.
+    pub fn raw(&self) -> RawSymbolRef<'a> {
+        self.raw
+    }
+
+    /// Returns the resolved symbol, discarding its symbol ID.
+    pub fn resolved(&self) -> SymbolRef<'a> {
+        match self.text {
+            Some(text) => SymbolRef::with_text(text),
+            None => SymbolRef::with_unknown_text(),
+        }
+    }
+}
+
+// Writing a `SymbolToken` reproduces its original encoding.
+impl<'a> AsRawSymbolRef for SymbolToken<'a> {
+    fn as_raw_symbol_token_ref(&self) -> RawSymbolRef<'_> {
+        self.raw
+    }
+}
+
+impl<'a> From<SymbolToken<'a>> for SymbolRef<'a> {
+    fn from(token: SymbolToken<'a>) -> Self {
+        token.resolved()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::text(RawSymbolRef::Text("foo"), None, Some("foo"))]
+    #[case::symbol_id(RawSymbolRef::SymbolId(4), Some(4), Some("name"))]
+    #[case::unknown_text(RawSymbolRef::SymbolId(10), Some(10), None)]
+    #[case::symbol_zero(RawSymbolRef::SymbolId(0), Some(0), None)]
+    fn resolve(
+        #[case] raw: RawSymbolRef,
+        #[case] symbol_id: Option<SymbolId>,
+        #[case] text: Option<&str>,
+    ) -> IonResult<()> {
+        let mut symbol_table = SymbolTable::new();
+        symbol_table.add_placeholder();
+        let token = SymbolToken::resolve(raw, &symbol_table)?;
+        assert_eq!(token.symbol_id(), symbol_id);
+        assert_eq!(token.text(), text);
+        assert_eq!(token.raw(), raw);
+        assert_eq!(token.is_symbol_zero(), symbol_id == Some(0));
+        assert_eq!(token.resolved().text(), text);
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_out_of_range() {
+        let symbol_table = SymbolTable::new();
+        assert!(SymbolToken::resolve(RawSymbolRef::SymbolId(10), &symbol_table).is_err());
+    }
+}
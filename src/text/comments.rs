@@ -0,0 +1,769 @@
+//! Reading and writing text Ion documents without losing their comments.
+//!
+//! Comments are not part of the Ion data model, so readers discard them. [`CommentedDocument`]
+//! reads a text Ion document into `Element`s and also records each comment that it contains,
+//! attaching it to a nearby value by position. When the document is written back out, each comment
+//! is emitted next to the value it was attached to. This allows tools like configuration file
+//! formatters to normalize the layout of a document without destroying the commentary in it.
+
+use std::fmt::Write;
+use std::ops::Range;
+
+use crate::lazy::decoder::HasRange;
+use crate::lazy::system_reader::SystemReader;
+use crate::lazy::system_stream_item::SystemStreamItem;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::result::IonFailure;
+use crate::text::lossless::PathComponent;
+use crate::text::text_formatter::FmtValueFormatter;
+use crate::text::whitespace_config::PRETTY_WHITESPACE_CONFIG;
+use crate::{v1_0, Element, IonError, IonResult, IonType, Symbol, Value};
+
+/// The syntax used to write a [`Comment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentKind {
+    /// A comment that begins with `//` and ends at the end of the line.
+    Line,
+    /// A comment that begins with `/*` and ends with `*/`.
+    Block,
+}
+
+/// A comment in a text Ion document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    kind: CommentKind,
+    text: String,
+    // The range of source bytes occupied by the comment, including its delimiters.
+    range: Option<Range<usize>>,
+}
+
+impl Comment {
+    /// Constructs a `//` comment. `text` must not contain a line break.
+    pub fn line(text: impl Into<String>) -> Self {
+        Self {
+            kind: CommentKind::Line,
+            text: text.into(),
+            range: None,
+        }
+    }
+
+    /// Constructs a `/* */` comment. `text` must not contain `*/`.
+    pub fn block(text: impl Into<String>) -> Self {
+        Self {
+            kind: CommentKind::Block,
+            text: text.into(),
+            range: None,
+        }
+    }
+
+    pub fn kind(&self) -> CommentKind {
+        self.kind
+    }
+
+    /// The text of the comment, not including its delimiters.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The range of source bytes (including the delimiters) that the comment was read from, or
+    /// `None` if it was not read from a source document.
+    pub fn range(&self) -> Option<Range<usize>> {
+        self.range.clone()
+    }
+
+    fn write_to(&self, output: &mut String) {
+        match self.kind {
+            CommentKind::Line => write!(output, "//{}", self.text),
+            CommentKind::Block => write!(output, "/*{}*/", self.text),
+        }
+        .unwrap()
+    }
+}
+
+/// The comments attached to a value in a [`CommentedDocument`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AttachedComments {
+    leading: Vec<Comment>,
+    trailing: Vec<Comment>,
+    dangling: Vec<Comment>,
+}
+
+impl AttachedComments {
+    /// Comments that appear before the value, each on a line of its own.
+    pub fn leading(&self) -> &[Comment] {
+        &self.leading
+    }
+
+    /// Comments that appear after the value on the same line.
+    pub fn trailing(&self) -> &[Comment] {
+        &self.trailing
+    }
+
+    /// For containers, comments that appear after the last child value, before the closing
+    /// delimiter.
+    pub fn dangling(&self) -> &[Comment] {
+        &self.dangling
+    }
+
+    pub fn leading_mut(&mut self) -> &mut Vec<Comment> {
+        &mut self.leading
+    }
+
+    pub fn trailing_mut(&mut self) -> &mut Vec<Comment> {
+        &mut self.trailing
+    }
+
+    pub fn dangling_mut(&mut self) -> &mut Vec<Comment> {
+        &mut self.dangling
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty() && self.trailing.is_empty() && self.dangling.is_empty()
+    }
+}
+
+/// The comments attached to a value and to each of its child values, in the same order as the
+/// value's children.
+#[derive(Debug, Clone, Default)]
+struct CommentNode {
+    comments: AttachedComments,
+    children: Vec<CommentNode>,
+}
+
+impl CommentNode {
+    fn child(&self, element: &Element, component: &PathComponent) -> Option<(usize, &CommentNode)> {
+        let index = match (component, element.value()) {
+            (PathComponent::Index(index), Value::List(_) | Value::SExp(_)) => *index,
+            (PathComponent::Field(name), Value::Struct(fields)) => fields
+                .fields()
+                .position(|(field_name, _)| field_name.text() == Some(name.as_str()))?,
+            _ => return None,
+        };
+        self.children.get(index).map(|child| (index, child))
+    }
+}
+
+/// A value's location in the source text, recorded while reading the document.
+struct SourceNode {
+    // The range of the value, including its annotations
+    range: Range<usize>,
+    // The offset at which the value itself begins, following any annotations
+    value_start: usize,
+    is_scalar: bool,
+    // The index of the containing value in the list of nodes
+    parent: Option<usize>,
+}
+
+/// A text Ion 1.0 document read along with its comments.
+///
+/// Each comment in the source is attached to a value:
+/// * a comment that follows a value on the same line (separated by nothing other than whitespace
+///   and commas) is a *trailing* comment of that value.
+/// * a comment that appears after the last value in a container is a *dangling* comment of the
+///   container.
+/// * any other comment is a *leading* comment of the value that follows it.
+///
+/// Comments that follow the last top-level value are attached to the document itself. A comment
+/// between a value's annotations (or between its annotations and the value) is a leading comment
+/// of the value. Comments inside a symbol table are not recorded.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # #[cfg(feature = "experimental-tooling-apis")]
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::{CommentedDocument, PathComponent};
+///
+/// let source = r#"
+///     // Service configuration
+///     {port: 8080, /* the default */ name: "my-service"}  // end of config
+/// "#;
+/// let document = CommentedDocument::parse(source)?;
+/// let config = document.comments(&[PathComponent::Index(0)]).unwrap();
+/// assert_eq!(config.leading()[0].text(), " Service configuration");
+/// assert_eq!(config.trailing()[0].text(), " end of config");
+///
+/// assert_eq!(
+///     document.to_text(),
+///     r#"// Service configuration
+/// {
+///   port: 8080, /* the default */
+///   name: "my-service",
+/// } // end of config
+/// "#
+/// );
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "experimental-tooling-apis"))]
+/// # fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CommentedDocument {
+    elements: Vec<Element>,
+    // The comments attached to each top-level value and its children.
+    nodes: Vec<CommentNode>,
+    // Comments that follow the last top-level value.
+    end_comments: Vec<Comment>,
+}
+
+impl CommentedDocument {
+    /// Reads the provided text Ion 1.0 data, recording each of the comments it contains.
+    pub fn parse(source: impl AsRef<str>) -> IonResult<Self> {
+        let source = source.as_ref();
+        let mut elements = Vec::new();
+        let mut source_nodes = Vec::new();
+        let mut top_level = Vec::new();
+        // The ranges of source text in which comments are not looked for: scalar values (not
+        // including their annotations) and symbol tables, in the order they appear.
+        let mut skipped = Vec::new();
+        let mut reader = SystemReader::new(v1_0::Text, source)?;
+        loop {
+            match reader.next_item()? {
+                SystemStreamItem::Value(value) => {
+                    let first_node = source_nodes.len();
+                    top_level.push(first_node);
+                    record_source_nodes(value, None, &mut source_nodes)?;
+                    skipped.extend(
+                        source_nodes[first_node..]
+                            .iter()
+                            .filter(|node| node.is_scalar)
+                            .map(|node| node.value_start..node.range.end),
+                    );
+                    elements.push(Element::try_from(value)?);
+                }
+                SystemStreamItem::SymbolTable(symbol_table) => {
+                    skipped.extend(symbol_table.as_value().raw().map(|raw| raw.range()));
+                }
+                SystemStreamItem::EndOfStream(_) => break,
+                _ => {}
+            }
+        }
+
+        let mut attached = vec![AttachedComments::default(); source_nodes.len()];
+        let mut end_comments = Vec::new();
+        let comments = find_comments(source, &skipped);
+        for (comment, attachment) in attach_comments(source, &source_nodes, comments) {
+            match attachment {
+                Some((index, Placement::Leading)) => attached[index].leading.push(comment),
+                Some((index, Placement::Trailing)) => attached[index].trailing.push(comment),
+                Some((index, Placement::Dangling)) => attached[index].dangling.push(comment),
+                None => end_comments.push(comment),
+            }
+        }
+
+        // Assemble the comments into trees that mirror the elements. Nodes are in depth-first
+        // order, so each node's children follow it. Visiting the nodes from last to first moves
+        // each node into its parent only after all of its own children have been moved into it
+        // (in reverse order).
+        let mut nodes: Vec<Option<CommentNode>> = attached
+            .into_iter()
+            .map(|comments| {
+                Some(CommentNode {
+                    comments,
+                    children: Vec::new(),
+                })
+            })
+            .collect();
+        for index in (0..nodes.len()).rev() {
+            let node = nodes[index].as_mut().unwrap();
+            node.children.reverse();
+            if let Some(parent) = source_nodes[index].parent {
+                let node = nodes[index].take().unwrap();
+                nodes[parent].as_mut().unwrap().children.push(node);
+            }
+        }
+        let nodes = top_level
+            .into_iter()
+            .map(|index| nodes[index].take().unwrap())
+            .collect();
+
+        Ok(Self {
+            elements,
+            nodes,
+            end_comments,
+        })
+    }
+
+    /// Returns the top-level values in the document.
+    pub fn elements(&self) -> &[Element] {
+        &self.elements
+    }
+
+    /// Returns the comments attached to the value at `path`, or `None` if there is no value at
+    /// `path`. The first component of the path must be the index of a top-level value.
+    pub fn comments(&self, path: &[PathComponent]) -> Option<&AttachedComments> {
+        let (node_index, nested_path) = self.top_level_index(path)?;
+        let mut element = &self.elements[node_index];
+        let mut node = &self.nodes[node_index];
+        for component in nested_path {
+            let (index, child) = node.child(element, component)?;
+            element = nth_child(element, index)?;
+            node = child;
+        }
+        Some(&node.comments)
+    }
+
+    /// Like [`comments`](Self::comments), but allows the comments to be modified.
+    pub fn comments_mut(&mut self, path: &[PathComponent]) -> Option<&mut AttachedComments> {
+        let (node_index, nested_path) = self.top_level_index(path)?;
+        let mut element = &self.elements[node_index];
+        let mut node = &mut self.nodes[node_index];
+        for component in nested_path {
+            let (index, _) = node.child(element, component)?;
+            element = nth_child(element, index)?;
+            node = &mut node.children[index];
+        }
+        Some(&mut node.comments)
+    }
+
+    /// Returns the comments that follow the last top-level value.
+    pub fn end_comments(&self) -> &[Comment] {
+        &self.end_comments
+    }
+
+    pub fn end_comments_mut(&mut self) -> &mut Vec<Comment> {
+        &mut self.end_comments
+    }
+
+    /// Writes the document as pretty-printed text Ion, emitting each comment next to the value it
+    /// is attached to.
+    pub fn to_text(&self) -> String {
+        let mut output = String::new();
+        for (element, node) in self.elements.iter().zip(&self.nodes) {
+            write_value(&mut output, element, node, None, 0, "");
+        }
+        for comment in &self.end_comments {
+            comment.write_to(&mut output);
+            output.push('\n');
+        }
+        output
+    }
+
+    fn top_level_index<'a>(
+        &self,
+        path: &'a [PathComponent],
+    ) -> Option<(usize, &'a [PathComponent])> {
+        match path.split_first() {
+            Some((PathComponent::Index(index), nested_path)) if *index < self.elements.len() => {
+                Some((*index, nested_path))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn nth_child(element: &Element, index: usize) -> Option<&Element> {
+    match element.value() {
+        Value::List(sequence) | Value::SExp(sequence) => sequence.get(index),
+        Value::Struct(fields) => fields.fields().nth(index).map(|(_, value)| value),
+        _ => None,
+    }
+}
+
+/// Records the location of `value` and each of its nested values in depth-first order.
+fn record_source_nodes(
+    value: LazyValue<'_, v1_0::Text>,
+    parent: Option<usize>,
+    nodes: &mut Vec<SourceNode>,
+) -> IonResult<()> {
+    let raw = value
+        .raw()
+        .ok_or_else(|| IonError::illegal_operation("value was not found in the source text"))?;
+    let index = nodes.len();
+    nodes.push(SourceNode {
+        range: raw.range(),
+        value_start: raw.data_range().start,
+        is_scalar: !value.ion_type().is_container() || value.is_null(),
+        parent,
+    });
+    match value.read()? {
+        ValueRef::List(list) => {
+            for child in list.iter() {
+                record_source_nodes(child?, Some(index), nodes)?;
+            }
+        }
+        ValueRef::SExp(sexp) => {
+            for child in sexp.iter() {
+                record_source_nodes(child?, Some(index), nodes)?;
+            }
+        }
+        ValueRef::Struct(strukt) => {
+            for field in strukt.iter() {
+                record_source_nodes(field?.value(), Some(index), nodes)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Scans `source` for comments, skipping over the `skipped` ranges (which must be in order) and
+/// quoted field names.
+fn find_comments(source: &str, skipped: &[Range<usize>]) -> Vec<Comment> {
+    let bytes = source.as_bytes();
+    let mut skipped = skipped.iter().peekable();
+    let mut comments = Vec::new();
+    let mut position = 0;
+    while position < bytes.len() {
+        // Skip over any skipped range that contains this position.
+        while matches!(skipped.peek(), Some(range) if range.end <= position) {
+            skipped.next();
+        }
+        if let Some(range) = skipped.peek() {
+            if range.start <= position {
+                position = range.end;
+                continue;
+            }
+        }
+        match (bytes[position], bytes.get(position + 1)) {
+            (quote @ (b'"' | b'\''), _) => {
+                position += 1;
+                while position < bytes.len() && bytes[position] != quote {
+                    position += if bytes[position] == b'\\' { 2 } else { 1 };
+                }
+                position += 1;
+            }
+            (b'/', Some(b'/')) => {
+                let end = source[position..]
+                    .find('\n')
+                    .map_or(bytes.len(), |offset| position + offset);
+                let text = source[position + 2..end].trim_end_matches('\r');
+                comments.push(Comment {
+                    range: Some(position..position + 2 + text.len()),
+                    ..Comment::line(text)
+                });
+                position = end;
+            }
+            (b'/', Some(b'*')) => {
+                let end = source[position + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |offset| position + 2 + offset + 2);
+                let text = &source[position + 2..end.saturating_sub(2).max(position + 2)];
+                comments.push(Comment {
+                    range: Some(position..end),
+                    ..Comment::block(text)
+                });
+                position = end;
+            }
+            _ => position += 1,
+        }
+    }
+    comments
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placement {
+    Leading,
+    Trailing,
+    Dangling,
+}
+
+/// Pairs each of `comments` with the index of the node to which it should be attached and how it
+/// should be attached, or with `None` if it follows the last top-level value.
+///
+/// Both the nodes and the comments are in source order, so this makes a single pass over them,
+/// keeping a stack of the nodes that are open at the current comment.
+fn attach_comments(
+    source: &str,
+    nodes: &[SourceNode],
+    comments: Vec<Comment>,
+) -> Vec<(Comment, Option<(usize, Placement)>)> {
+    // The nodes that begin before the current comment and that have not been seen to end
+    let mut open: Vec<usize> = Vec::new();
+    // The node that most recently ended before the current comment
+    let mut preceding: Option<usize> = None;
+    // The first node that has not yet been opened
+    let mut next = 0;
+    // Pops the open nodes that end at or before `position`, the last of which is the one that ends
+    // closest before it.
+    let close_before = |open: &mut Vec<usize>, preceding: &mut Option<usize>, position: usize| {
+        while let Some(&index) = open.last() {
+            if nodes[index].range.end > position {
+                break;
+            }
+            *preceding = open.pop();
+        }
+    };
+    let mut attachments = Vec::with_capacity(comments.len());
+    for comment in comments {
+        let range = comment.range.clone().unwrap();
+        while next < nodes.len() && nodes[next].range.start < range.start {
+            close_before(&mut open, &mut preceding, nodes[next].range.start);
+            open.push(next);
+            next += 1;
+        }
+        close_before(&mut open, &mut preceding, range.start);
+        // A comment among a value's annotations belongs to the value. That value is the last node
+        // to have been opened.
+        if let Some(&index) = open.last() {
+            if range.end <= nodes[index].value_start {
+                attachments.push((comment, Some((index, Placement::Leading))));
+                continue;
+            }
+        }
+        if let Some(index) = preceding {
+            let between = &source[nodes[index].range.end..range.start];
+            if between
+                .chars()
+                .all(|c| c == ',' || (c.is_whitespace() && c != '\n'))
+            {
+                attachments.push((comment, Some((index, Placement::Trailing))));
+                continue;
+            }
+        }
+        // The innermost container that encloses the comment
+        let enclosing = open
+            .last()
+            .copied()
+            .filter(|&index| range.end <= nodes[index].range.end);
+        // The first value that begins after the comment
+        let following = nodes.get(next).map(|_| next);
+        let attachment = match (enclosing, following) {
+            (Some(container), Some(index))
+                if nodes[index].range.start >= nodes[container].range.end =>
+            {
+                Some((container, Placement::Dangling))
+            }
+            (Some(container), None) => Some((container, Placement::Dangling)),
+            (_, Some(index)) => Some((index, Placement::Leading)),
+            (None, None) => None,
+        };
+        attachments.push((comment, attachment));
+    }
+    attachments
+}
+
+fn write_indentation(output: &mut String, depth: usize) {
+    for _ in 0..depth {
+        output.push_str(PRETTY_WHITESPACE_CONFIG.indentation);
+    }
+}
+
+fn write_comment_lines(output: &mut String, comments: &[Comment], depth: usize) {
+    for comment in comments {
+        write_indentation(output, depth);
+        comment.write_to(output);
+        output.push('\n');
+    }
+}
+
+/// Writes `element` (preceded by `field_name`, if any) and its attached comments on lines of
+/// their own at the specified depth, followed by `separator`.
+fn write_value(
+    output: &mut String,
+    element: &Element,
+    node: &CommentNode,
+    field_name: Option<&Symbol>,
+    depth: usize,
+    separator: &str,
+) {
+    let comments = &node.comments;
+    write_comment_lines(output, &comments.leading, depth);
+    write_indentation(output, depth);
+    if let Some(name) = field_name {
+        FmtValueFormatter::new(output).format_symbol(name).unwrap();
+        output.push_str(": ");
+    }
+    let (open, close, child_separator) = match element.ion_type() {
+        _ if element.is_null() => ("", "", ""),
+        IonType::List => ("[", "]", ","),
+        IonType::SExp => ("(", ")", ""),
+        IonType::Struct => ("{", "}", ","),
+        _ => ("", "", ""),
+    };
+    if open.is_empty() {
+        write!(output, "{element}").unwrap();
+    } else {
+        FmtValueFormatter::new(output)
+            .format_annotations(element.annotations())
+            .unwrap();
+        output.push_str(open);
+        if !node.children.is_empty() || !comments.dangling.is_empty() {
+            output.push('\n');
+            let children = node.children.iter();
+            match element.value() {
+                Value::Struct(fields) => {
+                    for ((name, child), child_node) in fields.fields().zip(children) {
+                        let name = Some(name);
+                        write_value(output, child, child_node, name, depth + 1, child_separator);
+                    }
+                }
+                Value::List(sequence) | Value::SExp(sequence) => {
+                    for (child, child_node) in sequence.elements().zip(children) {
+                        write_value(output, child, child_node, None, depth + 1, child_separator);
+                    }
+                }
+                _ => unreachable!("only containers have children"),
+            }
+            write_comment_lines(output, &comments.dangling, depth + 1);
+            write_indentation(output, depth);
+        }
+        output.push_str(close);
+    }
+    output.push_str(separator);
+    for (index, comment) in comments.trailing.iter().enumerate() {
+        if index > 0 && comments.trailing[index - 1].kind == CommentKind::Line {
+            // A line comment extends to the end of the line, so the next comment needs a new one.
+            output.push('\n');
+            write_indentation(output, depth);
+        } else {
+            output.push(' ');
+        }
+        comment.write_to(output);
+    }
+    output.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"$ion_1_0
+// Inventory
+/* generated */
+{
+  sku: "A-100 // not a comment",   // legacy id
+  'quoted // field': 0x0A, /* hex */
+  tags: [red, /* inline */ '''large''', 1_000],
+  // nothing follows
+}
+(a /* b */ c) // sexp
+/* trailing */"#;
+
+    fn texts(comments: &[Comment]) -> Vec<&str> {
+        comments.iter().map(Comment::text).collect()
+    }
+
+    #[test]
+    fn attaches_comments_by_position() -> IonResult<()> {
+        let document = CommentedDocument::parse(SOURCE)?;
+        let path = |components: &[PathComponent]| document.comments(components).unwrap();
+
+        let inventory = path(&[0.into()]);
+        assert_eq!(texts(inventory.leading()), [" Inventory", " generated "]);
+        assert_eq!(texts(inventory.dangling()), [" nothing follows"]);
+        assert!(inventory.trailing().is_empty());
+        assert_eq!(
+            texts(path(&[0.into(), "sku".into()]).trailing()),
+            [" legacy id"]
+        );
+        assert_eq!(
+            texts(path(&[0.into(), "quoted // field".into()]).trailing()),
+            [" hex "]
+        );
+        // A comment that follows a value on the same line trails it, even inside a container
+        assert_eq!(
+            texts(path(&[0.into(), "tags".into(), 0.into()]).trailing()),
+            [" inline "]
+        );
+        assert_eq!(texts(path(&[1.into(), 0.into()]).trailing()), [" b "]);
+        assert_eq!(texts(path(&[1.into()]).trailing()), [" sexp"]);
+        assert_eq!(texts(document.end_comments()), [" trailing "]);
+        assert!(document.comments(&[2.into()]).is_none());
+        assert!(document.comments(&[0.into(), "missing".into()]).is_none());
+
+        let comment = &path(&[0.into()]).leading()[0];
+        assert_eq!(comment.kind(), CommentKind::Line);
+        assert_eq!(&SOURCE[comment.range().unwrap()], "// Inventory");
+        Ok(())
+    }
+
+    #[test]
+    fn writes_comments_next_to_their_values() -> IonResult<()> {
+        let document = CommentedDocument::parse(SOURCE)?;
+        let expected = r#"// Inventory
+/* generated */
+{
+  sku: "A-100 \/\/ not a comment", // legacy id
+  'quoted \/\/ field': 10, /* hex */
+  tags: [
+    red, /* inline */
+    "large",
+    1000,
+  ],
+  // nothing follows
+}
+(
+  a /* b */
+  c
+) // sexp
+/* trailing */
+"#;
+        let text = document.to_text();
+        assert_eq!(text, expected);
+
+        // Reading the output produces the same values and comments
+        let reread = CommentedDocument::parse(&text)?;
+        assert_eq!(reread.elements(), document.elements());
+        assert_eq!(reread.to_text(), text);
+        Ok(())
+    }
+
+    #[test]
+    fn comments_in_symbol_tables_are_not_recorded() -> IonResult<()> {
+        let source = r#"$ion_1_0
+// Symbols
+$ion_symbol_table::{
+  // imports none
+  symbols: ["red" /* 10 */],
+}
+$10 // color
+"#;
+        let document = CommentedDocument::parse(source)?;
+        let color = document.comments(&[0.into()]).unwrap();
+        assert_eq!(texts(color.leading()), [" Symbols"]);
+        assert_eq!(texts(color.trailing()), [" color"]);
+        assert_eq!(document.to_text(), "// Symbols\nred // color\n");
+        Ok(())
+    }
+
+    #[test]
+    fn comments_among_annotations_are_leading_comments() -> IonResult<()> {
+        let source = "ann:: /* a */ 1\n{x: ann:: /* b */ 3}\na:: // c\n b:: 2\nd:: /* d */ [e]\n";
+        let document = CommentedDocument::parse(source)?;
+        let path = |components: &[PathComponent]| document.comments(components).unwrap();
+        assert_eq!(texts(path(&[0.into()]).leading()), [" a "]);
+        assert_eq!(texts(path(&[1.into(), "x".into()]).leading()), [" b "]);
+        assert_eq!(texts(path(&[2.into()]).leading()), [" c"]);
+        // A container's comment is not moved to its first child
+        assert_eq!(texts(path(&[3.into()]).leading()), [" d "]);
+        assert!(path(&[3.into(), 0.into()]).is_empty());
+
+        let text = document.to_text();
+        assert_eq!(
+            text,
+            "/* a */\nann::1\n{\n  /* b */\n  x: ann::3,\n}\n// c\na::b::2\n/* d */\nd::[\n  e,\n]\n"
+        );
+        let reread = CommentedDocument::parse(&text)?;
+        assert_eq!(reread.elements(), document.elements());
+        assert_eq!(reread.to_text(), text);
+        Ok(())
+    }
+
+    #[test]
+    fn children_keep_their_comments_in_order() -> IonResult<()> {
+        let items: String = (0..1000).map(|i| format!("{i}, // {i}\n")).collect();
+        let document = CommentedDocument::parse(format!("[\n{items}]"))?;
+        for i in 0..1000 {
+            let comments = document.comments(&[0.into(), i.into()]).unwrap();
+            assert_eq!(texts(comments.trailing()), [format!(" {i}")]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn comments_can_be_added() -> IonResult<()> {
+        let mut document = CommentedDocument::parse("{a: 1, b: []}")?;
+        let comments = document.comments_mut(&[0.into(), "a".into()]).unwrap();
+        comments.trailing_mut().push(Comment::line(" first"));
+        comments.trailing_mut().push(Comment::block(" second "));
+        let list = document.comments_mut(&[0.into(), "b".into()]).unwrap();
+        list.dangling_mut().push(Comment::line(" empty"));
+        document.end_comments_mut().push(Comment::line(" done"));
+        assert_eq!(
+            document.to_text(),
+            "{\n  a: 1, // first\n  /* second */\n  b: [\n    // empty\n  ],\n}\n// done\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,359 @@
+//! Lossless editing of text Ion documents.
+//!
+//! When a text Ion document is read into `Element`s and written back out, everything that is not
+//! part of the data model is lost: comments, whitespace, integer radixes (`0xFF` becomes `255`),
+//! long-string forms (`'''...'''` becomes `"..."`), and so on. That makes the `Element` API a poor
+//! fit for tools that make automated edits to files that are maintained by humans.
+//!
+//! [`LosslessTextDocument`] remembers the location of every value in the source text. Values can
+//! be replaced by their path; when the document is written out, only the bytes corresponding to
+//! replaced values are re-encoded. Every other byte in the source (including comments, formatting,
+//! and the original spelling of untouched values) is emitted exactly as it was read.
+
+use std::ops::Range;
+
+use crate::lazy::decoder::HasRange;
+use crate::lazy::value::LazyValue;
+use crate::lazy::value_ref::ValueRef;
+use crate::result::IonFailure;
+use crate::{v1_0, Element, IonError, IonResult, Reader};
+
+/// A single step in the path from a top-level value to a nested value in a
+/// [`LosslessTextDocument`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathComponent {
+    /// The child value at the given position in a list or s-expression.
+    Index(usize),
+    /// The first field in a struct with the given name.
+    Field(String),
+}
+
+impl From<usize> for PathComponent {
+    fn from(index: usize) -> Self {
+        PathComponent::Index(index)
+    }
+}
+
+impl From<&str> for PathComponent {
+    fn from(field_name: &str) -> Self {
+        PathComponent::Field(field_name.to_owned())
+    }
+}
+
+impl From<String> for PathComponent {
+    fn from(field_name: String) -> Self {
+        PathComponent::Field(field_name)
+    }
+}
+
+/// The location of a value in the source text along with the locations of its child values.
+#[derive(Debug, Clone)]
+struct SourceValue {
+    // The range of source bytes occupied by this value, including its annotations.
+    range: Range<usize>,
+    // If this value is a container, the (optional field name, location) pairs of its child values.
+    children: Vec<(Option<String>, SourceValue)>,
+}
+
+impl SourceValue {
+    fn from_lazy_value(value: LazyValue<'_, v1_0::Text>) -> IonResult<Self> {
+        let range = value
+            .raw()
+            .map(|raw| raw.range())
+            .ok_or_else(|| IonError::illegal_operation("value was not found in the source text"))?;
+        let mut children = Vec::new();
+        match value.read()? {
+            ValueRef::List(list) => {
+                for child in list.iter() {
+                    children.push((None, Self::from_lazy_value(child?)?));
+                }
+            }
+            ValueRef::SExp(sexp) => {
+                for child in sexp.iter() {
+                    children.push((None, Self::from_lazy_value(child?)?));
+                }
+            }
+            ValueRef::Struct(strukt) => {
+                for field in strukt.iter() {
+                    let field = field?;
+                    let name = field.name()?.text().map(str::to_owned);
+                    children.push((name, Self::from_lazy_value(field.value())?));
+                }
+            }
+            _ => {}
+        }
+        Ok(Self { range, children })
+    }
+
+    fn child(&self, component: &PathComponent) -> Option<&SourceValue> {
+        match component {
+            PathComponent::Index(index) => self.children.get(*index).map(|(_, child)| child),
+            PathComponent::Field(name) => self
+                .children
+                .iter()
+                .find(|(field_name, _)| field_name.as_deref() == Some(name.as_str()))
+                .map(|(_, child)| child),
+        }
+    }
+}
+
+/// A value that has been replaced and the range of source bytes it replaces.
+#[derive(Debug, Clone)]
+struct Replacement {
+    range: Range<usize>,
+    element: Element,
+}
+
+/// A text Ion 1.0 document that can be edited without disturbing the bytes of any value that was
+/// not modified.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # #[cfg(feature = "experimental-tooling-apis")]
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::{LosslessTextDocument, PathComponent};
+///
+/// let source = r#"
+///     // Service configuration
+///     {
+///         port: 0x1F90, // 8080
+///         name: '''my-service''',
+///     }
+/// "#;
+/// let mut document = LosslessTextDocument::parse(source)?;
+/// document.replace(&[PathComponent::Index(0), "name".into()], "your-service")?;
+/// assert_eq!(
+///     document.to_text(),
+///     r#"
+///     // Service configuration
+///     {
+///         port: 0x1F90, // 8080
+///         name: "your-service",
+///     }
+/// "#
+/// );
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "experimental-tooling-apis"))]
+/// # fn main() -> IonResult<()> { Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LosslessTextDocument {
+    source: String,
+    // The top-level values as they were originally read.
+    elements: Vec<Element>,
+    // The location of each top-level value in `source`.
+    values: Vec<SourceValue>,
+    // Replaced values, sorted by their position in `source`. Ranges never overlap.
+    replacements: Vec<Replacement>,
+    // Values to write after the end of the source text.
+    appended: Vec<Element>,
+}
+
+impl LosslessTextDocument {
+    /// Reads the provided text Ion 1.0 data, recording the location of each value it contains.
+    pub fn parse(source: impl Into<String>) -> IonResult<Self> {
+        let source = source.into();
+        let mut elements = Vec::new();
+        let mut values = Vec::new();
+        let mut reader = Reader::new(v1_0::Text, source.as_str())?;
+        while let Some(value) = reader.next()? {
+            values.push(SourceValue::from_lazy_value(value)?);
+            elements.push(Element::try_from(value)?);
+        }
+        Ok(Self {
+            source,
+            elements,
+            values,
+            replacements: Vec::new(),
+            appended: Vec::new(),
+        })
+    }
+
+    /// Returns the text that this document was parsed from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the top-level values in the document as they were originally read. Replacements
+    /// and appended values are not reflected in this slice.
+    pub fn elements(&self) -> &[Element] {
+        &self.elements
+    }
+
+    /// Returns the number of top-level values in the source text.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the source text did not contain any values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns `true` if any value has been replaced or appended since the document was parsed.
+    pub fn is_modified(&self) -> bool {
+        !self.replacements.is_empty() || !self.appended.is_empty()
+    }
+
+    /// Returns the range of source bytes (including annotations) occupied by the value at `path`.
+    /// The first component of the path must be the index of a top-level value.
+    pub fn source_range(&self, path: &[PathComponent]) -> IonResult<Range<usize>> {
+        self.find(path).map(|value| value.range.clone())
+    }
+
+    /// Replaces the value at `path` (including any annotations it had) with `element`.
+    ///
+    /// Replacing a value discards any earlier replacements of values nested inside of it.
+    /// Replacing a value nested inside a value that was already replaced is an error; modify the
+    /// outer replacement instead.
+    pub fn replace(
+        &mut self,
+        path: &[PathComponent],
+        element: impl Into<Element>,
+    ) -> IonResult<()> {
+        let range = self.source_range(path)?;
+        if self
+            .replacements
+            .iter()
+            .any(|r| r.range.start <= range.start && range.end <= r.range.end && r.range != range)
+        {
+            return IonResult::illegal_operation(
+                "cannot replace a value nested inside a value that was already replaced",
+            );
+        }
+        // Discard any replacements that fall within the new one.
+        self.replacements
+            .retain(|r| !(range.start <= r.range.start && r.range.end <= range.end));
+        let index = self
+            .replacements
+            .partition_point(|r| r.range.start < range.start);
+        self.replacements.insert(
+            index,
+            Replacement {
+                range,
+                element: element.into(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Adds a new top-level value to the end of the document.
+    pub fn push(&mut self, element: impl Into<Element>) {
+        self.appended.push(element.into());
+    }
+
+    /// Returns the text of the edited document. Bytes of the source text that do not belong to a
+    /// replaced value are copied verbatim.
+    pub fn to_text(&self) -> String {
+        let mut output = String::with_capacity(self.source.len());
+        let mut position = 0;
+        for replacement in &self.replacements {
+            output.push_str(&self.source[position..replacement.range.start]);
+            output.push_str(&replacement.element.to_string());
+            position = replacement.range.end;
+        }
+        output.push_str(&self.source[position..]);
+        for element in &self.appended {
+            if !output.is_empty() && !output.ends_with(char::is_whitespace) {
+                output.push('\n');
+            }
+            output.push_str(&element.to_string());
+            output.push('\n');
+        }
+        output
+    }
+
+    fn find(&self, path: &[PathComponent]) -> IonResult<&SourceValue> {
+        let Some((PathComponent::Index(index), nested_path)) = path.split_first() else {
+            return IonResult::illegal_operation(
+                "a document path must begin with the index of a top-level value",
+            );
+        };
+        let mut value = self.values.get(*index).ok_or_else(|| {
+            IonError::illegal_operation(format!("document has no top-level value {index}"))
+        })?;
+        for component in nested_path {
+            value = value.child(component).ok_or_else(|| {
+                IonError::illegal_operation(format!("no value found at path {path:?}"))
+            })?;
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ion_list, ion_struct};
+
+    const SOURCE: &str = r#"$ion_1_0
+// Inventory
+{
+  sku: "A-100",   // legacy id
+  quantity: 0x0A,
+  tags: [red, '''large''', 1_000],
+}
+/* trailing */ 42"#;
+
+    #[test]
+    fn unmodified_document_round_trips() -> IonResult<()> {
+        let document = LosslessTextDocument::parse(SOURCE)?;
+        assert_eq!(document.len(), 2);
+        assert!(!document.is_modified());
+        assert_eq!(document.to_text(), SOURCE);
+        Ok(())
+    }
+
+    #[test]
+    fn replace_nested_value() -> IonResult<()> {
+        let mut document = LosslessTextDocument::parse(SOURCE)?;
+        document.replace(&[0.into(), "tags".into(), 2.into()], 7)?;
+        assert_eq!(document.to_text(), SOURCE.replace("1_000]", "7]"));
+        Ok(())
+    }
+
+    #[test]
+    fn replace_top_level_value() -> IonResult<()> {
+        let mut document = LosslessTextDocument::parse(SOURCE)?;
+        document.replace(&[1.into()], ion_list![1, 2])?;
+        assert_eq!(document.to_text(), SOURCE.replace("*/ 42", "*/ [1, 2]"));
+        Ok(())
+    }
+
+    #[test]
+    fn replacing_a_container_discards_nested_replacements() -> IonResult<()> {
+        let mut document = LosslessTextDocument::parse(SOURCE)?;
+        document.replace(&[0.into(), "quantity".into()], 11)?;
+        document.replace(&[0.into()], ion_struct! {"sku": "B-200"})?;
+        let expected = format!(
+            "$ion_1_0\n// Inventory\n{}\n/* trailing */ 42",
+            "{sku: \"B-200\"}"
+        );
+        assert_eq!(document.to_text(), expected);
+        // A value nested inside of a replacement can no longer be replaced.
+        assert!(document
+            .replace(&[0.into(), "sku".into()], "C-300")
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn push_appends_values() -> IonResult<()> {
+        let mut document = LosslessTextDocument::parse(SOURCE)?;
+        document.push("done");
+        assert_eq!(document.to_text(), format!("{SOURCE}\n\"done\"\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_paths_are_errors() -> IonResult<()> {
+        let mut document = LosslessTextDocument::parse(SOURCE)?;
+        assert!(document.replace(&[], 1).is_err());
+        assert!(document.replace(&["sku".into()], 1).is_err());
+        assert!(document.replace(&[5.into()], 1).is_err());
+        assert!(document.replace(&[0.into(), "missing".into()], 1).is_err());
+        assert!(document.replace(&[1.into(), 0.into()], 1).is_err());
+        Ok(())
+    }
+}
@@ -1,2 +1,4 @@
+pub(crate) mod comments;
+pub(crate) mod lossless;
 pub(crate) mod text_formatter;
 pub(crate) mod whitespace_config;
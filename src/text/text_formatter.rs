@@ -299,6 +299,14 @@ impl<'a, W: std::fmt::Write> FmtValueFormatter<'a, W> {
         Ok(())
     }
 
+    /// Writes symbol text in single quotes, even if the text would be legal without them.
+    pub(crate) fn format_quoted_symbol_text(&mut self, text: &str) -> IonResult<()> {
+        write!(self.output, "'")?;
+        self.format_escaped_text_body(text)?;
+        write!(self.output, "'")?;
+        Ok(())
+    }
+
     /// Writes the body (i.e. no start or end delimiters) of a string or symbol with any illegal
     /// characters escaped.
     pub(crate) fn format_escaped_text_body<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
@@ -319,6 +327,17 @@ impl<'a, W: std::fmt::Write> FmtValueFormatter<'a, W> {
                 '\x08' => r"\b", // backspace
                 '\x0B' => r"\v", // vertical tab
                 '\x0C' => r"\f", // form feed
+                '\x00'..='\x1F' => {
+                    // Other control characters have no short escape sequence.
+                    write!(
+                        self.output,
+                        "{}\\x{:02x}",
+                        &text[start..byte_index],
+                        character as u32
+                    )?;
+                    start = byte_index + character.len_utf8();
+                    continue;
+                }
                 _ => {
                     // Other characters can be left as-is
                     continue;
@@ -344,22 +363,7 @@ impl<'a, W: std::fmt::Write> FmtValueFormatter<'a, W> {
     }
 
     pub fn format_null(&mut self, ion_type: IonType) -> IonResult<()> {
-        use IonType::*;
-        let null_text = match ion_type {
-            Null => "null",
-            Bool => "null.bool",
-            Int => "null.int",
-            Float => "null.float",
-            Decimal => "null.decimal",
-            Timestamp => "null.timestamp",
-            Symbol => "null.symbol",
-            String => "null.string",
-            Blob => "null.blob",
-            Clob => "null.clob",
-            List => "null.list",
-            SExp => "null.sexp",
-            Struct => "null.struct",
-        };
+        let null_text = ion_type.null_text();
         write!(self.output, "{null_text}")?;
         Ok(())
     }
@@ -575,6 +579,16 @@ mod formatter_test {
         Ok(())
     }
 
+    #[test]
+    fn test_format_control_characters() -> IonResult<()> {
+        formatter(
+            |ivf| ivf.format_string("a\u{1}\tb\u{1f}"),
+            r#""a\x01\tb\x1f""#,
+        );
+        formatter(|ivf| ivf.format_symbol("\u{1e}"), r"'\x1e'");
+        Ok(())
+    }
+
     #[test]
     fn test_format_blob() -> IonResult<()> {
         formatter(|ivf| ivf.format_blob("hello".as_bytes()), "{{aGVsbG8=}}");
@@ -1,7 +1,18 @@
 use crate::ion_data::{IonEq, IonOrd};
 use std::cmp::Ordering;
 
+// With the `bytes` feature, payloads are stored in a reference-counted `bytes::Bytes` so that they
+// can be shared with (and sliced from) buffers owned by other code without being copied.
+#[cfg(not(feature = "bytes"))]
+type Storage = Vec<u8>;
+#[cfg(feature = "bytes")]
+type Storage = ::bytes::Bytes;
+
 /// An owned, immutable byte array.
+///
+/// When the `bytes` feature is enabled, the array is stored in a [`bytes::Bytes`](::bytes::Bytes);
+/// converting between the two types does not copy the data and cloning only increments a
+/// reference count.
 /// ```rust
 /// use ion_rs::Bytes;
 /// let ivm: &[u8] = &[0xEA_u8, 0x01, 0x00, 0xE0]; // Ion 1.0 version marker
@@ -20,7 +31,24 @@ use std::cmp::Ordering;
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd)]
 pub struct Bytes {
-    data: Vec<u8>,
+    data: Storage,
+}
+
+impl Bytes {
+    /// Returns the array's storage as a `Vec` so that its allocation can be reused, or `None` if
+    /// the storage is shared with other values.
+    pub(crate) fn into_reusable_vec(self) -> Option<Vec<u8>> {
+        #[cfg(not(feature = "bytes"))]
+        {
+            Some(self.data)
+        }
+        // Converting a `bytes::Bytes` that does not have the only reference to its buffer would
+        // copy the buffer.
+        #[cfg(feature = "bytes")]
+        {
+            self.data.is_unique().then(|| self.data.into())
+        }
+    }
 }
 
 impl IonEq for Bytes {
@@ -42,32 +70,28 @@ impl PartialEq<[u8]> for Bytes {
 }
 
 impl From<Vec<u8>> for Bytes {
+    // Without the `bytes` feature, `Storage` is `Vec<u8>` and this conversion does nothing.
+    #[allow(clippy::useless_conversion)]
     fn from(data: Vec<u8>) -> Self {
-        Bytes { data }
+        Bytes { data: data.into() }
     }
 }
 
 impl From<&[u8]> for Bytes {
     fn from(data: &[u8]) -> Self {
-        Bytes {
-            data: data.to_vec(),
-        }
+        Bytes::from(data.to_vec())
     }
 }
 
 impl<const N: usize> From<&[u8; N]> for Bytes {
     fn from(data: &[u8; N]) -> Self {
-        Bytes {
-            data: data.to_vec(),
-        }
+        Bytes::from(data.to_vec())
     }
 }
 
 impl From<&str> for Bytes {
     fn from(text: &str) -> Self {
-        Bytes {
-            data: text.as_bytes().into(),
-        }
+        Bytes::from(text.as_bytes().to_vec())
     }
 }
 
@@ -76,3 +100,17 @@ impl AsRef<[u8]> for Bytes {
         &self.data
     }
 }
+
+#[cfg(feature = "bytes")]
+impl From<::bytes::Bytes> for Bytes {
+    fn from(data: ::bytes::Bytes) -> Self {
+        Bytes { data }
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<Bytes> for ::bytes::Bytes {
+    fn from(bytes: Bytes) -> Self {
+        bytes.data
+    }
+}
@@ -0,0 +1,47 @@
+//! An [`Arbitrary`] implementation for [`Decimal`], for use in property tests and fuzzing.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::Decimal;
+
+impl<'a> Arbitrary<'a> for Decimal {
+    /// Produces a `Decimal` with an arbitrary coefficient and an exponent in the range of an
+    /// `i32`. Negative zero is occasionally produced.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // Exponents near the limits of an `i64` cannot be read back from binary Ion.
+        let exponent = i64::from(u.arbitrary::<i32>()?);
+        if u.ratio(1u8, 16u8)? {
+            return Ok(Decimal::negative_zero_with_exponent(exponent));
+        }
+        let coefficient: i128 = u.arbitrary()?;
+        Ok(Decimal::new(coefficient, exponent))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        // exponent + negative zero ratio + coefficient
+        (4, Some(4 + 1 + 16))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::ion_data::IonEq;
+    use crate::{v1_0, Decimal, Element, IonResult};
+
+    #[test]
+    fn arbitrary_decimals_round_trip() -> IonResult<()> {
+        let data: Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let decimal = Decimal::arbitrary(&mut u).unwrap();
+            let element = Element::from(decimal);
+            let text = element.encode_as(v1_0::Text)?;
+            let binary = element.encode_as(v1_0::Binary)?;
+            assert!(Element::read_one(text)?.ion_eq(&element));
+            assert!(Element::read_one(binary)?.ion_eq(&element));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+//! Conversions between [`Decimal`] and [`bigdecimal::BigDecimal`].
+
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::BigDecimal;
+
+use crate::decimal::coefficient::Coefficient;
+use crate::result::{IonError, IonFailure};
+use crate::{Decimal, Int};
+
+impl TryFrom<Decimal> for BigDecimal {
+    type Error = IonError;
+
+    /// Converts an Ion `Decimal` to a `BigDecimal`. `BigDecimal` does not distinguish between `0`
+    /// and `-0`, so negative zero is converted to zero.
+    ///
+    /// Returns an error only if the `Decimal`'s exponent is `i64::MIN`, which `BigDecimal`'s scale
+    /// cannot represent.
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        let scale = value.exponent().checked_neg().ok_or_else(|| {
+            IonError::illegal_operation(format!(
+                "Cannot convert Decimal {value:?} to BigDecimal; its exponent is out of range."
+            ))
+        })?;
+        let coefficient = signed_coefficient(value.coefficient());
+        Ok(BigDecimal::new(BigInt::from(coefficient.data), scale))
+    }
+}
+
+impl TryFrom<&BigDecimal> for Decimal {
+    type Error = IonError;
+
+    /// Converts a `BigDecimal` to an Ion `Decimal`, preserving its precision when possible.
+    ///
+    /// `Decimal` currently supports coefficients in the range of an `i128`. If the `BigDecimal`'s
+    /// digits do not fit, any trailing zeros are moved into the exponent; if they still do not fit,
+    /// an error is returned.
+    fn try_from(value: &BigDecimal) -> Result<Self, Self::Error> {
+        let (digits, scale) = value.as_bigint_and_scale();
+        if let Some(decimal) = decimal_from_parts(&digits, scale) {
+            return Ok(decimal);
+        }
+        let normalized = value.normalized();
+        let (digits, scale) = normalized.as_bigint_and_scale();
+        decimal_from_parts(&digits, scale).ok_or_else(|| {
+            IonError::illegal_operation(format!(
+                "Cannot convert BigDecimal {value} to Decimal; its coefficient is too large."
+            ))
+        })
+    }
+}
+
+impl TryFrom<BigDecimal> for Decimal {
+    type Error = IonError;
+
+    fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+        Decimal::try_from(&value)
+    }
+}
+
+fn decimal_from_parts(digits: &BigInt, scale: i64) -> Option<Decimal> {
+    let coefficient = i128::try_from(digits).ok()?;
+    Some(Decimal::new(coefficient, scale.checked_neg()?))
+}
+
+/// Returns the coefficient as an `Int`, treating negative zero as zero.
+fn signed_coefficient(coefficient: &Coefficient) -> Int {
+    Int::try_from(coefficient).unwrap_or(Int::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ion_data::IonEq;
+    use crate::IonResult;
+
+    #[rstest]
+    #[case::integer("5", Decimal::new(5, 0))]
+    #[case::fraction("-12.250", Decimal::new(-12250, -3))]
+    #[case::positive_exponent("12e5", Decimal::new(12, 5))]
+    #[case::zero_with_precision("0.00", Decimal::new(0, -2))]
+    #[case::large("170141183460469231731687303715884105727", Decimal::new(i128::MAX, 0))]
+    fn round_trip(#[case] text: &str, #[case] decimal: Decimal) -> IonResult<()> {
+        let big = BigDecimal::from_str(text).unwrap();
+        assert!(Decimal::try_from(&big)?.ion_eq(&decimal));
+        let converted = BigDecimal::try_from(decimal)?;
+        assert_eq!(converted, big);
+        assert_eq!(converted.as_bigint_and_scale(), big.as_bigint_and_scale());
+        Ok(())
+    }
+
+    #[test]
+    fn trailing_zeros_move_into_the_exponent() -> IonResult<()> {
+        let big = BigDecimal::from_str(&format!("{}00", i128::MAX)).unwrap();
+        assert!(Decimal::try_from(big)?.ion_eq(&Decimal::new(i128::MAX, 2)));
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_range() {
+        let big = BigDecimal::from_str(&format!("{}1", i128::MAX)).unwrap();
+        assert!(Decimal::try_from(big).is_err());
+        assert!(BigDecimal::try_from(Decimal::new(1, i64::MIN)).is_err());
+    }
+
+    #[test]
+    fn negative_zero() -> IonResult<()> {
+        let big = BigDecimal::try_from(Decimal::negative_zero_with_exponent(-1))?;
+        assert_eq!(big, BigDecimal::from_str("0.0").unwrap());
+        Ok(())
+    }
+}
@@ -4,15 +4,25 @@ use std::cmp::Ordering;
 
 use crate::decimal::coefficient::{Coefficient, Sign};
 use crate::ion_data::{IonEq, IonOrd};
+use crate::lazy::expanded::EncodingContext;
+use crate::lazy::text::buffer::TextBufferView;
 use crate::result::{IonError, IonFailure};
 use crate::{Int, IonResult, UInt};
 use num_traits::Zero;
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 use std::ops::Neg;
+use std::str::FromStr;
 
 pub mod coefficient;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary_decimal;
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal_conversions;
+#[cfg(feature = "rust-decimal")]
+mod rust_decimal_conversions;
+
 /// An arbitrary-precision Decimal type with a distinct representation of negative zero (`-0`).
 ///
 /// A `Decimal` can be thought of as a `(coefficient, exponent)` pair, and its value can be
@@ -92,6 +102,89 @@ impl Decimal {
         num_decimal_digits
     }
 
+    /// Returns a `Decimal` with the same value as this one (after rounding, if necessary) but with
+    /// the specified `exponent`. This can be used to normalize values to a fixed number of
+    /// fractional digits; for example, `rescale(-2, ...)` produces a value with exactly two digits
+    /// after the decimal point.
+    ///
+    /// If the new exponent is larger than the current one, digits are discarded and the result is
+    /// rounded using `rounding_mode`. The sign of the original value is preserved, so a negative
+    /// value that rounds to zero becomes `-0`. If the new exponent is smaller, the coefficient is
+    /// padded with zeros; this returns an error if the resulting coefficient would be outside the
+    /// supported range.
+    ///
+    /// ```
+    /// # use ion_rs::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// use ion_rs::decimal::RoundingMode;
+    /// use ion_rs::Decimal;
+    ///
+    /// let price = Decimal::new(12345, -3); // 12.345
+    /// assert_eq!(price.rescale(-2, RoundingMode::HalfEven)?.to_string(), "12.34");
+    /// assert_eq!(price.rescale(-2, RoundingMode::HalfUp)?.to_string(), "12.35");
+    /// assert_eq!(price.rescale(-5, RoundingMode::HalfUp)?.to_string(), "12.34500");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rescale(&self, exponent: i64, rounding_mode: RoundingMode) -> IonResult<Decimal> {
+        let magnitude = self.coefficient.magnitude().data;
+        let is_negative = self.coefficient.is_negative();
+        let new_magnitude = match exponent.cmp(&self.exponent) {
+            Ordering::Equal => return Ok(*self),
+            Ordering::Less => {
+                let shift = self.exponent.abs_diff(exponent);
+                u32::try_from(shift)
+                    .ok()
+                    .and_then(|shift| 10u128.checked_pow(shift))
+                    .and_then(|factor| magnitude.checked_mul(factor))
+                    .filter(|magnitude| *magnitude <= i128::MAX as u128)
+                    .ok_or_else(|| {
+                        IonError::illegal_operation(format!(
+                            "Cannot rescale Decimal {self:?} to exponent {exponent}; the \
+                             coefficient would be too large."
+                        ))
+                    })?
+            }
+            Ordering::Greater => {
+                let shift = exponent.abs_diff(self.exponent);
+                let divisor = u32::try_from(shift)
+                    .ok()
+                    .and_then(|shift| 10u128.checked_pow(shift));
+                let (quotient, remainder, remainder_vs_half) = match divisor {
+                    Some(divisor) => {
+                        let remainder = magnitude % divisor;
+                        // `remainder < divisor <= 10^38`, so doubling it cannot overflow.
+                        (
+                            magnitude / divisor,
+                            remainder,
+                            (remainder * 2).cmp(&divisor),
+                        )
+                    }
+                    // The divisor is larger than any coefficient, so every digit is discarded and
+                    // the discarded portion is less than half.
+                    None => (0, magnitude, Ordering::Less),
+                };
+                let round_up = remainder != 0
+                    && rounding_mode.rounds_away_from_zero(
+                        is_negative,
+                        quotient,
+                        remainder_vs_half,
+                    );
+                if round_up {
+                    quotient + 1
+                } else {
+                    quotient
+                }
+            }
+        };
+        let coefficient = match (is_negative, new_magnitude) {
+            (true, 0) => Coefficient::negative_zero(),
+            (true, magnitude) => Coefficient::new(-(magnitude as i128)),
+            (false, magnitude) => Coefficient::new(magnitude as i128),
+        };
+        Ok(Decimal::new(coefficient, exponent))
+    }
+
     /// Constructs a Decimal with the value `-0d0`. This is provided as a convenience method
     /// because Rust will ignore a unary minus when it is applied to an zero literal (`-0`).
     pub fn negative_zero() -> Decimal {
@@ -204,6 +297,52 @@ impl Decimal {
     }
 }
 
+/// Specifies how [`Decimal::rescale`] rounds a value when it discards digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Round away from zero.
+    Up,
+    /// Round toward zero (truncate).
+    Down,
+    /// Round toward positive infinity.
+    Ceiling,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round to the nearest value; ties are rounded away from zero.
+    HalfUp,
+    /// Round to the nearest value; ties are rounded toward zero.
+    HalfDown,
+    /// Round to the nearest value; ties are rounded to the neighbor with an even last digit.
+    /// This is sometimes called "banker's rounding".
+    #[default]
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Returns `true` if an inexact, truncated magnitude (`quotient`) should be incremented.
+    /// `remainder_vs_half` compares the discarded portion to half of the last retained digit.
+    fn rounds_away_from_zero(
+        &self,
+        is_negative: bool,
+        quotient: u128,
+        remainder_vs_half: Ordering,
+    ) -> bool {
+        match self {
+            RoundingMode::Up => true,
+            RoundingMode::Down => false,
+            RoundingMode::Ceiling => !is_negative,
+            RoundingMode::Floor => is_negative,
+            RoundingMode::HalfUp => remainder_vs_half != Ordering::Less,
+            RoundingMode::HalfDown => remainder_vs_half == Ordering::Greater,
+            RoundingMode::HalfEven => match remainder_vs_half {
+                Ordering::Less => false,
+                Ordering::Equal => quotient % 2 == 1,
+                Ordering::Greater => true,
+            },
+        }
+    }
+}
+
 impl PartialEq for Decimal {
     fn eq(&self, other: &Self) -> bool {
         self.cmp(other) == Ordering::Equal
@@ -283,9 +422,17 @@ impl From<Int> for Decimal {
 impl TryFrom<f32> for Decimal {
     type Error = IonError;
 
+    /// Converts an `f32` to a `Decimal`. See the `f64` implementation of `TryFrom` for details.
+    /// The shortest decimal that identifies the `f32` is used, so `0.1f32` becomes `0.1` rather
+    /// than the digits of its widened `f64` value.
     fn try_from(value: f32) -> Result<Self, Self::Error> {
-        // Defer to the f64 implementation of `TryInto`
-        (value as f64).try_into()
+        if !value.is_finite() {
+            return non_finite_float_error(value as f64);
+        }
+        Ok(Decimal::from_float_text(
+            value.is_sign_negative(),
+            &format!("{value:e}"),
+        ))
     }
 }
 
@@ -300,108 +447,83 @@ impl TryFrom<f64> for Decimal {
     ///
     /// Because Decimal can represent negative zero, f64::neg_zero() IS supported.
     ///
-    /// NOTE: While the resulting decimal will be a very close approximation of the original f64's
-    ///       value, this is an inherently lossy operation. Floating point values do not encode a
-    ///       precision. When converting an f64 to a Decimal, a precision for the new Decimal must
-    ///       be chosen somewhat arbitrarily. Do NOT rely on the precision of the resulting Decimal.
-    ///       This implementation may change without notice.
+    /// The conversion is lossless: the result is the decimal with the fewest digits that
+    /// [`to_f64`](Decimal::to_f64) converts back to exactly the same `f64`. (This is the same
+    /// number that Rust prints for the `f64`.) For example, `0.1f64` becomes `0.1`, even though
+    /// the binary value it stores is slightly larger than one tenth.
     fn try_from(value: f64) -> Result<Self, Self::Error> {
-        // === Special `f64` values ===
-
-        if value.is_infinite() {
-            if value.is_sign_negative() {
-                return IonResult::illegal_operation(
-                    "Cannot convert f64 negative infinity to Decimal.",
-                );
-            } else {
-                return IonResult::illegal_operation("Cannot convert f64 infinity to Decimal.");
-            }
-        } else if value.is_nan() {
-            return IonResult::illegal_operation(
-                "Cannot convert f64 NaN (not-a-number) to Decimal.",
-            );
+        if !value.is_finite() {
+            return non_finite_float_error(value);
         }
+        Ok(Decimal::from_float_text(
+            value.is_sign_negative(),
+            &format!("{value:e}"),
+        ))
+    }
+}
 
-        // === The signed and unsigned zero cases ===
-
-        // You can't use the `log10` operation on a zero value, so check for these cases explicitly.
-        if value == 0f64 {
-            //    ^- Positive and negative zero are mathematically equivalent,
-            //       so we can use `==` here to check for both.
-            if value.is_sign_negative() {
-                return Ok(Decimal::NEGATIVE_ZERO);
-            }
-            return Ok(Decimal::ZERO);
-        }
+fn non_finite_float_error(value: f64) -> IonResult<Decimal> {
+    if value.is_nan() {
+        IonResult::illegal_operation("Cannot convert f64 NaN (not-a-number) to Decimal.")
+    } else if value.is_sign_negative() {
+        IonResult::illegal_operation("Cannot convert f64 negative infinity to Decimal.")
+    } else {
+        IonResult::illegal_operation("Cannot convert f64 infinity to Decimal.")
+    }
+}
 
-        // === Split the value into its int and fraction components ===
-
-        // Isolate the integral portion of the f64. (e.g. 3.14 -> 3.0)
-        let f64_int = value.trunc();
-        //                  ^^^^^^
-        // The `trunc()` method discards the fractional part of the value, returning its integer
-        // component as an `f64`.
-
-        // Isolate the fractional portion of the value. (e.g. 3.14 -> 0.14)
-        let f64_fract = value.fract();
-        //                    ^^^^^^
-        // The `fract()` method returns the fractional part of the value as an f64.
-
-        // === The general case ===
-
-        // Due to the nature of IEEE-754 `f64` encoding, the value's integral component can be an
-        // integer outside the integer range supported by `i128`. Starting at (2^53 + 1), an `f64`
-        // will start skipping over one or more integers as its representational precision breaks down.
-        // An `i128` can precisely represent more integers than an `f64` can, but because `f64` can
-        // "skip" regions of large numbers, the `f64` has a wider range of integers it can represent.
-        //
-        // Our coefficient will be stored in an `Int` with 128 bits, allowing us to store up to 38
-        // decimal places of precision.
-        const MAX_DECIMAL_DIGITS: u32 = 38;
-        // If the total number of digits in the f64 exceed 38, we'll need to truncate the coefficient
-        // at 38 digits and increase the exponent (i.e. the number of trailing zeros) to approximate
-        // what was lost.
-        //
-        // For example, this 40 digit number:
-        //
-        //    1234567890_1234567890_1234567890_1234567890
-        //
-        // would be turned into a decimal whose coefficient was:
-        //
-        //    1234567890_1234567890_1234567890_12345678XX
-        //                               discarded ----^^
-        //
-        // and its exponent would be increased by 2 to retain the scale of discarded digits.
-
-        // Store a copy of the value as an i128
-        let integral_value = f64_int as i128;
-        // Determine how many decimal digits comprise the integral portion
-        let num_integral_decimal_digits = f64_int.abs().log10().floor() as u32 + 1;
-
-        // Check to see if the fractional part of the value is zero; if so, the value is an integer.
-        if f64_fract.is_zero() {
-            // If the f64 is an integer value, we can convert it to a decimal trivially.
-            // For very large integer values, we need to set the exponent to capture any scale
-            // that the coefficient alone was not able to store.
-            let exponent = (num_integral_decimal_digits as i64 - MAX_DECIMAL_DIGITS as i64).max(0);
-            return Ok(Decimal::new(integral_value, exponent));
+impl Decimal {
+    /// Converts this `Decimal` to the nearest `f64`, rounding ties to the value with an even
+    /// least significant bit.
+    ///
+    /// An `f64` has about 15 to 17 significant decimal digits of precision, so digits beyond that
+    /// are lost. Values too large in magnitude to be represented become positive or negative
+    /// infinity, and values too small become zero (keeping their sign). Negative zero becomes
+    /// `-0.0`.
+    ///
+    /// For any finite `f64` value `f`, `Decimal::try_from(f)?.to_f64()` returns `f`.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    ///
+    /// assert_eq!(Decimal::new(1, -1).to_f64(), 0.1);
+    /// assert_eq!(Decimal::new(-25, 1).to_f64(), -250.0);
+    /// assert_eq!(Decimal::new(1, 400).to_f64(), f64::INFINITY);
+    /// assert!(Decimal::negative_zero().to_f64().is_sign_negative());
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        // Rust's float parser rounds correctly, so let it do the work.
+        let sign = if self.coefficient.is_negative() {
+            "-"
+        } else {
+            ""
+        };
+        format!("{sign}{}e{}", self.coefficient.magnitude(), self.exponent)
+            .parse()
+            .expect("a decimal's text representation is a valid float")
+    }
+
+    /// Constructs a `Decimal` from the scientific notation produced by formatting a finite float
+    /// with `{:e}` (for example, `-1.25e-3`).
+    fn from_float_text(is_negative: bool, text: &str) -> Decimal {
+        let (mantissa, exponent) = text
+            .split_once('e')
+            .expect("floats formatted with {:e} have an exponent");
+        let mantissa = mantissa.trim_start_matches('-');
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        // A finite float has at most 17 significant digits, so this always fits in a u64.
+        let magnitude: u64 = format!("{integer}{fraction}")
+            .parse()
+            .expect("a float's digits are a valid integer");
+        let exponent = exponent
+            .parse::<i64>()
+            .expect("a float's exponent is a valid integer")
+            - fraction.len() as i64;
+        match (is_negative, magnitude) {
+            (true, 0) => Decimal::negative_zero_with_exponent(exponent),
+            (true, magnitude) => Decimal::new(-(magnitude as i128), exponent),
+            (false, magnitude) => Decimal::new(magnitude, exponent),
         }
-
-        // The number of fractional digits we'll retain is the smaller of:
-        //   * the number of digits _not_ occupied by the integral portion of the number
-        //     OR
-        //   * the number of fractional digits an f64 can represent
-        let num_fractional_digits =
-            (MAX_DECIMAL_DIGITS - num_integral_decimal_digits).min(f64::DIGITS);
-        //                                                         ^^^^^^^^^^^
-        // `f64::DIGITS` is the number of base 10 digits of fractional precision in an `f64`: 15
-
-        // Shift `num_fractional_digits` fractional digits into the integer portion of the f64.
-        let coefficient_f64 = value * 10f64.powi(num_fractional_digits as i32);
-        let coefficient = coefficient_f64 as i128;
-
-        let exponent = -(num_fractional_digits as i64);
-        Ok(Decimal::new(coefficient, exponent))
     }
 }
 
@@ -442,11 +564,43 @@ impl Display for Decimal {
     }
 }
 
+/// Parses a `Decimal` from its Ion text representation, such as `1.23d-4`, `-0.`, or `1d0`.
+///
+/// The text must be a complete Ion decimal literal without surrounding whitespace or annotations.
+/// In particular, a literal needs a decimal point or a `d` exponent to be a decimal; digits alone
+/// (for example, `5`) are an Ion int and are rejected.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::Decimal;
+///
+/// assert_eq!("1.23d-4".parse::<Decimal>()?, Decimal::new(123, -6));
+/// assert_eq!("1_000.5".parse::<Decimal>()?, Decimal::new(10005, -1));
+/// assert!("-0.".parse::<Decimal>()?.is_zero());
+/// assert!("5".parse::<Decimal>().is_err());
+/// # Ok(())
+/// # }
+/// ```
+impl FromStr for Decimal {
+    type Err = IonError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let context = EncodingContext::empty();
+        let input = TextBufferView::new(context.get_ref(), text.as_bytes());
+        match input.match_decimal() {
+            Ok((remaining, matched)) if remaining.is_empty() => matched.read(input),
+            _ => IonResult::decoding_error(format!("'{text}' is not a valid Ion decimal")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod decimal_tests {
     use crate::decimal::coefficient::Coefficient;
+    use crate::decimal::RoundingMode;
     use crate::result::IonResult;
-    use crate::{Decimal, Int};
+    use crate::{Decimal, Element, Int};
 
     use num_traits::Float;
     use std::cmp::Ordering;
@@ -457,6 +611,86 @@ mod decimal_tests {
 
     use rstest::*;
 
+    #[rstest]
+    #[case::decimal_point("1.5", Decimal::new(15, -1))]
+    #[case::trailing_point("-5.", Decimal::new(-5, 0))]
+    #[case::exponent("1d0", Decimal::new(1, 0))]
+    #[case::negative_exponent("1.23d-4", Decimal::new(123, -6))]
+    #[case::positive_exponent("1.23D+4", Decimal::new(123, 2))]
+    #[case::negative_zero("-0.", Decimal::negative_zero())]
+    #[case::underscores("1_234.5_6", Decimal::new(123456, -2))]
+    #[case::leading_zero("0.007", Decimal::new(7, -3))]
+    fn parse_decimal(#[case] text: &str, #[case] expected: Decimal) -> IonResult<()> {
+        let actual: Decimal = text.parse()?;
+        assert!(actual.ion_eq(&expected), "{actual} != {expected}");
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::int("5")]
+    #[case::float("1.5e0")]
+    #[case::leading_zeros("01.5")]
+    #[case::leading_plus("+1.5")]
+    #[case::whitespace(" 1.5")]
+    #[case::trailing_text("1.5 x")]
+    #[case::trailing_underscore("1.5_")]
+    #[case::null("null.decimal")]
+    fn parse_invalid_decimal(#[case] text: &str) {
+        assert!(text.parse::<Decimal>().is_err());
+    }
+
+    #[rstest]
+    #[case::half_even_down("12.345", -2, RoundingMode::HalfEven, "12.34")]
+    #[case::half_even_up("12.355", -2, RoundingMode::HalfEven, "12.36")]
+    #[case::half_even_above_half("12.3451", -2, RoundingMode::HalfEven, "12.35")]
+    #[case::half_up("12.345", -2, RoundingMode::HalfUp, "12.35")]
+    #[case::half_up_negative("-12.345", -2, RoundingMode::HalfUp, "-12.35")]
+    #[case::half_down("12.345", -2, RoundingMode::HalfDown, "12.34")]
+    #[case::half_down_above_half("12.3451", -2, RoundingMode::HalfDown, "12.35")]
+    #[case::up("12.341", -2, RoundingMode::Up, "12.35")]
+    #[case::up_exact("12.340", -2, RoundingMode::Up, "12.34")]
+    #[case::down("-12.349", -2, RoundingMode::Down, "-12.34")]
+    #[case::ceiling_positive("12.341", -2, RoundingMode::Ceiling, "12.35")]
+    #[case::ceiling_negative("-12.349", -2, RoundingMode::Ceiling, "-12.34")]
+    #[case::floor_positive("12.349", -2, RoundingMode::Floor, "12.34")]
+    #[case::floor_negative("-12.341", -2, RoundingMode::Floor, "-12.35")]
+    #[case::carry("9.99", -1, RoundingMode::HalfUp, "10.0")]
+    #[case::to_integer("2.5", 0, RoundingMode::HalfEven, "2.")]
+    #[case::positive_exponent("1234.", 2, RoundingMode::HalfUp, "12d2")]
+    #[case::negative_rounds_to_negative_zero("-0.004", -2, RoundingMode::HalfUp, "-0.00")]
+    #[case::all_digits_discarded("123.", 50, RoundingMode::Up, "1d50")]
+    #[case::all_digits_discarded_half_up("123.", 50, RoundingMode::HalfUp, "0d50")]
+    #[case::pad("12.3", -4, RoundingMode::Down, "12.3000")]
+    #[case::same_exponent("12.3", -1, RoundingMode::Down, "12.3")]
+    fn rescale(
+        #[case] input: &str,
+        #[case] exponent: i64,
+        #[case] rounding_mode: RoundingMode,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let decimal = Element::read_one(input)?.expect_decimal()?;
+        let rescaled = decimal.rescale(exponent, rounding_mode)?;
+        let expected = Element::read_one(expected)?.expect_decimal()?;
+        assert!(
+            rescaled.ion_eq(&expected),
+            "expected {expected}, found {rescaled}"
+        );
+        assert_eq!(rescaled.exponent(), exponent);
+        Ok(())
+    }
+
+    #[test]
+    fn rescale_out_of_range() {
+        assert!(Decimal::new(1, 0).rescale(-39, RoundingMode::Down).is_err());
+        assert!(Decimal::new(i128::MAX, 0)
+            .rescale(-1, RoundingMode::Down)
+            .is_err());
+        assert!(Decimal::new(1, 0)
+            .rescale(i64::MIN, RoundingMode::Down)
+            .is_err());
+    }
+
     #[rstest]
     #[case(Decimal::new(123, 1), "123d1")]
     #[case(Decimal::new(123, 0), "123.")]
@@ -603,6 +837,53 @@ mod decimal_tests {
         );
     }
 
+    #[rstest]
+    #[case::tenth(0.1, Decimal::new(1, -1))]
+    #[case::tiny(1e-300, Decimal::new(1, -300))]
+    #[case::huge(1.7976931348623157e308, Decimal::new(17976931348623157i64, 292))]
+    #[case::subnormal(5e-324, Decimal::new(5, -324))]
+    #[case::large_integer(1e20, Decimal::new(1, 20))]
+    #[case::many_digits(0.1 + 0.2, Decimal::new(30000000000000004i64, -17))]
+    fn test_decimal_try_from_f64_is_lossless(#[case] value: f64, #[case] expected: Decimal) {
+        let actual = Decimal::try_from(value).unwrap();
+        assert!(
+            actual.ion_eq(&expected),
+            "float {value}: actual {actual} != expected {expected}"
+        );
+        assert_eq!(actual.to_f64().to_bits(), value.to_bits());
+        let negated = Decimal::try_from(-value).unwrap();
+        assert_eq!(negated.to_f64().to_bits(), (-value).to_bits());
+    }
+
+    #[test]
+    fn test_decimal_try_from_f32() {
+        let actual = Decimal::try_from(0.1f32).unwrap();
+        assert!(actual.ion_eq(&Decimal::new(1, -1)));
+        assert!(Decimal::try_from(-0.0f32)
+            .unwrap()
+            .ion_eq(&Decimal::NEGATIVE_ZERO));
+        assert!(Decimal::try_from(f32::NAN).is_err());
+    }
+
+    #[rstest]
+    #[case::integer(Decimal::new(5, 0), 5.0)]
+    #[case::fraction(Decimal::new(-125, -2), -1.25)]
+    #[case::positive_exponent(Decimal::new(3, 2), 300.0)]
+    #[case::zero_with_precision(Decimal::new(0, -5), 0.0)]
+    #[case::negative_zero(Decimal::negative_zero(), -0.0)]
+    // More digits than an f64 can hold; the nearest f64 is 1.0
+    #[case::rounds(Decimal::new(10000000000000000001i128, -19), 1.0)]
+    #[case::overflow(Decimal::new(-1, 309), f64::NEG_INFINITY)]
+    #[case::underflow(Decimal::new(-1, -400), -0.0)]
+    fn test_decimal_to_f64(#[case] decimal: Decimal, #[case] expected: f64) {
+        let actual = decimal.to_f64();
+        assert_eq!(
+            actual.to_bits(),
+            expected.to_bits(),
+            "{decimal} -> {actual}"
+        );
+    }
+
     #[rstest]
     #[case::positive_infinity(f64::infinity())]
     #[case::negative_infinity(f64::neg_infinity())]
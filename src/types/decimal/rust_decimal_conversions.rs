@@ -0,0 +1,117 @@
+//! Conversions between [`Decimal`] and [`rust_decimal::Decimal`].
+
+use crate::result::{IonError, IonFailure};
+use crate::{Decimal, Int};
+
+impl From<rust_decimal::Decimal> for Decimal {
+    /// Converts a `rust_decimal::Decimal` to an Ion `Decimal` with the same precision. Negative
+    /// zero is preserved.
+    fn from(value: rust_decimal::Decimal) -> Self {
+        let exponent = -i64::from(value.scale());
+        if value.is_zero() && value.is_sign_negative() {
+            return Decimal::negative_zero_with_exponent(exponent);
+        }
+        Decimal::new(value.mantissa(), exponent)
+    }
+}
+
+impl TryFrom<Decimal> for rust_decimal::Decimal {
+    type Error = IonError;
+
+    /// Converts an Ion `Decimal` to a `rust_decimal::Decimal` with the same precision.
+    ///
+    /// `rust_decimal::Decimal` stores a 96-bit coefficient and at most 28 digits after the decimal
+    /// point. Values that cannot be represented exactly within those limits produce an error
+    /// rather than being rounded.
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        let out_of_range = || {
+            IonError::illegal_operation(format!(
+                "Cannot convert Decimal {value:?} to rust_decimal::Decimal; it is out of range."
+            ))
+        };
+        let coefficient = Int::try_from(value.coefficient()).unwrap_or(Int::ZERO).data;
+        let (mantissa, scale) = if value.exponent() > 0 {
+            // Fold the exponent into the mantissa.
+            let exponent = u32::try_from(value.exponent()).map_err(|_| out_of_range())?;
+            let mantissa = 10i128
+                .checked_pow(exponent)
+                .and_then(|factor| coefficient.checked_mul(factor))
+                .ok_or_else(out_of_range)?;
+            (mantissa, 0)
+        } else {
+            let scale = value
+                .exponent()
+                .checked_neg()
+                .and_then(|scale| u32::try_from(scale).ok())
+                .ok_or_else(out_of_range)?;
+            (coefficient, scale)
+        };
+        let mut converted = rust_decimal::Decimal::try_from_i128_with_scale(mantissa, scale)
+            .map_err(|_| out_of_range())?;
+        if value.coefficient().is_negative_zero() {
+            converted.set_sign_negative(true);
+        }
+        Ok(converted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ion_data::IonEq;
+    use crate::IonResult;
+
+    fn rust_decimal(text: &str) -> rust_decimal::Decimal {
+        rust_decimal::Decimal::from_str(text).unwrap()
+    }
+
+    #[rstest]
+    #[case::integer("5", Decimal::new(5, 0))]
+    #[case::fraction("-12.250", Decimal::new(-12250, -3))]
+    #[case::zero_with_precision("0.00", Decimal::new(0, -2))]
+    #[case::max(
+        "79228162514264337593543950335",
+        Decimal::new(79228162514264337593543950335i128, 0)
+    )]
+    #[case::max_scale("0.0000000000000000000000000001", Decimal::new(1, -28))]
+    fn round_trip(#[case] text: &str, #[case] decimal: Decimal) -> IonResult<()> {
+        let value = rust_decimal(text);
+        assert!(Decimal::from(value).ion_eq(&decimal));
+        let converted = rust_decimal::Decimal::try_from(decimal)?;
+        assert_eq!(converted, value);
+        assert_eq!(converted.scale(), value.scale());
+        assert_eq!(converted.is_sign_negative(), value.is_sign_negative());
+        Ok(())
+    }
+
+    #[test]
+    fn negative_zero() -> IonResult<()> {
+        let mut value = rust_decimal("0.0");
+        value.set_sign_negative(true);
+        let decimal = Decimal::from(value);
+        assert!(decimal.ion_eq(&Decimal::negative_zero_with_exponent(-1)));
+        assert!(rust_decimal::Decimal::try_from(decimal)?.is_sign_negative());
+        Ok(())
+    }
+
+    #[test]
+    fn positive_exponent_is_folded_into_the_mantissa() -> IonResult<()> {
+        let converted = rust_decimal::Decimal::try_from(Decimal::new(12, 3))?;
+        assert_eq!(converted, rust_decimal("12000"));
+        assert_eq!(converted.scale(), 0);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::too_many_digits(Decimal::new(79228162514264337593543950336i128, 0))]
+    #[case::exponent_too_large(Decimal::new(1, 29))]
+    #[case::scale_too_large(Decimal::new(1, -29))]
+    #[case::huge_exponent(Decimal::new(1, i64::MAX))]
+    fn out_of_range(#[case] decimal: Decimal) {
+        assert!(rust_decimal::Decimal::try_from(decimal).is_err());
+    }
+}
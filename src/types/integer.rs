@@ -1,4 +1,6 @@
 use crate::ion_data::{IonEq, IonOrd};
+use crate::lazy::expanded::EncodingContext;
+use crate::lazy::text::buffer::TextBufferView;
 use crate::result::IonFailure;
 use crate::types::CountDecimalDigits;
 use crate::{IonError, IonResult};
@@ -6,7 +8,8 @@ use num_traits::Zero;
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::mem;
-use std::ops::{Add, Neg};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
 /// Represents an unsigned integer of any size.
 #[derive(Debug, Copy, Clone)]
@@ -191,6 +194,14 @@ macro_rules! impl_small_int_try_from_int {
                 })
             }
         }
+
+        impl TryFrom<&Int> for $t {
+            type Error = IonError;
+
+            fn try_from(value: &Int) -> Result<Self, Self::Error> {
+                (*value).try_into()
+            }
+        }
     )*)
 }
 
@@ -283,6 +294,62 @@ impl Int {
     pub fn as_i128(&self) -> Option<i128> {
         Some(self.data)
     }
+
+    /// If this value is non-negative and small enough to fit in a `u64`, returns `Some(u64)`.
+    /// Otherwise, returns `None`.
+    pub fn as_u64(&self) -> Option<u64> {
+        u64::try_from(self.data).ok()
+    }
+
+    /// If this value is non-negative and small enough to fit in a `u128`, returns `Some(u128)`.
+    /// Otherwise, returns `None`.
+    pub fn as_u128(&self) -> Option<u128> {
+        u128::try_from(self.data).ok()
+    }
+
+    /// If this value is non-negative and small enough to fit in a `usize`, returns
+    /// `Some(usize)`. Otherwise, returns `None`.
+    pub fn as_usize(&self) -> Option<usize> {
+        usize::try_from(self.data).ok()
+    }
+
+    /// If this value is non-negative and small enough to fit in a `u64`, returns `Ok(u64)`.
+    /// Otherwise, returns a [`DecodingError`](IonError::Decoding).
+    pub fn expect_u64(&self) -> IonResult<u64> {
+        self.as_u64().ok_or_else(|| {
+            IonError::decoding_error(format!("Int {self} is outside the range of a u64."))
+        })
+    }
+
+    /// If this value is non-negative and small enough to fit in a `usize`, returns `Ok(usize)`.
+    /// Otherwise, returns a [`DecodingError`](IonError::Decoding).
+    pub fn expect_usize(&self) -> IonResult<usize> {
+        self.as_usize().ok_or_else(|| {
+            IonError::decoding_error(format!("Int {self} is outside the range of a usize."))
+        })
+    }
+
+    /// Returns the sum of `self` and `other`, or `None` if it is outside the supported `Int` range.
+    pub fn checked_add(self, other: impl Into<Int>) -> Option<Int> {
+        self.data.checked_add(other.into().data).map(Int::new)
+    }
+
+    /// Returns the difference of `self` and `other`, or `None` if it is outside the supported
+    /// `Int` range.
+    pub fn checked_sub(self, other: impl Into<Int>) -> Option<Int> {
+        self.data.checked_sub(other.into().data).map(Int::new)
+    }
+
+    /// Returns the product of `self` and `other`, or `None` if it is outside the supported `Int`
+    /// range.
+    pub fn checked_mul(self, other: impl Into<Int>) -> Option<Int> {
+        self.data.checked_mul(other.into().data).map(Int::new)
+    }
+
+    /// Returns the negation of `self`, or `None` if it is outside the supported `Int` range.
+    pub fn checked_neg(self) -> Option<Int> {
+        self.data.checked_neg().map(Int::new)
+    }
 }
 
 impl PartialEq for Int {
@@ -333,6 +400,40 @@ impl Add<Self> for Int {
     }
 }
 
+impl Sub<Self> for Int {
+    type Output = Int;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.data.sub(rhs.data).into()
+    }
+}
+
+impl Mul<Self> for Int {
+    type Output = Int;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.data.mul(rhs.data).into()
+    }
+}
+
+impl AddAssign<Self> for Int {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl SubAssign<Self> for Int {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl MulAssign<Self> for Int {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
 impl Zero for Int {
     fn zero() -> Self {
         Int { data: 0i128 }
@@ -391,6 +492,53 @@ macro_rules! impl_int_i128_from {
 }
 impl_int_i128_from!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
 
+// Arithmetic between Int and Rust integers. The operands are widened to i128 first, so combining
+// values in the i64 range cannot overflow. These are only implemented for i64 so that integer
+// literals (as in `int + 1`) remain unambiguous.
+macro_rules! impl_int_ops_with {
+    ($($t:ty),*) => ($(
+        impl Add<$t> for Int {
+            type Output = Int;
+            fn add(self, rhs: $t) -> Int {
+                self + Int::from(rhs)
+            }
+        }
+
+        impl Sub<$t> for Int {
+            type Output = Int;
+            fn sub(self, rhs: $t) -> Int {
+                self - Int::from(rhs)
+            }
+        }
+
+        impl Mul<$t> for Int {
+            type Output = Int;
+            fn mul(self, rhs: $t) -> Int {
+                self * Int::from(rhs)
+            }
+        }
+
+        impl AddAssign<$t> for Int {
+            fn add_assign(&mut self, rhs: $t) {
+                *self += Int::from(rhs)
+            }
+        }
+
+        impl SubAssign<$t> for Int {
+            fn sub_assign(&mut self, rhs: $t) {
+                *self -= Int::from(rhs)
+            }
+        }
+
+        impl MulAssign<$t> for Int {
+            fn mul_assign(&mut self, rhs: $t) {
+                *self *= Int::from(rhs)
+            }
+        }
+    )*)
+}
+impl_int_ops_with!(i64);
+
 // Conversion to Integer from integer types that may or may not fit in an i128
 macro_rules! impl_int_try_from {
     ($($t:ty),*) => ($(
@@ -432,6 +580,37 @@ impl Display for Int {
     }
 }
 
+/// Parses an `Int` from its Ion text representation. Decimal (`-42`), hexadecimal (`0x1F`), and
+/// binary (`0b101`) forms are supported, as are underscores between digits (`1_000_000`).
+///
+/// The text must be a complete Ion int literal without surrounding whitespace or annotations.
+/// Values outside the range of an `i128` cannot be represented and produce an error.
+///
+/// ```
+/// # use ion_rs::IonResult;
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::Int;
+///
+/// assert_eq!("-0x1F".parse::<Int>()?, Int::from(-31));
+/// assert_eq!("0b1010_1010".parse::<Int>()?, Int::from(170));
+/// assert_eq!("18_446_744_073_709_551_616".parse::<Int>()?, Int::from(1i128 << 64));
+/// assert!("1.0".parse::<Int>().is_err());
+/// # Ok(())
+/// # }
+/// ```
+impl FromStr for Int {
+    type Err = IonError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let context = EncodingContext::empty();
+        let input = TextBufferView::new(context.get_ref(), text.as_bytes());
+        match input.match_int() {
+            Ok((remaining, matched)) if remaining.is_empty() => matched.read(input),
+            _ => IonResult::decoding_error(format!("'{text}' is not a valid Ion int")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod integer_tests {
     use std::io::Write;
@@ -470,6 +649,74 @@ mod integer_tests {
         );
     }
 
+    #[test]
+    fn arithmetic() {
+        assert_eq!(Int::from(5) - Int::from(7), Int::from(-2));
+        assert_eq!(Int::from(-5) * Int::from(7), Int::from(-35));
+        assert_eq!(-Int::from(5), Int::from(-5));
+        assert_eq!(Int::from(5) + 2, Int::from(7));
+        assert_eq!(Int::from(5) - 7, Int::from(-2));
+        assert_eq!(Int::from(5) * -3, Int::from(-15));
+        let mut int = Int::from(10);
+        int += 5;
+        int -= Int::from(3);
+        int *= 2;
+        assert_eq!(int, Int::from(24));
+    }
+
+    #[test]
+    fn arithmetic_beyond_i64() {
+        let max = Int::from(i64::MAX);
+        assert_eq!(max + 1, Int::from(i64::MAX as i128 + 1));
+        assert_eq!((max + 1).as_i64(), None);
+        assert_eq!(max * max, Int::from(i64::MAX as i128 * i64::MAX as i128));
+        assert_eq!(Int::from(i64::MIN) - 1, Int::from(i64::MIN as i128 - 1));
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let max = Int::from(i128::MAX);
+        let min = Int::from(i128::MIN);
+        assert_eq!(max.checked_add(1), None);
+        assert_eq!(min.checked_sub(1), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(max.checked_sub(Int::from(i128::MAX)), Some(Int::ZERO));
+        assert_eq!(Int::from(6).checked_mul(7), Some(Int::from(42)));
+    }
+
+    #[rstest]
+    #[case::zero(Int::from(0), Some(0), Some(0))]
+    #[case::u64_max(Int::from(u64::MAX), Some(u64::MAX), None)]
+    #[case::beyond_u64(Int::from(u64::MAX as i128 + 1), None, None)]
+    #[case::negative(Int::from(-1), None, None)]
+    fn unsigned_accessors(
+        #[case] int: Int,
+        #[case] expected_u64: Option<u64>,
+        #[case] expected_u32: Option<u32>,
+    ) {
+        assert_eq!(int.as_u64(), expected_u64);
+        assert_eq!(int.expect_u64().ok(), expected_u64);
+        assert_eq!(u64::try_from(&int).ok(), expected_u64);
+        assert_eq!(u32::try_from(&int).ok(), expected_u32);
+        assert_eq!(
+            int.as_usize(),
+            expected_u64.and_then(|i| usize::try_from(i).ok())
+        );
+        assert_eq!(int.expect_usize().ok(), int.as_usize());
+        assert_eq!(int.as_i128(), Some(int.data));
+    }
+
+    #[test]
+    fn try_from_int_reference() {
+        let int = Int::from(-300);
+        assert_eq!(i16::try_from(&int).unwrap(), -300);
+        assert!(i8::try_from(&int).is_err());
+        assert!(u16::try_from(&int).is_err());
+        assert_eq!(Int::from(i128::MAX).as_u128(), Some(i128::MAX as u128));
+        assert_eq!(Int::from(-1).as_u128(), None);
+    }
+
     #[rstest]
     #[case::i64(5.into(), 4.into(), Ordering::Greater)]
     #[case::i64_equal(Int::from(-5), Int::from(-5), Ordering::Equal)]
@@ -613,4 +860,39 @@ mod integer_tests {
         assert_eq!(UInt::from(128_000u128).expect_u64(), Ok(128_000u64));
         assert!(UInt::from(u128::MAX).expect_u64().is_err())
     }
+
+    #[rstest]
+    #[case::zero("0", 0)]
+    #[case::negative("-42", -42)]
+    #[case::underscores("1_000_000", 1_000_000)]
+    #[case::hex("0x1F", 31)]
+    #[case::negative_hex("-0X1f", -31)]
+    #[case::binary("0b101", 5)]
+    #[case::binary_underscores("-0B1_0_1", -5)]
+    #[case::beyond_i64("0xFFFF_FFFF_FFFF_FFFF_FF", 0xFF_FFFF_FFFF_FFFF_FFFF)]
+    #[case::i128_max(
+        "170141183460469231731687303715884105727",
+        170141183460469231731687303715884105727
+    )]
+    fn parse_int(#[case] text: &str, #[case] expected: i128) -> IonResult<()> {
+        assert_eq!(text.parse::<Int>()?, Int::from(expected));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::sign_only("-")]
+    #[case::leading_plus("+1")]
+    #[case::leading_zero("007")]
+    #[case::decimal("1.0")]
+    #[case::float("1e0")]
+    #[case::double_underscore("1__000")]
+    #[case::trailing_underscore("1_")]
+    #[case::underscore_after_radix("0x_1F")]
+    #[case::invalid_digit("0b102")]
+    #[case::whitespace("1 ")]
+    #[case::too_large("170141183460469231731687303715884105728")]
+    fn parse_invalid_int(#[case] text: &str) {
+        assert!(text.parse::<Int>().is_err());
+    }
 }
@@ -1,10 +1,13 @@
 use crate::element::builders::SequenceBuilder;
+use crate::element::fmt_pretty;
 use crate::element::iterators::SequenceIterator;
 use crate::ion_data::IonEq;
 use crate::text::text_formatter::FmtValueFormatter;
 use crate::{Element, Sequence};
 use delegate::delegate;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::ops::{Index, RangeBounds};
 
 /// An in-memory representation of an Ion list.
 /// ```
@@ -35,8 +38,24 @@ impl List {
             pub fn get(&self, index: usize) -> Option<&Element>;
             pub fn len(&self) -> usize;
             pub fn is_empty(&self) -> bool;
+            pub fn sort(&mut self);
+            pub fn sort_by<F: FnMut(&Element, &Element) -> Ordering>(&mut self, compare: F);
+            pub fn retain<F: FnMut(&Element) -> bool>(&mut self, keep: F);
+            pub fn splice<R, E, I>(&mut self, range: R, replacement: I) -> Sequence
+            where
+                R: RangeBounds<usize>,
+                E: Into<Element>,
+                I: IntoIterator<Item = E>;
+            pub fn dedup(&mut self);
+            pub fn dedup_by<F: FnMut(&Element, &Element) -> bool>(&mut self, same: F);
         }
     }
+
+    /// Returns a copy of this list whose elements are sorted using Ion's total ordering. See
+    /// [`Sequence::sort`].
+    pub fn sorted(&self) -> List {
+        List(self.0.sorted())
+    }
 }
 
 impl IonEq for List {
@@ -62,8 +81,32 @@ impl<'a> IntoIterator for &'a List {
     }
 }
 
+// Allows `for element in list {...}` syntax, moving each child element out of the list
+impl IntoIterator for List {
+    type Item = Element;
+    type IntoIter = <Sequence as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+// Allows `list[index]` syntax, which panics if `index` is out of bounds
+impl Index<usize> for List {
+    type Output = Element;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
 impl Display for List {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            if let Some(result) = fmt_pretty(self, f) {
+                return result;
+            }
+        }
         let mut ivf = FmtValueFormatter { output: f };
         ivf.format_list(self).map_err(|_| std::fmt::Error)?;
         Ok(())
@@ -84,7 +127,7 @@ impl From<List> for Sequence {
 
 #[cfg(test)]
 mod tests {
-    use crate::{ion_list, IonResult};
+    use crate::{ion_list, Element, IonResult};
 
     #[test]
     fn for_element_in_list() -> IonResult<()> {
@@ -97,4 +140,44 @@ mod tests {
         assert_eq!(sum, 6i64);
         Ok(())
     }
+
+    #[test]
+    fn for_element_in_owned_list() -> IonResult<()> {
+        let list = ion_list![1, 2, 3];
+        let mut elements = list.into_iter();
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements.next_back(), Some(Element::int(3)));
+        let values = elements
+            .map(|element| element.expect_i64())
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(values, vec![1, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn index_into_list() {
+        let list = ion_list![1, 2, 3];
+        assert_eq!(list[0], Element::int(1));
+        assert_eq!(list[2], Element::int(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let _value = &ion_list![1, 2, 3][3];
+    }
+
+    #[test]
+    fn sort_list() {
+        let list = ion_list![3, "b", 1, "a"];
+        assert_eq!(list.sorted(), ion_list![1, 3, "a", "b"]);
+
+        // Sorting with a custom comparison is stable
+        let mut list = ion_list!["ccc", "a", "bb", "d"];
+        list.sort_by(|a, b| {
+            let length = |e: &Element| e.as_string().map_or(0, str::len);
+            length(a).cmp(&length(b))
+        });
+        assert_eq!(list, ion_list!["a", "d", "bb", "ccc"]);
+    }
 }
@@ -114,3 +114,17 @@ impl From<&str> for Clob {
         text.as_bytes().into()
     }
 }
+
+#[cfg(feature = "bytes")]
+impl From<::bytes::Bytes> for Blob {
+    fn from(data: ::bytes::Bytes) -> Self {
+        Blob(data.into())
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl From<::bytes::Bytes> for Clob {
+    fn from(data: ::bytes::Bytes) -> Self {
+        Clob(data.into())
+    }
+}
@@ -27,11 +27,17 @@ pub use r#struct::Struct;
 pub use sexp::SExp;
 pub use string::Str;
 pub use symbol::Symbol;
-pub use timestamp::{HasMinute, Mantissa, Timestamp, TimestampBuilder, TimestampPrecision};
+pub use timestamp::{
+    FractionalSecondsPrecision, HasMinute, Mantissa, Timestamp, TimestampBuilder,
+    TimestampPrecision,
+};
 
 use crate::ion_data::IonOrd;
+use crate::result::IonFailure;
+use crate::IonError;
 use std::cmp::Ordering;
 use std::fmt;
+use std::str::FromStr;
 
 /// Represents the Ion data type of a given value. To learn more about each data type,
 /// read [the Ion Data Model](https://amazon-ion.github.io/ion-docs/docs/spec.html#the-ion-data-model)
@@ -55,29 +61,98 @@ pub enum IonType {
 
 impl fmt::Display for IonType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                IonType::Null => "null",
-                IonType::Bool => "bool",
-                IonType::Int => "int",
-                IonType::Float => "float",
-                IonType::Decimal => "decimal",
-                IonType::Timestamp => "timestamp",
-                IonType::Symbol => "symbol",
-                IonType::String => "string",
-                IonType::Clob => "clob",
-                IonType::Blob => "blob",
-                IonType::List => "list",
-                IonType::SExp => "sexp",
-                IonType::Struct => "struct",
-            }
-        )
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the name of an Ion type, as written by its `Display` implementation (`"struct"`), or the
+/// text of a null of that type (`"null.struct"`). Both `"null"` and `"null.null"` are parsed as
+/// [`IonType::Null`].
+///
+/// ```
+/// use ion_rs::IonType;
+/// # use ion_rs::IonResult;
+/// # fn main() -> IonResult<()> {
+/// assert_eq!("sexp".parse::<IonType>()?, IonType::SExp);
+/// assert_eq!("null.timestamp".parse::<IonType>()?, IonType::Timestamp);
+/// assert_eq!(IonType::Timestamp.to_string().parse::<IonType>()?, IonType::Timestamp);
+/// assert!("date".parse::<IonType>().is_err());
+/// # Ok(())
+/// # }
+/// ```
+impl FromStr for IonType {
+    type Err = IonError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let name = match text.strip_prefix("null.") {
+            Some(name) => name,
+            None => text,
+        };
+        IonType::ALL
+            .into_iter()
+            .find(|ion_type| ion_type.name() == name)
+            .ok_or_else(|| IonError::decoding_error(format!("'{text}' is not an Ion type name")))
     }
 }
 
 impl IonType {
+    /// Each of the Ion types, in the order used by `Ord`.
+    pub const ALL: [IonType; 13] = [
+        IonType::Null,
+        IonType::Bool,
+        IonType::Int,
+        IonType::Float,
+        IonType::Decimal,
+        IonType::Timestamp,
+        IonType::Symbol,
+        IonType::String,
+        IonType::Clob,
+        IonType::Blob,
+        IonType::List,
+        IonType::SExp,
+        IonType::Struct,
+    ];
+
+    /// Returns the name of this type as it is written in Ion's type annotations and typed nulls
+    /// (for example, `"timestamp"`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            IonType::Null => "null",
+            IonType::Bool => "bool",
+            IonType::Int => "int",
+            IonType::Float => "float",
+            IonType::Decimal => "decimal",
+            IonType::Timestamp => "timestamp",
+            IonType::Symbol => "symbol",
+            IonType::String => "string",
+            IonType::Clob => "clob",
+            IonType::Blob => "blob",
+            IonType::List => "list",
+            IonType::SExp => "sexp",
+            IonType::Struct => "struct",
+        }
+    }
+
+    /// Returns the text Ion representation of a null of this type (for example,
+    /// `"null.timestamp"`). The null of type [`IonType::Null`] is written as `"null"`.
+    pub fn null_text(&self) -> &'static str {
+        match self {
+            IonType::Null => "null",
+            IonType::Bool => "null.bool",
+            IonType::Int => "null.int",
+            IonType::Float => "null.float",
+            IonType::Decimal => "null.decimal",
+            IonType::Timestamp => "null.timestamp",
+            IonType::Symbol => "null.symbol",
+            IonType::String => "null.string",
+            IonType::Clob => "null.clob",
+            IonType::Blob => "null.blob",
+            IonType::List => "null.list",
+            IonType::SExp => "null.sexp",
+            IonType::Struct => "null.struct",
+        }
+    }
+
     pub fn is_container(&self) -> bool {
         use IonType::*;
         matches!(self, List | SExp | Struct)
@@ -201,3 +276,42 @@ macro_rules! impl_count_decimal_digits_signed {
 
 impl_count_decimal_digits_unsigned!(u8, u16, u32, u64, u128, usize);
 impl_count_decimal_digits_signed!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{Element, IonResult};
+
+    #[test]
+    fn names_round_trip() -> IonResult<()> {
+        for ion_type in IonType::ALL {
+            assert_eq!(ion_type.to_string().parse::<IonType>()?, ion_type);
+            assert_eq!(ion_type.null_text().parse::<IonType>()?, ion_type);
+            // The null text is what the text writer produces
+            assert_eq!(Element::null(ion_type).to_string(), ion_type.null_text());
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::null_null("null.null", IonType::Null)]
+    #[case::sexp("sexp", IonType::SExp)]
+    #[case::typed_null("null.struct", IonType::Struct)]
+    fn parse(#[case] text: &str, #[case] expected: IonType) -> IonResult<()> {
+        assert_eq!(text.parse::<IonType>()?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::unknown("date")]
+    #[case::wrong_case("Struct")]
+    #[case::empty_null_type("null.")]
+    #[case::nested_null("null.null.int")]
+    #[case::padded(" int")]
+    fn parse_invalid(#[case] text: &str) {
+        assert!(text.parse::<IonType>().is_err());
+    }
+}
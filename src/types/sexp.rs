@@ -1,10 +1,13 @@
 use crate::element::builders::SequenceBuilder;
+use crate::element::fmt_pretty;
 use crate::element::iterators::SequenceIterator;
 use crate::ion_data::IonEq;
 use crate::text::text_formatter::FmtValueFormatter;
 use crate::{Element, Sequence};
 use delegate::delegate;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::ops::{Index, RangeBounds};
 
 /// An in-memory representation of an Ion s-expression
 /// ```
@@ -35,8 +38,24 @@ impl SExp {
             pub fn get(&self, index: usize) -> Option<&Element>;
             pub fn len(&self) -> usize;
             pub fn is_empty(&self) -> bool;
+            pub fn sort(&mut self);
+            pub fn sort_by<F: FnMut(&Element, &Element) -> Ordering>(&mut self, compare: F);
+            pub fn retain<F: FnMut(&Element) -> bool>(&mut self, keep: F);
+            pub fn splice<R, E, I>(&mut self, range: R, replacement: I) -> Sequence
+            where
+                R: RangeBounds<usize>,
+                E: Into<Element>,
+                I: IntoIterator<Item = E>;
+            pub fn dedup(&mut self);
+            pub fn dedup_by<F: FnMut(&Element, &Element) -> bool>(&mut self, same: F);
         }
     }
+
+    /// Returns a copy of this s-expression whose elements are sorted using Ion's total ordering. See
+    /// [`Sequence::sort`].
+    pub fn sorted(&self) -> SExp {
+        SExp(self.0.sorted())
+    }
 }
 
 impl IonEq for SExp {
@@ -62,8 +81,32 @@ impl<'a> IntoIterator for &'a SExp {
     }
 }
 
+// Allows `for element in sexp {...}` syntax, moving each child element out of the sexp
+impl IntoIterator for SExp {
+    type Item = Element;
+    type IntoIter = <Sequence as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+// Allows `sexp[index]` syntax, which panics if `index` is out of bounds
+impl Index<usize> for SExp {
+    type Output = Element;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
 impl Display for SExp {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            if let Some(result) = fmt_pretty(self, f) {
+                return result;
+            }
+        }
         let mut ivf = FmtValueFormatter { output: f };
         ivf.format_sexp(self).map_err(|_| std::fmt::Error)?;
         Ok(())
@@ -84,7 +127,7 @@ impl From<SExp> for Sequence {
 
 #[cfg(test)]
 mod tests {
-    use crate::{ion_sexp, IonResult};
+    use crate::{ion_sexp, Element, IonResult, SExp};
 
     #[test]
     fn for_element_in_sexp() -> IonResult<()> {
@@ -97,4 +140,53 @@ mod tests {
         assert_eq!(sum, 6i64);
         Ok(())
     }
+
+    #[test]
+    fn for_element_in_owned_sexp() -> IonResult<()> {
+        let sexp = ion_sexp!(1 2 3);
+        let mut elements = sexp.into_iter();
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements.next_back(), Some(Element::int(3)));
+        let values = elements
+            .map(|element| element.expect_i64())
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(values, vec![1, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn index_into_sexp() {
+        let sexp = ion_sexp!(1 2 3);
+        assert_eq!(sexp[0], Element::int(1));
+        assert_eq!(sexp[2], Element::int(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let _value = &ion_sexp!(1 2 3)[3];
+    }
+
+    #[test]
+    fn edit_sexp() {
+        let sexp_of = |ion: &str| SExp(Element::read_all(ion).unwrap());
+        let mut sexp = ion_sexp!(1 2 3 4 5 6);
+        sexp.retain(|e| !matches!(e.as_i64(), Some(i) if i % 2 != 0));
+        assert_eq!(sexp, ion_sexp!(2 4 6));
+
+        let removed = sexp.splice(..1, [Element::symbol("a"), Element::symbol("b")]);
+        assert_eq!(removed, Element::read_all("2").unwrap());
+        assert_eq!(sexp, sexp_of("a b 4 6"));
+
+        // Inserting with an empty range removes nothing
+        assert!(sexp.splice(4.., [7, 7, 8]).is_empty());
+        assert_eq!(sexp, sexp_of("a b 4 6 7 7 8"));
+
+        // Treat ints as duplicates if they have the same parity
+        sexp.dedup_by(|a, b| match (a.as_i64(), b.as_i64()) {
+            (Some(a), Some(b)) => a % 2 == b % 2,
+            _ => false,
+        });
+        assert_eq!(sexp, sexp_of("a b 4 7 8"));
+    }
 }
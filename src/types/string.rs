@@ -1,7 +1,9 @@
 use crate::ion_data::{IonEq, IonOrd};
 use crate::text::text_formatter::FmtValueFormatter;
 use std::cmp::Ordering;
-use std::fmt::{Display, Formatter};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 
 /// An owned, immutable in-memory representation of an Ion `string`.
 ///
@@ -10,16 +12,39 @@ use std::fmt::{Display, Formatter};
 /// let s: Str = "hello!".into();
 /// assert_eq!(s, "hello!");
 /// ```
-#[derive(Eq, Ord, PartialOrd, Debug, Clone, Hash)]
+#[derive(Clone)]
 pub struct Str {
-    // For the time being, the `Str` type is an opaque wrapper around the standard Rust `String`
-    // type. Having this opaque wrapper means that we can swap out its implementation without a
-    // breaking change, allowing us to offer string interning, stack-allocated small strings, or
-    // other optimizations as needed.
-    text: String,
+    // The `Str` type is an opaque wrapper around either a standard Rust `String` or text that is
+    // shared with other values (see `TextInterner`). Having this opaque wrapper means that we can
+    // swap out its implementation without a breaking change, allowing us to offer stack-allocated
+    // small strings or other optimizations as needed.
+    text: StrText,
+}
+
+#[derive(Clone)]
+enum StrText {
+    // This Str owns its own text
+    Owned(String),
+    // This Str refers to text that may also be used by other values
+    Shared(Arc<str>),
 }
 
 impl Str {
+    pub(crate) fn shared(text: Arc<str>) -> Str {
+        Str {
+            text: StrText::Shared(text),
+        }
+    }
+
+    /// Returns the string's text as a `String` so that its allocation can be reused, or `None` if
+    /// the text is shared with other values.
+    pub(crate) fn into_reusable_string(self) -> Option<String> {
+        match self.text {
+            StrText::Owned(owned) => Some(owned),
+            StrText::Shared(_) => None,
+        }
+    }
+
     /// Returns the number of UTF-8 encoded bytes in this string.
     ///
     /// ```
@@ -31,7 +56,7 @@ impl Str {
     /// assert_eq!(s.len(), 12);
     /// ```
     pub fn len(&self) -> usize {
-        self.text.len()
+        self.text().len()
     }
 
     /// Returns `true` if this is the empty string (`""`); otherwise, returns `false`.
@@ -46,7 +71,7 @@ impl Str {
     // This method is largely here because clippy complains if you provide a `len()` method but not
     // an accompanying `is_empty()` method.
     pub fn is_empty(&self) -> bool {
-        self.text.is_empty()
+        self.text().is_empty()
     }
 
     /// Returns a `&str` representation of this string's text.
@@ -58,7 +83,36 @@ impl Str {
     /// assert!(s.text().is_ascii());
     /// ```
     pub fn text(&self) -> &str {
-        self.text.as_str()
+        match &self.text {
+            StrText::Owned(text) => text.as_str(),
+            StrText::Shared(text) => text.as_ref(),
+        }
+    }
+}
+
+impl Debug for Str {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Str").field("text", &self.text()).finish()
+    }
+}
+
+impl Hash for Str {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text().hash(state)
+    }
+}
+
+impl Eq for Str {}
+
+impl PartialOrd for Str {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Str {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.text().cmp(other.text())
     }
 }
 
@@ -74,20 +128,25 @@ impl Display for Str {
 impl From<&str> for Str {
     fn from(value: &str) -> Self {
         Str {
-            text: value.to_string(),
+            text: StrText::Owned(value.to_string()),
         }
     }
 }
 
 impl From<String> for Str {
     fn from(value: String) -> Self {
-        Str { text: value }
+        Str {
+            text: StrText::Owned(value),
+        }
     }
 }
 
 impl From<Str> for String {
     fn from(value: Str) -> Self {
-        value.text
+        match value.text {
+            StrText::Owned(text) => text,
+            StrText::Shared(text) => text.as_ref().to_owned(),
+        }
     }
 }
 
@@ -1,13 +1,14 @@
 use crate::element::builders::StructBuilder;
-use crate::element::Element;
+use crate::element::{fmt_pretty, Element};
 use crate::ion_data::{IonEq, IonOrd};
 use crate::symbol_ref::AsSymbolRef;
 use crate::text::text_formatter::FmtValueFormatter;
 use crate::Symbol;
 use smallvec::SmallVec;
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Display, Formatter};
+use std::ops::Index;
 
 // A convenient type alias for a vector capable of storing a single `usize` inline
 // without heap allocation. This type should not be used in public interfaces directly.
@@ -78,6 +79,19 @@ impl Fields {
     fn iter(&self) -> impl Iterator<Item = &(Symbol, Element)> {
         self.by_index.iter()
     }
+
+    /// Removes the fields for which `keep` returns `false` and rebuilds the index of field names.
+    fn retain<F: FnMut(&Symbol, &Element) -> bool>(&mut self, mut keep: F) {
+        let original_len = self.by_index.len();
+        self.by_index.retain(|(name, value)| keep(name, value));
+        if self.by_index.len() == original_len {
+            return;
+        }
+        self.by_name.clear();
+        for (index, (name, _value)) in self.by_index.iter().enumerate() {
+            self.by_name.entry(name.clone()).or_default().push(index);
+        }
+    }
 }
 
 /// Iterates over the (field name, field value) pairs in a Struct.
@@ -108,6 +122,25 @@ impl<'a> Iterator for FieldIterator<'a> {
     }
 }
 
+/// Iterates over the (field name, field value) pairs in a Struct, moving each out of the Struct.
+pub struct OwnedFieldIterator {
+    fields: std::vec::IntoIter<(Symbol, Element)>,
+}
+
+impl Iterator for OwnedFieldIterator {
+    type Item = (Symbol, Element);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.fields.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.fields.size_hint()
+    }
+}
+
+impl ExactSizeIterator for OwnedFieldIterator {}
+
 /// Iterates over the values associated with a given field name in a Struct.
 pub(crate) struct FieldValuesIterator<'a> {
     current: usize,
@@ -151,6 +184,11 @@ pub struct Struct {
 
 impl Display for Struct {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            if let Some(result) = fmt_pretty(self, f) {
+                return result;
+            }
+        }
         let mut ivf = FmtValueFormatter { output: f };
         ivf.format_struct(self).map_err(|_| std::fmt::Error)?;
         Ok(())
@@ -219,6 +257,34 @@ impl Struct {
         self.len() == 0
     }
 
+    /// Returns the name and value of the field at `index`, in the order the fields were added.
+    pub(crate) fn field_at(&self, index: usize) -> Option<(&Symbol, &Element)> {
+        self.fields
+            .by_index
+            .get(index)
+            .map(|(name, value)| (name, value))
+    }
+
+    /// Removes all of the struct's fields, keeping the storage that held them so that it can be
+    /// reused by [`Self::extend_fields`].
+    pub(crate) fn drain_fields(&mut self) -> impl Iterator<Item = (Symbol, Element)> + '_ {
+        self.fields.by_name.clear();
+        self.fields.by_index.drain(..)
+    }
+
+    /// Appends each of `fields` to the struct.
+    pub(crate) fn extend_fields(&mut self, fields: impl IntoIterator<Item = (Symbol, Element)>) {
+        for (name, value) in fields {
+            let index = self.fields.by_index.len();
+            self.fields
+                .by_name
+                .entry(name.clone())
+                .or_default()
+                .push(index);
+            self.fields.by_index.push((name, value));
+        }
+    }
+
     pub fn iter(&self) -> FieldIterator<'_> {
         FieldIterator::new(&self.fields.by_index)
     }
@@ -237,6 +303,98 @@ impl Struct {
         self.fields.get_all(field_name)
     }
 
+    /// Returns `true` if this struct has at least one field with the specified name.
+    pub fn contains<A: AsSymbolRef>(&self, field_name: A) -> bool {
+        self.fields.get_indexes(field_name).is_some()
+    }
+
+    /// Returns the number of fields in this struct that have the specified name.
+    pub fn count_of<A: AsSymbolRef>(&self, field_name: A) -> usize {
+        self.fields
+            .get_indexes(field_name)
+            .map_or(0, |indexes| indexes.len())
+    }
+
+    /// Returns an iterator over each distinct field name in this struct, in the order of its first
+    /// appearance, together with an iterator over all of the values associated with that name in
+    /// the order they were added.
+    ///
+    /// ```
+    /// use ion_rs::{ion_struct, Element};
+    ///
+    /// let tags = ion_struct! { "tag": "a", "id": 7, "tag": "b" };
+    /// let grouped: Vec<(Option<&str>, Vec<&Element>)> = tags
+    ///     .fields_grouped()
+    ///     .map(|(name, values)| (name.text(), values.collect()))
+    ///     .collect();
+    /// assert_eq!(grouped.len(), 2);
+    /// assert_eq!(grouped[0], (Some("tag"), vec![&Element::string("a"), &Element::string("b")]));
+    /// assert_eq!(grouped[1], (Some("id"), vec![&Element::int(7)]));
+    /// ```
+    pub fn fields_grouped(
+        &self,
+    ) -> impl Iterator<Item = (&Symbol, impl Iterator<Item = &Element>)> {
+        self.fields
+            .iter()
+            .enumerate()
+            .filter_map(move |(index, (name, _value))| {
+                let indexes = self.fields.by_name.get(name)?;
+                // Only yield a name at its first appearance.
+                (indexes.first() == Some(&index))
+                    .then(|| (name, self.fields.get_values_at_indexes(indexes)))
+            })
+    }
+
+    /// Removes each field for which `keep` returns `false`, preserving the order of the remaining
+    /// fields.
+    ///
+    /// ```
+    /// use ion_rs::{ion_struct, Element};
+    ///
+    /// let mut record = ion_struct! {
+    ///     "id": 7,
+    ///     "token": Element::string("secret").with_annotations(["internal"]),
+    ///     "name": "widget",
+    /// };
+    /// // Redact all of the fields annotated `internal::`
+    /// record.retain(|_name, value| !value.annotations().contains("internal"));
+    /// assert_eq!(record, ion_struct! { "id": 7, "name": "widget" });
+    /// assert!(!record.contains("token"));
+    /// ```
+    pub fn retain<F: FnMut(&Symbol, &Element) -> bool>(&mut self, keep: F) {
+        self.fields.retain(keep)
+    }
+
+    /// Returns a copy of this struct containing only the fields for which `keep` returns `true`.
+    /// Fields that are dropped are not cloned. See [`Self::retain`].
+    pub fn filtered<F: FnMut(&Symbol, &Element) -> bool>(&self, mut keep: F) -> Struct {
+        self.fields
+            .iter()
+            .filter(|(name, value)| keep(name, value))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns a copy of this struct whose fields are sorted by name and then by value using Ion's
+    /// total ordering (see [`IonData`](crate::IonData)). Structs that are Ion equivalent have
+    /// identically ordered fields once sorted, which makes them easier to compare, hash, or
+    /// write canonically. Nested structs are not sorted.
+    ///
+    /// ```
+    /// use ion_rs::{ion_struct, Element, Symbol};
+    ///
+    /// let sorted = ion_struct! { "b": 2, "a": 3, "a": 1 }.sorted_fields();
+    /// let fields: Vec<(&Symbol, &Element)> = sorted.fields().collect();
+    /// assert_eq!(fields[0], (&Symbol::from("a"), &Element::int(1)));
+    /// assert_eq!(fields[1], (&Symbol::from("a"), &Element::int(3)));
+    /// assert_eq!(fields[2], (&Symbol::from("b"), &Element::int(2)));
+    /// ```
+    pub fn sorted_fields(&self) -> Struct {
+        let mut fields: Vec<&(Symbol, Element)> = self.fields.by_index.iter().collect();
+        fields.sort_by(ion_cmp_field);
+        fields.into_iter().cloned().collect()
+    }
+
     #[allow(clippy::map_identity)]
     // ^-- This is a temporary workaround for a bug in Clippy that should be fixed in the next release.
     // See: https://github.com/rust-lang/rust-clippy/issues/9280
@@ -258,6 +416,29 @@ impl<'a> IntoIterator for &'a Struct {
     }
 }
 
+// Allows `for (name, value) in my_struct {...}` syntax, moving each field out of the struct
+impl IntoIterator for Struct {
+    type Item = (Symbol, Element);
+    type IntoIter = OwnedFieldIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        OwnedFieldIterator {
+            fields: self.fields.by_index.into_iter(),
+        }
+    }
+}
+
+// Allows `my_struct["name"]` syntax, which returns the same value as `get` and panics if the
+// struct has no field with that name
+impl Index<&str> for Struct {
+    type Output = Element;
+
+    fn index(&self, field_name: &str) -> &Self::Output {
+        self.get(field_name)
+            .unwrap_or_else(|| panic!("struct has no field named '{field_name}'"))
+    }
+}
+
 impl<K, V> FromIterator<(K, V)> for Struct
 where
     K: Into<Symbol>,
@@ -283,6 +464,37 @@ where
     }
 }
 
+/// Converts each of the map's entries to a field. The fields are added in the map's iteration
+/// order, which for a `HashMap` is unspecified.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use ion_rs::{ion_struct, Struct};
+///
+/// let scores = HashMap::from([("alice", 3), ("bob", 5)]);
+/// assert_eq!(Struct::from(scores), ion_struct! { "alice": 3, "bob": 5 });
+/// ```
+impl<K, V, S> From<HashMap<K, V, S>> for Struct
+where
+    K: Into<Symbol>,
+    V: Into<Element>,
+{
+    fn from(map: HashMap<K, V, S>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+/// Converts each of the map's entries to a field. The fields are added in the order of their keys.
+impl<K, V> From<BTreeMap<K, V>> for Struct
+where
+    K: Into<Symbol>,
+    V: Into<Element>,
+{
+    fn from(map: BTreeMap<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
 impl PartialEq for Struct {
     fn eq(&self, other: &Self) -> bool {
         // check if both fields have same length
@@ -339,7 +551,9 @@ fn ion_cmp_field(this: &&(Symbol, Element), that: &&(Symbol, Element)) -> Orderi
 #[cfg(test)]
 mod tests {
     use crate::element::Element;
-    use crate::ion_struct;
+    use std::collections::{BTreeMap, HashMap};
+
+    use crate::{ion_list, ion_struct, Struct, Symbol};
 
     #[test]
     fn for_field_in_struct() {
@@ -353,4 +567,133 @@ mod tests {
         }
         assert_eq!(baz_value, Some(&Element::int(3)));
     }
+
+    #[test]
+    fn fields_in_owned_struct() {
+        let s = ion_struct! { "foo": 1, "bar": ion_list![2, 3], "foo": 4 };
+        let mut fields = s.into_iter();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields.next(), Some((Symbol::from("foo"), Element::int(1))));
+        let (name, value) = fields.next().unwrap();
+        assert_eq!(name, "bar");
+        assert_eq!(value.as_sequence().unwrap().len(), 2);
+        assert_eq!(fields.next(), Some((Symbol::from("foo"), Element::int(4))));
+        assert_eq!(fields.next(), None);
+    }
+
+    #[test]
+    fn from_maps() {
+        let mut map = HashMap::new();
+        map.insert("name".to_owned(), Element::string("Alice"));
+        map.insert("tags".to_owned(), ion_list![1, 2].into());
+        let s = Struct::from(map);
+        assert_eq!(s.len(), 2);
+        assert_eq!(s, ion_struct! { "tags": ion_list![1, 2], "name": "Alice" });
+
+        let map = BTreeMap::from([(Symbol::from("b"), 2), (Symbol::from("a"), 1)]);
+        let fields: Vec<_> = Struct::from(map).into_iter().collect();
+        // A `BTreeMap`'s entries are added in key order
+        assert_eq!(
+            fields,
+            vec![
+                (Symbol::from("a"), Element::int(1)),
+                (Symbol::from("b"), Element::int(2))
+            ]
+        );
+        assert!(Struct::from(BTreeMap::<String, i64>::new()).is_empty());
+    }
+
+    #[test]
+    fn repeated_field_names() {
+        let s = ion_struct! { "a": 1, "b": 2, "a": 3, "c": 4, "a": 5, "b": 6 };
+        assert!(s.contains("a"));
+        assert!(!s.contains("d"));
+        assert_eq!(s.count_of("a"), 3);
+        assert_eq!(s.count_of("b"), 2);
+        assert_eq!(s.count_of("c"), 1);
+        assert_eq!(s.count_of("d"), 0);
+
+        let grouped: Vec<(&str, Vec<i64>)> = s
+            .fields_grouped()
+            .map(|(name, values)| {
+                let values = values.map(|value| value.expect_i64().unwrap()).collect();
+                (name.text().unwrap(), values)
+            })
+            .collect();
+        assert_eq!(
+            grouped,
+            vec![("a", vec![1, 3, 5]), ("b", vec![2, 6]), ("c", vec![4])]
+        );
+    }
+
+    #[test]
+    fn group_unknown_field_names() {
+        let s = Struct::from_iter([
+            (Symbol::unknown_text(), Element::int(1)),
+            (Symbol::from("x"), Element::int(2)),
+            (Symbol::unknown_text(), Element::int(3)),
+        ]);
+        assert!(s.contains(Symbol::unknown_text()));
+        assert_eq!(s.count_of(Symbol::unknown_text()), 2);
+        let counts: Vec<usize> = s
+            .fields_grouped()
+            .map(|(_, values)| values.count())
+            .collect();
+        assert_eq!(counts, vec![2, 1]);
+        assert_eq!(ion_struct! {}.fields_grouped().count(), 0);
+    }
+
+    #[test]
+    fn index_by_field_name() {
+        let s = ion_struct! { "foo": 1, "bar": 2, "foo": 3 };
+        assert_eq!(s["bar"], Element::int(2));
+        // Like `get`, indexing returns the last value with the given name
+        assert_eq!(s["foo"], Element::int(3));
+    }
+
+    #[test]
+    fn sorted_fields_of_equivalent_structs_match() {
+        let a = ion_struct! { "x": ion_list![2, 1], "y": "b", "x": 1 };
+        let b = ion_struct! { "y": "b", "x": 1, "x": ion_list![2, 1] };
+        assert_eq!(a, b);
+        let (a, b) = (a.sorted_fields(), b.sorted_fields());
+        let a_fields: Vec<_> = a.fields().collect();
+        let b_fields: Vec<_> = b.fields().collect();
+        assert_eq!(a_fields, b_fields);
+        // Nested containers are left as-is
+        assert_eq!(a_fields[1].1, &Element::from(ion_list![2, 1]));
+    }
+
+    #[test]
+    fn retain_updates_field_indexes() {
+        let mut s = ion_struct! { "a": 1, "b": 2, "a": 3, "c": 4, "a": 5 };
+        s.retain(|name, value| name.text() != Some("b") && value != &Element::int(3));
+        assert_eq!(s.len(), 3);
+        assert_eq!(
+            s.get_all("a").collect::<Vec<_>>(),
+            vec![&1.into(), &5.into()]
+        );
+        assert_eq!(s.get("a"), Some(&Element::int(5)));
+        assert_eq!(s.get("c"), Some(&Element::int(4)));
+        assert!(!s.contains("b"));
+        assert_eq!(s["c"], Element::int(4));
+        s.retain(|_, _| false);
+        assert!(s.is_empty());
+        assert!(!s.contains("a"));
+    }
+
+    #[test]
+    fn filtered_leaves_original_intact() {
+        let s = ion_struct! { "a": 1, "b": 2, "a": 3 };
+        let filtered = s.filtered(|name, _| name.text() == Some("a"));
+        assert_eq!(filtered, ion_struct! { "a": 1, "a": 3 });
+        assert_eq!(filtered.count_of("a"), 2);
+        assert_eq!(s.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "struct has no field named 'baz'")]
+    fn index_by_missing_field_name() {
+        let _value = &ion_struct! { "foo": 1 }["baz"];
+    }
 }
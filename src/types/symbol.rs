@@ -1,19 +1,17 @@
 use crate::ion_data::{IonEq, IonOrd};
 use crate::result::IonFailure;
-use crate::{IonResult, SymbolRef};
+use crate::{IonResult, SymbolId, SymbolRef, SymbolToken};
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-/// Stores or points to the text of a given [Symbol].
-#[derive(Debug, Eq)]
+/// Points to the text of a given [Symbol].
+#[derive(Debug, Eq, Clone)]
 pub(crate) enum SymbolText {
-    // This Symbol refers to a string in the symbol table
+    // This Symbol's text may be shared with other Symbols, a symbol table, or a `TextInterner`
     Shared(Arc<str>),
-    // This Symbol owns its own text
-    Owned(String),
     // This Symbol is equivalent to SID zero (`$0`)
     Unknown,
 }
@@ -22,7 +20,6 @@ impl SymbolText {
     fn text(&self) -> Option<&str> {
         let text = match self {
             SymbolText::Shared(s) => s.as_ref(),
-            SymbolText::Owned(s) => s.as_str(),
             SymbolText::Unknown => return None,
         };
         Some(text)
@@ -33,22 +30,11 @@ impl Hash for SymbolText {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
             SymbolText::Shared(text) => text.hash(state),
-            SymbolText::Owned(text) => text.hash(state),
             SymbolText::Unknown => "".hash(state),
         }
     }
 }
 
-impl Clone for SymbolText {
-    fn clone(&self) -> Self {
-        match self {
-            SymbolText::Owned(text) => SymbolText::Owned(text.to_owned()),
-            SymbolText::Shared(text) => SymbolText::Shared(Arc::clone(text)),
-            SymbolText::Unknown => SymbolText::Unknown,
-        }
-    }
-}
-
 impl PartialEq<Self> for SymbolText {
     fn eq(&self, other: &Self) -> bool {
         self.cmp(other) == Ordering::Equal
@@ -75,41 +61,65 @@ impl Ord for SymbolText {
 }
 
 /// The text of a fully resolved field name, annotation, or symbol value. If the symbol has known
-/// text (that is: the symbol is not `$0`), it will be stored as either a `String` or a shared
-/// reference to text in a symbol table.
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+/// text (that is: the symbol is not `$0`), it is stored in a reference-counted `Arc<str>`, so
+/// cloning a `Symbol` copies a pointer rather than its text.
+///
+/// Symbols created from the same `Arc<str>` (see [`Symbol::shared`]) share a single copy of their
+/// text. A [`TextInterner`](crate::TextInterner) can be used to share text across all of the
+/// symbols that one or more readers produce.
+///
+/// A symbol that was read from a stream in which it was encoded as a symbol ID also remembers
+/// that ID (see [`Symbol::symbol_id`]), which distinguishes `$0` from a symbol ID whose text was
+/// undefined. The symbol ID is informational: symbols are compared, ordered, and hashed by their
+/// text alone, and writers encode a symbol using its text.
+#[derive(Debug, Clone)]
 pub struct Symbol {
     pub(crate) text: SymbolText,
+    symbol_id: Option<SymbolId>,
 }
 
 impl Symbol {
+    /// Creates a symbol with its own copy of `text`.
     pub fn owned<I: Into<String>>(text: I) -> Symbol {
-        Symbol {
-            text: SymbolText::Owned(text.into()),
-        }
+        Symbol::shared(text.into().into())
     }
 
+    /// Creates a symbol that shares `text` with any other holders of the `Arc`.
     pub fn shared(text: Arc<str>) -> Symbol {
         Symbol {
             text: SymbolText::Shared(text),
+            symbol_id: None,
         }
     }
 
     pub fn unknown_text() -> Symbol {
         Symbol {
             text: SymbolText::Unknown,
+            symbol_id: None,
         }
     }
 
-    /// Converts this symbol into a shared one
-    pub(crate) fn into_shared(self) -> Symbol {
-        match self.text {
-            SymbolText::Shared(text) => Symbol::shared(text),
-            SymbolText::Owned(text) => Symbol::shared(text.into()),
-            SymbolText::Unknown => Symbol::unknown_text(),
+    /// Returns a copy of this symbol that records it was encoded as `symbol_id`.
+    pub fn with_symbol_id(self, symbol_id: SymbolId) -> Symbol {
+        Symbol {
+            symbol_id: Some(symbol_id),
+            ..self
         }
     }
 
+    /// Returns a copy of this symbol that records how it was encoded: as `Some(symbol_id)`, or as
+    /// inline text (`None`).
+    pub(crate) fn with_encoding(self, symbol_id: Option<SymbolId>) -> Symbol {
+        Symbol { symbol_id, ..self }
+    }
+
+    /// If this symbol was read from a stream in which it was encoded as a symbol ID, returns
+    /// `Some(symbol_id)`. If it was encoded as inline text or was not read from a stream, returns
+    /// `None`.
+    pub fn symbol_id(&self) -> Option<SymbolId> {
+        self.symbol_id
+    }
+
     pub fn text(&self) -> Option<&str> {
         self.text.text()
     }
@@ -122,6 +132,32 @@ impl Symbol {
     }
 }
 
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for Symbol {}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.text.cmp(&other.text)
+    }
+}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.hash(state)
+    }
+}
+
 impl IonEq for Symbol {
     fn ion_eq(&self, other: &Self) -> bool {
         self == other
@@ -135,12 +171,18 @@ impl IonOrd for Symbol {
 }
 
 // We cannot use a blanket impl for AsRef<str> as that would prevent us from
-// optimizing the From<String> case, a conversion which can be performed
-// without cloning.
+// optimizing the From<Arc<str>> case, a conversion which can be performed
+// without copying the text.
 
 impl From<&str> for Symbol {
     fn from(text: &str) -> Self {
-        Symbol::owned(text)
+        Symbol::shared(text.into())
+    }
+}
+
+impl From<Arc<str>> for Symbol {
+    fn from(text: Arc<str>) -> Self {
+        Symbol::shared(text)
     }
 }
 
@@ -168,6 +210,12 @@ impl<'a> From<SymbolRef<'a>> for Symbol {
     }
 }
 
+impl<'a> From<SymbolToken<'a>> for Symbol {
+    fn from(token: SymbolToken<'a>) -> Self {
+        token.resolved().to_owned().with_encoding(token.symbol_id())
+    }
+}
+
 impl Display for Symbol {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self.text() {
@@ -247,4 +295,32 @@ mod symbol_tests {
         let expected = vec!["bar", "baz", "foo", "quux"];
         assert_eq!(symbols, expected)
     }
+
+    #[test]
+    fn clones_share_text() {
+        let symbol = Symbol::owned("foo");
+        let clone = symbol.clone();
+        assert_eq!(clone, symbol);
+        assert_eq!(
+            clone.text().unwrap().as_ptr(),
+            symbol.text().unwrap().as_ptr()
+        );
+
+        let text: Arc<str> = Arc::from("bar");
+        let shared = Symbol::from(Arc::clone(&text));
+        assert_eq!(shared.text().unwrap().as_ptr(), text.as_ptr());
+    }
+
+    #[test]
+    fn symbol_ids_do_not_affect_equality() {
+        let symbol = Symbol::owned("foo").with_symbol_id(10);
+        assert_eq!(symbol.symbol_id(), Some(10));
+        assert_eq!(symbol.clone().symbol_id(), Some(10));
+        assert_eq!(symbol, Symbol::owned("foo"));
+        assert_eq!(Symbol::owned("foo").symbol_id(), None);
+        assert_eq!(
+            Symbol::unknown_text().with_symbol_id(10),
+            Symbol::unknown_text()
+        );
+    }
 }
@@ -1,9 +1,10 @@
 use crate::decimal::coefficient::Sign;
 use crate::ion_data::{IonEq, IonOrd};
 use crate::result::{IonError, IonFailure, IonResult};
+use crate::types::decimal::RoundingMode;
 use crate::types::{CountDecimalDigits, Decimal};
 use chrono::{
-    DateTime, Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Timelike,
+    DateTime, Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc,
 };
 use num_traits::ToPrimitive;
 use std::cmp::Ordering;
@@ -11,6 +12,12 @@ use std::convert::TryInto;
 use std::fmt::{Debug, Display, Formatter};
 use std::marker::PhantomData;
 use std::ops::Div;
+use std::str::FromStr;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_timestamp;
+#[cfg(feature = "time")]
+mod time_conversions;
 
 /// Indicates the most precise time unit that has been specified in the accompanying [Timestamp].
 #[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default)]
@@ -28,6 +35,36 @@ pub enum TimestampPrecision {
     Second,
 }
 
+/// Controls how many digits of fractional seconds a text writer will emit for timestamps with
+/// [`TimestampPrecision::Second`]. Timestamps with a coarser precision are not affected.
+///
+/// The number of digits given to [`Truncate`](Self::Truncate) or [`Exact`](Self::Exact) cannot
+/// exceed nanosecond precision; a number greater than [`MAX_DIGITS`](Self::MAX_DIGITS) is treated
+/// as `MAX_DIGITS`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub enum FractionalSecondsPrecision {
+    /// Write the fractional seconds exactly as they are stored in the [`Timestamp`].
+    #[default]
+    Preserve,
+    /// Write at most the specified number of digits, truncating any digits beyond it.
+    /// Timestamps with fewer digits are written as-is.
+    ///
+    /// For example, `Truncate(3)` writes `12:34:56.123456Z` as `12:34:56.123Z`.
+    Truncate(u32),
+    /// Write exactly the specified number of digits, truncating any digits beyond it and padding
+    /// shorter values with trailing zeros.
+    ///
+    /// For example, `Exact(9)` writes `12:34:56.5Z` as `12:34:56.500000000Z`.
+    Exact(u32),
+}
+
+impl FractionalSecondsPrecision {
+    /// The largest number of fractional seconds digits that can be requested: nanosecond
+    /// precision.
+    pub const MAX_DIGITS: u32 = 9;
+}
+
 // [Default] cannot be derived for enum types. Providing a manual implementation of this type
 // allows us to derive Default for [Timestamp].
 
@@ -409,7 +446,47 @@ impl Timestamp {
         }
     }
 
+    /// Writes the fractional seconds portion of a text timestamp (including a leading `.`),
+    /// adjusting the number of digits written according to the specified `precision`.
+    fn format_fractional_seconds_with_precision<W: std::fmt::Write>(
+        &self,
+        output: &mut W,
+        precision: FractionalSecondsPrecision,
+    ) -> IonResult<()> {
+        let max_digits = match precision {
+            FractionalSecondsPrecision::Preserve => return self.format_fractional_seconds(output),
+            FractionalSecondsPrecision::Truncate(num_digits)
+            | FractionalSecondsPrecision::Exact(num_digits) => {
+                num_digits.min(FractionalSecondsPrecision::MAX_DIGITS) as usize
+            }
+        };
+        // Format the fractional seconds as they are stored, then trim or pad the resulting digits.
+        let mut text = String::new();
+        self.format_fractional_seconds(&mut text)?;
+        let digits = text.strip_prefix('.').unwrap_or("");
+        let num_digits = match precision {
+            FractionalSecondsPrecision::Exact(_) => max_digits,
+            _ => digits.len().min(max_digits),
+        };
+        if num_digits == 0 {
+            return Ok(());
+        }
+        let digits = &digits[..digits.len().min(num_digits)];
+        write!(output, ".{digits:0<num_digits$}")?;
+        Ok(())
+    }
+
     pub(crate) fn format<W: std::fmt::Write>(&self, output: &mut W) -> IonResult<()> {
+        self.format_with_precision(output, FractionalSecondsPrecision::Preserve)
+    }
+
+    /// Writes this timestamp in its text form, emitting the number of fractional seconds digits
+    /// dictated by `fractional_seconds_precision`.
+    pub(crate) fn format_with_precision<W: std::fmt::Write>(
+        &self,
+        output: &mut W,
+        fractional_seconds_precision: FractionalSecondsPrecision,
+    ) -> IonResult<()> {
         let (offset_minutes, datetime) = if let Some(minutes) = self.offset {
             // Create a datetime with the appropriate offset that we can use for formatting.
             let datetime: DateTime<FixedOffset> = (*self).try_into()?;
@@ -459,7 +536,7 @@ impl Timestamp {
 
         write!(output, ":{:0>2}", datetime.second())?;
         //                   ^-- delimiting colon, formatted second
-        self.format_fractional_seconds(output)?;
+        self.format_fractional_seconds_with_precision(output, fractional_seconds_precision)?;
         self.format_offset(offset_minutes, output)?;
         Ok(())
     }
@@ -499,6 +576,133 @@ impl Timestamp {
             .with_day(day)
     }
 
+    /// Returns a Timestamp representing the current instant with nanosecond precision and an
+    /// offset of `+00:00`.
+    pub fn now() -> Timestamp {
+        Timestamp::now_with_precision(TimestampPrecision::Second)
+    }
+
+    /// Returns a Timestamp representing the current instant in UTC with the specified precision.
+    /// Fields beyond the requested precision are discarded rather than rounded, so
+    /// `Timestamp::now_with_precision(TimestampPrecision::Day)` is the current UTC date.
+    pub fn now_with_precision(precision: TimestampPrecision) -> Timestamp {
+        Timestamp::now_at_offset(0, precision).expect("an offset of 0 is always valid")
+    }
+
+    /// Returns a Timestamp representing the current instant with the specified precision, with
+    /// its fields expressed in the local time of the specified offset (in minutes).
+    ///
+    /// Ion timestamps with [TimestampPrecision::Day] precision or less do not have an offset; for
+    /// these, the offset is only used to determine the current date.
+    ///
+    /// Returns an error if the offset is out of range.
+    ///
+    /// ```
+    /// use ion_rs::{IonResult, Timestamp, TimestampPrecision};
+    ///
+    /// # fn main() -> IonResult<()> {
+    /// let timestamp = Timestamp::now_at_offset(-5 * 60, TimestampPrecision::HourAndMinute)?;
+    /// assert_eq!(timestamp.offset(), Some(-5 * 60));
+    /// assert_eq!(timestamp.precision(), TimestampPrecision::HourAndMinute);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn now_at_offset(
+        offset_minutes: i32,
+        precision: TimestampPrecision,
+    ) -> IonResult<Timestamp> {
+        let offset = offset_minutes
+            .checked_mul(60)
+            .and_then(FixedOffset::east_opt)
+            .ok_or_else(|| {
+                IonError::illegal_operation(format!(
+                    "specified offset ({offset_minutes} minutes) is invalid"
+                ))
+            })?;
+        let now = Utc::now().with_timezone(&offset);
+        Ok(Timestamp::from(now).with_precision(precision))
+    }
+
+    /// Returns a Timestamp representing the current UTC date with [TimestampPrecision::Day].
+    pub fn today() -> Timestamp {
+        Timestamp::now_with_precision(TimestampPrecision::Day)
+    }
+
+    /// Returns a Timestamp representing the instant `millis` milliseconds after the Unix epoch
+    /// (`1970-01-01T00:00:00Z`), with its fields expressed in the local time of the specified
+    /// offset (in minutes). The Timestamp has millisecond precision.
+    ///
+    /// Returns an error if the offset is out of range or if the instant falls outside of the
+    /// years that an Ion timestamp can represent (`0001` through `9999`).
+    ///
+    /// ```
+    /// use ion_rs::{IonResult, Timestamp};
+    ///
+    /// # fn main() -> IonResult<()> {
+    /// let timestamp = Timestamp::from_millis_since_epoch(1_700_000_000_123, -8 * 60)?;
+    /// assert_eq!(timestamp.to_string(), "2023-11-14T14:13:20.123-08:00");
+    /// assert_eq!(timestamp.millis_since_epoch(), 1_700_000_000_123);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_millis_since_epoch(millis: i64, offset_minutes: i32) -> IonResult<Timestamp> {
+        let utc = DateTime::<Utc>::from_timestamp_millis(millis).ok_or_else(|| {
+            IonError::illegal_operation(format!(
+                "{millis} milliseconds since the epoch is out of range"
+            ))
+        })?;
+        Timestamp::from_utc_at_offset(utc, offset_minutes, 3)
+    }
+
+    /// Returns a Timestamp representing the instant `nanos` nanoseconds after the Unix epoch
+    /// (`1970-01-01T00:00:00Z`), with its fields expressed in the local time of the specified
+    /// offset (in minutes). The Timestamp has nanosecond precision.
+    ///
+    /// Returns an error if the offset is out of range.
+    pub fn from_nanos_since_epoch(nanos: i64, offset_minutes: i32) -> IonResult<Timestamp> {
+        let utc = DateTime::<Utc>::from_timestamp_nanos(nanos);
+        Timestamp::from_utc_at_offset(utc, offset_minutes, 9)
+    }
+
+    fn from_utc_at_offset(
+        utc: DateTime<Utc>,
+        offset_minutes: i32,
+        fractional_digits: u32,
+    ) -> IonResult<Timestamp> {
+        let offset = offset_minutes
+            .checked_mul(60)
+            .and_then(FixedOffset::east_opt)
+            .ok_or_else(|| {
+                IonError::illegal_operation(format!(
+                    "specified offset ({offset_minutes} minutes) is invalid"
+                ))
+            })?;
+        let local = utc.with_timezone(&offset);
+        if !(1..=9999).contains(&local.year()) {
+            return IonResult::illegal_operation(format!(
+                "{utc} is outside the range of years that a timestamp can represent"
+            ));
+        }
+        let mut timestamp = Timestamp::from(local);
+        timestamp.fractional_seconds = Some(Mantissa::Digits(fractional_digits));
+        Ok(timestamp)
+    }
+
+    /// Returns the number of milliseconds between the Unix epoch (`1970-01-01T00:00:00Z`) and
+    /// the instant this Timestamp represents. Fractional seconds beyond milliseconds are truncated
+    /// toward the beginning of time. Timestamps with an unknown offset are treated as UTC.
+    pub fn millis_since_epoch(&self) -> i64 {
+        DateTime::<Utc>::from(*self).timestamp_millis()
+    }
+
+    /// Returns the number of nanoseconds between the Unix epoch (`1970-01-01T00:00:00Z`) and
+    /// the instant this Timestamp represents, or `None` if the result would not fit in an `i64`
+    /// (that is, if the Timestamp is before 1677 or after 2262). Fractional seconds beyond
+    /// nanoseconds are truncated. Timestamps with an unknown offset are treated as UTC.
+    pub fn nanos_since_epoch(&self) -> Option<i64> {
+        DateTime::<Utc>::from(*self).timestamp_nanos_opt()
+    }
+
     /// Returns the offset in minutes that has been specified in the [Timestamp].
     /// A positive value indicates Eastern Hemisphere, while a negative value indicates Western Hemisphere.
     pub fn offset(&self) -> Option<i32> {
@@ -622,6 +826,247 @@ impl Timestamp {
             .map(|s| s / 1_000_000)
             .unwrap_or_default()
     }
+
+    /// If the precision is [TimestampPrecision::Second], returns this Timestamp's fractional
+    /// seconds as a Decimal with the same number of digits; otherwise, returns None.
+    ///
+    /// For example, a Timestamp with 553 milliseconds would return `0.553`.
+    pub fn fractional_seconds(&self) -> Option<Decimal> {
+        self.fractional_seconds_as_decimal()
+    }
+
+    /// Returns a copy of this Timestamp with the specified precision.
+    ///
+    /// Reducing the precision discards the fields beyond it; they are truncated, not rounded.
+    /// Increasing the precision sets the new fields to their lowest legal value. Because Ion
+    /// timestamps with [TimestampPrecision::Day] precision or less do not have an offset, reducing
+    /// a Timestamp to one of those precisions keeps the date in its local time and discards the
+    /// offset.
+    ///
+    /// ```
+    /// use ion_rs::{Element, IonResult, TimestampPrecision};
+    ///
+    /// # fn main() -> IonResult<()> {
+    /// let timestamp = Element::read_one("2024-03-10T23:59:59.999-05:00")?.expect_timestamp()?;
+    /// let minute = timestamp.with_precision(TimestampPrecision::HourAndMinute);
+    /// assert_eq!(minute.to_string(), "2024-03-10T23:59-05:00");
+    /// let day = timestamp.with_precision(TimestampPrecision::Day);
+    /// assert_eq!(day.to_string(), "2024-03-10T");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_precision(&self, precision: TimestampPrecision) -> Timestamp {
+        if precision == self.precision {
+            return *self;
+        }
+        let builder = Timestamp::with_year(self.year());
+        let timestamp = match precision {
+            TimestampPrecision::Year => builder.build(),
+            TimestampPrecision::Month => builder.with_month(self.month()).build(),
+            TimestampPrecision::Day => builder
+                .with_month(self.month())
+                .with_day(self.day())
+                .build(),
+            TimestampPrecision::HourAndMinute => {
+                let builder = builder
+                    .with_month(self.month())
+                    .with_day(self.day())
+                    .with_hour_and_minute(self.hour(), self.minute());
+                match self.offset() {
+                    Some(offset_minutes) => builder.with_offset(offset_minutes).build(),
+                    None => builder.build(),
+                }
+            }
+            // `self` has a lower precision, so it has no fractional seconds to copy.
+            TimestampPrecision::Second => {
+                let builder = builder
+                    .with_month(self.month())
+                    .with_day(self.day())
+                    .with_hms(self.hour(), self.minute(), self.second());
+                match self.offset() {
+                    Some(offset_minutes) => builder.with_offset(offset_minutes).build(),
+                    None => builder.build(),
+                }
+            }
+        };
+        timestamp.expect("the fields of an existing Timestamp are valid")
+    }
+
+    /// Returns a copy of this Timestamp with at most `max_digits` digits of fractional seconds.
+    /// Any digits beyond that are truncated; Timestamps with fewer digits (or a precision coarser
+    /// than [TimestampPrecision::Second]) are returned unchanged. A `max_digits` of `0` discards
+    /// the fractional seconds entirely.
+    ///
+    /// ```
+    /// use ion_rs::{Element, IonResult};
+    ///
+    /// # fn main() -> IonResult<()> {
+    /// let timestamp = Element::read_one("2024-03-10T12:34:56.123456Z")?.expect_timestamp()?;
+    /// assert_eq!(
+    ///     timestamp.truncate_fractional_seconds(3).to_string(),
+    ///     "2024-03-10T12:34:56.123+00:00"
+    /// );
+    /// assert_eq!(
+    ///     timestamp.truncate_fractional_seconds(0).to_string(),
+    ///     "2024-03-10T12:34:56+00:00"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn truncate_fractional_seconds(&self, max_digits: u32) -> Timestamp {
+        let Some(fractional_seconds) = self.fractional_seconds_as_decimal() else {
+            return *self;
+        };
+        if fractional_seconds.scale() <= i64::from(max_digits) {
+            return *self;
+        }
+        const MAX_NANOSECOND_DIGITS: u32 = 9;
+        let truncated = fractional_seconds
+            .rescale(-i64::from(max_digits), RoundingMode::Down)
+            .expect("reducing the scale of a Decimal cannot overflow");
+        let mut timestamp = *self;
+        if max_digits <= MAX_NANOSECOND_DIGITS {
+            // The truncated value fits in the `date_time`'s nanoseconds. Its coefficient has at
+            // most `max_digits` digits, so it also fits in a u32.
+            let digits = truncated.coefficient.magnitude().data as u32;
+            let nanoseconds = digits * 10u32.pow(MAX_NANOSECOND_DIGITS - max_digits);
+            timestamp.date_time = self
+                .date_time
+                .with_nanosecond(nanoseconds)
+                .expect("truncated nanoseconds are always valid");
+            timestamp.fractional_seconds = match max_digits {
+                0 => None,
+                _ => Some(Mantissa::Digits(max_digits)),
+            };
+        } else {
+            timestamp.fractional_seconds = Some(Mantissa::Arbitrary(truncated));
+        }
+        timestamp
+    }
+
+    /// Parses an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) `date-time` such as
+    /// `2024-03-10T12:34:56.789-05:00`. The resulting Timestamp has [TimestampPrecision::Second]
+    /// and keeps as many digits of fractional seconds as the text specified.
+    ///
+    /// As RFC 3339 prescribes, an offset of `-00:00` indicates that the local offset is unknown.
+    /// A lowercase `t` or `z`, or a space in place of the `T` separator, is also accepted.
+    ///
+    /// Returns an error if the text is not an RFC 3339 `date-time` or if it describes a point in
+    /// time that a Timestamp cannot represent, such as a leap second.
+    ///
+    /// ```
+    /// use ion_rs::{IonResult, Timestamp};
+    ///
+    /// # fn main() -> IonResult<()> {
+    /// let timestamp = Timestamp::parse_rfc3339("2024-03-10T12:34:56.780-05:00")?;
+    /// assert_eq!(timestamp.offset(), Some(-5 * 60));
+    /// assert_eq!(timestamp.milliseconds(), 780);
+    /// assert_eq!(timestamp.to_rfc3339(), "2024-03-10T12:34:56.780-05:00");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_rfc3339(text: &str) -> IonResult<Timestamp> {
+        let invalid =
+            || IonError::decoding_error(format!("'{text}' is not a valid RFC 3339 date-time"));
+        let bytes = text.as_bytes();
+        // Parses the `length` ASCII digits beginning at `start` as a number.
+        let number = |start: usize, length: usize| -> IonResult<u32> {
+            let digits = bytes.get(start..start + length).ok_or_else(invalid)?;
+            if !digits.iter().all(u8::is_ascii_digit) {
+                return Err(invalid());
+            }
+            Ok(digits
+                .iter()
+                .fold(0, |value, digit| value * 10 + u32::from(digit - b'0')))
+        };
+        let expect_one_of = |index: usize, expected: &[u8]| -> IonResult<()> {
+            match bytes.get(index) {
+                Some(byte) if expected.contains(byte) => Ok(()),
+                _ => Err(invalid()),
+            }
+        };
+
+        let year = number(0, 4)?;
+        expect_one_of(4, b"-")?;
+        let month = number(5, 2)?;
+        expect_one_of(7, b"-")?;
+        let day = number(8, 2)?;
+        expect_one_of(10, b"Tt ")?;
+        let hour = number(11, 2)?;
+        expect_one_of(13, b":")?;
+        let minute = number(14, 2)?;
+        expect_one_of(16, b":")?;
+        let second = number(17, 2)?;
+        let timestamp = Timestamp::with_ymd(year, month, day).with_hms(hour, minute, second);
+
+        let mut offset_start = 19;
+        let fractional_text = if bytes.get(19) == Some(&b'.') {
+            let num_digits = bytes[20..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+            if num_digits == 0 {
+                return Err(invalid());
+            }
+            offset_start = 20 + num_digits;
+            Some(&text[20..offset_start])
+        } else {
+            None
+        };
+
+        let offset_minutes = match bytes.get(offset_start) {
+            Some(b'Z' | b'z') if offset_start + 1 == bytes.len() => Some(0),
+            Some(sign @ (b'+' | b'-')) if offset_start + 6 == bytes.len() => {
+                let hours = number(offset_start + 1, 2)?;
+                expect_one_of(offset_start + 3, b":")?;
+                let minutes = number(offset_start + 4, 2)?;
+                let offset_magnitude_minutes = (hours * 60 + minutes) as i32;
+                match sign {
+                    // RFC 3339 uses `-00:00` to indicate an unknown local offset.
+                    b'-' if offset_magnitude_minutes == 0 => None,
+                    b'-' => Some(-offset_magnitude_minutes),
+                    _ => Some(offset_magnitude_minutes),
+                }
+            }
+            _ => return Err(invalid()),
+        };
+
+        let Some(fractional_text) = fractional_text else {
+            return match offset_minutes {
+                Some(offset) => timestamp.with_offset(offset).build(),
+                None => timestamp.build(),
+            };
+        };
+        let timestamp = match fractional_text.len() {
+            len if len <= 9 => {
+                let fraction = u32::from_str(fractional_text).unwrap();
+                let nanoseconds = fraction * 10u32.pow(9 - len as u32);
+                timestamp.with_nanoseconds_and_precision(nanoseconds, len as u32)
+            }
+            len => {
+                let coefficient = i128::from_str(fractional_text).map_err(|_| {
+                    IonError::decoding_error(format!(
+                        "the fractional seconds of '{text}' have too many digits"
+                    ))
+                })?;
+                timestamp.with_fractional_seconds(Decimal::new(coefficient, -(len as i64)))
+            }
+        };
+        match offset_minutes {
+            Some(offset) => timestamp.with_offset(offset).build(),
+            None => timestamp.build(),
+        }
+    }
+
+    /// Formats this Timestamp as an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339)
+    /// `date-time`.
+    ///
+    /// RFC 3339 requires seconds, so fields beyond this Timestamp's precision are written using
+    /// their lowest legal value (see [Self::with_precision]). An unknown offset is written as
+    /// `-00:00`.
+    pub fn to_rfc3339(&self) -> String {
+        self.with_precision(TimestampPrecision::Second).to_string()
+    }
 }
 
 /// Formats an ISO-8601 timestamp of appropriate precision and offset.
@@ -1195,30 +1640,39 @@ fn downconvert_to_naive_datetime_with_nanoseconds(timestamp: &Timestamp) -> Naiv
 }
 
 // Allows a Timestamp with an unknown offset to be converted to a NaiveDateTime.
-impl TryInto<NaiveDateTime> for Timestamp {
+impl TryFrom<Timestamp> for NaiveDateTime {
     type Error = IonError;
 
-    fn try_into(self) -> Result<NaiveDateTime, Self::Error> {
-        if self.offset.is_some() {
+    fn try_from(timestamp: Timestamp) -> Result<NaiveDateTime, Self::Error> {
+        if timestamp.offset.is_some() {
             return IonResult::illegal_operation(
                 "cannot convert a Timestamp with a known offset into a NaiveDateTime",
             );
         }
-        Ok(downconvert_to_naive_datetime_with_nanoseconds(&self))
+        Ok(downconvert_to_naive_datetime_with_nanoseconds(&timestamp))
     }
 }
 
-impl TryInto<DateTime<FixedOffset>> for Timestamp {
+impl TryFrom<Timestamp> for DateTime<FixedOffset> {
     type Error = IonError;
 
-    fn try_into(self) -> Result<DateTime<FixedOffset>, Self::Error> {
-        if self.offset.is_none() {
+    fn try_from(timestamp: Timestamp) -> Result<DateTime<FixedOffset>, Self::Error> {
+        let Some(offset) = timestamp.offset else {
             return IonResult::illegal_operation(
                 "cannot convert a Timestamp with an unknown offset into a DateTime<FixedOffset>",
             );
-        }
-        let date_time = downconvert_to_naive_datetime_with_nanoseconds(&self);
-        Ok(self.offset.unwrap().from_utc_datetime(&date_time))
+        };
+        let date_time = downconvert_to_naive_datetime_with_nanoseconds(&timestamp);
+        Ok(offset.from_utc_datetime(&date_time))
+    }
+}
+
+// Converts a Timestamp to the instant it represents. As the Ion specification prescribes, a
+// Timestamp with an unknown offset is treated as a UTC time.
+impl From<Timestamp> for DateTime<Utc> {
+    fn from(timestamp: Timestamp) -> DateTime<Utc> {
+        let date_time = downconvert_to_naive_datetime_with_nanoseconds(&timestamp);
+        Utc.from_utc_datetime(&date_time)
     }
 }
 
@@ -1252,6 +1706,13 @@ impl From<DateTime<FixedOffset>> for Timestamp {
     }
 }
 
+// Allows a DateTime<Utc> to be converted to a Timestamp with an offset of +00:00.
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(utc_date_time: DateTime<Utc>) -> Self {
+        Timestamp::from(utc_date_time.fixed_offset())
+    }
+}
+
 #[cfg(test)]
 mod timestamp_tests {
     use super::*;
@@ -1259,7 +1720,7 @@ mod timestamp_tests {
     use crate::result::IonResult;
     use crate::types::Mantissa;
     use crate::{Decimal, Timestamp, TimestampPrecision};
-    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
     use rstest::*;
     use std::cmp::Ordering;
     use std::convert::TryInto;
@@ -1640,6 +2101,240 @@ mod timestamp_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_timestamp_into_utc_datetime() -> IonResult<()> {
+        let timestamp = TimestampBuilder::with_ymd(2021, 4, 6)
+            .with_hms(10, 15, 0)
+            .with_milliseconds(449)
+            .with_offset(-5 * 60)
+            .build()?;
+        let datetime = DateTime::<Utc>::from(timestamp);
+        let expected_datetime = Utc
+            .with_ymd_and_hms(2021, 4, 6, 15, 15, 0)
+            .unwrap()
+            .with_nanosecond(449000000)
+            .unwrap();
+        assert_eq!(datetime, expected_datetime);
+
+        // A timestamp with an unknown offset is treated as UTC.
+        let timestamp = TimestampBuilder::with_ymd(2021, 4, 6)
+            .with_hms(10, 15, 0)
+            .build()?;
+        let datetime = DateTime::<Utc>::from(timestamp);
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2021, 4, 6, 10, 15, 0).unwrap()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_utc_datetime_into_timestamp() -> IonResult<()> {
+        let datetime = Utc.with_ymd_and_hms(2021, 4, 6, 10, 15, 0).unwrap();
+        let timestamp = Timestamp::from(datetime);
+        assert_eq!(timestamp.offset(), Some(0));
+        assert_eq!(DateTime::<Utc>::from(timestamp), datetime);
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_now() {
+        let before = Utc::now();
+        let timestamp = Timestamp::now();
+        let after = Utc::now();
+        assert_eq!(timestamp.offset(), Some(0));
+        assert_eq!(timestamp.precision(), TimestampPrecision::Second);
+        let now = DateTime::<Utc>::from(timestamp);
+        assert!(before <= now && now <= after);
+    }
+
+    #[test]
+    fn test_timestamp_today() -> IonResult<()> {
+        let timestamp = Timestamp::today();
+        let now = Utc::now();
+        assert_eq!(timestamp.precision(), TimestampPrecision::Day);
+        assert_eq!(timestamp.offset(), None);
+        // Guard against the date changing between the two calls.
+        let expected_dates = [now.date_naive(), now.date_naive().pred_opt().unwrap()];
+        let date =
+            NaiveDate::from_ymd_opt(timestamp.year() as i32, timestamp.month(), timestamp.day())
+                .unwrap();
+        assert!(expected_dates.contains(&date));
+        // Fields beyond the precision are discarded.
+        assert_eq!(
+            timestamp,
+            Timestamp::with_ymd(timestamp.year(), timestamp.month(), timestamp.day()).build()?
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::year(TimestampPrecision::Year, None)]
+    #[case::month(TimestampPrecision::Month, None)]
+    #[case::day(TimestampPrecision::Day, None)]
+    #[case::minute(TimestampPrecision::HourAndMinute, Some(90))]
+    #[case::second(TimestampPrecision::Second, Some(90))]
+    fn test_timestamp_now_at_offset(
+        #[case] precision: TimestampPrecision,
+        #[case] expected_offset: Option<i32>,
+    ) -> IonResult<()> {
+        let timestamp = Timestamp::now_at_offset(90, precision)?;
+        assert_eq!(timestamp.precision(), precision);
+        assert_eq!(timestamp.offset(), expected_offset);
+        Ok(())
+    }
+
+    fn read_timestamp(text: &str) -> Timestamp {
+        crate::Element::read_one(text)
+            .unwrap()
+            .expect_timestamp()
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case::to_year("2024-03-10T23:59:59.999-05:00", TimestampPrecision::Year, "2024T")]
+    #[case::to_month("2024-03-10T23:59:59.999-05:00", TimestampPrecision::Month, "2024-03T")]
+    #[case::to_day_in_local_time(
+        "2024-03-10T23:59:59.999-05:00",
+        TimestampPrecision::Day,
+        "2024-03-10T"
+    )]
+    #[case::to_minute(
+        "2024-03-10T23:59:59.999-05:00",
+        TimestampPrecision::HourAndMinute,
+        "2024-03-10T23:59-05:00"
+    )]
+    #[case::unchanged(
+        "2024-03-10T23:59:59.999-05:00",
+        TimestampPrecision::Second,
+        "2024-03-10T23:59:59.999-05:00"
+    )]
+    #[case::year_to_second("2024T", TimestampPrecision::Second, "2024-01-01T00:00:00-00:00")]
+    #[case::day_to_minute(
+        "2024-03-10T",
+        TimestampPrecision::HourAndMinute,
+        "2024-03-10T00:00-00:00"
+    )]
+    #[case::minute_to_second(
+        "2024-03-10T23:59+01:00",
+        TimestampPrecision::Second,
+        "2024-03-10T23:59:00+01:00"
+    )]
+    fn test_timestamp_with_precision(
+        #[case] input: &str,
+        #[case] precision: TimestampPrecision,
+        #[case] expected: &str,
+    ) {
+        let actual = read_timestamp(input).with_precision(precision);
+        let expected = read_timestamp(expected);
+        assert!(
+            actual.ion_eq(&expected),
+            "expected {expected}, found {actual}"
+        );
+        // Fields beyond the precision are discarded, not just hidden.
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case::to_millis("2024-03-10T12:34:56.123456Z", 3, "2024-03-10T12:34:56.123Z")]
+    #[case::to_seconds("2024-03-10T12:34:56.123456Z", 0, "2024-03-10T12:34:56Z")]
+    #[case::fewer_digits("2024-03-10T12:34:56.123456Z", 9, "2024-03-10T12:34:56.123456Z")]
+    #[case::no_fraction("2024-03-10T12:34:56Z", 3, "2024-03-10T12:34:56Z")]
+    #[case::coarse_precision("2024T", 0, "2024T")]
+    #[case::arbitrary_to_digits(
+        "2024-03-10T12:34:56.123456789012Z",
+        5,
+        "2024-03-10T12:34:56.12345Z"
+    )]
+    #[case::arbitrary_to_arbitrary(
+        "2024-03-10T12:34:56.123456789012Z",
+        10,
+        "2024-03-10T12:34:56.1234567890Z"
+    )]
+    fn test_timestamp_truncate_fractional_seconds(
+        #[case] input: &str,
+        #[case] max_digits: u32,
+        #[case] expected: &str,
+    ) {
+        let actual = read_timestamp(input).truncate_fractional_seconds(max_digits);
+        let expected = read_timestamp(expected);
+        assert!(
+            actual.ion_eq(&expected),
+            "expected {expected}, found {actual}"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_fractional_seconds() {
+        let timestamp = read_timestamp("2024-03-10T12:34:56.550Z");
+        assert!(timestamp
+            .fractional_seconds()
+            .unwrap()
+            .ion_eq(&Decimal::new(550, -3)));
+        assert_eq!(
+            read_timestamp("2024-03-10T12:34Z").fractional_seconds(),
+            None
+        );
+    }
+
+    #[rstest]
+    #[case::utc("2024-03-10T12:34:56Z", "2024-03-10T12:34:56Z")]
+    #[case::offset("2024-03-10T12:34:56+05:30", "2024-03-10T12:34:56+05:30")]
+    #[case::unknown_offset("2024-03-10T12:34:56-00:00", "2024-03-10T12:34:56-00:00")]
+    #[case::millis("2024-03-10T12:34:56.780-05:00", "2024-03-10T12:34:56.780-05:00")]
+    #[case::picos(
+        "2024-03-10T12:34:56.123456789012Z",
+        "2024-03-10T12:34:56.123456789012Z"
+    )]
+    #[case::lowercase("2024-03-10t12:34:56.5z", "2024-03-10T12:34:56.5Z")]
+    #[case::space_separator("2024-03-10 12:34:56Z", "2024-03-10T12:34:56Z")]
+    fn test_timestamp_parse_rfc3339(#[case] text: &str, #[case] expected: &str) -> IonResult<()> {
+        let timestamp = Timestamp::parse_rfc3339(text)?;
+        let expected = read_timestamp(expected);
+        assert!(
+            timestamp.ion_eq(&expected),
+            "expected {expected}, found {timestamp}"
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::date_only("2024-03-10")]
+    #[case::no_seconds("2024-03-10T12:34Z")]
+    #[case::no_offset("2024-03-10T12:34:56")]
+    #[case::empty_fraction("2024-03-10T12:34:56.Z")]
+    #[case::short_offset("2024-03-10T12:34:56+05")]
+    #[case::trailing_text("2024-03-10T12:34:56Z ")]
+    #[case::invalid_month("2024-13-10T12:34:56Z")]
+    #[case::invalid_day("2023-02-29T12:34:56Z")]
+    #[case::leap_second("2016-12-31T23:59:60Z")]
+    #[case::non_ascii("2024-03-10T12:34:56.\u{00e9}")]
+    fn test_timestamp_parse_rfc3339_error(#[case] text: &str) {
+        assert!(Timestamp::parse_rfc3339(text).is_err());
+    }
+
+    #[rstest]
+    #[case::second("2024-03-10T12:34:56-05:00", "2024-03-10T12:34:56-05:00")]
+    #[case::fractional_seconds("2024-03-10T12:34:56.007Z", "2024-03-10T12:34:56.007+00:00")]
+    #[case::minute("2024-03-10T12:34+01:00", "2024-03-10T12:34:00+01:00")]
+    #[case::year("2024T", "2024-01-01T00:00:00-00:00")]
+    fn test_timestamp_to_rfc3339(#[case] text: &str, #[case] expected: &str) -> IonResult<()> {
+        let timestamp = read_timestamp(text);
+        assert_eq!(timestamp.to_rfc3339(), expected);
+        // The output can be parsed by chrono's RFC 3339 parser...
+        assert!(DateTime::parse_from_rfc3339(expected).is_ok());
+        // ...and round-trips.
+        assert_eq!(Timestamp::parse_rfc3339(expected)?, timestamp);
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_now_at_invalid_offset() {
+        assert!(Timestamp::now_at_offset(24 * 60, TimestampPrecision::Second).is_err());
+        assert!(Timestamp::now_at_offset(i32::MAX, TimestampPrecision::Day).is_err());
+    }
+
     #[test]
     fn test_timestamp_builder() {
         // Using individual field setters produces the same Timestamp as using setters
@@ -2047,4 +2742,118 @@ mod timestamp_tests {
         write!(&mut buf, "{ts}").unwrap();
         assert_eq!(expect, String::from_utf8(buf).unwrap());
     }
+
+    #[rstest]
+    #[case::preserve(
+        FractionalSecondsPrecision::Preserve,
+        "2024-01-02T03:04:05.123456+00:00"
+    )]
+    #[case::truncate_to_millis(
+        FractionalSecondsPrecision::Truncate(3),
+        "2024-01-02T03:04:05.123+00:00"
+    )]
+    #[case::truncate_beyond_precision(
+        FractionalSecondsPrecision::Truncate(9),
+        "2024-01-02T03:04:05.123456+00:00"
+    )]
+    #[case::truncate_to_seconds(
+        FractionalSecondsPrecision::Truncate(0),
+        "2024-01-02T03:04:05+00:00"
+    )]
+    #[case::exact_millis(FractionalSecondsPrecision::Exact(3), "2024-01-02T03:04:05.123+00:00")]
+    #[case::exact_nanos(
+        FractionalSecondsPrecision::Exact(9),
+        "2024-01-02T03:04:05.123456000+00:00"
+    )]
+    #[case::exact_beyond_nanos(
+        FractionalSecondsPrecision::Exact(u32::MAX),
+        "2024-01-02T03:04:05.123456000+00:00"
+    )]
+    fn test_format_with_precision(
+        #[case] precision: FractionalSecondsPrecision,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2024, 1, 2)
+            .with_hms(3, 4, 5)
+            .with_microseconds(123456)
+            .with_offset(0)
+            .build()?;
+        let mut actual = String::new();
+        timestamp.format_with_precision(&mut actual, precision)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::seconds(Timestamp::with_ymd(2024, 1, 2).with_hms(3, 4, 5).build(), "2024-01-02T03:04:05.000-00:00")]
+    #[case::arbitrary(Timestamp::with_ymd(2024, 1, 2).with_hms(3, 4, 5).with_fractional_seconds(Decimal::new(5, -12)).build(), "2024-01-02T03:04:05.000-00:00")]
+    #[case::minutes(Timestamp::with_ymd(2024, 1, 2).with_hour_and_minute(3, 4).build(), "2024-01-02T03:04-00:00")]
+    #[case::day(Timestamp::with_ymd(2024, 1, 2).build(), "2024-01-02T")]
+    fn test_format_with_exact_precision(
+        #[case] timestamp: IonResult<Timestamp>,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let mut actual = String::new();
+        timestamp?.format_with_precision(&mut actual, FractionalSecondsPrecision::Exact(3))?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::epoch(0, 0, "1970-01-01T00:00:00.000+00:00")]
+    #[case::one_milli(1, 0, "1970-01-01T00:00:00.001+00:00")]
+    #[case::before_epoch(-1, 0, "1969-12-31T23:59:59.999+00:00")]
+    #[case::with_offset(1_700_000_000_123, 330, "2023-11-15T03:43:20.123+05:30")]
+    fn test_from_millis_since_epoch(
+        #[case] millis: i64,
+        #[case] offset_minutes: i32,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let timestamp = Timestamp::from_millis_since_epoch(millis, offset_minutes)?;
+        assert_eq!(timestamp.to_string(), expected);
+        assert_eq!(timestamp.precision(), TimestampPrecision::Second);
+        assert_eq!(timestamp.offset(), Some(offset_minutes));
+        assert_eq!(timestamp.millis_since_epoch(), millis);
+        assert_eq!(timestamp.nanos_since_epoch(), Some(millis * 1_000_000));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::epoch(0, "1970-01-01T00:00:00.000000000+00:00")]
+    #[case::after_epoch(1_500_000_000_123_456_789, "2017-07-14T02:40:00.123456789+00:00")]
+    #[case::before_epoch(-1, "1969-12-31T23:59:59.999999999+00:00")]
+    fn test_from_nanos_since_epoch(#[case] nanos: i64, #[case] expected: &str) -> IonResult<()> {
+        let timestamp = Timestamp::from_nanos_since_epoch(nanos, 0)?;
+        assert_eq!(timestamp.to_string(), expected);
+        assert_eq!(timestamp.nanos_since_epoch(), Some(nanos));
+        assert_eq!(timestamp.millis_since_epoch(), nanos.div_euclid(1_000_000));
+        Ok(())
+    }
+
+    #[test]
+    fn test_epoch_accessors_for_other_precisions() -> IonResult<()> {
+        let day = Timestamp::with_ymd(2000, 1, 2).build()?;
+        assert_eq!(day.millis_since_epoch(), 946_771_200_000);
+        let unknown_offset = Timestamp::with_ymd(1970, 1, 1).with_hms(0, 0, 1).build()?;
+        assert_eq!(unknown_offset.millis_since_epoch(), 1_000);
+        let with_offset = Timestamp::with_ymd(1970, 1, 1)
+            .with_hms(1, 0, 0)
+            .with_offset(60)
+            .build()?;
+        assert_eq!(with_offset.millis_since_epoch(), 0);
+        let distant = Timestamp::with_year(3000).build()?;
+        assert_eq!(distant.nanos_since_epoch(), None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::invalid_offset(0, 24 * 60)]
+    #[case::overflowing_offset(0, i32::MAX)]
+    #[case::before_year_one(-62_135_596_800_001, 0)]
+    #[case::offset_before_year_one(-62_135_596_800_000, -1)]
+    #[case::after_year_9999(253_402_300_800_000, 0)]
+    #[case::out_of_chrono_range(i64::MAX, 0)]
+    fn test_from_millis_since_epoch_errors(#[case] millis: i64, #[case] offset_minutes: i32) {
+        assert!(Timestamp::from_millis_since_epoch(millis, offset_minutes).is_err());
+    }
 }
@@ -0,0 +1,101 @@
+//! An [`Arbitrary`] implementation for [`Timestamp`], for use in property tests and fuzzing.
+
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+use chrono::NaiveDate;
+
+use crate::types::TimestampPrecision;
+use crate::{Decimal, Timestamp};
+
+impl<'a> Arbitrary<'a> for Timestamp {
+    /// Produces a valid `Timestamp` of any precision between the years 1 and 9999. Timestamps with
+    /// a time component have either a known UTC offset or an unknown offset (`-00:00`).
+    /// Fractional seconds may have more digits than a nanosecond can represent.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        use TimestampPrecision::*;
+        let year: u32 = u.int_in_range(1..=9999)?;
+        let builder = Timestamp::with_year(year);
+        let precision = *u.choose(&[Year, Month, Day, HourAndMinute, Second])?;
+        if precision == Year {
+            return builder.build().map_err(|_| Error::IncorrectFormat);
+        }
+        let month: u32 = u.int_in_range(1..=12)?;
+        let builder = builder.with_month(month);
+        if precision == Month {
+            return builder.build().map_err(|_| Error::IncorrectFormat);
+        }
+        let last_day = (28..=31)
+            .rev()
+            .find(|day| NaiveDate::from_ymd_opt(year as i32, month, *day).is_some())
+            .unwrap_or(28);
+        let builder = builder.with_day(u.int_in_range(1..=last_day)?);
+        if precision == Day {
+            return builder.build().map_err(|_| Error::IncorrectFormat);
+        }
+
+        let offset: Option<i32> = if u.arbitrary()? {
+            Some(u.int_in_range(-1439..=1439)?)
+        } else {
+            None
+        };
+        let builder =
+            builder.with_hour_and_minute(u.int_in_range(0..=23)?, u.int_in_range(0..=59)?);
+        let timestamp = if precision == HourAndMinute {
+            match offset {
+                Some(offset) => builder.with_offset(offset).build(),
+                None => builder.build(),
+            }
+        } else {
+            let builder = builder.with_second(u.int_in_range(0..=59)?);
+            let digits: u32 = u.int_in_range(0..=12)?;
+            if digits == 0 {
+                match offset {
+                    Some(offset) => builder.with_offset(offset).build(),
+                    None => builder.build(),
+                }
+            } else {
+                let fraction: u64 = u.int_in_range(0..=10u64.pow(digits) - 1)?;
+                let builder = if digits <= 9 {
+                    let nanoseconds = (fraction * 10u64.pow(9 - digits)) as u32;
+                    builder.with_nanoseconds_and_precision(nanoseconds, digits)
+                } else {
+                    builder.with_fractional_seconds(Decimal::new(fraction, -i64::from(digits)))
+                };
+                match offset {
+                    Some(offset) => builder.with_offset(offset).build(),
+                    None => builder.build(),
+                }
+            }
+        };
+        // An offset can move a timestamp at the very beginning or end of the supported range
+        // outside of it.
+        timestamp.map_err(|_| Error::IncorrectFormat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::ion_data::IonEq;
+    use crate::{v1_0, Element, IonResult, Timestamp};
+
+    #[test]
+    fn arbitrary_timestamps_round_trip() -> IonResult<()> {
+        let data: Vec<u8> = (0..=255u8).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&data);
+        let mut count = 0;
+        while !u.is_empty() {
+            let Ok(timestamp) = Timestamp::arbitrary(&mut u) else {
+                continue;
+            };
+            let element = Element::from(timestamp);
+            let text = element.encode_as(v1_0::Text)?;
+            let binary = element.encode_as(v1_0::Binary)?;
+            assert!(Element::read_one(text)?.ion_eq(&element), "{element}");
+            assert!(Element::read_one(binary)?.ion_eq(&element), "{element}");
+            count += 1;
+        }
+        assert!(count > 100);
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+//! Conversions between [`Timestamp`] and the date/time types of the [`time`] crate.
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::result::{IonError, IonFailure, IonResult};
+use crate::Timestamp;
+
+// Allows an OffsetDateTime to be converted to a Timestamp with the same offset. Ion offsets are
+// a whole number of minutes, so offsets with a nonzero seconds component are rejected.
+impl TryFrom<OffsetDateTime> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(value: OffsetDateTime) -> Result<Self, Self::Error> {
+        let offset = value.offset();
+        if offset.seconds_past_minute() != 0 {
+            return IonResult::illegal_operation(format!(
+                "cannot convert an OffsetDateTime with offset {offset} into a Timestamp; \
+                 offsets must be a whole number of minutes"
+            ));
+        }
+        let fixed_offset = FixedOffset::east_opt(offset.whole_seconds()).ok_or_else(|| {
+            IonError::illegal_operation(format!(
+                "cannot convert an OffsetDateTime with offset {offset} into a Timestamp"
+            ))
+        })?;
+        let local_date_time =
+            naive_from_primitive(PrimitiveDateTime::new(value.date(), value.time()))?;
+        let date_time = fixed_offset
+            .from_local_datetime(&local_date_time)
+            .single()
+            .ok_or_else(|| {
+                IonError::illegal_operation(format!(
+                    "cannot convert OffsetDateTime {value} into a Timestamp"
+                ))
+            })?;
+        Ok(Timestamp::from(date_time))
+    }
+}
+
+// Allows a PrimitiveDateTime to be converted to a Timestamp with an unknown offset.
+impl TryFrom<PrimitiveDateTime> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(value: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Ok(Timestamp::from(naive_from_primitive(value)?))
+    }
+}
+
+impl TryFrom<Timestamp> for OffsetDateTime {
+    type Error = IonError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        let date_time = DateTime::<FixedOffset>::try_from(timestamp)?;
+        let offset =
+            UtcOffset::from_whole_seconds(date_time.offset().local_minus_utc()).map_err(|e| {
+                IonError::illegal_operation(format!(
+                    "cannot convert offset {} into a UtcOffset: {e}",
+                    date_time.offset()
+                ))
+            })?;
+        Ok(primitive_from_naive(date_time.naive_local())?.assume_offset(offset))
+    }
+}
+
+// Allows a Timestamp with an unknown offset to be converted to a PrimitiveDateTime.
+impl TryFrom<Timestamp> for PrimitiveDateTime {
+    type Error = IonError;
+
+    fn try_from(timestamp: Timestamp) -> Result<Self, Self::Error> {
+        primitive_from_naive(NaiveDateTime::try_from(timestamp)?)
+    }
+}
+
+fn naive_from_primitive(value: PrimitiveDateTime) -> IonResult<NaiveDateTime> {
+    NaiveDate::from_ymd_opt(
+        value.year(),
+        u8::from(value.month()) as u32,
+        value.day() as u32,
+    )
+    .and_then(|date| {
+        date.and_hms_nano_opt(
+            value.hour() as u32,
+            value.minute() as u32,
+            value.second() as u32,
+            value.nanosecond(),
+        )
+    })
+    .ok_or_else(|| {
+        IonError::illegal_operation(format!(
+            "cannot convert {value} into a Timestamp; it is out of range"
+        ))
+    })
+}
+
+fn primitive_from_naive(value: NaiveDateTime) -> IonResult<PrimitiveDateTime> {
+    let out_of_range = |e: time::error::ComponentRange| {
+        IonError::illegal_operation(format!("cannot convert {value} into a time type: {e}"))
+    };
+    // The range of chrono's month is checked, so this cannot fail.
+    let month = Month::try_from(value.month() as u8).map_err(out_of_range)?;
+    let date =
+        Date::from_calendar_date(value.year(), month, value.day() as u8).map_err(out_of_range)?;
+    // chrono represents leap seconds as a nanosecond value of one billion or more, which `Time`
+    // does not support.
+    let time = Time::from_hms_nano(
+        value.hour() as u8,
+        value.minute() as u8,
+        value.second() as u8,
+        value.nanosecond(),
+    )
+    .map_err(out_of_range)?;
+    Ok(PrimitiveDateTime::new(date, time))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ion_data::IonEq;
+    use crate::types::TimestampBuilder;
+
+    fn date_time(
+        (year, month, day): (i32, Month, u8),
+        (hour, minute, second, nanosecond): (u8, u8, u8, u32),
+    ) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(year, month, day).unwrap(),
+            Time::from_hms_nano(hour, minute, second, nanosecond).unwrap(),
+        )
+    }
+
+    #[rstest]
+    #[case::utc(0)]
+    #[case::west(-5 * 60)]
+    #[case::east(5 * 60 + 30)]
+    fn offset_date_time_round_trip(#[case] offset_minutes: i32) -> IonResult<()> {
+        let offset = UtcOffset::from_whole_seconds(offset_minutes * 60).unwrap();
+        let value =
+            date_time((2021, Month::April, 6), (10, 15, 0, 449_000_000)).assume_offset(offset);
+        let timestamp = Timestamp::try_from(value)?;
+        let expected = TimestampBuilder::with_ymd(2021, 4, 6)
+            .with_hms(10, 15, 0)
+            .with_nanoseconds(449_000_000)
+            .with_offset(offset_minutes)
+            .build()?;
+        assert!(timestamp.ion_eq(&expected));
+        let converted = OffsetDateTime::try_from(timestamp)?;
+        assert_eq!(converted, value);
+        assert_eq!(converted.offset(), offset);
+        Ok(())
+    }
+
+    #[test]
+    fn primitive_date_time_round_trip() -> IonResult<()> {
+        let value = date_time((1857, Month::May, 29), (19, 25, 59, 100));
+        let timestamp = Timestamp::try_from(value)?;
+        assert_eq!(timestamp.offset(), None);
+        assert_eq!(PrimitiveDateTime::try_from(timestamp)?, value);
+        Ok(())
+    }
+
+    #[test]
+    fn offset_with_seconds_is_rejected() {
+        let offset = UtcOffset::from_hms(1, 0, 30).unwrap();
+        let value = date_time((2021, Month::April, 6), (10, 15, 0, 0)).assume_offset(offset);
+        assert!(Timestamp::try_from(value).is_err());
+    }
+
+    #[test]
+    fn offset_mismatch_is_rejected() -> IonResult<()> {
+        let unknown_offset = TimestampBuilder::with_ymd(2021, 4, 6)
+            .with_hms(10, 15, 0)
+            .build()?;
+        assert!(OffsetDateTime::try_from(unknown_offset).is_err());
+        let known_offset = TimestampBuilder::with_ymd(2021, 4, 6)
+            .with_hms(10, 15, 0)
+            .with_offset(0)
+            .build()?;
+        assert!(PrimitiveDateTime::try_from(known_offset).is_err());
+        Ok(())
+    }
+}
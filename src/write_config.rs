@@ -1,21 +1,24 @@
+use std::collections::HashMap;
 use std::io;
 use std::marker::PhantomData;
 
 use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::writer::Writer;
-use crate::lazy::encoder::LazyRawWriter;
+use crate::lazy::encoder::{LazyRawWriter, SymbolCreationPolicy};
 use crate::lazy::encoding::{
     BinaryEncoding_1_0, BinaryEncoding_1_1, Encoding, OutputFromBytes, TextEncoding_1_0,
     TextEncoding_1_1,
 };
-use crate::{IonResult, TextFormat};
+use crate::{FractionalSecondsPrecision, IonResult, SharedSymbolTable, TextFormat};
 
 /// Writer configuration to provide format and Ion version details to writer through encoding
 /// This will be used to create a writer without specifying which writer methods to use
 #[derive(Clone, Debug)]
 pub struct WriteConfig<E: Encoding> {
     pub(crate) kind: WriteConfigKind,
+    pub(crate) symbol_styles: HashMap<String, SymbolStyle>,
+    pub(crate) symbol_table_imports: Vec<SharedSymbolTable>,
     phantom_data: PhantomData<E>,
 }
 
@@ -77,42 +80,190 @@ impl<E: Encoding> WriteConfig<E> {
     pub(crate) fn build_raw_writer<W: io::Write>(self, output: W) -> IonResult<E::Writer<W>> {
         E::Writer::build(self, output)
     }
+
+    /// Overrides how the writer encodes symbols with the given `text`. The style applies wherever
+    /// the text appears: symbol values, field names, and annotations.
+    pub fn with_symbol_style(mut self, text: impl Into<String>, style: SymbolStyle) -> Self {
+        self.symbol_styles.insert(text.into(), style);
+        self
+    }
+
+    /// Adds `table` to the shared symbol tables that the writer imports at the beginning of each
+    /// stream. Symbols found in an imported table are encoded using their symbol IDs without being
+    /// defined in the stream, so repeated streams that use the same symbols can omit them.
+    ///
+    /// A reader must have each imported table in its [`Catalog`](crate::Catalog) to read the
+    /// output. A writer's own symbols can be captured for this purpose with
+    /// [`Writer::export_symbols`].
+    pub fn with_symbol_table_import(mut self, table: SharedSymbolTable) -> Self {
+        self.symbol_table_imports.push(table);
+        self
+    }
+
+    /// Returns the policy the writer uses to decide whether symbol text is added to the symbol
+    /// table.
+    pub(crate) fn symbol_creation_policy(&self) -> SymbolCreationPolicy {
+        match &self.kind {
+            WriteConfigKind::Binary(config) if config.inline_symbol_text => {
+                SymbolCreationPolicy::WriteProvidedToken
+            }
+            _ => E::DEFAULT_SYMBOL_CREATION_POLICY,
+        }
+    }
+
+    /// Returns `true` if the writer may write system values; see `with_system_values`.
+    pub(crate) fn writes_system_values(&self) -> bool {
+        match &self.kind {
+            WriteConfigKind::Text(text_config) => text_config.write_system_values,
+            WriteConfigKind::Binary(_) => true,
+        }
+    }
+
+    /// Returns a mutable reference to the text configuration. Only the text encodings' builder
+    /// methods call this, so the configuration is always `WriteConfigKind::Text`.
+    fn text_config_mut(&mut self) -> &mut TextWriteConfig {
+        match &mut self.kind {
+            WriteConfigKind::Text(text_config) => text_config,
+            WriteConfigKind::Binary(_) => {
+                unreachable!("text configuration options cannot be set on a binary WriteConfig")
+            }
+        }
+    }
+
+    /// Like [`Self::text_config_mut`], but for the binary encodings' builder methods.
+    fn binary_config_mut(&mut self) -> &mut BinaryWriteConfig {
+        match &mut self.kind {
+            WriteConfigKind::Binary(binary_config) => binary_config,
+            WriteConfigKind::Text(_) => {
+                unreachable!("binary configuration options cannot be set on a text WriteConfig")
+            }
+        }
+    }
 }
 
 impl WriteConfig<TextEncoding_1_0> {
     pub fn new(text_kind: TextFormat) -> Self {
         Self {
-            kind: WriteConfigKind::Text(TextWriteConfig { text_kind }),
+            kind: WriteConfigKind::Text(TextWriteConfig::new(text_kind)),
+            symbol_styles: HashMap::new(),
+            symbol_table_imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
+
+    /// Sets the number of fractional seconds digits that will be written for timestamps with
+    /// second-level precision. By default, timestamps are written using their own precision.
+    pub fn with_fractional_seconds_precision(
+        mut self,
+        precision: FractionalSecondsPrecision,
+    ) -> Self {
+        self.text_config_mut().fractional_seconds_precision = precision;
+        self
+    }
+
+    /// Wraps the base64 text of blobs so that no line of it is longer than `width` characters.
+    /// Wrapped blobs place each line of base64 text on its own line between the `{{` and `}}`
+    /// delimiters. By default (or if `width` is zero), blobs are written on a single line.
+    pub fn with_blob_line_width(mut self, width: usize) -> Self {
+        self.text_config_mut().blob_line_width = (width > 0).then_some(width);
+        self
+    }
+
+    /// Controls whether the writer emits system values (symbol tables) in addition to user values.
+    /// Disabling them is useful for producing small Ion text fragments that will be embedded in
+    /// another document. By default, system values are written.
+    ///
+    /// Text Ion 1.0 streams do not begin with a version marker, so a symbol table is the only
+    /// system value this encoding writes, and only for symbols written as symbol IDs (see
+    /// [`SymbolStyle::SymbolId`]) or for imported symbol tables. If system values are disabled,
+    /// the writer returns an error instead of writing a symbol table.
+    pub fn with_system_values(mut self, enabled: bool) -> Self {
+        self.text_config_mut().write_system_values = enabled;
+        self
+    }
 }
 
 impl WriteConfig<TextEncoding_1_1> {
     pub fn new(text_kind: TextFormat) -> Self {
         Self {
-            kind: WriteConfigKind::Text(TextWriteConfig { text_kind }),
+            kind: WriteConfigKind::Text(TextWriteConfig::new(text_kind)),
+            symbol_styles: HashMap::new(),
+            symbol_table_imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
+
+    /// Sets the number of fractional seconds digits that will be written for timestamps with
+    /// second-level precision. By default, timestamps are written using their own precision.
+    pub fn with_fractional_seconds_precision(
+        mut self,
+        precision: FractionalSecondsPrecision,
+    ) -> Self {
+        self.text_config_mut().fractional_seconds_precision = precision;
+        self
+    }
+
+    /// Wraps the base64 text of blobs so that no line of it is longer than `width` characters.
+    /// Wrapped blobs place each line of base64 text on its own line between the `{{` and `}}`
+    /// delimiters. By default (or if `width` is zero), blobs are written on a single line.
+    pub fn with_blob_line_width(mut self, width: usize) -> Self {
+        self.text_config_mut().blob_line_width = (width > 0).then_some(width);
+        self
+    }
+
+    /// Controls whether the writer emits system values (the Ion version marker, symbol tables,
+    /// and encoding directives) in addition to user values. Disabling them is useful for producing
+    /// small Ion text fragments that will be embedded in another document. By default, system
+    /// values are written.
+    ///
+    /// If system values are disabled, the writer returns an error instead of writing a symbol
+    /// table (which symbols written as symbol IDs and imported symbol tables require; see
+    /// [`SymbolStyle::SymbolId`]) or the encoding directive that registering a template macro
+    /// writes.
+    pub fn with_system_values(mut self, enabled: bool) -> Self {
+        self.text_config_mut().write_system_values = enabled;
+        self
+    }
 }
 
 impl WriteConfig<BinaryEncoding_1_0> {
     pub fn new() -> Self {
         Self {
-            kind: WriteConfigKind::Binary(BinaryWriteConfig),
+            kind: WriteConfigKind::Binary(BinaryWriteConfig::new()),
+            symbol_styles: HashMap::new(),
+            symbol_table_imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
+
+    /// Controls whether `f64` values that can be represented exactly as an `f32` are written
+    /// using the 4-byte float encoding instead of the 8-byte one. Values written with
+    /// [`ValueWriter::write_f32`](crate::ValueWriter::write_f32) always use the 4-byte encoding.
+    /// By default, floats are compacted.
+    pub fn with_compact_floats(mut self, enabled: bool) -> Self {
+        self.binary_config_mut().compact_floats = enabled;
+        self
+    }
 }
 
 impl WriteConfig<BinaryEncoding_1_1> {
     pub fn new() -> Self {
         Self {
-            kind: WriteConfigKind::Binary(BinaryWriteConfig),
+            kind: WriteConfigKind::Binary(BinaryWriteConfig::new()),
+            symbol_styles: HashMap::new(),
+            symbol_table_imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
+
+    /// Controls whether symbol text is encoded inline instead of being added to the symbol table
+    /// and written as a symbol ID. Inline text avoids the cost of a symbol table entry for symbols
+    /// that rarely repeat. Styles set with [`Self::with_symbol_style`] take precedence. By default,
+    /// symbols are written as symbol IDs.
+    pub fn with_inline_symbol_text(mut self, enabled: bool) -> Self {
+        self.binary_config_mut().inline_symbol_text = enabled;
+        self
+    }
 }
 
 impl Default for WriteConfig<TextEncoding_1_0> {
@@ -139,6 +290,19 @@ impl Default for WriteConfig<BinaryEncoding_1_1> {
     }
 }
 
+/// How a writer encodes a symbol. See [`WriteConfig::with_symbol_style`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SymbolStyle {
+    /// Write the symbol's text inline. Text Ion only quotes the symbol if its text requires it.
+    /// Encodings that cannot write inline symbol text (binary Ion 1.0) write a symbol ID instead.
+    Text,
+    /// Like [`SymbolStyle::Text`], but text Ion always encloses the symbol in single quotes.
+    QuotedText,
+    /// Write a symbol ID, adding the text to the symbol table if necessary.
+    SymbolId,
+}
+
 /// Writer configuration type enum for text and binary configuration
 #[derive(Clone, Debug)]
 pub(crate) enum WriteConfigKind {
@@ -150,12 +314,38 @@ pub(crate) enum WriteConfigKind {
 #[derive(Clone, Debug)]
 pub(crate) struct TextWriteConfig {
     pub(crate) text_kind: TextFormat,
+    pub(crate) fractional_seconds_precision: FractionalSecondsPrecision,
+    pub(crate) blob_line_width: Option<usize>,
+    pub(crate) write_system_values: bool,
+}
+
+impl TextWriteConfig {
+    pub(crate) fn new(text_kind: TextFormat) -> Self {
+        Self {
+            text_kind,
+            fractional_seconds_precision: FractionalSecondsPrecision::default(),
+            blob_line_width: None,
+            write_system_values: true,
+        }
+    }
 }
 
 /// Binary writer configuration to be used to create a writer
 // TODO: Add appropriate binary configuration if required for 1.1
 #[derive(Clone, Debug)]
-pub(crate) struct BinaryWriteConfig;
+pub(crate) struct BinaryWriteConfig {
+    pub(crate) compact_floats: bool,
+    pub(crate) inline_symbol_text: bool,
+}
+
+impl BinaryWriteConfig {
+    pub(crate) fn new() -> Self {
+        Self {
+            compact_floats: true,
+            inline_symbol_text: false,
+        }
+    }
+}
 
 impl From<TextEncoding_1_0> for WriteConfig<TextEncoding_1_0> {
     fn from(_encoding: TextEncoding_1_0) -> Self {